@@ -0,0 +1,128 @@
+// Benchmarks for the cost of getting OSC from Reaper to the track handlers
+//
+// Reaper sends volume and pan for every track while the transport runs, so these dispatch the
+// same sort of traffic through a router laid out like the bridge's: a track context gate in front
+// of the generated dispatcher.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use criterion::{Criterion, Throughput, black_box, criterion_group, criterion_main};
+use rosc::{OscMessage, OscPacket, OscType};
+
+use arpad_rust::guid::TrackGuid;
+use arpad_rust::osc::generated_osc::{Reaper, context_kind, dispatch_osc};
+use arpad_rust::osc::route_context::context_gate::OscGatedRouter;
+use arpad_rust::osc::route_context::{ContextGateBuilder, OscGatedRouterBuilder};
+use arpad_rust::osc::transport::NullTransport;
+use arpad_rust::traits::Bind;
+
+const NUM_TRACKS: usize = 64;
+
+// Messages dispatched per iteration, as in a burst of updates from Reaper
+const BURST_LEN: usize = 256;
+
+fn track_guid(idx: usize) -> TrackGuid {
+    TrackGuid::new(format!("{{{:08X}-0000-0000-0000-000000000000}}", idx))
+}
+
+fn message(addr: String, arg: OscType) -> OscMessage {
+    OscMessage {
+        addr,
+        args: vec![arg],
+    }
+}
+
+// A router with every track's context already initialized, and a count of the handler calls it
+// has led to
+fn track_router() -> (OscGatedRouter, Arc<AtomicU64>) {
+    let handled = Arc::new(AtomicU64::new(0));
+    let reaper = Reaper::new(Arc::new(NullTransport));
+    for idx in 0..NUM_TRACKS {
+        let guid = track_guid(idx);
+        reaper.track_volume(guid.clone()).bind({
+            let handled = handled.clone();
+            move |_| {
+                handled.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+        reaper.track_pan(guid).bind({
+            let handled = handled.clone();
+            move |_| {
+                handled.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+    }
+
+    let mut router = OscGatedRouterBuilder::new(move |msg: OscMessage| {
+        dispatch_osc(&reaper, msg, |_, _| {});
+    })
+    .add_layer(Box::new(
+        ContextGateBuilder::<context_kind::Track>::new().add_key_route("/track/{guid}/index"),
+    ))
+    .build()
+    .unwrap();
+    for idx in 0..NUM_TRACKS {
+        router.dispatch_osc(OscPacket::Message(message(
+            format!("/track/{}/index", track_guid(idx)),
+            OscType::Int(idx as i32),
+        )));
+    }
+    (router, handled)
+}
+
+// Volume and pan, round robin over the tracks
+fn burst() -> Vec<OscMessage> {
+    (0..BURST_LEN)
+        .map(|i| {
+            let guid = track_guid(i % NUM_TRACKS);
+            let value = OscType::Float((i % 100) as f32 / 100.0);
+            match i % 2 == 0 {
+                true => message(format!("/track/{}/volume", guid), value),
+                false => message(format!("/track/{}/pan", guid), value),
+            }
+        })
+        .collect()
+}
+
+fn bench_dispatch(c: &mut Criterion) {
+    let msgs = burst();
+    let mut group = c.benchmark_group("osc_dispatch");
+    // A burst takes long enough that the default 100 samples would run for minutes
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(BURST_LEN as u64));
+
+    let (mut router, handled) = track_router();
+    group.bench_function("messages", |b| {
+        b.iter(|| {
+            for msg in &msgs {
+                router.dispatch_osc(black_box(OscPacket::Message(msg.clone())));
+            }
+        })
+    });
+    assert!(handled.load(Ordering::Relaxed) > 0);
+
+    // Messages for tracks the router hasn't heard of yet are held back until they are
+    let (mut router, _) = track_router();
+    let unknown: Vec<OscMessage> = (0..BURST_LEN)
+        .map(|i| {
+            message(
+                format!("/track/{}/volume", track_guid(NUM_TRACKS + i % NUM_TRACKS)),
+                OscType::Float(0.5),
+            )
+        })
+        .collect();
+    group.bench_function("buffered", |b| {
+        b.iter(|| {
+            for msg in &unknown {
+                router.dispatch_osc(black_box(OscPacket::Message(msg.clone())));
+            }
+            router.reset_all();
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_dispatch);
+criterion_main!(benches);
@@ -0,0 +1,157 @@
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// One raw message as it arrived, before any decoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Captured {
+    /// An encoded OSC packet from Reaper
+    Osc(Vec<u8>),
+    /// A MIDI message from one unit of the control surface, counted in the order the units sit in
+    Midi { unit: usize, message: Vec<u8> },
+}
+
+/// A captured message and when it arrived, relative to the start of the capture.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CapturedEvent {
+    pub at: Duration,
+    pub data: Captured,
+}
+
+impl CapturedEvent {
+    /// The line this event is stored as: microseconds since the start, the kind of message and
+    /// its bytes in hex, e.g. `1500 osc 2f6e756d...`. A MIDI message's kind says which unit sent
+    /// it, e.g. `1500 midi:1 b01001`.
+    pub fn to_line(&self) -> String {
+        let (kind, bytes) = match &self.data {
+            Captured::Osc(bytes) => ("osc".to_string(), bytes),
+            Captured::Midi { unit, message } => (format!("midi:{}", unit), message),
+        };
+        let mut line = format!("{} {} ", self.at.as_micros(), kind);
+        for byte in bytes {
+            write!(line, "{:02x}", byte).unwrap();
+        }
+        line
+    }
+
+    pub fn from_line(line: &str) -> Option<Self> {
+        let mut fields = line.split_whitespace();
+        let at = Duration::from_micros(fields.next()?.parse().ok()?);
+        let kind = fields.next()?;
+        let hex = fields.next().unwrap_or("");
+        if fields.next().is_some() || !hex.len().is_multiple_of(2) {
+            return None;
+        }
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+            .collect::<Option<Vec<u8>>>()?;
+        let data = match kind.split_once(':') {
+            None if kind == "osc" => Captured::Osc(bytes),
+            Some(("midi", unit)) => Captured::Midi {
+                unit: unit.parse().ok()?,
+                message: bytes,
+            },
+            _ => return None,
+        };
+        Some(CapturedEvent { at, data })
+    }
+}
+
+/// Writes timestamped OSC and MIDI traffic to a capture, one event per line.
+///
+/// Clones share the same capture and clock, so every source of traffic can hold its own.
+#[derive(Clone)]
+pub struct Recorder {
+    start: Instant,
+    out: Arc<Mutex<Box<dyn Write + Send>>>,
+}
+
+impl Recorder {
+    pub fn new(out: impl Write + Send + 'static) -> Self {
+        Recorder {
+            start: Instant::now(),
+            out: Arc::new(Mutex::new(Box::new(out))),
+        }
+    }
+
+    /// Starts a capture file at `path`, replacing any file already there.
+    pub fn create(path: &Path) -> io::Result<Self> {
+        Ok(Recorder::new(BufWriter::new(File::create(path)?)))
+    }
+
+    pub fn record_osc(&self, packet: &[u8]) -> io::Result<()> {
+        self.record(Captured::Osc(packet.to_vec()))
+    }
+
+    /// Records a message the surface's `unit` sent.
+    pub fn record_midi(&self, unit: usize, message: &[u8]) -> io::Result<()> {
+        self.record(Captured::Midi {
+            unit,
+            message: message.to_vec(),
+        })
+    }
+
+    fn record(&self, data: Captured) -> io::Result<()> {
+        let event = CapturedEvent {
+            at: self.start.elapsed(),
+            data,
+        };
+        let mut out = self.out.lock().unwrap();
+        writeln!(out, "{}", event.to_line())?;
+        // A capture is most useful right after a crash, so don't leave anything sitting in a buffer
+        out.flush()
+    }
+}
+
+/// Reads back a capture written by a Recorder. Blank lines are skipped.
+pub fn read_capture(input: impl BufRead) -> io::Result<Vec<CapturedEvent>> {
+    let mut events = Vec::new();
+    for (number, line) in input.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let event = CapturedEvent::from_line(&line).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid capture line {}: {:?}", number + 1, line),
+            )
+        })?;
+        events.push(event);
+    }
+    Ok(events)
+}
+
+/// Hands each event to `handle` with the same spacing it was captured with, `speed` times faster.
+///
+/// A speed of 2.0 plays back twice as fast; an infinite speed plays everything back to back.
+/// Panics unless the speed is positive; see `parse_speed` for checking one first.
+pub fn replay<F>(events: &[CapturedEvent], speed: f64, mut handle: F)
+where
+    F: FnMut(&CapturedEvent),
+{
+    assert!(speed > 0.0, "replay speed must be positive, got {}", speed);
+    let start = Instant::now();
+    for event in events {
+        let due = start + event.at.div_f64(speed);
+        let wait = due.saturating_duration_since(Instant::now());
+        if !wait.is_zero() {
+            thread::sleep(wait);
+        }
+        handle(event);
+    }
+}
+
+/// Reads a replay speed, which has to be positive, e.g. from the command line.
+pub fn parse_speed(speed: &str) -> Result<f64, String> {
+    match speed.parse::<f64>() {
+        Ok(speed) if speed > 0.0 => Ok(speed),
+        Ok(speed) => Err(format!("the speed has to be positive, not {}", speed)),
+        Err(e) => Err(e.to_string()),
+    }
+}
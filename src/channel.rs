@@ -0,0 +1,204 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, Weak};
+use std::thread;
+use std::time::Duration;
+
+use crossbeam_channel::{SendTimeoutError, Sender, TrySendError};
+use serde::Deserialize;
+use tracing::warn;
+
+/// What a [`BoundedSender`] does with a message when its channel is full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Backpressure {
+    /// Wait up to this many milliseconds for room, then drop the message
+    Block(u64),
+    /// Drop the message straight away
+    Drop,
+    /// Hold on to the message until there's room, keeping only the latest of those with the
+    /// same key
+    #[default]
+    CoalesceLatest,
+}
+
+/// Says which messages stand in for one another when they back up; None for a message that's
+/// always kept.
+pub type CoalesceKey<T> = fn(&T) -> Option<String>;
+
+// Messages waiting for room in the channel, oldest first
+struct Held<T> {
+    queue: VecDeque<(Option<String>, T)>,
+    // Whether the oldest is out of the queue, waiting for room
+    handing_over: bool,
+}
+
+type SharedHeld<T> = (Mutex<Held<T>>, Condvar);
+
+/// Feeds a bounded channel without ever panicking or blocking for long when it fills up, which
+/// it does whenever Reaper sends more than its consumer can keep up with.
+///
+/// What happens to a message that doesn't fit is up to the [`Backpressure`] policy. Messages that
+/// are dropped, including any sent after the receiver has gone, are counted.
+///
+/// When coalescing, messages that don't fit are held in order and handed over by a thread of
+/// their own as room frees up. Anything sent while messages are held waits its turn behind them,
+/// or takes the place of a held message with the same key.
+pub struct BoundedSender<T> {
+    name: String,
+    sender: Sender<T>,
+    policy: Backpressure,
+    key: CoalesceKey<T>,
+    held: Arc<SharedHeld<T>>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl<T> Clone for BoundedSender<T> {
+    fn clone(&self) -> Self {
+        BoundedSender {
+            name: self.name.clone(),
+            sender: self.sender.clone(),
+            policy: self.policy,
+            key: self.key,
+            held: self.held.clone(),
+            dropped: self.dropped.clone(),
+        }
+    }
+}
+
+impl<T: Send + 'static> BoundedSender<T> {
+    /// `name` is what drops are reported under.
+    pub fn new(name: &str, sender: Sender<T>, policy: Backpressure) -> Self {
+        let held = Arc::new((
+            Mutex::new(Held {
+                queue: VecDeque::new(),
+                handing_over: false,
+            }),
+            Condvar::new(),
+        ));
+        if policy == Backpressure::CoalesceLatest {
+            let holding = Arc::downgrade(&held);
+            let sender = sender.clone();
+            thread::spawn(move || hand_over_held(holding, sender));
+        }
+        BoundedSender {
+            name: name.to_string(),
+            sender,
+            policy,
+            key: |_| None,
+            held,
+            dropped: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Coalesces messages by `key`. Without one, every held message is kept.
+    pub fn with_coalesce_key(mut self, key: CoalesceKey<T>) -> Self {
+        self.key = key;
+        self
+    }
+
+    /// Sends `msg`, or deals with it according to the policy if there's no room.
+    pub fn send(&self, msg: T) {
+        match self.policy {
+            Backpressure::Block(timeout_ms) => {
+                match self
+                    .sender
+                    .send_timeout(msg, Duration::from_millis(timeout_ms))
+                {
+                    Ok(()) => {}
+                    Err(SendTimeoutError::Timeout(_)) => self.record_drop(),
+                    Err(SendTimeoutError::Disconnected(_)) => self.record_drop(),
+                }
+            }
+            Backpressure::Drop => match self.sender.try_send(msg) {
+                Ok(()) => {}
+                Err(TrySendError::Full(_)) => self.record_drop(),
+                Err(TrySendError::Disconnected(_)) => self.record_drop(),
+            },
+            Backpressure::CoalesceLatest => self.coalesce(msg),
+        }
+    }
+
+    fn coalesce(&self, msg: T) {
+        let (held, room_needed) = &*self.held;
+        let mut held = held.lock().unwrap();
+        // Held messages go first, so nothing overtakes them
+        let msg = match held.queue.is_empty() && !held.handing_over {
+            true => match self.sender.try_send(msg) {
+                Ok(()) => return,
+                Err(TrySendError::Full(msg)) => msg,
+                Err(TrySendError::Disconnected(_)) => return self.record_drop(),
+            },
+            false => msg,
+        };
+        let key = (self.key)(&msg);
+        match held
+            .queue
+            .iter_mut()
+            .find(|(other, _)| key.is_some() && *other == key)
+        {
+            Some((_, superseded)) => *superseded = msg,
+            None => {
+                held.queue.push_back((key, msg));
+                room_needed.notify_one();
+            }
+        }
+    }
+
+    fn record_drop(&self) {
+        let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+        // Only now and then, since drops come in floods
+        if dropped.is_power_of_two() {
+            warn!("{} is full, {} messages dropped so far", self.name, dropped);
+        }
+    }
+
+    /// How many messages have been dropped so far.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// How many messages are being held until there's room for them.
+    pub fn held(&self) -> usize {
+        let held = self.held.0.lock().unwrap();
+        held.queue.len() + held.handing_over as usize
+    }
+
+    /// The channel this feeds, e.g. to watch its occupancy.
+    pub fn sender(&self) -> &Sender<T> {
+        &self.sender
+    }
+}
+
+// Hands held messages over to the channel in order as room frees up, until every BoundedSender
+// holding them is dropped or the receiver goes away
+fn hand_over_held<T>(holding: Weak<SharedHeld<T>>, sender: Sender<T>) {
+    // Wake up now and then even with nothing held, to notice the senders going away
+    const IDLE_WAIT: Duration = Duration::from_millis(100);
+    while let Some(holding) = holding.upgrade() {
+        let (held, room_needed) = &*holding;
+        let mut waiting = held.lock().unwrap();
+        let Some((key, msg)) = waiting.queue.pop_front() else {
+            drop(room_needed.wait_timeout(waiting, IDLE_WAIT).unwrap());
+            continue;
+        };
+        // Wait for room without the lock, so senders can keep coalescing meanwhile
+        waiting.handing_over = true;
+        drop(waiting);
+        let sent = sender.send_timeout(msg, IDLE_WAIT);
+        let mut waiting = held.lock().unwrap();
+        waiting.handing_over = false;
+        match sent {
+            Ok(()) => {}
+            Err(SendTimeoutError::Timeout(msg)) => {
+                // Unless a later message with its key came along while it was out
+                let superseded =
+                    key.is_some() && waiting.queue.iter().any(|(other, _)| *other == key);
+                if !superseded {
+                    waiting.queue.push_front((key, msg));
+                }
+            }
+            Err(SendTimeoutError::Disconnected(_)) => return,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use serde_yaml::Value;
+
+use crate::channel::Backpressure;
+use crate::midi::ports::PortRoute;
+use crate::modes::mode_manager::BarrierRecovery;
+use crate::modes::taper::Taper;
+use crate::modes::track_mapping::MappingPolicy;
+use crate::transport::ActionBinding;
+
+/// The OSC spec the generated Reaper client was built from. It ships inside the binary so a
+/// deployment always carries the spec its routes expect; `spec_file` overrides it at runtime.
+pub const DEFAULT_SPEC: &[u8] = include_bytes!("../spec/reaper_osc.yaml");
+
+/// The config used when no config file is given, and the base any config file is layered on.
+pub const DEFAULT_CONFIG: &[u8] = include_bytes!("../config/default.yaml");
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Read(std::io::Error),
+    Parse(serde_yaml::Error),
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub osc_address: String,
+    pub reaper_host: String,
+    pub reaper_port: u16,
+    pub address_prefix: Option<String>,
+    pub transport: String,
+    pub headless: bool,
+    pub dry_run: bool,
+    pub surface_ports: Vec<PortRoute>,
+    pub strict_routing: bool,
+    pub spec_file: Option<PathBuf>,
+    pub gate_max_buffered: usize,
+    pub gate_overflow_policy: String,
+    pub gate_purge_interval_secs: u64,
+    pub mapping_policy: MappingPolicy,
+    pub fader_taper: Taper,
+    pub subscribers: Vec<String>,
+    pub coalesce_window_ms: u64,
+    pub echo_ttl_ms: u64,
+    pub backpressure: Backpressure,
+    pub follow_selection: bool,
+    pub show_markers: bool,
+    pub barrier_timeout_ms: u64,
+    pub barrier_recovery: BarrierRecovery,
+    pub resync_interval_ms: u64,
+    pub action_buttons: Vec<ActionBinding>,
+    pub log_level: String,
+    pub log_format: String,
+    pub metrics_interval_secs: u64,
+    pub watchdog_stall_ms: u64,
+    pub watchdog_restart: bool,
+    pub session_file: Option<PathBuf>,
+    pub session_save_interval_secs: u64,
+    pub scenes_file: Option<PathBuf>,
+    pub api_address: Option<String>,
+}
+
+impl Config {
+    /// Loads the embedded default config, with the config file at `path` (if any) layered on top.
+    pub fn load(path: Option<&Path>) -> Result<Self, ConfigError> {
+        let overrides = match path {
+            Some(path) => Some(std::fs::read(path).map_err(ConfigError::Read)?),
+            None => None,
+        };
+        Self::from_layers(overrides.as_deref())
+    }
+
+    /// Parses the embedded default config with `overrides` layered on top.
+    ///
+    /// The overrides only need to contain the keys they change.
+    pub fn from_layers(overrides: Option<&[u8]>) -> Result<Self, ConfigError> {
+        let mut config: Value =
+            serde_yaml::from_slice(DEFAULT_CONFIG).map_err(ConfigError::Parse)?;
+        if let Some(overrides) = overrides {
+            let overrides: Value = serde_yaml::from_slice(overrides).map_err(ConfigError::Parse)?;
+            merge(&mut config, overrides);
+        }
+        serde_yaml::from_value(config).map_err(ConfigError::Parse)
+    }
+}
+
+// Recursively replaces the values in `base` with those in `overrides`. Mappings are merged key by
+// key; anything else in `overrides` replaces the value in `base` wholesale.
+fn merge(base: &mut Value, overrides: Value) {
+    match (base, overrides) {
+        (Value::Mapping(base), Value::Mapping(overrides)) => {
+            for (key, value) in overrides {
+                match base.get_mut(&key) {
+                    Some(base_value) => merge(base_value, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        // An empty config file parses as null and changes nothing
+        (_, Value::Null) => {}
+        (base, overrides) => *base = overrides,
+    }
+}
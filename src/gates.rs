@@ -0,0 +1,823 @@
+use std::time::Duration;
+
+use crossbeam_channel::Sender;
+use rosc::OscMessage;
+use tracing::{debug, warn};
+
+use crate::osc::decode::Unhandled;
+use crate::osc::generated_osc::{Reaper, context, context_kind, dispatch_osc};
+use crate::osc::route_context::context_gate::{
+    ContextGateBuilderTrait, OscGatedRouter, OverflowPolicy,
+};
+use crate::osc::route_context::key_messages::{
+    MarkerKeyMessages, RegionKeyMessages, TrackFxKeyMessages, TrackFxParamKeyMessages,
+    TrackKeyMessages, TrackSendKeyMessages,
+};
+use crate::osc::route_context::{
+    ContextGateBuilder, ContextKindTrait, InitReceipt, OscGatedRouterBuilder,
+};
+use crate::osc::spec::RouteSpec;
+use crate::shared::Shared;
+use crate::traits::Bind;
+
+use arpad_rust::channel::BoundedSender;
+use arpad_rust::guid::TrackGuid;
+use arpad_rust::project::ProjectMsg;
+use arpad_rust::track::track::{
+    DataPayload, Direction, FXBypass, FXEnabled, FXGuid, FXName, FXParamMax, FXParamMin,
+    FXParamName, FXParamValue, FXWet, SendIndex, SendLevel, SendMute, SendPan, TrackDataMsg,
+    TrackMsg,
+};
+
+/// How the router holds back messages for contexts that haven't initialized yet.
+pub struct GateOptions {
+    /// Reject /track/ messages that don't match any known context instead of passing them through
+    pub strict_routing: bool,
+    /// The spec loaded in place of the built-in one, whose routes are the only ones dispatched
+    pub spec: Option<RouteSpec>,
+    /// How often messages still waiting on a context that never initialized are dropped
+    pub purge_interval: Duration,
+    /// How many messages each context may hold back; 0 for no limit
+    pub max_buffered: usize,
+    /// What happens to a message for a context already holding back `max_buffered`
+    pub overflow_policy: OverflowPolicy,
+}
+
+/// The router Reaper's messages pass through on their way to the handlers bound on `reaper`. It
+/// holds each message back until the contexts it's in have initialized, binding their routes as
+/// they do.
+///
+/// The GUID of each track Reaper reports deleted is sent to `removed_tracks`, so the receive loop
+/// can reset its context once the message has been dispatched.
+pub fn build_router(
+    reaper: &Shared<Reaper>,
+    track_send: &BoundedSender<TrackMsg>,
+    project_send: &Sender<ProjectMsg>,
+    removed_tracks: &Sender<TrackGuid>,
+    options: &GateOptions,
+) -> OscGatedRouter {
+    let dispatcher = {
+        let reaper = reaper.clone();
+        let spec = options.spec.clone();
+        move |msg: OscMessage| {
+            if let Some(spec) = &spec
+                && !spec.allows(&msg.addr)
+            {
+                debug!(
+                    "Unhandled message to {}: {}",
+                    msg.addr,
+                    Unhandled::UnknownAddress
+                );
+                return;
+            }
+            reaper.with(|reaper| {
+                dispatch_osc(reaper, msg, |addr, reason| {
+                    debug!("Unhandled message to {}: {}", addr, reason)
+                });
+            })
+        }
+    };
+
+    let strict_prefixes = match options.strict_routing {
+        true => vec!["/track/"],
+        false => vec![],
+    };
+    OscGatedRouterBuilder::new(dispatcher)
+        .with_purge_interval(options.purge_interval)
+        .with_strict_prefixes(strict_prefixes, |msg, count| {
+            warn!(
+                "Rejected OSC message for unknown context: {} ({} so far)",
+                msg.addr, count
+            )
+        })
+        .add_layer(limited(
+            track_layer(reaper, track_send, removed_tracks),
+            options,
+        ))
+        .add_layer(limited(track_send_layer(reaper, track_send), options))
+        .add_layer(limited(track_fx_layer(reaper, track_send), options))
+        .add_layer(limited(track_fx_param_layer(reaper, track_send), options))
+        .add_layer(limited(marker_layer(reaper, project_send), options))
+        .add_layer(limited(region_layer(reaper, project_send), options))
+        .build()
+        .unwrap_or_else(|e| panic!("Couldn't build the OSC router: {}", e))
+}
+
+// Caps how many messages each of the layer's contexts may hold back, if the options say to
+fn limited<K: ContextKindTrait + 'static>(
+    layer: ContextGateBuilder<K>,
+    options: &GateOptions,
+) -> Box<dyn ContextGateBuilderTrait> {
+    match options.max_buffered {
+        0 => Box::new(layer),
+        max_messages => Box::new(layer.with_max_buffered(max_messages, options.overflow_policy)),
+    }
+}
+
+// Binds each track's routes as its context initializes, telling TrackManager of the track and
+// holding its messages back until TrackManager has taken that in
+fn track_layer(
+    reaper: &Shared<Reaper>,
+    track_send: &BoundedSender<TrackMsg>,
+    removed_tracks: &Sender<TrackGuid>,
+) -> ContextGateBuilder<context_kind::Track> {
+    let reaper = reaper.clone();
+    let track_send = track_send.clone();
+    let removed_tracks = removed_tracks.clone();
+    ContextGateBuilder::<context_kind::Track>::new().with_acknowledged_key_messages(
+        move |ctx, key: TrackKeyMessages| {
+            debug!(
+                "Initialized track context: {:?} at index {}",
+                ctx, key.index
+            );
+            let guid = ctx.track_guid.clone();
+            reaper.with(|reaper| {
+                let track_guid = ctx.track_guid;
+                // Track Index
+                //
+                // For now, we aren't doing anything with this
+                reaper.track_index(track_guid.clone()).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    let removed_tracks = removed_tracks.clone();
+                    move |index| {
+                        if index.index < 0 {
+                            let _ = removed_tracks.try_send(track_guid.clone());
+                        }
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            // Reaper reports a deleted track at index -1
+                            data: DataPayload::ReaperTrackIndex(match index.index >= 0 {
+                                true => Some(index.index),
+                                false => None,
+                            }),
+                        }));
+                        debug!(
+                            "Track {} index initial value: {:?}",
+                            track_guid.clone(),
+                            index
+                        )
+                    }
+                });
+                // Track Name
+                reaper.track_name(track_guid.clone()).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |name| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::Name(
+                                String::from_utf8_lossy(&name.name).into_owned(),
+                            ),
+                        }));
+                        debug!(
+                            "Track {} name initial value: {:?}",
+                            track_guid.clone(),
+                            name
+                        )
+                    }
+                });
+                // Track Selected
+                reaper.track_selected(track_guid.clone()).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |selected| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::Selected(selected.selected),
+                        }));
+                        debug!(
+                            "Track {} selected initial value: {:?}",
+                            track_guid.clone(),
+                            selected
+                        )
+                    }
+                });
+                // Track Muted
+                reaper.track_mute(track_guid.clone()).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |muted| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::Muted(muted.mute),
+                        }));
+                        debug!(
+                            "Track {} muted initial value: {:?}",
+                            track_guid.clone(),
+                            muted
+                        )
+                    }
+                });
+                // Track Soloed
+                reaper.track_solo(track_guid.clone()).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |soloed| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::Soloed(soloed.solo),
+                        }));
+                        debug!(
+                            "Track {} soloed initial value: {:?}",
+                            track_guid.clone(),
+                            soloed
+                        )
+                    }
+                });
+                // Track Armed
+                reaper.track_rec_arm(track_guid.clone()).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |rec_arm| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::Armed(rec_arm.rec_arm),
+                        }));
+                        debug!(
+                            "Track {} armed initial value: {:?}",
+                            track_guid.clone(),
+                            rec_arm
+                        )
+                    }
+                });
+                // Track Volume
+                reaper.track_volume(track_guid.clone()).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |volume| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::Volume(volume.volume),
+                        }));
+                        debug!(
+                            "Track {} volume initial value: {:?}",
+                            track_guid.clone(),
+                            volume
+                        )
+                    }
+                });
+                // Track Pan
+                reaper.track_pan(track_guid.clone()).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |pan| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::Pan(pan.pan),
+                        }));
+                        debug!("Track {} pan initial value: {:?}", track_guid.clone(), pan)
+                    }
+                });
+                // Track Width
+                reaper.track_width(track_guid.clone()).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |width| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::Width(width.width),
+                        }));
+                        debug!(
+                            "Track {} width initial value: {:?}",
+                            track_guid.clone(),
+                            width
+                        )
+                    }
+                });
+                // Track Phase
+                reaper.track_phase(track_guid.clone()).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |phase| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::Phase(phase.phase),
+                        }));
+                        debug!(
+                            "Track {} phase initial value: {:?}",
+                            track_guid.clone(),
+                            phase
+                        )
+                    }
+                });
+                // Track Input Gain
+                reaper.track_input_gain(track_guid.clone()).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |input_gain| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::InputGain(input_gain.input_gain),
+                        }));
+                        debug!(
+                            "Track {} input gain initial value: {:?}",
+                            track_guid.clone(),
+                            input_gain
+                        )
+                    }
+                });
+                // Track Color
+                reaper.track_color(track_guid.clone()).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |color| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::Color(color.color),
+                        }));
+                        debug!(
+                            "Track {} color initial value: {:?}",
+                            track_guid.clone(),
+                            color
+                        )
+                    }
+                });
+                // Track Send Count
+                reaper.track_send_count(track_guid.clone()).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |send_count| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::SendCount(send_count.send_count),
+                        }));
+                        debug!(
+                            "Track {} send count initial value: {:?}",
+                            track_guid.clone(),
+                            send_count
+                        )
+                    }
+                });
+            });
+            // TrackManager learns of the track before any of its buffered messages
+            // are let through
+            track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid,
+                direction: Direction::Downstream,
+                data: DataPayload::ReaperTrackIndex(match key.index >= 0 {
+                    true => Some(key.index),
+                    false => None,
+                }),
+            }));
+            let (acknowledge, receipt) = InitReceipt::new();
+            track_send.send(TrackMsg::Acknowledge(acknowledge));
+            receipt
+        },
+    )
+}
+
+// Binds the routes of each of a track's sends as its context initializes
+fn track_send_layer(
+    reaper: &Shared<Reaper>,
+    track_send: &BoundedSender<TrackMsg>,
+) -> ContextGateBuilder<context_kind::TrackSend> {
+    let reaper = reaper.clone();
+    let track_send = track_send.clone();
+    ContextGateBuilder::<context_kind::TrackSend>::new()
+        .requires_parent::<context_kind::Track>(|ctx| context::Track {
+            track_guid: ctx.track_guid.clone(),
+        })
+        .with_key_messages(move |ctx, key: TrackSendKeyMessages| {
+            let track_guid = ctx.track_guid.clone();
+            let send_index = ctx.send_index;
+            debug!(
+                "Initialized track send context: {:?} sending to {}",
+                ctx, key.guid
+            );
+            reaper.with(|reaper| {
+                // Track Send GUID
+                reaper
+                    .track_send_guid(track_guid.clone(), send_index)
+                    .bind({
+                        let track_guid = track_guid.clone();
+                        let track_send = track_send.clone();
+                        move |send_guid| {
+                            track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                                guid: track_guid.clone(),
+                                direction: Direction::Downstream,
+                                data: DataPayload::SendIndex(SendIndex {
+                                    guid: TrackGuid::from(send_guid.guid.clone()),
+                                    send_index,
+                                }),
+                            }));
+                            debug!(
+                                "Track {} send {} guid initial value: {:?}",
+                                track_guid.clone(),
+                                send_index,
+                                send_guid
+                            )
+                        }
+                    });
+                // Track Send Volume
+                reaper
+                    .track_send_volume(track_guid.clone(), send_index)
+                    .bind({
+                        let track_guid = track_guid.clone();
+                        let track_send = track_send.clone();
+                        move |send_volume| {
+                            track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                                guid: track_guid.clone(),
+                                direction: Direction::Downstream,
+                                data: DataPayload::SendLevel(SendLevel {
+                                    send_index,
+                                    level: send_volume.volume,
+                                }),
+                            }));
+                            debug!(
+                                "Track {} send {} volume initial value: {:?}",
+                                track_guid.clone(),
+                                send_index,
+                                send_volume
+                            )
+                        }
+                    });
+                // Track Send Pan
+                reaper.track_send_pan(track_guid.clone(), send_index).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |send_pan| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::SendPan(SendPan {
+                                send_index,
+                                pan: send_pan.pan,
+                            }),
+                        }));
+                        debug!(
+                            "Track {} send {} pan initial value: {:?}",
+                            track_guid.clone(),
+                            send_index,
+                            send_pan
+                        )
+                    }
+                });
+                // Track Send Mute
+                reaper
+                    .track_send_mute(track_guid.clone(), send_index)
+                    .bind({
+                        let track_guid = track_guid.clone();
+                        let track_send = track_send.clone();
+                        move |send_mute| {
+                            track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                                guid: track_guid.clone(),
+                                direction: Direction::Downstream,
+                                data: DataPayload::SendMute(SendMute {
+                                    send_index,
+                                    muted: send_mute.mute,
+                                }),
+                            }));
+                            debug!(
+                                "Track {} send {} mute initial value: {:?}",
+                                track_guid.clone(),
+                                send_index,
+                                send_mute
+                            )
+                        }
+                    });
+            });
+        })
+}
+
+// Binds the routes of each FX on a track as its context initializes
+fn track_fx_layer(
+    reaper: &Shared<Reaper>,
+    track_send: &BoundedSender<TrackMsg>,
+) -> ContextGateBuilder<context_kind::TrackFx> {
+    let reaper = reaper.clone();
+    let track_send = track_send.clone();
+    ContextGateBuilder::<context_kind::TrackFx>::new()
+        .requires_parent::<context_kind::Track>(|ctx| context::Track {
+            track_guid: ctx.track_guid.clone(),
+        })
+        .with_key_messages(move |ctx, key: TrackFxKeyMessages| {
+            let track_guid = ctx.track_guid.clone();
+            let track_send = track_send.clone();
+            debug!(
+                "Initialized track fx context: {:?} with GUID {}",
+                ctx, key.guid
+            );
+            reaper.with(|reaper| {
+                // Track FX guid
+                reaper.track_fx_guid(track_guid.clone(), ctx.fx_idx).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |fx_guid| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::FXGuid(FXGuid {
+                                fx_index: ctx.fx_idx,
+                                guid: fx_guid.guid.clone(),
+                            }),
+                        }));
+                    }
+                });
+                // Track FX Name
+                reaper.track_fx_name(track_guid.clone(), ctx.fx_idx).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |fx_name| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::FXName(FXName {
+                                fx_index: ctx.fx_idx,
+                                name: fx_name.name.clone(),
+                            }),
+                        }));
+                        debug!(
+                            "Track {} fx {} name initial value: {:?}",
+                            track_guid.clone(),
+                            ctx.fx_idx,
+                            fx_name
+                        )
+                    }
+                });
+                // Track FX Enabled
+                reaper
+                    .track_fx_enabled(track_guid.clone(), ctx.fx_idx)
+                    .bind({
+                        let track_guid = track_guid.clone();
+                        let track_send = track_send.clone();
+                        move |fx_enabled| {
+                            track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                                guid: track_guid.clone(),
+                                direction: Direction::Downstream,
+                                data: DataPayload::FXEnabled(FXEnabled {
+                                    fx_index: ctx.fx_idx,
+                                    enabled: fx_enabled.enabled,
+                                }),
+                            }));
+                            debug!(
+                                "Track {} fx {} enabled initial value: {:?}",
+                                track_guid.clone(),
+                                ctx.fx_idx,
+                                fx_enabled
+                            )
+                        }
+                    });
+                // Track FX Bypass
+                reaper
+                    .track_fx_bypass(track_guid.clone(), ctx.fx_idx)
+                    .bind({
+                        let track_guid = track_guid.clone();
+                        let track_send = track_send.clone();
+                        move |fx_bypass| {
+                            track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                                guid: track_guid.clone(),
+                                direction: Direction::Downstream,
+                                data: DataPayload::FXBypass(FXBypass {
+                                    fx_index: ctx.fx_idx,
+                                    bypass: fx_bypass.bypass,
+                                }),
+                            }));
+                            debug!(
+                                "Track {} fx {} bypass initial value: {:?}",
+                                track_guid.clone(),
+                                ctx.fx_idx,
+                                fx_bypass
+                            )
+                        }
+                    });
+                // Track FX Wet
+                reaper.track_fx_wet(track_guid.clone(), ctx.fx_idx).bind({
+                    let track_guid = track_guid.clone();
+                    let track_send = track_send.clone();
+                    move |fx_wet| {
+                        track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                            guid: track_guid.clone(),
+                            direction: Direction::Downstream,
+                            data: DataPayload::FXWet(FXWet {
+                                fx_index: ctx.fx_idx,
+                                wet: fx_wet.wet,
+                            }),
+                        }));
+                        debug!(
+                            "Track {} fx {} wet initial value: {:?}",
+                            track_guid.clone(),
+                            ctx.fx_idx,
+                            fx_wet
+                        )
+                    }
+                });
+            })
+        })
+}
+
+// Binds the routes of each parameter of an FX as its context initializes
+fn track_fx_param_layer(
+    reaper: &Shared<Reaper>,
+    track_send: &BoundedSender<TrackMsg>,
+) -> ContextGateBuilder<context_kind::TrackFxParam> {
+    let reaper = reaper.clone();
+    let track_send = track_send.clone();
+    ContextGateBuilder::<context_kind::TrackFxParam>::new()
+        .requires_parent::<context_kind::TrackFx>(|ctx| context::TrackFx {
+            track_guid: ctx.track_guid.clone(),
+            fx_idx: ctx.fx_idx,
+        })
+        .with_key_messages(move |ctx, key: TrackFxParamKeyMessages| {
+            let track_guid = ctx.track_guid.clone();
+            let track_send = track_send.clone();
+            debug!(
+                "Initialized track fx param context: {:?} named {}",
+                ctx, key.name
+            );
+            reaper.with(|reaper| {
+                // Track FX Param Name
+                reaper
+                    .track_fx_param_name(track_guid.clone(), ctx.fx_idx, ctx.param_idx)
+                    .bind({
+                        let track_guid = track_guid.clone();
+                        let track_send = track_send.clone();
+                        move |fx_param_name| {
+                            track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                                guid: track_guid.clone(),
+                                direction: Direction::Downstream,
+                                data: DataPayload::FXParamName(FXParamName {
+                                    fx_index: ctx.fx_idx,
+                                    param_index: ctx.param_idx,
+                                    name: fx_param_name.param_name.clone(),
+                                }),
+                            }));
+                            debug!(
+                                "Track {} fx {} param {} name initial value: {:?}",
+                                track_guid.clone(),
+                                ctx.fx_idx,
+                                ctx.param_idx,
+                                fx_param_name
+                            )
+                        }
+                    });
+                // Track FX Param Value
+                reaper
+                    .track_fx_param_value(track_guid.clone(), ctx.fx_idx, ctx.param_idx)
+                    .bind({
+                        let track_guid = track_guid.clone();
+                        let track_send = track_send.clone();
+                        move |fx_param_value| {
+                            track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                                guid: track_guid.clone(),
+                                direction: Direction::Downstream,
+                                data: DataPayload::FXParamValue(FXParamValue {
+                                    fx_index: ctx.fx_idx,
+                                    param_index: ctx.param_idx,
+                                    value: fx_param_value.value,
+                                }),
+                            }));
+                            debug!(
+                                "Track {} fx {} param {} value initial value: {:?}",
+                                track_guid.clone(),
+                                ctx.fx_idx,
+                                ctx.param_idx,
+                                fx_param_value
+                            )
+                        }
+                    });
+                // Track FX Param Min
+                reaper
+                    .track_fx_param_min(track_guid.clone(), ctx.fx_idx, ctx.param_idx)
+                    .bind({
+                        let track_guid = track_guid.clone();
+                        let track_send = track_send.clone();
+                        move |fx_param_min| {
+                            track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                                guid: track_guid.clone(),
+                                direction: Direction::Downstream,
+                                data: DataPayload::FXParamMin(FXParamMin {
+                                    fx_index: ctx.fx_idx,
+                                    param_index: ctx.param_idx,
+                                    min: fx_param_min.min,
+                                }),
+                            }));
+                            debug!(
+                                "Track {} fx {} param {} min initial value: {:?}",
+                                track_guid.clone(),
+                                ctx.fx_idx,
+                                ctx.param_idx,
+                                fx_param_min
+                            )
+                        }
+                    });
+                // Track FX Param Max
+                reaper
+                    .track_fx_param_max(track_guid.clone(), ctx.fx_idx, ctx.param_idx)
+                    .bind({
+                        let track_guid = track_guid.clone();
+                        let track_send = track_send.clone();
+                        move |fx_param_max| {
+                            track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                                guid: track_guid.clone(),
+                                direction: Direction::Downstream,
+                                data: DataPayload::FXParamMax(FXParamMax {
+                                    fx_index: ctx.fx_idx,
+                                    param_index: ctx.param_idx,
+                                    max: fx_param_max.max,
+                                }),
+                            }));
+                            debug!(
+                                "Track {} fx {} param {} max initial value: {:?}",
+                                track_guid.clone(),
+                                ctx.fx_idx,
+                                ctx.param_idx,
+                                fx_param_max
+                            )
+                        }
+                    });
+            })
+        })
+}
+
+// Binds each marker's routes as its context initializes, for ProjectStateManager
+fn marker_layer(
+    reaper: &Shared<Reaper>,
+    project_send: &Sender<ProjectMsg>,
+) -> ContextGateBuilder<context_kind::Marker> {
+    let reaper = reaper.clone();
+    let project_send = project_send.clone();
+    ContextGateBuilder::<context_kind::Marker>::new().with_key_messages(
+        move |ctx, key: MarkerKeyMessages| {
+            let idx = ctx.marker_idx;
+            debug!("Initialized marker context: {:?} named {}", ctx, key.name);
+            reaper.with(|reaper| {
+                reaper.marker_name(idx).bind({
+                    let project_send = project_send.clone();
+                    move |marker| {
+                        let _ = project_send.try_send(ProjectMsg::MarkerName {
+                            idx,
+                            name: marker.name,
+                        });
+                    }
+                });
+                reaper.marker_position(idx).bind({
+                    let project_send = project_send.clone();
+                    move |marker| {
+                        let _ = project_send.try_send(ProjectMsg::MarkerPosition {
+                            idx,
+                            position: marker.position,
+                        });
+                    }
+                });
+            })
+        },
+    )
+}
+
+// Binds each region's routes as its context initializes, for ProjectStateManager
+fn region_layer(
+    reaper: &Shared<Reaper>,
+    project_send: &Sender<ProjectMsg>,
+) -> ContextGateBuilder<context_kind::Region> {
+    let reaper = reaper.clone();
+    let project_send = project_send.clone();
+    ContextGateBuilder::<context_kind::Region>::new().with_key_messages(
+        move |ctx, key: RegionKeyMessages| {
+            let idx = ctx.region_idx;
+            debug!("Initialized region context: {:?} named {}", ctx, key.name);
+            reaper.with(|reaper| {
+                reaper.region_name(idx).bind({
+                    let project_send = project_send.clone();
+                    move |region| {
+                        let _ = project_send.try_send(ProjectMsg::RegionName {
+                            idx,
+                            name: region.name,
+                        });
+                    }
+                });
+                reaper.region_start(idx).bind({
+                    let project_send = project_send.clone();
+                    move |region| {
+                        let _ = project_send.try_send(ProjectMsg::RegionStart {
+                            idx,
+                            start: region.start,
+                        });
+                    }
+                });
+                reaper.region_end(idx).bind({
+                    let project_send = project_send.clone();
+                    move |region| {
+                        let _ = project_send.try_send(ProjectMsg::RegionEnd {
+                            idx,
+                            end: region.end,
+                        });
+                    }
+                });
+            })
+        },
+    )
+}
@@ -0,0 +1,104 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+
+use serde::{Deserialize, Serialize};
+
+/// Declares a GUID newtype. Each kind of GUID gets its own type, so one can't be passed where
+/// another is expected, while still reading like the string it wraps.
+macro_rules! guid_type {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(
+            Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn new(guid: impl Into<String>) -> Self {
+                $name(guid.into())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        // Lets maps keyed by GUID be looked up with a &str
+        impl Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(guid: String) -> Self {
+                $name(guid)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(guid: &str) -> Self {
+                $name(guid.to_string())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(guid: $name) -> Self {
+                guid.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    };
+}
+
+guid_type!(
+    /// Reaper's GUID for a track, which stays the same when tracks are moved around or renamed.
+    TrackGuid
+);
+
+guid_type!(
+    /// Reaper's GUID for a send, as opposed to the GUID of the track it sends to.
+    SendGuid
+);
+
+guid_type!(
+    /// Reaper's GUID for a project, which tells apart the projects open in its tabs.
+    ProjectGuid
+);
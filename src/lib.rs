@@ -1,7 +1,16 @@
 pub mod traits;
 
+pub mod capture;
+pub mod channel;
+pub mod config;
+pub mod guid;
+pub mod metrics;
 pub mod midi;
 pub mod modes;
 pub mod motu;
 pub mod osc;
+pub mod project;
+pub mod scene;
 pub mod track;
+pub mod transport;
+pub mod watchdog;
@@ -1,544 +1,1481 @@
+mod gates;
 mod osc;
 mod shared;
 mod traits;
 
-use std::net::{SocketAddrV4, UdpSocket};
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{self, BufReader, Write};
+use std::net::{SocketAddr, SocketAddrV4, TcpListener, ToSocketAddrs, UdpSocket};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
 
-use clap::Parser;
-use crossbeam_channel::bounded;
-use rosc::OscMessage;
+use clap::{Parser, Subcommand, ValueEnum};
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender, bounded};
+use reaper_oscgen::GenArgs;
+use rosc::OscPacket;
+use tracing::{debug, debug_span, error, info, trace, warn};
+use tracing_subscriber::EnvFilter;
 
-use osc::generated_osc::{Reaper, context_kind, dispatch_osc};
-use osc::route_context::{ContextGateBuilder, OscGatedRouterBuilder};
+use gates::GateOptions;
+use osc::coalesce::{CoalescingTransport, DISCRETE_ROUTES};
+use osc::decode::{Unhandled, decode_packet};
+use osc::echo::EchoSuppressingTransport;
+use osc::error::OscError;
+use osc::generated_osc::{
+    BundleBuilder, MasterMuteArgs, MasterPanArgs, MasterVolumeArgs, Reaper, RunActionArgs,
+    StopArgs, TrackFxBypassArgs, TrackFxParamValueArgs, TrackFxWetArgs, TrackInputGainArgs,
+    TrackMuteArgs, TrackPanArgs, TrackPhaseArgs, TrackRecArmArgs, TrackSendMuteArgs,
+    TrackSendPanArgs, TrackSendVolumeArgs, TrackSoloArgs, TrackVolumeArgs, TrackWidthArgs, context,
+};
+use osc::monitor::{self, MonitorFilter};
+use osc::prefix::PrefixedTransport;
+use osc::route_context::context_gate::{OscGatedRouter, OverflowPolicy};
+use osc::spec::RouteSpec;
+use osc::subscribers::{FanOutTransport, SubscriberRegistry};
+use osc::supervisor::{ConnectionEvent, ConnectionSupervisor};
+use osc::transport::{
+    DryRunTransport, NullTransport, OscTransport, TcpSlipTransport, UdpTransport,
+};
 
+use arpad_rust::capture::{self, Captured, Recorder};
+use arpad_rust::channel::BoundedSender;
+use arpad_rust::config::{self, Config, DEFAULT_SPEC};
+use arpad_rust::guid::{self, ProjectGuid, TrackGuid};
+use arpad_rust::metrics::Metrics;
+use arpad_rust::midi::control_surface::{Capabilities, ControlSurface};
+use arpad_rust::midi::dry_run::DryRunSurface;
+use arpad_rust::midi::ports::{MidiTap, PortRouting, TappedPorts};
+use arpad_rust::midi::xtouch::simulator::SimulatedXTouch;
+use arpad_rust::midi::xtouch::surface::SurfaceLayout;
+use arpad_rust::midi::xtouch::{XTouchDownstreamMsg, XTouchUpstreamMsg};
+use arpad_rust::modes::history::UndoMsg;
+use arpad_rust::modes::mode_manager::{
+    Barrier, ModeBridge, ModeManager, ModeOptions, ModeStatus, ResyncRequest,
+};
+use arpad_rust::modes::session::{SessionSnapshot, SessionStore};
+// The API answers for the modes, so it's served from the library's side of the OSC module
+use arpad_rust::osc::bridge_api::BridgeApi;
+use arpad_rust::osc::transport::UdpTransport as ApiTransport;
+use arpad_rust::project::{ProjectMsg, ProjectStateManager, ProjectTabs};
+use arpad_rust::scene::SceneStore;
 use arpad_rust::track::track::{
-    DataPayload, Direction, FXEnabled, FXGuid, FXName, FXParamMax, FXParamMin, FXParamName,
-    FXParamValue, SendIndex, SendLevel, SendPan, TrackDataMsg, TrackManager, TrackMsg,
+    DataPayload, Direction, MASTER_GUID, SendDiscovery, TrackDataMsg, TrackManager, TrackMsg,
 };
+use arpad_rust::transport::{ActionBinding, TransportManager, TransportMsg};
+use arpad_rust::watchdog::{Consumer, Heartbeat, Watchdog};
 
 use crate::shared::Shared;
-use crate::traits::Bind;
+use crate::traits::{Bind, Query, QueryWithResponse, Set};
+
+// How long to wait for the first bank of tracks, which the surface waits on before its first full
+// render, before rendering whatever has arrived
+const INITIAL_SYNC_TIMEOUT: Duration = Duration::from_secs(5);
+// How often to poke Reaper so that a live connection never goes quiet
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(2);
+// How long Reaper can go without answering before we treat it as gone
+const REAPER_SILENCE_TIMEOUT: Duration = Duration::from_secs(6);
+// How often to check on tracks waiting for TrackManager to acknowledge them, so their buffered
+// messages go through without waiting on the next packet
+const ACK_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Transport {
+    Udp,
+    /// OSC 1.1 stream framing (SLIP)
+    Tcp,
+}
+
+// Spelled with underscores in the config file, like its other settings
+#[derive(Clone, Copy, ValueEnum)]
+enum GateOverflow {
+    /// Make room by dropping the oldest waiting message
+    #[value(alias = "drop_oldest")]
+    DropOldest,
+    /// Drop the new message
+    #[value(alias = "drop_newest")]
+    DropNewest,
+    /// Drop the new message and log a warning
+    #[value(alias = "fail_and_log")]
+    FailAndLog,
+}
+
+impl From<GateOverflow> for OverflowPolicy {
+    fn from(overflow: GateOverflow) -> Self {
+        match overflow {
+            GateOverflow::DropOldest => OverflowPolicy::DropOldest,
+            GateOverflow::DropNewest => OverflowPolicy::DropNewest,
+            GateOverflow::FailAndLog => OverflowPolicy::FailAndLog,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum LogFormat {
+    Text,
+    /// One JSON object per line
+    Json,
+}
 
+/// Bridges Reaper and an X-Touch over OSC. Without a command, runs the bridge.
 #[derive(Parser)]
+#[clap(name = "arpad", args_conflicts_with_subcommands = true)]
 struct Cli {
-    #[clap(short, long, default_value = "0.0.0.0:9000")]
-    osc_address: String,
+    /// Print the built-in OSC spec and exit
+    #[clap(long)]
+    print_spec: bool,
+    #[clap(flatten)]
+    bridge: BridgeArgs,
+    #[clap(subcommand)]
+    command: Option<Command>,
+}
+
+/// How to reach Reaper and the surface, for the commands that do
+#[derive(clap::Args)]
+struct BridgeArgs {
+    /// Config file layered over the built-in defaults; only the keys it sets are changed
+    #[clap(long)]
+    config: Option<PathBuf>,
+    #[clap(short, long)]
+    osc_address: Option<String>,
+    /// Host Reaper listens on for OSC, when talking to it over udp
+    #[clap(long)]
+    reaper_host: Option<String>,
+    /// Port Reaper listens on for OSC, when talking to it over udp
+    #[clap(long)]
+    reaper_port: Option<u16>,
+    /// Namespace Reaper's OSC is under, e.g. "/reaper1", put on everything sent to it and taken
+    /// off everything from it
+    #[clap(long)]
+    address_prefix: Option<String>,
+    #[clap(long, value_enum)]
+    transport: Option<Transport>,
+    /// Run the modes without a control surface, even if one is given, for the bridge's API alone;
+    /// --headless=false runs with one even if the config file says not to
+    #[clap(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    headless: Option<bool>,
+    /// Run everything as usual, but log what would be sent to Reaper and the surface instead of
+    /// sending it
+    #[clap(long)]
+    dry_run: bool,
+    /// Run the modes against an in-memory XTouch, printing what it shows. Lines on stdin like
+    /// "fader 0 0.5" act on it.
+    #[clap(long)]
+    simulate_xtouch: bool,
+    /// Drive the XTouch on the MIDI ports whose names contain this, reconnecting if it's unplugged
+    #[clap(long)]
+    xtouch_port: Option<String>,
+    /// Drive an X-Touch Extender on the MIDI ports whose names contain this, adding its channels
+    /// after the X-Touch's; may be given more than once, in the order the units sit in
+    #[clap(long = "xtouch-extender-port")]
+    xtouch_extender_ports: Vec<String>,
+    /// Report /track/ messages that don't match any known context instead of passing them through
+    #[clap(long)]
+    strict_routing: bool,
+    /// OSC spec to serve Reaper's routes from in place of the built-in one
+    #[clap(long)]
+    spec: Option<PathBuf>,
+    /// How many messages each track, send, FX, marker or region may have waiting on it before
+    /// Reaper has said what it is; 0 for no limit
+    #[clap(long)]
+    gate_max_buffered: Option<usize>,
+    /// What happens to a message for one that already has --gate-max-buffered waiting
+    #[clap(long, value_enum)]
+    gate_overflow_policy: Option<GateOverflow>,
+    /// Send a copy of everything sent to Reaper to this "host:port"; may be given more than once
+    #[clap(long = "subscriber")]
+    subscribers: Vec<String>,
+    /// Send each OSC address at most once per this many milliseconds; 0 sends everything
+    #[clap(long)]
+    coalesce_window_ms: Option<u64>,
+    /// Drop changes Reaper reports back within this many milliseconds of us sending the same
+    /// value; 0 lets everything through
+    #[clap(long)]
+    echo_ttl_ms: Option<u64>,
+    /// Show the sends of the track selected in Reaper, and volume and pan when none is
+    #[clap(long)]
+    follow_selection: bool,
+    /// Show the current marker's name along the bottom of the scribble strips
+    #[clap(long)]
+    show_markers: bool,
+    /// Which log messages are shown, e.g. "debug" or "info,arpad_rust::osc=trace"
+    #[clap(long)]
+    log_level: Option<String>,
+    #[clap(long, value_enum)]
+    log_format: Option<LogFormat>,
+    /// Log throughput, latency and buffer sizes once per this many seconds; 0 turns it off
+    #[clap(long)]
+    metrics_interval_secs: Option<u64>,
+    /// Keep the surface's state in this file, and pick up from it when started again
+    #[clap(long)]
+    session_file: Option<PathBuf>,
+    /// Keep the scenes captured on the surface in this file, and recall them from it
+    #[clap(long)]
+    scenes_file: Option<PathBuf>,
+    /// Record every OSC packet received, and every MIDI message from the surface, to this file,
+    /// for `replay`
+    #[clap(long)]
+    capture: Option<PathBuf>,
+    /// Serve the bridge's own OSC API on this address, for tablets to show the mode and the
+    /// tracks on the surface and switch modes from
+    #[clap(long)]
+    api_address: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the bridge between Reaper and the surface
+    Run(BridgeArgs),
+    /// Feed a capture back through the router instead of listening to Reaper
+    Replay {
+        path: PathBuf,
+        /// How many times faster than it was recorded to play the capture back
+        #[clap(
+            long,
+            default_value_t = 1.0,
+            value_parser = capture::parse_speed,
+            allow_negative_numbers = true
+        )]
+        speed: f64,
+        #[clap(flatten)]
+        bridge: BridgeArgs,
+    },
+    /// Set Reaper's tracks back to a scene from the scenes file and exit, alongside a running
+    /// bridge or without one
+    RecallScene {
+        /// The scene's name; the function keys capture scenes called "F1" to "F8"
+        name: String,
+        #[clap(flatten)]
+        bridge: BridgeArgs,
+    },
+    /// Print the OSC arriving on the OSC address, with the routes of the spec it matches, instead
+    /// of running the bridge
+    #[clap(alias = "osc-monitor")]
+    Monitor {
+        /// Only show messages to addresses matching this OSC pattern, e.g. "/track/*/volume";
+        /// may be given more than once
+        #[clap(long = "filter")]
+        filters: Vec<String>,
+        #[clap(flatten)]
+        bridge: BridgeArgs,
+    },
+    /// Generate the Rust client, or a .ReaperOSC config, from an OSC spec
+    GenOsc(GenArgs),
+    /// Check an OSC spec and list its routes and contexts
+    InspectSpec {
+        /// The spec to inspect; the built-in one if left out
+        spec: Option<PathBuf>,
+    },
+}
+
+/// What the bridge's process does once it's connected
+enum BridgeCommand {
+    Run,
+    Replay { path: PathBuf, speed: f64 },
 }
 
 fn main() {
     let cli = Cli::parse();
-    let socket_addr = SocketAddrV4::from_str(&cli.osc_address)
-        .unwrap_or_else(|_| panic!("couldn't parse address {:?}", cli.osc_address));
-    let socket = UdpSocket::bind(socket_addr)
-        .unwrap_or_else(|_| panic!("couldn't bind to address {:?}", cli.osc_address));
-
-    let reaper = Shared::new(Reaper::new(Arc::new(socket.try_clone().unwrap())));
+    if cli.print_spec {
+        std::io::stdout()
+            .write_all(DEFAULT_SPEC)
+            .expect("couldn't write spec");
+        return;
+    }
+    match cli.command {
+        None => run_bridge(cli.bridge, BridgeCommand::Run),
+        Some(Command::Run(bridge)) => run_bridge(bridge, BridgeCommand::Run),
+        Some(Command::Replay {
+            path,
+            speed,
+            bridge,
+        }) => run_bridge(bridge, BridgeCommand::Replay { path, speed }),
+        Some(Command::RecallScene { name, bridge }) => run_recall_scene(bridge, &name),
+        Some(Command::Monitor { filters, bridge }) => run_monitor(bridge, filters),
+        Some(Command::GenOsc(args)) => gen_osc(&args),
+        Some(Command::InspectSpec { spec }) => inspect_spec(spec.as_deref()),
+    }
+}
 
-    let (a_send, a_rec) = bounded(128); // buffer size as needed
-    let (b, _) = bounded(128); // buffer size as needed
-    let (c, _) = bounded(128); // buffer size as needed
-    TrackManager::start(a_rec.clone(), b.clone(), c.clone());
+fn gen_osc(args: &GenArgs) {
+    if let Err(e) = reaper_oscgen::run(args) {
+        eprintln!("{}; no code was generated from {}", e, args.spec.display());
+        std::process::exit(1);
+    }
+}
 
-    let dispatcher = {
-        let reaper = reaper.clone();
-        move |msg: OscMessage| {
-            reaper.with_mut(|reaper| {
-                dispatch_osc(reaper, msg, |_| println!("Unhandled message"));
-            })
+fn inspect_spec(spec: Option<&Path>) {
+    let routes = match spec {
+        Some(path) => reaper_oscgen::load_spec(path),
+        None => reaper_oscgen::parse_spec(
+            std::str::from_utf8(DEFAULT_SPEC).expect("the built-in spec isn't UTF-8"),
+        ),
+    };
+    match routes {
+        Ok(routes) => print!("{}", reaper_oscgen::describe_spec(&routes)),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
         }
+    }
+}
+
+// Loads the config the flags given on the command line are layered over, and starts logging the
+// way they say to
+fn load_config(cli: &BridgeArgs) -> Config {
+    let config = Config::load(cli.config.as_deref())
+        .unwrap_or_else(|e| panic!("couldn't load config {:?}: {:?}", cli.config, e));
+    let log_level = cli.log_level.as_ref().unwrap_or(&config.log_level);
+    let log_format = cli.log_format.unwrap_or_else(|| {
+        LogFormat::from_str(&config.log_format, true)
+            .unwrap_or_else(|e| panic!("invalid log format {:?}: {}", config.log_format, e))
+    });
+    init_logging(log_level, log_format);
+    config
+}
+
+fn run_recall_scene(cli: BridgeArgs, name: &str) {
+    let config = load_config(&cli);
+    let path = cli
+        .scenes_file
+        .or(config.scenes_file)
+        .expect("recall-scene needs a scenes file to recall from");
+    let scenes = SceneStore::open(&path)
+        .unwrap_or_else(|e| panic!("couldn't read scenes from {:?}: {:?}", path, e));
+    let socket = UdpSocket::bind("0.0.0.0:0").expect("couldn't bind OSC socket");
+    let transport: Arc<dyn OscTransport> = Arc::new(UdpTransport::new(socket));
+    let transport: Arc<dyn OscTransport> = match cli.address_prefix.or(config.address_prefix) {
+        Some(prefix) => Arc::new(PrefixedTransport::new(transport, &prefix)),
+        None => transport,
+    };
+    let transport: Arc<dyn OscTransport> = match cli.dry_run || config.dry_run {
+        true => Arc::new(DryRunTransport::new(transport)),
+        false => transport,
     };
+    let reaper_host = cli.reaper_host.unwrap_or(config.reaper_host);
+    let reaper_port = cli.reaper_port.unwrap_or(config.reaper_port);
+    let reaper = Reaper::with_destination(transport, resolve_reaper(&reaper_host, reaper_port));
+    recall_scene(&reaper, &scenes, name);
+}
 
-    let mut router = OscGatedRouterBuilder::new(dispatcher)
-        .add_layer({
-            let reaper = reaper.clone();
-            let a_send = a_send.clone();
-            Box::new(
-                ContextGateBuilder::<context_kind::Track>::new()
-                    .add_key_route("/track/{guid}/index")
-                    .with_initialization_callback(move |ctx, key_messages| {
-                        println!(
-                            "Initialized track context: {:?} with messages: {:?}",
-                            ctx, key_messages
-                        );
-                        reaper.with_mut(|reaper| {
-                            let track_guid = ctx.track_guid;
-                            // Track Index
-                            //
-                            // For now, we aren't doing anything with this
-                            reaper.track_index(track_guid.clone()).bind({
-                                let track_guid = track_guid.clone();
-                                let a_send = a_send.clone();
-                                move |index| {
-                                    a_send
-                                        .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                            guid: track_guid.clone(),
-                                            direction: Direction::Downstream,
-                                            data: DataPayload::ReaperTrackIndex(Some(index.index)),
-                                        }))
-                                        .unwrap();
-                                    println!(
-                                        "Track {} index initial value: {:?}",
-                                        track_guid.clone(),
-                                        index
-                                    )
-                                }
-                            });
-                            // Track Name
-                            reaper.track_name(track_guid.clone()).bind({
-                                let track_guid = track_guid.clone();
-                                let a_send = a_send.clone();
-                                move |name| {
-                                    a_send
-                                        .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                            guid: track_guid.clone(),
-                                            direction: Direction::Downstream,
-                                            data: DataPayload::Name(name.name.clone()),
-                                        }))
-                                        .unwrap();
-                                    println!(
-                                        "Track {} name initial value: {:?}",
-                                        track_guid.clone(),
-                                        name
-                                    )
-                                }
-                            });
-                            // Track Selected
-                            reaper.track_selected(track_guid.clone()).bind({
-                                let track_guid = track_guid.clone();
-                                let a_send = a_send.clone();
-                                move |selected| {
-                                    a_send
-                                        .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                            guid: track_guid.clone(),
-                                            direction: Direction::Downstream,
-                                            data: DataPayload::Selected(selected.selected),
-                                        }))
-                                        .unwrap();
-                                    println!(
-                                        "Track {} selected initial value: {:?}",
-                                        track_guid.clone(),
-                                        selected
-                                    )
-                                }
-                            });
-                            // Track Muted
-                            reaper.track_mute(track_guid.clone()).bind({
-                                let track_guid = track_guid.clone();
-                                let a_send = a_send.clone();
-                                move |muted| {
-                                    a_send
-                                        .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                            guid: track_guid.clone(),
-                                            direction: Direction::Downstream,
-                                            data: DataPayload::Muted(muted.mute),
-                                        }))
-                                        .unwrap();
-                                    println!(
-                                        "Track {} muted initial value: {:?}",
-                                        track_guid.clone(),
-                                        muted
-                                    )
-                                }
-                            });
-                            // Track Soloed
-                            reaper.track_solo(track_guid.clone()).bind({
-                                let track_guid = track_guid.clone();
-                                let a_send = a_send.clone();
-                                move |soloed| {
-                                    a_send
-                                        .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                            guid: track_guid.clone(),
-                                            direction: Direction::Downstream,
-                                            data: DataPayload::Soloed(soloed.solo),
-                                        }))
-                                        .unwrap();
-                                    println!(
-                                        "Track {} soloed initial value: {:?}",
-                                        track_guid.clone(),
-                                        soloed
-                                    )
-                                }
-                            });
-                            // Track Armed
-                            reaper.track_rec_arm(track_guid.clone()).bind({
-                                let track_guid = track_guid.clone();
-                                let a_send = a_send.clone();
-                                move |rec_arm| {
-                                    a_send
-                                        .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                            guid: track_guid.clone(),
-                                            direction: Direction::Downstream,
-                                            data: DataPayload::Armed(rec_arm.rec_arm),
-                                        }))
-                                        .unwrap();
-                                    println!(
-                                        "Track {} armed initial value: {:?}",
-                                        track_guid.clone(),
-                                        rec_arm
-                                    )
-                                }
-                            });
-                            // Track Volume
-                            reaper.track_volume(track_guid.clone()).bind({
-                                let track_guid = track_guid.clone();
-                                let a_send = a_send.clone();
-                                move |volume| {
-                                    a_send
-                                        .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                            guid: track_guid.clone(),
-                                            direction: Direction::Downstream,
-                                            data: DataPayload::Volume(volume.volume),
-                                        }))
-                                        .unwrap();
-                                    println!(
-                                        "Track {} volume initial value: {:?}",
-                                        track_guid.clone(),
-                                        volume
-                                    )
-                                }
-                            });
-                            // Track Pan
-                            reaper.track_pan(track_guid.clone()).bind({
-                                let track_guid = track_guid.clone();
-                                let a_send = a_send.clone();
-                                move |pan| {
-                                    a_send
-                                        .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                            guid: track_guid.clone(),
-                                            direction: Direction::Downstream,
-                                            data: DataPayload::Pan(pan.pan),
-                                        }))
-                                        .unwrap();
-                                    println!(
-                                        "Track {} pan initial value: {:?}",
-                                        track_guid.clone(),
-                                        pan
-                                    )
-                                }
-                            });
-                        });
-                    }),
-            )
-        })
-        .add_layer({
-            let reaper = reaper.clone();
-            let a_send = a_send.clone();
-            Box::new(
-                ContextGateBuilder::<context_kind::TrackSend>::new()
-                    .add_key_route("/track/{guid}/send/{send_index}/guid")
-                    .with_initialization_callback(move |ctx, key_messages| {
-                        let track_guid = ctx.track_guid.clone();
-                        let send_index = ctx.send_index;
-                        println!(
-                            "Initialized track send context: {:?} with messages: {:?}",
-                            ctx, key_messages
-                        );
-                        reaper.with_mut(|reaper| {
-                            // Track Send GUID
-                            reaper
-                                .track_send_guid(track_guid.clone(), send_index)
-                                .bind({
-                                    let track_guid = track_guid.clone();
-                                    let a_send = a_send.clone();
-                                    move |send_guid| {
-                                        a_send
-                                            .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                                guid: track_guid.clone(),
-                                                direction: Direction::Downstream,
-                                                data: DataPayload::SendIndex(SendIndex {
-                                                    guid: send_guid.guid.clone(),
-                                                    send_index,
-                                                }),
-                                            }))
-                                            .unwrap();
-                                        println!(
-                                            "Track {} send {} guid initial value: {:?}",
-                                            track_guid.clone(),
-                                            send_index,
-                                            send_guid
-                                        )
-                                    }
-                                });
-                            // Track Send Volume
-                            reaper
-                                .track_send_volume(track_guid.clone(), send_index)
-                                .bind({
-                                    let track_guid = track_guid.clone();
-                                    let a_send = a_send.clone();
-                                    move |send_volume| {
-                                        a_send
-                                            .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                                guid: track_guid.clone(),
-                                                direction: Direction::Downstream,
-                                                data: DataPayload::SendLevel(SendLevel {
-                                                    send_index,
-                                                    level: send_volume.volume,
-                                                }),
-                                            }))
-                                            .unwrap();
-                                        println!(
-                                            "Track {} send {} volume initial value: {:?}",
-                                            track_guid.clone(),
-                                            send_index,
-                                            send_volume
-                                        )
-                                    }
-                                });
-                            // Track Send Pan
-                            reaper.track_send_pan(track_guid.clone(), send_index).bind({
-                                let track_guid = track_guid.clone();
-                                let a_send = a_send.clone();
-                                move |send_pan| {
-                                    a_send
-                                        .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                            guid: track_guid.clone(),
-                                            direction: Direction::Downstream,
-                                            data: DataPayload::SendPan(SendPan {
-                                                send_index,
-                                                pan: send_pan.pan,
-                                            }),
-                                        }))
-                                        .unwrap();
-                                    println!(
-                                        "Track {} send {} pan initial value: {:?}",
-                                        track_guid.clone(),
-                                        send_index,
-                                        send_pan
-                                    )
-                                }
-                            });
-                        });
-                    }),
-            )
-        })
-        .add_layer({
-            let reaper = reaper.clone();
-            let a_send = a_send.clone();
-            Box::new(
-                ContextGateBuilder::<context_kind::TrackFx>::new()
-                    .add_key_route("/track/{guid}/fx/{fx_idx}/guid")
-                    .with_initialization_callback(move |ctx, key_messages| {
-                        let track_guid = ctx.track_guid.clone();
-                        let a_send = a_send.clone();
-                        println!(
-                            "Initialized track fxcontext: {:?} with messages: {:?}",
-                            ctx, key_messages
-                        );
-                        reaper.with_mut(|reaper| {
-                            // Track FX guid
-                            reaper.track_fx_guid(track_guid.clone(), ctx.fx_idx).bind({
-                                let track_guid = track_guid.clone();
-                                let a_send = a_send.clone();
-                                move |fx_guid| {
-                                    a_send
-                                        .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                            guid: track_guid.clone(),
-                                            direction: Direction::Downstream,
-                                            data: DataPayload::FXGuid(FXGuid {
-                                                fx_index: ctx.fx_idx,
-                                                guid: fx_guid.guid.clone(),
-                                            }),
-                                        }))
-                                        .unwrap();
-                                }
-                            });
-                            // Track FX Name
-                            reaper.track_fx_name(track_guid.clone(), ctx.fx_idx).bind({
-                                let track_guid = track_guid.clone();
-                                let a_send = a_send.clone();
-                                move |fx_name| {
-                                    a_send
-                                        .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                            guid: track_guid.clone(),
-                                            direction: Direction::Downstream,
-                                            data: DataPayload::FXName(FXName {
-                                                fx_index: ctx.fx_idx,
-                                                name: fx_name.name.clone(),
-                                            }),
-                                        }))
-                                        .unwrap();
-                                    println!(
-                                        "Track {} fx {} name initial value: {:?}",
-                                        track_guid.clone(),
-                                        ctx.fx_idx,
-                                        fx_name
-                                    )
-                                }
-                            });
-                            // Track FX Enabled
-                            reaper
-                                .track_fx_enabled(track_guid.clone(), ctx.fx_idx)
-                                .bind({
-                                    let track_guid = track_guid.clone();
-                                    let a_send = a_send.clone();
-                                    move |fx_enabled| {
-                                        a_send
-                                            .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                                guid: track_guid.clone(),
-                                                direction: Direction::Downstream,
-                                                data: DataPayload::FXEnabled(FXEnabled {
-                                                    fx_index: ctx.fx_idx,
-                                                    enabled: fx_enabled.enabled,
-                                                }),
-                                            }))
-                                            .unwrap();
-                                        println!(
-                                            "Track {} fx {} enabled initial value: {:?}",
-                                            track_guid.clone(),
-                                            ctx.fx_idx,
-                                            fx_enabled
-                                        )
-                                    }
-                                });
-                        })
-                    }),
+fn run_monitor(cli: BridgeArgs, filters: Vec<String>) {
+    let config = load_config(&cli);
+    let osc_address = cli.osc_address.unwrap_or(config.osc_address);
+    let socket = UdpSocket::bind(&osc_address)
+        .unwrap_or_else(|_| panic!("couldn't bind to address {:?}", osc_address));
+    info!("Monitoring OSC on {}", osc_address);
+    let filter = MonitorFilter::new(filters);
+    if let Err(e) = monitor::run(
+        &UdpTransport::new(socket),
+        &filter,
+        &mut std::io::stdout().lock(),
+    ) {
+        error!("Stopped monitoring OSC: {:?}", e);
+    }
+}
+
+// Runs the bridge, or replays a capture through it, until Reaper's connection fails
+fn run_bridge(cli: BridgeArgs, command: BridgeCommand) {
+    // Flags given on the command line win over the config file
+    let config = load_config(&cli);
+    let osc_address = cli.osc_address.unwrap_or(config.osc_address);
+    let reaper_host = cli.reaper_host.unwrap_or(config.reaper_host);
+    let reaper_port = cli.reaper_port.unwrap_or(config.reaper_port);
+    let address_prefix = cli.address_prefix.or(config.address_prefix);
+    let transport_kind = cli.transport.unwrap_or_else(|| {
+        Transport::from_str(&config.transport, true)
+            .unwrap_or_else(|e| panic!("invalid transport {:?}: {}", config.transport, e))
+    });
+    let headless = cli.headless.unwrap_or(config.headless);
+    let dry_run = cli.dry_run || config.dry_run;
+    let strict_routing = cli.strict_routing || config.strict_routing;
+    let spec = cli.spec.or(config.spec_file).map(|path| {
+        let spec = RouteSpec::load(&path)
+            .unwrap_or_else(|e| panic!("couldn't load spec {:?}:\n{}", path, e));
+        for address in spec.unsupported() {
+            warn!(
+                "{:?} has a route for {}, which isn't served until the client is generated from it",
+                path, address
+            );
+        }
+        spec
+    });
+    let gate_max_buffered = cli.gate_max_buffered.unwrap_or(config.gate_max_buffered);
+    let gate_overflow_policy = cli.gate_overflow_policy.unwrap_or_else(|| {
+        GateOverflow::from_str(&config.gate_overflow_policy, true).unwrap_or_else(|e| {
+            panic!(
+                "invalid gate overflow policy {:?}: {}",
+                config.gate_overflow_policy, e
             )
         })
-        .add_layer({
+    });
+    let follow_selection = cli.follow_selection || config.follow_selection;
+    let show_markers = cli.show_markers || config.show_markers;
+    let coalesce_window =
+        Duration::from_millis(cli.coalesce_window_ms.unwrap_or(config.coalesce_window_ms));
+    let echo_ttl = Duration::from_millis(cli.echo_ttl_ms.unwrap_or(config.echo_ttl_ms));
+    let metrics_interval = Duration::from_secs(
+        cli.metrics_interval_secs
+            .unwrap_or(config.metrics_interval_secs),
+    );
+    let watchdog_stall = Duration::from_millis(config.watchdog_stall_ms);
+    let api_address = cli.api_address.or(config.api_address);
+    let session = cli.session_file.or(config.session_file).map(|path| {
+        SessionStore::new(path, Duration::from_secs(config.session_save_interval_secs))
+    });
+    let restored_session = session.as_ref().and_then(|store| match store.load() {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            warn!(
+                "Couldn't restore session from {:?}, starting afresh: {:?}",
+                store.path(),
+                e
+            );
+            None
+        }
+    });
+    let scenes_file = cli.scenes_file.or(config.scenes_file);
+    let scenes = match &scenes_file {
+        Some(path) => SceneStore::open(path).unwrap_or_else(|e| {
+            warn!(
+                "Couldn't read scenes from {:?}, starting without any: {:?}",
+                path, e
+            );
+            SceneStore::in_memory()
+        }),
+        None => SceneStore::in_memory(),
+    };
+    let subscribers = match cli.subscribers.is_empty() {
+        true => config.subscribers,
+        false => cli.subscribers,
+    };
+    let subscribers = Arc::new(SubscriberRegistry::new(subscribers.iter().map(|addr| {
+        SocketAddr::from_str(addr)
+            .unwrap_or_else(|_| panic!("couldn't parse subscriber address {:?}", addr))
+    })));
+
+    // A replay stands in for Reaper, so nothing is sent anywhere
+    let transport: Arc<dyn OscTransport> = match command {
+        BridgeCommand::Replay { .. } => Arc::new(NullTransport),
+        BridgeCommand::Run => connect(
+            transport_kind,
+            &osc_address,
+            subscribers.clone(),
+            coalesce_window,
+            echo_ttl,
+            address_prefix.as_deref(),
+        ),
+    };
+    let transport: Arc<dyn OscTransport> = match dry_run {
+        true => Arc::new(DryRunTransport::new(transport)),
+        false => transport,
+    };
+
+    // The udp socket listens to everyone, so what's for Reaper has to say where it's going. Over
+    // tcp there's only Reaper on the other end.
+    let reaper_address = match transport_kind {
+        Transport::Udp => Some(resolve_reaper(&reaper_host, reaper_port)),
+        Transport::Tcp => None,
+    };
+    let reaper = match reaper_address {
+        Some(address) => Reaper::with_destination(transport.clone(), address),
+        None => Reaper::new(transport.clone()),
+    };
+    let reaper = Shared::new(reaper);
+
+    // Ports given on the command line replace those in the config
+    let surface_ports = match &cli.xtouch_port {
+        Some(port_name) => {
+            PortRouting::xtouch_with_extenders(port_name, &cli.xtouch_extender_ports).map(Some)
+        }
+        None if config.surface_ports.is_empty() => Ok(None),
+        None => PortRouting::new(config.surface_ports).map(Some),
+    }
+    .unwrap_or_else(|e| panic!("invalid surface ports: {:?}", e));
+    let layout = match &surface_ports {
+        Some(ports) => ports.layout(),
+        None => SurfaceLayout::xtouch_with_extenders(cli.xtouch_extender_ports.len()),
+    };
+    // A replay plays its capture's MIDI back into the surface rather than capturing any more
+    let recorder = match &command {
+        BridgeCommand::Run => cli.capture.map(|path| {
+            Recorder::create(&path)
+                .unwrap_or_else(|e| panic!("couldn't create capture {:?}: {}", path, e))
+        }),
+        BridgeCommand::Replay { .. } => None,
+    };
+    let midi_tap = MidiTap::new(
+        surface_ports
+            .as_ref()
+            .map_or(0, |ports| ports.routes().len()),
+        recorder.clone(),
+    );
+    // Whatever the modes drive. Headless, the modes run without one, and with no surface of any
+    // kind otherwise they aren't run at all.
+    let surface: Option<Box<dyn ControlSurface>> =
+        match (headless, dry_run, surface_ports, cli.simulate_xtouch) {
+            (true, _, _, _) => None,
+            // A dry run never touches the hardware, whatever surface was asked for
+            (false, true, _, _) => Some(Box::new(DryRunSurface::new(layout.clone()))),
+            (false, false, Some(ports), _) => Some(Box::new(TappedPorts {
+                routing: ports,
+                tap: midi_tap.clone(),
+            })),
+            (false, false, None, true) => Some(Box::new(TerminalSurface {
+                layout: layout.clone(),
+            })),
+            (false, false, None, false) => None,
+        };
+    let run_modes = headless || surface.is_some();
+    let capabilities = surface.as_ref().map(|surface| surface.capabilities());
+    let num_channels = match &capabilities {
+        Some(capabilities) => capabilities.channels,
+        None => layout.num_channels(),
+    };
+
+    let (a_send, a_rec) = bounded(128); // buffer size as needed
+    let (b, b_rec) = bounded(128); // buffer size as needed
+    let (c, c_rec) = bounded(128); // buffer size as needed
+    TrackManager::start_with_scenes(
+        a_rec.clone(),
+        b.clone(),
+        c.clone(),
+        num_channels,
+        INITIAL_SYNC_TIMEOUT,
+        scenes,
+    );
+
+    let (transport_send, transport_rec) = bounded(128);
+    let actions = config.action_buttons.clone();
+    if let Some(capabilities) = &capabilities {
+        for action in actions
+            .iter()
+            .filter(|action| !capabilities.action_buttons.contains(&action.button))
+        {
+            warn!(
+                "The surface has no {:?} button to run action {} from",
+                action.button, action.command_id
+            );
+        }
+    }
+    let (transport_upstream, transport_upstream_rec) = bounded(128);
+    let (undo_send, undo_rec) = bounded(16);
+    // One resync waiting to go out is enough; it asks for everything on the surface anyway
+    let (resync_send, resync_rec) = bounded(1);
+
+    let (project_send, project_rec) = bounded(128);
+    let (project_to_modes, from_project) = bounded(128);
+    ProjectStateManager::start(project_rec, project_to_modes.clone());
+
+    let metrics = Arc::new(Metrics::new());
+    // Reaper can send far more than TrackManager keeps up with, e.g. while loading a project
+    let track_send = BoundedSender::new("to_track_manager", a_send.clone(), config.backpressure)
+        .with_coalesce_key(TrackMsg::coalesce_key);
+    metrics.watch_bounded_sender("to_track_manager", &track_send);
+    metrics.watch_channel("to_modes", &c);
+    metrics.watch_channel("to_project_state", &project_send);
+    metrics.watch_channel("project_to_modes", &project_to_modes);
+    metrics.watch_channel("to_transport", &transport_send);
+    metrics.watch_channel("transport_to_reaper", &transport_upstream);
+
+    if run_modes {
+        let (to_surface, from_modes) = bounded(128);
+        // Headless, only the bridge's API gets through to the modes here
+        let (transport_to_modes, from_transport) = bounded(128);
+        metrics.watch_channel("to_surface", &to_surface);
+        match surface {
+            Some(surface) => {
+                let (to_transport, from_surface) = bounded(128);
+                metrics.watch_channel("from_surface", &to_transport);
+                surface.connect(from_modes, to_transport);
+                TransportManager::start_with_actions(
+                    transport_rec,
+                    transport_upstream,
+                    from_surface,
+                    to_surface.clone(),
+                    transport_to_modes.clone(),
+                    actions.clone(),
+                );
+            }
+            // With nothing there to show it on, what the modes send the surface goes nowhere
+            None => drop(from_modes),
+        }
+        let heartbeat = Heartbeat::new();
+        let status = ModeStatus::new();
+        let mode_options = ModeOptions {
+            num_channels,
+            mapping_policy: config.mapping_policy.clone(),
+            fader_taper: config.fader_taper,
+            wait_for_initial_sync: true,
+            follow_selection,
+            from_project: Some(from_project),
+            show_markers,
+            session,
+            restored_session: restored_session.clone(),
+            to_undo: Some(undo_send),
+            to_resync: Some(resync_send),
+            resync_interval: Duration::from_millis(config.resync_interval_ms),
+            barrier_timeout: match config.barrier_timeout_ms {
+                0 => None,
+                ms => Some(Duration::from_millis(ms)),
+            },
+            barrier_recovery: config.barrier_recovery,
+            heartbeat: Some(heartbeat.clone()),
+            status: Some(status.clone()),
+            // Nothing reflects barriers in a dry run either
+            headless: headless || dry_run,
+        };
+        ModeManager::start_with_options(
+            c_rec.clone(),
+            a_send.clone(),
+            from_transport.clone(),
+            to_surface.clone(),
+            mode_options.clone(),
+        );
+        if !watchdog_stall.is_zero() {
+            let modes = Consumer::new("modes", &heartbeat)
+                .with_channel("to_modes", &c)
+                .with_channel("transport_to_modes", &transport_to_modes)
+                .with_channel("project_to_modes", &project_to_modes);
+            let modes = match config.watchdog_restart {
+                true => modes.with_restart(move || {
+                    restart_modes(&c_rec, &a_send, &from_transport, &to_surface, &mode_options)
+                }),
+                false => modes,
+            };
+            let watchdog = Arc::new(Watchdog::new(watchdog_stall));
+            watchdog.watch(modes);
+            watchdog.start(watchdog_stall / 2);
+        }
+        if let Some(api_address) = &api_address {
+            let socket = UdpSocket::bind(api_address)
+                .unwrap_or_else(|_| panic!("couldn't bind to address {:?}", api_address));
+            info!("Serving the bridge's API on {}", api_address);
+            BridgeApi::new(ModeBridge::new(status, transport_to_modes.clone()))
+                .start(Arc::new(ApiTransport::new(socket)));
+        }
+    }
+
+    bind_project_routes(
+        &reaper,
+        &track_send,
+        &transport_send,
+        &project_send,
+        &actions,
+    );
+
+    // Switching project tabs changes every GUID, which the receive loop deals with once the
+    // message has been dispatched
+    let (project_tab_send, project_tab_rec) = bounded(16);
+    reaper.with(|reaper| {
+        reaper.active_project().bind(move |args| {
+            let _ = project_tab_send.try_send(ProjectGuid::from(args.project_guid));
+        });
+    });
+    forward_to_reaper(&reaper, transport_upstream_rec, undo_rec, resync_rec, b_rec);
+
+    // Each deleted track's context is reset once the message reporting it has been dispatched, so
+    // a track an undo brings back initializes afresh
+    let (removed_track_send, removed_track_rec) = bounded(128);
+    let mut router = gates::build_router(
+        &reaper,
+        &track_send,
+        &project_send,
+        &removed_track_send,
+        &GateOptions {
+            strict_routing,
+            spec,
+            purge_interval: Duration::from_secs(config.gate_purge_interval_secs),
+            max_buffered: gate_max_buffered,
+            overflow_policy: gate_overflow_policy.into(),
+        },
+    );
+
+    query_initial_state(&reaper, &track_send, restored_session.as_ref());
+
+    if let BridgeCommand::Replay { path, speed } = command {
+        replay(&mut router, &path, speed, &midi_tap);
+        return;
+    }
+
+    // Other controllers can send to the udp socket too, and they don't say whether Reaper is there
+    let supervisor = ConnectionSupervisor::new(REAPER_SILENCE_TIMEOUT);
+    let supervisor = Arc::new(match reaper_address {
+        Some(address) => supervisor.with_reaper_address(address),
+        None => supervisor,
+    });
+    supervisor.start_heartbeat(
+        HEARTBEAT_INTERVAL,
+        {
             let reaper = reaper.clone();
-            let a_send = a_send.clone();
-            Box::new(
-                ContextGateBuilder::<context_kind::TrackFxParam>::new()
-                    .add_key_route("/track/{guid}/fx/{fx_idx}/param/{param_idx}/name")
-                    .with_initialization_callback(move |ctx, key_messages| {
-                        let track_guid = ctx.track_guid.clone();
-                        let a_send = a_send.clone();
-                        println!(
-                            "Initialized track fx param context: {:?} with messages: {:?}",
-                            ctx, key_messages
-                        );
-                        reaper.with_mut(|reaper| {
-                            // Track FX Param Name
-                            reaper
-                                .track_fx_param_name(track_guid.clone(), ctx.fx_idx, ctx.param_idx)
-                                .bind({
-                                    let track_guid = track_guid.clone();
-                                    let a_send = a_send.clone();
-                                    move |fx_param_name| {
-                                        a_send
-                                            .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                                guid: track_guid.clone(),
-                                                direction: Direction::Downstream,
-                                                data: DataPayload::FXParamName(FXParamName {
-                                                    fx_index: ctx.fx_idx,
-                                                    param_index: ctx.param_idx,
-                                                    name: fx_param_name.param_name.clone(),
-                                                }),
-                                            }))
-                                            .unwrap();
-                                        println!(
-                                            "Track {} fx {} param {} name initial value: {:?}",
-                                            track_guid.clone(),
-                                            ctx.fx_idx,
-                                            ctx.param_idx,
-                                            fx_param_name
-                                        )
-                                    }
-                                });
-                            // Track FX Param Value
-                            reaper
-                                .track_fx_param_value(track_guid.clone(), ctx.fx_idx, ctx.param_idx)
-                                .bind({
-                                    let track_guid = track_guid.clone();
-                                    let a_send = a_send.clone();
-                                    move |fx_param_value| {
-                                        a_send
-                                            .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                                guid: track_guid.clone(),
-                                                direction: Direction::Downstream,
-                                                data: DataPayload::FXParamValue(FXParamValue {
-                                                    fx_index: ctx.fx_idx,
-                                                    param_index: ctx.param_idx,
-                                                    value: fx_param_value.value,
-                                                }),
-                                            }))
-                                            .unwrap();
-                                        println!(
-                                            "Track {} fx {} param {} value initial value: {:?}",
-                                            track_guid.clone(),
-                                            ctx.fx_idx,
-                                            ctx.param_idx,
-                                            fx_param_value
-                                        )
-                                    }
-                                });
-                            // Track FX Param Min
-                            reaper
-                                .track_fx_param_min(track_guid.clone(), ctx.fx_idx, ctx.param_idx)
-                                .bind({
-                                    let track_guid = track_guid.clone();
-                                    let a_send = a_send.clone();
-                                    move |fx_param_min| {
-                                        a_send
-                                            .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                                guid: track_guid.clone(),
-                                                direction: Direction::Downstream,
-                                                data: DataPayload::FXParamMin(FXParamMin {
-                                                    fx_index: ctx.fx_idx,
-                                                    param_index: ctx.param_idx,
-                                                    min: fx_param_min.min,
-                                                }),
-                                            }))
-                                            .unwrap();
-                                        println!(
-                                            "Track {} fx {} param {} min initial value: {:?}",
-                                            track_guid.clone(),
-                                            ctx.fx_idx,
-                                            ctx.param_idx,
-                                            fx_param_min
-                                        )
-                                    }
-                                });
-                            // Track FX Param Max
-                            reaper
-                                .track_fx_param_max(track_guid.clone(), ctx.fx_idx, ctx.param_idx)
-                                .bind({
-                                    let track_guid = track_guid.clone();
-                                    let a_send = a_send.clone();
-                                    move |fx_param_max| {
-                                        a_send
-                                            .try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                                                guid: track_guid.clone(),
-                                                direction: Direction::Downstream,
-                                                data: DataPayload::FXParamMax(FXParamMax {
-                                                    fx_index: ctx.fx_idx,
-                                                    param_index: ctx.param_idx,
-                                                    max: fx_param_max.max,
-                                                }),
-                                            }))
-                                            .unwrap();
-                                        println!(
-                                            "Track {} fx {} param {} max initial value: {:?}",
-                                            track_guid.clone(),
-                                            ctx.fx_idx,
-                                            ctx.param_idx,
-                                            fx_param_max
-                                        )
-                                    }
-                                });
-                        })
-                    }),
-            )
-        })
-        .build()
-        .unwrap();
+            move || {
+                if let Err(e) = reaper.with(|reaper| reaper.num_tracks().query()) {
+                    warn!("Failed to send heartbeat: {}", e);
+                }
+            }
+        },
+        || warn!("Lost contact with Reaper; waiting for it to come back"),
+    );
+
+    if !metrics_interval.is_zero() {
+        metrics.clone().start_reporting(metrics_interval);
+    }
 
-    println!("Listening on {}", cli.osc_address);
-    let mut buf = [0u8; rosc::decoder::MTU];
+    let mut tabs = ProjectTabs::new();
+    let packets = receive_packets(transport.clone());
+    info!("Listening on {}", osc_address);
     loop {
-        match socket.recv_from(&mut buf) {
-            Ok((size, addr)) => {
-                println!("Received packet with size {} from: {}", size, addr);
-                let (_, packet) = rosc::decoder::decode_udp(&buf[..size]).unwrap();
+        let received = match router.awaiting_acknowledgment() {
+            true => match packets.recv_timeout(ACK_POLL_INTERVAL) {
+                Ok(received) => received,
+                Err(RecvTimeoutError::Timeout) => {
+                    router.flush_acknowledged();
+                    continue;
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            },
+            false => match packets.recv() {
+                Ok(received) => received,
+                Err(_) => break,
+            },
+        };
+        match received {
+            Ok((buf, addr)) => {
+                let received = Instant::now();
+                let _span = debug_span!("osc_packet", from = %addr, size = buf.len()).entered();
+                trace!("Received packet");
+                metrics.record_packet();
+                let recorded = recorder.as_ref().map(|recorder| recorder.record_osc(&buf));
+                if let Some(Err(e)) = recorded {
+                    warn!("Failed to record OSC packet: {}", e);
+                }
+                let packet = match decode_packet(&buf) {
+                    Ok(packet) => packet,
+                    Err(e) => {
+                        warn!(
+                            "Dropping OSC packet from {}: {} ({})",
+                            addr,
+                            Unhandled::BadPacket,
+                            e
+                        );
+                        continue;
+                    }
+                };
+                let is_subscription = match &packet {
+                    OscPacket::Message(msg) => subscribers.handle_message(msg, addr),
+                    OscPacket::Bundle(_) => false,
+                };
+                if is_subscription {
+                    continue;
+                }
+                if supervisor.heard_from(addr, Instant::now()) == Some(ConnectionEvent::Restored) {
+                    // Reaper may have restarted with a different project, so everything we knew
+                    // about its tracks has to be learned again
+                    info!("Reaper is back; re-requesting project state");
+                    resync_project(&mut router, &reaper, &track_send, &actions, &transport_send);
+                }
+                let dispatch_start = Instant::now();
                 router.dispatch_osc(packet);
+                metrics.record_dispatch(dispatch_start.elapsed());
+                for track_guid in removed_track_rec.try_iter() {
+                    router.reset_context(&context::Track { track_guid });
+                }
+                // Every report is kept, even past a switch, so the last one seen is the active tab
+                let switches = project_tab_rec
+                    .try_iter()
+                    .filter(|guid| tabs.report(guid.clone()))
+                    .count();
+                if switches > 0 {
+                    resync_project(&mut router, &reaper, &track_send, &actions, &transport_send);
+                }
+                // Follows whatever the packet set off through to the surface, or only as far as
+                // the modes when headless. Without the modes, there's nowhere for it to end up.
+                if run_modes && let Some(probe) = metrics.probe(received) {
+                    track_send.send(TrackMsg::Probe(probe));
+                }
+                if metrics.gate_stats_wanted() {
+                    let gates = router.stats();
+                    metrics.set_gate_buffers(router.buffered_context_count(), gates.buffered);
+                    metrics.set_gate_contexts(
+                        gates.contexts,
+                        gates.initialized,
+                        gates.oldest_buffer_age,
+                    );
+                }
                 // handle_packet(packet);
             }
             Err(e) => {
-                println!("Error receiving from socket: {}", e);
+                error!("Error receiving from socket: {}", e);
+                break;
+            }
+        }
+    }
+}
+
+// Receives packets from the transport on a thread of its own, so the OSC loop can wake up in
+// between them. It stops after the first error, handing that over too.
+fn receive_packets(
+    transport: Arc<dyn OscTransport>,
+) -> Receiver<io::Result<(Vec<u8>, SocketAddr)>> {
+    let (send, packets) = bounded(1);
+    thread::spawn(move || {
+        loop {
+            let received = transport.recv();
+            let failed = received.is_err();
+            if send.send(received).is_err() || failed {
                 break;
             }
         }
+    });
+    packets
+}
+
+// Binds the routes of the project as a whole: the master track, the transport and what's shown
+// of the project besides its tracks
+fn bind_project_routes(
+    reaper: &Shared<Reaper>,
+    track_send: &BoundedSender<TrackMsg>,
+    transport_send: &Sender<TransportMsg>,
+    project_send: &Sender<ProjectMsg>,
+    actions: &[ActionBinding],
+) {
+    // The master track has its own routes rather than a track context, so it's bound up front
+    reaper.with(|reaper| {
+        reaper.master_volume().bind({
+            let track_send = track_send.clone();
+            move |volume| {
+                track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                    guid: TrackGuid::from(MASTER_GUID),
+                    direction: Direction::Downstream,
+                    data: DataPayload::Volume(volume.volume),
+                }));
+            }
+        });
+        reaper.master_pan().bind({
+            let track_send = track_send.clone();
+            move |pan| {
+                track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                    guid: TrackGuid::from(MASTER_GUID),
+                    direction: Direction::Downstream,
+                    data: DataPayload::Pan(pan.pan),
+                }));
+            }
+        });
+        reaper.master_mute().bind({
+            let track_send = track_send.clone();
+            move |mute| {
+                track_send.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                    guid: TrackGuid::from(MASTER_GUID),
+                    direction: Direction::Downstream,
+                    data: DataPayload::Muted(mute.mute),
+                }));
+            }
+        });
+    });
+
+    // Transport state from Reaper lights up the surface's transport section
+    reaper.with(|reaper| {
+        reaper.play().bind({
+            let transport_send = transport_send.clone();
+            move |play| {
+                let _ = transport_send.try_send(TransportMsg::Playing(play.playing));
+            }
+        });
+        reaper.record().bind({
+            let transport_send = transport_send.clone();
+            let project_send = project_send.clone();
+            move |record| {
+                let _ = transport_send.try_send(TransportMsg::Recording(record.recording));
+                let _ = project_send.try_send(ProjectMsg::Recording(record.recording));
+            }
+        });
+        reaper.repeat().bind({
+            let transport_send = transport_send.clone();
+            move |repeat| {
+                let _ = transport_send.try_send(TransportMsg::Repeat(repeat.repeat));
+            }
+        });
+        reaper.time().bind({
+            let transport_send = transport_send.clone();
+            let project_send = project_send.clone();
+            move |time| {
+                let _ = transport_send.try_send(TransportMsg::Position(time.time));
+                let _ = project_send.try_send(ProjectMsg::Position(time.time));
+            }
+        });
+    });
+    if let Err(e) = reaper.with(|reaper| bind_action_states(reaper, actions, transport_send)) {
+        warn!("Failed to request the state of the action buttons: {}", e);
+    }
+
+    // Tempo, markers and regions go to ProjectStateManager. Like tracks, each marker and region
+    // is bound once its name arrives.
+    reaper.with(|reaper| {
+        reaper.tempo().bind({
+            let project_send = project_send.clone();
+            move |tempo| {
+                let _ = project_send.try_send(ProjectMsg::Tempo(tempo.bpm));
+            }
+        });
+    });
+}
+
+// Sends on to Reaper what the transport section, the undo buttons, the resync button and the
+// modes change from the surface
+fn forward_to_reaper(
+    reaper: &Shared<Reaper>,
+    from_transport: Receiver<TransportMsg>,
+    from_undo: Receiver<UndoMsg>,
+    from_resync: Receiver<ResyncRequest>,
+    from_modes: Receiver<TrackMsg>,
+) {
+    // The transport section drives Reaper's transport
+    thread::spawn({
+        let reaper = reaper.clone();
+        move || {
+            for msg in from_transport {
+                let sent = reaper.with(|reaper| match msg {
+                    TransportMsg::Playing(playing) => reaper.play().set_value(playing),
+                    TransportMsg::Stop => reaper.stop().set(StopArgs {}),
+                    TransportMsg::Recording(recording) => reaper.record().set_value(recording),
+                    TransportMsg::Repeat(repeat) => reaper.repeat().set_value(repeat),
+                    TransportMsg::Rewind(held) => reaper.rewind().set_value(held),
+                    TransportMsg::FastForward(held) => reaper.forward().set_value(held),
+                    TransportMsg::Position(time) => reaper.time().set_value(time),
+                    TransportMsg::Action(id) => reaper.run_action(id).set(RunActionArgs {}),
+                    // Only ever comes from Reaper
+                    TransportMsg::ActionToggled(..) => Ok(()),
+                });
+                if let Err(e) = sent {
+                    warn!("Failed to send transport message to Reaper: {}", e);
+                }
+            }
+        }
+    });
+    // Undo and redo from the surface run Reaper's own actions for them
+    thread::spawn({
+        let reaper = reaper.clone();
+        move || {
+            for msg in from_undo {
+                let sent = reaper.with(|reaper| reaper.action().set_value(msg.action()));
+                if let Err(e) = sent {
+                    warn!("Failed to send {:?} to Reaper: {}", msg, e);
+                }
+            }
+        }
+    });
+
+    // The resync button asks Reaper for everything on the surface again
+    thread::spawn({
+        let reaper = reaper.clone();
+        move || {
+            for request in from_resync {
+                let queried = reaper.with(|reaper| {
+                    reaper.master_volume().query()?;
+                    reaper.master_pan().query()?;
+                    reaper.master_mute().query()?;
+                    reaper.play().query()?;
+                    reaper.record().query()?;
+                    reaper.repeat().query()
+                });
+                if let Err(e) = queried {
+                    warn!("Failed to query master track and transport state: {}", e);
+                }
+                for guid in request.tracks.iter().filter(|guid| **guid != MASTER_GUID) {
+                    if let Err(e) = reaper.with(|reaper| query_track(reaper, guid)) {
+                        warn!("Failed to query track {}: {}", guid, e);
+                    }
+                }
+            }
+        }
+    });
+
+    // What the modes change from the surface goes on to Reaper
+    thread::spawn({
+        let reaper = reaper.clone();
+        move || {
+            for msg in from_modes {
+                let msg = match msg {
+                    TrackMsg::TrackDataMsg(msg) => msg,
+                    TrackMsg::DiscoverSends(discovery) => {
+                        if let Err(e) = reaper.with(|reaper| discover_sends(reaper, &discovery)) {
+                            warn!("Failed to ask after track {} sends: {}", discovery.guid, e);
+                        }
+                        continue;
+                    }
+                    _ => continue,
+                };
+                let guid = msg.guid.clone();
+                if let Err(e) = reaper.with(|reaper| send_track_data(reaper, msg)) {
+                    warn!("Failed to send track {} change to Reaper: {}", guid, e);
+                }
+            }
+        }
+    });
+}
+
+// Asks Reaper for everything shown from the start, including the tracks of a restored session
+fn query_initial_state(
+    reaper: &Shared<Reaper>,
+    track_send: &BoundedSender<TrackMsg>,
+    restored_session: Option<&SessionSnapshot>,
+) {
+    // TrackManager needs the project's track count to tell when the initial sync is done
+    match reaper.with(|reaper| reaper.num_tracks().query_response()) {
+        Ok(num_tracks) => {
+            let track_send = track_send.clone();
+            thread::spawn(move || {
+                if let Ok(num_tracks) = num_tracks.recv_timeout(INITIAL_SYNC_TIMEOUT) {
+                    track_send.send(TrackMsg::NumTracks(num_tracks.num_tracks));
+                }
+            });
+        }
+        Err(e) => warn!("Failed to query track count: {}", e),
+    }
+
+    // Bring the master fader and transport section in line with Reaper from the start
+    let queried = reaper.with(|reaper| {
+        reaper.master_volume().query()?;
+        reaper.master_pan().query()?;
+        reaper.master_mute().query()?;
+        reaper.play().query()?;
+        reaper.record().query()?;
+        reaper.repeat().query()
+    });
+    if let Err(e) = queried {
+        warn!("Failed to query master track and transport state: {}", e);
+    }
+    let queried = reaper.with(|reaper| {
+        reaper.active_project().query()?;
+        reaper.tempo().query()?;
+        reaper.marker_all().query()?;
+        reaper.region_all().query()
+    });
+    if let Err(e) = queried {
+        warn!(
+            "Failed to query the active project, tempo, markers and regions: {}",
+            e
+        );
+    }
+
+    // The restored session is only a guess at where things were; Reaper has the final say on
+    // every track it mentions, and tracks it no longer knows never reappear
+    if let Some(session) = restored_session {
+        let guids: BTreeSet<&TrackGuid> = session
+            .vol_pan
+            .tracks
+            .keys()
+            .chain(session.vol_pan.pinned.values())
+            .chain(session.sends.track_guid.iter())
+            .filter(|guid| **guid != MASTER_GUID)
+            .collect();
+        for guid in guids {
+            if let Err(e) = reaper.with(|reaper| query_track(reaper, guid)) {
+                warn!("Failed to query restored track {}: {}", guid, e);
+            }
+        }
+    }
+}
+
+// Feeds a capture through the router in place of Reaper, and its MIDI into the surface as though
+// each unit had sent it
+fn replay(router: &mut OscGatedRouter, path: &Path, speed: f64, midi: &MidiTap) {
+    let events = File::open(path)
+        .and_then(|file| capture::read_capture(BufReader::new(file)))
+        .unwrap_or_else(|e| panic!("couldn't read capture {:?}: {}", path, e));
+    info!("Replaying {} events from {:?}", events.len(), path);
+    let mut skipped_midi = 0;
+    capture::replay(&events, speed, |event| match &event.data {
+        Captured::Osc(packet) => {
+            let _span = debug_span!("osc_packet", at = ?event.at, size = packet.len()).entered();
+            match decode_packet(packet) {
+                Ok(packet) => router.dispatch_osc(packet),
+                Err(e) => warn!("Skipping OSC packet: {} ({})", Unhandled::BadPacket, e),
+            }
+        }
+        Captured::Midi { unit, message } => {
+            if !midi.replay(*unit, message) {
+                debug!(
+                    "Skipping MIDI message at {:?}: unit {} isn't connected",
+                    event.at, unit
+                );
+                skipped_midi += 1;
+            }
+        }
+    });
+    // Whatever was still waiting on TrackManager when the capture ran out
+    while router.awaiting_acknowledgment() {
+        thread::sleep(ACK_POLL_INTERVAL);
+        router.flush_acknowledged();
+    }
+    if skipped_midi > 0 {
+        warn!(
+            "Skipped {} MIDI messages for units of the surface that weren't connected",
+            skipped_midi
+        );
+    }
+}
+
+// Forgets everything learned about the project's tracks and asks Reaper about it all again, for
+// when they may no longer be the ones we knew: Reaper has restarted, or switched project tabs
+fn resync_project(
+    router: &mut OscGatedRouter,
+    reaper: &Shared<Reaper>,
+    track_send: &BoundedSender<TrackMsg>,
+    actions: &[ActionBinding],
+    transport_send: &crossbeam_channel::Sender<TransportMsg>,
+) {
+    // The contexts bind their handlers afresh as they initialize again
+    reaper.with(|reaper| reaper.unbind_contexts());
+    router.reset_all();
+    track_send.send(TrackMsg::Reset);
+    let requested = reaper.with(|reaper| {
+        reaper.active_project().query()?;
+        reaper.num_tracks().query()?;
+        reaper.master_volume().query()?;
+        reaper.master_pan().query()?;
+        reaper.master_mute().query()?;
+        reaper.play().query()?;
+        reaper.record().query()?;
+        reaper.repeat().query()?;
+        reaper.tempo().query()?;
+        reaper.marker_all().query()?;
+        reaper.region_all().query()?;
+        bind_action_states(reaper, actions, transport_send)?;
+        reaper.track_all_guids().query()
+    });
+    if let Err(e) = requested {
+        warn!("Failed to re-request project state: {}", e);
+    }
+}
+
+// Follows the toggle state of each action whose button lights up with it, and asks Reaper where
+// they stand. The state routes are keyed by command ID, so these are unbound along with the
+// contexts and have to be bound again after.
+fn bind_action_states(
+    reaper: &Reaper,
+    actions: &[ActionBinding],
+    transport_send: &crossbeam_channel::Sender<TransportMsg>,
+) -> Result<(), OscError> {
+    let command_ids: BTreeSet<i32> = actions
+        .iter()
+        .filter(|binding| binding.toggle)
+        .map(|binding| binding.command_id)
+        .collect();
+    for command_id in command_ids {
+        reaper.action_state(command_id).bind({
+            let transport_send = transport_send.clone();
+            move |state| {
+                let _ = transport_send.try_send(TransportMsg::ActionToggled(command_id, state.on));
+            }
+        });
+        reaper.action_state(command_id).query()?;
+    }
+    Ok(())
+}
+
+// The simulated X-Touch, acted on by lines typed on stdin, e.g. "fader 0 0.5", and redrawn on
+// stdout whenever the modes change it
+struct TerminalSurface {
+    layout: SurfaceLayout,
+}
+
+impl ControlSurface for TerminalSurface {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::xtouch(&self.layout)
+    }
+
+    fn connect(
+        self: Box<Self>,
+        input: crossbeam_channel::Receiver<XTouchDownstreamMsg>,
+        upstream: crossbeam_channel::Sender<XTouchUpstreamMsg>,
+    ) {
+        let xtouch = SimulatedXTouch::start(self.layout.num_channels(), input, upstream);
+        thread::spawn({
+            let xtouch = xtouch.clone();
+            move || {
+                for line in std::io::stdin().lines().map_while(Result::ok) {
+                    if let Err(e) = xtouch.run_command(&line) {
+                        warn!("Couldn't act out {:?} on the surface: {:?}", line, e);
+                    }
+                }
+            }
+        });
+        thread::spawn(move || {
+            let mut shown = xtouch.state();
+            print!("{}", shown);
+            loop {
+                if let Some(state) =
+                    xtouch.wait_until(Duration::from_secs(60), |state| *state != shown)
+                {
+                    print!("{}", state);
+                    shown = state;
+                }
+            }
+        });
+    }
+}
+
+// Starts the modes over in place of ones the watchdog found stuck. They start out knowing nothing,
+// so TrackManager is asked to tell them about every track again. The stuck ones stop as soon as
+// they get going again, since the watchdog superseded their heartbeat before calling this.
+fn restart_modes(
+    from_reaper: &crossbeam_channel::Receiver<TrackMsg>,
+    to_reaper: &crossbeam_channel::Sender<TrackMsg>,
+    from_surface: &crossbeam_channel::Receiver<XTouchUpstreamMsg>,
+    to_surface: &crossbeam_channel::Sender<XTouchDownstreamMsg>,
+    options: &ModeOptions,
+) {
+    warn!("Starting the modes over");
+    ModeManager::start_with_options(
+        from_reaper.clone(),
+        to_reaper.clone(),
+        from_surface.clone(),
+        to_surface.clone(),
+        ModeOptions {
+            wait_for_initial_sync: false,
+            restored_session: None,
+            ..options.clone()
+        },
+    );
+    if to_reaper
+        .try_send(TrackMsg::Replay(Barrier::new()))
+        .is_err()
+    {
+        warn!("Couldn't ask for the tracks to be replayed to the restarted modes");
+    }
+}
+
+// Asks Reaper for everything a channel strip shows of a track
+fn query_track(reaper: &Reaper, guid: &TrackGuid) -> Result<(), OscError> {
+    reaper.track_index(guid.clone()).query()?;
+    reaper.track_name(guid.clone()).query()?;
+    reaper.track_selected(guid.clone()).query()?;
+    reaper.track_volume(guid.clone()).query()?;
+    reaper.track_pan(guid.clone()).query()?;
+    reaper.track_mute(guid.clone()).query()?;
+    reaper.track_solo(guid.clone()).query()?;
+    reaper.track_rec_arm(guid.clone()).query()?;
+    reaper.track_color(guid.clone()).query()
+}
+
+// Asks Reaper how many sends a track has and where each of them goes. Sends it doesn't have go
+// unanswered.
+fn discover_sends(reaper: &Reaper, discovery: &SendDiscovery) -> Result<(), OscError> {
+    reaper.track_send_count(discovery.guid.clone()).query()?;
+    for send_index in 0..discovery.num_sends as i32 {
+        reaper
+            .track_send_guid(discovery.guid.clone(), send_index)
+            .query()?;
+    }
+    Ok(())
+}
+
+// Where Reaper listens for OSC over udp
+fn resolve_reaper(host: &str, port: u16) -> SocketAddr {
+    (host, port)
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addrs| addrs.next())
+        .unwrap_or_else(|| panic!("couldn't resolve Reaper at {}:{}", host, port))
+}
+
+// Sets every track in the scene called `name` back to how it was captured. Unlike a recall from
+// the surface, there's no telling which tracks are still around, so they're all sent.
+fn recall_scene(reaper: &Reaper, scenes: &SceneStore, name: &str) {
+    let scene = scenes.get(name).unwrap_or_else(|| {
+        panic!(
+            "no scene called {:?} in {:?}; there's {:?}",
+            name,
+            scenes.path(),
+            scenes.names().collect::<Vec<_>>()
+        )
+    });
+    // Each track's mix goes in a bundle of its own, so it changes all at once
+    for (guid, track) in &scene.tracks {
+        let mut bundle = reaper.bundle();
+        for data in track.recall_payloads(guid) {
+            let msg = TrackDataMsg {
+                guid: guid.clone(),
+                direction: Direction::Upstream,
+                data,
+            };
+            stage_track_data(&mut bundle, reaper, msg);
+        }
+        let results = match bundle.commit() {
+            Ok(results) => results,
+            Err(e) => vec![Err(e)],
+        };
+        for e in results.into_iter().filter_map(Result::err) {
+            warn!("Failed to recall track {} in Reaper: {}", guid, e);
+        }
+    }
+    info!("Recalled scene {:?} on {} tracks", name, scene.tracks.len());
+}
+
+// Sets whatever a mode changed on a track in Reaper
+fn send_track_data(reaper: &Reaper, msg: TrackDataMsg) -> Result<(), OscError> {
+    let mut bundle = reaper.bundle();
+    stage_track_data(&mut bundle, reaper, msg);
+    // There's at most the one message, which goes out on its own
+    bundle.commit()?.into_iter().collect()
+}
+
+// Adds setting whatever a mode changed on a track to a bundle. The master track has routes of its
+// own.
+fn stage_track_data(bundle: &mut BundleBuilder, reaper: &Reaper, msg: TrackDataMsg) {
+    let guid = msg.guid;
+    match (msg.data, guid == MASTER_GUID) {
+        (DataPayload::Volume(volume), true) => {
+            bundle.set(&reaper.master_volume(), MasterVolumeArgs::new(volume));
+        }
+        (DataPayload::Pan(pan), true) => {
+            bundle.set(&reaper.master_pan(), MasterPanArgs::new(pan));
+        }
+        (DataPayload::Muted(muted), true) => {
+            bundle.set(&reaper.master_mute(), MasterMuteArgs::new(muted));
+        }
+        (DataPayload::Volume(volume), false) => {
+            bundle.set(&reaper.track_volume(guid), TrackVolumeArgs::new(volume));
+        }
+        (DataPayload::Pan(pan), false) => {
+            bundle.set(&reaper.track_pan(guid), TrackPanArgs::new(pan));
+        }
+        (DataPayload::Muted(muted), false) => {
+            bundle.set(&reaper.track_mute(guid), TrackMuteArgs::new(muted));
+        }
+        (DataPayload::Width(width), false) => {
+            bundle.set(&reaper.track_width(guid), TrackWidthArgs::new(width));
+        }
+        (DataPayload::Phase(phase), false) => {
+            bundle.set(&reaper.track_phase(guid), TrackPhaseArgs::new(phase));
+        }
+        (DataPayload::InputGain(input_gain), false) => {
+            bundle.set(
+                &reaper.track_input_gain(guid),
+                TrackInputGainArgs::new(input_gain),
+            );
+        }
+        (DataPayload::Soloed(soloed), _) => {
+            bundle.set(&reaper.track_solo(guid), TrackSoloArgs::new(soloed));
+        }
+        (DataPayload::Armed(armed), _) => {
+            bundle.set(&reaper.track_rec_arm(guid), TrackRecArmArgs::new(armed));
+        }
+        (DataPayload::SendLevel(send), _) => {
+            bundle.set(
+                &reaper.track_send_volume(guid, send.send_index),
+                TrackSendVolumeArgs::new(send.level),
+            );
+        }
+        (DataPayload::SendPan(send), _) => {
+            bundle.set(
+                &reaper.track_send_pan(guid, send.send_index),
+                TrackSendPanArgs::new(send.pan),
+            );
+        }
+        (DataPayload::SendMute(send), _) => {
+            bundle.set(
+                &reaper.track_send_mute(guid, send.send_index),
+                TrackSendMuteArgs::new(send.muted),
+            );
+        }
+        (DataPayload::FXBypass(fx), _) => {
+            bundle.set(
+                &reaper.track_fx_bypass(guid, fx.fx_index),
+                TrackFxBypassArgs::new(fx.bypass),
+            );
+        }
+        (DataPayload::FXWet(fx), _) => {
+            bundle.set(
+                &reaper.track_fx_wet(guid, fx.fx_index),
+                TrackFxWetArgs::new(fx.wet),
+            );
+        }
+        (DataPayload::FXParamValue(param), _) => {
+            bundle.set(
+                &reaper.track_fx_param_value(guid, param.fx_index, param.param_index),
+                TrackFxParamValueArgs::new(param.value),
+            );
+        }
+        // Nothing else is changed from the surface
+        (data, _) => {
+            trace!("Not sending {:?} for track {} to Reaper", data, guid);
+        }
+    }
+}
+
+// Shows log messages allowed by `log_level` on stdout, as text or one JSON object per line
+fn init_logging(log_level: &str, log_format: LogFormat) {
+    let filter = EnvFilter::try_new(log_level)
+        .unwrap_or_else(|e| panic!("invalid log level {:?}: {}", log_level, e));
+    let logger = tracing_subscriber::fmt().with_env_filter(filter);
+    match log_format {
+        LogFormat::Text => logger.init(),
+        LogFormat::Json => logger.json().init(),
     }
 }
+
+// Listens for Reaper on `osc_address`, fanning everything we send out to the subscribers too
+fn connect(
+    transport_kind: Transport,
+    osc_address: &str,
+    subscribers: Arc<SubscriberRegistry>,
+    coalesce_window: Duration,
+    echo_ttl: Duration,
+    address_prefix: Option<&str>,
+) -> Arc<dyn OscTransport> {
+    let socket_addr = SocketAddrV4::from_str(osc_address)
+        .unwrap_or_else(|_| panic!("couldn't parse address {:?}", osc_address));
+    // Feedback to subscribers goes out over UDP whichever transport Reaper uses
+    let (reaper_transport, feedback_socket): (Arc<dyn OscTransport>, UdpSocket) =
+        match transport_kind {
+            Transport::Udp => {
+                let socket = UdpSocket::bind(socket_addr)
+                    .unwrap_or_else(|_| panic!("couldn't bind to address {:?}", osc_address));
+                let feedback_socket = socket.try_clone().expect("couldn't clone OSC socket");
+                (Arc::new(UdpTransport::new(socket)), feedback_socket)
+            }
+            Transport::Tcp => {
+                let listener = TcpListener::bind(socket_addr)
+                    .unwrap_or_else(|_| panic!("couldn't bind to address {:?}", osc_address));
+                info!("Waiting for OSC connection on {}", osc_address);
+                let (stream, addr) = listener.accept().expect("couldn't accept OSC connection");
+                info!("Accepted OSC connection from {}", addr);
+                let feedback_socket =
+                    UdpSocket::bind("0.0.0.0:0").expect("couldn't bind OSC feedback socket");
+                (
+                    Arc::new(
+                        TcpSlipTransport::new(stream).expect("couldn't set up OSC connection"),
+                    ),
+                    feedback_socket,
+                )
+            }
+        };
+    let fan_out: Arc<dyn OscTransport> = Arc::new(FanOutTransport::new(
+        reaper_transport,
+        feedback_socket,
+        subscribers,
+    ));
+    // Subscribers stand in for Reaper, so they're under the prefix too
+    let prefixed: Arc<dyn OscTransport> = match address_prefix {
+        Some(prefix) => Arc::new(PrefixedTransport::new(fan_out, prefix)),
+        None => fan_out,
+    };
+    // Under the coalescing, so it also sees the messages that were held back when they go out
+    let echo_suppressed: Arc<dyn OscTransport> = match echo_ttl.is_zero() {
+        true => prefixed,
+        false => Arc::new(EchoSuppressingTransport::new(prefixed, echo_ttl)),
+    };
+    Arc::new(CoalescingTransport::new(
+        echo_suppressed,
+        coalesce_window,
+        DISCRETE_ROUTES
+            .iter()
+            .map(|route| route.to_string())
+            .collect(),
+    ))
+}
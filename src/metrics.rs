@@ -0,0 +1,354 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::Sender;
+use tracing::info;
+
+use crate::channel::BoundedSender;
+
+/// The least time between two latency probes, which keeps them from adding to the traffic they
+/// time when Reaper sends a lot
+pub const PROBE_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Most latencies kept between two reports. Any more still count towards the max.
+pub const MAX_LATENCY_SAMPLES: usize = 10_000;
+
+/// Counts the traffic flowing through the bridge and where it's piling up.
+///
+/// The OSC receive loop counts packets, times how long each one takes to dispatch, and records what
+/// the context gates know of and are holding back whenever [`Metrics::gate_stats_wanted`] asks it
+/// to, which is once after every report. Channels registered with `watch_channel` have
+/// their occupancy read each time a report is taken. Everything is atomic, so one `Metrics` can be
+/// shared between the receive loop and whatever reports on it.
+///
+/// The time from a packet arriving to the surface showing it is timed with [`LatencyProbe`]s.
+#[derive(Default)]
+pub struct Metrics {
+    packets: AtomicU64,
+    dispatches: AtomicU64,
+    dispatch_nanos: AtomicU64,
+    max_dispatch_nanos: AtomicU64,
+    buffered_contexts: AtomicUsize,
+    buffered_messages: AtomicUsize,
+    gate_contexts: AtomicUsize,
+    initialized_contexts: AtomicUsize,
+    oldest_buffered: Mutex<Option<Duration>>,
+    // Set when the next report wants the gates' stats brought up to date
+    gate_stats_wanted: AtomicBool,
+    channels: Mutex<Vec<WatchedChannel>>,
+    // In the order the probes came back
+    latencies: Mutex<Vec<Duration>>,
+    max_latency_nanos: AtomicU64,
+    last_probe: Mutex<Option<Instant>>,
+}
+
+/// Rides along behind the messages a packet from Reaper turns into, through the same channels,
+/// to time how long they take to reach the surface.
+///
+/// Every stage on the way handles messages in the order they come, so by the time the surface
+/// gets to a probe it has sent the MIDI for everything ahead of it. The surface then calls
+/// [`LatencyProbe::arrived`].
+#[derive(Clone)]
+pub struct LatencyProbe {
+    received: Instant,
+    metrics: Arc<Metrics>,
+}
+
+impl LatencyProbe {
+    /// Records the time since the packet the probe follows was received.
+    pub fn arrived(&self) {
+        self.metrics.record_latency(self.received.elapsed());
+    }
+}
+
+// Copies of a probe follow the same packet, so they're the same probe
+impl PartialEq for LatencyProbe {
+    fn eq(&self, other: &Self) -> bool {
+        self.received == other.received && Arc::ptr_eq(&self.metrics, &other.metrics)
+    }
+}
+
+// The metrics would only clutter logs of the messages carrying probes
+impl fmt::Debug for LatencyProbe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LatencyProbe")
+            .field("received", &self.received)
+            .finish()
+    }
+}
+
+struct WatchedChannel {
+    name: String,
+    capacity: Option<usize>,
+    len: Box<dyn Fn() -> usize + Send>,
+    dropped: Box<dyn Fn() -> u64 + Send>,
+}
+
+/// How full one watched channel was when a report was taken
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChannelOccupancy {
+    pub name: String,
+    pub len: usize,
+    /// None for unbounded channels
+    pub capacity: Option<usize>,
+    /// How many messages have been dropped on the way in since the start, for channels fed by a
+    /// BoundedSender
+    pub dropped: u64,
+}
+
+/// What happened since the previous report
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricsReport {
+    pub packets_per_sec: f64,
+    /// Zero if nothing was dispatched
+    pub mean_dispatch: Duration,
+    pub max_dispatch: Duration,
+    /// How many contexts were waiting on their key messages when the report was taken
+    pub buffered_contexts: usize,
+    /// How many messages those contexts were holding back between them
+    pub buffered_messages: usize,
+    /// How many contexts the gates knew of, and how many of those were initialized
+    pub gate_contexts: usize,
+    pub initialized_contexts: usize,
+    /// How long the longest waiting buffered message had waited, if there were any
+    pub oldest_buffered: Option<Duration>,
+    pub channels: Vec<ChannelOccupancy>,
+    pub latency: LatencySummary,
+}
+
+/// How long packets took to reach the surface, from the probes that came back since the previous
+/// report. All zero if none did.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LatencySummary {
+    pub samples: usize,
+    pub p50: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    /// The mean difference between one latency and the next
+    pub jitter: Duration,
+}
+
+impl LatencySummary {
+    /// Summarises `latencies`, given in the order they were measured. `max` is passed separately
+    /// as it also covers latencies that didn't fit in with the rest.
+    pub fn of(latencies: &[Duration], max: Duration) -> Self {
+        if latencies.is_empty() {
+            return LatencySummary {
+                max,
+                ..Default::default()
+            };
+        }
+        let jitter = match latencies.len() {
+            1 => Duration::ZERO,
+            n => {
+                let total: Duration = latencies
+                    .windows(2)
+                    .map(|pair| pair[0].abs_diff(pair[1]))
+                    .sum();
+                total / (n - 1) as u32
+            }
+        };
+        let mut sorted = latencies.to_vec();
+        sorted.sort();
+        // The nearest rank, so every percentile is one of the latencies measured
+        let percentile = |p: usize| sorted[(sorted.len() * p).div_ceil(100).max(1) - 1];
+        LatencySummary {
+            samples: sorted.len(),
+            p50: percentile(50),
+            p95: percentile(95),
+            max,
+            jitter,
+        }
+    }
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts one packet received from Reaper.
+    pub fn record_packet(&self) {
+        self.packets.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records how long one packet took to get through the router.
+    pub fn record_dispatch(&self, elapsed: Duration) {
+        let nanos = elapsed.as_nanos() as u64;
+        self.dispatches.fetch_add(1, Ordering::Relaxed);
+        self.dispatch_nanos.fetch_add(nanos, Ordering::Relaxed);
+        self.max_dispatch_nanos.fetch_max(nanos, Ordering::Relaxed);
+    }
+
+    /// A probe to send after the messages of a packet received at `received`, unless one was sent
+    /// less than [`PROBE_INTERVAL`] before.
+    pub fn probe(self: &Arc<Self>, received: Instant) -> Option<LatencyProbe> {
+        let mut last_probe = self.last_probe.lock().unwrap();
+        if last_probe.is_some_and(|last| received.duration_since(last) < PROBE_INTERVAL) {
+            return None;
+        }
+        *last_probe = Some(received);
+        Some(LatencyProbe {
+            received,
+            metrics: self.clone(),
+        })
+    }
+
+    /// Records how long one packet took to reach the surface.
+    pub fn record_latency(&self, latency: Duration) {
+        self.max_latency_nanos
+            .fetch_max(latency.as_nanos() as u64, Ordering::Relaxed);
+        let mut latencies = self.latencies.lock().unwrap();
+        if latencies.len() < MAX_LATENCY_SAMPLES {
+            latencies.push(latency);
+        }
+    }
+
+    /// Whether the receive loop should record the gates' stats, which are costly to gather and
+    /// only needed once per report. True once after each report, and once reporting starts.
+    pub fn gate_stats_wanted(&self) -> bool {
+        self.gate_stats_wanted.swap(false, Ordering::Relaxed)
+    }
+
+    /// Records what the context gates are currently holding back.
+    pub fn set_gate_buffers(&self, contexts: usize, messages: usize) {
+        self.buffered_contexts.store(contexts, Ordering::Relaxed);
+        self.buffered_messages.store(messages, Ordering::Relaxed);
+    }
+
+    /// Records how many contexts the gates know of, how many of them are initialized, and how
+    /// long the longest waiting buffered message has waited.
+    pub fn set_gate_contexts(
+        &self,
+        contexts: usize,
+        initialized: usize,
+        oldest_buffered: Option<Duration>,
+    ) {
+        self.gate_contexts.store(contexts, Ordering::Relaxed);
+        self.initialized_contexts
+            .store(initialized, Ordering::Relaxed);
+        *self.oldest_buffered.lock().unwrap() = oldest_buffered;
+    }
+
+    /// Includes the occupancy of the channel `sender` feeds in every report, under `name`.
+    ///
+    /// This holds on to a clone of `sender`, so the channel's receiver never sees it disconnect
+    /// while these metrics are alive.
+    pub fn watch_channel<T: Send + 'static>(&self, name: &str, sender: &Sender<T>) {
+        let sender = sender.clone();
+        self.channels.lock().unwrap().push(WatchedChannel {
+            name: name.to_string(),
+            capacity: sender.capacity(),
+            len: Box::new(move || sender.len()),
+            dropped: Box::new(|| 0),
+        });
+    }
+
+    /// Like `watch_channel`, but also reports how many messages `sender` has dropped.
+    pub fn watch_bounded_sender<T: Send + 'static>(&self, name: &str, sender: &BoundedSender<T>) {
+        let sender = sender.clone();
+        self.channels.lock().unwrap().push(WatchedChannel {
+            name: name.to_string(),
+            capacity: sender.sender().capacity(),
+            len: Box::new({
+                let sender = sender.sender().clone();
+                move || sender.len()
+            }),
+            dropped: Box::new(move || sender.dropped()),
+        });
+    }
+
+    /// Reports on the `elapsed` since the previous report and starts counting afresh.
+    pub fn report(&self, elapsed: Duration) -> MetricsReport {
+        let packets = self.packets.swap(0, Ordering::Relaxed);
+        let dispatches = self.dispatches.swap(0, Ordering::Relaxed);
+        let dispatch_nanos = self.dispatch_nanos.swap(0, Ordering::Relaxed);
+        let max_dispatch_nanos = self.max_dispatch_nanos.swap(0, Ordering::Relaxed);
+        let packets_per_sec = match elapsed.is_zero() {
+            true => 0.0,
+            false => packets as f64 / elapsed.as_secs_f64(),
+        };
+        let channels = self
+            .channels
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|channel| ChannelOccupancy {
+                name: channel.name.clone(),
+                len: (channel.len)(),
+                capacity: channel.capacity,
+                dropped: (channel.dropped)(),
+            })
+            .collect();
+        let latencies = std::mem::take(&mut *self.latencies.lock().unwrap());
+        self.gate_stats_wanted.store(true, Ordering::Relaxed);
+        let max_latency = Duration::from_nanos(self.max_latency_nanos.swap(0, Ordering::Relaxed));
+        MetricsReport {
+            packets_per_sec,
+            mean_dispatch: Duration::from_nanos(
+                dispatch_nanos.checked_div(dispatches).unwrap_or(0),
+            ),
+            max_dispatch: Duration::from_nanos(max_dispatch_nanos),
+            buffered_contexts: self.buffered_contexts.load(Ordering::Relaxed),
+            buffered_messages: self.buffered_messages.load(Ordering::Relaxed),
+            gate_contexts: self.gate_contexts.load(Ordering::Relaxed),
+            initialized_contexts: self.initialized_contexts.load(Ordering::Relaxed),
+            oldest_buffered: *self.oldest_buffered.lock().unwrap(),
+            channels,
+            latency: LatencySummary::of(&latencies, max_latency),
+        }
+    }
+
+    /// Logs a report every `interval`, for as long as the process runs.
+    pub fn start_reporting(self: Arc<Self>, interval: Duration) {
+        self.gate_stats_wanted.store(true, Ordering::Relaxed);
+        thread::spawn(move || {
+            let mut last = Instant::now();
+            loop {
+                thread::sleep(interval);
+                let now = Instant::now();
+                let report = self.report(now.duration_since(last));
+                last = now;
+                let channels = report
+                    .channels
+                    .iter()
+                    .map(|channel| {
+                        let occupancy = match channel.capacity {
+                            Some(capacity) => {
+                                format!("{} {}/{}", channel.name, channel.len, capacity)
+                            }
+                            None => format!("{} {}", channel.name, channel.len),
+                        };
+                        match channel.dropped {
+                            0 => occupancy,
+                            dropped => format!("{} ({} dropped)", occupancy, dropped),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                info!(
+                    packets_per_sec = format_args!("{:.1}", report.packets_per_sec),
+                    mean_dispatch_us = report.mean_dispatch.as_micros() as u64,
+                    max_dispatch_us = report.max_dispatch.as_micros() as u64,
+                    buffered_contexts = report.buffered_contexts,
+                    buffered_messages = report.buffered_messages,
+                    gate_contexts = report.gate_contexts,
+                    initialized_contexts = report.initialized_contexts,
+                    oldest_buffered_ms = report
+                        .oldest_buffered
+                        .map_or(0, |age| age.as_millis() as u64),
+                    latency_samples = report.latency.samples,
+                    latency_p50_us = report.latency.p50.as_micros() as u64,
+                    latency_p95_us = report.latency.p95.as_micros() as u64,
+                    latency_max_us = report.latency.max.as_micros() as u64,
+                    jitter_us = report.latency.jitter.as_micros() as u64,
+                    channels = %channels,
+                    "Metrics"
+                );
+            }
+        });
+    }
+}
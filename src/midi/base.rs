@@ -4,8 +4,11 @@ use helgoboss_midi::{
     Channel, ControllerNumber, RawShortMessage, ShortMessage, ShortMessageFactory,
     StructuredShortMessage, U7,
 };
-use midir::{MidiInput, MidiInputPort, MidiOutputConnection};
+use midir::{MidiInput, MidiInputConnection, MidiInputPort, MidiOutputConnection};
+use tracing::{debug, warn};
 
+use crate::capture::Recorder;
+use crate::midi::xtouch::ChannelIndexError;
 use crate::traits::{Bind, Set};
 
 fn byte_slice(msg: RawShortMessage) -> [u8; 3] {
@@ -17,8 +20,12 @@ fn byte_slice(msg: RawShortMessage) -> [u8; 3] {
 pub enum MidiError {
     Send(midir::SendError),
     Connect(midir::ConnectError<midir::MidiInput>),
+    ConnectOutput(midir::ConnectError<midir::MidiOutput>),
+    PortNotFound(String),
     Init(midir::InitError),
     FromBytes(helgoboss_midi::FromBytesError),
+    InvalidDeviceId(u8),
+    InvalidChannels(ChannelIndexError),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -38,7 +45,8 @@ impl Bind<u8> for NoteOnBuilder<'_> {
         F: FnMut(u8) + Send + 'static,
     {
         self.device
-            .note_on_callbacks
+            .callbacks
+            .note_on
             .lock()
             .unwrap()
             .push((self.spec, Box::new(_callback)));
@@ -54,10 +62,7 @@ impl Set<u8> for NoteOnBuilder<'_> {
             helgoboss_midi::KeyNumber::new(self.spec.key_number),
             U7::new(value),
         );
-        self.device
-            .midi_out
-            .send(&byte_slice(message))
-            .map_err(MidiError::Send)
+        self.device.midi_out.send(&byte_slice(message))
     }
 }
 
@@ -78,7 +83,8 @@ impl Bind<u8> for NoteOffBuilder<'_> {
         F: FnMut(u8) + Send + 'static,
     {
         self.device
-            .note_off_callbacks
+            .callbacks
+            .note_off
             .lock()
             .unwrap()
             .push((self.spec, Box::new(_callback)));
@@ -94,10 +100,7 @@ impl Set<u8> for NoteOffBuilder<'_> {
             helgoboss_midi::KeyNumber::new(self.spec.key_number),
             U7::new(value),
         );
-        self.device
-            .midi_out
-            .send(&byte_slice(message))
-            .map_err(MidiError::Send)
+        self.device.midi_out.send(&byte_slice(message))
     }
 }
 
@@ -118,7 +121,8 @@ impl Bind<u8> for ControlChangeBuilder<'_> {
         F: FnMut(u8) + Send + 'static,
     {
         self.device
-            .cc_callbacks
+            .callbacks
+            .cc
             .lock()
             .unwrap()
             .push((self.spec, Box::new(_callback)));
@@ -134,10 +138,7 @@ impl Set<u8> for ControlChangeBuilder<'_> {
             ControllerNumber::new(self.spec.controller_number),
             U7::new(value),
         );
-        self.device
-            .midi_out
-            .send(&byte_slice(message))
-            .map_err(MidiError::Send)
+        self.device.midi_out.send(&byte_slice(message))
     }
 }
 
@@ -157,7 +158,8 @@ impl Bind<u16> for PitchBendBuilder<'_> {
         F: FnMut(u16) + Send + 'static,
     {
         self.device
-            .pitch_bend_callbacks
+            .callbacks
+            .pitch_bend
             .lock()
             .unwrap()
             .push((self.spec, Box::new(_callback)));
@@ -172,118 +174,289 @@ impl Set<u16> for PitchBendBuilder<'_> {
             Channel::new(self.spec.channel),
             helgoboss_midi::U14::new(value),
         );
-        self.device
-            .midi_out
-            .send(&byte_slice(message))
-            .map_err(MidiError::Send)
+        self.device.midi_out.send(&byte_slice(message))
+    }
+}
+
+pub(super) type Callbacks<Spec, Value> = Arc<Mutex<Vec<(Spec, Box<dyn FnMut(Value) + Send>)>>>;
+
+/// An incoming message that didn't reach any binding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnhandledMidi {
+    /// Not a valid short message, e.g. a data byte with its high bit set
+    Malformed(Vec<u8>),
+    /// A valid message that nothing is bound to, including kinds that can't be bound at all
+    Unbound(StructuredShortMessage),
+}
+
+type UnhandledCallback = Box<dyn FnMut(UnhandledMidi) + Send>;
+
+type SysExCallback = Box<dyn FnMut(&[u8]) + Send>;
+
+// Calls every callback whose spec matches, returning whether there were any
+fn call_matching<Spec, Value: Copy>(
+    callbacks: &Callbacks<Spec, Value>,
+    matches: impl Fn(&Spec) -> bool,
+    value: Value,
+) -> bool {
+    let mut called = false;
+    for (spec, callback) in callbacks.lock().unwrap().iter_mut() {
+        if matches(spec) {
+            callback(value);
+            called = true;
+        }
+    }
+    called
+}
+
+// Everything bound to incoming messages, shared with the MIDI input thread
+#[derive(Clone, Default)]
+pub(super) struct InputCallbacks {
+    pub(super) note_on: Callbacks<NoteOn, u8>,
+    pub(super) note_off: Callbacks<NoteOff, u8>,
+    pub(super) cc: Callbacks<ControlChange, u8>,
+    pub(super) pitch_bend: Callbacks<PitchBend, u16>,
+    pub(super) sysex: Arc<Mutex<Vec<SysExCallback>>>,
+    pub(super) unhandled: Arc<Mutex<Option<UnhandledCallback>>>,
+}
+
+impl InputCallbacks {
+    // Calls every callback bound to a raw incoming message
+    pub(super) fn dispatch(&self, message: &[u8]) -> Result<(), UnhandledMidi> {
+        // SysEx goes to its own callbacks whole, since it isn't a short message
+        if message.first() == Some(&0xF0) {
+            let mut callbacks = self.sysex.lock().unwrap();
+            for callback in callbacks.iter_mut() {
+                callback(message);
+            }
+            if !callbacks.is_empty() {
+                return Ok(());
+            }
+        }
+        // Channel pressure (and program change) only have one data byte
+        let data_byte = |i: usize| U7::try_from(message.get(i).copied().unwrap_or(0));
+        let (Some(&status), Ok(data_1), Ok(data_2)) = (message.first(), data_byte(1), data_byte(2))
+        else {
+            return Err(UnhandledMidi::Malformed(message.to_vec()));
+        };
+        let structured = RawShortMessage::from_bytes((status, data_1, data_2))
+            .map_err(|_| UnhandledMidi::Malformed(message.to_vec()))?
+            .to_structured();
+        let handled = match structured {
+            // Note on with zero velocity is how most devices, the X-Touch included, send note off
+            StructuredShortMessage::NoteOn {
+                channel,
+                key_number,
+                velocity,
+            } if velocity == U7::MIN => self.dispatch_note_off(channel, u8::from(key_number), 0),
+            StructuredShortMessage::NoteOn {
+                channel,
+                key_number,
+                velocity,
+            } => call_matching(
+                &self.note_on,
+                |spec| {
+                    Channel::new(spec.channel) == channel && u8::from(key_number) == spec.key_number
+                },
+                u8::from(velocity),
+            ),
+            StructuredShortMessage::NoteOff {
+                channel,
+                key_number,
+                velocity,
+            } => self.dispatch_note_off(channel, u8::from(key_number), u8::from(velocity)),
+            StructuredShortMessage::ControlChange {
+                channel,
+                controller_number,
+                control_value,
+            } => call_matching(
+                &self.cc,
+                |spec| {
+                    Channel::new(spec.channel) == channel
+                        && ControllerNumber::new(spec.controller_number) == controller_number
+                },
+                u8::from(control_value),
+            ),
+            StructuredShortMessage::PitchBendChange {
+                channel,
+                pitch_bend_value,
+            } => call_matching(
+                &self.pitch_bend,
+                |spec| Channel::new(spec.channel) == channel,
+                u16::from(pitch_bend_value),
+            ),
+            // Nothing on the surfaces sends pressure or program changes, so they can't be bound
+            _ => false,
+        };
+        match handled {
+            true => Ok(()),
+            false => Err(UnhandledMidi::Unbound(structured)),
+        }
+    }
+
+    fn dispatch_note_off(&self, channel: Channel, key_number: u8, velocity: u8) -> bool {
+        call_matching(
+            &self.note_off,
+            |spec| Channel::new(spec.channel) == channel && key_number == spec.key_number,
+            velocity,
+        )
+    }
+
+    // Passes a message nothing handled to the host, or logs it if the host didn't ask for them
+    pub(super) fn report_unhandled(&self, unhandled: UnhandledMidi) {
+        match self.unhandled.lock().unwrap().as_mut() {
+            Some(callback) => callback(unhandled),
+            None => debug!("Unhandled MIDI message: {:?}", unhandled),
+        }
+    }
+}
+
+/// Where a device's outgoing messages are written.
+pub trait MidiSink: Send {
+    fn send(&mut self, message: &[u8]) -> Result<(), MidiError>;
+}
+
+impl MidiSink for MidiOutputConnection {
+    fn send(&mut self, message: &[u8]) -> Result<(), MidiError> {
+        MidiOutputConnection::send(self, message).map_err(MidiError::Send)
     }
 }
 
 pub struct MidiDevice {
     name: String,
-    midi_in_port: MidiInputPort,
-    pub midi_out: MidiOutputConnection,
+    // None for a device that only hears what's replayed into it
+    midi_in_port: Option<MidiInputPort>,
+    pub midi_out: Box<dyn MidiSink>,
 
-    note_on_callbacks: Arc<Mutex<Vec<(NoteOn, Box<dyn FnMut(u8) + Send>)>>>,
-    note_off_callbacks: Arc<Mutex<Vec<(NoteOff, Box<dyn FnMut(u8) + Send>)>>>,
-    cc_callbacks: Arc<Mutex<Vec<(ControlChange, Box<dyn FnMut(u8) + Send>)>>>,
-    pitch_bend_callbacks: Arc<Mutex<Vec<(PitchBend, Box<dyn FnMut(u16) + Send>)>>>,
+    callbacks: InputCallbacks,
+    recorder: Option<(Recorder, usize)>,
 }
 
 impl MidiDevice {
     pub fn new(name: &str, midi_in_port: MidiInputPort, midi_out: MidiOutputConnection) -> Self {
         MidiDevice {
             name: name.to_string(),
-            midi_in_port,
-            midi_out,
-            note_on_callbacks: Arc::new(Mutex::new(Vec::new())),
-            note_off_callbacks: Arc::new(Mutex::new(Vec::new())),
-            cc_callbacks: Arc::new(Mutex::new(Vec::new())),
-            pitch_bend_callbacks: Arc::new(Mutex::new(Vec::new())),
+            midi_in_port: Some(midi_in_port),
+            midi_out: Box::new(midi_out),
+            callbacks: InputCallbacks::default(),
+            recorder: None,
         }
     }
 
-    pub fn run(&self) -> Result<(), MidiError> {
+    /// A device without any ports, writing to `midi_out` and hearing only what's replayed into it.
+    #[cfg(test)]
+    pub(crate) fn with_sink(name: &str, midi_out: impl MidiSink + 'static) -> Self {
+        MidiDevice {
+            name: name.to_string(),
+            midi_in_port: None,
+            midi_out: Box::new(midi_out),
+            callbacks: InputCallbacks::default(),
+            recorder: None,
+        }
+    }
+
+    /// Records every incoming message to `recorder` before it is dispatched, as sent by the
+    /// surface's `unit`.
+    pub fn record_to(&mut self, recorder: Recorder, unit: usize) {
+        self.recorder = Some((recorder, unit));
+    }
+
+    /// Dispatches a message as though it had just arrived, e.g. one read back from a capture.
+    pub fn replay_input(&self, message: &[u8]) {
+        if let Err(unhandled) = self.callbacks.dispatch(message) {
+            self.callbacks.report_unhandled(unhandled);
+        }
+    }
+
+    /// Calls `callback` with every incoming message that no binding handles, instead of logging
+    /// it.
+    pub fn on_unhandled<F>(&mut self, callback: F)
+    where
+        F: FnMut(UnhandledMidi) + Send + 'static,
+    {
+        *self.callbacks.unhandled.lock().unwrap() = Some(Box::new(callback));
+    }
+
+    /// Calls `callback` with every incoming SysEx message, including its framing bytes.
+    pub fn on_sysex<F>(&mut self, callback: F)
+    where
+        F: FnMut(&[u8]) + Send + 'static,
+    {
+        self.callbacks
+            .sysex
+            .lock()
+            .unwrap()
+            .push(Box::new(callback));
+    }
+
+    /// Sends a complete SysEx message, including its F0 and F7 framing bytes.
+    pub fn send_sysex(&mut self, message: &[u8]) -> Result<(), MidiError> {
+        self.midi_out.send(message)
+    }
+
+    /// Starts dispatching incoming messages to the bindings, until the returned connection is
+    /// dropped.
+    pub fn run(&self) -> Result<MidiInputConnection<()>, MidiError> {
+        let midi_in_port = self
+            .midi_in_port
+            .as_ref()
+            .ok_or_else(|| MidiError::PortNotFound(self.name.clone()))?;
         let midi_in = MidiInput::new(&self.name).map_err(MidiError::Init)?;
-        let cc_callbacks_clone = self.cc_callbacks.clone();
-        let note_on_callbacks_clone = self.note_on_callbacks.clone();
-        let note_off_callbacks_clone = self.note_off_callbacks.clone();
-        let pitch_bend_callbacks_clone = self.pitch_bend_callbacks.clone();
+        let callbacks = self.callbacks.clone();
+        let recorder = self.recorder.clone();
         midi_in
             .connect(
-                &self.midi_in_port,
+                midi_in_port,
                 "MidiDevice",
                 move |_, message, _| {
-                    let structured = RawShortMessage::from_bytes((
-                        message[0],
-                        U7::new(message[1]),
-                        U7::new(message[2]),
-                    ))
-                    .unwrap()
-                    .to_structured();
-                    match structured {
-                        StructuredShortMessage::NoteOn {
-                            channel,
-                            key_number,
-                            velocity,
-                        } => {
-                            let mut callbacks = note_on_callbacks_clone.lock().unwrap();
-                            for (spec, callback) in callbacks.iter_mut() {
-                                if Channel::new(spec.channel) == channel
-                                    && u8::from(key_number) == spec.key_number
-                                {
-                                    callback(u8::from(velocity));
-                                }
-                            }
-                        }
-                        StructuredShortMessage::NoteOff {
-                            channel,
-                            key_number,
-                            velocity,
-                        } => {
-                            let mut callbacks = note_off_callbacks_clone.lock().unwrap();
-                            for (spec, callback) in callbacks.iter_mut() {
-                                if Channel::new(spec.channel) == channel
-                                    && u8::from(key_number) == spec.key_number
-                                {
-                                    callback(u8::from(velocity));
-                                }
-                            }
-                        }
-                        StructuredShortMessage::ControlChange {
-                            channel,
-                            controller_number,
-                            control_value,
-                        } => {
-                            let mut callbacks = cc_callbacks_clone.lock().unwrap();
-                            for (spec, callback) in callbacks.iter_mut() {
-                                if Channel::new(spec.channel) == channel
-                                    && ControllerNumber::new(spec.controller_number)
-                                        == controller_number
-                                {
-                                    callback(u8::from(control_value));
-                                }
-                            }
-                        }
-                        StructuredShortMessage::PitchBendChange {
-                            channel,
-                            pitch_bend_value,
-                        } => {
-                            let mut callbacks = pitch_bend_callbacks_clone.lock().unwrap();
-                            for (spec, callback) in callbacks.iter_mut() {
-                                if Channel::new(spec.channel) == channel {
-                                    callback(u16::from(pitch_bend_value));
-                                }
-                            }
-                        }
-                        _ => {
-                            println!("Received unexpected message: {:?}", structured);
-                        }
+                    let recorded = recorder
+                        .as_ref()
+                        .map(|(recorder, unit)| recorder.record_midi(*unit, message));
+                    if let Some(Err(e)) = recorded {
+                        warn!("Failed to record MIDI message: {}", e);
+                    }
+                    if let Err(unhandled) = callbacks.dispatch(message) {
+                        callbacks.report_unhandled(unhandled);
                     }
                 },
                 (),
             )
-            .map_err(MidiError::Connect)?;
-        Ok(())
+            .map_err(MidiError::Connect)
+    }
+}
+
+/// Dispatches captured messages as though a device had just sent them, for as long as one is
+/// attached.
+///
+/// Clones share the device, so whoever replays a capture can hold one while the device comes and
+/// goes.
+#[derive(Clone, Default)]
+pub struct MidiReplay {
+    callbacks: Arc<Mutex<Option<InputCallbacks>>>,
+}
+
+impl MidiReplay {
+    /// Dispatches `message` to the attached device's bindings, returning false if there's no
+    /// device to dispatch it to.
+    pub fn send(&self, message: &[u8]) -> bool {
+        match self.callbacks.lock().unwrap().as_ref() {
+            Some(callbacks) => {
+                if let Err(unhandled) = callbacks.dispatch(message) {
+                    callbacks.report_unhandled(unhandled);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sends what's replayed to `device` from now on.
+    pub fn attach(&self, device: &MidiDevice) {
+        *self.callbacks.lock().unwrap() = Some(device.callbacks.clone());
+    }
+
+    /// Stops replaying to the device, e.g. once it has been unplugged.
+    pub fn detach(&self) {
+        *self.callbacks.lock().unwrap() = None;
     }
 }
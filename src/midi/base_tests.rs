@@ -0,0 +1,148 @@
+use std::sync::{Arc, Mutex};
+
+use helgoboss_midi::{Channel, KeyNumber, StructuredShortMessage, U7};
+
+use crate::midi::base::{Callbacks, InputCallbacks, NoteOff, NoteOn, PitchBend, UnhandledMidi};
+
+// Binds a callback that records every value it's called with
+fn record<Spec, Value: Send + 'static>(
+    callbacks: &Callbacks<Spec, Value>,
+    spec: Spec,
+) -> Arc<Mutex<Vec<Value>>> {
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = received.clone();
+    callbacks.lock().unwrap().push((
+        spec,
+        Box::new(move |value| received_clone.lock().unwrap().push(value)),
+    ));
+    received
+}
+
+#[test]
+fn test_note_on_with_zero_velocity_is_note_off() {
+    let callbacks = InputCallbacks::default();
+    let note_on = record(
+        &callbacks.note_on,
+        NoteOn {
+            channel: 0,
+            key_number: 104,
+        },
+    );
+    let note_off = record(
+        &callbacks.note_off,
+        NoteOff {
+            channel: 0,
+            key_number: 104,
+        },
+    );
+
+    assert!(callbacks.dispatch(&[0x90, 104, 127]).is_ok());
+    assert!(callbacks.dispatch(&[0x90, 104, 0]).is_ok());
+    assert!(callbacks.dispatch(&[0x80, 104, 64]).is_ok());
+    // Nothing is bound to this key
+    assert_eq!(
+        callbacks.dispatch(&[0x80, 105, 64]),
+        Err(UnhandledMidi::Unbound(StructuredShortMessage::NoteOff {
+            channel: Channel::new(0),
+            key_number: KeyNumber::new(105),
+            velocity: U7::new(64),
+        }))
+    );
+
+    assert_eq!(*note_on.lock().unwrap(), vec![127]);
+    assert_eq!(*note_off.lock().unwrap(), vec![0, 64]);
+}
+
+#[test]
+fn test_pitch_bend_is_dispatched() {
+    let callbacks = InputCallbacks::default();
+    let pitch_bend = record(&callbacks.pitch_bend, PitchBend { channel: 2 });
+
+    // LSB first: 0x7F | (0x40 << 7)
+    assert!(callbacks.dispatch(&[0xE2, 0x7F, 0x40]).is_ok());
+    // Pitch bend on another channel
+    assert!(callbacks.dispatch(&[0xE3, 0x00, 0x00]).is_err());
+
+    assert_eq!(*pitch_bend.lock().unwrap(), vec![0x207F]);
+}
+
+#[test]
+fn test_pressure_is_reported_unbound() {
+    let callbacks = InputCallbacks::default();
+    // Channel pressure has a single data byte
+    assert_eq!(
+        callbacks.dispatch(&[0xD0, 0x35]),
+        Err(UnhandledMidi::Unbound(
+            StructuredShortMessage::ChannelPressure {
+                channel: Channel::new(0),
+                pressure_amount: U7::new(0x35),
+            }
+        ))
+    );
+    assert!(matches!(
+        callbacks.dispatch(&[0xA0, 60, 90]),
+        Err(UnhandledMidi::Unbound(_))
+    ));
+}
+
+#[test]
+fn test_malformed_messages_are_reported() {
+    let callbacks = InputCallbacks::default();
+    let note_on = record(
+        &callbacks.note_on,
+        NoteOn {
+            channel: 0,
+            key_number: 1,
+        },
+    );
+
+    for message in [
+        vec![],
+        // Data bytes must be 7 bit
+        vec![0x90, 1, 0x80],
+        // Not a status byte
+        vec![0x10, 1, 1],
+    ] {
+        assert_eq!(
+            callbacks.dispatch(&message),
+            Err(UnhandledMidi::Malformed(message.clone()))
+        );
+    }
+
+    assert!(note_on.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_unhandled_messages_go_to_the_host() {
+    let callbacks = InputCallbacks::default();
+    let unhandled = Arc::new(Mutex::new(Vec::new()));
+    let unhandled_clone = unhandled.clone();
+    *callbacks.unhandled.lock().unwrap() = Some(Box::new(move |msg| {
+        unhandled_clone.lock().unwrap().push(msg)
+    }));
+
+    callbacks.report_unhandled(UnhandledMidi::Malformed(vec![0x90]));
+
+    assert_eq!(
+        *unhandled.lock().unwrap(),
+        vec![UnhandledMidi::Malformed(vec![0x90])]
+    );
+}
+
+#[test]
+fn test_sysex_goes_to_sysex_callbacks() {
+    let callbacks = InputCallbacks::default();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = received.clone();
+    callbacks
+        .sysex
+        .lock()
+        .unwrap()
+        .push(Box::new(move |msg: &[u8]| {
+            received_clone.lock().unwrap().push(msg.to_vec())
+        }));
+
+    let ping = [0xF0, 0x00, 0x20, 0x32, 0x58, 0x54, 0x00, 0xF7];
+    assert_eq!(callbacks.dispatch(&ping), Ok(()));
+    assert_eq!(*received.lock().unwrap(), vec![ping.to_vec()]);
+}
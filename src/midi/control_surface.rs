@@ -0,0 +1,47 @@
+use crossbeam_channel::{Receiver, Sender};
+
+use crate::midi::xtouch::surface::SurfaceLayout;
+use crate::midi::xtouch::{ActionButton, XTouchDownstreamMsg, XTouchUpstreamMsg};
+
+/// What a control surface has for the modes to drive, where it differs between surfaces.
+///
+/// Anything else, like the master fader or the scribble strips, the modes send to every surface,
+/// and one without it ignores it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Channel strips across the whole surface, each with a fader, an encoder and the mute, solo,
+    /// arm and select buttons
+    pub channels: usize,
+    /// The buttons that can be set up to run Reaper actions
+    pub action_buttons: Vec<ActionButton>,
+}
+
+impl Capabilities {
+    /// An X-Touch followed by extenders, laid out like `layout`.
+    pub fn xtouch(layout: &SurfaceLayout) -> Self {
+        Capabilities {
+            channels: layout.num_channels(),
+            action_buttons: ActionButton::ALL.to_vec(),
+        }
+    }
+}
+
+/// A surface the modes can drive, be it an X-Touch or anything else that can be made to speak its
+/// messages.
+///
+/// The modes talk to every surface with the X-Touch's messages, which follow the Mackie Control
+/// layout most surfaces share. A surface shows what it has a place for and ignores the rest, and
+/// sends what's done on it in the same terms. Whatever it is, it has to reflect barriers back
+/// once everything before them has been shown, and call back latency probes as they reach it,
+/// as mode transitions and the metrics wait on those.
+pub trait ControlSurface {
+    fn capabilities(&self) -> Capabilities;
+
+    /// Starts showing what the modes send on `input`, and sending what's done on the surface to
+    /// `upstream`.
+    fn connect(
+        self: Box<Self>,
+        input: Receiver<XTouchDownstreamMsg>,
+        upstream: Sender<XTouchUpstreamMsg>,
+    );
+}
@@ -0,0 +1,375 @@
+use std::collections::HashMap;
+use std::mem::Discriminant;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender, bounded};
+use midir::{MidiIO, MidiInput, MidiInputConnection, MidiOutput};
+use tracing::{debug, info, warn};
+
+use crate::capture::Recorder;
+use crate::midi::xtouch::keepalive::KeepAlive;
+use crate::midi::xtouch::surface::{ExtendedSurface, SurfaceLayout};
+use crate::midi::xtouch::{
+    ActionButton, ChannelIndex, DeviceId, EncoderRingLEDMsg, Model, XTouchBuilder,
+    XTouchDemuxBuilder, XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
+use crate::midi::{MidiDevice, MidiError, MidiReplay};
+
+/// How often the manager checks that the device's ports are still there.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Delay before the first attempt to reconnect a device that went away.
+pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+
+/// The longest the manager waits between attempts to reconnect.
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(8);
+
+/// Spaces out reconnection attempts, doubling the delay after each failure up to a ceiling.
+#[derive(Clone, Debug)]
+pub struct Backoff {
+    initial: Duration,
+    max: Duration,
+    next: Duration,
+}
+
+impl Backoff {
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Backoff {
+            initial,
+            max,
+            next: initial,
+        }
+    }
+
+    /// How long to wait before the next attempt.
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self.next;
+        self.next = (self.next * 2).min(self.max);
+        delay
+    }
+
+    /// Starts over from the initial delay, once an attempt succeeds.
+    pub fn reset(&mut self) {
+        self.next = self.initial;
+    }
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Backoff::new(DEFAULT_INITIAL_BACKOFF, DEFAULT_MAX_BACKOFF)
+    }
+}
+
+/// One element of the surface that holds on to what it was last told to show.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum SurfaceElement {
+    Fader(ChannelIndex),
+    MasterFader,
+    EncoderRing(ChannelIndex),
+    Mute(ChannelIndex),
+    Solo(ChannelIndex),
+    Arm(ChannelIndex),
+    Select(ChannelIndex),
+    ScribbleStrip(ChannelIndex),
+    AssignmentDisplay,
+    Action(ActionButton),
+    // The transport, view and encoder assign LEDs, which carry no index of their own
+    Button(Discriminant<XTouchDownstreamMsg>),
+}
+
+impl SurfaceElement {
+    // The element a message changes, or None for messages that don't leave anything shown
+    fn of(msg: &XTouchDownstreamMsg) -> Option<Self> {
+        let element = match msg {
+            XTouchDownstreamMsg::Barrier(_) | XTouchDownstreamMsg::Probe(_) => return None,
+            XTouchDownstreamMsg::FaderAbs(msg) => SurfaceElement::Fader(msg.idx),
+            XTouchDownstreamMsg::MasterFaderAbs(_) => SurfaceElement::MasterFader,
+            XTouchDownstreamMsg::EncoderRingLED(msg) => SurfaceElement::EncoderRing(match msg {
+                EncoderRingLEDMsg::Blank(msg) => msg.idx,
+                EncoderRingLEDMsg::AllSegments(msg) => msg.idx,
+                EncoderRingLEDMsg::RangePoint(msg) => msg.idx,
+                EncoderRingLEDMsg::RangeFill(msg) => msg.idx,
+                EncoderRingLEDMsg::Edges(msg) => msg.idx,
+            }),
+            XTouchDownstreamMsg::MuteLED(msg) => SurfaceElement::Mute(msg.idx),
+            XTouchDownstreamMsg::SoloLED(msg) => SurfaceElement::Solo(msg.idx),
+            XTouchDownstreamMsg::ArmLED(msg) => SurfaceElement::Arm(msg.idx),
+            XTouchDownstreamMsg::SelectLED(msg) => SurfaceElement::Select(msg.idx),
+            XTouchDownstreamMsg::ScribbleStrip(msg) => SurfaceElement::ScribbleStrip(msg.idx),
+            XTouchDownstreamMsg::AssignmentDisplay(_) => SurfaceElement::AssignmentDisplay,
+            XTouchDownstreamMsg::Action(button, _) => SurfaceElement::Action(*button),
+            _ => SurfaceElement::Button(std::mem::discriminant(msg)),
+        };
+        Some(element)
+    }
+}
+
+/// The last message sent to each element of the surface, so a device that comes back can be
+/// shown everything it missed, and a message that wouldn't change anything needn't be sent at all.
+#[derive(Default)]
+pub struct SurfaceCache {
+    latest: HashMap<SurfaceElement, XTouchDownstreamMsg>,
+}
+
+impl SurfaceCache {
+    pub fn new() -> Self {
+        SurfaceCache::default()
+    }
+
+    /// Remembers `msg` as what its element is showing, replacing whatever it showed before.
+    ///
+    /// Returns whether `msg` needs sending: false if its element was already showing exactly that,
+    /// e.g. a fader a mode transition puts back where it was. Messages that don't leave anything
+    /// shown, like barriers, always need sending.
+    pub fn record(&mut self, msg: &XTouchDownstreamMsg) -> bool {
+        match SurfaceElement::of(msg) {
+            Some(element) => self.latest.insert(element, msg.clone()).as_ref() != Some(msg),
+            None => true,
+        }
+    }
+
+    /// Remembers where the user left a fader, which isn't anything we sent it.
+    ///
+    /// Without this a mode setting the fader back to where it was before being moved would look
+    /// like it changed nothing, and the fader would stay put.
+    pub fn record_from_surface(&mut self, msg: &XTouchUpstreamMsg) {
+        let shown = match msg {
+            XTouchUpstreamMsg::FaderAbs(msg) => XTouchDownstreamMsg::FaderAbs(msg.clone()),
+            XTouchUpstreamMsg::MasterFaderAbs(msg) => XTouchDownstreamMsg::MasterFaderAbs(*msg),
+            _ => return,
+        };
+        self.record(&shown);
+    }
+
+    /// Messages that put every element back the way it was last shown, in no particular order.
+    pub fn replay(&self) -> Vec<XTouchDownstreamMsg> {
+        self.latest.values().cloned().collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.latest.is_empty()
+    }
+}
+
+// A connected device: the XTouch worker's input, and the MIDI input that stops when dropped
+struct Connection {
+    downstream: Sender<XTouchDownstreamMsg>,
+    _midi_in: MidiInputConnection<()>,
+}
+
+/// Keeps an XTouch connected across being unplugged and plugged back in.
+///
+/// The manager sits between the modes and the device. It watches for the device's ports to go
+/// away, retries connecting with a backoff until they return, and then replays the faders, LEDs
+/// and scribble strips so the device shows what it would have if it had never left. While the
+/// device is away, barriers are answered on its behalf so mode transitions still complete.
+///
+/// The same record keeps messages that wouldn't change anything off the wire, so a mode
+/// transition only sends the faders, LEDs and strips that actually differ between the modes.
+pub struct XTouchDeviceManager {
+    /// Part of the name of the MIDI ports to connect to, e.g. "X-Touch"
+    pub port_name: String,
+    /// Part of the name of the output port, when it isn't named like the input
+    pub output_port_name: Option<String>,
+    pub num_channels: usize,
+    pub device_id: DeviceId,
+    pub model: Model,
+    /// Units daisy-chained after this one on the same ports, told apart by their device IDs. The
+    /// manager drives all of them as one surface, each unit's strips after the previous one's.
+    pub chained: Vec<(DeviceId, Model)>,
+    pub poll_interval: Duration,
+    pub backoff: Backoff,
+    /// Which unit of the surface the device is, counted in the order the units sit in
+    pub unit: usize,
+    /// Where the device's incoming messages are captured, if anywhere
+    pub recorder: Option<Recorder>,
+    /// Attached to the device whenever it's connected, so a capture can be played back into it
+    pub replay: MidiReplay,
+}
+
+impl XTouchDeviceManager {
+    pub fn new(port_name: &str, num_channels: usize) -> Self {
+        XTouchDeviceManager {
+            port_name: port_name.to_string(),
+            output_port_name: None,
+            num_channels,
+            device_id: DeviceId::default(),
+            model: Model::default(),
+            chained: Vec::new(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            backoff: Backoff::default(),
+            unit: 0,
+            recorder: None,
+            replay: MidiReplay::default(),
+        }
+    }
+
+    pub fn start(
+        mut self,
+        input: Receiver<XTouchDownstreamMsg>,
+        upstream: Sender<XTouchUpstreamMsg>,
+    ) {
+        thread::spawn(move || {
+            let cache = Arc::new(Mutex::new(SurfaceCache::new()));
+            let mut connection: Option<Connection> = None;
+            let mut next_attempt = Instant::now();
+            let mut next_poll = Instant::now() + self.poll_interval;
+            loop {
+                match input.recv_timeout(self.poll_interval) {
+                    // Nothing to send when the surface already shows what it says
+                    Ok(msg) if !cache.lock().unwrap().record(&msg) => {}
+                    Ok(msg) => {
+                        connection = match connection.take() {
+                            Some(conn) => match conn.downstream.send(msg) {
+                                Ok(()) => Some(conn),
+                                Err(_) => {
+                                    warn!("Lost the connection to {}", self.port_name);
+                                    self.replay.detach();
+                                    None
+                                }
+                            },
+                            None => {
+                                if let XTouchDownstreamMsg::Barrier(barrier) = msg {
+                                    let _ = upstream.send(XTouchUpstreamMsg::Barrier(barrier));
+                                }
+                                None
+                            }
+                        };
+                    }
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+
+                let now = Instant::now();
+                if connection.is_some() && now >= next_poll {
+                    next_poll = now + self.poll_interval;
+                    if !self.ports_present() {
+                        warn!("{} was disconnected", self.port_name);
+                        self.replay.detach();
+                        connection = None;
+                        next_attempt = now + self.backoff.next_delay();
+                    }
+                }
+                if connection.is_none() && now >= next_attempt {
+                    match self.connect(&upstream, &cache) {
+                        Ok(conn) => {
+                            info!("Connected to {}", self.port_name);
+                            for msg in cache.lock().unwrap().replay() {
+                                let _ = conn.downstream.send(msg);
+                            }
+                            self.backoff.reset();
+                            next_poll = now + self.poll_interval;
+                            connection = Some(conn);
+                        }
+                        Err(e) => {
+                            let delay = self.backoff.next_delay();
+                            debug!(
+                                "Couldn't connect to {}, retrying in {:?}: {:?}",
+                                self.port_name, delay, e
+                            );
+                            next_attempt = now + delay;
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    fn output_port_name(&self) -> &str {
+        self.output_port_name.as_deref().unwrap_or(&self.port_name)
+    }
+
+    // Whether both of the device's ports are still listed
+    fn ports_present(&self) -> bool {
+        let input =
+            MidiInput::new("arpad-probe").map(|midi| find_port(&midi, &self.port_name).is_some());
+        let output = MidiOutput::new("arpad-probe")
+            .map(|midi| find_port(&midi, self.output_port_name()).is_some());
+        input.unwrap_or(false) && output.unwrap_or(false)
+    }
+
+    fn connect(
+        &self,
+        upstream: &Sender<XTouchUpstreamMsg>,
+        cache: &Arc<Mutex<SurfaceCache>>,
+    ) -> Result<Connection, MidiError> {
+        let midi_in = MidiInput::new("arpad").map_err(MidiError::Init)?;
+        let in_port = find_port(&midi_in, &self.port_name)
+            .ok_or_else(|| MidiError::PortNotFound(self.port_name.clone()))?;
+        let midi_out = MidiOutput::new("arpad").map_err(MidiError::Init)?;
+        let out_port = find_port(&midi_out, self.output_port_name())
+            .ok_or_else(|| MidiError::PortNotFound(self.output_port_name().to_string()))?;
+        let midi_out = midi_out
+            .connect(&out_port, "arpad")
+            .map_err(MidiError::ConnectOutput)?;
+
+        let mut device = MidiDevice::new("arpad", in_port, midi_out);
+        // With several units connected, say which one sent what nothing handled
+        let source = self.port_name.clone();
+        device.on_unhandled(move |unhandled| {
+            debug!("Unhandled MIDI message from {}: {:?}", source, unhandled);
+        });
+        if let Some(recorder) = &self.recorder {
+            device.record_to(recorder.clone(), self.unit);
+        }
+        let midi_in = device.run()?;
+        let base = Arc::new(Mutex::new(device));
+        let status_upstream = upstream.clone();
+        KeepAlive::default().start(&base, move |status| {
+            let _ = status_upstream.send(XTouchUpstreamMsg::SurfaceStatus(status));
+        });
+        let (downstream, downstream_rx) = bounded(128);
+        let (device_upstream, from_device) = bounded(128);
+        if self.chained.is_empty() {
+            XTouchBuilder {
+                base: base.clone(),
+                num_channels: self.num_channels,
+                device_id: self.device_id,
+                master_fader_channel: self.device_id.master_fader_channel(self.num_channels),
+                model: self.model,
+            }
+            .build(downstream_rx, device_upstream)?;
+        } else {
+            let units = XTouchDemuxBuilder {
+                base: base.clone(),
+                num_channels: self.num_channels,
+                units: std::iter::once((self.device_id, self.model))
+                    .chain(self.chained.iter().copied())
+                    .collect(),
+            }
+            .build()?;
+            let layout = SurfaceLayout::new(vec![self.num_channels; units.len()]);
+            let units = units
+                .into_iter()
+                .map(|unit| (unit.downstream, unit.upstream))
+                .collect();
+            ExtendedSurface::start(layout, units, downstream_rx, device_upstream);
+        }
+        self.replay.attach(&base.lock().unwrap());
+        // Runs until the device's handlers are dropped along with the connection
+        let cache = cache.clone();
+        let upstream = upstream.clone();
+        thread::spawn(move || {
+            for msg in from_device {
+                cache.lock().unwrap().record_from_surface(&msg);
+                if upstream.send(msg).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(Connection {
+            downstream,
+            _midi_in: midi_in,
+        })
+    }
+}
+
+// The first port whose name contains `name`
+fn find_port<T: MidiIO>(midi: &T, name: &str) -> Option<T::Port> {
+    midi.ports().into_iter().find(|port| {
+        midi.port_name(port)
+            .is_ok_and(|port_name| port_name.contains(name))
+    })
+}
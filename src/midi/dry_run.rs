@@ -0,0 +1,54 @@
+use std::thread;
+
+use crossbeam_channel::{Receiver, Sender};
+use tracing::info;
+
+use crate::midi::control_surface::{Capabilities, ControlSurface};
+use crate::midi::xtouch::surface::SurfaceLayout;
+use crate::midi::xtouch::{XTouchDownstreamMsg, XTouchUpstreamMsg};
+
+/// Stands in for the control surface during a dry run, logging everything the modes would have
+/// shown on it.
+///
+/// It never answers, so the modes run headless alongside it rather than wait on it to reflect
+/// their barriers. As a [`ControlSurface`], it has everything the surface it stands in for would
+/// have.
+pub struct DryRunSurface {
+    layout: SurfaceLayout,
+}
+
+impl DryRunSurface {
+    /// Stands in for an X-Touch and extenders laid out like `layout`.
+    pub fn new(layout: SurfaceLayout) -> Self {
+        DryRunSurface { layout }
+    }
+
+    /// Logs what the modes send until they go away.
+    pub fn start(input: Receiver<XTouchDownstreamMsg>, upstream: Sender<XTouchUpstreamMsg>) {
+        thread::spawn(move || {
+            // Holding on to upstream keeps the transport's channel from the surface connected
+            let _upstream = upstream;
+            while let Ok(msg) = input.recv() {
+                match msg {
+                    // Only there for the modes' own transitions
+                    XTouchDownstreamMsg::Barrier(_) => {}
+                    msg => info!(msg = ?msg, "Would send to the surface"),
+                }
+            }
+        });
+    }
+}
+
+impl ControlSurface for DryRunSurface {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::xtouch(&self.layout)
+    }
+
+    fn connect(
+        self: Box<Self>,
+        input: Receiver<XTouchDownstreamMsg>,
+        upstream: Sender<XTouchUpstreamMsg>,
+    ) {
+        DryRunSurface::start(input, upstream);
+    }
+}
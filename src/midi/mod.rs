@@ -1,5 +1,15 @@
 mod base;
+pub mod control_surface;
+pub mod device_manager;
+pub mod dry_run;
 mod encoder_led_mappings;
+pub mod ports;
 pub mod xtouch;
 
+pub use base::MidiReplay;
 use base::{MidiDevice, MidiError};
+
+#[cfg(test)]
+mod base_tests;
+#[cfg(test)]
+mod xtouch_tests;
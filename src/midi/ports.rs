@@ -0,0 +1,256 @@
+use crossbeam_channel::{Receiver, Sender, bounded};
+use serde::Deserialize;
+
+use crate::capture::Recorder;
+use crate::midi::MidiReplay;
+use crate::midi::control_surface::{Capabilities, ControlSurface};
+use crate::midi::device_manager::XTouchDeviceManager;
+use crate::midi::xtouch::surface::{ExtendedSurface, SurfaceLayout};
+use crate::midi::xtouch::{
+    DeviceId, Model, XTOUCH_CHANNELS, XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
+
+/// The MIDI ports one unit of the surface is driven on.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PortRoute {
+    /// Part of the name of the port the unit is heard on, e.g. "X-Touch"
+    pub input: String,
+    /// Part of the name of the port the unit is driven on, when it isn't named like the input
+    #[serde(default)]
+    pub output: Option<String>,
+    #[serde(default)]
+    pub model: Model,
+    /// Which of the units daisy-chained on the input port this is, 0 when it has the port to
+    /// itself
+    #[serde(default)]
+    pub device_id: DeviceId,
+}
+
+impl PortRoute {
+    pub fn new(port_name: &str, model: Model) -> Self {
+        PortRoute {
+            input: port_name.to_string(),
+            output: None,
+            model,
+            device_id: DeviceId::default(),
+        }
+    }
+
+    /// Part of the name of the port the unit is driven on.
+    pub fn output(&self) -> &str {
+        self.output.as_deref().unwrap_or(&self.input)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PortRoutingError {
+    /// There are no ports to drive a surface on
+    Empty,
+    /// The first unit has to be an X-Touch, as it holds everything outside the channel strips
+    FirstNotXTouch,
+    /// Only the first unit can be an X-Touch; this is the position of another one
+    SecondXTouch(usize),
+    /// Two units are heard on the same input port with the same device ID, so there'd be no
+    /// telling them apart
+    SharedInput(String),
+    /// Units chained on this input port aren't next to each other, so their channels couldn't be
+    /// either
+    SplitChain(String),
+    /// The device ID of the unit at this position puts its channels past the port's last one
+    InvalidDeviceId(usize),
+}
+
+/// Which MIDI ports feed which unit of the surface the modes drive.
+///
+/// Units are laid out in the order of their routes: the X-Touch first, then its extenders. Every
+/// unit is heard on its own input port, or daisy-chained with the units next to it on a shared one
+/// and told apart from them by its device ID. What it sends is tagged with the unit it came from
+/// on its way to the modes, so its channels can be told apart from those of the other units.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PortRouting {
+    routes: Vec<PortRoute>,
+}
+
+impl PortRouting {
+    pub fn new(routes: Vec<PortRoute>) -> Result<Self, PortRoutingError> {
+        match routes.first() {
+            None => return Err(PortRoutingError::Empty),
+            Some(route) if route.model != Model::XTouch => {
+                return Err(PortRoutingError::FirstNotXTouch);
+            }
+            Some(_) => {}
+        }
+        if let Some(pos) = routes
+            .iter()
+            .skip(1)
+            .position(|route| route.model == Model::XTouch)
+        {
+            return Err(PortRoutingError::SecondXTouch(pos + 1));
+        }
+        for (i, route) in routes.iter().enumerate() {
+            let mut earlier = routes[..i]
+                .iter()
+                .filter(|other| other.input == route.input);
+            if earlier
+                .clone()
+                .any(|other| other.device_id == route.device_id)
+            {
+                return Err(PortRoutingError::SharedInput(route.input.clone()));
+            }
+            if earlier.next().is_some() && routes[i - 1].input != route.input {
+                return Err(PortRoutingError::SplitChain(route.input.clone()));
+            }
+            if route.device_id.first_channel(XTOUCH_CHANNELS).is_none() {
+                return Err(PortRoutingError::InvalidDeviceId(i));
+            }
+        }
+        Ok(PortRouting { routes })
+    }
+
+    /// An X-Touch on the ports named like `xtouch_port`, followed by an extender on the ports
+    /// named like each of `extender_ports`.
+    pub fn xtouch_with_extenders(
+        xtouch_port: &str,
+        extender_ports: &[String],
+    ) -> Result<Self, PortRoutingError> {
+        let routes = std::iter::once(PortRoute::new(xtouch_port, Model::XTouch))
+            .chain(
+                extender_ports
+                    .iter()
+                    .map(|port_name| PortRoute::new(port_name, Model::Extender)),
+            )
+            .collect();
+        PortRouting::new(routes)
+    }
+
+    pub fn routes(&self) -> &[PortRoute] {
+        &self.routes
+    }
+
+    /// How the surface's channels are spread over the units.
+    pub fn layout(&self) -> SurfaceLayout {
+        SurfaceLayout::new(vec![XTOUCH_CHANNELS; self.routes.len()])
+    }
+
+    /// Starts driving each unit on its ports, reconnecting any that are unplugged, as a single
+    /// surface of all their channels. What the units send is captured, and a capture played back
+    /// into them, through `tap`; units chained on a port go through the first of them.
+    pub fn start(
+        &self,
+        input: Receiver<XTouchDownstreamMsg>,
+        upstream: Sender<XTouchUpstreamMsg>,
+        tap: &MidiTap,
+    ) {
+        // The units on each input port, which the routes keep next to each other
+        let ports: Vec<&[PortRoute]> = self
+            .routes
+            .chunk_by(|route, next| route.input == next.input)
+            .collect();
+        let layout = SurfaceLayout::new(
+            ports
+                .iter()
+                .map(|routes| XTOUCH_CHANNELS * routes.len())
+                .collect(),
+        );
+        let mut unit = 0;
+        let mut managers = ports.into_iter().map(|routes| {
+            let route = &routes[0];
+            let first_unit = unit;
+            unit += routes.len();
+            XTouchDeviceManager {
+                output_port_name: route.output.clone(),
+                device_id: route.device_id,
+                model: route.model,
+                chained: routes[1..]
+                    .iter()
+                    .map(|route| (route.device_id, route.model))
+                    .collect(),
+                unit: first_unit,
+                recorder: tap.recorder.clone(),
+                replay: tap.units.get(first_unit).cloned().unwrap_or_default(),
+                ..XTouchDeviceManager::new(&route.input, XTOUCH_CHANNELS)
+            }
+        });
+        // The units on a lone port have no others to be told apart from, beyond what its
+        // manager already tells apart
+        if layout.num_units() == 1 {
+            if let Some(manager) = managers.next() {
+                manager.start(input, upstream);
+            }
+            return;
+        }
+        let units = managers
+            .map(|manager| {
+                let (to_unit, unit_input) = bounded(128);
+                let (unit_upstream, from_unit) = bounded(128);
+                manager.start(unit_input, unit_upstream);
+                (to_unit, from_unit)
+            })
+            .collect();
+        ExtendedSurface::start(layout, units, input, upstream);
+    }
+}
+
+impl ControlSurface for PortRouting {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::xtouch(&self.layout())
+    }
+
+    fn connect(
+        self: Box<Self>,
+        input: Receiver<XTouchDownstreamMsg>,
+        upstream: Sender<XTouchUpstreamMsg>,
+    ) {
+        self.start(input, upstream, &MidiTap::default());
+    }
+}
+
+/// Where the MIDI the surface's units send is captured, and the way into them for playing a
+/// capture back as though they had sent it.
+///
+/// Clones share the units, so the tap can be held on to after the surface is started with it.
+#[derive(Clone, Default)]
+pub struct MidiTap {
+    pub recorder: Option<Recorder>,
+    units: Vec<MidiReplay>,
+}
+
+impl MidiTap {
+    /// A tap into the surface's first `num_units` units, capturing to `recorder` if there is one.
+    pub fn new(num_units: usize, recorder: Option<Recorder>) -> Self {
+        MidiTap {
+            recorder,
+            // Each unit needs a replay of its own, which a clone wouldn't be
+            units: (0..num_units).map(|_| MidiReplay::default()).collect(),
+        }
+    }
+
+    /// Dispatches `message` as though `unit` had just sent it, returning false if the unit isn't
+    /// connected.
+    pub fn replay(&self, unit: usize, message: &[u8]) -> bool {
+        self.units
+            .get(unit)
+            .is_some_and(|replay| replay.send(message))
+    }
+}
+
+/// The units on a PortRouting's ports, captured and replayed into through a tap.
+pub struct TappedPorts {
+    pub routing: PortRouting,
+    pub tap: MidiTap,
+}
+
+impl ControlSurface for TappedPorts {
+    fn capabilities(&self) -> Capabilities {
+        self.routing.capabilities()
+    }
+
+    fn connect(
+        self: Box<Self>,
+        input: Receiver<XTouchDownstreamMsg>,
+        upstream: Sender<XTouchUpstreamMsg>,
+    ) {
+        self.routing.start(input, upstream, &self.tap);
+    }
+}
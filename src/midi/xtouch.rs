@@ -1,46 +1,345 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 use std::thread;
 
-use crossbeam_channel::{Receiver, Sender};
+use crossbeam_channel::{Receiver, Sender, bounded};
 use derive_more::From;
 use helgoboss_midi::{Channel, RawShortMessage, ShortMessage};
+use serde::Deserialize;
+use serde::de::IntoDeserializer;
+use tracing::{debug, warn};
 
+use crate::metrics::LatencyProbe;
 use crate::midi::base::{
     ControlChange, ControlChangeBuilder, NoteOff, NoteOffBuilder, NoteOn, NoteOnBuilder, PitchBend,
     PitchBendBuilder,
 };
 use crate::midi::encoder_led_mappings;
+use crate::midi::xtouch::keepalive::SurfaceStatus;
 use crate::midi::{MidiDevice, MidiError};
 use crate::modes::mode_manager::Barrier;
 use crate::traits::{Bind, Set};
 
-#[derive(Clone, Debug)]
+pub mod keepalive;
+pub mod simulator;
+pub mod surface;
+
+/// Number of MIDI channels available on a single port.
+const MIDI_CHANNELS_PER_PORT: usize = 16;
+
+/// Number of channel strips on an X-Touch, and on each X-Touch Extender.
+pub const XTOUCH_CHANNELS: usize = 8;
+
+/// Which kind of unit a MIDI port talks to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Model {
+    /// The X-Touch itself, with the master fader and the buttons outside the channel strips
+    #[default]
+    #[serde(rename = "xtouch")]
+    XTouch,
+    /// An X-Touch Extender, which only has channel strips
+    Extender,
+}
+
+/// The buttons nothing else uses, which can be set up to run Reaper actions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionButton {
+    Flip,
+    Read,
+    Write,
+    Trim,
+    Touch,
+    Latch,
+    Group,
+    Save,
+    Marker,
+    Nudge,
+    Drop,
+    Replace,
+    Click,
+    Solo,
+}
+
+impl ActionButton {
+    pub const ALL: [ActionButton; 14] = [
+        ActionButton::Flip,
+        ActionButton::Read,
+        ActionButton::Write,
+        ActionButton::Trim,
+        ActionButton::Touch,
+        ActionButton::Latch,
+        ActionButton::Group,
+        ActionButton::Save,
+        ActionButton::Marker,
+        ActionButton::Nudge,
+        ActionButton::Drop,
+        ActionButton::Replace,
+        ActionButton::Click,
+        ActionButton::Solo,
+    ];
+
+    fn midi_note(self) -> u8 {
+        match self {
+            ActionButton::Flip => 0x32,
+            ActionButton::Read => 0x4A,
+            ActionButton::Write => 0x4B,
+            ActionButton::Trim => 0x4C,
+            ActionButton::Touch => 0x4D,
+            ActionButton::Latch => 0x4E,
+            ActionButton::Group => 0x4F,
+            ActionButton::Save => 0x50,
+            ActionButton::Marker => 0x54,
+            ActionButton::Nudge => 0x55,
+            ActionButton::Drop => 0x57,
+            ActionButton::Replace => 0x58,
+            ActionButton::Click => 0x59,
+            ActionButton::Solo => 0x5A,
+        }
+    }
+}
+
+/// Parses a button by the name it goes by in the config, e.g. "click".
+impl FromStr for ActionButton {
+    type Err = serde::de::value::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        ActionButton::deserialize(name.into_deserializer())
+    }
+}
+
+impl Model {
+    /// The unit's device code in SysEx messages.
+    pub fn sysex_device(self) -> u8 {
+        match self {
+            Model::XTouch => 0x14,
+            Model::Extender => 0x15,
+        }
+    }
+}
+
+/// Identifies one unit in a chain of MCU-compatible surfaces sharing a single MIDI port.
+///
+/// Each unit owns a contiguous block of MIDI channels: unit `n` with `num_channels` strips uses
+/// channels `n * num_channels..(n + 1) * num_channels`. The chain has a single master fader, on
+/// the first channel past the last unit's block.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(transparent)]
+pub struct DeviceId(pub u8);
+
+impl DeviceId {
+    /// Returns the first MIDI channel owned by this unit, or None if its channel block does not
+    /// fit on one port.
+    pub fn first_channel(self, num_channels: usize) -> Option<u8> {
+        let first = self.0 as usize * num_channels;
+        if first + num_channels <= MIDI_CHANNELS_PER_PORT {
+            Some(first as u8)
+        } else {
+            None
+        }
+    }
+
+    /// The channel of the master fader on a port chaining units up to this one, or None if this
+    /// unit's block leaves no channel for it.
+    pub fn master_fader_channel(self, num_channels: usize) -> Option<u8> {
+        let after = self.first_channel(num_channels)? as usize + num_channels;
+        (after < MIDI_CHANNELS_PER_PORT).then_some(after as u8)
+    }
+
+    /// Maps a MIDI channel heard on a port chaining units up to `last` back to the unit that owns
+    /// it and the strip index within that unit. Channels past `last`'s block, like the master
+    /// fader's, belong to no unit.
+    pub fn from_channel(
+        channel: u8,
+        num_channels: usize,
+        last: DeviceId,
+    ) -> Option<(DeviceId, usize)> {
+        if num_channels == 0 {
+            return None;
+        }
+        let id = DeviceId((channel as usize / num_channels) as u8);
+        if id.0 > last.0 {
+            return None;
+        }
+        id.first_channel(num_channels)?;
+        Some((id, channel as usize % num_channels))
+    }
+}
+
+/// Index of a channel strip on the control surface.
+///
+/// Indices that originate outside the surface (e.g. a Reaper track index) should be converted with
+/// `try_from` and then checked with [`ChannelIndex::within`] so that an out-of-range value is
+/// rejected instead of being silently truncated or used to index past the end of a strip array.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChannelIndex(u8);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChannelIndexError {
+    /// The value cannot be represented as a channel index at all
+    Invalid(i64),
+    /// The value is a valid index, but the surface does not have that many channels
+    OutOfBounds { idx: u8, num_channels: usize },
+}
+
+impl ChannelIndex {
+    pub const fn new(idx: u8) -> Self {
+        ChannelIndex(idx)
+    }
+
+    pub fn get(self) -> usize {
+        self.0 as usize
+    }
+
+    /// Checks that this index addresses one of `num_channels` channels.
+    pub fn within(self, num_channels: usize) -> Result<Self, ChannelIndexError> {
+        if self.get() < num_channels {
+            Ok(self)
+        } else {
+            Err(ChannelIndexError::OutOfBounds {
+                idx: self.0,
+                num_channels,
+            })
+        }
+    }
+}
+
+impl TryFrom<usize> for ChannelIndex {
+    type Error = ChannelIndexError;
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        u8::try_from(value)
+            .map(ChannelIndex)
+            .map_err(|_| ChannelIndexError::Invalid(i64::try_from(value).unwrap_or(i64::MAX)))
+    }
+}
+
+impl TryFrom<i32> for ChannelIndex {
+    type Error = ChannelIndexError;
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        u8::try_from(value)
+            .map(ChannelIndex)
+            .map_err(|_| ChannelIndexError::Invalid(value.into()))
+    }
+}
+
+impl From<ChannelIndex> for usize {
+    fn from(value: ChannelIndex) -> Self {
+        value.get()
+    }
+}
+
+impl From<ChannelIndex> for i32 {
+    fn from(value: ChannelIndex) -> Self {
+        value.0.into()
+    }
+}
+
+impl PartialEq<i32> for ChannelIndex {
+    fn eq(&self, other: &i32) -> bool {
+        i32::from(self.0) == *other
+    }
+}
+
+impl std::fmt::Display for ChannelIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The value the hardware reports for a fader at the top of its travel; faders have 14 bits.
+const FADER_MIDI_MAX: f64 = 16383.0;
+
+/// The position of a fader, from 0.0 at the bottom to 1.0 at the top, for the 14-bit value the
+/// hardware reports.
+pub fn fader_from_midi(value: u16) -> f64 {
+    (value as f64 / FADER_MIDI_MAX).clamp(0.0, 1.0)
+}
+
+/// The 14-bit value that puts a fader at `position`, to the nearest step the motor can reach.
+pub fn fader_to_midi(position: f64) -> i32 {
+    (position.clamp(0.0, 1.0) * FADER_MIDI_MAX).round() as i32
+}
+
+/// Fader positions travel as f64, so a value Reaper sent as an OSC double reaches the motor as it
+/// was sent. The tapers, and Reaper's OSC floats, work in f32: the modes go through `at` and
+/// `position` to get between the two.
+#[derive(Clone, Debug, PartialEq)]
 pub struct FaderAbsMsg {
-    pub idx: i32,
-    pub value: f64, // Probably too much precision?
+    pub idx: ChannelIndex,
+    pub value: f64,
+}
+
+impl FaderAbsMsg {
+    /// Puts the fader on `idx` at a position worked out in f32.
+    pub fn at(idx: ChannelIndex, position: f32) -> Self {
+        FaderAbsMsg {
+            idx,
+            value: position as f64,
+        }
+    }
+
+    /// The position, for the tapers and Reaper's OSC floats.
+    pub fn position(&self) -> f32 {
+        self.value as f32
+    }
+}
+
+/// Position of the master fader, which sits apart from the channel strips.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MasterFaderAbsMsg {
+    pub value: f64,
+}
+
+impl MasterFaderAbsMsg {
+    /// Puts the master fader at a position worked out in f32.
+    pub fn at(position: f32) -> Self {
+        MasterFaderAbsMsg {
+            value: position as f64,
+        }
+    }
+
+    /// The position, for the tapers and Reaper's OSC floats.
+    pub fn position(&self) -> f32 {
+        self.value as f32
+    }
+}
+
+/// The user has put a finger on a fader. The faders are touch sensitive, so this arrives before
+/// any movement.
+#[derive(Clone, Copy, Debug)]
+pub struct FaderTouchMsg {
+    pub idx: ChannelIndex,
+}
+
+/// The user has let go of a fader.
+#[derive(Clone, Copy, Debug)]
+pub struct FaderReleaseMsg {
+    pub idx: ChannelIndex,
 }
 
 #[derive(Clone, Copy, Debug)]
 pub struct EncoderTurnCW {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
 #[derive(Clone, Copy, Debug)]
 pub struct EncoderTurnCCW {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
 #[derive(Clone, Copy, Debug)]
 pub struct EncoderPressMsg {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
 #[derive(Clone, Copy, Debug)]
 pub struct EncoderReleaseMsg {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum EncoderRingLEDMsg {
     Blank(EncoderRingLEDBlankMsg),
     AllSegments(EncoderRingLEDAllSegmentsMsg),
@@ -49,37 +348,144 @@ pub enum EncoderRingLEDMsg {
     Edges(EncoderRingLEDEdges),
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct EncoderRingLEDBlankMsg {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct EncoderRingLEDAllSegmentsMsg {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct EncoderRingLEDRangePointMsg {
-    pub idx: i32,
+    pub idx: ChannelIndex,
     pub pos: f32, // 0.0 to 1.0
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct EncoderRingLEDRangeFillMsg {
-    pub idx: i32,
+    pub idx: ChannelIndex,
     pub pos: f32, // 0.0 to 1.0
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct EncoderRingLEDEdges {
-    pub idx: i32,
+    pub idx: ChannelIndex,
+}
+
+/// Two characters for the assignment display, e.g. "V1" for bank 1 of the vol/pan mode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssignmentDisplayMsg {
+    pub text: [char; 2],
+}
+
+impl AssignmentDisplayMsg {
+    /// Shows a mode abbreviation followed by a 0-based bank, displayed 1-based. Banks that need two
+    /// digits take over the whole display.
+    pub fn mode_bank(mode: char, bank: usize) -> Self {
+        let digit = |n: usize| char::from_digit(n as u32, 10).unwrap();
+        let text = match bank + 1 {
+            n @ 1..=9 => [mode, digit(n)],
+            n @ 10..=99 => [digit(n / 10), digit(n % 10)],
+            _ => ['-', '-'],
+        };
+        AssignmentDisplayMsg { text }
+    }
+}
+
+/// Maps a character onto the code the assignment display's 7-segment digits expect.
+///
+/// The display understands the printable ASCII range 0x20..=0x5F folded into 6 bits, so lowercase
+/// letters are shown as uppercase and anything else is shown as a blank.
+pub fn seven_segment_code(c: char) -> u8 {
+    match c.to_ascii_uppercase() {
+        c @ ' '..='_' => c as u8 & 0x3F,
+        _ => b' ',
+    }
+}
+
+/// Backlight colors supported by the scribble strips.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScribbleColor {
+    Off,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl ScribbleColor {
+    /// The backlight closest to a color given as 0xRRGGBB, like Reaper's track colors.
+    ///
+    /// The strips only mix full red, green and blue, so each channel is lit if it's at least half
+    /// as bright as the brightest one. Black has no backlight that looks like it, and comes out
+    /// White so the text stays readable.
+    pub fn nearest(rgb: i32) -> ScribbleColor {
+        let channels = [(rgb >> 16) & 0xFF, (rgb >> 8) & 0xFF, rgb & 0xFF];
+        let brightest = channels.into_iter().max().unwrap_or(0);
+        let lit = channels.map(|channel| brightest > 0 && channel * 2 >= brightest);
+        match lit {
+            [true, false, false] => ScribbleColor::Red,
+            [false, true, false] => ScribbleColor::Green,
+            [true, true, false] => ScribbleColor::Yellow,
+            [false, false, true] => ScribbleColor::Blue,
+            [true, false, true] => ScribbleColor::Magenta,
+            [false, true, true] => ScribbleColor::Cyan,
+            _ => ScribbleColor::White,
+        }
+    }
+}
+
+/// Text for one channel's scribble strip. Each line holds 7 characters; longer text is cut off.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScribbleStripMsg {
+    pub idx: ChannelIndex,
+    pub top_line: String,
+    pub bottom_line: String,
+    pub color: ScribbleColor,
+}
+
+/// Number of characters on each line of a scribble strip.
+pub const SCRIBBLE_LINE_LEN: usize = 7;
+
+/// Encodes a scribble strip update as the X-Touch's SysEx message.
+///
+/// The message is `F0 00 20 32 <device> 4C <strip> <color> <14 chars> F7`, with the top line in
+/// the first 7 characters. Lines are padded with spaces and characters outside of printable ASCII
+/// are shown as '?'.
+pub fn scribble_strip_sysex(strip: u8, msg: &ScribbleStripMsg) -> Vec<u8> {
+    model_scribble_strip_sysex(Model::XTouch, strip, msg)
+}
+
+/// Like [`scribble_strip_sysex`], but for the given kind of unit.
+pub fn model_scribble_strip_sysex(model: Model, strip: u8, msg: &ScribbleStripMsg) -> Vec<u8> {
+    let mut sysex = vec![0xF0, 0x00, 0x20, 0x32, model.sysex_device(), 0x4C, strip];
+    // The bottom line is drawn inverted when bit 5 is set; we always use the normal style
+    sysex.push(msg.color as u8);
+    for line in [&msg.top_line, &msg.bottom_line] {
+        let mut chars = line.chars();
+        for _ in 0..SCRIBBLE_LINE_LEN {
+            sysex.push(match chars.next() {
+                Some(c @ ' '..='~') => c as u8,
+                Some(_) => b'?',
+                None => b' ',
+            });
+        }
+    }
+    sysex.push(0xF7);
+    sysex
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum LEDState {
     Off,
     On,
+    /// Blinking, which the surface does by itself so it keeps time without any more MIDI
     Flash,
 }
 
@@ -94,74 +500,104 @@ impl From<bool> for LEDState {
 
 #[derive(Clone)]
 pub struct MutePress {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
 #[derive(Clone)]
 pub struct MuteRelease {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct MuteLEDMsg {
-    pub idx: i32,
+    pub idx: ChannelIndex,
     pub state: LEDState,
 }
 
 #[derive(Clone)]
 pub struct SoloPress {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
 #[derive(Clone)]
 pub struct SoloRelease {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct SoloLEDMsg {
-    pub idx: i32,
+    pub idx: ChannelIndex,
     pub state: LEDState,
 }
 
 #[derive(Clone)]
 pub struct ArmPress {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
 #[derive(Clone)]
 pub struct ArmRelease {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ArmLEDMsg {
-    pub idx: i32,
+    pub idx: ChannelIndex,
     pub state: LEDState,
 }
 
 #[derive(Clone)]
 pub struct SelectPress {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
 #[derive(Clone)]
 pub struct SelectRelease {
-    pub idx: i32,
+    pub idx: ChannelIndex,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct SelectLEDMsg {
-    pub idx: i32,
+    pub idx: ChannelIndex,
     pub state: LEDState,
 }
 
+/// How many function keys the surface has, F1 to F8
+pub const NUM_FUNCTION_KEYS: u8 = 8;
+
+/// A function key, numbered from 0 for F1
+#[derive(Clone)]
+pub struct FunctionPress {
+    pub idx: u8,
+}
+
+#[derive(Clone)]
+pub struct FunctionRelease {
+    pub idx: u8,
+}
+
+/// One of the buttons left to run Reaper actions
+#[derive(Clone)]
+pub struct ActionPress {
+    pub button: ActionButton,
+}
+
+#[derive(Clone)]
+pub struct ActionRelease {
+    pub button: ActionButton,
+}
+
 #[derive(From)]
 pub enum XTouchUpstreamMsg {
     Barrier(Barrier),
 
+    /// The surface started or stopped answering the host's keep-alive pings
+    SurfaceStatus(SurfaceStatus),
+
     // Channel strip messages
     FaderAbs(FaderAbsMsg),
+    FaderTouch(FaderTouchMsg),
+    FaderRelease(FaderReleaseMsg),
     EncoderTurnInc(EncoderTurnCW),
     EncoderTurnDec(EncoderTurnCCW),
     EncoderPress(EncoderPressMsg),
@@ -175,6 +611,9 @@ pub enum XTouchUpstreamMsg {
     SelectPress(SelectPress),
     SelectRelease(SelectRelease),
 
+    // Master fader messages
+    MasterFaderAbs(MasterFaderAbsMsg),
+
     // Encoder assign messages
     TrackPress,
     TrackRelease,
@@ -208,11 +647,59 @@ pub enum XTouchUpstreamMsg {
     OutputsRelease,
     UserPress,
     UserRelease,
+
+    // Fader bank messages
+    BankLeftPress,
+    BankLeftRelease,
+    BankRightPress,
+    BankRightRelease,
+
+    // Modifier messages
+    ShiftPress,
+    ShiftRelease,
+
+    // Function key messages
+    FunctionPress(FunctionPress),
+    FunctionRelease(FunctionRelease),
+
+    // Utility messages
+    UndoPress,
+    UndoRelease,
+    RedoPress,
+    RedoRelease,
+    ResyncPress,
+    ResyncRelease,
+
+    // Transport messages
+    RewindPress,
+    RewindRelease,
+    FastForwardPress,
+    FastForwardRelease,
+    StopPress,
+    StopRelease,
+    PlayPress,
+    PlayRelease,
+    RecordPress,
+    RecordRelease,
+    CyclePress,
+    CycleRelease,
+    JogCW,
+    JogCCW,
+    ScrubPress,
+    ScrubRelease,
+    ZoomPress,
+    ZoomRelease,
+
+    // Buttons left to run Reaper actions
+    ActionPress(ActionPress),
+    ActionRelease(ActionRelease),
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum XTouchDownstreamMsg {
     Barrier(Barrier),
+    /// Calls back once everything sent before it has gone out to the surface
+    Probe(LatencyProbe),
 
     // Channel strip messages
     FaderAbs(FaderAbsMsg),
@@ -221,6 +708,13 @@ pub enum XTouchDownstreamMsg {
     SoloLED(SoloLEDMsg),
     ArmLED(ArmLEDMsg),
     SelectLED(SelectLEDMsg),
+    ScribbleStrip(ScribbleStripMsg),
+
+    // Master fader messages
+    MasterFaderAbs(MasterFaderAbsMsg),
+
+    // Assignment display
+    AssignmentDisplay(AssignmentDisplayMsg),
 
     // Encoder assign messages
     Track(LEDState),
@@ -240,6 +734,63 @@ pub enum XTouchDownstreamMsg {
     Buses(LEDState),
     Outputs(LEDState),
     User(LEDState),
+
+    // Transport messages
+    Rewind(LEDState),
+    FastForward(LEDState),
+    Stop(LEDState),
+    Play(LEDState),
+    Record(LEDState),
+    Cycle(LEDState),
+    Scrub(LEDState),
+    Zoom(LEDState),
+    Action(ActionButton, LEDState),
+}
+
+impl XTouchUpstreamMsg {
+    /// The channel strip the message came from, for messages from a strip.
+    pub fn channel_mut(&mut self) -> Option<&mut ChannelIndex> {
+        match self {
+            XTouchUpstreamMsg::FaderAbs(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::FaderTouch(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::FaderRelease(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::EncoderTurnInc(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::EncoderTurnDec(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::EncoderPress(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::EncoderRelease(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::MutePress(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::MuteRelease(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::SoloPress(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::SoloRelease(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::ArmPress(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::ArmRelease(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::SelectPress(msg) => Some(&mut msg.idx),
+            XTouchUpstreamMsg::SelectRelease(msg) => Some(&mut msg.idx),
+            _ => None,
+        }
+    }
+}
+
+impl XTouchDownstreamMsg {
+    /// The channel strip the message is for, for messages to a strip.
+    pub fn channel_mut(&mut self) -> Option<&mut ChannelIndex> {
+        match self {
+            XTouchDownstreamMsg::FaderAbs(msg) => Some(&mut msg.idx),
+            XTouchDownstreamMsg::EncoderRingLED(msg) => Some(match msg {
+                EncoderRingLEDMsg::Blank(msg) => &mut msg.idx,
+                EncoderRingLEDMsg::AllSegments(msg) => &mut msg.idx,
+                EncoderRingLEDMsg::RangePoint(msg) => &mut msg.idx,
+                EncoderRingLEDMsg::RangeFill(msg) => &mut msg.idx,
+                EncoderRingLEDMsg::Edges(msg) => &mut msg.idx,
+            }),
+            XTouchDownstreamMsg::MuteLED(msg) => Some(&mut msg.idx),
+            XTouchDownstreamMsg::SoloLED(msg) => Some(&mut msg.idx),
+            XTouchDownstreamMsg::ArmLED(msg) => Some(&mut msg.idx),
+            XTouchDownstreamMsg::SelectLED(msg) => Some(&mut msg.idx),
+            XTouchDownstreamMsg::ScribbleStrip(msg) => Some(&mut msg.idx),
+            _ => None,
+        }
+    }
 }
 
 fn byte_slice(msg: RawShortMessage) -> [u8; 3] {
@@ -416,223 +967,871 @@ impl Set<LEDState> for Button {
     }
 }
 
+pub struct JogWheel {
+    base: Arc<Mutex<MidiDevice>>,
+    channel: Channel,
+}
+
+impl JogWheel {
+    fn bind_turn<F>(&mut self, mut callback: F)
+    where
+        F: FnMut(u8) + 'static + std::marker::Send,
+    {
+        ControlChangeBuilder {
+            device: &mut self.base.lock().unwrap(),
+            spec: ControlChange {
+                channel: self.channel.get(),
+                controller_number: 0x3C,
+            },
+        }
+        .bind(move |value| {
+            callback(value);
+        })
+    }
+}
+
+pub struct AssignmentDisplay {
+    base: Arc<Mutex<MidiDevice>>,
+    channel: Channel,
+}
+
+impl AssignmentDisplay {
+    // The left digit is driven by CC 0x4B and the right digit by CC 0x4A
+    fn set(&mut self, text: [char; 2]) -> Result<(), MidiError> {
+        for (controller_number, c) in [(0x4B, text[0]), (0x4A, text[1])] {
+            ControlChangeBuilder {
+                device: &mut self.base.lock().unwrap(),
+                spec: ControlChange {
+                    channel: self.channel.get(),
+                    controller_number,
+                },
+            }
+            .set(seven_segment_code(c))?;
+        }
+        Ok(())
+    }
+}
+
+pub struct ScribbleStrip {
+    base: Arc<Mutex<MidiDevice>>,
+    model: Model,
+    strip: u8,
+}
+
+impl Set<&ScribbleStripMsg> for ScribbleStrip {
+    type Error = MidiError;
+    fn set(&mut self, msg: &ScribbleStripMsg) -> Result<(), Self::Error> {
+        self.base
+            .lock()
+            .unwrap()
+            .send_sysex(&model_scribble_strip_sysex(self.model, self.strip, msg))
+    }
+}
+
+// Looks up the strip for a channel, dropping messages that address a channel the surface doesn't
+// have instead of panicking on the index.
+fn strip_mut<T>(strips: &mut [T], idx: ChannelIndex) -> Option<&mut T> {
+    match idx.within(strips.len()) {
+        Ok(idx) => strips.get_mut(idx.get()),
+        Err(err) => {
+            warn!("Dropping message for invalid channel: {:?}", err);
+            None
+        }
+    }
+}
+
 pub struct XTouchBuilder {
     pub base: Arc<Mutex<MidiDevice>>,
     pub num_channels: usize,
+    pub device_id: DeviceId,
+    /// The channel the unit's master fader talks on, if it has one. Units chained on a port share
+    /// the one from [`DeviceId::master_fader_channel`].
+    pub master_fader_channel: Option<u8>,
+    pub model: Model,
 }
 
 impl XTouchBuilder {
-    pub fn build(self, input: Receiver<XTouchDownstreamMsg>, upstream: Sender<XTouchUpstreamMsg>) {
+    /// Binds the unit's controls on its port and starts driving it from `input`, sending what's
+    /// done on it to `upstream`. Fails if the unit has more strips than a channel index can tell
+    /// apart, or if its device ID puts them past the port's last channel.
+    pub fn build(
+        self,
+        input: Receiver<XTouchDownstreamMsg>,
+        upstream: Sender<XTouchUpstreamMsg>,
+    ) -> Result<(), MidiError> {
+        let strips = (0..self.num_channels)
+            .map(ChannelIndex::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(MidiError::InvalidChannels)?;
+        let first = self
+            .device_id
+            .first_channel(self.num_channels)
+            .ok_or(MidiError::InvalidDeviceId(self.device_id.0))?;
+        let channel = |idx: usize| Channel::new(first + idx as u8);
         let mut faders = Vec::with_capacity(self.num_channels);
-        for i in 0..self.num_channels {
+        for &idx in &strips {
+            let i = idx.get();
             let mut f = Fader {
                 base: self.base.clone(),
-                channel: Channel::new(i as u8),
+                channel: channel(i),
             };
             let upstream_fader = upstream.clone();
             f.bind(move |value| {
                 let _ = upstream_fader.send(XTouchUpstreamMsg::from(FaderAbsMsg {
-                    idx: i as i32,
-                    value: value as f64 / 16383.0, // TODO: check this...
+                    idx,
+                    value: fader_from_midi(value),
                 }));
             });
             faders.push(f);
         }
+        let master_fader = self.master_fader_channel.map(|master_channel| {
+            let mut f = Fader {
+                base: self.base.clone(),
+                channel: Channel::new(master_channel),
+            };
+            let upstream_master = upstream.clone();
+            f.bind(move |value| {
+                let _ = upstream_master.send(XTouchUpstreamMsg::from(MasterFaderAbsMsg {
+                    value: fader_from_midi(value),
+                }));
+            });
+            f
+        });
+        let mut fader_touches = Vec::with_capacity(self.num_channels);
+        for &idx in &strips {
+            let i = idx.get();
+            let mut b = Button {
+                base: self.base.clone(),
+                channel: channel(i),
+                midi_note: 0x68 + i as u8,
+            };
+            let upstream_touch = upstream.clone();
+            b.bind_press(move |_velocity| {
+                let _ = upstream_touch.send(XTouchUpstreamMsg::from(FaderTouchMsg { idx }));
+            });
+            let upstream_release = upstream.clone();
+            b.bind_release(move |_velocity| {
+                let _ = upstream_release.send(XTouchUpstreamMsg::from(FaderReleaseMsg { idx }));
+            });
+            fader_touches.push(b);
+        }
         let mut encoders = Vec::with_capacity(self.num_channels);
-        for i in 0..self.num_channels {
+        for &idx in &strips {
+            let i = idx.get();
             let mut e = Encoder {
                 base: self.base.clone(),
-                channel: Channel::new(i as u8),
+                channel: channel(i),
                 knob_cc: 0x16 + i as u8,
                 button_note: 0x32 + i as u8,
                 led_cc_1: 0x48 + i as u8,
                 led_cc_2: 0x56 + i as u8,
             };
             let upstream_turn = upstream.clone();
+            // Turns are relative: the low bits are how far the knob moved and bit 6 is the
+            // direction. We only report the direction.
             e.bind_turn(move |value| match value {
-                1 => upstream_turn
-                    .send(XTouchUpstreamMsg::from(EncoderTurnCW { idx: i as i32 }))
+                1..=63 => upstream_turn
+                    .send(XTouchUpstreamMsg::from(EncoderTurnCW { idx }))
                     .unwrap(),
-                65 => upstream_turn
-                    .send(XTouchUpstreamMsg::from(EncoderTurnCCW { idx: i as i32 }))
+                65..=127 => upstream_turn
+                    .send(XTouchUpstreamMsg::from(EncoderTurnCCW { idx }))
                     .unwrap(),
-                _ => panic!("Unexpected encoder turn value: {}", value),
+                _ => warn!("Unexpected encoder turn value: {}", value),
             });
             let upstream_press = upstream.clone();
             e.bind_press(move |_value| {
                 upstream_press
-                    .send(XTouchUpstreamMsg::from(EncoderPressMsg { idx: i as i32 }))
+                    .send(XTouchUpstreamMsg::from(EncoderPressMsg { idx }))
                     .unwrap();
             });
             let upstream_release = upstream.clone();
             e.bind_release(move |_value| {
                 upstream_release
-                    .send(XTouchUpstreamMsg::from(EncoderReleaseMsg { idx: i as i32 }))
+                    .send(XTouchUpstreamMsg::from(EncoderReleaseMsg { idx }))
                     .unwrap();
             });
             encoders.push(e);
         }
         let mut mutes = Vec::with_capacity(self.num_channels);
-        for i in 0..self.num_channels {
+        for &idx in &strips {
+            let i = idx.get();
             // TODO: repeat this for the other button types
             let mut b = Button {
                 base: self.base.clone(),
-                channel: Channel::new(i as u8),
-                midi_note: 0x16 + i as u8,
+                channel: channel(i),
+                midi_note: 0x10 + i as u8,
             };
             let upstream_press = upstream.clone();
             b.bind_press(move |_velocity| {
-                let _ = upstream_press.send(XTouchUpstreamMsg::from(MutePress { idx: i as i32 }));
+                let _ = upstream_press.send(XTouchUpstreamMsg::from(MutePress { idx }));
             });
             let upstream_release = upstream.clone();
             b.bind_release(move |_velocity| {
-                let _ =
-                    upstream_release.send(XTouchUpstreamMsg::from(MuteRelease { idx: i as i32 }));
+                let _ = upstream_release.send(XTouchUpstreamMsg::from(MuteRelease { idx }));
             });
             mutes.push(b);
         }
         let mut solos = Vec::with_capacity(self.num_channels);
-        for i in 0..self.num_channels {
+        for &idx in &strips {
+            let i = idx.get();
             let mut b = Button {
                 base: self.base.clone(),
-                channel: Channel::new(i as u8),
+                channel: channel(i),
                 midi_note: 0x08 + i as u8,
             };
             let upstream_press = upstream.clone();
             b.bind_press(move |_velocity| {
-                let _ = upstream_press.send(XTouchUpstreamMsg::from(SoloPress { idx: i as i32 }));
+                let _ = upstream_press.send(XTouchUpstreamMsg::from(SoloPress { idx }));
             });
             let upstream_release = upstream.clone();
             b.bind_release(move |_velocity| {
-                let _ =
-                    upstream_release.send(XTouchUpstreamMsg::from(SoloRelease { idx: i as i32 }));
+                let _ = upstream_release.send(XTouchUpstreamMsg::from(SoloRelease { idx }));
             });
             solos.push(b);
         }
         let mut arms = Vec::with_capacity(self.num_channels);
-        for i in 0..self.num_channels {
+        for &idx in &strips {
+            let i = idx.get();
             let mut b = Button {
                 base: self.base.clone(),
-                channel: Channel::new(i as u8),
+                channel: channel(i),
                 midi_note: i as u8,
             };
             let upstream_press = upstream.clone();
             b.bind_press(move |_velocity| {
-                let _ = upstream_press.send(XTouchUpstreamMsg::from(ArmPress { idx: i as i32 }));
+                let _ = upstream_press.send(XTouchUpstreamMsg::from(ArmPress { idx }));
             });
             let upstream_release = upstream.clone();
             b.bind_release(move |_velocity| {
-                let _ =
-                    upstream_release.send(XTouchUpstreamMsg::from(ArmRelease { idx: i as i32 }));
+                let _ = upstream_release.send(XTouchUpstreamMsg::from(ArmRelease { idx }));
             });
             arms.push(b);
         }
         let mut selects = Vec::with_capacity(self.num_channels);
-        for i in 0..self.num_channels {
+        for &idx in &strips {
+            let i = idx.get();
             let mut b = Button {
                 base: self.base.clone(),
-                channel: Channel::new(i as u8),
-                midi_note: 0x24 + i as u8,
+                channel: channel(i),
+                midi_note: 0x18 + i as u8,
             };
             let upstream_press = upstream.clone();
             b.bind_press(move |_velocity| {
-                let _ = upstream_press.send(XTouchUpstreamMsg::from(ArmPress { idx: i as i32 }));
+                let _ = upstream_press.send(XTouchUpstreamMsg::from(SelectPress { idx }));
             });
             let upstream_release = upstream.clone();
             b.bind_release(move |_velocity| {
-                let _ =
-                    upstream_release.send(XTouchUpstreamMsg::from(ArmRelease { idx: i as i32 }));
+                let _ = upstream_release.send(XTouchUpstreamMsg::from(SelectRelease { idx }));
             });
             selects.push(b);
         }
 
+        // The fader bank buttons aren't part of a strip, so they live on the unit's first channel
+        let mut bank_left = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x2E,
+        };
+        let upstream_press = upstream.clone();
+        bank_left.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::BankLeftPress);
+        });
+        let upstream_release = upstream.clone();
+        bank_left.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::BankLeftRelease);
+        });
+        let mut bank_right = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x2F,
+        };
+        let upstream_press = upstream.clone();
+        bank_right.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::BankRightPress);
+        });
+        let upstream_release = upstream.clone();
+        bank_right.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::BankRightRelease);
+        });
+
+        // The encoder assign buttons pick what the modes put on the strips
+        let mut track = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x28,
+        };
+        let upstream_press = upstream.clone();
+        track.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::TrackPress);
+        });
+        let upstream_release = upstream.clone();
+        track.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::TrackRelease);
+        });
+        let mut send = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x29,
+        };
+        let upstream_press = upstream.clone();
+        send.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::SendPress);
+        });
+        let upstream_release = upstream.clone();
+        send.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::SendRelease);
+        });
+        let mut pan = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x2A,
+        };
+        let upstream_press = upstream.clone();
+        pan.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::PanPress);
+        });
+        let upstream_release = upstream.clone();
+        pan.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::PanRelease);
+        });
+        let mut plugin = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x2B,
+        };
+        let upstream_press = upstream.clone();
+        plugin.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::PluginPress);
+        });
+        let upstream_release = upstream.clone();
+        plugin.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::PluginRelease);
+        });
+        let mut eq = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x2C,
+        };
+        let upstream_press = upstream.clone();
+        eq.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::EQPress);
+        });
+        let upstream_release = upstream.clone();
+        eq.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::EQRelease);
+        });
+        let mut inst = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x2D,
+        };
+        let upstream_press = upstream.clone();
+        inst.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::InstPress);
+        });
+        let upstream_release = upstream.clone();
+        inst.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::InstRelease);
+        });
+
+        let mut shift = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x46,
+        };
+        let upstream_press = upstream.clone();
+        shift.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::ShiftPress);
+        });
+        let upstream_release = upstream.clone();
+        shift.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::ShiftRelease);
+        });
+
+        let mut function_keys = Vec::with_capacity(NUM_FUNCTION_KEYS as usize);
+        for idx in 0..NUM_FUNCTION_KEYS {
+            let mut b = Button {
+                base: self.base.clone(),
+                channel: channel(0),
+                midi_note: 0x36 + idx,
+            };
+            let upstream_press = upstream.clone();
+            b.bind_press(move |_velocity| {
+                let _ = upstream_press.send(XTouchUpstreamMsg::from(FunctionPress { idx }));
+            });
+            let upstream_release = upstream.clone();
+            b.bind_release(move |_velocity| {
+                let _ = upstream_release.send(XTouchUpstreamMsg::from(FunctionRelease { idx }));
+            });
+            function_keys.push(b);
+        }
+
+        // Redo doesn't have a button of its own, so it takes the Cancel button next to Undo
+        let mut undo = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x51,
+        };
+        let upstream_press = upstream.clone();
+        undo.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::UndoPress);
+        });
+        let upstream_release = upstream.clone();
+        undo.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::UndoRelease);
+        });
+        let mut redo = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x52,
+        };
+        let upstream_press = upstream.clone();
+        redo.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::RedoPress);
+        });
+        let upstream_release = upstream.clone();
+        redo.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::RedoRelease);
+        });
+        // Resyncing with Reaper doesn't have a button of its own either, so it takes Enter
+        let mut resync = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x53,
+        };
+        let upstream_press = upstream.clone();
+        resync.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::ResyncPress);
+        });
+        let upstream_release = upstream.clone();
+        resync.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::ResyncRelease);
+        });
+
+        // The transport section is on the unit's first channel too
+        let mut rewind = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x5B,
+        };
+        let upstream_press = upstream.clone();
+        rewind.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::RewindPress);
+        });
+        let upstream_release = upstream.clone();
+        rewind.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::RewindRelease);
+        });
+        let mut fast_forward = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x5C,
+        };
+        let upstream_press = upstream.clone();
+        fast_forward.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::FastForwardPress);
+        });
+        let upstream_release = upstream.clone();
+        fast_forward.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::FastForwardRelease);
+        });
+        let mut stop = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x5D,
+        };
+        let upstream_press = upstream.clone();
+        stop.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::StopPress);
+        });
+        let upstream_release = upstream.clone();
+        stop.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::StopRelease);
+        });
+        let mut play = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x5E,
+        };
+        let upstream_press = upstream.clone();
+        play.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::PlayPress);
+        });
+        let upstream_release = upstream.clone();
+        play.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::PlayRelease);
+        });
+        let mut record = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x5F,
+        };
+        let upstream_press = upstream.clone();
+        record.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::RecordPress);
+        });
+        let upstream_release = upstream.clone();
+        record.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::RecordRelease);
+        });
+        let mut cycle = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x56,
+        };
+        let upstream_press = upstream.clone();
+        cycle.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::CyclePress);
+        });
+        let upstream_release = upstream.clone();
+        cycle.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::CycleRelease);
+        });
+        let mut scrub = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x65,
+        };
+        let upstream_press = upstream.clone();
+        scrub.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::ScrubPress);
+        });
+        let upstream_release = upstream.clone();
+        scrub.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::ScrubRelease);
+        });
+        let mut zoom = Button {
+            base: self.base.clone(),
+            channel: channel(0),
+            midi_note: 0x64,
+        };
+        let upstream_press = upstream.clone();
+        zoom.bind_press(move |_velocity| {
+            let _ = upstream_press.send(XTouchUpstreamMsg::ZoomPress);
+        });
+        let upstream_release = upstream.clone();
+        zoom.bind_release(move |_velocity| {
+            let _ = upstream_release.send(XTouchUpstreamMsg::ZoomRelease);
+        });
+        let mut action_buttons = BTreeMap::new();
+        for button in ActionButton::ALL {
+            let mut b = Button {
+                base: self.base.clone(),
+                channel: channel(0),
+                midi_note: button.midi_note(),
+            };
+            let upstream_press = upstream.clone();
+            b.bind_press(move |_velocity| {
+                let _ = upstream_press.send(XTouchUpstreamMsg::from(ActionPress { button }));
+            });
+            let upstream_release = upstream.clone();
+            b.bind_release(move |_velocity| {
+                let _ = upstream_release.send(XTouchUpstreamMsg::from(ActionRelease { button }));
+            });
+            action_buttons.insert(button, b);
+        }
+        let mut jog_wheel = JogWheel {
+            base: self.base.clone(),
+            channel: channel(0),
+        };
+        let upstream_jog = upstream.clone();
+        // Like the encoders, the wheel reports relative turns and we only report the direction
+        jog_wheel.bind_turn(move |value| match value {
+            1..=63 => {
+                let _ = upstream_jog.send(XTouchUpstreamMsg::JogCW);
+            }
+            65..=127 => {
+                let _ = upstream_jog.send(XTouchUpstreamMsg::JogCCW);
+            }
+            _ => warn!("Unexpected jog wheel value: {}", value),
+        });
+
+        let scribble_strips = (0..self.num_channels)
+            .map(|i| ScribbleStrip {
+                base: self.base.clone(),
+                model: self.model,
+                strip: i as u8,
+            })
+            .collect();
+
+        let assignment_display = AssignmentDisplay {
+            base: self.base.clone(),
+            channel: channel(0),
+        };
+
         let mut xtouch = XTouch {
             input,
             upstream,
             faders,
+            master_fader,
+            fader_touches,
             encoders,
             mutes,
             solos,
             arms,
             selects,
+            bank_left,
+            bank_right,
+            track,
+            send,
+            pan,
+            plugin,
+            eq,
+            inst,
+            shift,
+            function_keys,
+            undo,
+            redo,
+            resync,
+            rewind,
+            fast_forward,
+            stop,
+            play,
+            record,
+            cycle,
+            scrub,
+            zoom,
+            action_buttons,
+            jog_wheel,
+            scribble_strips,
+            assignment_display,
         };
 
         thread::spawn(move || {
-            loop {
-                if let Ok(msg) = xtouch.input.recv() {
-                    match msg {
-                        XTouchDownstreamMsg::Barrier(barrier_msg) => {
-                            let _ = xtouch
-                                .upstream
-                                .send(XTouchUpstreamMsg::Barrier(barrier_msg));
-                        }
-                        XTouchDownstreamMsg::FaderAbs(fader_msg) => {
-                            xtouch.faders[fader_msg.idx as usize]
-                                .set((fader_msg.value * 16383.0) as i32) // TODO: check this...
-                                .unwrap();
-                        }
-                        XTouchDownstreamMsg::EncoderRingLED(encoder_led_msg) => {
-                            match encoder_led_msg {
-                                EncoderRingLEDMsg::Blank(blank_msg) => {
-                                    xtouch.encoders[blank_msg.idx as usize].set(0, 0).unwrap();
-                                }
-                                EncoderRingLEDMsg::AllSegments(all_msg) => {
-                                    xtouch.encoders[all_msg.idx as usize].set(127, 127).unwrap();
-                                }
-                                EncoderRingLEDMsg::RangePoint(range_msg) => {
-                                    let (val1, val2) =
-                                        encoder_led_mappings::range_point(range_msg.pos);
-                                    xtouch.encoders[range_msg.idx as usize]
-                                        .set(val1, val2)
-                                        .unwrap();
-                                }
-                                EncoderRingLEDMsg::RangeFill(fill_msg) => {
-                                    let (val1, val2) =
-                                        encoder_led_mappings::range_fill(fill_msg.pos);
-                                    xtouch.encoders[fill_msg.idx as usize]
-                                        .set(val1, val2)
-                                        .unwrap();
-                                }
-                                EncoderRingLEDMsg::Edges(edges_msg) => {
-                                    xtouch.encoders[edges_msg.idx as usize].set(1, 32).unwrap();
-                                }
-                            }
-                        }
-                        XTouchDownstreamMsg::MuteLED(mute_msg) => {
-                            xtouch.mutes[mute_msg.idx as usize]
-                                .set(mute_msg.state)
-                                .unwrap();
-                        }
-                        XTouchDownstreamMsg::SoloLED(solo_msg) => {
-                            xtouch.solos[solo_msg.idx as usize]
-                                .set(solo_msg.state)
-                                .unwrap();
-                        }
-                        XTouchDownstreamMsg::ArmLED(arm_msg) => {
-                            xtouch.arms[arm_msg.idx as usize]
-                                .set(arm_msg.state)
-                                .unwrap();
-                        }
-                        XTouchDownstreamMsg::SelectLED(select_msg) => {
-                            xtouch.selects[select_msg.idx as usize]
-                                .set(select_msg.state)
-                                .unwrap();
-                        }
-                        _ => panic!("Message {:?} implemented yet!", msg),
-                    }
+            // Runs until the downstream sender goes away, e.g. when the device is reconnected, or
+            // until the device does. Letting go of the input then tells whoever drives it.
+            while let Ok(msg) = xtouch.input.recv() {
+                if let Err(e) = xtouch.show(msg) {
+                    warn!("Couldn't write to the XTouch, letting it go: {:?}", e);
+                    break;
                 }
             }
         });
+        Ok(())
+    }
+}
+
+/// Handle to one unit created by [`XTouchDemuxBuilder`].
+pub struct XTouchUnit {
+    pub device_id: DeviceId,
+    pub downstream: Sender<XTouchDownstreamMsg>,
+    pub upstream: Receiver<XTouchUpstreamMsg>,
+}
+
+/// Builds one XTouch instance per device ID on a shared MIDI port.
+///
+/// Each unit binds only its own block of channels, so what the port hears is routed to the unit
+/// that owns its channel, and each unit looks like a standalone surface to whatever aggregates
+/// them. The first unit gets the chain's master fader, past every unit's block. Like
+/// [`XTouchBuilder`], this leaves keeping the port alive to whoever connected it.
+pub struct XTouchDemuxBuilder {
+    pub base: Arc<Mutex<MidiDevice>>,
+    pub num_channels: usize,
+    /// The units on the port, in the order the surface lays them out
+    pub units: Vec<(DeviceId, Model)>,
+}
+
+impl XTouchDemuxBuilder {
+    /// Fails if two units share a device ID, or one's channels don't fit on the port.
+    pub fn build(self) -> Result<Vec<XTouchUnit>, MidiError> {
+        for (i, (device_id, _)) in self.units.iter().enumerate() {
+            let shared = self.units[..i].iter().any(|(other, _)| other == device_id);
+            if shared || device_id.first_channel(self.num_channels).is_none() {
+                return Err(MidiError::InvalidDeviceId(device_id.0));
+            }
+        }
+        let master_fader_channel = self
+            .units
+            .iter()
+            .map(|&(device_id, _)| device_id)
+            .max_by_key(|device_id| device_id.0)
+            .and_then(|last| last.master_fader_channel(self.num_channels));
+        let mut units = Vec::with_capacity(self.units.len());
+        for (i, (device_id, model)) in self.units.into_iter().enumerate() {
+            let (downstream_tx, downstream_rx) = bounded(128);
+            let (upstream_tx, upstream_rx) = bounded(128);
+            XTouchBuilder {
+                base: self.base.clone(),
+                num_channels: self.num_channels,
+                device_id,
+                master_fader_channel: master_fader_channel.filter(|_| i == 0),
+                model,
+            }
+            .build(downstream_rx, upstream_tx)?;
+            units.push(XTouchUnit {
+                device_id,
+                downstream: downstream_tx,
+                upstream: upstream_rx,
+            });
+        }
+        Ok(units)
     }
 }
 
 pub struct XTouch {
     pub faders: Vec<Fader>,
+    pub master_fader: Option<Fader>,
+    pub fader_touches: Vec<Button>,
     pub encoders: Vec<Encoder>,
     pub mutes: Vec<Button>,
     pub solos: Vec<Button>,
     pub arms: Vec<Button>,
     pub selects: Vec<Button>,
+    pub bank_left: Button,
+    pub bank_right: Button,
+    pub track: Button,
+    pub send: Button,
+    pub pan: Button,
+    pub plugin: Button,
+    pub eq: Button,
+    pub inst: Button,
+    pub shift: Button,
+    pub function_keys: Vec<Button>,
+    pub undo: Button,
+    pub redo: Button,
+    pub resync: Button,
+    pub rewind: Button,
+    pub fast_forward: Button,
+    pub stop: Button,
+    pub play: Button,
+    pub record: Button,
+    pub cycle: Button,
+    pub scrub: Button,
+    pub zoom: Button,
+    pub action_buttons: BTreeMap<ActionButton, Button>,
+    pub jog_wheel: JogWheel,
+    pub scribble_strips: Vec<ScribbleStrip>,
+    pub assignment_display: AssignmentDisplay,
     input: Receiver<XTouchDownstreamMsg>,
     upstream: Sender<XTouchUpstreamMsg>,
 }
+
+impl XTouch {
+    // Writes what `msg` says to show to the device, failing once the device can't be written to
+    fn show(&mut self, msg: XTouchDownstreamMsg) -> Result<(), MidiError> {
+        match msg {
+            XTouchDownstreamMsg::Barrier(barrier_msg) => {
+                let _ = self.upstream.send(XTouchUpstreamMsg::Barrier(barrier_msg));
+            }
+            // Everything ahead of it has been written to the MIDI port by now
+            XTouchDownstreamMsg::Probe(probe) => probe.arrived(),
+            XTouchDownstreamMsg::FaderAbs(fader_msg) => {
+                if let Some(fader) = strip_mut(&mut self.faders, fader_msg.idx) {
+                    fader.set(fader_to_midi(fader_msg.value))?;
+                }
+            }
+            XTouchDownstreamMsg::MasterFaderAbs(master_msg) => {
+                if let Some(fader) = self.master_fader.as_mut() {
+                    fader.set(fader_to_midi(master_msg.value))?;
+                }
+            }
+            XTouchDownstreamMsg::EncoderRingLED(encoder_led_msg) => match encoder_led_msg {
+                EncoderRingLEDMsg::Blank(blank_msg) => {
+                    if let Some(strip) = strip_mut(&mut self.encoders, blank_msg.idx) {
+                        strip.set(0, 0)?;
+                    }
+                }
+                EncoderRingLEDMsg::AllSegments(all_msg) => {
+                    if let Some(strip) = strip_mut(&mut self.encoders, all_msg.idx) {
+                        strip.set(127, 127)?;
+                    }
+                }
+                EncoderRingLEDMsg::RangePoint(range_msg) => {
+                    let (val1, val2) = encoder_led_mappings::range_point(range_msg.pos);
+                    if let Some(strip) = strip_mut(&mut self.encoders, range_msg.idx) {
+                        strip.set(val1, val2)?;
+                    }
+                }
+                EncoderRingLEDMsg::RangeFill(fill_msg) => {
+                    let (val1, val2) = encoder_led_mappings::range_fill(fill_msg.pos);
+                    if let Some(strip) = strip_mut(&mut self.encoders, fill_msg.idx) {
+                        strip.set(val1, val2)?;
+                    }
+                }
+                EncoderRingLEDMsg::Edges(edges_msg) => {
+                    if let Some(strip) = strip_mut(&mut self.encoders, edges_msg.idx) {
+                        strip.set(1, 32)?;
+                    }
+                }
+            },
+            XTouchDownstreamMsg::MuteLED(mute_msg) => {
+                if let Some(strip) = strip_mut(&mut self.mutes, mute_msg.idx) {
+                    strip.set(mute_msg.state)?;
+                }
+            }
+            XTouchDownstreamMsg::SoloLED(solo_msg) => {
+                if let Some(strip) = strip_mut(&mut self.solos, solo_msg.idx) {
+                    strip.set(solo_msg.state)?;
+                }
+            }
+            XTouchDownstreamMsg::ArmLED(arm_msg) => {
+                if let Some(strip) = strip_mut(&mut self.arms, arm_msg.idx) {
+                    strip.set(arm_msg.state)?;
+                }
+            }
+            XTouchDownstreamMsg::SelectLED(select_msg) => {
+                if let Some(strip) = strip_mut(&mut self.selects, select_msg.idx) {
+                    strip.set(select_msg.state)?;
+                }
+            }
+            XTouchDownstreamMsg::ScribbleStrip(scribble_msg) => {
+                if let Some(strip) = strip_mut(&mut self.scribble_strips, scribble_msg.idx) {
+                    strip.set(&scribble_msg)?;
+                }
+            }
+            XTouchDownstreamMsg::AssignmentDisplay(display_msg) => {
+                self.assignment_display.set(display_msg.text)?;
+            }
+            XTouchDownstreamMsg::Track(state) => {
+                self.track.set(state)?;
+            }
+            XTouchDownstreamMsg::Send(state) => {
+                self.send.set(state)?;
+            }
+            XTouchDownstreamMsg::Pan(state) => {
+                self.pan.set(state)?;
+            }
+            XTouchDownstreamMsg::Plugin(state) => {
+                self.plugin.set(state)?;
+            }
+            XTouchDownstreamMsg::EQ(state) => {
+                self.eq.set(state)?;
+            }
+            XTouchDownstreamMsg::Inst(state) => {
+                self.inst.set(state)?;
+            }
+            XTouchDownstreamMsg::Rewind(state) => {
+                self.rewind.set(state)?;
+            }
+            XTouchDownstreamMsg::FastForward(state) => {
+                self.fast_forward.set(state)?;
+            }
+            XTouchDownstreamMsg::Stop(state) => {
+                self.stop.set(state)?;
+            }
+            XTouchDownstreamMsg::Play(state) => {
+                self.play.set(state)?;
+            }
+            XTouchDownstreamMsg::Record(state) => {
+                self.record.set(state)?;
+            }
+            XTouchDownstreamMsg::Cycle(state) => {
+                self.cycle.set(state)?;
+            }
+            XTouchDownstreamMsg::Scrub(state) => {
+                self.scrub.set(state)?;
+            }
+            XTouchDownstreamMsg::Zoom(state) => {
+                self.zoom.set(state)?;
+            }
+            XTouchDownstreamMsg::Action(button, state) => {
+                if let Some(button) = self.action_buttons.get_mut(&button) {
+                    button.set(state)?;
+                }
+            }
+            // The surface shows what it has a place for and ignores the rest
+            _ => debug!("Nothing on the XTouch shows {:?}, ignoring it", msg),
+        }
+        Ok(())
+    }
+}
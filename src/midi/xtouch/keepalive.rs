@@ -0,0 +1,150 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::{RecvTimeoutError, bounded};
+use tracing::{debug, info, warn};
+
+use crate::midi::MidiDevice;
+
+/// The ping the host sends to keep the surface out of its "waiting for host" screen. It's the
+/// Mackie Control device query, which the X-Touch accepts in both MC and XCtl modes.
+pub const HOST_PING: [u8; 7] = [0xF0, 0x00, 0x00, 0x66, 0x14, 0x00, 0xF7];
+
+/// The ping an X-Touch in XCtl mode sends about every two seconds.
+pub const XCTL_SURFACE_PING: [u8; 8] = [0xF0, 0x00, 0x20, 0x32, 0x58, 0x54, 0x00, 0xF7];
+
+/// How a surface in MC mode starts its answer to the device query, before its serial number and
+/// a challenge we don't need to answer.
+const MCU_QUERY_REPLY: [u8; 6] = [0xF0, 0x00, 0x00, 0x66, 0x14, 0x01];
+
+/// How often the host pings the surface.
+pub const DEFAULT_PING_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How long the surface can go without pinging back before it's considered offline.
+pub const DEFAULT_SURFACE_TIMEOUT: Duration = Duration::from_secs(6);
+
+/// Whether the surface is answering the host.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SurfaceStatus {
+    Online,
+    Offline,
+}
+
+/// Whether `msg` is the surface letting the host know it's there.
+pub fn is_surface_ping(msg: &[u8]) -> bool {
+    msg == XCTL_SURFACE_PING || msg.starts_with(&MCU_QUERY_REPLY)
+}
+
+/// Tracks whether the surface is online from when it last pinged.
+///
+/// A surface starts out offline, comes online as soon as it's heard from, and goes offline again
+/// once it's been quiet for longer than the timeout.
+#[derive(Debug)]
+pub struct Liveness {
+    timeout: Duration,
+    last_heard: Option<Instant>,
+    status: SurfaceStatus,
+}
+
+impl Liveness {
+    pub fn new(timeout: Duration) -> Self {
+        Liveness {
+            timeout,
+            last_heard: None,
+            status: SurfaceStatus::Offline,
+        }
+    }
+
+    pub fn status(&self) -> SurfaceStatus {
+        self.status
+    }
+
+    /// Notes a ping from the surface, returning the new status if this brought it online.
+    pub fn heard_from(&mut self, at: Instant) -> Option<SurfaceStatus> {
+        self.last_heard = Some(at);
+        self.set(SurfaceStatus::Online)
+    }
+
+    /// Returns the new status if the surface has just gone quiet for too long.
+    pub fn check(&mut self, now: Instant) -> Option<SurfaceStatus> {
+        match self.last_heard {
+            Some(at) if now.saturating_duration_since(at) > self.timeout => {
+                self.set(SurfaceStatus::Offline)
+            }
+            _ => None,
+        }
+    }
+
+    fn set(&mut self, status: SurfaceStatus) -> Option<SurfaceStatus> {
+        match self.status == status {
+            true => None,
+            false => {
+                self.status = status;
+                Some(status)
+            }
+        }
+    }
+}
+
+/// Pings the surface to keep it connected to the host, and reports when it comes and goes.
+pub struct KeepAlive {
+    pub ping_interval: Duration,
+    pub surface_timeout: Duration,
+}
+
+impl Default for KeepAlive {
+    fn default() -> Self {
+        KeepAlive {
+            ping_interval: DEFAULT_PING_INTERVAL,
+            surface_timeout: DEFAULT_SURFACE_TIMEOUT,
+        }
+    }
+}
+
+impl KeepAlive {
+    /// Starts pinging the surface on `base`, calling `on_status` whenever it goes online or
+    /// offline. Stops once the device is dropped.
+    pub fn start<F>(self, base: &Arc<Mutex<MidiDevice>>, mut on_status: F)
+    where
+        F: FnMut(SurfaceStatus) + Send + 'static,
+    {
+        let (pings_tx, pings) = bounded(16);
+        base.lock().unwrap().on_sysex(move |msg| {
+            if is_surface_ping(msg) {
+                let _ = pings_tx.try_send(Instant::now());
+            }
+        });
+        let base = Arc::downgrade(base);
+        thread::spawn(move || {
+            let mut liveness = Liveness::new(self.surface_timeout);
+            let mut next_ping = Instant::now();
+            loop {
+                let now = Instant::now();
+                if now >= next_ping {
+                    let Some(device) = base.upgrade() else {
+                        return;
+                    };
+                    if let Err(e) = device.lock().unwrap().send_sysex(&HOST_PING) {
+                        debug!("Couldn't ping the surface: {:?}", e);
+                    }
+                    next_ping = now + self.ping_interval;
+                }
+                let change = match pings.recv_timeout(next_ping.saturating_duration_since(now)) {
+                    Ok(at) => liveness.heard_from(at),
+                    Err(RecvTimeoutError::Timeout) => liveness.check(Instant::now()),
+                    // The device went away along with its callbacks
+                    Err(RecvTimeoutError::Disconnected) => return,
+                };
+                match change {
+                    Some(SurfaceStatus::Online) => info!("The surface is online"),
+                    Some(SurfaceStatus::Offline) => warn!("The surface stopped answering"),
+                    None => {}
+                }
+                if let Some(status) = change {
+                    on_status(status);
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,580 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::{Receiver, Sender};
+use tracing::debug;
+
+use crate::midi::xtouch::{
+    ActionButton, ActionPress, ActionRelease, ArmPress, ArmRelease, ChannelIndex, EncoderPressMsg,
+    EncoderReleaseMsg, EncoderRingLEDMsg, EncoderTurnCCW, EncoderTurnCW, FaderAbsMsg,
+    FaderReleaseMsg, FaderTouchMsg, FunctionPress, FunctionRelease, LEDState, MasterFaderAbsMsg,
+    MutePress, MuteRelease, ScribbleColor, SelectPress, SelectRelease, SoloPress, SoloRelease,
+    XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
+
+/// What an encoder's LED ring is showing.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EncoderRing {
+    Blank,
+    AllSegments,
+    Point(f32),
+    Fill(f32),
+    Edges,
+}
+
+impl From<EncoderRingLEDMsg> for EncoderRing {
+    fn from(msg: EncoderRingLEDMsg) -> Self {
+        match msg {
+            EncoderRingLEDMsg::Blank(_) => EncoderRing::Blank,
+            EncoderRingLEDMsg::AllSegments(_) => EncoderRing::AllSegments,
+            EncoderRingLEDMsg::RangePoint(msg) => EncoderRing::Point(msg.pos),
+            EncoderRingLEDMsg::RangeFill(msg) => EncoderRing::Fill(msg.pos),
+            EncoderRingLEDMsg::Edges(_) => EncoderRing::Edges,
+        }
+    }
+}
+
+/// Everything one channel strip is showing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChannelState {
+    pub fader: f64,
+    pub encoder_ring: EncoderRing,
+    pub mute: LEDState,
+    pub solo: LEDState,
+    pub arm: LEDState,
+    pub select: LEDState,
+    pub top_line: String,
+    pub bottom_line: String,
+    pub color: ScribbleColor,
+}
+
+impl Default for ChannelState {
+    fn default() -> Self {
+        ChannelState {
+            fader: 0.0,
+            encoder_ring: EncoderRing::Blank,
+            mute: LEDState::Off,
+            solo: LEDState::Off,
+            arm: LEDState::Off,
+            select: LEDState::Off,
+            top_line: String::new(),
+            bottom_line: String::new(),
+            color: ScribbleColor::Off,
+        }
+    }
+}
+
+/// The buttons outside the channel strips.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SurfaceButton {
+    // Encoder assign
+    Track,
+    Pan,
+    EQ,
+    Send,
+    Plugin,
+    Inst,
+
+    // View
+    Global,
+    MIDITracks,
+    Inputs,
+    AudioTracks,
+    AudioInst,
+    Aux,
+    Buses,
+    Outputs,
+    User,
+
+    // Fader bank
+    BankLeft,
+    BankRight,
+
+    // Modifiers
+    Shift,
+
+    /// F1 to F8, numbered from 0
+    Function(u8),
+
+    // Utility
+    Undo,
+    Redo,
+    Resync,
+
+    // Transport
+    Rewind,
+    FastForward,
+    Stop,
+    Play,
+    Record,
+    Cycle,
+
+    // Jog wheel
+    Scrub,
+    Zoom,
+
+    /// The buttons left to run Reaper actions
+    Action(ActionButton),
+}
+
+impl SurfaceButton {
+    fn press_msg(self) -> XTouchUpstreamMsg {
+        match self {
+            SurfaceButton::Track => XTouchUpstreamMsg::TrackPress,
+            SurfaceButton::Pan => XTouchUpstreamMsg::PanPress,
+            SurfaceButton::EQ => XTouchUpstreamMsg::EQPress,
+            SurfaceButton::Send => XTouchUpstreamMsg::SendPress,
+            SurfaceButton::Plugin => XTouchUpstreamMsg::PluginPress,
+            SurfaceButton::Inst => XTouchUpstreamMsg::InstPress,
+            SurfaceButton::Global => XTouchUpstreamMsg::GlobalPress,
+            SurfaceButton::MIDITracks => XTouchUpstreamMsg::MIDITracksPress,
+            SurfaceButton::Inputs => XTouchUpstreamMsg::InputsPress,
+            SurfaceButton::AudioTracks => XTouchUpstreamMsg::AudioTracksPress,
+            SurfaceButton::AudioInst => XTouchUpstreamMsg::AudioInstPress,
+            SurfaceButton::Aux => XTouchUpstreamMsg::AuxPress,
+            SurfaceButton::Buses => XTouchUpstreamMsg::BusesPress,
+            SurfaceButton::Outputs => XTouchUpstreamMsg::OutputsPress,
+            SurfaceButton::User => XTouchUpstreamMsg::UserPress,
+            SurfaceButton::BankLeft => XTouchUpstreamMsg::BankLeftPress,
+            SurfaceButton::BankRight => XTouchUpstreamMsg::BankRightPress,
+            SurfaceButton::Shift => XTouchUpstreamMsg::ShiftPress,
+            SurfaceButton::Function(idx) => XTouchUpstreamMsg::from(FunctionPress { idx }),
+            SurfaceButton::Undo => XTouchUpstreamMsg::UndoPress,
+            SurfaceButton::Redo => XTouchUpstreamMsg::RedoPress,
+            SurfaceButton::Resync => XTouchUpstreamMsg::ResyncPress,
+            SurfaceButton::Rewind => XTouchUpstreamMsg::RewindPress,
+            SurfaceButton::FastForward => XTouchUpstreamMsg::FastForwardPress,
+            SurfaceButton::Stop => XTouchUpstreamMsg::StopPress,
+            SurfaceButton::Play => XTouchUpstreamMsg::PlayPress,
+            SurfaceButton::Record => XTouchUpstreamMsg::RecordPress,
+            SurfaceButton::Cycle => XTouchUpstreamMsg::CyclePress,
+            SurfaceButton::Scrub => XTouchUpstreamMsg::ScrubPress,
+            SurfaceButton::Zoom => XTouchUpstreamMsg::ZoomPress,
+            SurfaceButton::Action(button) => XTouchUpstreamMsg::from(ActionPress { button }),
+        }
+    }
+
+    fn release_msg(self) -> XTouchUpstreamMsg {
+        match self {
+            SurfaceButton::Track => XTouchUpstreamMsg::TrackRelease,
+            SurfaceButton::Pan => XTouchUpstreamMsg::PanRelease,
+            SurfaceButton::EQ => XTouchUpstreamMsg::EQRelease,
+            SurfaceButton::Send => XTouchUpstreamMsg::SendRelease,
+            SurfaceButton::Plugin => XTouchUpstreamMsg::PluginRelease,
+            SurfaceButton::Inst => XTouchUpstreamMsg::InstRelease,
+            SurfaceButton::Global => XTouchUpstreamMsg::GlobalRelease,
+            SurfaceButton::MIDITracks => XTouchUpstreamMsg::MIDITracksRelease,
+            SurfaceButton::Inputs => XTouchUpstreamMsg::InputsRelease,
+            SurfaceButton::AudioTracks => XTouchUpstreamMsg::AudioTracksRelease,
+            SurfaceButton::AudioInst => XTouchUpstreamMsg::AudioInstRelease,
+            SurfaceButton::Aux => XTouchUpstreamMsg::AuxRelease,
+            SurfaceButton::Buses => XTouchUpstreamMsg::BusesRelease,
+            SurfaceButton::Outputs => XTouchUpstreamMsg::OutputsRelease,
+            SurfaceButton::User => XTouchUpstreamMsg::UserRelease,
+            SurfaceButton::BankLeft => XTouchUpstreamMsg::BankLeftRelease,
+            SurfaceButton::BankRight => XTouchUpstreamMsg::BankRightRelease,
+            SurfaceButton::Shift => XTouchUpstreamMsg::ShiftRelease,
+            SurfaceButton::Function(idx) => XTouchUpstreamMsg::from(FunctionRelease { idx }),
+            SurfaceButton::Undo => XTouchUpstreamMsg::UndoRelease,
+            SurfaceButton::Redo => XTouchUpstreamMsg::RedoRelease,
+            SurfaceButton::Resync => XTouchUpstreamMsg::ResyncRelease,
+            SurfaceButton::Rewind => XTouchUpstreamMsg::RewindRelease,
+            SurfaceButton::FastForward => XTouchUpstreamMsg::FastForwardRelease,
+            SurfaceButton::Stop => XTouchUpstreamMsg::StopRelease,
+            SurfaceButton::Play => XTouchUpstreamMsg::PlayRelease,
+            SurfaceButton::Record => XTouchUpstreamMsg::RecordRelease,
+            SurfaceButton::Cycle => XTouchUpstreamMsg::CycleRelease,
+            SurfaceButton::Scrub => XTouchUpstreamMsg::ScrubRelease,
+            SurfaceButton::Zoom => XTouchUpstreamMsg::ZoomRelease,
+            SurfaceButton::Action(button) => XTouchUpstreamMsg::from(ActionRelease { button }),
+        }
+    }
+}
+
+/// The buttons on each channel strip.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChannelButton {
+    Mute,
+    Solo,
+    Arm,
+    Select,
+    Encoder,
+}
+
+impl ChannelButton {
+    fn press_msg(self, idx: ChannelIndex) -> XTouchUpstreamMsg {
+        match self {
+            ChannelButton::Mute => MutePress { idx }.into(),
+            ChannelButton::Solo => SoloPress { idx }.into(),
+            ChannelButton::Arm => ArmPress { idx }.into(),
+            ChannelButton::Select => SelectPress { idx }.into(),
+            ChannelButton::Encoder => EncoderPressMsg { idx }.into(),
+        }
+    }
+
+    fn release_msg(self, idx: ChannelIndex) -> XTouchUpstreamMsg {
+        match self {
+            ChannelButton::Mute => MuteRelease { idx }.into(),
+            ChannelButton::Solo => SoloRelease { idx }.into(),
+            ChannelButton::Arm => ArmRelease { idx }.into(),
+            ChannelButton::Select => SelectRelease { idx }.into(),
+            ChannelButton::Encoder => EncoderReleaseMsg { idx }.into(),
+        }
+    }
+}
+
+/// Why a line given to [`SimulatedXTouch::run_command`] couldn't be acted out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CommandError {
+    Unknown(String),
+    /// The argument at this position is missing or isn't a number of the right kind
+    InvalidArgument(usize),
+}
+
+// Parses the word at `i` of a command
+fn parse_arg<T: FromStr>(words: &[&str], i: usize) -> Result<T, CommandError> {
+    words
+        .get(i)
+        .and_then(|word| word.parse().ok())
+        .ok_or(CommandError::InvalidArgument(i))
+}
+
+/// Everything the simulated surface is showing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct XTouchState {
+    pub channels: Vec<ChannelState>,
+    pub master_fader: f64,
+    pub assignment_display: [char; 2],
+    /// LEDs of the buttons outside the channel strips; buttons never lit are left out
+    pub buttons: HashMap<SurfaceButton, LEDState>,
+}
+
+impl XTouchState {
+    fn new(num_channels: usize) -> Self {
+        XTouchState {
+            channels: vec![ChannelState::default(); num_channels],
+            master_fader: 0.0,
+            assignment_display: [' ', ' '],
+            buttons: HashMap::new(),
+        }
+    }
+
+    pub fn channel(&self, idx: ChannelIndex) -> &ChannelState {
+        &self.channels[idx.get()]
+    }
+
+    pub fn button(&self, button: SurfaceButton) -> LEDState {
+        self.buttons.get(&button).copied().unwrap_or(LEDState::Off)
+    }
+
+    // Applies one message from the modes. Returns false for channel messages past the last strip,
+    // which a real surface would never see.
+    fn apply(&mut self, msg: XTouchDownstreamMsg) -> bool {
+        let button = match msg {
+            XTouchDownstreamMsg::Barrier(_) | XTouchDownstreamMsg::Probe(_) => return true,
+            XTouchDownstreamMsg::FaderAbs(msg) => {
+                return self.update_channel(msg.idx, |channel| channel.fader = msg.value);
+            }
+            XTouchDownstreamMsg::MasterFaderAbs(msg) => {
+                self.master_fader = msg.value;
+                return true;
+            }
+            XTouchDownstreamMsg::EncoderRingLED(msg) => {
+                let idx = match msg {
+                    EncoderRingLEDMsg::Blank(msg) => msg.idx,
+                    EncoderRingLEDMsg::AllSegments(msg) => msg.idx,
+                    EncoderRingLEDMsg::RangePoint(msg) => msg.idx,
+                    EncoderRingLEDMsg::RangeFill(msg) => msg.idx,
+                    EncoderRingLEDMsg::Edges(msg) => msg.idx,
+                };
+                return self.update_channel(idx, |channel| channel.encoder_ring = msg.into());
+            }
+            XTouchDownstreamMsg::MuteLED(msg) => {
+                return self.update_channel(msg.idx, |channel| channel.mute = msg.state);
+            }
+            XTouchDownstreamMsg::SoloLED(msg) => {
+                return self.update_channel(msg.idx, |channel| channel.solo = msg.state);
+            }
+            XTouchDownstreamMsg::ArmLED(msg) => {
+                return self.update_channel(msg.idx, |channel| channel.arm = msg.state);
+            }
+            XTouchDownstreamMsg::SelectLED(msg) => {
+                return self.update_channel(msg.idx, |channel| channel.select = msg.state);
+            }
+            XTouchDownstreamMsg::ScribbleStrip(msg) => {
+                return self.update_channel(msg.idx, |channel| {
+                    channel.top_line = msg.top_line;
+                    channel.bottom_line = msg.bottom_line;
+                    channel.color = msg.color;
+                });
+            }
+            XTouchDownstreamMsg::AssignmentDisplay(msg) => {
+                self.assignment_display = msg.text;
+                return true;
+            }
+            XTouchDownstreamMsg::Track(state) => (SurfaceButton::Track, state),
+            XTouchDownstreamMsg::Pan(state) => (SurfaceButton::Pan, state),
+            XTouchDownstreamMsg::EQ(state) => (SurfaceButton::EQ, state),
+            XTouchDownstreamMsg::Send(state) => (SurfaceButton::Send, state),
+            XTouchDownstreamMsg::Plugin(state) => (SurfaceButton::Plugin, state),
+            XTouchDownstreamMsg::Inst(state) => (SurfaceButton::Inst, state),
+            XTouchDownstreamMsg::Global(state) => (SurfaceButton::Global, state),
+            XTouchDownstreamMsg::MIDITracks(state) => (SurfaceButton::MIDITracks, state),
+            XTouchDownstreamMsg::Inputs(state) => (SurfaceButton::Inputs, state),
+            XTouchDownstreamMsg::AudioTracks(state) => (SurfaceButton::AudioTracks, state),
+            XTouchDownstreamMsg::AudioInst(state) => (SurfaceButton::AudioInst, state),
+            XTouchDownstreamMsg::Aux(state) => (SurfaceButton::Aux, state),
+            XTouchDownstreamMsg::Buses(state) => (SurfaceButton::Buses, state),
+            XTouchDownstreamMsg::Outputs(state) => (SurfaceButton::Outputs, state),
+            XTouchDownstreamMsg::User(state) => (SurfaceButton::User, state),
+            XTouchDownstreamMsg::Rewind(state) => (SurfaceButton::Rewind, state),
+            XTouchDownstreamMsg::FastForward(state) => (SurfaceButton::FastForward, state),
+            XTouchDownstreamMsg::Stop(state) => (SurfaceButton::Stop, state),
+            XTouchDownstreamMsg::Play(state) => (SurfaceButton::Play, state),
+            XTouchDownstreamMsg::Record(state) => (SurfaceButton::Record, state),
+            XTouchDownstreamMsg::Cycle(state) => (SurfaceButton::Cycle, state),
+            XTouchDownstreamMsg::Scrub(state) => (SurfaceButton::Scrub, state),
+            XTouchDownstreamMsg::Zoom(state) => (SurfaceButton::Zoom, state),
+            XTouchDownstreamMsg::Action(button, state) => (SurfaceButton::Action(button), state),
+        };
+        self.buttons.insert(button.0, button.1);
+        true
+    }
+
+    fn update_channel<F>(&mut self, idx: ChannelIndex, update: F) -> bool
+    where
+        F: FnOnce(&mut ChannelState),
+    {
+        match self.channels.get_mut(idx.get()) {
+            Some(channel) => {
+                update(channel);
+                true
+            }
+            None => false,
+        }
+    }
+}
+/// Draws the surface as text, one line per channel strip.
+impl fmt::Display for XTouchState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let led = |state: LEDState, c: char| match state {
+            LEDState::Off => '.',
+            LEDState::On => c,
+            LEDState::Flash => '*',
+        };
+        writeln!(
+            f,
+            "[{}{}]",
+            self.assignment_display[0], self.assignment_display[1]
+        )?;
+        for (i, channel) in self.channels.iter().enumerate() {
+            writeln!(
+                f,
+                "{} {:<7}|{:<7} {:>5.1}% {}{}{}{} {:?}",
+                i,
+                channel.top_line,
+                channel.bottom_line,
+                channel.fader * 100.0,
+                led(channel.mute, 'M'),
+                led(channel.solo, 'S'),
+                led(channel.arm, 'R'),
+                led(channel.select, 'X'),
+                channel.encoder_ring,
+            )?;
+        }
+        writeln!(f, "master {:>5.1}%", self.master_fader * 100.0)
+    }
+}
+
+/// An in-memory XTouch that sits on the same channels as the real one.
+///
+/// Messages from the modes update a model of the surface instead of hardware, and the methods
+/// standing in for a user's hands send the same upstream messages the real surface would. Like
+/// the real surface, barriers are reflected upstream once every message before them has been
+/// applied.
+#[derive(Clone)]
+pub struct SimulatedXTouch {
+    state: Arc<(Mutex<XTouchState>, Condvar)>,
+    upstream: Sender<XTouchUpstreamMsg>,
+}
+
+impl SimulatedXTouch {
+    pub fn start(
+        num_channels: usize,
+        input: Receiver<XTouchDownstreamMsg>,
+        upstream: Sender<XTouchUpstreamMsg>,
+    ) -> Self {
+        let state = Arc::new((Mutex::new(XTouchState::new(num_channels)), Condvar::new()));
+        thread::spawn({
+            let state = state.clone();
+            let upstream = upstream.clone();
+            move || {
+                while let Ok(msg) = input.recv() {
+                    if let XTouchDownstreamMsg::Barrier(barrier) = msg {
+                        let _ = upstream.send(XTouchUpstreamMsg::Barrier(barrier));
+                        continue;
+                    }
+                    if let XTouchDownstreamMsg::Probe(probe) = &msg {
+                        probe.arrived();
+                        continue;
+                    }
+                    let description = format!("{:?}", msg);
+                    let (model, changed) = &*state;
+                    if !model.lock().unwrap().apply(msg) {
+                        debug!("Simulated XTouch ignoring {}", description);
+                    }
+                    changed.notify_all();
+                }
+            }
+        });
+        SimulatedXTouch { state, upstream }
+    }
+
+    /// A snapshot of what the surface is showing right now.
+    pub fn state(&self) -> XTouchState {
+        self.state.0.lock().unwrap().clone()
+    }
+
+    /// Waits until the surface shows something `predicate` accepts, returning what it showed. Gives
+    /// up after `timeout`, returning None.
+    pub fn wait_until<F>(&self, timeout: Duration, mut predicate: F) -> Option<XTouchState>
+    where
+        F: FnMut(&XTouchState) -> bool,
+    {
+        let deadline = Instant::now() + timeout;
+        let (model, changed) = &*self.state;
+        let mut state = model.lock().unwrap();
+        while !predicate(&state) {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return None;
+            }
+            state = changed.wait_timeout(state, remaining).unwrap().0;
+        }
+        Some(state.clone())
+    }
+
+    /// Moves a fader by hand. The motor would hold it there, so the model follows right away.
+    pub fn move_fader(&self, idx: ChannelIndex, value: f64) {
+        let (model, changed) = &*self.state;
+        if let Some(channel) = model.lock().unwrap().channels.get_mut(idx.get()) {
+            channel.fader = value;
+        }
+        changed.notify_all();
+        self.send(FaderAbsMsg { idx, value }.into());
+    }
+
+    /// Moves the master fader by hand.
+    pub fn move_master_fader(&self, value: f64) {
+        let (model, changed) = &*self.state;
+        model.lock().unwrap().master_fader = value;
+        changed.notify_all();
+        self.send(MasterFaderAbsMsg { value }.into());
+    }
+
+    pub fn touch_fader(&self, idx: ChannelIndex) {
+        self.send(FaderTouchMsg { idx }.into());
+    }
+
+    pub fn release_fader(&self, idx: ChannelIndex) {
+        self.send(FaderReleaseMsg { idx }.into());
+    }
+
+    /// Turns an encoder by `steps` detents; positive is clockwise.
+    pub fn turn_encoder(&self, idx: ChannelIndex, steps: i32) {
+        for _ in 0..steps.unsigned_abs() {
+            match steps > 0 {
+                true => self.send(EncoderTurnCW { idx }.into()),
+                false => self.send(EncoderTurnCCW { idx }.into()),
+            }
+        }
+    }
+
+    /// Turns the jog wheel by `steps` clicks; positive is clockwise.
+    pub fn turn_jog_wheel(&self, steps: i32) {
+        for _ in 0..steps.unsigned_abs() {
+            match steps > 0 {
+                true => self.send(XTouchUpstreamMsg::JogCW),
+                false => self.send(XTouchUpstreamMsg::JogCCW),
+            }
+        }
+    }
+
+    pub fn press(&self, button: SurfaceButton) {
+        self.send(button.press_msg());
+    }
+
+    pub fn release(&self, button: SurfaceButton) {
+        self.send(button.release_msg());
+    }
+
+    /// Presses and releases a button.
+    pub fn tap(&self, button: SurfaceButton) {
+        self.press(button);
+        self.release(button);
+    }
+
+    pub fn press_channel(&self, button: ChannelButton, idx: ChannelIndex) {
+        self.send(button.press_msg(idx));
+    }
+
+    pub fn release_channel(&self, button: ChannelButton, idx: ChannelIndex) {
+        self.send(button.release_msg(idx));
+    }
+
+    /// Presses and releases a button on a channel strip.
+    pub fn tap_channel(&self, button: ChannelButton, idx: ChannelIndex) {
+        self.press_channel(button, idx);
+        self.release_channel(button, idx);
+    }
+
+    /// Acts out one line of a script, as typed at the simulator:
+    ///
+    /// - `fader <channel> <value>` moves a fader the way a hand would, touching it first and
+    ///   letting go after
+    /// - `master <value>` moves the master fader
+    /// - `encoder <channel> <steps>` and `jog <steps>` turn an encoder or the jog wheel
+    /// - `mute`, `solo`, `arm` or `select` followed by `<channel>` taps that channel's button
+    /// - `undo` and `redo` tap the Undo and Redo buttons
+    /// - `scrub` and `zoom` tap the Scrub and Zoom buttons
+    /// - `resync` taps the button that resyncs the surface with Reaper
+    /// - `action <button>` taps one of the buttons left to run Reaper actions, e.g. `action click`
+    pub fn run_command(&self, command: &str) -> Result<(), CommandError> {
+        let words: Vec<&str> = command.split_whitespace().collect();
+        let channel = |i: usize| parse_arg::<u8>(&words, i).map(ChannelIndex::new);
+        match words.first().copied() {
+            None => {}
+            Some("fader") => {
+                let idx = channel(1)?;
+                let value = parse_arg::<f64>(&words, 2)?;
+                self.touch_fader(idx);
+                self.move_fader(idx, value);
+                self.release_fader(idx);
+            }
+            Some("master") => self.move_master_fader(parse_arg(&words, 1)?),
+            Some("encoder") => self.turn_encoder(channel(1)?, parse_arg(&words, 2)?),
+            Some("jog") => self.turn_jog_wheel(parse_arg(&words, 1)?),
+            Some("mute") => self.tap_channel(ChannelButton::Mute, channel(1)?),
+            Some("solo") => self.tap_channel(ChannelButton::Solo, channel(1)?),
+            Some("arm") => self.tap_channel(ChannelButton::Arm, channel(1)?),
+            Some("select") => self.tap_channel(ChannelButton::Select, channel(1)?),
+            Some("undo") => self.tap(SurfaceButton::Undo),
+            Some("redo") => self.tap(SurfaceButton::Redo),
+            Some("resync") => self.tap(SurfaceButton::Resync),
+            Some("scrub") => self.tap(SurfaceButton::Scrub),
+            Some("zoom") => self.tap(SurfaceButton::Zoom),
+            Some("action") => self.tap(SurfaceButton::Action(parse_arg(&words, 1)?)),
+            Some(other) => return Err(CommandError::Unknown(other.to_string())),
+        }
+        Ok(())
+    }
+
+    fn send(&self, msg: XTouchUpstreamMsg) {
+        // The modes going away just leaves the simulated user talking to no one
+        let _ = self.upstream.send(msg);
+    }
+}
@@ -0,0 +1,196 @@
+use std::thread;
+
+use crossbeam_channel::{Receiver, Select, Sender};
+use tracing::warn;
+
+use crate::midi::xtouch::keepalive::SurfaceStatus;
+use crate::midi::xtouch::{ChannelIndex, XTOUCH_CHANNELS, XTouchDownstreamMsg, XTouchUpstreamMsg};
+use crate::modes::mode_manager::Barrier;
+
+/// How the channels of a logical surface are spread over the units making it up.
+///
+/// The first unit holds the surface's first channels, the next unit the ones after those, and so
+/// on. The first unit is also the one with everything outside of the channel strips.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SurfaceLayout {
+    channels_per_unit: Vec<usize>,
+}
+
+impl SurfaceLayout {
+    pub fn new(channels_per_unit: Vec<usize>) -> Self {
+        SurfaceLayout { channels_per_unit }
+    }
+
+    /// An X-Touch followed by `extenders` X-Touch Extenders.
+    pub fn xtouch_with_extenders(extenders: usize) -> Self {
+        SurfaceLayout::new(vec![XTOUCH_CHANNELS; 1 + extenders])
+    }
+
+    pub fn num_units(&self) -> usize {
+        self.channels_per_unit.len()
+    }
+
+    /// Number of channels on the whole surface.
+    pub fn num_channels(&self) -> usize {
+        self.channels_per_unit.iter().sum()
+    }
+
+    /// The unit a surface channel is on, and its index on that unit.
+    pub fn to_unit(&self, idx: ChannelIndex) -> Option<(usize, ChannelIndex)> {
+        let mut first = 0;
+        for (unit, &channels) in self.channels_per_unit.iter().enumerate() {
+            if idx.get() < first + channels {
+                return ChannelIndex::try_from(idx.get() - first)
+                    .ok()
+                    .map(|local| (unit, local));
+            }
+            first += channels;
+        }
+        None
+    }
+
+    /// The surface channel of channel `idx` on `unit`.
+    pub fn from_unit(&self, unit: usize, idx: ChannelIndex) -> Option<ChannelIndex> {
+        match self.channels_per_unit.get(unit) {
+            Some(&channels) if idx.get() < channels => {
+                let first: usize = self.channels_per_unit[..unit].iter().sum();
+                ChannelIndex::try_from(first + idx.get()).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Aggregates several units, e.g. an X-Touch and its extenders, into a single surface.
+///
+/// The modes see one surface with the channels of all units, numbered as in the layout. Messages
+/// for a channel strip go to the unit holding it, everything else to the first unit. Barriers go
+/// to every unit, and are reflected once all of them have reflected it, so they still mark the
+/// point where the whole surface has caught up. Once a unit stops taking messages, e.g. because
+/// it was unplugged, so does the surface, for whatever drives it to see.
+pub struct ExtendedSurface;
+
+impl ExtendedSurface {
+    /// Starts routing between the surface's channels and `units`, given as the channels to and
+    /// from each unit in the layout's order.
+    ///
+    /// The surface is online once every unit is, and offline as soon as any of them isn't, since
+    /// the modes can't show everything on a surface missing a unit. Only changes to that are
+    /// passed upstream.
+    pub fn start(
+        layout: SurfaceLayout,
+        units: Vec<(Sender<XTouchDownstreamMsg>, Receiver<XTouchUpstreamMsg>)>,
+        input: Receiver<XTouchDownstreamMsg>,
+        upstream: Sender<XTouchUpstreamMsg>,
+    ) {
+        assert_eq!(
+            layout.num_units(),
+            units.len(),
+            "the layout should have a unit for each connection"
+        );
+        let (downstreams, upstreams): (Vec<_>, Vec<_>) = units.into_iter().unzip();
+
+        let downstream_layout = layout.clone();
+        thread::spawn(move || {
+            for mut msg in input.iter() {
+                if let XTouchDownstreamMsg::Barrier(barrier) = msg {
+                    for downstream in &downstreams {
+                        if downstream
+                            .send(XTouchDownstreamMsg::Barrier(barrier))
+                            .is_err()
+                        {
+                            warn!("A unit of the surface is gone, letting go of the rest");
+                            return;
+                        }
+                    }
+                    continue;
+                }
+                let unit = match msg.channel_mut() {
+                    Some(idx) => match downstream_layout.to_unit(*idx) {
+                        Some((unit, local)) => {
+                            *idx = local;
+                            Some(unit)
+                        }
+                        None => None,
+                    },
+                    None => Some(0),
+                };
+                match unit {
+                    Some(unit) => {
+                        if downstreams[unit].send(msg).is_err() {
+                            warn!(
+                                "Unit {} of the surface is gone, letting go of the rest",
+                                unit
+                            );
+                            return;
+                        }
+                    }
+                    None => warn!("No unit has the channel of {:?}, dropping it", msg),
+                }
+            }
+        });
+
+        thread::spawn(move || {
+            // Barriers on their way back, with how many units have reflected them so far
+            let mut pending: Vec<(Barrier, usize)> = Vec::new();
+            // Units, like the surface, start out offline until they're heard from
+            let mut statuses = vec![SurfaceStatus::Offline; upstreams.len()];
+            let mut status = SurfaceStatus::Offline;
+            let mut select = Select::new();
+            for rx in &upstreams {
+                select.recv(rx);
+            }
+            loop {
+                let op = select.select();
+                let unit = op.index();
+                let mut msg = match op.recv(&upstreams[unit]) {
+                    Ok(msg) => msg,
+                    Err(_) => return,
+                };
+                match msg {
+                    XTouchUpstreamMsg::Barrier(barrier) => {
+                        let pos = match pending.iter().position(|(b, _)| *b == barrier) {
+                            Some(pos) => pos,
+                            None => {
+                                pending.push((barrier, 0));
+                                pending.len() - 1
+                            }
+                        };
+                        pending[pos].1 += 1;
+                        if pending[pos].1 == upstreams.len() {
+                            pending.remove(pos);
+                            let _ = upstream.send(msg);
+                        }
+                        continue;
+                    }
+                    XTouchUpstreamMsg::SurfaceStatus(unit_status) => {
+                        statuses[unit] = unit_status;
+                        let surface_status = match statuses.contains(&SurfaceStatus::Offline) {
+                            true => SurfaceStatus::Offline,
+                            false => SurfaceStatus::Online,
+                        };
+                        if surface_status != status {
+                            status = surface_status;
+                            let _ = upstream.send(XTouchUpstreamMsg::SurfaceStatus(status));
+                        }
+                        continue;
+                    }
+                    _ => {}
+                }
+                if let Some(idx) = msg.channel_mut() {
+                    match layout.from_unit(unit, *idx) {
+                        Some(surface_idx) => *idx = surface_idx,
+                        None => {
+                            warn!(
+                                "Unit {} has no channel {}, dropping a message from it",
+                                unit, idx
+                            );
+                            continue;
+                        }
+                    }
+                }
+                let _ = upstream.send(msg);
+            }
+        });
+    }
+}
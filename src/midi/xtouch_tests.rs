@@ -0,0 +1,210 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crossbeam_channel::{Receiver, Sender, unbounded};
+
+use crate::midi::base::MidiSink;
+use crate::midi::xtouch::{
+    ChannelIndex, DeviceId, LEDState, Model, SelectLEDMsg, XTouchBuilder, XTouchDownstreamMsg,
+    XTouchUpstreamMsg,
+};
+use crate::midi::{MidiDevice, MidiError, MidiReplay};
+use crate::modes::mode_manager::Barrier;
+
+const TIMEOUT: Duration = Duration::from_millis(500);
+
+// Keeps everything written to it, for the test to look at
+#[derive(Clone, Default)]
+struct Written(Arc<Mutex<Vec<Vec<u8>>>>);
+
+impl MidiSink for Written {
+    fn send(&mut self, message: &[u8]) -> Result<(), MidiError> {
+        self.0.lock().unwrap().push(message.to_vec());
+        Ok(())
+    }
+}
+
+struct Surface {
+    to_xtouch: Sender<XTouchDownstreamMsg>,
+    from_xtouch: Receiver<XTouchUpstreamMsg>,
+    written: Written,
+    // Plays MIDI into the device as though it came from the port
+    replay: MidiReplay,
+}
+
+impl Surface {
+    // Everything written to the port once what was sent before now has gone out
+    fn flush(&self) -> Vec<Vec<u8>> {
+        self.to_xtouch
+            .send(XTouchDownstreamMsg::Barrier(Barrier::new()))
+            .unwrap();
+        loop {
+            match self.from_xtouch.recv_timeout(TIMEOUT) {
+                Ok(XTouchUpstreamMsg::Barrier(_)) => break,
+                Ok(_) => {}
+                Err(e) => panic!("the barrier didn't come back: {}", e),
+            }
+        }
+        std::mem::take(&mut *self.written.0.lock().unwrap())
+    }
+}
+
+fn build() -> Surface {
+    let written = Written::default();
+    let device = MidiDevice::with_sink("test", written.clone());
+    let replay = MidiReplay::default();
+    replay.attach(&device);
+    let (to_xtouch, input) = unbounded();
+    let (upstream, from_xtouch) = unbounded();
+    XTouchBuilder {
+        base: Arc::new(Mutex::new(device)),
+        num_channels: 8,
+        device_id: DeviceId(0),
+        master_fader_channel: Some(8),
+        model: Model::XTouch,
+    }
+    .build(input, upstream)
+    .unwrap();
+    Surface {
+        to_xtouch,
+        from_xtouch,
+        written,
+        replay,
+    }
+}
+
+#[test]
+fn test_encoder_assign_leds_light_their_buttons() {
+    let surface = build();
+    for (msg, written) in [
+        (XTouchDownstreamMsg::Track(LEDState::On), [0x90, 0x28, 0x7F]),
+        (XTouchDownstreamMsg::Send(LEDState::On), [0x90, 0x29, 0x7F]),
+        (XTouchDownstreamMsg::Pan(LEDState::On), [0x90, 0x2A, 0x7F]),
+        (
+            XTouchDownstreamMsg::Plugin(LEDState::Flash),
+            [0x90, 0x2B, 0x01],
+        ),
+        (XTouchDownstreamMsg::EQ(LEDState::On), [0x90, 0x2C, 0x7F]),
+        (XTouchDownstreamMsg::Inst(LEDState::Off), [0x90, 0x2D, 0x00]),
+    ] {
+        surface.to_xtouch.send(msg).unwrap();
+        assert_eq!(surface.flush(), vec![written.to_vec()]);
+    }
+}
+
+#[test]
+fn test_encoder_assign_buttons_are_heard() {
+    let surface = build();
+    assert!(surface.replay.send(&[0x90, 0x2A, 0x7F]));
+    assert!(matches!(
+        surface.from_xtouch.recv_timeout(TIMEOUT),
+        Ok(XTouchUpstreamMsg::PanPress)
+    ));
+    assert!(surface.replay.send(&[0x90, 0x2A, 0x00]));
+    assert!(matches!(
+        surface.from_xtouch.recv_timeout(TIMEOUT),
+        Ok(XTouchUpstreamMsg::PanRelease)
+    ));
+    for (note, pressed) in [
+        (0x28, XTouchUpstreamMsg::TrackPress),
+        (0x29, XTouchUpstreamMsg::SendPress),
+        (0x2B, XTouchUpstreamMsg::PluginPress),
+        (0x2D, XTouchUpstreamMsg::InstPress),
+    ] {
+        assert!(surface.replay.send(&[0x90, note, 0x7F]));
+        let heard = surface.from_xtouch.recv_timeout(TIMEOUT).unwrap();
+        assert_eq!(
+            std::mem::discriminant(&heard),
+            std::mem::discriminant(&pressed)
+        );
+    }
+}
+
+#[test]
+fn test_selects_are_on_their_mcu_notes() {
+    let surface = build();
+    surface
+        .to_xtouch
+        .send(XTouchDownstreamMsg::SelectLED(SelectLEDMsg {
+            idx: ChannelIndex::new(7),
+            state: LEDState::On,
+        }))
+        .unwrap();
+    surface
+        .to_xtouch
+        .send(XTouchDownstreamMsg::Inst(LEDState::On))
+        .unwrap();
+    // Clear of the encoder assign buttons' notes, which the last selects used to share
+    assert_eq!(
+        surface.flush(),
+        vec![vec![0x97, 0x1F, 0x7F], vec![0x90, 0x2D, 0x7F]]
+    );
+}
+
+#[test]
+fn test_messages_without_a_place_on_the_surface_are_ignored() {
+    let surface = build();
+    surface
+        .to_xtouch
+        .send(XTouchDownstreamMsg::Global(LEDState::On))
+        .unwrap();
+    surface
+        .to_xtouch
+        .send(XTouchDownstreamMsg::Pan(LEDState::On))
+        .unwrap();
+    // The thread is still there to light the Pan button after the one it has no place for
+    assert_eq!(surface.flush(), vec![vec![0x90, 0x2A, 0x7F]]);
+}
+
+#[test]
+fn test_selects_are_heard_as_selects() {
+    let surface = build();
+    assert!(surface.replay.send(&[0x93, 0x1B, 0x7F]));
+    assert!(matches!(
+        surface.from_xtouch.recv_timeout(TIMEOUT),
+        Ok(XTouchUpstreamMsg::SelectPress(press)) if press.idx == ChannelIndex::new(3)
+    ));
+    assert!(surface.replay.send(&[0x93, 0x1B, 0x00]));
+    assert!(matches!(
+        surface.from_xtouch.recv_timeout(TIMEOUT),
+        Ok(XTouchUpstreamMsg::SelectRelease(release)) if release.idx == ChannelIndex::new(3)
+    ));
+}
+
+// Fails every write, like a device that was just unplugged
+struct Unplugged;
+
+impl MidiSink for Unplugged {
+    fn send(&mut self, _message: &[u8]) -> Result<(), MidiError> {
+        Err(MidiError::PortNotFound("X-Touch".to_string()))
+    }
+}
+
+#[test]
+fn test_failed_write_lets_go_of_the_input() {
+    let base = Arc::new(Mutex::new(MidiDevice::with_sink("test", Unplugged)));
+    let (to_xtouch, input) = unbounded();
+    let (upstream, _from_xtouch) = unbounded();
+    XTouchBuilder {
+        base: base.clone(),
+        num_channels: 8,
+        device_id: DeviceId(0),
+        master_fader_channel: Some(8),
+        model: Model::XTouch,
+    }
+    .build(input, upstream)
+    .unwrap();
+    to_xtouch
+        .send(XTouchDownstreamMsg::Pan(LEDState::On))
+        .unwrap();
+    let deadline = std::time::Instant::now() + TIMEOUT;
+    while to_xtouch
+        .send(XTouchDownstreamMsg::Pan(LEDState::Off))
+        .is_ok()
+    {
+        assert!(std::time::Instant::now() < deadline, "the input was kept");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    // Without a panic while it was held, the device is still there for the keep-alive
+    assert!(base.lock().is_ok());
+}
@@ -0,0 +1,136 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use crate::midi::xtouch::{ChannelIndex, XTouchUpstreamMsg};
+
+/// Command ID of Reaper's "Edit: Undo" action
+pub const UNDO_ACTION: i32 = 40029;
+
+/// Command ID of Reaper's "Edit: Redo" action
+pub const REDO_ACTION: i32 = 40030;
+
+/// How many changes ActionHistory remembers; older ones are forgotten first
+pub const HISTORY_LEN: usize = 100;
+
+/// Asks Reaper to step through its undo history.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UndoMsg {
+    Undo,
+    Redo,
+}
+
+impl UndoMsg {
+    /// The Reaper action that does this.
+    pub fn action(self) -> i32 {
+        match self {
+            UndoMsg::Undo => UNDO_ACTION,
+            UndoMsg::Redo => REDO_ACTION,
+        }
+    }
+}
+
+/// A change made from the surface, which Reaper keeps an undo step for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SurfaceAction {
+    /// A fader gesture, from touching the fader to letting go of it
+    Fader(ChannelIndex),
+    /// The master fader moving. It doesn't report being touched, so its moves count as one
+    /// gesture until something else changes.
+    MasterFader,
+    Mute(ChannelIndex),
+}
+
+/// The changes made from the surface, for the undo and redo buttons to step through.
+///
+/// Each change is one step, however many messages it took: a fader gesture only becomes a step
+/// when the fader is let go, so undo takes back the whole move rather than its last few
+/// messages. Undoing a step makes it available to redo until something else changes.
+#[derive(Debug, Default)]
+pub struct ActionHistory {
+    undo: VecDeque<SurfaceAction>,
+    redo: Vec<SurfaceAction>,
+    // Faders being moved right now
+    gestures: BTreeSet<ChannelIndex>,
+    master_moving: bool,
+}
+
+impl ActionHistory {
+    pub fn new() -> Self {
+        ActionHistory::default()
+    }
+
+    /// Takes note of a message from the surface, recording the change it makes, if any.
+    pub fn record(&mut self, msg: &XTouchUpstreamMsg) {
+        match msg {
+            // A fader without touch sensing only reports moving, which starts a gesture all the
+            // same
+            XTouchUpstreamMsg::FaderTouch(touch) => self.start_gesture(touch.idx),
+            XTouchUpstreamMsg::FaderAbs(fader) => self.start_gesture(fader.idx),
+            XTouchUpstreamMsg::FaderRelease(release) => self.end_gesture(release.idx),
+            XTouchUpstreamMsg::MasterFaderAbs(_) => self.move_master(),
+            XTouchUpstreamMsg::MutePress(press) => self.push(SurfaceAction::Mute(press.idx)),
+            _ => {}
+        }
+    }
+
+    /// The step to undo, which becomes the next one to redo. Faders still being moved finish
+    /// their gestures first, so they're what gets undone.
+    pub fn undo(&mut self) -> Option<SurfaceAction> {
+        for idx in std::mem::take(&mut self.gestures) {
+            self.push(SurfaceAction::Fader(idx));
+        }
+        self.master_moving = false;
+        let action = self.undo.pop_back()?;
+        self.redo.push(action);
+        Some(action)
+    }
+
+    /// The last step undone, which becomes the next one to undo again.
+    pub fn redo(&mut self) -> Option<SurfaceAction> {
+        let action = self.redo.pop()?;
+        self.undo.push_back(action);
+        self.master_moving = false;
+        Some(action)
+    }
+
+    /// Number of steps there are to undo, not counting gestures still going on.
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// Number of steps there are to redo.
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    fn start_gesture(&mut self, idx: ChannelIndex) {
+        self.gestures.insert(idx);
+        // Redo would bring back a value the fader has since moved away from
+        self.redo.clear();
+        self.master_moving = false;
+    }
+
+    fn end_gesture(&mut self, idx: ChannelIndex) {
+        // Anything else let go of was never moved, or was already undone
+        if self.gestures.remove(&idx) {
+            self.push(SurfaceAction::Fader(idx));
+        }
+    }
+
+    fn move_master(&mut self) {
+        if !self.master_moving {
+            self.push(SurfaceAction::MasterFader);
+            self.master_moving = true;
+        }
+    }
+
+    fn push(&mut self, action: SurfaceAction) {
+        if action != SurfaceAction::MasterFader {
+            self.master_moving = false;
+        }
+        self.redo.clear();
+        if self.undo.len() == HISTORY_LEN {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(action);
+    }
+}
@@ -1,4 +1,11 @@
+pub mod history;
 pub mod mode_manager;
 pub mod reaper_channel_strip;
+pub mod reaper_fx;
+pub mod reaper_fx_params;
+pub mod reaper_selected_track;
 pub mod reaper_track_sends;
 pub mod reaper_vol_pan;
+pub mod session;
+pub mod taper;
+pub mod track_mapping;
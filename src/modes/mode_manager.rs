@@ -1,14 +1,30 @@
 use once_cell::sync::Lazy;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, Instant};
 
-use crossbeam_channel::{Receiver, Sender, select};
+use crossbeam_channel::{Receiver, Sender, after, never, select, tick};
+use serde::Deserialize;
+use tracing::{Span, info, info_span, warn};
 
-use crate::midi::xtouch::{XTouchDownstreamMsg, XTouchUpstreamMsg};
+use crate::guid::TrackGuid;
+use crate::midi::xtouch::keepalive::SurfaceStatus;
+use crate::midi::xtouch::{LEDState, XTOUCH_CHANNELS, XTouchDownstreamMsg, XTouchUpstreamMsg};
+use crate::modes::history::{ActionHistory, UndoMsg};
+use crate::modes::reaper_fx::FxMode;
+use crate::modes::reaper_fx_params::FxParamsMode;
+use crate::modes::reaper_selected_track::SelectedTrackMode;
 use crate::modes::reaper_track_sends::TrackSendsMode;
 use crate::modes::reaper_vol_pan::VolumePanMode;
-use crate::track::track::TrackMsg;
+use crate::modes::session::{SessionSnapshot, SessionStore};
+use crate::modes::taper::Taper;
+use crate::modes::track_mapping::MappingPolicy;
+use crate::osc::bridge_api::BridgeState;
+use crate::project::ProjectMsg;
+use crate::track::track::{DataPayload, TrackDataMsg, TrackMsg};
+use crate::watchdog::Heartbeat;
 
 // Global atomic counter for unique IDs
 static BARRIER_COUNTER: Lazy<AtomicU64> = Lazy::new(|| AtomicU64::new(0));
@@ -50,14 +66,113 @@ pub enum State {
     WaitingBarrierFromDownstream(Barrier),
 }
 
+/// What ModeManager does about a transition whose barrier hasn't come back in time, e.g. because
+/// the surface was unplugged before it could reflect it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BarrierRecovery {
+    /// Start the transition over, up to this many times, then activate the mode regardless
+    Retry(u32),
+    /// Go back to the mode the transition started from, activating that regardless if it times
+    /// out as well
+    Revert,
+    /// Activate the mode regardless, taking the surface to have caught up with it
+    ForceActivate,
+}
+
+impl Default for BarrierRecovery {
+    fn default() -> Self {
+        BarrierRecovery::Retry(2)
+    }
+}
+
 /// Represents the various control modes supported.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Mode {
     ReaperVolPan,
     ReaperSends,
+    ReaperFX,
+    ReaperFxParams,
+    ReaperSelectedTrack,
     MotuVolPan,
 }
 
+impl Mode {
+    /// The mode an encoder assign button switches to, wherever the surface is.
+    pub fn for_button(msg: &XTouchUpstreamMsg) -> Option<Mode> {
+        match msg {
+            XTouchUpstreamMsg::PanPress => Some(Mode::ReaperVolPan),
+            XTouchUpstreamMsg::SendPress => Some(Mode::ReaperSends),
+            XTouchUpstreamMsg::PluginPress => Some(Mode::ReaperFX),
+            XTouchUpstreamMsg::InstPress => Some(Mode::ReaperFxParams),
+            XTouchUpstreamMsg::TrackPress => Some(Mode::ReaperSelectedTrack),
+            _ => None,
+        }
+    }
+
+    /// What the mode is called outside the bridge, e.g. by its OSC API.
+    pub fn name(self) -> &'static str {
+        match self {
+            Mode::ReaperVolPan => "vol_pan",
+            Mode::ReaperSends => "sends",
+            Mode::ReaperFX => "fx",
+            Mode::ReaperFxParams => "fx_params",
+            Mode::ReaperSelectedTrack => "selected_track",
+            Mode::MotuVolPan => "motu_vol_pan",
+        }
+    }
+
+    /// The mode with the given name, see `name`.
+    pub fn named(name: &str) -> Option<Mode> {
+        [
+            Mode::ReaperVolPan,
+            Mode::ReaperSends,
+            Mode::ReaperFX,
+            Mode::ReaperFxParams,
+            Mode::ReaperSelectedTrack,
+            Mode::MotuVolPan,
+        ]
+        .into_iter()
+        .find(|mode| mode.name() == name)
+    }
+
+    /// Pressing and releasing the encoder assign button that switches to this mode, if it has one.
+    pub fn button_press(self) -> Option<[XTouchUpstreamMsg; 2]> {
+        match self {
+            Mode::ReaperVolPan => {
+                Some([XTouchUpstreamMsg::PanPress, XTouchUpstreamMsg::PanRelease])
+            }
+            Mode::ReaperSends => {
+                Some([XTouchUpstreamMsg::SendPress, XTouchUpstreamMsg::SendRelease])
+            }
+            Mode::ReaperFX => Some([
+                XTouchUpstreamMsg::PluginPress,
+                XTouchUpstreamMsg::PluginRelease,
+            ]),
+            Mode::ReaperFxParams => {
+                Some([XTouchUpstreamMsg::InstPress, XTouchUpstreamMsg::InstRelease])
+            }
+            Mode::ReaperSelectedTrack => Some([
+                XTouchUpstreamMsg::TrackPress,
+                XTouchUpstreamMsg::TrackRelease,
+            ]),
+            Mode::MotuVolPan => None,
+        }
+    }
+
+    /// Sets the LED of the button that switches to this mode, if it has one.
+    pub fn button_led(self, state: LEDState) -> Option<XTouchDownstreamMsg> {
+        match self {
+            Mode::ReaperVolPan => Some(XTouchDownstreamMsg::Pan(state)),
+            Mode::ReaperSends => Some(XTouchDownstreamMsg::Send(state)),
+            Mode::ReaperFX => Some(XTouchDownstreamMsg::Plugin(state)),
+            Mode::ReaperFxParams => Some(XTouchDownstreamMsg::Inst(state)),
+            Mode::ReaperSelectedTrack => Some(XTouchDownstreamMsg::Track(state)),
+            Mode::MotuVolPan => None,
+        }
+    }
+}
+
 /// Represents the current mode and state of the mode manager.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct ModeState {
@@ -74,6 +189,143 @@ pub trait ModeHandler<ToUpstream, FromUpstream, ToDownstream, FromDownstream> {
     fn handle_downstream_messages(&mut self, msg: FromUpstream, curr_mode: ModeState) -> ModeState;
 }
 
+/// Asks for everything on the surface to be fetched from Reaper again, for when the two have
+/// drifted apart: the master track and transport, along with the tracks shown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResyncRequest {
+    pub tracks: Vec<TrackGuid>,
+}
+
+/// What the modes are showing, kept up to date as they run for anything else that wants to know.
+#[derive(Clone, Debug, Default)]
+pub struct ModeStatus {
+    mode: Arc<Mutex<Option<ModeState>>>,
+    channel_tracks: Arc<Mutex<Vec<Option<TrackGuid>>>>,
+}
+
+impl ModeStatus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The current mode and whether it's being transitioned to, or None before the modes start
+    pub fn mode(&self) -> Option<ModeState> {
+        *self.mode.lock().unwrap()
+    }
+
+    /// The track VolumePanMode has on each channel strip, across the X-Touch and any extenders
+    pub fn channel_tracks(&self) -> Vec<Option<TrackGuid>> {
+        self.channel_tracks.lock().unwrap().clone()
+    }
+}
+
+/// The modes as the bridge's OSC API sees them. Modes are switched to as if their buttons had
+/// been pressed on the surface, so only from an active mode.
+pub struct ModeBridge {
+    status: ModeStatus,
+    to_modes: Sender<XTouchUpstreamMsg>,
+}
+
+impl ModeBridge {
+    /// `to_modes` is where ModeManager hears from the surface.
+    pub fn new(status: ModeStatus, to_modes: Sender<XTouchUpstreamMsg>) -> Self {
+        ModeBridge { status, to_modes }
+    }
+}
+
+impl BridgeState for ModeBridge {
+    fn mode(&self) -> String {
+        self.status
+            .mode()
+            .map_or(String::new(), |mode| mode.mode.name().to_string())
+    }
+
+    fn channel_tracks(&self) -> Vec<String> {
+        self.status
+            .channel_tracks()
+            .into_iter()
+            .map(|guid| guid.map_or(String::new(), TrackGuid::into_string))
+            .collect()
+    }
+
+    fn switch_mode(&self, name: &str) -> bool {
+        let Some(press) = Mode::named(name).and_then(Mode::button_press) else {
+            return false;
+        };
+        for msg in press {
+            let _ = self.to_modes.send(msg);
+        }
+        true
+    }
+}
+
+/// Startup options for the modes run by ModeManager.
+#[derive(Clone, Debug)]
+pub struct ModeOptions {
+    /// Number of channel strips on the surface, across the X-Touch and any extenders
+    pub num_channels: usize,
+    /// Decides which tracks VolumePanMode shows
+    pub mapping_policy: MappingPolicy,
+    /// Show a loading state until TrackManager reports the initial sync, then render everything at
+    /// once
+    pub wait_for_initial_sync: bool,
+    /// Show the sends of a track as soon as it's selected in Reaper, and go back to VolumePanMode
+    /// when it's deselected
+    pub follow_selection: bool,
+    /// Where to keep saving the modes' state while they run, so a restart can pick up from it
+    pub session: Option<SessionStore>,
+    /// A session saved by an earlier run to start from
+    pub restored_session: Option<SessionSnapshot>,
+    /// How volume is laid out along the faders, for tracks and sends alike
+    pub fader_taper: Taper,
+    /// Where ProjectStateManager's news about the project arrives, if it's running
+    pub from_project: Option<Receiver<ProjectMsg>>,
+    /// Show the name of the marker the play cursor last passed along the bottom of the scribble
+    /// strips in VolumePanMode, in place of the track numbers
+    pub show_markers: bool,
+    /// Where the undo and redo buttons send their requests for Reaper, if anywhere
+    pub to_undo: Option<Sender<UndoMsg>>,
+    /// Where the resync button sends its requests for Reaper, if anywhere
+    pub to_resync: Option<Sender<ResyncRequest>>,
+    /// How soon the resync button can resync again
+    pub resync_interval: Duration,
+    /// How long a transition waits on its barrier before recovering as barrier_recovery says, or
+    /// None to wait for as long as it takes
+    pub barrier_timeout: Option<Duration>,
+    pub barrier_recovery: BarrierRecovery,
+    /// Beaten each time round the modes' loop, for a watchdog to tell when they're stuck
+    pub heartbeat: Option<Heartbeat>,
+    /// Kept up to date with the mode and the tracks on the channel strips
+    pub status: Option<ModeStatus>,
+    /// Run without a surface: nothing downstream reflects barriers, so each counts as reflected
+    /// as soon as a mode waits on it, and probes end here rather than at the surface
+    pub headless: bool,
+}
+
+impl Default for ModeOptions {
+    fn default() -> Self {
+        ModeOptions {
+            num_channels: XTOUCH_CHANNELS,
+            mapping_policy: MappingPolicy::default(),
+            wait_for_initial_sync: false,
+            follow_selection: false,
+            session: None,
+            restored_session: None,
+            fader_taper: Taper::default(),
+            from_project: None,
+            show_markers: false,
+            to_undo: None,
+            to_resync: None,
+            resync_interval: Duration::from_secs(2),
+            barrier_timeout: None,
+            barrier_recovery: BarrierRecovery::default(),
+            heartbeat: None,
+            status: None,
+            headless: false,
+        }
+    }
+}
+
 /// Presents all modes with a uniform interface, (mostly) seamlessly handling switching between modes.
 ///
 /// Shields upstream and downstream from having to know anything about the modes.
@@ -90,7 +342,28 @@ pub struct ModeManager {
     to_xtouch: Sender<XTouchDownstreamMsg>,
     curr_mode: ModeState,
 
-    reaper_currently_selected_track_guid: Option<String>,
+    reaper_currently_selected_track_guid: Option<TrackGuid>,
+    follow_selection: bool,
+    // Whether each track was last reported selected, to tell a selection change from a repeat
+    track_selection: HashMap<TrackGuid, bool>,
+    // Open from the moment a transition is requested until the new mode is active
+    transition: Option<Span>,
+    // The mode whose button is lit, and how
+    shown_mode: Option<(Mode, LEDState)>,
+    // Turns the function keys from recalling scenes to capturing them
+    shift_held: bool,
+    // What was changed from the surface, for the undo and redo buttons
+    history: ActionHistory,
+    to_undo: Option<Sender<UndoMsg>>,
+    to_resync: Option<Sender<ResyncRequest>>,
+    resync_interval: Duration,
+    last_resync: Option<Instant>,
+    barrier_recovery: BarrierRecovery,
+    // The mode the current transition started from, and how it has been recovered so far
+    transition_from: Mode,
+    retries: u32,
+    reverting: bool,
+    status: Option<ModeStatus>,
 }
 
 impl ModeManager {
@@ -101,6 +374,23 @@ impl ModeManager {
         to_reaper: Sender<TrackMsg>,
         from_xtouch: Receiver<XTouchUpstreamMsg>,
         to_xtouch: Sender<XTouchDownstreamMsg>,
+    ) {
+        Self::start_with_options(
+            from_reaper,
+            to_reaper,
+            from_xtouch,
+            to_xtouch,
+            ModeOptions::default(),
+        );
+    }
+
+    /// Like start, but with the given options.
+    pub fn start_with_options(
+        from_reaper: Receiver<TrackMsg>,
+        to_reaper: Sender<TrackMsg>,
+        from_xtouch: Receiver<XTouchUpstreamMsg>,
+        to_xtouch: Sender<XTouchDownstreamMsg>,
+        options: ModeOptions,
     ) {
         let mut manager = ModeManager {
             from_reaper: from_reaper.clone(),
@@ -112,31 +402,113 @@ impl ModeManager {
                 state: State::Active,
             },
             reaper_currently_selected_track_guid: None,
+            follow_selection: options.follow_selection,
+            track_selection: HashMap::new(),
+            transition: None,
+            shown_mode: None,
+            shift_held: false,
+            history: ActionHistory::new(),
+            to_undo: options.to_undo,
+            to_resync: options.to_resync,
+            resync_interval: options.resync_interval,
+            last_resync: None,
+            barrier_recovery: options.barrier_recovery,
+            transition_from: Mode::ReaperVolPan,
+            retries: 0,
+            reverting: false,
+            status: options.status.clone(),
         };
 
         // Each mode's implementation struct needs to be initialized here
-        let reaper_pan_vol = Arc::new(Mutex::new(VolumePanMode::new(
-            8, // For now, assume we have 8 faders on the conroller
+        let mut vol_pan = VolumePanMode::new(
+            options.num_channels,
             from_reaper.clone(),
             to_reaper.clone(),
             from_xtouch.clone(),
             to_xtouch.clone(),
-        )));
-
-        let reaper_track_sends = Arc::new(Mutex::new(TrackSendsMode::new(
-            8,
+        );
+        vol_pan.set_mapping_policy(options.mapping_policy);
+        vol_pan.set_taper(options.fader_taper);
+        vol_pan.set_show_markers(options.show_markers);
+        if let Some(status) = &options.status {
+            vol_pan.share_assignments(status.channel_tracks.clone());
+        }
+        let mut track_sends = TrackSendsMode::new(
+            options.num_channels,
             from_reaper.clone(),
             to_reaper.clone(),
             from_xtouch.clone(),
             to_xtouch.clone(),
+        );
+        track_sends.set_taper(options.fader_taper);
+        if let Some(session) = options.restored_session.clone() {
+            // Until Reaper says otherwise, the track selected last time still is
+            manager.reaper_currently_selected_track_guid = session.sends.track_guid.clone();
+            vol_pan.restore(session.vol_pan);
+            track_sends.restore(session.sends);
+        }
+        if options.wait_for_initial_sync {
+            vol_pan.wait_for_initial_sync();
+        }
+        let reaper_pan_vol = Arc::new(Mutex::new(vol_pan));
+
+        let reaper_track_sends = Arc::new(Mutex::new(track_sends));
+
+        let reaper_fx = Arc::new(Mutex::new(FxMode::new(
+            options.num_channels,
+            to_reaper.clone(),
+            to_xtouch.clone(),
         )));
 
+        let reaper_fx_params = Arc::new(Mutex::new(FxParamsMode::new(
+            options.num_channels,
+            to_reaper.clone(),
+            to_xtouch.clone(),
+        )));
+
+        let mut selected_track =
+            SelectedTrackMode::new(options.num_channels, to_reaper.clone(), to_xtouch.clone());
+        selected_track.set_taper(options.fader_taper);
+        let reaper_selected_track = Arc::new(Mutex::new(selected_track));
+
         let reaper_pan_vol_clone = reaper_pan_vol.clone();
         let reaper_track_sends_clone = reaper_track_sends.clone();
+        let reaper_fx_clone = reaper_fx.clone();
+        let reaper_fx_params_clone = reaper_fx_params.clone();
+        let reaper_selected_track_clone = reaper_selected_track.clone();
+
+        let session = options.session;
+        let save_ticker = match &session {
+            Some(store) => tick(store.save_interval()),
+            None => never(),
+        };
+        // Starting from the restored session, nothing needs saving until something changes
+        let mut saved_session = options.restored_session;
+        let mut from_project = options.from_project.unwrap_or_else(never);
+        let barrier_timeout = options.barrier_timeout;
+        let heartbeat = options.heartbeat;
+        // Taken before the thread starts, so a restart can't come between
+        let generation = heartbeat.as_ref().map(Heartbeat::generation);
+        let headless = options.headless;
+        // The state the barrier deadline was set for, and the deadline
+        let mut timed_state = Some(manager.curr_mode.state);
+        let mut barrier_deadline = never();
 
         thread::spawn(move || {
             let handle_transitions = |manager: &mut ModeManager, mode: ModeState| {
                 if mode.state == State::RequestingModeTransition {
+                    let span = info_span!(
+                        "mode_transition",
+                        from = ?manager.curr_mode.mode,
+                        to = ?mode.mode
+                    );
+                    let _entered = span.enter();
+                    info!("Starting mode transition");
+                    manager.transition = Some(span.clone());
+                    // A transition started over still came from where it first did
+                    if manager.curr_mode.state == State::Active {
+                        manager.transition_from = manager.curr_mode.mode;
+                    }
                     match mode.mode {
                         Mode::ReaperVolPan => {
                             manager.curr_mode = reaper_pan_vol_clone
@@ -156,9 +528,58 @@ impl ModeManager {
                                         &currently_selected_track_guid,
                                     );
                             } else {
-                                //TODO: log that we won't enter the mode because no track is selected
+                                warn!("Not entering the mode, since no track is selected");
+                                // If we can't transition, stay in current mode
+                                manager.transition = None;
+                            }
+                        }
+                        Mode::ReaperFX => {
+                            if let Some(currently_selected_track_guid) =
+                                manager.reaper_currently_selected_track_guid.clone()
+                            {
+                                manager.curr_mode =
+                                    reaper_fx_clone.lock().unwrap().initiate_mode_transition(
+                                        manager.to_reaper.clone(),
+                                        &currently_selected_track_guid,
+                                    );
+                            } else {
+                                warn!("Not entering the mode, since no track is selected");
+                                // If we can't transition, stay in current mode
+                                manager.transition = None;
+                            }
+                        }
+                        Mode::ReaperFxParams => {
+                            if let Some(currently_selected_track_guid) =
+                                manager.reaper_currently_selected_track_guid.clone()
+                            {
+                                manager.curr_mode = reaper_fx_params_clone
+                                    .lock()
+                                    .unwrap()
+                                    .initiate_mode_transition(
+                                        manager.to_reaper.clone(),
+                                        &currently_selected_track_guid,
+                                    );
+                            } else {
+                                warn!("Not entering the mode, since no track is selected");
                                 // If we can't transition, stay in current mode
-                                manager.curr_mode = mode;
+                                manager.transition = None;
+                            }
+                        }
+                        Mode::ReaperSelectedTrack => {
+                            if let Some(currently_selected_track_guid) =
+                                manager.reaper_currently_selected_track_guid.clone()
+                            {
+                                manager.curr_mode = reaper_selected_track_clone
+                                    .lock()
+                                    .unwrap()
+                                    .initiate_mode_transition(
+                                        manager.to_reaper.clone(),
+                                        &currently_selected_track_guid,
+                                    );
+                            } else {
+                                warn!("Not entering the mode, since no track is selected");
+                                // If we can't transition, stay in current mode
+                                manager.transition = None;
                             }
                         }
                         Mode::MotuVolPan => {
@@ -167,22 +588,130 @@ impl ModeManager {
                     }
                 } else {
                     // Not requesting a transition, just update the mode
+                    let finished = match mode.state {
+                        State::Active => {
+                            manager.retries = 0;
+                            manager.reverting = false;
+                            manager.transition.take()
+                        }
+                        _ => None,
+                    };
+                    if let Some(span) = finished {
+                        span.in_scope(|| info!("Mode transition complete"));
+                    }
                     manager.curr_mode = mode;
                 }
+                manager.show_mode();
+            };
+            // Entering the mode again repaints the rest of the surface; the mixer's channels it
+            // leaves as they are
+            let repaint = |manager: &mut ModeManager| {
+                let mode = manager.curr_mode.mode;
+                if mode == Mode::ReaperVolPan {
+                    reaper_pan_vol.lock().unwrap().repaint();
+                }
+                handle_transitions(
+                    manager,
+                    ModeState {
+                        mode,
+                        state: State::RequestingModeTransition,
+                    },
+                );
             };
 
+            manager.show_mode();
             loop {
+                if let (Some(heartbeat), Some(generation)) = (&heartbeat, generation) {
+                    // The watchdog started the modes over while these were stuck, and the new
+                    // ones read the same channels and drive the same surface
+                    if heartbeat.superseded(generation) {
+                        warn!("The modes were started over, stopping these");
+                        return;
+                    }
+                    heartbeat.beat();
+                }
+                // Without a surface to reflect it, the barrier is as good as reflected once sent
+                if headless
+                    && let State::WaitingBarrierFromDownstream(barrier) = manager.curr_mode.state
+                {
+                    let curr_mode = manager.curr_mode;
+                    let reflected = XTouchUpstreamMsg::Barrier(barrier);
+                    let new_mode = match curr_mode.mode {
+                        Mode::ReaperVolPan => reaper_pan_vol
+                            .lock()
+                            .unwrap()
+                            .handle_upstream_messages(reflected, curr_mode),
+                        Mode::ReaperSends => reaper_track_sends
+                            .lock()
+                            .unwrap()
+                            .handle_upstream_messages(reflected, curr_mode),
+                        Mode::ReaperFX => reaper_fx
+                            .lock()
+                            .unwrap()
+                            .handle_upstream_messages(reflected, curr_mode),
+                        Mode::ReaperFxParams => reaper_fx_params
+                            .lock()
+                            .unwrap()
+                            .handle_upstream_messages(reflected, curr_mode),
+                        Mode::ReaperSelectedTrack => reaper_selected_track
+                            .lock()
+                            .unwrap()
+                            .handle_upstream_messages(reflected, curr_mode),
+                        _ => panic!("Inside unknown mode in ModeManager"),
+                    };
+                    handle_transitions(&mut manager, new_mode);
+                    continue;
+                }
+                // Each barrier waited on gets a deadline of its own
+                if timed_state != Some(manager.curr_mode.state) {
+                    timed_state = Some(manager.curr_mode.state);
+                    barrier_deadline = match (manager.curr_mode.state, barrier_timeout) {
+                        (
+                            State::WaitingBarrierFromUpstream(_)
+                            | State::WaitingBarrierFromDownstream(_),
+                            Some(timeout),
+                        ) => after(timeout),
+                        _ => never(),
+                    };
+                }
                 select! {
                     recv(manager.from_reaper) -> msg => {
                         if let Ok(track_msg) = msg {
+                        // Probes time the way to the surface, whichever mode is showing on it, or to
+                        // here when there's none
+                        if let TrackMsg::Probe(probe) = track_msg {
+                            match headless {
+                                true => probe.arrived(),
+                                false => {
+                                    let _ = manager.to_xtouch.send(XTouchDownstreamMsg::Probe(probe));
+                                }
+                            }
+                            continue;
+                        }
+                        let mut follow = None;
                         // Track currently selected track for mode transitions
                         if let TrackMsg::TrackDataMsg(ref data_msg) = track_msg {
-                            if let crate::track::track::DataPayload::Selected(true) = data_msg.data {
+                            if let DataPayload::Selected(true) = data_msg.data {
                                 manager.reaper_currently_selected_track_guid = Some(data_msg.guid.clone());
                             }
+                            // Sends are labelled with their destination track, which the modes
+                            // showing sends need to know about even while they are inactive
+                            if let DataPayload::Name(ref name) = data_msg.data {
+                                reaper_track_sends.lock().unwrap().set_track_name(&data_msg.guid, name);
+                                reaper_selected_track.lock().unwrap().set_track_name(&data_msg.guid, name);
+                            }
+                            let sends_track = reaper_track_sends.lock().unwrap().selected_track_guid().cloned();
+                            follow = manager.selection_transition(data_msg, sends_track.as_ref());
                         }
 
                         let curr_mode = manager.curr_mode;
+                        // The mixer has to know which tracks are gone by the time it's back on
+                        // the surface
+                        match (&track_msg, curr_mode.mode) {
+                            (TrackMsg::TrackRemoved(_), Mode::ReaperVolPan) => {}
+                            (TrackMsg::TrackRemoved(guid), _) => reaper_pan_vol.lock().unwrap().forget_track(guid),
+                            _ => {}
+                        }
                         match curr_mode.mode {
                         Mode::ReaperVolPan => {
                             // TODO: Do we need to gate this during transition? I think probably
@@ -199,13 +728,95 @@ impl ModeManager {
                             Mode::ReaperSends => {
                                 handle_transitions(&mut manager, reaper_track_sends.lock().unwrap().handle_downstream_messages(track_msg, curr_mode))
                             },
+                            Mode::ReaperFX => {
+                                handle_transitions(&mut manager, reaper_fx.lock().unwrap().handle_downstream_messages(track_msg, curr_mode))
+                            },
+                            Mode::ReaperFxParams => {
+                                handle_transitions(&mut manager, reaper_fx_params.lock().unwrap().handle_downstream_messages(track_msg, curr_mode))
+                            },
+                            Mode::ReaperSelectedTrack => {
+                                handle_transitions(&mut manager, reaper_selected_track.lock().unwrap().handle_downstream_messages(track_msg, curr_mode))
+                            },
                         _ => {panic!("Inside unknown mode in ModeManager")},
                         }
+                        // The current mode sees the selection change before the surface moves on
+                        if let Some(mode) = follow {
+                            handle_transitions(&mut manager, mode);
+                        }
                     }
                 }
                     recv(manager.from_xtouch) -> msg => {
                         if let Ok(xtouch_msg) = msg {
                             let curr_mode = manager.curr_mode;
+                            // The function keys capture and recall scenes from anywhere, so they
+                            // never reach the modes either. Shift still does, for the modes that
+                            // use it.
+                            match &xtouch_msg {
+                                XTouchUpstreamMsg::FunctionPress(press) => {
+                                    manager.request_scene(press.idx);
+                                    continue;
+                                }
+                                XTouchUpstreamMsg::FunctionRelease(_) => continue,
+                                XTouchUpstreamMsg::ShiftPress => manager.shift_held = true,
+                                XTouchUpstreamMsg::ShiftRelease => manager.shift_held = false,
+                                // Neither do undo and redo, which step through Reaper's undo history
+                                XTouchUpstreamMsg::UndoPress => {
+                                    manager.request_undo(UndoMsg::Undo);
+                                    continue;
+                                }
+                                XTouchUpstreamMsg::RedoPress => {
+                                    manager.request_undo(UndoMsg::Redo);
+                                    continue;
+                                }
+                                XTouchUpstreamMsg::UndoRelease | XTouchUpstreamMsg::RedoRelease => continue,
+                                // Nor does resync, which works the same whichever mode is showing
+                                XTouchUpstreamMsg::ResyncPress => {
+                                    let tracks = match curr_mode.mode {
+                                        Mode::ReaperVolPan => reaper_pan_vol.lock().unwrap().assigned_tracks(),
+                                        _ => manager.reaper_currently_selected_track_guid.iter().cloned().collect(),
+                                    };
+                                    if manager.request_resync(tracks) {
+                                        repaint(&mut manager);
+                                    }
+                                    continue;
+                                }
+                                XTouchUpstreamMsg::ResyncRelease => continue,
+                                // A surface that was away has forgotten what it showed. A
+                                // transition under way paints it anyway.
+                                XTouchUpstreamMsg::SurfaceStatus(SurfaceStatus::Online) => {
+                                    info!("The surface is back, repainting it");
+                                    if curr_mode.state == State::Active {
+                                        repaint(&mut manager);
+                                    }
+                                    continue;
+                                }
+                                XTouchUpstreamMsg::SurfaceStatus(SurfaceStatus::Offline) => {
+                                    warn!("The surface went away");
+                                    continue;
+                                }
+                                _ => {}
+                            }
+                            // Only what gets through to the modes changes anything, but a fader
+                            // let go of mid-transition still ends its gesture
+                            match (curr_mode.state, &xtouch_msg) {
+                                (State::Active, _) | (_, XTouchUpstreamMsg::FaderRelease(_)) => manager.history.record(&xtouch_msg),
+                                _ => {}
+                            }
+                            // The encoder assign buttons switch modes from anywhere, so they never
+                            // reach the modes
+                            if let Some(mode) = Mode::for_button(&xtouch_msg) {
+                                match (curr_mode.state, mode == curr_mode.mode) {
+                                    (State::Active, false) => handle_transitions(&mut manager, ModeState {
+                                        mode,
+                                        state: State::RequestingModeTransition,
+                                    }),
+                                    (State::Active, true) => {}
+                                    // One transition at a time; the button can be pressed again
+                                    // once this one's done
+                                    _ => info!("Not switching to {:?} in the middle of a transition", mode),
+                                }
+                                continue;
+                            }
                             match curr_mode.mode{
                                 Mode::ReaperVolPan => {
                                     match curr_mode.state {
@@ -213,6 +824,12 @@ impl ModeManager {
                                             let new_mode = reaper_pan_vol.lock().unwrap().handle_upstream_messages(xtouch_msg, curr_mode);
                                             handle_transitions(&mut manager, new_mode);
                                         },
+                                        // The hw reflecting the barrier is what confirms it, so
+                                        // that has to get through
+                                        State::WaitingBarrierFromDownstream(_) if matches!(xtouch_msg, XTouchUpstreamMsg::Barrier(_)) => {
+                                            let new_mode = reaper_pan_vol.lock().unwrap().handle_upstream_messages(xtouch_msg, curr_mode);
+                                            handle_transitions(&mut manager, new_mode);
+                                        },
                                         // We don't send any messages up from the hw until the hw
                                         // is confirmed to reflect the upsream state
                                         State::WaitingBarrierFromDownstream(_) => {
@@ -230,6 +847,81 @@ impl ModeManager {
                                             let new_mode = reaper_track_sends.lock().unwrap().handle_upstream_messages(xtouch_msg, curr_mode);
                                             handle_transitions(&mut manager, new_mode);
                                         },
+                                        // The hw reflecting the barrier is what confirms it, so
+                                        // that has to get through
+                                        State::WaitingBarrierFromDownstream(_) if matches!(xtouch_msg, XTouchUpstreamMsg::Barrier(_)) => {
+                                            let new_mode = reaper_track_sends.lock().unwrap().handle_upstream_messages(xtouch_msg, curr_mode);
+                                            handle_transitions(&mut manager, new_mode);
+                                        },
+                                        // We don't send any messages up from the hw until the hw
+                                        // is confirmed to reflect the upsream state
+                                        State::WaitingBarrierFromDownstream(_) => {
+                                            // Block
+                                        },
+                                        State::WaitingBarrierFromUpstream(_) => {
+                                            // Block
+                                        },
+                                        State::RequestingModeTransition => panic!("We should never be handling upstream messages while requesting a mode transition!")
+                                    }
+                                },
+                                Mode::ReaperFX => {
+                                    match curr_mode.state {
+                                        State::Active => {
+                                            let new_mode = reaper_fx.lock().unwrap().handle_upstream_messages(xtouch_msg, curr_mode);
+                                            handle_transitions(&mut manager, new_mode);
+                                        },
+                                        // The hw reflecting the barrier is what confirms it, so
+                                        // that has to get through
+                                        State::WaitingBarrierFromDownstream(_) if matches!(xtouch_msg, XTouchUpstreamMsg::Barrier(_)) => {
+                                            let new_mode = reaper_fx.lock().unwrap().handle_upstream_messages(xtouch_msg, curr_mode);
+                                            handle_transitions(&mut manager, new_mode);
+                                        },
+                                        // We don't send any messages up from the hw until the hw
+                                        // is confirmed to reflect the upsream state
+                                        State::WaitingBarrierFromDownstream(_) => {
+                                            // Block
+                                        },
+                                        State::WaitingBarrierFromUpstream(_) => {
+                                            // Block
+                                        },
+                                        State::RequestingModeTransition => panic!("We should never be handling upstream messages while requesting a mode transition!")
+                                    }
+                                },
+                                Mode::ReaperFxParams => {
+                                    match curr_mode.state {
+                                        State::Active => {
+                                            let new_mode = reaper_fx_params.lock().unwrap().handle_upstream_messages(xtouch_msg, curr_mode);
+                                            handle_transitions(&mut manager, new_mode);
+                                        },
+                                        // The hw reflecting the barrier is what confirms it, so
+                                        // that has to get through
+                                        State::WaitingBarrierFromDownstream(_) if matches!(xtouch_msg, XTouchUpstreamMsg::Barrier(_)) => {
+                                            let new_mode = reaper_fx_params.lock().unwrap().handle_upstream_messages(xtouch_msg, curr_mode);
+                                            handle_transitions(&mut manager, new_mode);
+                                        },
+                                        // We don't send any messages up from the hw until the hw
+                                        // is confirmed to reflect the upsream state
+                                        State::WaitingBarrierFromDownstream(_) => {
+                                            // Block
+                                        },
+                                        State::WaitingBarrierFromUpstream(_) => {
+                                            // Block
+                                        },
+                                        State::RequestingModeTransition => panic!("We should never be handling upstream messages while requesting a mode transition!")
+                                    }
+                                },
+                                Mode::ReaperSelectedTrack => {
+                                    match curr_mode.state {
+                                        State::Active => {
+                                            let new_mode = reaper_selected_track.lock().unwrap().handle_upstream_messages(xtouch_msg, curr_mode);
+                                            handle_transitions(&mut manager, new_mode);
+                                        },
+                                        // The hw reflecting the barrier is what confirms it, so
+                                        // that has to get through
+                                        State::WaitingBarrierFromDownstream(_) if matches!(xtouch_msg, XTouchUpstreamMsg::Barrier(_)) => {
+                                            let new_mode = reaper_selected_track.lock().unwrap().handle_upstream_messages(xtouch_msg, curr_mode);
+                                            handle_transitions(&mut manager, new_mode);
+                                        },
                                         // We don't send any messages up from the hw until the hw
                                         // is confirmed to reflect the upsream state
                                         State::WaitingBarrierFromDownstream(_) => {
@@ -243,10 +935,200 @@ impl ModeManager {
                                 },
                                 _ => {panic!("Inside unknown mode in ModeManager")},
                             }
+                        } else {
+                            // Nothing more is coming, e.g. when headless without the bridge's API
+                            manager.from_xtouch = never();
+                        }
+                    }
+                    recv(from_project) -> msg => match msg {
+                        Ok(project_msg) => {
+                            let curr_mode = manager.curr_mode;
+                            reaper_pan_vol.lock().unwrap().handle_project_msg(project_msg, curr_mode);
+                        }
+                        // Nothing more is coming, so stop listening
+                        Err(_) => from_project = never(),
+                    },
+                    recv(barrier_deadline) -> _ => {
+                        let recovery = manager.recover_from_barrier_timeout();
+                        // Whatever is still waited on after recovering is given another deadline
+                        timed_state = None;
+                        handle_transitions(&mut manager, recovery);
+                    }
+                    recv(save_ticker) -> _ => {
+                        if let Some(store) = &session {
+                            let snapshot = SessionSnapshot {
+                                vol_pan: reaper_pan_vol.lock().unwrap().snapshot(),
+                                sends: reaper_track_sends.lock().unwrap().snapshot(),
+                            };
+                            if saved_session.as_ref() != Some(&snapshot) {
+                                match store.save(&snapshot) {
+                                    Ok(()) => saved_session = Some(snapshot),
+                                    Err(e) => warn!("Failed to save session to {:?}: {:?}", store.path(), e),
+                                }
+                            }
                         }
                     }
                 }
             }
         });
     }
+    /// Lights the button of the current mode, flashing it until the mode is active, and turns off
+    /// the one lit before.
+    fn show_mode(&mut self) {
+        if let Some(status) = &self.status {
+            *status.mode.lock().unwrap() = Some(self.curr_mode);
+        }
+        let led = match self.curr_mode.state {
+            State::Active => LEDState::On,
+            _ => LEDState::Flash,
+        };
+        let shown = (self.curr_mode.mode, led);
+        if self.shown_mode == Some(shown) {
+            return;
+        }
+        let previous = self.shown_mode.replace(shown);
+        let unlit = previous
+            .filter(|(mode, _)| *mode != self.curr_mode.mode)
+            .and_then(|(mode, _)| mode.button_led(LEDState::Off));
+        let lit = self.curr_mode.mode.button_led(led);
+        for msg in unlit.into_iter().chain(lit) {
+            let _ = self.to_xtouch.send(msg);
+        }
+    }
+
+    /// The way out of a transition whose barrier hasn't come back in time, as barrier_recovery
+    /// says. Once retrying or going back has been given up on, the mode on the surface is
+    /// activated as it is.
+    fn recover_from_barrier_timeout(&mut self) -> ModeState {
+        let mode = self.curr_mode.mode;
+        let recovery = match self.barrier_recovery {
+            BarrierRecovery::Retry(max) if self.retries >= max => BarrierRecovery::ForceActivate,
+            BarrierRecovery::Revert if self.reverting => BarrierRecovery::ForceActivate,
+            recovery => recovery,
+        };
+        match recovery {
+            BarrierRecovery::Retry(max) => {
+                self.retries += 1;
+                warn!(
+                    "Timed out entering {:?}, starting over ({} of {})",
+                    mode, self.retries, max
+                );
+                ModeState {
+                    mode,
+                    state: State::RequestingModeTransition,
+                }
+            }
+            BarrierRecovery::Revert => {
+                self.reverting = true;
+                warn!(
+                    "Timed out entering {:?}, going back to {:?}",
+                    mode, self.transition_from
+                );
+                ModeState {
+                    mode: self.transition_from,
+                    state: State::RequestingModeTransition,
+                }
+            }
+            BarrierRecovery::ForceActivate => {
+                warn!("Timed out entering {:?}, activating it anyway", mode);
+                ModeState {
+                    mode,
+                    state: State::Active,
+                }
+            }
+        }
+    }
+
+    /// Asks TrackManager to recall the scene of the function key numbered `idx`, or to capture it
+    /// while shift is held. F1's scene is called "F1", and so on.
+    fn request_scene(&self, idx: u8) {
+        let name = format!("F{}", idx + 1);
+        let msg = match self.shift_held {
+            true => TrackMsg::CaptureScene(name),
+            false => TrackMsg::RecallScene(name),
+        };
+        let _ = self.to_reaper.send(msg);
+    }
+
+    /// Steps through Reaper's undo history, as far as there's something changed from the surface
+    /// to undo or redo. Reaper's history has everything else done in the project as well, so
+    /// this keeps the buttons from reaching past what the surface did.
+    fn request_undo(&mut self, msg: UndoMsg) {
+        let Some(to_undo) = &self.to_undo else {
+            return;
+        };
+        let action = match msg {
+            UndoMsg::Undo => self.history.undo(),
+            UndoMsg::Redo => self.history.redo(),
+        };
+        match action {
+            Some(action) => {
+                info!("{:?} {:?}", msg, action);
+                let _ = to_undo.send(msg);
+            }
+            None => info!("Nothing from the surface to {:?}", msg),
+        }
+    }
+
+    /// Asks Reaper for everything on the surface again, along with the given tracks, unless the
+    /// last resync was too recent or a transition is under way. Returns whether it did, in which
+    /// case the surface should be repainted too.
+    fn request_resync(&mut self, tracks: Vec<TrackGuid>) -> bool {
+        if self.curr_mode.state != State::Active {
+            info!("Not resyncing in the middle of a transition");
+            return false;
+        }
+        let now = Instant::now();
+        match self.last_resync {
+            Some(last) if now.duration_since(last) < self.resync_interval => {
+                info!("Not resyncing again so soon after the last time");
+                return false;
+            }
+            _ => {}
+        }
+        self.last_resync = Some(now);
+        info!("Resyncing {} tracks with Reaper", tracks.len());
+        if let Some(to_resync) = &self.to_resync {
+            // A resync still waiting to go out covers this one
+            let _ = to_resync.try_send(ResyncRequest { tracks });
+        }
+        true
+    }
+
+    /// The transition a change in a track's selection asks for when following the selection: into
+    /// the sends of a newly selected track, or back to VolumePanMode when the track whose sends
+    /// are shown is deselected.
+    ///
+    /// Reaper repeats a track's selection whenever the track is queried, which entering either
+    /// mode does, so only an actual change counts.
+    fn selection_transition(
+        &mut self,
+        data_msg: &TrackDataMsg,
+        sends_track: Option<&TrackGuid>,
+    ) -> Option<ModeState> {
+        if !self.follow_selection {
+            return None;
+        }
+        let DataPayload::Selected(selected) = data_msg.data else {
+            return None;
+        };
+        let was_selected = self
+            .track_selection
+            .insert(data_msg.guid.clone(), selected)
+            .unwrap_or(false);
+        if selected == was_selected {
+            return None;
+        }
+        let showing_sends =
+            self.curr_mode.mode == Mode::ReaperSends && sends_track == Some(&data_msg.guid);
+        let mode = match (selected, showing_sends) {
+            (true, false) => Mode::ReaperSends,
+            (false, true) => Mode::ReaperVolPan,
+            _ => return None,
+        };
+        Some(ModeState {
+            mode,
+            state: State::RequestingModeTransition,
+        })
+    }
 }
@@ -3,9 +3,13 @@ use std::sync::{Arc, Mutex};
 use std::vec::Vec;
 
 use crossbeam_channel::{Receiver, Sender};
+use tracing::debug;
 
+use crate::guid::TrackGuid;
 use crate::midi::xtouch;
-use crate::midi::xtouch::{FaderAbsMsg, LEDState, XTouchDownstreamMsg, XTouchUpstreamMsg};
+use crate::midi::xtouch::{
+    ChannelIndex, ChannelIndexError, FaderAbsMsg, LEDState, XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
 use crate::modes::mode_manager::{Barrier, Mode, ModeHandler, ModeState, State};
 use crate::track::track::{
     DataPayload as TrackDataPayload, Direction, TrackDataMsg, TrackMsg, TrackQuery,
@@ -213,8 +217,8 @@ impl ChannelStripMap {
 /// | 16. Gain       | interface gain (only if armed)   | saturation type  | gain type      |                    |               |
 pub struct ChannelStripMode {
     // Maps each channel on the hardware controller to a Reaper track
-    track_hw_assignments: Arc<Mutex<Vec<Option<String>>>>,
-    track_states: HashMap<String, MuteSoloArmButtonState>,
+    track_hw_assignments: Arc<Mutex<Vec<Option<TrackGuid>>>>,
+    track_states: HashMap<TrackGuid, MuteSoloArmButtonState>,
     to_reaper: Sender<TrackMsg>,
     from_reaper: Receiver<TrackMsg>,
     to_xtouch: Sender<XTouchDownstreamMsg>,
@@ -242,7 +246,7 @@ impl ChannelStripMode {
         }
     }
 
-    fn get_track_state(&mut self, guid: String) -> &mut MuteSoloArmButtonState {
+    fn get_track_state(&mut self, guid: TrackGuid) -> &mut MuteSoloArmButtonState {
         self.track_states
             .entry(guid)
             .or_insert(MuteSoloArmButtonState {
@@ -252,18 +256,29 @@ impl ChannelStripMode {
             })
     }
 
-    fn get_guid_for_hw_channel(&self, hw_channel: usize) -> Option<String> {
+    fn get_guid_for_hw_channel(&self, hw_channel: ChannelIndex) -> Option<TrackGuid> {
         let assignments = self.track_hw_assignments.lock().unwrap();
-        assignments[hw_channel].clone()
+        assignments.get(hw_channel.get()).cloned().flatten()
+    }
+
+    // Validates a channel index from Reaper against the number of channels on the surface
+    fn checked_channel(&self, index: i32) -> Result<ChannelIndex, ChannelIndexError> {
+        let num_channels = self.track_hw_assignments.lock().unwrap().len();
+        ChannelIndex::try_from(index)?.within(num_channels)
+    }
+
+    fn find_channel_index(&self, guid: &TrackGuid) -> Option<ChannelIndex> {
+        self.find_hw_channel(guid)
+            .and_then(|hw_channel| ChannelIndex::try_from(hw_channel).ok())
     }
 
     // For a given track GUID, find which hardware channel it's assigned to (if any)
-    pub fn find_hw_channel(&self, guid: &str) -> Option<usize> {
+    pub fn find_hw_channel(&self, guid: &TrackGuid) -> Option<usize> {
         let assignments = self.track_hw_assignments.lock().unwrap();
         assignments
             .iter()
             .enumerate()
-            .find(|(_, assigned_guid)| *assigned_guid == &Some(guid.to_string()))
+            .find(|(_, assigned_guid)| assigned_guid.as_ref() == Some(guid))
             .map(|(hw_channel, _)| hw_channel)
     }
 }
@@ -273,9 +288,7 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
         if let TrackMsg::Barrier(barrier) = msg {
             // Forward barriers downstream (they need to reflect back upstream for the mode to
             // transition)
-            self.to_xtouch
-                .send(XTouchDownstreamMsg::Barrier(barrier))
-                .unwrap();
+            let _ = self.to_xtouch.send(XTouchDownstreamMsg::Barrier(barrier));
             match curr_mode.state {
                 // If we were already waiting on a barrier from upstream, check if this is the one
                 // we were waiting for. If yes, transition to waiting for the barrier to reflect back up from downstream.
@@ -296,57 +309,64 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
             match msg.data {
                 // We use track index according to reaper to assign tracks to hardware channels
                 TrackDataPayload::ReaperTrackIndex(Some(index)) => {
-                    self.track_hw_assignments.lock().unwrap()[index as usize] =
+                    let hw_channel = match self.checked_channel(index) {
+                        Ok(hw_channel) => hw_channel,
+                        Err(err) => {
+                            debug!("Not mapping track {} to hardware: {:?}", msg.guid, err);
+                            return curr_mode;
+                        }
+                    };
+                    self.track_hw_assignments.lock().unwrap()[hw_channel.get()] =
                         Some(msg.guid.clone());
                     return curr_mode;
                 }
                 TrackDataPayload::Volume(value) => {
-                    if let Some(hw_channel) = self.find_hw_channel(&msg.guid) {
+                    if let Some(hw_channel) = self.find_channel_index(&msg.guid) {
                         // Send volume update to XTouch for the corresponding fader
                         let fader_value = value; // TODO: scale appropriately
-                        let _ = self
-                            .to_xtouch
-                            .send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg {
-                                idx: hw_channel as i32,
-                                value: fader_value as f64,
-                            }));
+                        let _ =
+                            self.to_xtouch
+                                .send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg::at(
+                                    hw_channel,
+                                    fader_value,
+                                )));
                     }
                     return curr_mode;
                 }
                 TrackDataPayload::Muted(muted) => {
-                    if let Some(hw_channel) = self.find_hw_channel(&msg.guid) {
+                    if let Some(hw_channel) = self.find_channel_index(&msg.guid) {
                         self.get_track_state(msg.guid).mute.set(muted);
                         // Send mute LED update to XTouch
                         let _ =
                             self.to_xtouch
                                 .send(XTouchDownstreamMsg::MuteLED(xtouch::MuteLEDMsg {
-                                    idx: hw_channel as i32,
+                                    idx: hw_channel,
                                     state: LEDState::from(muted),
                                 }));
                     }
                     return curr_mode;
                 }
                 TrackDataPayload::Soloed(soloed) => {
-                    if let Some(hw_channel) = self.find_hw_channel(&msg.guid) {
+                    if let Some(hw_channel) = self.find_channel_index(&msg.guid) {
                         self.get_track_state(msg.guid).solo.set(soloed);
                         // Send solo LED update to XTouch
                         let _ =
                             self.to_xtouch
                                 .send(XTouchDownstreamMsg::SoloLED(xtouch::SoloLEDMsg {
-                                    idx: hw_channel as i32,
+                                    idx: hw_channel,
                                     state: LEDState::from(soloed),
                                 }));
                     }
                     return curr_mode;
                 }
                 TrackDataPayload::Armed(armed) => {
-                    if let Some(hw_channel) = self.find_hw_channel(&msg.guid) {
+                    if let Some(hw_channel) = self.find_channel_index(&msg.guid) {
                         self.get_track_state(msg.guid).arm.set(armed);
                         // Send arm LED update to XTouch
                         let _ =
                             self.to_xtouch
                                 .send(XTouchDownstreamMsg::ArmLED(xtouch::ArmLEDMsg {
-                                    idx: hw_channel as i32,
+                                    idx: hw_channel,
                                     state: LEDState::from(armed),
                                 }));
                     }
@@ -404,20 +424,18 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                 }
             }
             XTouchUpstreamMsg::FaderAbs(fader_msg) => {
-                if let Some(guid) =
-                    &self.track_hw_assignments.lock().unwrap()[fader_msg.idx as usize]
-                {
+                if let Some(guid) = self.get_guid_for_hw_channel(fader_msg.idx) {
                     // Send volume update to Reaper for the corresponding track
                     let _ = self.to_reaper.send(TrackMsg::TrackDataMsg(TrackDataMsg {
                         direction: Direction::Upstream,
                         guid: guid.clone(),
-                        data: TrackDataPayload::Volume(fader_msg.value as f32), // TODO: Need to scale appropriately
+                        data: TrackDataPayload::Volume(fader_msg.position()), // TODO: Need to scale appropriately
                     }));
                 }
                 curr_mode
             }
             XTouchUpstreamMsg::MutePress(mute_msg) => {
-                if let Some(guid) = self.get_guid_for_hw_channel(mute_msg.idx as usize) {
+                if let Some(guid) = self.get_guid_for_hw_channel(mute_msg.idx) {
                     let new_state = self.get_track_state(guid.clone()).mute.toggle();
                     // Send mute toggle to Reaper for the corresponding track
                     self.to_reaper
@@ -428,17 +446,17 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                         }))
                         .unwrap();
                     // Update the toggle on the hardware
-                    self.to_xtouch
+                    let _ = self
+                        .to_xtouch
                         .send(XTouchDownstreamMsg::MuteLED(xtouch::MuteLEDMsg {
                             idx: mute_msg.idx,
                             state: LEDState::from(new_state),
-                        }))
-                        .unwrap();
+                        }));
                 }
                 curr_mode
             }
             XTouchUpstreamMsg::SoloPress(solo_msg) => {
-                if let Some(guid) = self.get_guid_for_hw_channel(solo_msg.idx as usize) {
+                if let Some(guid) = self.get_guid_for_hw_channel(solo_msg.idx) {
                     let new_state = self.get_track_state(guid.clone()).solo.toggle();
                     // Send solo toggle to Reaper for the corresponding track
                     self.to_reaper
@@ -448,17 +466,17 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                             data: TrackDataPayload::Soloed(new_state),
                         }))
                         .unwrap();
-                    self.to_xtouch
+                    let _ = self
+                        .to_xtouch
                         .send(XTouchDownstreamMsg::SoloLED(xtouch::SoloLEDMsg {
                             idx: solo_msg.idx,
                             state: LEDState::from(new_state),
-                        }))
-                        .unwrap();
+                        }));
                 }
                 curr_mode
             }
             XTouchUpstreamMsg::ArmPress(arm_msg) => {
-                if let Some(guid) = self.get_guid_for_hw_channel(arm_msg.idx as usize) {
+                if let Some(guid) = self.get_guid_for_hw_channel(arm_msg.idx) {
                     let new_state = self.get_track_state(guid.clone()).arm.toggle();
                     // Send arm toggle to Reaper for the corresponding track
                     self.to_reaper
@@ -468,12 +486,12 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                             data: TrackDataPayload::Armed(new_state),
                         }))
                         .unwrap();
-                    self.to_xtouch
+                    let _ = self
+                        .to_xtouch
                         .send(XTouchDownstreamMsg::ArmLED(xtouch::ArmLEDMsg {
                             idx: arm_msg.idx,
                             state: LEDState::from(new_state),
-                        }))
-                        .unwrap();
+                        }));
                 }
                 curr_mode
             }
@@ -0,0 +1,327 @@
+use crossbeam_channel::Sender;
+
+use crate::guid::TrackGuid;
+use crate::midi::xtouch::{
+    AssignmentDisplayMsg, ChannelIndex, EncoderRingLEDBlankMsg, EncoderRingLEDMsg,
+    EncoderRingLEDRangeFillMsg, ScribbleColor, ScribbleStripMsg, XTouchDownstreamMsg,
+    XTouchUpstreamMsg,
+};
+use crate::modes::mode_manager::{Barrier, Mode, ModeHandler, ModeState, State};
+use crate::track::track::{
+    DataPayload as TrackDataPayload, Direction, FXBypass, FXWet, TrackDataMsg, TrackMsg, TrackQuery,
+};
+
+// How far one encoder detent moves the wet/dry mix
+const WET_STEP: f32 = 0.05;
+
+#[derive(Clone)]
+struct FxState {
+    name: String,
+    bypass: bool,
+    wet: f32,
+}
+
+impl FxState {
+    fn new() -> Self {
+        FxState {
+            name: String::new(),
+            bypass: false,
+            wet: 1.0,
+        }
+    }
+}
+
+/// Implements a mode where each channel strip controls one FX on the selected track:
+/// - The scribble strip shows the FX name and whether it is bypassed
+/// - Pressing the encoder toggles bypass
+/// - Turning the encoder while holding Shift adjusts the wet/dry mix, shown on the encoder ring
+///
+/// Strip N controls the FX in slot N; FX past the last strip are not reachable.
+pub struct FxMode {
+    num_channels: usize,
+    // The track whose FX are shown, chosen when entering the mode
+    track_guid: Option<TrackGuid>,
+    // State for each FX on the track by FX index
+    fx: Vec<FxState>,
+    shift_held: bool,
+    to_reaper: Sender<TrackMsg>,
+    to_xtouch: Sender<XTouchDownstreamMsg>,
+}
+
+impl FxMode {
+    pub fn new(
+        num_channels: usize,
+        to_reaper: Sender<TrackMsg>,
+        to_xtouch: Sender<XTouchDownstreamMsg>,
+    ) -> Self {
+        FxMode {
+            num_channels,
+            track_guid: None,
+            fx: Vec::new(),
+            shift_held: false,
+            to_reaper,
+            to_xtouch,
+        }
+    }
+
+    fn get_fx_state(&mut self, fx_index: usize) -> &mut FxState {
+        if self.fx.len() <= fx_index {
+            self.fx.resize(fx_index + 1, FxState::new());
+        }
+        &mut self.fx[fx_index]
+    }
+
+    // Sends the state of the FX shown on a hardware channel, or blanks the channel if the track
+    // has no FX in that slot
+    fn send_fx_state(&self, hw_channel: ChannelIndex) {
+        let Some(fx) = self.fx.get(hw_channel.get()) else {
+            let _ = self.to_xtouch.send(XTouchDownstreamMsg::EncoderRingLED(
+                EncoderRingLEDMsg::Blank(EncoderRingLEDBlankMsg { idx: hw_channel }),
+            ));
+            let _ = self
+                .to_xtouch
+                .send(XTouchDownstreamMsg::ScribbleStrip(ScribbleStripMsg {
+                    idx: hw_channel,
+                    top_line: String::new(),
+                    bottom_line: String::new(),
+                    color: ScribbleColor::Off,
+                }));
+            return;
+        };
+        let _ = self.to_xtouch.send(XTouchDownstreamMsg::EncoderRingLED(
+            EncoderRingLEDMsg::RangeFill(EncoderRingLEDRangeFillMsg {
+                idx: hw_channel,
+                pos: fx.wet,
+            }),
+        ));
+        let (bottom_line, color) = match fx.bypass {
+            true => ("Bypass", ScribbleColor::Red),
+            false => ("Active", ScribbleColor::Green),
+        };
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::ScribbleStrip(ScribbleStripMsg {
+                idx: hw_channel,
+                top_line: fx.name.clone(),
+                bottom_line: bottom_line.to_string(),
+                color,
+            }));
+    }
+
+    fn send_all_fx_state(&self) {
+        for hw_channel in 0..self.num_channels {
+            if let Ok(hw_channel) = ChannelIndex::try_from(hw_channel) {
+                self.send_fx_state(hw_channel);
+            }
+        }
+    }
+
+    // Refreshes the hardware channel showing the given FX, if it is on the surface
+    fn refresh_fx(&self, fx_index: i32) {
+        if let Ok(hw_channel) = ChannelIndex::try_from(fx_index)
+            .and_then(|hw_channel| hw_channel.within(self.num_channels))
+        {
+            self.send_fx_state(hw_channel);
+        }
+    }
+
+    // Validates an FX index from Reaper and returns its slot
+    fn checked_fx_index(fx_index: i32) -> Option<usize> {
+        usize::try_from(fx_index).ok()
+    }
+
+    // Moves the wet/dry mix of the FX on a hardware channel and sends it to Reaper
+    fn adjust_wet(&mut self, hw_channel: ChannelIndex, delta: f32) {
+        let Some(guid) = self.track_guid.clone() else {
+            return;
+        };
+        if hw_channel.get() >= self.fx.len() {
+            return;
+        }
+        let fx = self.get_fx_state(hw_channel.get());
+        fx.wet = (fx.wet + delta).clamp(0.0, 1.0);
+        let wet = fx.wet;
+        let _ = self.to_reaper.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+            direction: Direction::Upstream,
+            guid,
+            data: TrackDataPayload::FXWet(FXWet {
+                fx_index: hw_channel.into(),
+                wet,
+            }),
+        }));
+        self.send_fx_state(hw_channel);
+    }
+}
+
+impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for FxMode {
+    fn handle_downstream_messages(&mut self, msg: TrackMsg, curr_mode: ModeState) -> ModeState {
+        if let TrackMsg::Barrier(barrier) = msg {
+            // Forward barriers downstream (they need to reflect back upstream for the mode to
+            // transition)
+            let _ = self.to_xtouch.send(XTouchDownstreamMsg::Barrier(barrier));
+            match curr_mode.state {
+                // If we were already waiting on a barrier from upstream, check if this is the one
+                // we were waiting for. If yes, transition to waiting for the barrier to reflect back up from downstream.
+                State::WaitingBarrierFromUpstream(expected_barrier)
+                    if barrier == expected_barrier =>
+                {
+                    return ModeState {
+                        mode: curr_mode.mode,
+                        state: State::WaitingBarrierFromDownstream(barrier),
+                    };
+                }
+                _ => return curr_mode,
+            }
+        }
+        if let TrackMsg::TrackDataMsg(msg) = msg {
+            // Only the FX of the track we entered the mode with are shown
+            if self.track_guid.as_ref() != Some(&msg.guid) {
+                return curr_mode;
+            }
+            match msg.data {
+                // Response to the query sent when entering the mode
+                TrackDataPayload::TrackData(track_data) => {
+                    self.fx = track_data
+                        .fx()
+                        .iter()
+                        .map(|fx| FxState {
+                            name: fx.name.clone(),
+                            bypass: fx.bypass,
+                            wet: fx.wet,
+                        })
+                        .collect();
+                    self.send_all_fx_state();
+                }
+                TrackDataPayload::FXName(fx_name) => {
+                    if let Some(fx_index) = Self::checked_fx_index(fx_name.fx_index) {
+                        self.get_fx_state(fx_index).name = fx_name.name;
+                        self.refresh_fx(fx_name.fx_index);
+                    }
+                }
+                TrackDataPayload::FXBypass(fx_bypass) => {
+                    if let Some(fx_index) = Self::checked_fx_index(fx_bypass.fx_index) {
+                        self.get_fx_state(fx_index).bypass = fx_bypass.bypass;
+                        self.refresh_fx(fx_bypass.fx_index);
+                    }
+                }
+                TrackDataPayload::FXWet(fx_wet) => {
+                    if let Some(fx_index) = Self::checked_fx_index(fx_wet.fx_index) {
+                        self.get_fx_state(fx_index).wet = fx_wet.wet;
+                        self.refresh_fx(fx_wet.fx_index);
+                    }
+                }
+                _ => {
+                    // Ignore unhandled payloads
+                    return curr_mode;
+                }
+            }
+        }
+        curr_mode
+    }
+
+    fn handle_upstream_messages(
+        &mut self,
+        msg: XTouchUpstreamMsg,
+        curr_mode: ModeState,
+    ) -> ModeState {
+        match msg {
+            // If we were already waiting on a barrier from downstream, check if this is the one
+            // we were waiting for. If yes, the state transition is finished.
+            XTouchUpstreamMsg::Barrier(barrier) => match curr_mode.state {
+                State::WaitingBarrierFromDownstream(expected_barrier) => {
+                    if barrier == expected_barrier {
+                        ModeState {
+                            mode: curr_mode.mode,
+                            state: State::Active,
+                        }
+                    } else {
+                        curr_mode
+                    }
+                }
+                _ => curr_mode,
+            },
+            XTouchUpstreamMsg::GlobalPress => ModeState {
+                mode: Mode::ReaperVolPan,
+                state: State::RequestingModeTransition,
+            },
+            XTouchUpstreamMsg::MIDITracksPress => ModeState {
+                mode: Mode::ReaperSends,
+                state: State::RequestingModeTransition,
+            },
+            XTouchUpstreamMsg::PluginPress => curr_mode, // PluginPress maps to this mode!
+            XTouchUpstreamMsg::InstPress => ModeState {
+                mode: Mode::ReaperFxParams,
+                state: State::RequestingModeTransition,
+            },
+            XTouchUpstreamMsg::ShiftPress => {
+                self.shift_held = true;
+                curr_mode
+            }
+            XTouchUpstreamMsg::ShiftRelease => {
+                self.shift_held = false;
+                curr_mode
+            }
+            XTouchUpstreamMsg::EncoderPress(encoder_msg) => {
+                let Some(guid) = self.track_guid.clone() else {
+                    return curr_mode;
+                };
+                let Some(fx) = self.fx.get_mut(encoder_msg.idx.get()) else {
+                    return curr_mode;
+                };
+                fx.bypass = !fx.bypass;
+                let bypass = fx.bypass;
+                let _ = self.to_reaper.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                    direction: Direction::Upstream,
+                    guid,
+                    data: TrackDataPayload::FXBypass(FXBypass {
+                        fx_index: encoder_msg.idx.into(),
+                        bypass,
+                    }),
+                }));
+                self.send_fx_state(encoder_msg.idx);
+                curr_mode
+            }
+            XTouchUpstreamMsg::EncoderTurnInc(encoder_msg) => {
+                if self.shift_held {
+                    self.adjust_wet(encoder_msg.idx, WET_STEP);
+                }
+                curr_mode
+            }
+            XTouchUpstreamMsg::EncoderTurnDec(encoder_msg) => {
+                if self.shift_held {
+                    self.adjust_wet(encoder_msg.idx, -WET_STEP);
+                }
+                curr_mode
+            }
+            _ => curr_mode,
+        }
+    }
+}
+
+impl FxMode {
+    pub fn initiate_mode_transition(
+        &mut self,
+        upstream: Sender<TrackMsg>,
+        selected_track_guid: &TrackGuid,
+    ) -> ModeState {
+        // Start from a clean slate; the query below fills in the selected track's FX
+        self.track_guid = Some(selected_track_guid.clone());
+        self.fx.clear();
+        self.shift_held = false;
+        upstream
+            .send(TrackMsg::TrackQuery(TrackQuery {
+                direction: Direction::Downstream,
+                guid: selected_track_guid.clone(),
+            }))
+            .unwrap();
+        let _ = self.to_xtouch.send(XTouchDownstreamMsg::AssignmentDisplay(
+            AssignmentDisplayMsg::mode_bank('F', 0),
+        ));
+        let barrier = Barrier::new();
+        upstream.send(TrackMsg::Barrier(barrier)).unwrap();
+        ModeState {
+            mode: Mode::ReaperFX,
+            state: State::WaitingBarrierFromDownstream(barrier),
+        }
+    }
+}
@@ -0,0 +1,410 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crossbeam_channel::Sender;
+
+use crate::guid::TrackGuid;
+use crate::midi::xtouch::{
+    AssignmentDisplayMsg, ChannelIndex, EncoderRingLEDBlankMsg, EncoderRingLEDMsg,
+    EncoderRingLEDRangeFillMsg, FaderAbsMsg, ScribbleColor, ScribbleStripMsg, XTouchDownstreamMsg,
+    XTouchUpstreamMsg,
+};
+use crate::modes::mode_manager::{Barrier, Mode, ModeHandler, ModeState, State};
+use crate::track::track::{
+    DataPayload as TrackDataPayload, Direction, FXParamValue, TrackDataMsg, TrackMsg, TrackQuery,
+};
+
+// How far one encoder detent moves a parameter, as a fraction of its range
+const PARAM_STEP: f32 = 0.01;
+
+#[derive(Clone)]
+struct ParamState {
+    name: String,
+    value: f32,
+    min: f32,
+    max: f32,
+}
+
+impl ParamState {
+    fn new() -> Self {
+        ParamState {
+            name: String::new(),
+            value: 0.0,
+            min: 0.0,
+            max: 1.0,
+        }
+    }
+
+    // Where the value sits in the parameter's range, for the fader and encoder ring
+    fn position(&self) -> f32 {
+        match self.max > self.min {
+            true => ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0),
+            false => 0.0,
+        }
+    }
+
+    fn value_at(&self, position: f32) -> f32 {
+        self.min + position.clamp(0.0, 1.0) * (self.max - self.min)
+    }
+}
+
+/// Implements a mode where the channel strips control the FX parameters of the selected track:
+/// - Every parameter of every FX is laid out in order, one per strip, a page at a time
+/// - The scribble strip shows the parameter name over the name of its FX
+/// - The fader sets the parameter; turning the encoder nudges it, and the ring shows where it is
+/// - Bank left/right page through the parameters
+pub struct FxParamsMode {
+    num_channels: usize,
+    // The track whose FX parameters are shown, chosen when entering the mode
+    track_guid: Option<TrackGuid>,
+    // Parameters by (FX index, parameter index), which is also the order they are laid out in
+    params: BTreeMap<(i32, i32), ParamState>,
+    // FX names by FX index, for the bottom line of the scribble strips
+    fx_names: HashMap<i32, String>,
+    // The page currently shown on the hardware; page N shows parameters starting at
+    // N * num_channels
+    page: usize,
+    to_reaper: Sender<TrackMsg>,
+    to_xtouch: Sender<XTouchDownstreamMsg>,
+}
+
+impl FxParamsMode {
+    pub fn new(
+        num_channels: usize,
+        to_reaper: Sender<TrackMsg>,
+        to_xtouch: Sender<XTouchDownstreamMsg>,
+    ) -> Self {
+        FxParamsMode {
+            num_channels,
+            track_guid: None,
+            params: BTreeMap::new(),
+            fx_names: HashMap::new(),
+            page: 0,
+            to_reaper,
+            to_xtouch,
+        }
+    }
+
+    /// Returns the page of parameters currently shown on the hardware.
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    // The parameter shown on a hardware channel of the current page, if there is one
+    fn param_on_channel(&self, hw_channel: ChannelIndex) -> Option<((i32, i32), &ParamState)> {
+        self.params
+            .iter()
+            .nth(self.page * self.num_channels + hw_channel.get())
+            .map(|(&key, param)| (key, param))
+    }
+
+    // The hardware channel showing a parameter, if it is on the current page
+    fn channel_for_param(&self, key: (i32, i32)) -> Option<ChannelIndex> {
+        let slot = self.params.keys().position(|&k| k == key)?;
+        let offset = slot.checked_sub(self.page * self.num_channels)?;
+        ChannelIndex::try_from(offset)
+            .and_then(|hw_channel| hw_channel.within(self.num_channels))
+            .ok()
+    }
+
+    fn get_param_state(&mut self, fx_index: i32, param_index: i32) -> &mut ParamState {
+        self.params
+            .entry((fx_index, param_index))
+            .or_insert_with(ParamState::new)
+    }
+
+    // Sends the state of the parameter shown on a hardware channel, or blanks the channel if the
+    // current page runs out of parameters before it
+    fn send_param_state(&self, hw_channel: ChannelIndex) {
+        let Some(((fx_index, _), param)) = self.param_on_channel(hw_channel) else {
+            let _ = self
+                .to_xtouch
+                .send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg {
+                    idx: hw_channel,
+                    value: 0.0,
+                }));
+            let _ = self.to_xtouch.send(XTouchDownstreamMsg::EncoderRingLED(
+                EncoderRingLEDMsg::Blank(EncoderRingLEDBlankMsg { idx: hw_channel }),
+            ));
+            let _ = self
+                .to_xtouch
+                .send(XTouchDownstreamMsg::ScribbleStrip(ScribbleStripMsg {
+                    idx: hw_channel,
+                    top_line: String::new(),
+                    bottom_line: String::new(),
+                    color: ScribbleColor::Off,
+                }));
+            return;
+        };
+        let position = param.position();
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg::at(
+                hw_channel, position,
+            )));
+        let _ = self.to_xtouch.send(XTouchDownstreamMsg::EncoderRingLED(
+            EncoderRingLEDMsg::RangeFill(EncoderRingLEDRangeFillMsg {
+                idx: hw_channel,
+                pos: position,
+            }),
+        ));
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::ScribbleStrip(ScribbleStripMsg {
+                idx: hw_channel,
+                top_line: param.name.clone(),
+                bottom_line: self.fx_names.get(&fx_index).cloned().unwrap_or_default(),
+                color: ScribbleColor::Magenta,
+            }));
+    }
+
+    fn send_all_param_state(&self) {
+        for hw_channel in 0..self.num_channels {
+            if let Ok(hw_channel) = ChannelIndex::try_from(hw_channel) {
+                self.send_param_state(hw_channel);
+            }
+        }
+    }
+
+    // Refreshes whatever a change to a parameter affects: just its own channel if it was already
+    // known, or the whole page if it's new, since it pushes the parameters after it along
+    fn refresh_param(&self, key: (i32, i32), is_new: bool) {
+        match is_new {
+            true => self.send_all_param_state(),
+            false => {
+                if let Some(hw_channel) = self.channel_for_param(key) {
+                    self.send_param_state(hw_channel);
+                }
+            }
+        }
+    }
+
+    fn send_assignment_display(&self) {
+        let _ = self.to_xtouch.send(XTouchDownstreamMsg::AssignmentDisplay(
+            AssignmentDisplayMsg::mode_bank('P', self.page),
+        ));
+    }
+
+    // Paging right is only allowed if there is at least one parameter on the next page
+    fn has_page(&self, page: usize) -> bool {
+        self.params.len() > page * self.num_channels
+    }
+
+    fn set_page(&mut self, page: usize) {
+        self.page = page;
+        self.send_assignment_display();
+        self.send_all_param_state();
+    }
+
+    // Moves the parameter on a hardware channel to a position in its range and sends it to Reaper
+    fn set_param_position(&mut self, hw_channel: ChannelIndex, position: f32) {
+        let Some(guid) = self.track_guid.clone() else {
+            return;
+        };
+        let Some((key, _)) = self.param_on_channel(hw_channel) else {
+            return;
+        };
+        let param = self.get_param_state(key.0, key.1);
+        param.value = param.value_at(position);
+        let value = param.value;
+        let _ = self.to_reaper.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+            direction: Direction::Upstream,
+            guid,
+            data: TrackDataPayload::FXParamValue(FXParamValue {
+                fx_index: key.0,
+                param_index: key.1,
+                value,
+            }),
+        }));
+    }
+
+    // Nudges the parameter on a hardware channel by some number of encoder detents
+    fn step_param(&mut self, hw_channel: ChannelIndex, steps: f32) {
+        let Some((_, param)) = self.param_on_channel(hw_channel) else {
+            return;
+        };
+        let position = param.position() + steps * PARAM_STEP;
+        self.set_param_position(hw_channel, position);
+        self.send_param_state(hw_channel);
+    }
+}
+
+impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for FxParamsMode {
+    fn handle_downstream_messages(&mut self, msg: TrackMsg, curr_mode: ModeState) -> ModeState {
+        if let TrackMsg::Barrier(barrier) = msg {
+            // Forward barriers downstream (they need to reflect back upstream for the mode to
+            // transition)
+            let _ = self.to_xtouch.send(XTouchDownstreamMsg::Barrier(barrier));
+            match curr_mode.state {
+                // If we were already waiting on a barrier from upstream, check if this is the one
+                // we were waiting for. If yes, transition to waiting for the barrier to reflect back up from downstream.
+                State::WaitingBarrierFromUpstream(expected_barrier)
+                    if barrier == expected_barrier =>
+                {
+                    return ModeState {
+                        mode: curr_mode.mode,
+                        state: State::WaitingBarrierFromDownstream(barrier),
+                    };
+                }
+                _ => return curr_mode,
+            }
+        }
+        if let TrackMsg::TrackDataMsg(msg) = msg {
+            // Only the parameters of the track we entered the mode with are shown
+            if self.track_guid.as_ref() != Some(&msg.guid) {
+                return curr_mode;
+            }
+            match msg.data {
+                // Response to the query sent when entering the mode
+                TrackDataPayload::TrackData(track_data) => {
+                    self.params.clear();
+                    self.fx_names.clear();
+                    for fx in track_data.fx() {
+                        self.fx_names.insert(fx.fx_index, fx.name.clone());
+                        for param in &fx.params {
+                            self.params.insert(
+                                (fx.fx_index, param.param_index),
+                                ParamState {
+                                    name: param.name.clone(),
+                                    value: param.value,
+                                    min: param.min,
+                                    max: param.max,
+                                },
+                            );
+                        }
+                    }
+                    self.send_all_param_state();
+                }
+                TrackDataPayload::FXName(fx_name) => {
+                    self.fx_names.insert(fx_name.fx_index, fx_name.name);
+                    // Every parameter of the FX is labelled with its name
+                    self.send_all_param_state();
+                }
+                TrackDataPayload::FXParamName(param_name) => {
+                    let key = (param_name.fx_index, param_name.param_index);
+                    let is_new = !self.params.contains_key(&key);
+                    self.get_param_state(key.0, key.1).name = param_name.name;
+                    self.refresh_param(key, is_new);
+                }
+                TrackDataPayload::FXParamValue(param_value) => {
+                    let key = (param_value.fx_index, param_value.param_index);
+                    let is_new = !self.params.contains_key(&key);
+                    self.get_param_state(key.0, key.1).value = param_value.value;
+                    self.refresh_param(key, is_new);
+                }
+                TrackDataPayload::FXParamMin(param_min) => {
+                    let key = (param_min.fx_index, param_min.param_index);
+                    let is_new = !self.params.contains_key(&key);
+                    self.get_param_state(key.0, key.1).min = param_min.min;
+                    self.refresh_param(key, is_new);
+                }
+                TrackDataPayload::FXParamMax(param_max) => {
+                    let key = (param_max.fx_index, param_max.param_index);
+                    let is_new = !self.params.contains_key(&key);
+                    self.get_param_state(key.0, key.1).max = param_max.max;
+                    self.refresh_param(key, is_new);
+                }
+                _ => {
+                    // Ignore unhandled payloads
+                    return curr_mode;
+                }
+            }
+        }
+        curr_mode
+    }
+
+    fn handle_upstream_messages(
+        &mut self,
+        msg: XTouchUpstreamMsg,
+        curr_mode: ModeState,
+    ) -> ModeState {
+        match msg {
+            // If we were already waiting on a barrier from downstream, check if this is the one
+            // we were waiting for. If yes, the state transition is finished.
+            XTouchUpstreamMsg::Barrier(barrier) => match curr_mode.state {
+                State::WaitingBarrierFromDownstream(expected_barrier)
+                    if barrier == expected_barrier =>
+                {
+                    ModeState {
+                        mode: curr_mode.mode,
+                        state: State::Active,
+                    }
+                }
+                _ => curr_mode,
+            },
+            XTouchUpstreamMsg::GlobalPress => ModeState {
+                mode: Mode::ReaperVolPan,
+                state: State::RequestingModeTransition,
+            },
+            XTouchUpstreamMsg::MIDITracksPress => ModeState {
+                mode: Mode::ReaperSends,
+                state: State::RequestingModeTransition,
+            },
+            XTouchUpstreamMsg::PluginPress => ModeState {
+                mode: Mode::ReaperFX,
+                state: State::RequestingModeTransition,
+            },
+            XTouchUpstreamMsg::InstPress => curr_mode, // InstPress maps to this mode!
+            XTouchUpstreamMsg::BankLeftPress => {
+                if self.page > 0 {
+                    self.set_page(self.page - 1);
+                }
+                curr_mode
+            }
+            XTouchUpstreamMsg::BankRightPress => {
+                if self.has_page(self.page + 1) {
+                    self.set_page(self.page + 1);
+                }
+                curr_mode
+            }
+            // The fader is already where the user put it, so only the ring needs to follow
+            XTouchUpstreamMsg::FaderAbs(fader_msg) => {
+                self.set_param_position(fader_msg.idx, fader_msg.position());
+                if let Some((_, param)) = self.param_on_channel(fader_msg.idx) {
+                    let _ = self.to_xtouch.send(XTouchDownstreamMsg::EncoderRingLED(
+                        EncoderRingLEDMsg::RangeFill(EncoderRingLEDRangeFillMsg {
+                            idx: fader_msg.idx,
+                            pos: param.position(),
+                        }),
+                    ));
+                }
+                curr_mode
+            }
+            XTouchUpstreamMsg::EncoderTurnInc(encoder_msg) => {
+                self.step_param(encoder_msg.idx, 1.0);
+                curr_mode
+            }
+            XTouchUpstreamMsg::EncoderTurnDec(encoder_msg) => {
+                self.step_param(encoder_msg.idx, -1.0);
+                curr_mode
+            }
+            _ => curr_mode,
+        }
+    }
+}
+
+impl FxParamsMode {
+    pub fn initiate_mode_transition(
+        &mut self,
+        upstream: Sender<TrackMsg>,
+        selected_track_guid: &TrackGuid,
+    ) -> ModeState {
+        // Start from a clean slate; the query below fills in the selected track's parameters
+        self.track_guid = Some(selected_track_guid.clone());
+        self.params.clear();
+        self.fx_names.clear();
+        self.page = 0;
+        upstream
+            .send(TrackMsg::TrackQuery(TrackQuery {
+                direction: Direction::Downstream,
+                guid: selected_track_guid.clone(),
+            }))
+            .unwrap();
+        self.send_assignment_display();
+        let barrier = Barrier::new();
+        upstream.send(TrackMsg::Barrier(barrier)).unwrap();
+        ModeState {
+            mode: Mode::ReaperFxParams,
+            state: State::WaitingBarrierFromDownstream(barrier),
+        }
+    }
+}
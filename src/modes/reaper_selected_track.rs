@@ -0,0 +1,515 @@
+use std::collections::HashMap;
+
+use crossbeam_channel::Sender;
+use tracing::debug;
+
+use crate::guid::TrackGuid;
+use crate::midi::xtouch::{
+    ArmLEDMsg, AssignmentDisplayMsg, ChannelIndex, EncoderRingLEDBlankMsg, EncoderRingLEDMsg,
+    EncoderRingLEDRangeFillMsg, EncoderRingLEDRangePointMsg, FaderAbsMsg, LEDState, MuteLEDMsg,
+    ScribbleColor, ScribbleStripMsg, SelectLEDMsg, SoloLEDMsg, XTouchDownstreamMsg,
+    XTouchUpstreamMsg,
+};
+use crate::modes::mode_manager::{Barrier, Mode, ModeHandler, ModeState, State};
+use crate::modes::taper::Taper;
+use crate::track::track::{
+    DataPayload as TrackDataPayload, Direction, SendLevel, TrackDataMsg, TrackMsg, TrackQuery,
+};
+
+// The strip whose fader and buttons control the track itself. The track's sends follow it, one
+// per strip.
+const TRACK_STRIP: usize = 0;
+// Which encoder moves what
+const PAN_ENCODER: usize = 0;
+const WIDTH_ENCODER: usize = 1;
+const INPUT_GAIN_ENCODER: usize = 2;
+
+// How far one detent moves pan or width, which both run from -1.0 to 1.0
+const PAN_STEP: f32 = 0.1;
+// How far one detent moves the input gain, which runs from 0.0 to 1.0
+const INPUT_GAIN_STEP: f32 = 0.02;
+
+/// What we know of the track shown on the surface
+#[derive(Clone, Debug)]
+pub struct ChannelStripState {
+    pub muted: bool,
+    pub soloed: bool,
+    pub armed: bool,
+    /// Whether the track's polarity is inverted
+    pub phase: bool,
+    pub pan: f32,
+    pub width: f32,
+    pub input_gain: f32,
+}
+
+impl Default for ChannelStripState {
+    fn default() -> Self {
+        ChannelStripState {
+            muted: false,
+            soloed: false,
+            armed: false,
+            phase: false,
+            pan: 0.0,
+            // Reaper's tracks start out at full width
+            width: 1.0,
+            input_gain: 0.0,
+        }
+    }
+}
+
+/// Implements a mode that gives the whole surface over to the selected track.
+///
+/// The first strip is the track itself: its fader sets the volume, and its mute, solo, arm and
+/// select buttons toggle mute, solo, record arm and phase. The first three encoders set the pan,
+/// width and input gain. The faders of the remaining strips set the levels of the track's first
+/// sends, labelled with their destination track.
+pub struct SelectedTrackMode {
+    num_channels: usize,
+    track: ChannelStripState,
+    // Destination track of each send shown, by send index
+    sends: Vec<Option<TrackGuid>>,
+    // How volume is laid out along the faders, for the track and its sends alike
+    taper: Taper,
+    // Names of every track by GUID, used to label the track and its sends
+    track_names: HashMap<TrackGuid, String>,
+    selected_track_guid: Option<TrackGuid>,
+    to_reaper: Sender<TrackMsg>,
+    to_xtouch: Sender<XTouchDownstreamMsg>,
+}
+
+impl SelectedTrackMode {
+    pub fn new(
+        num_channels: usize,
+        to_reaper: Sender<TrackMsg>,
+        to_xtouch: Sender<XTouchDownstreamMsg>,
+    ) -> Self {
+        SelectedTrackMode {
+            num_channels,
+            track: ChannelStripState::default(),
+            sends: vec![None; num_channels.saturating_sub(TRACK_STRIP + 1)],
+            taper: Taper::default(),
+            track_names: HashMap::new(),
+            selected_track_guid: None,
+            to_reaper,
+            to_xtouch,
+        }
+    }
+
+    /// Replaces the taper volumes are shown on the faders with.
+    pub fn set_taper(&mut self, taper: Taper) {
+        self.taper = taper;
+    }
+
+    /// The track shown, once the mode has been entered.
+    pub fn selected_track_guid(&self) -> Option<&TrackGuid> {
+        self.selected_track_guid.as_ref()
+    }
+
+    /// Records a track's name so that sends to it can be labelled.
+    ///
+    /// Send destinations are other tracks, so this needs to be kept up to date even while the
+    /// mode is not active.
+    pub fn set_track_name(&mut self, guid: &TrackGuid, name: &str) {
+        self.track_names.insert(guid.clone(), name.to_string());
+    }
+
+    // The hardware channel showing a send, if the surface has room for it
+    fn send_channel(&self, send_index: i32) -> Option<ChannelIndex> {
+        let send_index = usize::try_from(send_index).ok()?;
+        match send_index < self.sends.len() {
+            true => ChannelIndex::try_from(send_index + TRACK_STRIP + 1).ok(),
+            false => None,
+        }
+    }
+
+    // The send shown on a hardware channel, if there is one
+    fn send_on_channel(&self, hw_channel: ChannelIndex) -> Option<usize> {
+        let send_index = hw_channel.get().checked_sub(TRACK_STRIP + 1)?;
+        self.sends.get(send_index)?.as_ref().map(|_| send_index)
+    }
+
+    // Labels a hardware channel with what its fader sets, and what its encoder sets if it has a
+    // job
+    fn send_scribble_strip(&self, hw_channel: ChannelIndex) {
+        let name_of = |guid: Option<&TrackGuid>| {
+            guid.and_then(|guid| self.track_names.get(guid))
+                .cloned()
+                .unwrap_or_default()
+        };
+        let (top_line, color) = match hw_channel.get() {
+            TRACK_STRIP => (
+                name_of(self.selected_track_guid.as_ref()),
+                ScribbleColor::White,
+            ),
+            _ => match self.send_on_channel(hw_channel) {
+                Some(send_index) => (
+                    name_of(self.sends[send_index].as_ref()),
+                    ScribbleColor::Cyan,
+                ),
+                None => (String::new(), ScribbleColor::Off),
+            },
+        };
+        let bottom_line = match hw_channel.get() {
+            PAN_ENCODER => "Pan".to_string(),
+            WIDTH_ENCODER => "Width".to_string(),
+            INPUT_GAIN_ENCODER => "InGain".to_string(),
+            _ => match self.send_on_channel(hw_channel) {
+                Some(send_index) => format!("Send {}", send_index + 1),
+                None => String::new(),
+            },
+        };
+        // Strips with an encoder job stay lit even without a send
+        let color = match (color, bottom_line.is_empty()) {
+            (ScribbleColor::Off, false) => ScribbleColor::White,
+            (color, _) => color,
+        };
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::ScribbleStrip(ScribbleStripMsg {
+                idx: hw_channel,
+                top_line,
+                bottom_line,
+                color,
+            }));
+    }
+
+    // Points an encoder's ring at the value it sets, or blanks it if the encoder has no job
+    fn send_ring(&self, hw_channel: ChannelIndex) {
+        let ring = match hw_channel.get() {
+            PAN_ENCODER => EncoderRingLEDMsg::RangePoint(EncoderRingLEDRangePointMsg {
+                idx: hw_channel,
+                pos: (self.track.pan + 1.0) / 2.0,
+            }),
+            WIDTH_ENCODER => EncoderRingLEDMsg::RangePoint(EncoderRingLEDRangePointMsg {
+                idx: hw_channel,
+                pos: (self.track.width + 1.0) / 2.0,
+            }),
+            INPUT_GAIN_ENCODER => EncoderRingLEDMsg::RangeFill(EncoderRingLEDRangeFillMsg {
+                idx: hw_channel,
+                pos: self.track.input_gain,
+            }),
+            _ => EncoderRingLEDMsg::Blank(EncoderRingLEDBlankMsg { idx: hw_channel }),
+        };
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::EncoderRingLED(ring));
+    }
+
+    // Lights the track strip's buttons for mute, solo, arm and phase
+    fn send_button_leds(&self, hw_channel: ChannelIndex) {
+        let on_track_strip = hw_channel.get() == TRACK_STRIP;
+        let led = |state: bool| LEDState::from(on_track_strip && state);
+        let leds = [
+            XTouchDownstreamMsg::MuteLED(MuteLEDMsg {
+                idx: hw_channel,
+                state: led(self.track.muted),
+            }),
+            XTouchDownstreamMsg::SoloLED(SoloLEDMsg {
+                idx: hw_channel,
+                state: led(self.track.soloed),
+            }),
+            XTouchDownstreamMsg::ArmLED(ArmLEDMsg {
+                idx: hw_channel,
+                state: led(self.track.armed),
+            }),
+            XTouchDownstreamMsg::SelectLED(SelectLEDMsg {
+                idx: hw_channel,
+                state: led(self.track.phase),
+            }),
+        ];
+        for msg in leds {
+            let _ = self.to_xtouch.send(msg);
+        }
+    }
+
+    fn send_fader(&self, hw_channel: ChannelIndex, volume: f32) {
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg::at(
+                hw_channel,
+                self.taper.volume_to_fader(volume),
+            )));
+    }
+
+    // Redraws the whole surface from what we know. Faders go to the bottom until Reaper reports
+    // their levels.
+    fn send_all_state(&self) {
+        for hw_channel in 0..self.num_channels {
+            if let Ok(hw_channel) = ChannelIndex::try_from(hw_channel) {
+                self.send_scribble_strip(hw_channel);
+                self.send_ring(hw_channel);
+                self.send_button_leds(hw_channel);
+                self.send_fader(hw_channel, 0.0);
+            }
+        }
+    }
+
+    // Sends a change to the shown track up to Reaper
+    fn send_to_reaper(&self, data: TrackDataPayload) {
+        let Some(guid) = self.selected_track_guid.clone() else {
+            return;
+        };
+        self.to_reaper
+            .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                direction: Direction::Upstream,
+                guid,
+                data,
+            }))
+            .unwrap();
+    }
+
+    // Moves whatever an encoder sets by `detents`
+    fn turn_encoder(&mut self, hw_channel: ChannelIndex, detents: f32) {
+        let data = match hw_channel.get() {
+            PAN_ENCODER => {
+                self.track.pan = (self.track.pan + detents * PAN_STEP).clamp(-1.0, 1.0);
+                TrackDataPayload::Pan(self.track.pan)
+            }
+            WIDTH_ENCODER => {
+                self.track.width = (self.track.width + detents * PAN_STEP).clamp(-1.0, 1.0);
+                TrackDataPayload::Width(self.track.width)
+            }
+            INPUT_GAIN_ENCODER => {
+                self.track.input_gain =
+                    (self.track.input_gain + detents * INPUT_GAIN_STEP).clamp(0.0, 1.0);
+                TrackDataPayload::InputGain(self.track.input_gain)
+            }
+            _ => return,
+        };
+        self.send_to_reaper(data);
+        self.send_ring(hw_channel);
+    }
+
+    // Flips one of the track's toggles from the track strip's buttons
+    fn toggle(
+        &mut self,
+        hw_channel: ChannelIndex,
+        payload: fn(&mut ChannelStripState) -> TrackDataPayload,
+    ) {
+        if hw_channel.get() != TRACK_STRIP {
+            return;
+        }
+        let data = payload(&mut self.track);
+        self.send_to_reaper(data);
+        self.send_button_leds(hw_channel);
+    }
+
+    // Shows what Reaper reports for the shown track
+    fn handle_track_data(&mut self, data: TrackDataPayload) {
+        let track_strip = ChannelIndex::new(TRACK_STRIP as u8);
+        match data {
+            TrackDataPayload::Volume(volume) => self.send_fader(track_strip, volume),
+            TrackDataPayload::Pan(pan) => {
+                self.track.pan = pan;
+                self.send_ring(ChannelIndex::new(PAN_ENCODER as u8));
+            }
+            TrackDataPayload::Width(width) => {
+                self.track.width = width;
+                self.send_ring(ChannelIndex::new(WIDTH_ENCODER as u8));
+            }
+            TrackDataPayload::InputGain(input_gain) => {
+                self.track.input_gain = input_gain;
+                self.send_ring(ChannelIndex::new(INPUT_GAIN_ENCODER as u8));
+            }
+            TrackDataPayload::Muted(muted) => {
+                self.track.muted = muted;
+                self.send_button_leds(track_strip);
+            }
+            TrackDataPayload::Soloed(soloed) => {
+                self.track.soloed = soloed;
+                self.send_button_leds(track_strip);
+            }
+            TrackDataPayload::Armed(armed) => {
+                self.track.armed = armed;
+                self.send_button_leds(track_strip);
+            }
+            TrackDataPayload::Phase(phase) => {
+                self.track.phase = phase;
+                self.send_button_leds(track_strip);
+            }
+            TrackDataPayload::SendIndex(msg) => match self.send_channel(msg.send_index) {
+                Some(hw_channel) => {
+                    self.sends[hw_channel.get() - TRACK_STRIP - 1] = Some(msg.guid);
+                    self.send_scribble_strip(hw_channel);
+                }
+                None => debug!("No room on the surface for send {}", msg.send_index),
+            },
+            TrackDataPayload::SendLevel(msg) => {
+                if let Some(hw_channel) = self.send_channel(msg.send_index) {
+                    self.send_fader(hw_channel, msg.level);
+                }
+            }
+            _ => {
+                // Ignore unhandled payloads
+            }
+        }
+    }
+}
+
+impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for SelectedTrackMode {
+    fn handle_downstream_messages(&mut self, msg: TrackMsg, curr_mode: ModeState) -> ModeState {
+        match msg {
+            TrackMsg::Barrier(barrier) => {
+                // Forward barriers downstream (they need to reflect back upstream for the mode to
+                // transition)
+                let _ = self.to_xtouch.send(XTouchDownstreamMsg::Barrier(barrier));
+                match curr_mode.state {
+                    // If we were already waiting on a barrier from upstream, check if this is the
+                    // one we were waiting for. If yes, transition to waiting for the barrier to
+                    // reflect back up from downstream.
+                    State::WaitingBarrierFromUpstream(expected_barrier)
+                        if barrier == expected_barrier =>
+                    {
+                        ModeState {
+                            mode: curr_mode.mode,
+                            state: State::WaitingBarrierFromDownstream(barrier),
+                        }
+                    }
+                    _ => curr_mode,
+                }
+            }
+            TrackMsg::TrackDataMsg(msg) => {
+                let shown = self.selected_track_guid.as_ref() == Some(&msg.guid);
+                match (msg.data, shown) {
+                    (TrackDataPayload::Name(name), _) => {
+                        self.set_track_name(&msg.guid, &name);
+                        // Relabel the track strip if it's the shown track, and any sends to it
+                        for hw_channel in 0..self.num_channels {
+                            let Ok(hw_channel) = ChannelIndex::try_from(hw_channel) else {
+                                continue;
+                            };
+                            let labelled = match self.send_on_channel(hw_channel) {
+                                Some(send_index) => {
+                                    self.sends[send_index].as_ref() == Some(&msg.guid)
+                                }
+                                None => shown && hw_channel.get() == TRACK_STRIP,
+                            };
+                            if labelled {
+                                self.send_scribble_strip(hw_channel);
+                            }
+                        }
+                    }
+                    (data, true) => self.handle_track_data(data),
+                    // Other tracks are not shown
+                    (_, false) => {}
+                }
+                curr_mode
+            }
+            _ => curr_mode,
+        }
+    }
+
+    fn handle_upstream_messages(
+        &mut self,
+        msg: XTouchUpstreamMsg,
+        curr_mode: ModeState,
+    ) -> ModeState {
+        match msg {
+            // If we were already waiting on a barrier from downstream, check if this is the one
+            // we were waiting for. If yes, the state transition is finished.
+            //
+            // Note, we do not need to forward this barrier onward, since the hardware is not
+            // allowed to reflect barriers back upstream.
+            XTouchUpstreamMsg::Barrier(barrier) => match curr_mode.state {
+                State::WaitingBarrierFromDownstream(expected_barrier)
+                    if barrier == expected_barrier =>
+                {
+                    ModeState {
+                        mode: curr_mode.mode,
+                        state: State::Active,
+                    }
+                }
+                // A barrier for some old transition that has already been superseded
+                _ => curr_mode,
+            },
+            XTouchUpstreamMsg::GlobalPress => {
+                // Request transition to ReaperVolPan mode
+                ModeState {
+                    mode: Mode::ReaperVolPan,
+                    state: State::RequestingModeTransition,
+                }
+            }
+            XTouchUpstreamMsg::FaderAbs(fader_msg) => {
+                let level = self.taper.fader_to_volume(fader_msg.position());
+                match (fader_msg.idx.get(), self.send_on_channel(fader_msg.idx)) {
+                    (TRACK_STRIP, _) => self.send_to_reaper(TrackDataPayload::Volume(level)),
+                    (_, Some(send_index)) => {
+                        self.send_to_reaper(TrackDataPayload::SendLevel(SendLevel {
+                            send_index: send_index as i32,
+                            level,
+                        }))
+                    }
+                    // No send on this fader
+                    (_, None) => {}
+                }
+                curr_mode
+            }
+            XTouchUpstreamMsg::EncoderTurnInc(encoder_msg) => {
+                self.turn_encoder(encoder_msg.idx, 1.0);
+                curr_mode
+            }
+            XTouchUpstreamMsg::EncoderTurnDec(encoder_msg) => {
+                self.turn_encoder(encoder_msg.idx, -1.0);
+                curr_mode
+            }
+            XTouchUpstreamMsg::MutePress(press) => {
+                self.toggle(press.idx, |track| {
+                    track.muted = !track.muted;
+                    TrackDataPayload::Muted(track.muted)
+                });
+                curr_mode
+            }
+            XTouchUpstreamMsg::SoloPress(press) => {
+                self.toggle(press.idx, |track| {
+                    track.soloed = !track.soloed;
+                    TrackDataPayload::Soloed(track.soloed)
+                });
+                curr_mode
+            }
+            XTouchUpstreamMsg::ArmPress(press) => {
+                self.toggle(press.idx, |track| {
+                    track.armed = !track.armed;
+                    TrackDataPayload::Armed(track.armed)
+                });
+                curr_mode
+            }
+            XTouchUpstreamMsg::SelectPress(press) => {
+                self.toggle(press.idx, |track| {
+                    track.phase = !track.phase;
+                    TrackDataPayload::Phase(track.phase)
+                });
+                curr_mode
+            }
+            _ => curr_mode, // For now, the other buttons do nothing
+        }
+    }
+}
+
+impl SelectedTrackMode {
+    pub fn initiate_mode_transition(
+        &mut self,
+        upstream: Sender<TrackMsg>,
+        selected_track_guid: &TrackGuid,
+    ) -> ModeState {
+        // Start from a clean slate; the query below fills in the selected track
+        self.selected_track_guid = Some(selected_track_guid.clone());
+        self.track = ChannelStripState::default();
+        self.sends.iter_mut().for_each(|send| *send = None);
+        self.send_all_state();
+        upstream
+            .send(TrackMsg::TrackQuery(TrackQuery {
+                direction: Direction::Downstream,
+                guid: selected_track_guid.clone(),
+            }))
+            .unwrap();
+        let _ = self.to_xtouch.send(XTouchDownstreamMsg::AssignmentDisplay(
+            AssignmentDisplayMsg::mode_bank('C', 0),
+        ));
+        let barrier = Barrier::new();
+        upstream.send(TrackMsg::Barrier(barrier)).unwrap();
+        ModeState {
+            mode: Mode::ReaperSelectedTrack,
+            state: State::WaitingBarrierFromDownstream(barrier),
+        }
+    }
+}
@@ -1,20 +1,44 @@
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::vec::Vec;
 
 use crossbeam_channel::{Receiver, Sender};
+use tracing::debug;
 
-use crate::midi::xtouch::{FaderAbsMsg, LEDState, XTouchDownstreamMsg, XTouchUpstreamMsg};
+use crate::guid::TrackGuid;
+use crate::midi::xtouch::{
+    AssignmentDisplayMsg, ChannelIndex, ChannelIndexError, EncoderRingLEDMsg,
+    EncoderRingLEDRangePointMsg, FaderAbsMsg, LEDState, MuteLEDMsg, ScribbleColor,
+    ScribbleStripMsg, XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
 use crate::modes::mode_manager::{Barrier, Mode, ModeHandler, ModeState, State};
+use crate::modes::session::SendsSession;
+use crate::modes::taper::Taper;
 use crate::track::track::{
-    DataPayload as TrackDataPayload, Direction, SendLevel, TrackDataMsg, TrackMsg, TrackQuery,
+    DataPayload as TrackDataPayload, Direction, SendDiscovery, SendLevel, SendMute, SendPan,
+    TrackDataMsg, TrackMsg, TrackQuery,
 };
 
-pub struct TrackSendState {}
+// How far one detent of an encoder moves a send's pan, which runs from -1.0 to 1.0
+const PAN_STEP: f32 = 0.1;
+
+/// What we know of the send shown on each hardware channel
+#[derive(Clone, Debug, Default)]
+pub struct TrackSendState {
+    pub muted: bool,
+    pub pan: f32,
+}
 
 pub struct TrackSendsMode {
     // Maps track send index to track guid
-    track_sends: Arc<Mutex<Vec<Option<String>>>>,
-    selected_track_guid: Option<String>,
+    track_sends: Arc<Mutex<Vec<Option<TrackGuid>>>>,
+    // Mute and pan of the send on each hardware channel
+    send_states: Vec<TrackSendState>,
+    // How send levels are laid out along the faders
+    taper: Taper,
+    // Names of every track by GUID, used to label sends with their destination
+    track_names: HashMap<TrackGuid, String>,
+    selected_track_guid: Option<TrackGuid>,
     to_reaper: Sender<TrackMsg>,
     from_reaper: Receiver<TrackMsg>,
     to_xtouch: Sender<XTouchDownstreamMsg>,
@@ -31,6 +55,9 @@ impl TrackSendsMode {
     ) -> Self {
         TrackSendsMode {
             track_sends: Arc::new(Mutex::new(vec![None; num_channels])),
+            send_states: vec![TrackSendState::default(); num_channels],
+            taper: Taper::default(),
+            track_names: HashMap::new(),
             selected_track_guid: None,
             to_reaper,
             from_reaper,
@@ -39,12 +66,144 @@ impl TrackSendsMode {
         }
     }
 
-    fn get_guid_for_hw_channel(&self, hw_channel: usize) -> Option<String> {
+    /// Replaces the taper send levels are shown on the faders with.
+    pub fn set_taper(&mut self, taper: Taper) {
+        self.taper = taper;
+    }
+
+    /// The track whose sends are shown, once the mode has been entered.
+    pub fn selected_track_guid(&self) -> Option<&TrackGuid> {
+        self.selected_track_guid.as_ref()
+    }
+
+    /// Returns what's worth keeping of the mode across a restart.
+    pub fn snapshot(&self) -> SendsSession {
+        SendsSession {
+            track_guid: self.selected_track_guid.clone(),
+            track_names: self
+                .track_names
+                .iter()
+                .map(|(guid, name)| (guid.clone(), name.clone()))
+                .collect(),
+        }
+    }
+
+    /// Picks up a session saved by an earlier run. Names reported by Reaper since then are kept.
+    pub fn restore(&mut self, session: SendsSession) {
+        self.selected_track_guid = session.track_guid;
+        for (guid, name) in session.track_names {
+            self.track_names.entry(guid).or_insert(name);
+        }
+    }
+
+    fn get_guid_for_hw_channel(&self, hw_channel: ChannelIndex) -> Option<TrackGuid> {
         let assignments = self.track_sends.lock().unwrap();
-        assignments[hw_channel].clone()
+        assignments.get(hw_channel.get()).cloned().flatten()
+    }
+
+    // Validates a send index from Reaper against the number of channels on the surface
+    fn checked_channel(&self, send_index: i32) -> Result<ChannelIndex, ChannelIndexError> {
+        let num_channels = self.track_sends.lock().unwrap().len();
+        ChannelIndex::try_from(send_index)?.within(num_channels)
+    }
+
+    /// Records a track's name so that sends to it can be labelled.
+    ///
+    /// Send destinations are other tracks, so this needs to be kept up to date even while the
+    /// mode is not active.
+    pub fn set_track_name(&mut self, guid: &TrackGuid, name: &str) {
+        self.track_names.insert(guid.clone(), name.to_string());
+    }
+
+    // Labels a hardware channel with the name of the send's destination track
+    fn send_scribble_strip(&self, hw_channel: ChannelIndex, target_guid: &TrackGuid) {
+        let name = self
+            .track_names
+            .get(target_guid)
+            .cloned()
+            .unwrap_or_default();
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::ScribbleStrip(ScribbleStripMsg {
+                idx: hw_channel,
+                top_line: name,
+                bottom_line: format!("Send {}", hw_channel.get() + 1),
+                color: ScribbleColor::Cyan,
+            }));
+    }
+
+    // Lights the mute button of a hardware channel if its send is muted
+    fn send_mute_led(&self, hw_channel: ChannelIndex) {
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::MuteLED(MuteLEDMsg {
+                idx: hw_channel,
+                state: LEDState::from(self.send_states[hw_channel.get()].muted),
+            }));
+    }
+
+    // Points a hardware channel's encoder ring at its send's pan
+    fn send_pan_ring(&self, hw_channel: ChannelIndex) {
+        let pan = self.send_states[hw_channel.get()].pan;
+        let _ = self.to_xtouch.send(XTouchDownstreamMsg::EncoderRingLED(
+            EncoderRingLEDMsg::RangePoint(EncoderRingLEDRangePointMsg {
+                idx: hw_channel,
+                pos: (pan + 1.0) / 2.0,
+            }),
+        ));
+    }
+
+    // Sends a change to one of the shown track's sends up to Reaper
+    fn send_to_reaper(&self, data: TrackDataPayload) {
+        let Some(guid) = self.selected_track_guid.clone() else {
+            return;
+        };
+        self.to_reaper
+            .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                direction: Direction::Upstream,
+                guid,
+                data,
+            }))
+            .unwrap();
+    }
+
+    // Moves the pan of the send on a hardware channel by `delta`, if there is one
+    fn nudge_pan(&mut self, hw_channel: ChannelIndex, delta: f32) {
+        if self.get_guid_for_hw_channel(hw_channel).is_none() {
+            return;
+        }
+        let state = &mut self.send_states[hw_channel.get()];
+        state.pan = (state.pan + delta).clamp(-1.0, 1.0);
+        let pan = state.pan;
+        self.send_to_reaper(TrackDataPayload::SendPan(SendPan {
+            send_index: hw_channel.into(),
+            pan,
+        }));
+        self.send_pan_ring(hw_channel);
+    }
+
+    // Forgets the send on a hardware channel and blanks its scribble strip
+    fn unmap_channel(&mut self, hw_channel: ChannelIndex) {
+        self.track_sends.lock().unwrap()[hw_channel.get()] = None;
+        self.send_states[hw_channel.get()] = TrackSendState::default();
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::ScribbleStrip(ScribbleStripMsg {
+                idx: hw_channel,
+                top_line: String::new(),
+                bottom_line: String::new(),
+                color: ScribbleColor::Off,
+            }));
+    }
+
+    // Whether a message about `guid`'s sends is about some other track than the one shown
+    fn is_other_track(&self, guid: &TrackGuid) -> bool {
+        self.selected_track_guid
+            .as_ref()
+            .is_some_and(|selected| selected != guid)
     }
 
-    fn find_hw_channel_for_guid(&self, guid: &str) -> Option<usize> {
+    fn find_hw_channel_for_guid(&self, guid: &TrackGuid) -> Option<usize> {
         let assignments = self.track_sends.lock().unwrap();
         for (hw_channel, assigned_guid) in assignments.iter().enumerate() {
             if let Some(assigned_guid) = assigned_guid {
@@ -62,9 +221,7 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
         if let TrackMsg::Barrier(barrier) = msg {
             // Forward barriers downstream (they need to reflect back upstream for the mode to
             // transition)
-            self.to_xtouch
-                .send(XTouchDownstreamMsg::Barrier(barrier))
-                .unwrap();
+            let _ = self.to_xtouch.send(XTouchDownstreamMsg::Barrier(barrier));
             match curr_mode.state {
                 // If we were already waiting on a barrier from upstream, check if this is the one
                 // we were waiting for. If yes, transition to waiting for the barrier to reflect back up from downstream.
@@ -82,21 +239,73 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
             }
         }
         if let TrackMsg::TrackDataMsg(msg) = msg {
+            let is_send = matches!(
+                msg.data,
+                TrackDataPayload::SendCount(_)
+                    | TrackDataPayload::SendIndex(_)
+                    | TrackDataPayload::SendLevel(_)
+                    | TrackDataPayload::SendPan(_)
+                    | TrackDataPayload::SendMute(_)
+            );
+            // Only the sends of the track being shown are mapped to the surface
+            if is_send && self.is_other_track(&msg.guid) {
+                return curr_mode;
+            }
             match msg.data {
-                TrackDataPayload::SendIndex(msg) => {
-                    let mut assignments = self.track_sends.lock().unwrap();
-                    assignments[msg.send_index as usize] = Some(msg.guid);
+                TrackDataPayload::SendCount(send_count) => {
+                    let num_channels = self.track_sends.lock().unwrap().len();
+                    let first_missing = usize::try_from(send_count).unwrap_or_default();
+                    for hw_channel in first_missing..num_channels {
+                        if let Ok(hw_channel) = ChannelIndex::try_from(hw_channel) {
+                            self.unmap_channel(hw_channel);
+                        }
+                    }
                 }
+                TrackDataPayload::SendIndex(msg) => match self.checked_channel(msg.send_index) {
+                    Ok(hw_channel) => {
+                        self.track_sends.lock().unwrap()[hw_channel.get()] = Some(msg.guid.clone());
+                        self.send_scribble_strip(hw_channel, &msg.guid);
+                    }
+                    Err(err) => debug!("Not mapping send to hardware: {:?}", err),
+                },
                 TrackDataPayload::SendLevel(msg) => {
-                    let fader_value = msg.level; // TODO: scale appropriately
-                    self.to_xtouch
-                        .send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg {
-                            idx: msg.send_index,
-                            value: fader_value as f64,
-                        }))
-                        .unwrap();
+                    if let Ok(hw_channel) = self.checked_channel(msg.send_index) {
+                        let _ =
+                            self.to_xtouch
+                                .send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg::at(
+                                    hw_channel,
+                                    self.taper.volume_to_fader(msg.level),
+                                )));
+                    }
+                }
+                TrackDataPayload::SendPan(msg) => {
+                    if let Ok(hw_channel) = self.checked_channel(msg.send_index) {
+                        self.send_states[hw_channel.get()].pan = msg.pan;
+                        self.send_pan_ring(hw_channel);
+                    }
+                }
+                TrackDataPayload::SendMute(msg) => {
+                    if let Ok(hw_channel) = self.checked_channel(msg.send_index) {
+                        self.send_states[hw_channel.get()].muted = msg.muted;
+                        self.send_mute_led(hw_channel);
+                    }
+                }
+                TrackDataPayload::Name(name) => {
+                    self.set_track_name(&msg.guid, &name);
+                    // Relabel any sends to the renamed track
+                    let hw_channels: Vec<ChannelIndex> = self
+                        .track_sends
+                        .lock()
+                        .unwrap()
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, target_guid)| target_guid.as_ref() == Some(&msg.guid))
+                        .filter_map(|(hw_channel, _)| ChannelIndex::try_from(hw_channel).ok())
+                        .collect();
+                    for hw_channel in hw_channels {
+                        self.send_scribble_strip(hw_channel, &msg.guid);
+                    }
                 }
-                // TODO: pan
                 _ => {
                     // Ignore unhandled payloads
                     return curr_mode;
@@ -148,22 +357,51 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                 }
             }
             XTouchUpstreamMsg::MIDITracksPress => curr_mode, //MIDITracksPress maps to this mode!
+            XTouchUpstreamMsg::PluginPress => {
+                // Request transition to ReaperFX mode
+                ModeState {
+                    mode: Mode::ReaperFX,
+                    state: State::RequestingModeTransition,
+                }
+            }
+            XTouchUpstreamMsg::InstPress => {
+                // Request transition to ReaperFxParams mode
+                ModeState {
+                    mode: Mode::ReaperFxParams,
+                    state: State::RequestingModeTransition,
+                }
+            }
             XTouchUpstreamMsg::FaderAbs(fader_msg) => {
-                if let Some(guid) = self.get_guid_for_hw_channel(fader_msg.idx as usize) {
-                    self.to_reaper
-                        .send(TrackMsg::TrackDataMsg(TrackDataMsg {
-                            direction: Direction::Upstream,
-                            guid,
-                            data: TrackDataPayload::SendLevel(SendLevel {
-                                send_index: fader_msg.idx,
-                                level: fader_msg.value as f32, // TODO: scale appropriately
-                            }),
-                        }))
-                        .unwrap();
+                if self.get_guid_for_hw_channel(fader_msg.idx).is_some() {
+                    self.send_to_reaper(TrackDataPayload::SendLevel(SendLevel {
+                        send_index: fader_msg.idx.into(),
+                        level: self.taper.fader_to_volume(fader_msg.position()),
+                    }));
+                }
+                curr_mode
+            }
+            XTouchUpstreamMsg::MutePress(mute_msg) => {
+                if self.get_guid_for_hw_channel(mute_msg.idx).is_some() {
+                    let state = &mut self.send_states[mute_msg.idx.get()];
+                    state.muted = !state.muted;
+                    let muted = state.muted;
+                    self.send_to_reaper(TrackDataPayload::SendMute(SendMute {
+                        send_index: mute_msg.idx.into(),
+                        muted,
+                    }));
+                    self.send_mute_led(mute_msg.idx);
                 }
                 curr_mode
             }
-            _ => curr_mode, // For now, the buttons and encoder do nothing
+            XTouchUpstreamMsg::EncoderTurnInc(encoder_msg) => {
+                self.nudge_pan(encoder_msg.idx, PAN_STEP);
+                curr_mode
+            }
+            XTouchUpstreamMsg::EncoderTurnDec(encoder_msg) => {
+                self.nudge_pan(encoder_msg.idx, -PAN_STEP);
+                curr_mode
+            }
+            _ => curr_mode, // For now, the other buttons do nothing
         }
     }
 }
@@ -172,15 +410,36 @@ impl TrackSendsMode {
     pub fn initiate_mode_transition(
         &mut self,
         upstream: Sender<TrackMsg>,
-        selected_track_guid: &str,
+        selected_track_guid: &TrackGuid,
     ) -> ModeState {
-        self.selected_track_guid = Some(selected_track_guid.to_string());
+        // The sends of whichever track was shown before don't belong on this one
+        if self.selected_track_guid.as_ref() != Some(selected_track_guid) {
+            let num_channels = self.track_sends.lock().unwrap().len();
+            for hw_channel in 0..num_channels {
+                if let Ok(hw_channel) = ChannelIndex::try_from(hw_channel) {
+                    self.unmap_channel(hw_channel);
+                }
+            }
+        }
+        self.selected_track_guid = Some(selected_track_guid.clone());
         upstream
             .send(TrackMsg::TrackQuery(TrackQuery {
                 direction: Direction::Downstream,
-                guid: selected_track_guid.to_string(),
+                guid: selected_track_guid.clone(),
+            }))
+            .unwrap();
+        // Reaper is asked where the track's sends go, and each one it has is mapped to its
+        // channel as the answers come back
+        upstream
+            .send(TrackMsg::DiscoverSends(SendDiscovery {
+                guid: selected_track_guid.clone(),
+                num_sends: self.track_sends.lock().unwrap().len(),
             }))
             .unwrap();
+        // Sends have a single bank
+        let _ = self.to_xtouch.send(XTouchDownstreamMsg::AssignmentDisplay(
+            AssignmentDisplayMsg::mode_bank('S', 0),
+        ));
         let barrier = Barrier::new();
         upstream.send(TrackMsg::Barrier(barrier)).unwrap();
         ModeState {
@@ -1,20 +1,31 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use std::vec::Vec;
 
 use crossbeam_channel::{Receiver, Sender};
 
-use crate::midi::xtouch::{self, EncoderRingLEDRangePointMsg, EncoderTurnCCW};
-use crate::midi::xtouch::{FaderAbsMsg, LEDState, XTouchDownstreamMsg, XTouchUpstreamMsg};
+use crate::guid::TrackGuid;
+use crate::midi::xtouch::{
+    self, AssignmentDisplayMsg, ChannelIndex, ChannelIndexError, EncoderRingLEDRangePointMsg,
+    EncoderTurnCCW, FaderReleaseMsg, FaderTouchMsg,
+};
+use crate::midi::xtouch::{
+    FaderAbsMsg, LEDState, MasterFaderAbsMsg, SCRIBBLE_LINE_LEN, ScribbleColor,
+    XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
 use crate::modes::mode_manager::{Barrier, Mode, ModeHandler, ModeState, State};
+use crate::modes::session::{TrackSession, VolPanSession};
+use crate::modes::taper::Taper;
+use crate::modes::track_mapping::{MappingPolicy, TrackMapper};
+use crate::project::ProjectMsg;
 use crate::track::track::{
-    DataPayload as TrackDataPayload, Direction, TrackDataMsg, TrackMsg, TrackQuery,
+    DataPayload as TrackDataPayload, Direction, MASTER_GUID, TrackDataMsg, TrackMsg, TrackQuery,
 };
 
 // Threshold for filtering out insignificant volume/pan changes
 const EPSILON: f32 = 0.01;
 
-pub const FADER_0DB: f32 = 0.72; // Placeholder value for 0dB on fader scale
+pub use crate::modes::taper::FADER_0DB;
 
 #[derive(Clone)]
 struct Button {
@@ -56,6 +67,9 @@ struct TrackState {
     buttons: ButtonState,
     pan: f32,
     volume: f32,
+    name: String,
+    // Reaper's color for the track as 0xRRGGBB, 0 if it has none
+    color: i32,
 }
 
 /// Implements a mode where that "basic" reaper functionality is mapped to the channel strips on
@@ -63,17 +77,41 @@ struct TrackState {
 /// - Volume on faders
 /// - Pan on rotary encoders
 /// - Select/Mute/Solo/Arm on buttons
+/// - The master track's volume on the master fader, whichever bank is shown
+/// - Each track's name and color on its scribble strip
 ///
 /// Button LED toggling is handled here (downstream does not need to worry about managing button
 /// LEDS.)
 pub struct VolumePanMode {
     // Maps each channel on the hardware controller to a Reaper track
-    track_hw_assignments: Arc<Mutex<Vec<Option<String>>>>,
+    track_hw_assignments: Arc<Mutex<Vec<Option<TrackGuid>>>>,
+    // Decides which tracks are shown on the surface
+    mapper: TrackMapper,
+    // The mapper's latest layout: the track GUID in each slot, including slots outside the current
+    // bank
+    slots: BTreeMap<usize, TrackGuid>,
+    // The bank currently shown on the hardware; bank N shows slots starting at N * num_channels
+    bank: usize,
+    // Set until the initial sync with Reaper finishes; nothing is assigned to the hardware until
+    // then, so the first render shows every track at once
+    loading: bool,
     // Store state for each track by track GUID
-    track_states: HashMap<String, TrackState>,
+    track_states: HashMap<TrackGuid, TrackState>,
     // Store last sent volume/pan values to avoid sending updates for tiny changes
-    last_sent_volume: HashMap<String, f32>,
-    last_sent_pan: HashMap<String, f32>,
+    last_sent_volume: HashMap<TrackGuid, f32>,
+    last_sent_pan: HashMap<TrackGuid, f32>,
+    // Faders the user currently has a hand on. Positions from Reaper aren't sent to these, since
+    // the motor would fight the user; the fader catches up once it's let go.
+    touched_faders: HashSet<ChannelIndex>,
+    // How volume is laid out along the faders
+    taper: Taper,
+    // Whether the current marker's name takes the place of the track numbers on the scribble
+    // strips
+    show_markers: bool,
+    // Name of the marker the play cursor last passed, empty before the first one
+    marker: String,
+    // Whether the transport is recording, which makes the arm LEDs of armed tracks blink
+    recording: bool,
     to_reaper: Sender<TrackMsg>,
     from_reaper: Receiver<TrackMsg>,
     to_xtouch: Sender<XTouchDownstreamMsg>,
@@ -93,9 +131,18 @@ impl VolumePanMode {
 
         VolumePanMode {
             track_hw_assignments,
+            mapper: TrackMapper::new(MappingPolicy::default()),
+            slots: BTreeMap::new(),
+            bank: 0,
+            loading: false,
             track_states: button_states,
             last_sent_volume: HashMap::new(),
             last_sent_pan: HashMap::new(),
+            touched_faders: HashSet::new(),
+            taper: Taper::default(),
+            show_markers: false,
+            marker: String::new(),
+            recording: false,
             to_reaper,
             from_reaper,
             to_xtouch,
@@ -103,7 +150,13 @@ impl VolumePanMode {
         }
     }
 
-    fn get_track_state(&mut self, guid: String) -> &mut TrackState {
+    // Keeps the channel assignments in `assignments` from here on, for ModeStatus to read
+    pub(crate) fn share_assignments(&mut self, assignments: Arc<Mutex<Vec<Option<TrackGuid>>>>) {
+        *assignments.lock().unwrap() = self.track_hw_assignments.lock().unwrap().clone();
+        self.track_hw_assignments = assignments;
+    }
+
+    fn get_track_state(&mut self, guid: TrackGuid) -> &mut TrackState {
         self.track_states.entry(guid).or_insert(TrackState {
             buttons: ButtonState {
                 mute: Button::new(),
@@ -112,21 +165,464 @@ impl VolumePanMode {
             },
             pan: 0.5,          // Default center pan
             volume: FADER_0DB, // Default volume at 0dB
+            name: String::new(),
+            color: 0,
         })
     }
 
-    fn get_guid_for_hw_channel(&self, hw_channel: usize) -> Option<String> {
+    fn get_guid_for_hw_channel(&self, hw_channel: ChannelIndex) -> Option<TrackGuid> {
         let assignments = self.track_hw_assignments.lock().unwrap();
-        assignments[hw_channel].clone()
+        assignments.get(hw_channel.get()).cloned().flatten()
+    }
+
+    fn num_channels(&self) -> usize {
+        self.track_hw_assignments.lock().unwrap().len()
+    }
+
+    // Maps a slot to a hardware channel in the current bank, failing if the slot lives in a
+    // different bank
+    fn checked_channel(&self, slot: usize) -> Result<ChannelIndex, ChannelIndexError> {
+        let num_channels = self.num_channels();
+        let bank_start = self.bank * num_channels;
+        let Some(offset) = slot.checked_sub(bank_start) else {
+            return Err(ChannelIndexError::Invalid(slot as i64 - bank_start as i64));
+        };
+        ChannelIndex::try_from(offset)?.within(num_channels)
+    }
+
+    /// Replaces the policy deciding which tracks are shown, and re-lays out the surface with it.
+    pub fn set_mapping_policy(&mut self, policy: MappingPolicy) {
+        self.mapper.set_policy(policy);
+        self.apply_layout();
+    }
+
+    /// Replaces the taper volume is shown on the faders with. Faders already shown stay put until
+    /// their volume next changes or the surface is laid out again.
+    pub fn set_taper(&mut self, taper: Taper) {
+        self.taper = taper;
+    }
+
+    /// Shows the name of the marker the play cursor last passed along the bottom line of the
+    /// scribble strips, in place of the track numbers.
+    pub fn set_show_markers(&mut self, show_markers: bool) {
+        self.show_markers = show_markers;
+    }
+
+    /// Takes in news about the project from ProjectStateManager. Only the current marker is shown,
+    /// and only while this mode is on the surface; it's picked up again when the mode comes back.
+    pub fn handle_project_msg(&mut self, msg: ProjectMsg, curr_mode: ModeState) {
+        let showing = !self.loading && curr_mode.mode == Mode::ReaperVolPan;
+        match msg {
+            ProjectMsg::CurrentMarker(marker) => {
+                self.marker = marker.map(|marker| marker.name).unwrap_or_default();
+                if self.show_markers && showing {
+                    self.send_scribble_strips();
+                }
+            }
+            ProjectMsg::Recording(recording) => {
+                self.recording = recording;
+                if showing {
+                    self.send_arm_leds();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Pins a track to a slot, for the Restore and Manual mapping policies.
+    pub fn pin_track(&mut self, slot: usize, guid: TrackGuid) {
+        self.mapper.pin(slot, guid);
+        self.apply_layout();
+    }
+
+    /// Returns what's worth keeping of the mode across a restart.
+    pub fn snapshot(&self) -> VolPanSession {
+        VolPanSession {
+            pinned: self.mapper.pinned().clone(),
+            bank: self.bank,
+            tracks: self
+                .track_states
+                .iter()
+                .map(|(guid, state)| {
+                    let track = TrackSession {
+                        name: state.name.clone(),
+                        volume: state.volume,
+                        pan: state.pan,
+                        mute: state.buttons.mute.is_on(),
+                        solo: state.buttons.solo.is_on(),
+                        arm: state.buttons.arm.is_on(),
+                    };
+                    (guid.clone(), track)
+                })
+                .collect(),
+        }
+    }
+
+    /// Picks up a session saved by an earlier run. Each track's saved state stands in until
+    /// Reaper reports the real thing.
+    ///
+    /// Tracks still only appear on the surface once Reaper reports their index, so any deleted
+    /// since the session was saved stay off it.
+    pub fn restore(&mut self, session: VolPanSession) {
+        for (slot, guid) in session.pinned {
+            self.mapper.pin(slot, guid);
+        }
+        for (guid, track) in session.tracks {
+            let state = self.get_track_state(guid);
+            state.name = track.name;
+            state.volume = track.volume;
+            state.pan = track.pan;
+            state.buttons.mute.set(track.mute);
+            state.buttons.solo.set(track.solo);
+            state.buttons.arm.set(track.arm);
+        }
+        self.bank = session.bank;
+        self.apply_layout();
+    }
+
+    /// Shows a loading state on the surface until [`TrackMsg::InitialSync`] arrives.
+    pub fn wait_for_initial_sync(&mut self) {
+        self.loading = true;
+        *self.track_hw_assignments.lock().unwrap() = vec![None; self.num_channels()];
+        self.send_assignment_display();
+    }
+
+    // Ends the loading state, if we're in it, with a full render of the current bank
+    fn finish_loading(&mut self) {
+        if !self.loading {
+            return;
+        }
+        self.loading = false;
+        self.set_bank(self.bank);
+    }
+
+    // Works out which track belongs on each hardware channel of the current bank
+    fn bank_assignments(&self) -> Vec<Option<TrackGuid>> {
+        let mut assignments = vec![None; self.num_channels()];
+        for (slot, guid) in &self.slots {
+            if let Ok(hw_channel) = self.checked_channel(*slot) {
+                assignments[hw_channel.get()] = Some(guid.clone());
+            }
+        }
+        assignments
+    }
+
+    // Drops everything we know of a track that's gone from the project. The mapper moves the
+    // tracks after it up into its place.
+    fn remove_track(&mut self, guid: &TrackGuid) {
+        self.mapper.remove(guid);
+        self.track_states.remove(guid);
+        self.last_sent_volume.remove(guid);
+        self.last_sent_pan.remove(guid);
+    }
+
+    /// Redraws every channel of the current bank from what the mode knows, for when the surface
+    /// may have drifted from it. Nothing is drawn while loading.
+    pub fn repaint(&mut self) {
+        if !self.loading {
+            self.set_bank(self.bank);
+        }
+    }
+
+    /// The tracks on the surface's channels, from left to right.
+    pub fn assigned_tracks(&self) -> Vec<TrackGuid> {
+        self.track_hw_assignments
+            .lock()
+            .unwrap()
+            .iter()
+            .flatten()
+            .cloned()
+            .collect()
+    }
+
+    /// Forgets a track that's gone from the project while another mode has the surface. The
+    /// channels are laid out afresh without touching the surface, which catches up when this
+    /// mode takes it back.
+    pub fn forget_track(&mut self, guid: &TrackGuid) {
+        self.remove_track(guid);
+        self.slots = self.mapper.layout();
+        if !self.loading {
+            *self.track_hw_assignments.lock().unwrap() = self.bank_assignments();
+        }
+    }
+
+    // Asks the mapper for a fresh layout and refreshes every hardware channel whose track changed
+    fn apply_layout(&mut self) {
+        self.slots = self.mapper.layout();
+        if self.loading {
+            return;
+        }
+        let assignments = self.bank_assignments();
+        let previous = std::mem::replace(
+            &mut *self.track_hw_assignments.lock().unwrap(),
+            assignments.clone(),
+        );
+        let changed: Vec<(usize, Option<TrackGuid>, Option<TrackGuid>)> = previous
+            .into_iter()
+            .zip(assignments)
+            .enumerate()
+            .filter(|(_, (old, new))| old != new)
+            .map(|(hw_channel, (old, new))| (hw_channel, old, new))
+            .collect();
+        // Tracks leaving a channel have their full state sent wherever they show up next
+        for (_, old, _) in &changed {
+            if let Some(guid) = old {
+                self.last_sent_volume.remove(guid);
+                self.last_sent_pan.remove(guid);
+            }
+        }
+        // Show tracks in their new places before blanking the channels they left
+        for (hw_channel, _, new) in &changed {
+            let (Ok(hw_channel), Some(guid)) = (ChannelIndex::try_from(*hw_channel), new) else {
+                continue;
+            };
+            self.send_track_state(hw_channel, guid);
+        }
+        for (hw_channel, _, new) in changed {
+            let (Ok(hw_channel), None) = (ChannelIndex::try_from(hw_channel), new) else {
+                continue;
+            };
+            self.send_blank_channel(hw_channel);
+        }
+    }
+
+    /// Returns the bank currently shown on the hardware.
+    pub fn bank(&self) -> usize {
+        self.bank
+    }
+
+    // Switches to the given bank, reassigning every hardware channel and refreshing its state
+    fn set_bank(&mut self, bank: usize) {
+        self.bank = bank;
+        let assignments = self.bank_assignments();
+        *self.track_hw_assignments.lock().unwrap() = assignments.clone();
+        for (hw_channel, assignment) in assignments.into_iter().enumerate() {
+            let Ok(hw_channel) = ChannelIndex::try_from(hw_channel) else {
+                continue;
+            };
+            match assignment {
+                Some(guid) => self.send_track_state(hw_channel, &guid),
+                None => self.send_blank_channel(hw_channel),
+            }
+        }
+        self.send_assignment_display();
+    }
+
+    // Shows this mode and the current bank on the assignment display, or "LD" while loading
+    fn send_assignment_display(&self) {
+        let msg = match self.loading {
+            true => AssignmentDisplayMsg { text: ['L', 'D'] },
+            false => AssignmentDisplayMsg::mode_bank('V', self.bank),
+        };
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::AssignmentDisplay(msg));
+    }
+
+    // Bank right is only allowed if there is at least one track to show in the next bank
+    fn has_bank(&self, bank: usize) -> bool {
+        let bank_start = bank * self.num_channels();
+        self.slots
+            .keys()
+            .next_back()
+            .is_some_and(|&last| last >= bank_start)
+    }
+
+    // Moves a fader to a volume from Reaper, unless the user is holding it
+    fn send_fader(&self, hw_channel: ChannelIndex, volume: f32) {
+        if self.touched_faders.contains(&hw_channel) {
+            return;
+        }
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg::at(
+                hw_channel,
+                self.taper.volume_to_fader(volume),
+            )));
+    }
+
+    fn send_master_fader(&self, volume: f32) {
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::MasterFaderAbs(MasterFaderAbsMsg::at(
+                self.taper.volume_to_fader(volume),
+            )));
+    }
+
+    // Sends the full state of a track to the given hardware channel
+    fn send_track_state(&mut self, hw_channel: ChannelIndex, guid: &TrackGuid) {
+        let track_state = self.get_track_state(guid.clone()).clone();
+        // Send volume
+        self.send_fader(hw_channel, track_state.volume);
+        // Update EPSILON tracking for volume since we just sent it
+        self.last_sent_volume
+            .insert(guid.clone(), track_state.volume);
+        // Send mute LED
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::MuteLED(xtouch::MuteLEDMsg {
+                idx: hw_channel,
+                state: LEDState::from(track_state.buttons.mute.is_on()),
+            }));
+        // Send solo LED
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::SoloLED(xtouch::SoloLEDMsg {
+                idx: hw_channel,
+                state: LEDState::from(track_state.buttons.solo.is_on()),
+            }));
+        // Send arm LED
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::ArmLED(xtouch::ArmLEDMsg {
+                idx: hw_channel,
+                state: self.arm_led(track_state.buttons.arm.is_on()),
+            }));
+        // Send pan
+        let _ = self.to_xtouch.send(XTouchDownstreamMsg::EncoderRingLED(
+            xtouch::EncoderRingLEDMsg::RangePoint(EncoderRingLEDRangePointMsg {
+                idx: hw_channel,
+                pos: track_state.pan,
+            }),
+        ));
+        // Update EPSILON tracking for pan since we just sent it
+        self.last_sent_pan.insert(guid.clone(), track_state.pan);
+        self.send_scribble_strip(hw_channel, guid);
+    }
+
+    // Labels a hardware channel with the name and Reaper track number of its track, lit in the
+    // track's color
+    fn send_scribble_strip(&mut self, hw_channel: ChannelIndex, guid: &TrackGuid) {
+        let track_number = self
+            .mapper
+            .index(guid)
+            .map(|index| (index + 1).to_string())
+            .unwrap_or_default();
+        let track_state = self.get_track_state(guid.clone());
+        let name = track_state.name.clone();
+        let color = ScribbleColor::nearest(track_state.color);
+        let _ = self.to_xtouch.send(XTouchDownstreamMsg::ScribbleStrip(
+            xtouch::ScribbleStripMsg {
+                idx: hw_channel,
+                top_line: name,
+                bottom_line: self.marker_line(hw_channel).unwrap_or(track_number),
+                color,
+            },
+        ));
+    }
+
+    // Blanks the scribble strip of a channel with no track, apart from its part of the marker
+    fn send_blank_scribble_strip(&mut self, hw_channel: ChannelIndex) {
+        let bottom_line = self.marker_line(hw_channel).unwrap_or_default();
+        let color = match bottom_line.is_empty() {
+            true => ScribbleColor::Off,
+            false => ScribbleColor::White,
+        };
+        let _ = self.to_xtouch.send(XTouchDownstreamMsg::ScribbleStrip(
+            xtouch::ScribbleStripMsg {
+                idx: hw_channel,
+                top_line: String::new(),
+                bottom_line,
+                color,
+            },
+        ));
+    }
+
+    // Relabels every hardware channel in the current bank
+    fn send_scribble_strips(&mut self) {
+        let assignments = self.track_hw_assignments.lock().unwrap().clone();
+        for (hw_channel, assignment) in assignments.into_iter().enumerate() {
+            let Ok(hw_channel) = ChannelIndex::try_from(hw_channel) else {
+                continue;
+            };
+            match assignment {
+                Some(guid) => self.send_scribble_strip(hw_channel, &guid),
+                None => self.send_blank_scribble_strip(hw_channel),
+            }
+        }
+    }
+
+    // How an arm LED shows whether its track is armed: blinking while recording, so it's clear
+    // which tracks are being recorded onto
+    fn arm_led(&self, armed: bool) -> LEDState {
+        match armed && self.recording {
+            true => LEDState::Flash,
+            false => LEDState::from(armed),
+        }
+    }
+
+    // Relights the arm LED of every hardware channel with a track in the current bank
+    fn send_arm_leds(&mut self) {
+        let assignments = self.track_hw_assignments.lock().unwrap().clone();
+        for (hw_channel, assignment) in assignments.into_iter().enumerate() {
+            let (Ok(hw_channel), Some(guid)) = (ChannelIndex::try_from(hw_channel), assignment)
+            else {
+                continue;
+            };
+            let armed = self.get_track_state(guid).buttons.arm.is_on();
+            let _ = self
+                .to_xtouch
+                .send(XTouchDownstreamMsg::ArmLED(xtouch::ArmLEDMsg {
+                    idx: hw_channel,
+                    state: self.arm_led(armed),
+                }));
+        }
+    }
+
+    // The piece of the current marker's name a channel shows, if markers are shown and there is
+    // one. The name reads across the bottom lines of all the strips.
+    fn marker_line(&self, hw_channel: ChannelIndex) -> Option<String> {
+        match self.show_markers && !self.marker.is_empty() {
+            true => Some(
+                self.marker
+                    .chars()
+                    .skip(hw_channel.get() * SCRIBBLE_LINE_LEN)
+                    .take(SCRIBBLE_LINE_LEN)
+                    .collect(),
+            ),
+            false => None,
+        }
+    }
+
+    // Resets a hardware channel that has no track assigned in the current bank
+    fn send_blank_channel(&mut self, hw_channel: ChannelIndex) {
+        self.send_fader(hw_channel, 0.0);
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::MuteLED(xtouch::MuteLEDMsg {
+                idx: hw_channel,
+                state: LEDState::Off,
+            }));
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::SoloLED(xtouch::SoloLEDMsg {
+                idx: hw_channel,
+                state: LEDState::Off,
+            }));
+        let _ = self
+            .to_xtouch
+            .send(XTouchDownstreamMsg::ArmLED(xtouch::ArmLEDMsg {
+                idx: hw_channel,
+                state: LEDState::Off,
+            }));
+        let _ = self.to_xtouch.send(XTouchDownstreamMsg::EncoderRingLED(
+            xtouch::EncoderRingLEDMsg::Blank(xtouch::EncoderRingLEDBlankMsg { idx: hw_channel }),
+        ));
+        self.send_blank_scribble_strip(hw_channel);
+    }
+
+    fn find_channel_index(&self, guid: &TrackGuid) -> Option<ChannelIndex> {
+        self.find_hw_channel(guid)
+            .and_then(|hw_channel| ChannelIndex::try_from(hw_channel).ok())
     }
 
     // For a given track GUID, find which hardware channel it's assigned to (if any)
-    pub fn find_hw_channel(&self, guid: &str) -> Option<usize> {
+    pub fn find_hw_channel(&self, guid: &TrackGuid) -> Option<usize> {
         let assignments = self.track_hw_assignments.lock().unwrap();
         assignments
             .iter()
             .enumerate()
-            .find(|(_, assigned_guid)| *assigned_guid == &Some(guid.to_string()))
+            .find(|(_, assigned_guid)| assigned_guid.as_ref() == Some(guid))
             .map(|(hw_channel, _)| hw_channel)
     }
 }
@@ -136,9 +632,7 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
         if let TrackMsg::Barrier(barrier) = msg {
             // Forward barriers downstream (they need to reflect back upstream for the mode to
             // transition)
-            self.to_xtouch
-                .send(XTouchDownstreamMsg::Barrier(barrier))
-                .unwrap();
+            let _ = self.to_xtouch.send(XTouchDownstreamMsg::Barrier(barrier));
             match curr_mode.state {
                 // If we were already waiting on a barrier from upstream, check if this is the one
                 // we were waiting for. If yes, transition to waiting for the barrier to reflect back up from downstream.
@@ -155,83 +649,52 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                 _ => return curr_mode,
             }
         }
+        if let TrackMsg::InitialSync(_) = msg {
+            // Render whatever we have, even if the sync timed out
+            self.finish_loading();
+            return curr_mode;
+        }
+        if let TrackMsg::TrackRemoved(guid) = msg {
+            // The channel it was on is blanked, or taken by the track that moved up into its place
+            self.remove_track(&guid);
+            self.apply_layout();
+            return curr_mode;
+        }
         if let TrackMsg::TrackDataMsg(msg) = msg {
             match msg.data {
-                // We use track index according to reaper to assign tracks to hardware channels
-                TrackDataPayload::ReaperTrackIndex(Some(index)) => {
-                    // First, check if the assignment is changing. If not changing, do nothing.
-                    if let Some(current_guid) =
-                        &self.track_hw_assignments.lock().unwrap()[index as usize]
-                    {
-                        if current_guid == &msg.guid {
-                            return curr_mode; // No change in assignment
-                        }
-                    }
-                    {
-                        // Clear any existing assignment for this track GUID before setting the new one
-                        let mut assignments = self.track_hw_assignments.lock().unwrap();
-                        for slot in assignments.iter_mut() {
-                            if let Some(guid) = slot {
-                                if guid == &msg.guid {
-                                    // Clear EPSILON tracking for this track since it's being unmapped
-                                    self.last_sent_volume.remove(guid);
-                                    self.last_sent_pan.remove(guid);
-                                    *slot = None;
-                                }
-                            }
-                        }
-                        // Now set the new assignment
-                        assignments[index as usize] = Some(msg.guid.clone());
+                // The mapping policy uses Reaper's track index and selection to lay out tracks
+                TrackDataPayload::ReaperTrackIndex(index) => {
+                    self.mapper.set_index(&msg.guid, index);
+                    self.apply_layout();
+                    return curr_mode;
+                }
+                TrackDataPayload::Selected(selected) => {
+                    self.mapper.set_selected(&msg.guid, selected);
+                    self.apply_layout();
+                    return curr_mode;
+                }
+                TrackDataPayload::Name(name) => {
+                    self.get_track_state(msg.guid.clone()).name = name;
+                    if let Some(hw_channel) = self.find_channel_index(&msg.guid) {
+                        self.send_scribble_strip(hw_channel, &msg.guid);
                     }
-                    // Now, send the current state of the track to the hardware for this channel
-                    if let Some(hw_channel) = self.find_hw_channel(&msg.guid) {
-                        let track_state = self.get_track_state(msg.guid.clone()).clone();
-                        // Send volume
-                        let _ = self
-                            .to_xtouch
-                            .send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg {
-                                idx: hw_channel as i32,
-                                value: track_state.volume as f64,
-                            }));
-                        // Update EPSILON tracking for volume since we just sent it
-                        self.last_sent_volume.insert(msg.guid.clone(), track_state.volume);
-                        
-                        // Send mute LED
-                        let _ =
-                            self.to_xtouch
-                                .send(XTouchDownstreamMsg::MuteLED(xtouch::MuteLEDMsg {
-                                    idx: hw_channel as i32,
-                                    state: LEDState::from(track_state.buttons.mute.is_on()),
-                                }));
-                        // Send solo LED
-                        let _ =
-                            self.to_xtouch
-                                .send(XTouchDownstreamMsg::SoloLED(xtouch::SoloLEDMsg {
-                                    idx: hw_channel as i32,
-                                    state: LEDState::from(track_state.buttons.solo.is_on()),
-                                }));
-                        // Send arm LED
-                        let _ =
-                            self.to_xtouch
-                                .send(XTouchDownstreamMsg::ArmLED(xtouch::ArmLEDMsg {
-                                    idx: hw_channel as i32,
-                                    state: LEDState::from(track_state.buttons.arm.is_on()),
-                                }));
-                        // Send pan
-                        let _ = self.to_xtouch.send(XTouchDownstreamMsg::EncoderRingLED(
-                            xtouch::EncoderRingLEDMsg::RangePoint(EncoderRingLEDRangePointMsg {
-                                idx: hw_channel as i32,
-                                pos: track_state.pan,
-                            }),
-                        ));
-                        // Update EPSILON tracking for pan since we just sent it
-                        self.last_sent_pan.insert(msg.guid.clone(), track_state.pan);
+                    return curr_mode;
+                }
+                TrackDataPayload::Color(color) => {
+                    self.get_track_state(msg.guid.clone()).color = color;
+                    if let Some(hw_channel) = self.find_channel_index(&msg.guid) {
+                        self.send_scribble_strip(hw_channel, &msg.guid);
                     }
                     return curr_mode;
                 }
+                TrackDataPayload::Volume(value) if msg.guid == MASTER_GUID => {
+                    self.get_track_state(msg.guid).volume = value;
+                    self.send_master_fader(value);
+                    return curr_mode;
+                }
                 TrackDataPayload::Volume(value) => {
                     self.get_track_state(msg.guid.clone()).volume = value;
-                    if let Some(hw_channel) = self.find_hw_channel(&msg.guid) {
+                    if let Some(hw_channel) = self.find_channel_index(&msg.guid) {
                         // Check if the change is significant enough to send
                         let should_send =
                             if let Some(&last_value) = self.last_sent_volume.get(&msg.guid) {
@@ -245,13 +708,7 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                             self.last_sent_volume.insert(msg.guid.clone(), value);
 
                             // Send volume update to XTouch for the corresponding fader
-                            let fader_value = value; // TODO: scale appropriately
-                            let _ =
-                                self.to_xtouch
-                                    .send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg {
-                                        idx: hw_channel as i32,
-                                        value: fader_value as f64,
-                                    }));
+                            self.send_fader(hw_channel, value);
                         }
                     }
                     return curr_mode;
@@ -261,12 +718,12 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                         .buttons
                         .mute
                         .set(muted);
-                    if let Some(hw_channel) = self.find_hw_channel(&msg.guid) {
+                    if let Some(hw_channel) = self.find_channel_index(&msg.guid) {
                         // Send mute LED update to XTouch
                         let _ =
                             self.to_xtouch
                                 .send(XTouchDownstreamMsg::MuteLED(xtouch::MuteLEDMsg {
-                                    idx: hw_channel as i32,
+                                    idx: hw_channel,
                                     state: LEDState::from(muted),
                                 }));
                     }
@@ -277,12 +734,12 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                         .buttons
                         .solo
                         .set(soloed);
-                    if let Some(hw_channel) = self.find_hw_channel(&msg.guid) {
+                    if let Some(hw_channel) = self.find_channel_index(&msg.guid) {
                         // Send solo LED update to XTouch
                         let _ =
                             self.to_xtouch
                                 .send(XTouchDownstreamMsg::SoloLED(xtouch::SoloLEDMsg {
-                                    idx: hw_channel as i32,
+                                    idx: hw_channel,
                                     state: LEDState::from(soloed),
                                 }));
                     }
@@ -293,20 +750,20 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                         .buttons
                         .arm
                         .set(armed);
-                    if let Some(hw_channel) = self.find_hw_channel(&msg.guid) {
+                    if let Some(hw_channel) = self.find_channel_index(&msg.guid) {
                         // Send arm LED update to XTouch
                         let _ =
                             self.to_xtouch
                                 .send(XTouchDownstreamMsg::ArmLED(xtouch::ArmLEDMsg {
-                                    idx: hw_channel as i32,
-                                    state: LEDState::from(armed),
+                                    idx: hw_channel,
+                                    state: self.arm_led(armed),
                                 }));
                     }
                     return curr_mode;
                 }
                 TrackDataPayload::Pan(value) => {
                     self.get_track_state(msg.guid.clone()).pan = value;
-                    if let Some(hw_channel) = self.find_hw_channel(&msg.guid) {
+                    if let Some(hw_channel) = self.find_channel_index(&msg.guid) {
                         // Check if the change is significant enough to send
                         let should_send =
                             if let Some(&last_value) = self.last_sent_pan.get(&msg.guid) {
@@ -324,7 +781,7 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                             let _ = self.to_xtouch.send(XTouchDownstreamMsg::EncoderRingLED(
                                 xtouch::EncoderRingLEDMsg::RangePoint(
                                     EncoderRingLEDRangePointMsg {
-                                        idx: hw_channel as i32,
+                                        idx: hw_channel,
                                         pos: pan_value,
                                     },
                                 ),
@@ -334,7 +791,7 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                     return curr_mode;
                 }
                 _ => {
-                    // Ignore unhandled payloads (e.g., Selected, SendIndex, etc.)
+                    // Ignore unhandled payloads (e.g., SendIndex, etc.)
                     return curr_mode;
                 }
             }
@@ -384,21 +841,73 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                     state: State::RequestingModeTransition,
                 }
             }
+            // PluginPress maps to ReaperFX mode
+            XTouchUpstreamMsg::PluginPress => ModeState {
+                mode: Mode::ReaperFX,
+                state: State::RequestingModeTransition,
+            },
+            // InstPress maps to ReaperFxParams mode
+            XTouchUpstreamMsg::InstPress => ModeState {
+                mode: Mode::ReaperFxParams,
+                state: State::RequestingModeTransition,
+            },
+            XTouchUpstreamMsg::BankLeftPress => {
+                if !self.loading && self.bank > 0 {
+                    self.set_bank(self.bank - 1);
+                }
+                curr_mode
+            }
+            XTouchUpstreamMsg::BankRightPress => {
+                if !self.loading && self.has_bank(self.bank + 1) {
+                    self.set_bank(self.bank + 1);
+                }
+                curr_mode
+            }
             XTouchUpstreamMsg::FaderAbs(fader_msg) => {
-                if let Some(guid) =
-                    &self.track_hw_assignments.lock().unwrap()[fader_msg.idx as usize]
-                {
+                if let Some(guid) = self.get_guid_for_hw_channel(fader_msg.idx) {
                     // Send volume update to Reaper for the corresponding track
                     let _ = self.to_reaper.send(TrackMsg::TrackDataMsg(TrackDataMsg {
                         direction: Direction::Upstream,
                         guid: guid.clone(),
-                        data: TrackDataPayload::Volume(fader_msg.value as f32), // TODO: Need to scale appropriately
+                        data: TrackDataPayload::Volume(
+                            self.taper.fader_to_volume(fader_msg.position()),
+                        ),
                     }));
                 }
                 curr_mode
             }
+            XTouchUpstreamMsg::MasterFaderAbs(fader_msg) => {
+                let _ = self.to_reaper.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                    direction: Direction::Upstream,
+                    guid: TrackGuid::from(MASTER_GUID),
+                    data: TrackDataPayload::Volume(
+                        self.taper.fader_to_volume(fader_msg.position()),
+                    ),
+                }));
+                curr_mode
+            }
+            XTouchUpstreamMsg::FaderTouch(FaderTouchMsg { idx }) => {
+                self.touched_faders.insert(idx);
+                curr_mode
+            }
+            XTouchUpstreamMsg::FaderRelease(FaderReleaseMsg { idx }) => {
+                self.touched_faders.remove(&idx);
+                // Bring the fader back in line with Reaper, which may have moved on while it
+                // was held (e.g. automation, or a value Reaper clamped)
+                let volume = self
+                    .get_guid_for_hw_channel(idx)
+                    .map(|guid| (self.get_track_state(guid.clone()).volume, guid));
+                match volume {
+                    Some((volume, guid)) => {
+                        self.last_sent_volume.insert(guid, volume);
+                        self.send_fader(idx, volume);
+                    }
+                    None => self.send_fader(idx, 0.0),
+                }
+                curr_mode
+            }
             XTouchUpstreamMsg::MutePress(mute_msg) => {
-                if let Some(guid) = self.get_guid_for_hw_channel(mute_msg.idx as usize) {
+                if let Some(guid) = self.get_guid_for_hw_channel(mute_msg.idx) {
                     let new_state = self.get_track_state(guid.clone()).buttons.mute.toggle();
                     // Send mute toggle to Reaper for the corresponding track
                     self.to_reaper
@@ -409,17 +918,17 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                         }))
                         .unwrap();
                     // Update the toggle on the hardware
-                    self.to_xtouch
+                    let _ = self
+                        .to_xtouch
                         .send(XTouchDownstreamMsg::MuteLED(xtouch::MuteLEDMsg {
                             idx: mute_msg.idx,
                             state: LEDState::from(new_state),
-                        }))
-                        .unwrap();
+                        }));
                 }
                 curr_mode
             }
             XTouchUpstreamMsg::SoloPress(solo_msg) => {
-                if let Some(guid) = self.get_guid_for_hw_channel(solo_msg.idx as usize) {
+                if let Some(guid) = self.get_guid_for_hw_channel(solo_msg.idx) {
                     let new_state = self.get_track_state(guid.clone()).buttons.solo.toggle();
                     // Send solo toggle to Reaper for the corresponding track
                     self.to_reaper
@@ -429,17 +938,17 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                             data: TrackDataPayload::Soloed(new_state),
                         }))
                         .unwrap();
-                    self.to_xtouch
+                    let _ = self
+                        .to_xtouch
                         .send(XTouchDownstreamMsg::SoloLED(xtouch::SoloLEDMsg {
                             idx: solo_msg.idx,
                             state: LEDState::from(new_state),
-                        }))
-                        .unwrap();
+                        }));
                 }
                 curr_mode
             }
             XTouchUpstreamMsg::ArmPress(arm_msg) => {
-                if let Some(guid) = self.get_guid_for_hw_channel(arm_msg.idx as usize) {
+                if let Some(guid) = self.get_guid_for_hw_channel(arm_msg.idx) {
                     let new_state = self.get_track_state(guid.clone()).buttons.arm.toggle();
                     // Send arm toggle to Reaper for the corresponding track
                     self.to_reaper
@@ -449,17 +958,17 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                             data: TrackDataPayload::Armed(new_state),
                         }))
                         .unwrap();
-                    self.to_xtouch
+                    let _ = self
+                        .to_xtouch
                         .send(XTouchDownstreamMsg::ArmLED(xtouch::ArmLEDMsg {
                             idx: arm_msg.idx,
-                            state: LEDState::from(new_state),
-                        }))
-                        .unwrap();
+                            state: self.arm_led(new_state),
+                        }));
                 }
                 curr_mode
             }
             XTouchUpstreamMsg::EncoderTurnInc(encoder_msg) => {
-                if let Some(guid) = self.get_guid_for_hw_channel(encoder_msg.idx as usize) {
+                if let Some(guid) = self.get_guid_for_hw_channel(encoder_msg.idx) {
                     // Get current pan value and increment it
                     let current_pan = self.get_track_state(guid.clone()).pan;
                     let new_pan = (current_pan + 0.05).min(1.0); // Clamp to max 1.0
@@ -477,19 +986,17 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                         .unwrap();
 
                     // Send encoder LED update downstream to hardware
-                    self.to_xtouch
-                        .send(XTouchDownstreamMsg::EncoderRingLED(
-                            xtouch::EncoderRingLEDMsg::RangePoint(EncoderRingLEDRangePointMsg {
-                                idx: encoder_msg.idx,
-                                pos: new_pan,
-                            }),
-                        ))
-                        .unwrap();
+                    let _ = self.to_xtouch.send(XTouchDownstreamMsg::EncoderRingLED(
+                        xtouch::EncoderRingLEDMsg::RangePoint(EncoderRingLEDRangePointMsg {
+                            idx: encoder_msg.idx,
+                            pos: new_pan,
+                        }),
+                    ));
                 }
                 curr_mode
             }
             XTouchUpstreamMsg::EncoderTurnDec(encoder_msg) => {
-                if let Some(guid) = self.get_guid_for_hw_channel(encoder_msg.idx as usize) {
+                if let Some(guid) = self.get_guid_for_hw_channel(encoder_msg.idx) {
                     // Get current pan value and decrement it
                     let current_pan = self.get_track_state(guid.clone()).pan;
                     let new_pan = (current_pan - 0.05).max(0.0); // Clamp to min 0.0
@@ -507,14 +1014,12 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
                         .unwrap();
 
                     // Send encoder LED update downstream to hardware
-                    self.to_xtouch
-                        .send(XTouchDownstreamMsg::EncoderRingLED(
-                            xtouch::EncoderRingLEDMsg::RangePoint(EncoderRingLEDRangePointMsg {
-                                idx: encoder_msg.idx,
-                                pos: new_pan,
-                            }),
-                        ))
-                        .unwrap();
+                    let _ = self.to_xtouch.send(XTouchDownstreamMsg::EncoderRingLED(
+                        xtouch::EncoderRingLEDMsg::RangePoint(EncoderRingLEDRangePointMsg {
+                            idx: encoder_msg.idx,
+                            pos: new_pan,
+                        }),
+                    ));
                 }
                 curr_mode
             }
@@ -525,6 +1030,10 @@ impl ModeHandler<TrackMsg, TrackMsg, XTouchDownstreamMsg, XTouchUpstreamMsg> for
 
 impl VolumePanMode {
     pub fn initiate_mode_transition(&mut self, upstream: Sender<TrackMsg>) -> ModeState {
+        // Coming back from another mode, show what we have rather than waiting on the sync
+        self.finish_loading();
+        // Any release happened while another mode had the surface
+        self.touched_faders.clear();
         self.track_hw_assignments
             .lock()
             .unwrap()
@@ -538,6 +1047,11 @@ impl VolumePanMode {
                     }));
                 }
             });
+        // Bring the master fader in line with what we know of the master track
+        if let Some(master) = self.track_states.get(MASTER_GUID) {
+            self.send_master_fader(master.volume);
+        }
+        self.send_assignment_display();
         let barrier = Barrier::new();
         upstream.send(TrackMsg::Barrier(barrier)).unwrap();
         ModeState {
@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::guid::TrackGuid;
+
+/// The state of the modes worth carrying over when the bridge restarts mid-session.
+///
+/// Everything in here is only a starting point: Reaper is still the authority, and whatever it
+/// reports after a restore replaces what was saved.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionSnapshot {
+    pub vol_pan: VolPanSession,
+    pub sends: SendsSession,
+}
+
+/// What VolumePanMode was showing
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolPanSession {
+    /// Tracks pinned to slots by GUID, for the restore and manual mapping policies
+    pub pinned: BTreeMap<usize, TrackGuid>,
+    pub bank: usize,
+    /// The last known state of each track, by GUID
+    pub tracks: BTreeMap<TrackGuid, TrackSession>,
+}
+
+/// The last known state of one track
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrackSession {
+    pub name: String,
+    pub volume: f32,
+    pub pan: f32,
+    pub mute: bool,
+    pub solo: bool,
+    pub arm: bool,
+}
+
+/// What TrackSendsMode was showing
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SendsSession {
+    /// The track whose sends were shown last
+    pub track_guid: Option<TrackGuid>,
+    /// Names of every track by GUID, for labelling sends
+    pub track_names: BTreeMap<TrackGuid, String>,
+}
+
+#[derive(Debug)]
+pub enum SessionError {
+    Io(io::Error),
+    Parse(serde_yaml::Error),
+}
+
+/// Where a session is kept between runs, and how often it's saved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionStore {
+    path: PathBuf,
+    save_interval: Duration,
+}
+
+impl SessionStore {
+    pub fn new(path: impl Into<PathBuf>, save_interval: Duration) -> Self {
+        SessionStore {
+            path: path.into(),
+            save_interval,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn save_interval(&self) -> Duration {
+        self.save_interval
+    }
+
+    /// Reads the saved session, or None if nothing has been saved yet.
+    pub fn load(&self) -> Result<Option<SessionSnapshot>, SessionError> {
+        let contents = match std::fs::read(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(SessionError::Io(e)),
+        };
+        serde_yaml::from_slice(&contents)
+            .map(Some)
+            .map_err(SessionError::Parse)
+    }
+
+    /// Replaces the saved session with `snapshot`.
+    ///
+    /// The new session is written next to the old one and moved over it, so a crash partway
+    /// through leaves the old session intact.
+    pub fn save(&self, snapshot: &SessionSnapshot) -> Result<(), SessionError> {
+        let contents = serde_yaml::to_string(snapshot).map_err(SessionError::Parse)?;
+        let partial = self.path.with_extension("partial");
+        std::fs::write(&partial, contents).map_err(SessionError::Io)?;
+        std::fs::rename(&partial, &self.path).map_err(SessionError::Io)
+    }
+}
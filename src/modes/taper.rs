@@ -0,0 +1,83 @@
+use serde::Deserialize;
+
+/// The loudest a fader goes, as in Reaper
+pub const MAX_DB: f32 = 12.0;
+
+/// The quietest volume the Log taper shows above the bottom of the fader
+pub const LOG_MIN_DB: f32 = -60.0;
+
+/// Where 0 dB sits on a fader following Reaper's own curve. Reaper reports volume as a position
+/// on that curve, so this is also the volume Reaper reports for 0 dB.
+pub const FADER_0DB: f32 = 0.716;
+
+/// Converts decibels to a linear gain, where 1.0 is 0 dB.
+pub fn db_to_gain(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
+
+/// Converts a linear gain to decibels; silence is negative infinity.
+pub fn gain_to_db(gain: f32) -> f32 {
+    match gain > 0.0 {
+        true => 20.0 * gain.log10(),
+        false => f32::NEG_INFINITY,
+    }
+}
+
+/// How volume is laid out along the travel of a fader, from 0.0 at the bottom to 1.0 at the top.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Taper {
+    /// Gain grows evenly along the fader, up to MAX_DB at the top. Most of the useful range ends
+    /// up squeezed into the top of the travel, with 0 dB about a quarter of the way up.
+    Linear,
+    /// Decibels grow evenly along the fader, from LOG_MIN_DB to MAX_DB; anything quieter sits at
+    /// the bottom.
+    Log,
+    /// Reaper's own fader curve, with 0 dB at FADER_0DB and about MAX_DB at the top. Gain grows
+    /// with the fourth power of the position, which puts the dB marks close to where a mixing
+    /// console prints them.
+    #[default]
+    ReaperDb,
+}
+
+impl Taper {
+    /// Where `db` sits on a fader with this taper.
+    pub fn db_to_fader(self, db: f32) -> f32 {
+        let fader = match self {
+            Taper::Linear => db_to_gain(db) / db_to_gain(MAX_DB),
+            Taper::Log => (db - LOG_MIN_DB) / (MAX_DB - LOG_MIN_DB),
+            Taper::ReaperDb => FADER_0DB * 10f32.powf(db / 80.0),
+        };
+        fader.clamp(0.0, 1.0)
+    }
+
+    /// The volume in dB at a fader position with this taper; the bottom of the fader is silence.
+    pub fn fader_to_db(self, fader: f32) -> f32 {
+        let fader = fader.clamp(0.0, 1.0);
+        if fader == 0.0 {
+            return f32::NEG_INFINITY;
+        }
+        match self {
+            Taper::Linear => gain_to_db(fader * db_to_gain(MAX_DB)),
+            Taper::Log => LOG_MIN_DB + fader * (MAX_DB - LOG_MIN_DB),
+            Taper::ReaperDb => 80.0 * (fader / FADER_0DB).log10(),
+        }
+    }
+
+    /// Where a volume reported by Reaper sits on a fader with this taper.
+    pub fn volume_to_fader(self, volume: f32) -> f32 {
+        match self {
+            // Reaper already reports positions on its own curve
+            Taper::ReaperDb => volume.clamp(0.0, 1.0),
+            _ => self.db_to_fader(Taper::ReaperDb.fader_to_db(volume)),
+        }
+    }
+
+    /// The volume to send Reaper for a fader position with this taper.
+    pub fn fader_to_volume(self, fader: f32) -> f32 {
+        match self {
+            Taper::ReaperDb => fader.clamp(0.0, 1.0),
+            _ => Taper::ReaperDb.db_to_fader(self.fader_to_db(fader)),
+        }
+    }
+}
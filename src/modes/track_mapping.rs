@@ -0,0 +1,156 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+
+use crate::guid::TrackGuid;
+
+/// Decides which tracks VolumePanMode puts on the surface, and where.
+///
+/// Each policy lays tracks out in slots; slot N is shown on hardware channel N % num_channels of
+/// bank N / num_channels.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MappingPolicy {
+    /// Every track, in the slot matching its Reaper index
+    #[default]
+    ByIndex,
+    /// Like ByIndex, but only the first N tracks in the project
+    FirstTracks(usize),
+    /// Only selected tracks, packed into slots in Reaper index order
+    SelectedOnly,
+    /// Tracks in fixed slots by GUID, e.g. a mapping saved from an earlier session
+    Restore(BTreeMap<usize, TrackGuid>),
+    /// No tracks until they are pinned to a slot explicitly
+    Manual,
+}
+
+#[derive(Default)]
+struct TrackPlacement {
+    index: Option<i32>,
+    selected: bool,
+}
+
+/// Keeps the track data a MappingPolicy needs and applies the policy to it.
+pub struct TrackMapper {
+    policy: MappingPolicy,
+    tracks: HashMap<TrackGuid, TrackPlacement>,
+    // Tracks placed in a slot by hand (or restored); used by the Restore and Manual policies
+    pinned: BTreeMap<usize, TrackGuid>,
+}
+
+impl TrackMapper {
+    pub fn new(policy: MappingPolicy) -> Self {
+        let mut mapper = TrackMapper {
+            policy: MappingPolicy::default(),
+            tracks: HashMap::new(),
+            pinned: BTreeMap::new(),
+        };
+        mapper.set_policy(policy);
+        mapper
+    }
+
+    /// Switches to another policy, keeping the track data gathered so far.
+    ///
+    /// Pinned tracks are reset to the policy's own (for Restore) or cleared.
+    pub fn set_policy(&mut self, policy: MappingPolicy) {
+        self.pinned = match &policy {
+            MappingPolicy::Restore(pinned) => pinned.clone(),
+            _ => BTreeMap::new(),
+        };
+        self.policy = policy;
+    }
+
+    /// Records a track's Reaper index, or that it no longer has one.
+    ///
+    /// Reaper indices are unique, so any other track still holding this index has moved and
+    /// loses it until its own update arrives.
+    pub fn set_index(&mut self, guid: &TrackGuid, index: Option<i32>) {
+        if index.is_some() {
+            for (other_guid, track) in self.tracks.iter_mut() {
+                if other_guid != guid && track.index == index {
+                    track.index = None;
+                }
+            }
+        }
+        self.tracks.entry(guid.clone()).or_default().index = index;
+    }
+
+    /// Forgets a track that's gone from the project. The tracks after it in Reaper's order move up
+    /// one, as Reaper renumbers them, so the layout closes the gap it leaves.
+    ///
+    /// A pinned track keeps its slot, in case it comes back (e.g. the deletion is undone).
+    pub fn remove(&mut self, guid: &TrackGuid) {
+        let Some(removed) = self.tracks.remove(guid).and_then(|track| track.index) else {
+            return;
+        };
+        for index in self
+            .tracks
+            .values_mut()
+            .filter_map(|track| track.index.as_mut())
+        {
+            if *index > removed {
+                *index -= 1;
+            }
+        }
+    }
+
+    pub fn set_selected(&mut self, guid: &TrackGuid, selected: bool) {
+        self.tracks.entry(guid.clone()).or_default().selected = selected;
+    }
+
+    /// Returns the Reaper index of a track, if it has one.
+    pub fn index(&self, guid: &TrackGuid) -> Option<i32> {
+        self.tracks.get(guid).and_then(|track| track.index)
+    }
+
+    /// Places a track in a slot, moving it out of any slot it was already in.
+    pub fn pin(&mut self, slot: usize, guid: TrackGuid) {
+        self.pinned.retain(|_, pinned_guid| *pinned_guid != guid);
+        self.pinned.insert(slot, guid);
+    }
+
+    /// Returns the tracks placed in slots by hand (or restored).
+    pub fn pinned(&self) -> &BTreeMap<usize, TrackGuid> {
+        &self.pinned
+    }
+
+    /// Lays out the known tracks according to the policy, returning the track in each used slot.
+    pub fn layout(&self) -> BTreeMap<usize, TrackGuid> {
+        match &self.policy {
+            MappingPolicy::ByIndex => self.by_index(usize::MAX),
+            MappingPolicy::FirstTracks(count) => self.by_index(*count),
+            MappingPolicy::SelectedOnly => {
+                let mut selected: Vec<(i32, &TrackGuid)> = self
+                    .tracks
+                    .iter()
+                    .filter(|(_, track)| track.selected)
+                    .filter_map(|(guid, track)| track.index.map(|index| (index, guid)))
+                    .collect();
+                selected.sort();
+                selected
+                    .into_iter()
+                    .enumerate()
+                    .map(|(slot, (_, guid))| (slot, guid.clone()))
+                    .collect()
+            }
+            // Only tracks that exist in the project are shown
+            MappingPolicy::Restore(_) | MappingPolicy::Manual => self
+                .pinned
+                .iter()
+                .filter(|(_, guid)| self.index(guid).is_some())
+                .map(|(slot, guid)| (*slot, guid.clone()))
+                .collect(),
+        }
+    }
+
+    // Every track with a Reaper index below `count`, in the slot matching that index
+    fn by_index(&self, count: usize) -> BTreeMap<usize, TrackGuid> {
+        self.tracks
+            .iter()
+            .filter_map(|(guid, track)| {
+                let slot = usize::try_from(track.index?).ok()?;
+                (slot < count).then(|| (slot, guid.clone()))
+            })
+            .collect()
+    }
+}
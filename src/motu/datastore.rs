@@ -5,6 +5,7 @@ use std::sync::{Arc, Mutex};
 use crossbeam_channel::{Receiver, Sender};
 use reqwest::blocking::Client;
 use serde_json::Value;
+use tracing::debug;
 
 enum JsonValue {
     String(String),
@@ -75,7 +76,7 @@ impl HttpDatastore {
                     .unwrap_or(etag);
                 // This now has all the new data from the server.
                 let data = response.json::<BTreeMap<String, Value>>().unwrap();
-                println!("Received data from store: {:?}", data);
+                debug!("Received data from store: {:?}", data);
                 // Process any data for which we have a callback registered.
                 // For each key with a callback, check if the value has changed from the cache (or
                 // if not in the cache, treat as changed). If changed, update the cache and call
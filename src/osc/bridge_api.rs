@@ -0,0 +1,141 @@
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+
+use rosc::{OscMessage, OscPacket, OscType};
+use tracing::{debug, warn};
+
+use crate::osc::decode::decode_packet;
+use crate::osc::pattern::match_address;
+use crate::osc::transport::OscTransport;
+
+/// Replies with the current mode's name, e.g. "vol_pan". With a mode's name, switches to it.
+pub const MODE: &str = "/arpad/mode";
+/// Replies with the GUID of the track on each channel strip, one message per channel
+pub const CHANNELS: &str = "/arpad/channels";
+/// Replies with the GUID of the track on channel strip `idx`, counted from 0, or "" if it has none
+pub const CHANNEL_TRACK: &str = "/arpad/channel/{idx}/track";
+
+/// What the bridge's API shows and controls.
+pub trait BridgeState: Send + 'static {
+    /// The current mode's name, or "" before there is one
+    fn mode(&self) -> String;
+
+    /// The GUID of the track on each channel strip, "" for strips without one
+    fn channel_tracks(&self) -> Vec<String>;
+
+    /// Switches to the mode called `name`, returning false if there's no switching to it
+    fn switch_mode(&self, name: &str) -> bool;
+}
+
+/// An OSC API onto the bridge itself, for tablets and the like to see what the surface is showing
+/// and switch modes from. It's served apart from Reaper's OSC, on an address of its own.
+///
+/// Replies go back to whoever asked, on the address they asked on.
+pub struct BridgeApi<S: BridgeState> {
+    state: S,
+}
+
+impl<S: BridgeState> BridgeApi<S> {
+    pub fn new(state: S) -> Self {
+        BridgeApi { state }
+    }
+
+    /// Handles one request, returning the replies to it.
+    pub fn handle(&self, msg: &OscMessage) -> Vec<OscMessage> {
+        if msg.addr == MODE {
+            match msg.args.first() {
+                None => {}
+                Some(OscType::String(name)) => {
+                    if !self.state.switch_mode(name) {
+                        warn!(name = %name, "No mode to switch to by that name");
+                    }
+                    return vec![];
+                }
+                Some(arg) => {
+                    warn!(?arg, "Modes are switched to by name");
+                    return vec![];
+                }
+            }
+            return vec![OscMessage {
+                addr: MODE.to_string(),
+                args: vec![OscType::String(self.state.mode())],
+            }];
+        }
+        if msg.addr == CHANNELS {
+            return self
+                .state
+                .channel_tracks()
+                .into_iter()
+                .enumerate()
+                .map(|(idx, guid)| channel_reply(idx, guid))
+                .collect();
+        }
+        if let Some(params) = match_address(CHANNEL_TRACK, &msg.addr) {
+            let tracks = self.state.channel_tracks();
+            return match params[0].parse::<usize>() {
+                Ok(idx) if idx < tracks.len() => {
+                    vec![channel_reply(idx, tracks[idx].clone())]
+                }
+                _ => {
+                    warn!(addr = %msg.addr, "No such channel strip");
+                    vec![]
+                }
+            };
+        }
+        debug!(addr = %msg.addr, "Not part of the bridge's API");
+        vec![]
+    }
+
+    /// Spawns a thread that answers the requests arriving on `transport`, until it fails.
+    pub fn start(self, transport: Arc<dyn OscTransport>) -> JoinHandle<()> {
+        thread::spawn(move || {
+            loop {
+                let (buf, from) = match transport.recv() {
+                    Ok(received) => received,
+                    Err(err) => {
+                        warn!(%err, "Stopped serving the bridge's API");
+                        return;
+                    }
+                };
+                let packet = match decode_packet(&buf) {
+                    Ok(packet) => packet,
+                    Err(err) => {
+                        debug!(?err, %from, "Dropping a request that isn't OSC");
+                        continue;
+                    }
+                };
+                for reply in self.handle_packet(packet) {
+                    let buf = match rosc::encoder::encode(&OscPacket::Message(reply)) {
+                        Ok(buf) => buf,
+                        Err(err) => {
+                            warn!(?err, "Couldn't encode a reply to an API request");
+                            continue;
+                        }
+                    };
+                    if let Err(err) = transport.send_to(&buf, from) {
+                        warn!(%err, %from, "Couldn't reply to an API request");
+                    }
+                }
+            }
+        })
+    }
+
+    // The replies to each message in `packet`, in order
+    fn handle_packet(&self, packet: OscPacket) -> Vec<OscMessage> {
+        match packet {
+            OscPacket::Message(msg) => self.handle(&msg),
+            OscPacket::Bundle(bundle) => bundle
+                .content
+                .into_iter()
+                .flat_map(|packet| self.handle_packet(packet))
+                .collect(),
+        }
+    }
+}
+
+fn channel_reply(idx: usize, guid: String) -> OscMessage {
+    OscMessage {
+        addr: CHANNEL_TRACK.replace("{idx}", &idx.to_string()),
+        args: vec![OscType::String(guid)],
+    }
+}
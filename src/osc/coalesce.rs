@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Condvar, Mutex, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tracing::warn;
+
+use crate::osc::pattern::match_address;
+use crate::osc::transport::OscTransport;
+
+/// Routes whose messages are discrete changes, like a toggle, rather than a position that only
+/// matters once it settles. These are never held back.
+pub const DISCRETE_ROUTES: &[&str] = &[
+    "/track/{track_guid}/{mute,solo,rec-arm,phase,selected,delete}",
+    "/track/{track_guid}/fx/{fx_idx}/{enabled,bypass}",
+    "/master/mute",
+    "/{play,stop,record,repeat,rewind,forward,action}",
+];
+
+/// Limits how often each address is sent, keeping only the latest message for it.
+///
+/// The first message to an address goes out straight away. Any more within `window` of it are
+/// held, each replacing the last, and the latest is sent once the window is up; so each address is
+/// sent at most once per window. Messages to addresses matching one of the `bypass` routes, and
+/// bundles, are always sent straight away.
+pub struct CoalescingTransport {
+    inner: Arc<dyn OscTransport>,
+    window: Duration,
+    bypass: Vec<String>,
+    slots: Arc<(Mutex<HashMap<String, Slot>>, Condvar)>,
+}
+
+#[derive(Default)]
+struct Slot {
+    last_sent: Option<Instant>,
+    // The latest packet held back, and where it's going
+    pending: Option<(Vec<u8>, Option<SocketAddr>)>,
+}
+
+impl CoalescingTransport {
+    pub fn new(inner: Arc<dyn OscTransport>, window: Duration, bypass: Vec<String>) -> Self {
+        let slots = Arc::new((Mutex::new(HashMap::new()), Condvar::new()));
+        let flusher = Arc::downgrade(&slots);
+        let flush_to = inner.clone();
+        thread::spawn(move || flush_pending(flusher, flush_to, window));
+        CoalescingTransport {
+            inner,
+            window,
+            bypass,
+            slots,
+        }
+    }
+
+    fn bypasses(&self, addr: &str) -> bool {
+        self.bypass
+            .iter()
+            .any(|route| match_address(route, addr).is_some())
+    }
+
+    /// A message that is held back always reports success; if it later fails to send, the error
+    /// is logged instead.
+    fn coalesce(&self, packet: &[u8], to: Option<SocketAddr>) -> io::Result<()> {
+        let Some(addr) = message_address(packet) else {
+            return send(&*self.inner, packet, to);
+        };
+        if self.bypasses(addr) {
+            return send(&*self.inner, packet, to);
+        }
+        let (slots, pending_changed) = &*self.slots;
+        let mut slots = slots.lock().unwrap();
+        let slot = slots.entry(addr.to_string()).or_default();
+        let now = Instant::now();
+        let window_open = slot
+            .last_sent
+            .is_some_and(|last_sent| now.duration_since(last_sent) < self.window);
+        match window_open {
+            true => {
+                slot.pending = Some((packet.to_vec(), to));
+                pending_changed.notify_one();
+                Ok(())
+            }
+            false => {
+                slot.last_sent = Some(now);
+                slot.pending = None;
+                send(&*self.inner, packet, to)
+            }
+        }
+    }
+}
+
+impl OscTransport for CoalescingTransport {
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        self.coalesce(packet, None)
+    }
+
+    fn send_to(&self, packet: &[u8], to: SocketAddr) -> io::Result<()> {
+        self.coalesce(packet, Some(to))
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        self.inner.recv()
+    }
+}
+
+// Sends each held message once its address's window is up, until the transport is dropped
+fn flush_pending(
+    slots: Weak<(Mutex<HashMap<String, Slot>>, Condvar)>,
+    inner: Arc<dyn OscTransport>,
+    window: Duration,
+) {
+    // Wake up now and then even with nothing pending, to notice the transport going away
+    const IDLE_WAIT: Duration = Duration::from_millis(100);
+    while let Some(slots) = slots.upgrade() {
+        let (slots, pending_changed) = &*slots;
+        let mut slots = slots.lock().unwrap();
+        let now = Instant::now();
+        let mut next_due: Option<Instant> = None;
+        for (addr, slot) in slots.iter_mut() {
+            let Some(last_sent) = slot.last_sent else {
+                continue;
+            };
+            if slot.pending.is_none() {
+                continue;
+            }
+            let due = last_sent + window;
+            if due > now {
+                next_due = Some(next_due.map_or(due, |next_due| next_due.min(due)));
+                continue;
+            }
+            let (packet, to) = slot.pending.take().unwrap();
+            slot.last_sent = Some(now);
+            if let Err(e) = send(&*inner, &packet, to) {
+                warn!("Failed to send coalesced message to {}: {}", addr, e);
+            }
+        }
+        let wait = next_due.map_or(IDLE_WAIT, |due| due.saturating_duration_since(now));
+        drop(pending_changed.wait_timeout(slots, wait).unwrap());
+    }
+}
+
+fn send(inner: &dyn OscTransport, packet: &[u8], to: Option<SocketAddr>) -> io::Result<()> {
+    match to {
+        Some(to) => inner.send_to(packet, to),
+        None => inner.send(packet),
+    }
+}
+
+// The address of an encoded OSC message, or None for a bundle or anything unreadable
+fn message_address(packet: &[u8]) -> Option<&str> {
+    let end = packet.iter().position(|&byte| byte == 0)?;
+    let addr = std::str::from_utf8(&packet[..end]).ok()?;
+    match addr.starts_with('/') {
+        true => Some(addr),
+        false => None,
+    }
+}
@@ -0,0 +1,488 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use rosc::{OscError, OscMidiMessage, OscPacket, OscType};
+
+/// A type the arguments of incoming messages are decoded into.
+///
+/// Senders don't always agree with the spec on how a value is typed; Reaper itself sends toggles
+/// as floats. So an argument is taken as long as it carries a value of the type without losing
+/// anything, e.g. the float 3.0 as the int 3, or the string "1" as true.
+pub trait FromOscArg: Sized {
+    /// What the spec calls the type, for saying why an argument couldn't be decoded
+    const NAME: &'static str;
+
+    fn from_osc_arg(arg: &OscType) -> Option<Self>;
+}
+
+impl FromOscArg for i32 {
+    const NAME: &'static str = "int";
+
+    fn from_osc_arg(arg: &OscType) -> Option<Self> {
+        match arg {
+            OscType::Int(value) => Some(*value),
+            OscType::Long(value) => i32::try_from(*value).ok(),
+            OscType::Float(value) => {
+                whole(*value as f64, i32::MIN as f64, i32::MAX as f64).map(|value| value as i32)
+            }
+            OscType::Double(value) => {
+                whole(*value, i32::MIN as f64, i32::MAX as f64).map(|value| value as i32)
+            }
+            OscType::String(value) => value.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl FromOscArg for i64 {
+    const NAME: &'static str = "long";
+
+    fn from_osc_arg(arg: &OscType) -> Option<Self> {
+        match arg {
+            OscType::Int(value) => Some(*value as i64),
+            OscType::Long(value) => Some(*value),
+            OscType::Float(value) => {
+                whole(*value as f64, i64::MIN as f64, i64::MAX as f64).map(|value| value as i64)
+            }
+            OscType::Double(value) => {
+                whole(*value, i64::MIN as f64, i64::MAX as f64).map(|value| value as i64)
+            }
+            OscType::String(value) => value.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl FromOscArg for f32 {
+    const NAME: &'static str = "float";
+
+    fn from_osc_arg(arg: &OscType) -> Option<Self> {
+        match arg {
+            OscType::Int(value) => Some(*value as f32),
+            OscType::Long(value) => Some(*value as f32),
+            OscType::Float(value) => Some(*value),
+            OscType::Double(value) => Some(*value as f32),
+            OscType::String(value) => value.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl FromOscArg for f64 {
+    const NAME: &'static str = "double";
+
+    fn from_osc_arg(arg: &OscType) -> Option<Self> {
+        match arg {
+            OscType::Int(value) => Some(*value as f64),
+            OscType::Long(value) => Some(*value as f64),
+            OscType::Float(value) => Some(*value as f64),
+            OscType::Double(value) => Some(*value),
+            OscType::String(value) => value.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl FromOscArg for bool {
+    const NAME: &'static str = "bool";
+
+    // Numbers are only taken as bools when they're 0 or 1, so a level sent to a toggle by
+    // mistake doesn't flip it
+    fn from_osc_arg(arg: &OscType) -> Option<Self> {
+        match arg {
+            OscType::Bool(value) => Some(*value),
+            OscType::String(value) => match value.trim().to_ascii_lowercase().as_str() {
+                "1" | "true" => Some(true),
+                "0" | "false" => Some(false),
+                _ => None,
+            },
+            _ => match i64::from_osc_arg(arg)? {
+                0 => Some(false),
+                1 => Some(true),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl FromOscArg for String {
+    const NAME: &'static str = "string";
+
+    fn from_osc_arg(arg: &OscType) -> Option<Self> {
+        match arg {
+            OscType::String(value) => Some(value.clone()),
+            // What `decode_packet` makes of strings that aren't UTF-8
+            OscType::Blob(value) => Some(String::from_utf8_lossy(value).into_owned()),
+            _ => None,
+        }
+    }
+}
+
+impl FromOscArg for Vec<u8> {
+    const NAME: &'static str = "blob";
+
+    // Strings are taken as their bytes, for string arguments flagged `binary` in the spec
+    fn from_osc_arg(arg: &OscType) -> Option<Self> {
+        match arg {
+            OscType::Blob(value) => Some(value.clone()),
+            OscType::String(value) => Some(value.clone().into_bytes()),
+            _ => None,
+        }
+    }
+}
+
+impl FromOscArg for OscMidiMessage {
+    const NAME: &'static str = "midi";
+
+    fn from_osc_arg(arg: &OscType) -> Option<Self> {
+        match arg {
+            OscType::Midi(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl<T: FromOscArg> FromOscArg for Vec<T> {
+    const NAME: &'static str = "array";
+
+    fn from_osc_arg(arg: &OscType) -> Option<Self> {
+        match arg {
+            OscType::Array(array) => array.content.iter().map(T::from_osc_arg).collect(),
+            _ => None,
+        }
+    }
+}
+
+// A float as a whole number, if it is one and fits between `min` and `max`
+fn whole(value: f64, min: f64, max: f64) -> Option<f64> {
+    match value.fract() == 0.0 && (min..=max).contains(&value) {
+        true => Some(value),
+        false => None,
+    }
+}
+
+/// An argument a route takes, as `line_up` looks for it in a message.
+#[derive(Clone, Copy)]
+pub struct Expected {
+    name: &'static str,
+    decodes: fn(&OscType) -> bool,
+}
+
+/// The argument of type `T` a route takes.
+pub fn expect<T: FromOscArg>() -> Expected {
+    Expected {
+        name: T::NAME,
+        decodes: |arg| T::from_osc_arg(arg).is_some(),
+    }
+}
+
+/// Lines the arguments of a message up with the ones a route takes, in the spec's order.
+///
+/// Messages whose arguments decode where the spec puts them are taken as they are. Peers don't
+/// always send them that way though, so otherwise each of the route's arguments takes the first
+/// argument left whose OSC type is exactly its own, which copes with reordered arguments and
+/// ones the route doesn't know about among them. If that doesn't place all of them the message
+/// is again taken as it is, to fail decoding with the reason it would have. Arguments past the
+/// route's are ignored either way.
+pub fn line_up<'a>(args: &'a [OscType], expected: &[Expected]) -> Cow<'a, [OscType]> {
+    let in_order = args
+        .iter()
+        .zip(expected)
+        .all(|(arg, expected)| (expected.decodes)(arg));
+    if in_order {
+        return Cow::Borrowed(args);
+    }
+    let mut left: Vec<Option<&OscType>> = args.iter().map(Some).collect();
+    let mut lined_up = Vec::with_capacity(expected.len());
+    for expected in expected {
+        let found = left
+            .iter_mut()
+            .find(|arg| arg.is_some_and(|arg| type_name(arg) == Some(expected.name)))
+            .and_then(Option::take);
+        match found {
+            Some(arg) => lined_up.push(arg.clone()),
+            None => return Cow::Borrowed(args),
+        }
+    }
+    Cow::Owned(lined_up)
+}
+
+// What the spec calls the type an argument was sent as
+fn type_name(arg: &OscType) -> Option<&'static str> {
+    match arg {
+        OscType::Int(_) => Some(i32::NAME),
+        OscType::Long(_) => Some(i64::NAME),
+        OscType::Float(_) => Some(f32::NAME),
+        OscType::Double(_) => Some(f64::NAME),
+        OscType::Bool(_) => Some(bool::NAME),
+        OscType::String(_) => Some(String::NAME),
+        OscType::Blob(_) => Some(<Vec<u8>>::NAME),
+        OscType::Midi(_) => Some(OscMidiMessage::NAME),
+        OscType::Array(_) => Some("array"),
+        _ => None,
+    }
+}
+
+/// Decodes the argument at `idx`, which the route requires.
+pub fn required<T: FromOscArg>(args: &[OscType], idx: usize) -> Result<T, String> {
+    optional(args, idx)?.ok_or_else(|| format!("argument {} is missing", idx))
+}
+
+/// Decodes the argument at `idx`, if the message goes that far.
+pub fn optional<T: FromOscArg>(args: &[OscType], idx: usize) -> Result<Option<T>, String> {
+    match args.get(idx) {
+        Some(arg) => match T::from_osc_arg(arg) {
+            Some(value) => Ok(Some(value)),
+            None => Err(format!(
+                "argument {} should be {}, not {:?}",
+                idx,
+                T::NAME,
+                arg
+            )),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Decodes a packet received from a peer.
+///
+/// OSC strings are meant to be ASCII, but Reaper sends names as they were typed, in whatever
+/// encoding that was, and rosc turns a message away when a string in it isn't UTF-8; in a bundle
+/// it quietly drops that message and all those after it. Such packets are decoded anyway: string
+/// arguments that aren't UTF-8 arrive as blobs of their exact bytes, which decode as strings with
+/// the bad bytes replaced and as bytes untouched, and an address that isn't is made UTF-8 the
+/// same lossy way. Packets that are malformed otherwise are still errors.
+pub fn decode_packet(buf: &[u8]) -> Result<OscPacket, OscError> {
+    match rosc::decoder::decode_udp(buf) {
+        Ok((&[], packet)) => Ok(packet),
+        // What rosc made of a bundle it stopped reading partway is kept if that's all there is
+        Ok((_, packet)) => match repaired(buf) {
+            Some(buf) => rosc::decoder::decode_udp(&buf).map(|(_, packet)| packet),
+            None => Ok(packet),
+        },
+        Err(OscError::StringError(e)) => match repaired(buf) {
+            Some(buf) => rosc::decoder::decode_udp(&buf).map(|(_, packet)| packet),
+            None => Err(OscError::StringError(e)),
+        },
+        Err(e) => Err(e),
+    }
+}
+
+// The packet in `buf` with its strings that aren't UTF-8 made decodable, or None if it's
+// malformed
+fn repaired(buf: &[u8]) -> Option<Vec<u8>> {
+    let mut repaired = Vec::with_capacity(buf.len());
+    rewrite_packet(buf, &mut repaired, &mut repair_message)?;
+    Some(repaired)
+}
+
+/// The argument sending `bytes` as a string, for string arguments flagged `binary` in the spec.
+///
+/// A rosc string has to be UTF-8, so bytes that aren't are carried as a blob, which
+/// [`encode_packet`] sends as the string it stands for.
+pub fn binary_arg(bytes: Vec<u8>) -> OscType {
+    match String::from_utf8(bytes) {
+        Ok(value) => OscType::String(value),
+        Err(e) => OscType::Blob(e.into_bytes()),
+    }
+}
+
+/// Encodes a packet to send to a peer, with the binary arguments in it sent as strings of their
+/// exact bytes.
+///
+/// `binary` holds where the binary arguments are in each message of the packet, in the order
+/// the messages appear in it; a blob in one of those places is one [`binary_arg`] made of bytes
+/// that aren't UTF-8.
+pub fn encode_packet(packet: &OscPacket, binary: &[&[usize]]) -> Result<Vec<u8>, OscError> {
+    let buf = rosc::encoder::encode(packet)?;
+    if binary.iter().all(|positions| positions.is_empty()) {
+        return Ok(buf);
+    }
+    let mut binary = binary.iter();
+    let mut encoded = Vec::with_capacity(buf.len());
+    rewrite_packet(&buf, &mut encoded, &mut |message, out| {
+        unblob_message(message, binary.next().copied().unwrap_or_default(), out)
+    })
+    .ok_or(OscError::BadPacket("rosc encoded a malformed packet"))?;
+    Ok(encoded)
+}
+
+const BUNDLE_TAG: &[u8] = b"#bundle\0";
+
+// Copies the packet in `buf` to `out`, with each message in it rewritten by `message`, or None
+// if it's malformed
+fn rewrite_packet(
+    buf: &[u8],
+    out: &mut Vec<u8>,
+    message: &mut impl FnMut(&[u8], &mut Vec<u8>) -> Option<()>,
+) -> Option<()> {
+    match buf.starts_with(BUNDLE_TAG) {
+        true => rewrite_bundle(buf, out, message),
+        false => message(buf, out),
+    }
+}
+
+fn rewrite_bundle(
+    buf: &[u8],
+    out: &mut Vec<u8>,
+    message: &mut impl FnMut(&[u8], &mut Vec<u8>) -> Option<()>,
+) -> Option<()> {
+    // The tag and the time tag are kept as they are
+    out.extend_from_slice(buf.get(..BUNDLE_TAG.len() + 8)?);
+    let mut rest = &buf[BUNDLE_TAG.len() + 8..];
+    while !rest.is_empty() {
+        let size = i32::from_be_bytes(rest.get(..4)?.try_into().ok()?);
+        let element = rest.get(4..4 + usize::try_from(size).ok()?)?;
+        let mut rewritten = Vec::with_capacity(element.len());
+        rewrite_packet(element, &mut rewritten, message)?;
+        out.extend_from_slice(&i32::try_from(rewritten.len()).ok()?.to_be_bytes());
+        out.extend_from_slice(&rewritten);
+        rest = &rest[4 + element.len()..];
+    }
+    Some(())
+}
+
+fn repair_message(buf: &[u8], out: &mut Vec<u8>) -> Option<()> {
+    let (addr, mut rest) = split_string(buf)?;
+    push_string(out, String::from_utf8_lossy(addr).as_bytes());
+    // A message without a type tag string has no arguments to repair
+    if rest.is_empty() {
+        return Some(());
+    }
+    let (tags, args) = split_string(rest)?;
+    let tags = tags.strip_prefix(b",")?;
+    rest = args;
+    let mut repaired_tags = vec![b','];
+    let mut repaired_args = Vec::with_capacity(rest.len());
+    for &tag in tags {
+        let size = match tag {
+            b'i' | b'f' | b'c' | b'r' | b'm' => 4,
+            b'h' | b'd' | b't' => 8,
+            b'T' | b'F' | b'N' | b'I' | b'[' | b']' => 0,
+            b's' | b'S' => {
+                let (value, after) = split_string(rest)?;
+                match std::str::from_utf8(value) {
+                    Ok(_) => {
+                        repaired_tags.push(tag);
+                        repaired_args.extend_from_slice(&rest[..rest.len() - after.len()]);
+                    }
+                    Err(_) => {
+                        repaired_tags.push(b'b');
+                        push_blob(&mut repaired_args, value)?;
+                    }
+                }
+                rest = after;
+                continue;
+            }
+            b'b' => {
+                let len =
+                    usize::try_from(i32::from_be_bytes(rest.get(..4)?.try_into().ok()?)).ok()?;
+                4 + padded(len)
+            }
+            _ => return None,
+        };
+        repaired_tags.push(tag);
+        repaired_args.extend_from_slice(rest.get(..size)?);
+        rest = &rest[size..];
+    }
+    push_string(out, &repaired_tags);
+    out.extend_from_slice(&repaired_args);
+    Some(())
+}
+
+// Copies the message in `buf` to `out`, with the blobs among its arguments at `binary` sent as
+// strings, or None if it's malformed
+fn unblob_message(buf: &[u8], binary: &[usize], out: &mut Vec<u8>) -> Option<()> {
+    let (addr, rest) = split_string(buf)?;
+    push_string(out, addr);
+    if rest.is_empty() {
+        return Some(());
+    }
+    let (tags, mut rest) = split_string(rest)?;
+    let tags = tags.strip_prefix(b",")?;
+    let mut unblobbed_tags = vec![b','];
+    let mut unblobbed_args = Vec::with_capacity(rest.len());
+    // Which argument each tag is, counting an array as one
+    let (mut idx, mut depth) = (0, 0);
+    for &tag in tags {
+        let size = match tag {
+            b'i' | b'f' | b'c' | b'r' | b'm' => 4,
+            b'h' | b'd' | b't' => 8,
+            b'T' | b'F' | b'N' | b'I' | b'[' | b']' => 0,
+            b's' | b'S' => split_string(rest)?.0.len() + 1,
+            b'b' => {
+                let len =
+                    usize::try_from(i32::from_be_bytes(rest.get(..4)?.try_into().ok()?)).ok()?;
+                if depth == 0 && binary.contains(&idx) {
+                    unblobbed_tags.push(b's');
+                    push_string(&mut unblobbed_args, rest.get(4..4 + len)?);
+                    rest = &rest[4 + padded(len)..];
+                    idx += 1;
+                    continue;
+                }
+                4 + len
+            }
+            _ => return None,
+        };
+        let size = padded(size);
+        unblobbed_tags.push(tag);
+        unblobbed_args.extend_from_slice(rest.get(..size)?);
+        rest = &rest[size..];
+        match tag {
+            b'[' => depth += 1,
+            b']' => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 && tag != b'[' {
+            idx += 1;
+        }
+    }
+    push_string(out, &unblobbed_tags);
+    out.extend_from_slice(&unblobbed_args);
+    Some(())
+}
+
+// The bytes of the null-terminated, padded string at the start of `buf`, and what follows it
+fn split_string(buf: &[u8]) -> Option<(&[u8], &[u8])> {
+    let len = buf.iter().position(|&byte| byte == 0)?;
+    let after = buf.get(padded(len + 1)..)?;
+    Some((&buf[..len], after))
+}
+
+fn push_string(out: &mut Vec<u8>, value: &[u8]) {
+    out.extend_from_slice(value);
+    out.resize(out.len() + padded(value.len() + 1) - value.len(), 0);
+}
+
+fn push_blob(out: &mut Vec<u8>, value: &[u8]) -> Option<()> {
+    out.extend_from_slice(&i32::try_from(value.len()).ok()?.to_be_bytes());
+    out.extend_from_slice(value);
+    out.resize(out.len() + padded(value.len()) - value.len(), 0);
+    Some(())
+}
+
+// `len` rounded up to OSC's 4-byte alignment
+fn padded(len: usize) -> usize {
+    len.div_ceil(4) * 4
+}
+
+/// Why `dispatch_osc` didn't hand a message to any endpoint.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Unhandled {
+    /// No route has the message's address
+    UnknownAddress,
+    /// A route has the address, but the message's arguments can't be decoded into what it takes
+    BadArgs(String),
+    /// The packet isn't OSC, so it has no address to speak of
+    BadPacket,
+}
+
+impl fmt::Display for Unhandled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Unhandled::UnknownAddress => write!(f, "no route has this address"),
+            Unhandled::BadArgs(reason) => write!(f, "bad arguments: {}", reason),
+            Unhandled::BadPacket => write!(f, "not an OSC packet"),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use rosc::{OscMessage, OscPacket, OscType};
+use tracing::trace;
+
+use crate::osc::decode::decode_packet;
+use crate::osc::transport::OscTransport;
+
+/// Drops the changes Reaper reports back to us just after we made them.
+///
+/// Reaper reports every change made over OSC back to where it came from, so a fader moved on the
+/// surface would be moved again by its own echo, and a value being changed quickly could see
+/// older values bounce back in between. Each message sent is remembered for `ttl`, and a received
+/// message with the same address and arguments within that time is dropped, once. Messages in
+/// bundles are dropped one by one, and a bundle left empty isn't received at all.
+pub struct EchoSuppressingTransport {
+    inner: Arc<dyn OscTransport>,
+    ttl: Duration,
+    sent: Mutex<HashMap<String, Sent>>,
+}
+
+// The last values sent to one address
+struct Sent {
+    args: Vec<OscType>,
+    at: Instant,
+}
+
+impl EchoSuppressingTransport {
+    pub fn new(inner: Arc<dyn OscTransport>, ttl: Duration) -> Self {
+        EchoSuppressingTransport {
+            inner,
+            ttl,
+            sent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn remember(&self, packet: &[u8]) {
+        let Ok((_, packet)) = rosc::decoder::decode_udp(packet) else {
+            return;
+        };
+        let now = Instant::now();
+        let mut sent = self.sent.lock().unwrap();
+        sent.retain(|_, sent| now.duration_since(sent.at) < self.ttl);
+        for_each_message(packet, &mut |msg| {
+            sent.insert(
+                msg.addr,
+                Sent {
+                    args: msg.args,
+                    at: now,
+                },
+            );
+        });
+    }
+
+    // Whether `msg` is the echo of something sent, which it then uses up
+    fn is_echo(&self, msg: &OscMessage) -> bool {
+        let mut sent = self.sent.lock().unwrap();
+        let echoed = sent
+            .get(&msg.addr)
+            .is_some_and(|sent| sent.at.elapsed() < self.ttl && sent.args == msg.args);
+        if echoed {
+            sent.remove(&msg.addr);
+        }
+        echoed
+    }
+
+    // The packet without its echoes, or None if that leaves nothing
+    fn without_echoes(&self, packet: OscPacket) -> Option<OscPacket> {
+        match packet {
+            OscPacket::Message(msg) => match self.is_echo(&msg) {
+                true => {
+                    trace!(addr = %msg.addr, "Dropping echo");
+                    None
+                }
+                false => Some(OscPacket::Message(msg)),
+            },
+            OscPacket::Bundle(mut bundle) => {
+                bundle.content = bundle
+                    .content
+                    .into_iter()
+                    .filter_map(|packet| self.without_echoes(packet))
+                    .collect();
+                match bundle.content.is_empty() {
+                    true => None,
+                    false => Some(OscPacket::Bundle(bundle)),
+                }
+            }
+        }
+    }
+}
+
+impl OscTransport for EchoSuppressingTransport {
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        self.remember(packet);
+        self.inner.send(packet)
+    }
+
+    fn send_to(&self, packet: &[u8], to: SocketAddr) -> io::Result<()> {
+        self.remember(packet);
+        self.inner.send_to(packet, to)
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        loop {
+            let (buf, from) = self.inner.recv()?;
+            // Anything unreadable is left for the receive loop to complain about
+            let Ok(packet) = decode_packet(&buf) else {
+                return Ok((buf, from));
+            };
+            let Some(kept) = self.without_echoes(packet.clone()) else {
+                continue;
+            };
+            if kept == packet {
+                return Ok((buf, from));
+            }
+            match rosc::encoder::encode(&kept) {
+                Ok(buf) => return Ok((buf, from)),
+                Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+            }
+        }
+    }
+}
+
+fn for_each_message(packet: OscPacket, f: &mut impl FnMut(OscMessage)) {
+    match packet {
+        OscPacket::Message(msg) => f(msg),
+        OscPacket::Bundle(bundle) => {
+            for packet in bundle.content {
+                for_each_message(packet, f);
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::{error, fmt, io};
+
+/// Why a message couldn't be sent to Reaper.
+#[derive(Debug)]
+pub enum OscError {
+    /// rosc couldn't encode the message; this is a bug on our side rather than a network problem
+    Encode(rosc::OscError),
+    /// The transport failed to send the encoded message
+    Io(io::Error),
+    /// A route parameter can't be spliced into an OSC address, e.g. a GUID containing a '/'
+    AddressFormat(String),
+    /// The endpoint can't be set or queried the way it was asked to, e.g. set dynamically to a
+    /// value of the wrong type
+    Unsupported(String),
+    /// An argument is outside the range or set of values the spec allows it
+    OutOfRange(String),
+}
+
+impl fmt::Display for OscError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OscError::Encode(err) => write!(f, "encoding OSC message: {}", err),
+            OscError::Io(err) => write!(f, "sending OSC message: {}", err),
+            OscError::AddressFormat(segment) => {
+                write!(f, "invalid OSC address segment: {:?}", segment)
+            }
+            OscError::Unsupported(reason) => write!(f, "unsupported: {}", reason),
+            OscError::OutOfRange(reason) => write!(f, "out of range: {}", reason),
+        }
+    }
+}
+
+impl error::Error for OscError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            OscError::Encode(err) => Some(err),
+            OscError::Io(err) => Some(err),
+            OscError::AddressFormat(_) | OscError::Unsupported(_) | OscError::OutOfRange(_) => None,
+        }
+    }
+}
+
+impl From<rosc::OscError> for OscError {
+    fn from(err: rosc::OscError) -> Self {
+        OscError::Encode(err)
+    }
+}
+
+impl From<io::Error> for OscError {
+    fn from(err: io::Error) -> Self {
+        OscError::Io(err)
+    }
+}
+
+/// Checks that a route parameter can stand in for one level of an OSC address.
+///
+/// An empty segment, or one containing a '/', would address a different route.
+pub fn check_address_segment(segment: &str) -> Result<(), OscError> {
+    let is_valid = !segment.is_empty() && !segment.contains('/');
+    match is_valid {
+        true => Ok(()),
+        false => Err(OscError::AddressFormat(segment.to_string())),
+    }
+}
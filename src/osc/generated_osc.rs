@@ -1,34 +1,125 @@
 // AUTO-GENERATED CODE. DO NOT EDIT!
 
-use std::net::UdpSocket;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
 
-use crate::traits::{Bind, Query, Set};
+use crossbeam_channel::{Receiver, bounded};
 
+use crate::osc::transport::OscTransport;
+use crate::traits::{Bind, Query, QueryWithResponse, Set, SetMessage, Subscribe, Subscription};
+
+use crate::osc::decode::{self, Unhandled};
+use crate::osc::error::{OscError, check_address_segment};
+use crate::osc::pattern::{is_pattern, match_address};
 use crate::osc::route_context::ContextTrait;
 
-#[derive(Debug)]
-pub struct OscError;
+/// Called with the first message to arrive on the address a query is waiting on
+type ResponseWaiter = Box<dyn FnOnce(&rosc::OscMessage) + Send>;
+
+/// Queries waiting for a response, by the concrete OSC address the response arrives on
+pub type PendingResponses = Arc<Mutex<HashMap<String, Vec<ResponseWaiter>>>>;
+
+/// A handler bound to one concrete address, parsing the message for the endpoint's own handler
+type BoundHandler = Arc<Mutex<Box<dyn FnMut(&rosc::OscMessage) + Send>>>;
+
+/// Every bound handler and its subscription, by the concrete OSC address it was bound on
+pub type Handlers = Arc<Mutex<HashMap<String, Vec<(Subscription, BoundHandler)>>>>;
 
 #[derive(Debug)]
 pub struct NumTracksArgs {
     pub num_tracks: i32, // number of tracks in the current project
 }
 
-pub type NumTracksHandler = Box<dyn FnMut(NumTracksArgs) + 'static>;
+impl NumTracksArgs {
+    pub fn new(num_tracks: i32) -> Self {
+        Self { num_tracks }
+    }
+}
+
+impl From<i32> for NumTracksArgs {
+    fn from(num_tracks: i32) -> Self {
+        Self::new(num_tracks)
+    }
+}
+
+pub type NumTracksHandler = Box<dyn FnMut(NumTracksArgs) + Send + 'static>;
 
 pub struct NumTracks {
-    socket: Arc<UdpSocket>,
-    handler: Option<NumTracksHandler>,
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+}
+
+impl NumTracks {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/num_tracks";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for NumTracks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
 }
 
 /// /num_tracks
 impl Bind<NumTracksArgs> for NumTracks {
     fn bind<F>(&mut self, callback: F)
     where
-        F: FnMut(NumTracksArgs) + 'static,
+        F: FnMut(NumTracksArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /num_tracks
+impl Subscribe<NumTracksArgs> for NumTracks {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(NumTracksArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/num_tracks");
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl NumTracks {
+    fn add_handler(&mut self, subscription: Subscription, handler: NumTracksHandler) {
+        let osc_address = format!("/num_tracks");
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_num_tracks_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
 }
 
@@ -42,29 +133,141 @@ impl Query for NumTracks {
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+fn parse_num_tracks_args(msg: &rosc::OscMessage) -> Result<NumTracksArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(NumTracksArgs {
+        num_tracks: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /num_tracks
+impl QueryWithResponse<NumTracksArgs> for NumTracks {
+    fn query_response(&self) -> Result<Receiver<NumTracksArgs>, Self::Error> {
+        let osc_address = format!("/num_tracks");
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_num_tracks_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for NumTracks {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
 #[derive(Debug)]
 pub struct TrackAllGuidsArgs {}
 
-pub type TrackAllGuidsHandler = Box<dyn FnMut(TrackAllGuidsArgs) + 'static>;
+pub type TrackAllGuidsHandler = Box<dyn FnMut(TrackAllGuidsArgs) + Send + 'static>;
 
 pub struct TrackAllGuids {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackAllGuidsHandler>,
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+}
+
+impl TrackAllGuids {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/all_guids";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for TrackAllGuids {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
 }
 
 /// /track/all_guids
 impl Bind<TrackAllGuidsArgs> for TrackAllGuids {
     fn bind<F>(&mut self, callback: F)
     where
-        F: FnMut(TrackAllGuidsArgs) + 'static,
+        F: FnMut(TrackAllGuidsArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/all_guids
+impl Subscribe<TrackAllGuidsArgs> for TrackAllGuids {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackAllGuidsArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/track/all_guids");
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackAllGuids {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackAllGuidsHandler) {
+        let osc_address = format!("/track/all_guids");
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_all_guids_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
 }
 
@@ -78,32 +281,161 @@ impl Query for TrackAllGuids {
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+fn parse_track_all_guids_args(_msg: &rosc::OscMessage) -> Result<TrackAllGuidsArgs, String> {
+    Ok(TrackAllGuidsArgs {})
+}
+
+/// /track/all_guids
+impl QueryWithResponse<TrackAllGuidsArgs> for TrackAllGuids {
+    fn query_response(&self) -> Result<Receiver<TrackAllGuidsArgs>, Self::Error> {
+        let osc_address = format!("/track/all_guids");
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_all_guids_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackAllGuids {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
 #[derive(Debug)]
 pub struct TrackIndexArgs {
     pub index: i32, // index of the track in the project according to reaper's mixer view
 }
 
-pub type TrackIndexHandler = Box<dyn FnMut(TrackIndexArgs) + 'static>;
+impl TrackIndexArgs {
+    pub fn new(index: i32) -> Self {
+        Self { index }
+    }
+}
+
+impl From<i32> for TrackIndexArgs {
+    fn from(index: i32) -> Self {
+        Self::new(index)
+    }
+}
+
+pub type TrackIndexHandler = Box<dyn FnMut(TrackIndexArgs) + Send + 'static>;
 
 pub struct TrackIndex {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackIndexHandler>,
-    pub track_guid: String,
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackIndex {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/index";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/index", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
 }
 
 /// /track/{track_guid}/index
 impl Bind<TrackIndexArgs> for TrackIndex {
     fn bind<F>(&mut self, callback: F)
     where
-        F: FnMut(TrackIndexArgs) + 'static,
+        F: FnMut(TrackIndexArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/index
+impl Subscribe<TrackIndexArgs> for TrackIndex {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackIndexArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/index", self.track_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackIndex {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackIndexHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/index", self.track_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_index_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
 }
 
@@ -111,81 +443,292 @@ impl Bind<TrackIndexArgs> for TrackIndex {
 impl Query for TrackIndex {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/index", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+fn parse_track_index_args(msg: &rosc::OscMessage) -> Result<TrackIndexArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(TrackIndexArgs {
+        index: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/index
+impl QueryWithResponse<TrackIndexArgs> for TrackIndex {
+    fn query_response(&self) -> Result<Receiver<TrackIndexArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/index", self.track_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_index_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackIndex {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
 #[derive(Debug)]
 pub struct TrackDeleteArgs {}
 
-pub type TrackDeleteHandler = Box<dyn FnMut(TrackDeleteArgs) + 'static>;
+pub type TrackDeleteHandler = Box<dyn FnMut(TrackDeleteArgs) + Send + 'static>;
 
 pub struct TrackDelete {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackDeleteHandler>,
-    pub track_guid: String,
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackDelete {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/delete";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/delete", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackDelete {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
 }
 
 /// /track/{track_guid}/delete
-impl Set<TrackDeleteArgs> for TrackDelete {
+impl SetMessage<TrackDeleteArgs> for TrackDelete {
     type Error = OscError;
-    fn set(&mut self, args: TrackDeleteArgs) -> Result<(), Self::Error> {
+    fn set_message(&self, args: TrackDeleteArgs) -> Result<rosc::OscMessage, Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/delete", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/delete
+impl Set<TrackDeleteArgs> for TrackDelete {
+    type Error = OscError;
+    fn set(&mut self, args: TrackDeleteArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+fn parse_track_delete_args(_msg: &rosc::OscMessage) -> Result<TrackDeleteArgs, String> {
+    Ok(TrackDeleteArgs {})
+}
+
+impl DynEndpoint for TrackDelete {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, _value: ParamValue) -> Result<(), OscError> {
+        self.set(TrackDeleteArgs {})
+    }
+}
+
 #[derive(Debug)]
 pub struct TrackNameArgs {
-    pub name: String, // name of the track
+    pub name: Vec<u8>, // name of the track
+}
+
+impl TrackNameArgs {
+    pub fn new(name: impl Into<Vec<u8>>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl From<Vec<u8>> for TrackNameArgs {
+    fn from(name: Vec<u8>) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&str> for TrackNameArgs {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
 }
 
-pub type TrackNameHandler = Box<dyn FnMut(TrackNameArgs) + 'static>;
+pub type TrackNameHandler = Box<dyn FnMut(TrackNameArgs) + Send + 'static>;
 
 pub struct TrackName {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackNameHandler>,
-    pub track_guid: String,
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/name", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
 }
 
 /// /track/{track_guid}/name
-impl Set<TrackNameArgs> for TrackName {
+impl SetMessage<TrackNameArgs> for TrackName {
     type Error = OscError;
-    fn set(&mut self, args: TrackNameArgs) -> Result<(), Self::Error> {
+    const BINARY_ARGS: &'static [usize] = &[0];
+    fn set_message(&self, args: TrackNameArgs) -> Result<rosc::OscMessage, Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/name", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
-            args: vec![rosc::OscType::String(args.name.clone())],
+            args: vec![decode::binary_arg(args.name)],
         };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/name
+impl Set<TrackNameArgs> for TrackName {
+    type Error = OscError;
+    fn set(&mut self, args: TrackNameArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = decode::encode_packet(&packet, &[&[0]])?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+impl TrackName {
+    pub fn set_value(&mut self, value: impl Into<TrackNameArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
 /// /track/{track_guid}/name
 impl Bind<TrackNameArgs> for TrackName {
     fn bind<F>(&mut self, callback: F)
     where
-        F: FnMut(TrackNameArgs) + 'static,
+        F: FnMut(TrackNameArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/name
+impl Subscribe<TrackNameArgs> for TrackName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackNameArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/name", self.track_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackName {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackNameHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/name", self.track_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_name_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
 }
 
@@ -193,54 +736,225 @@ impl Bind<TrackNameArgs> for TrackName {
 impl Query for TrackName {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/name", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+fn parse_track_name_args(msg: &rosc::OscMessage) -> Result<TrackNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<Vec<u8>>()]);
+    Ok(TrackNameArgs {
+        name: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/name
+impl QueryWithResponse<TrackNameArgs> for TrackName {
+    fn query_response(&self) -> Result<Receiver<TrackNameArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/name", self.track_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_name_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackName {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let name = match value {
+            ParamValue::String(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes string, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackNameArgs::new(name))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
 #[derive(Debug)]
 pub struct TrackSelectedArgs {
     pub selected: bool, // true means track is selected
 }
 
-pub type TrackSelectedHandler = Box<dyn FnMut(TrackSelectedArgs) + 'static>;
+impl TrackSelectedArgs {
+    pub fn new(selected: bool) -> Self {
+        Self { selected }
+    }
+}
+
+impl From<bool> for TrackSelectedArgs {
+    fn from(selected: bool) -> Self {
+        Self::new(selected)
+    }
+}
+
+pub type TrackSelectedHandler = Box<dyn FnMut(TrackSelectedArgs) + Send + 'static>;
 
 pub struct TrackSelected {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackSelectedHandler>,
-    pub track_guid: String,
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackSelected {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/selected";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/selected", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackSelected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
 }
 
 /// /track/{track_guid}/selected
-impl Set<TrackSelectedArgs> for TrackSelected {
+impl SetMessage<TrackSelectedArgs> for TrackSelected {
     type Error = OscError;
-    fn set(&mut self, args: TrackSelectedArgs) -> Result<(), Self::Error> {
+    fn set_message(&self, args: TrackSelectedArgs) -> Result<rosc::OscMessage, Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/selected", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![rosc::OscType::Bool(args.selected)],
         };
-        let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
-        Ok(())
+        Ok(osc_msg)
     }
 }
 
 /// /track/{track_guid}/selected
-impl Bind<TrackSelectedArgs> for TrackSelected {
-    fn bind<F>(&mut self, callback: F)
+impl Set<TrackSelectedArgs> for TrackSelected {
+    type Error = OscError;
+    fn set(&mut self, args: TrackSelectedArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackSelected {
+    pub fn set_value(&mut self, value: impl Into<TrackSelectedArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /track/{track_guid}/selected
+impl Bind<TrackSelectedArgs> for TrackSelected {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackSelectedArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/selected
+impl Subscribe<TrackSelectedArgs> for TrackSelected {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
     where
-        F: FnMut(TrackSelectedArgs) + 'static,
+        F: FnMut(TrackSelectedArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/selected", self.track_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackSelected {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSelectedHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/selected", self.track_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_selected_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
 }
 
@@ -248,54 +962,227 @@ impl Bind<TrackSelectedArgs> for TrackSelected {
 impl Query for TrackSelected {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/selected", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+fn parse_track_selected_args(msg: &rosc::OscMessage) -> Result<TrackSelectedArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackSelectedArgs {
+        selected: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/selected
+impl QueryWithResponse<TrackSelectedArgs> for TrackSelected {
+    fn query_response(&self) -> Result<Receiver<TrackSelectedArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/selected", self.track_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_selected_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackSelected {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let selected = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackSelectedArgs::new(selected))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
 #[derive(Debug)]
 pub struct TrackVolumeArgs {
     pub volume: f32, // volume of the track, normalized to 0 to 1.0
 }
 
-pub type TrackVolumeHandler = Box<dyn FnMut(TrackVolumeArgs) + 'static>;
+impl TrackVolumeArgs {
+    pub fn new(volume: f32) -> Self {
+        Self { volume }
+    }
+}
+
+impl From<f32> for TrackVolumeArgs {
+    fn from(volume: f32) -> Self {
+        Self::new(volume)
+    }
+}
+
+pub type TrackVolumeHandler = Box<dyn FnMut(TrackVolumeArgs) + Send + 'static>;
 
 pub struct TrackVolume {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackVolumeHandler>,
-    pub track_guid: String,
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackVolume {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/volume";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/volume", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackVolume {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
 }
 
 /// /track/{track_guid}/volume
-impl Set<TrackVolumeArgs> for TrackVolume {
+impl SetMessage<TrackVolumeArgs> for TrackVolume {
     type Error = OscError;
-    fn set(&mut self, args: TrackVolumeArgs) -> Result<(), Self::Error> {
+    fn set_message(&self, args: TrackVolumeArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let mut args = args;
+        args.volume = crate::osc::range::clamp(args.volume, Some(0.0), Some(1.0));
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/volume", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![rosc::OscType::Float(args.volume)],
         };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/volume
+impl Set<TrackVolumeArgs> for TrackVolume {
+    type Error = OscError;
+    fn set(&mut self, args: TrackVolumeArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+impl TrackVolume {
+    pub fn set_value(&mut self, value: impl Into<TrackVolumeArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
 /// /track/{track_guid}/volume
 impl Bind<TrackVolumeArgs> for TrackVolume {
     fn bind<F>(&mut self, callback: F)
     where
-        F: FnMut(TrackVolumeArgs) + 'static,
+        F: FnMut(TrackVolumeArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/volume
+impl Subscribe<TrackVolumeArgs> for TrackVolume {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackVolumeArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/volume", self.track_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackVolume {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackVolumeHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/volume", self.track_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_volume_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
 }
 
@@ -303,54 +1190,230 @@ impl Bind<TrackVolumeArgs> for TrackVolume {
 impl Query for TrackVolume {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/volume", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+fn parse_track_volume_args(msg: &rosc::OscMessage) -> Result<TrackVolumeArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = TrackVolumeArgs {
+        volume: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("volume", args.volume, Some(0.0), Some(1.0))?;
+    Ok(args)
+}
+
+/// /track/{track_guid}/volume
+impl QueryWithResponse<TrackVolumeArgs> for TrackVolume {
+    fn query_response(&self) -> Result<Receiver<TrackVolumeArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/volume", self.track_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_volume_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackVolume {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let volume = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackVolumeArgs::new(volume))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
 #[derive(Debug)]
 pub struct TrackPanArgs {
     pub pan: f32, // pan of the track, normalized to -1.0 to 1.0
 }
 
-pub type TrackPanHandler = Box<dyn FnMut(TrackPanArgs) + 'static>;
+impl TrackPanArgs {
+    pub fn new(pan: f32) -> Self {
+        Self { pan }
+    }
+}
+
+impl From<f32> for TrackPanArgs {
+    fn from(pan: f32) -> Self {
+        Self::new(pan)
+    }
+}
+
+pub type TrackPanHandler = Box<dyn FnMut(TrackPanArgs) + Send + 'static>;
 
 pub struct TrackPan {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackPanHandler>,
-    pub track_guid: String,
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackPan {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/pan";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/pan", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackPan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
 }
 
 /// /track/{track_guid}/pan
-impl Set<TrackPanArgs> for TrackPan {
+impl SetMessage<TrackPanArgs> for TrackPan {
     type Error = OscError;
-    fn set(&mut self, args: TrackPanArgs) -> Result<(), Self::Error> {
+    fn set_message(&self, args: TrackPanArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let mut args = args;
+        args.pan = crate::osc::range::clamp(args.pan, Some(-1.0), Some(1.0));
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/pan", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![rosc::OscType::Float(args.pan)],
         };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/pan
+impl Set<TrackPanArgs> for TrackPan {
+    type Error = OscError;
+    fn set(&mut self, args: TrackPanArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+impl TrackPan {
+    pub fn set_value(&mut self, value: impl Into<TrackPanArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
 /// /track/{track_guid}/pan
 impl Bind<TrackPanArgs> for TrackPan {
     fn bind<F>(&mut self, callback: F)
     where
-        F: FnMut(TrackPanArgs) + 'static,
+        F: FnMut(TrackPanArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/pan
+impl Subscribe<TrackPanArgs> for TrackPan {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackPanArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/pan", self.track_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackPan {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackPanHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/pan", self.track_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_pan_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
 }
 
@@ -358,54 +1421,228 @@ impl Bind<TrackPanArgs> for TrackPan {
 impl Query for TrackPan {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/pan", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+fn parse_track_pan_args(msg: &rosc::OscMessage) -> Result<TrackPanArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = TrackPanArgs {
+        pan: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("pan", args.pan, Some(-1.0), Some(1.0))?;
+    Ok(args)
+}
+
+/// /track/{track_guid}/pan
+impl QueryWithResponse<TrackPanArgs> for TrackPan {
+    fn query_response(&self) -> Result<Receiver<TrackPanArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/pan", self.track_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_pan_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackPan {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let pan = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackPanArgs::new(pan))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
 #[derive(Debug)]
 pub struct TrackMuteArgs {
     pub mute: bool, // true means track is muted
 }
 
-pub type TrackMuteHandler = Box<dyn FnMut(TrackMuteArgs) + 'static>;
+impl TrackMuteArgs {
+    pub fn new(mute: bool) -> Self {
+        Self { mute }
+    }
+}
+
+impl From<bool> for TrackMuteArgs {
+    fn from(mute: bool) -> Self {
+        Self::new(mute)
+    }
+}
+
+pub type TrackMuteHandler = Box<dyn FnMut(TrackMuteArgs) + Send + 'static>;
 
 pub struct TrackMute {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackMuteHandler>,
-    pub track_guid: String,
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackMute {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/mute";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/mute", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackMute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
 }
 
 /// /track/{track_guid}/mute
-impl Set<TrackMuteArgs> for TrackMute {
+impl SetMessage<TrackMuteArgs> for TrackMute {
     type Error = OscError;
-    fn set(&mut self, args: TrackMuteArgs) -> Result<(), Self::Error> {
+    fn set_message(&self, args: TrackMuteArgs) -> Result<rosc::OscMessage, Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/mute", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![rosc::OscType::Bool(args.mute)],
         };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/mute
+impl Set<TrackMuteArgs> for TrackMute {
+    type Error = OscError;
+    fn set(&mut self, args: TrackMuteArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+impl TrackMute {
+    pub fn set_value(&mut self, value: impl Into<TrackMuteArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
 /// /track/{track_guid}/mute
 impl Bind<TrackMuteArgs> for TrackMute {
     fn bind<F>(&mut self, callback: F)
     where
-        F: FnMut(TrackMuteArgs) + 'static,
+        F: FnMut(TrackMuteArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/mute
+impl Subscribe<TrackMuteArgs> for TrackMute {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackMuteArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/mute", self.track_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackMute {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackMuteHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/mute", self.track_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_mute_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
 }
 
@@ -413,44 +1650,162 @@ impl Bind<TrackMuteArgs> for TrackMute {
 impl Query for TrackMute {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/mute", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+fn parse_track_mute_args(msg: &rosc::OscMessage) -> Result<TrackMuteArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackMuteArgs {
+        mute: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/mute
+impl QueryWithResponse<TrackMuteArgs> for TrackMute {
+    fn query_response(&self) -> Result<Receiver<TrackMuteArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/mute", self.track_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_mute_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackMute {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let mute = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackMuteArgs::new(mute))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
 #[derive(Debug)]
 pub struct TrackSoloArgs {
     pub solo: bool, // true means track is soloed
 }
 
-pub type TrackSoloHandler = Box<dyn FnMut(TrackSoloArgs) + 'static>;
+impl TrackSoloArgs {
+    pub fn new(solo: bool) -> Self {
+        Self { solo }
+    }
+}
+
+impl From<bool> for TrackSoloArgs {
+    fn from(solo: bool) -> Self {
+        Self::new(solo)
+    }
+}
+
+pub type TrackSoloHandler = Box<dyn FnMut(TrackSoloArgs) + Send + 'static>;
 
 pub struct TrackSolo {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackSoloHandler>,
-    pub track_guid: String,
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackSolo {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/solo";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/solo", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackSolo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
 }
 
 /// /track/{track_guid}/solo
-impl Set<TrackSoloArgs> for TrackSolo {
+impl SetMessage<TrackSoloArgs> for TrackSolo {
     type Error = OscError;
-    fn set(&mut self, args: TrackSoloArgs) -> Result<(), Self::Error> {
+    fn set_message(&self, args: TrackSoloArgs) -> Result<rosc::OscMessage, Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/solo", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![rosc::OscType::Bool(args.solo)],
         };
-        let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
-        Ok(())
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/solo
+impl Set<TrackSoloArgs> for TrackSolo {
+    type Error = OscError;
+    fn set(&mut self, args: TrackSoloArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackSolo {
+    pub fn set_value(&mut self, value: impl Into<TrackSoloArgs>) -> Result<(), OscError> {
+        self.set(value.into())
     }
 }
 
@@ -458,9 +1813,62 @@ impl Set<TrackSoloArgs> for TrackSolo {
 impl Bind<TrackSoloArgs> for TrackSolo {
     fn bind<F>(&mut self, callback: F)
     where
-        F: FnMut(TrackSoloArgs) + 'static,
+        F: FnMut(TrackSoloArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/solo
+impl Subscribe<TrackSoloArgs> for TrackSolo {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackSoloArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/solo", self.track_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackSolo {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSoloHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/solo", self.track_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_solo_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
 }
 
@@ -468,54 +1876,225 @@ impl Bind<TrackSoloArgs> for TrackSolo {
 impl Query for TrackSolo {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/solo", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+fn parse_track_solo_args(msg: &rosc::OscMessage) -> Result<TrackSoloArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackSoloArgs {
+        solo: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/solo
+impl QueryWithResponse<TrackSoloArgs> for TrackSolo {
+    fn query_response(&self) -> Result<Receiver<TrackSoloArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/solo", self.track_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_solo_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackSolo {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let solo = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackSoloArgs::new(solo))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
 #[derive(Debug)]
 pub struct TrackRecArmArgs {
     pub rec_arm: bool, // true means track is armed for recording
 }
 
-pub type TrackRecArmHandler = Box<dyn FnMut(TrackRecArmArgs) + 'static>;
+impl TrackRecArmArgs {
+    pub fn new(rec_arm: bool) -> Self {
+        Self { rec_arm }
+    }
+}
+
+impl From<bool> for TrackRecArmArgs {
+    fn from(rec_arm: bool) -> Self {
+        Self::new(rec_arm)
+    }
+}
+
+pub type TrackRecArmHandler = Box<dyn FnMut(TrackRecArmArgs) + Send + 'static>;
 
 pub struct TrackRecArm {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackRecArmHandler>,
-    pub track_guid: String,
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackRecArm {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/rec-arm";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/rec-arm", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackRecArm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
 }
 
 /// /track/{track_guid}/rec-arm
-impl Set<TrackRecArmArgs> for TrackRecArm {
+impl SetMessage<TrackRecArmArgs> for TrackRecArm {
     type Error = OscError;
-    fn set(&mut self, args: TrackRecArmArgs) -> Result<(), Self::Error> {
+    fn set_message(&self, args: TrackRecArmArgs) -> Result<rosc::OscMessage, Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/rec-arm", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![rosc::OscType::Bool(args.rec_arm)],
         };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/rec-arm
+impl Set<TrackRecArmArgs> for TrackRecArm {
+    type Error = OscError;
+    fn set(&mut self, args: TrackRecArmArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
+impl TrackRecArm {
+    pub fn set_value(&mut self, value: impl Into<TrackRecArmArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
 /// /track/{track_guid}/rec-arm
 impl Bind<TrackRecArmArgs> for TrackRecArm {
     fn bind<F>(&mut self, callback: F)
     where
-        F: FnMut(TrackRecArmArgs) + 'static,
+        F: FnMut(TrackRecArmArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/rec-arm
+impl Subscribe<TrackRecArmArgs> for TrackRecArm {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackRecArmArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/rec-arm", self.track_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackRecArm {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackRecArmHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/rec-arm", self.track_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_rec_arm_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
 }
 
@@ -523,1160 +2102,9801 @@ impl Bind<TrackRecArmArgs> for TrackRecArm {
 impl Query for TrackRecArm {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
         let osc_address = format!("/track/{}/rec-arm", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
-#[derive(Debug)]
-pub struct TrackSendGuidArgs {
-    pub guid: String, // unique identifier for the send
+fn parse_track_rec_arm_args(msg: &rosc::OscMessage) -> Result<TrackRecArmArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackRecArmArgs {
+        rec_arm: decode::required(&osc_args, 0)?,
+    })
 }
 
-pub type TrackSendGuidHandler = Box<dyn FnMut(TrackSendGuidArgs) + 'static>;
-
-pub struct TrackSendGuid {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackSendGuidHandler>,
-    pub track_guid: String,
-    pub send_index: i32,
+/// /track/{track_guid}/rec-arm
+impl QueryWithResponse<TrackRecArmArgs> for TrackRecArm {
+    fn query_response(&self) -> Result<Receiver<TrackRecArmArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/rec-arm", self.track_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_rec_arm_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
 }
 
-/// /track/{track_guid}/send/{send_index}/guid
-impl Bind<TrackSendGuidArgs> for TrackSendGuid {
-    fn bind<F>(&mut self, callback: F)
-    where
-        F: FnMut(TrackSendGuidArgs) + 'static,
-    {
-        self.handler = Some(Box::new(callback));
+impl DynEndpoint for TrackRecArm {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
     }
-}
 
-/// /track/{track_guid}/send/{send_index}/guid
-impl Query for TrackSendGuid {
-    type Error = OscError;
-    fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/track/{}/send/{}/guid", self.track_guid, self.send_index);
-        let osc_msg = rosc::OscMessage {
-            addr: osc_address,
-            args: vec![],
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let rec_arm = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
         };
-        let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
-        Ok(())
+        self.set(TrackRecArmArgs::new(rec_arm))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
     }
 }
 
 #[derive(Debug)]
-pub struct TrackSendVolumeArgs {
-    pub volume: f32, // volume of the send, normalized to 0 to 1.
+pub struct TrackWidthArgs {
+    pub width: f32, // stereo width of the track, normalized to -1.0 to 1.0
 }
 
-pub type TrackSendVolumeHandler = Box<dyn FnMut(TrackSendVolumeArgs) + 'static>;
+impl TrackWidthArgs {
+    pub fn new(width: f32) -> Self {
+        Self { width }
+    }
+}
 
-pub struct TrackSendVolume {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackSendVolumeHandler>,
-    pub track_guid: String,
-    pub send_index: i32,
+impl From<f32> for TrackWidthArgs {
+    fn from(width: f32) -> Self {
+        Self::new(width)
+    }
 }
 
-/// /track/{track_guid}/send/{send_index}/volume
-impl Set<TrackSendVolumeArgs> for TrackSendVolume {
+pub type TrackWidthHandler = Box<dyn FnMut(TrackWidthArgs) + Send + 'static>;
+
+pub struct TrackWidth {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackWidth {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/width";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/width", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/width
+impl SetMessage<TrackWidthArgs> for TrackWidth {
     type Error = OscError;
-    fn set(&mut self, args: TrackSendVolumeArgs) -> Result<(), Self::Error> {
-        let osc_address = format!("/track/{}/send/{}/volume", self.track_guid, self.send_index);
+    fn set_message(&self, args: TrackWidthArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let mut args = args;
+        args.width = crate::osc::range::clamp(args.width, Some(-1.0), Some(1.0));
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/width", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
-            args: vec![rosc::OscType::Float(args.volume)],
+            args: vec![rosc::OscType::Float(args.width)],
         };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/width
+impl Set<TrackWidthArgs> for TrackWidth {
+    type Error = OscError;
+    fn set(&mut self, args: TrackWidthArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
-/// /track/{track_guid}/send/{send_index}/volume
-impl Bind<TrackSendVolumeArgs> for TrackSendVolume {
+impl TrackWidth {
+    pub fn set_value(&mut self, value: impl Into<TrackWidthArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /track/{track_guid}/width
+impl Bind<TrackWidthArgs> for TrackWidth {
     fn bind<F>(&mut self, callback: F)
     where
-        F: FnMut(TrackSendVolumeArgs) + 'static,
+        F: FnMut(TrackWidthArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        self.add_handler(Subscription::new(), Box::new(callback));
     }
 }
 
-/// /track/{track_guid}/send/{send_index}/volume
-impl Query for TrackSendVolume {
+/// /track/{track_guid}/width
+impl Subscribe<TrackWidthArgs> for TrackWidth {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackWidthArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/width", self.track_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackWidth {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackWidthHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/width", self.track_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_width_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/width
+impl Query for TrackWidth {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/track/{}/send/{}/volume", self.track_guid, self.send_index);
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/width", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
-#[derive(Debug)]
-pub struct TrackSendPanArgs {
-    pub pan: f32, // pan of the send, normalized to -1.0 to 1.0
+fn parse_track_width_args(msg: &rosc::OscMessage) -> Result<TrackWidthArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = TrackWidthArgs {
+        width: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("width", args.width, Some(-1.0), Some(1.0))?;
+    Ok(args)
 }
 
-pub type TrackSendPanHandler = Box<dyn FnMut(TrackSendPanArgs) + 'static>;
-
-pub struct TrackSendPan {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackSendPanHandler>,
-    pub track_guid: String,
-    pub send_index: i32,
+/// /track/{track_guid}/width
+impl QueryWithResponse<TrackWidthArgs> for TrackWidth {
+    fn query_response(&self) -> Result<Receiver<TrackWidthArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/width", self.track_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_width_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
 }
 
-/// /track/{track_guid}/send/{send_index}/pan
-impl Set<TrackSendPanArgs> for TrackSendPan {
-    type Error = OscError;
-    fn set(&mut self, args: TrackSendPanArgs) -> Result<(), Self::Error> {
-        let osc_address = format!("/track/{}/send/{}/pan", self.track_guid, self.send_index);
-        let osc_msg = rosc::OscMessage {
-            addr: osc_address,
-            args: vec![rosc::OscType::Float(args.pan)],
+impl DynEndpoint for TrackWidth {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let width = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
         };
-        let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
-        Ok(())
+        self.set(TrackWidthArgs::new(width))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
     }
 }
 
-/// /track/{track_guid}/send/{send_index}/pan
-impl Bind<TrackSendPanArgs> for TrackSendPan {
-    fn bind<F>(&mut self, callback: F)
-    where
-        F: FnMut(TrackSendPanArgs) + 'static,
-    {
-        self.handler = Some(Box::new(callback));
+#[derive(Debug)]
+pub struct TrackPhaseArgs {
+    pub phase: bool, // true means the track's polarity is inverted
+}
+
+impl TrackPhaseArgs {
+    pub fn new(phase: bool) -> Self {
+        Self { phase }
     }
 }
 
-/// /track/{track_guid}/send/{send_index}/pan
-impl Query for TrackSendPan {
-    type Error = OscError;
-    fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/track/{}/send/{}/pan", self.track_guid, self.send_index);
-        let osc_msg = rosc::OscMessage {
-            addr: osc_address,
-            args: vec![],
-        };
-        let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
-        Ok(())
+impl From<bool> for TrackPhaseArgs {
+    fn from(phase: bool) -> Self {
+        Self::new(phase)
     }
 }
 
-#[derive(Debug)]
-pub struct TrackColorArgs {
-    pub color: i32, // color of the track, represented as an RGB integer
+pub type TrackPhaseHandler = Box<dyn FnMut(TrackPhaseArgs) + Send + 'static>;
+
+pub struct TrackPhase {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
 }
 
-pub type TrackColorHandler = Box<dyn FnMut(TrackColorArgs) + 'static>;
+impl TrackPhase {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/phase";
 
-pub struct TrackColor {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackColorHandler>,
-    pub track_guid: String,
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/phase", self.track_guid)
+    }
 }
 
-/// /track/{track_guid}/color
-impl Set<TrackColorArgs> for TrackColor {
+impl std::fmt::Display for TrackPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/phase
+impl SetMessage<TrackPhaseArgs> for TrackPhase {
     type Error = OscError;
-    fn set(&mut self, args: TrackColorArgs) -> Result<(), Self::Error> {
-        let osc_address = format!("/track/{}/color", self.track_guid);
+    fn set_message(&self, args: TrackPhaseArgs) -> Result<rosc::OscMessage, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/phase", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
-            args: vec![rosc::OscType::Int(args.color)],
+            args: vec![rosc::OscType::Bool(args.phase)],
         };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/phase
+impl Set<TrackPhaseArgs> for TrackPhase {
+    type Error = OscError;
+    fn set(&mut self, args: TrackPhaseArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
-/// /track/{track_guid}/color
-impl Bind<TrackColorArgs> for TrackColor {
+impl TrackPhase {
+    pub fn set_value(&mut self, value: impl Into<TrackPhaseArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /track/{track_guid}/phase
+impl Bind<TrackPhaseArgs> for TrackPhase {
     fn bind<F>(&mut self, callback: F)
     where
-        F: FnMut(TrackColorArgs) + 'static,
+        F: FnMut(TrackPhaseArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        self.add_handler(Subscription::new(), Box::new(callback));
     }
 }
 
-/// /track/{track_guid}/color
-impl Query for TrackColor {
-    type Error = OscError;
-    fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/track/{}/color", self.track_guid);
-        let osc_msg = rosc::OscMessage {
-            addr: osc_address,
-            args: vec![],
-        };
-        let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
-        Ok(())
+/// /track/{track_guid}/phase
+impl Subscribe<TrackPhaseArgs> for TrackPhase {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackPhaseArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
     }
-}
-
-#[derive(Debug)]
-pub struct TrackFxGuidArgs {
-    pub guid: String, // unique identifier for the FX
-}
 
-pub type TrackFxGuidHandler = Box<dyn FnMut(TrackFxGuidArgs) + 'static>;
-
-pub struct TrackFxGuid {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackFxGuidHandler>,
-    pub track_guid: String,
-    pub fx_idx: i32,
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/phase", self.track_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/guid
-impl Bind<TrackFxGuidArgs> for TrackFxGuid {
-    fn bind<F>(&mut self, callback: F)
-    where
-        F: FnMut(TrackFxGuidArgs) + 'static,
-    {
-        self.handler = Some(Box::new(callback));
+impl TrackPhase {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackPhaseHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/phase", self.track_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_phase_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/guid
-impl Query for TrackFxGuid {
+/// /track/{track_guid}/phase
+impl Query for TrackPhase {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/track/{}/fx/{}/guid", self.track_guid, self.fx_idx);
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/phase", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
-#[derive(Debug)]
-pub struct TrackFxNameArgs {
-    pub name: String, // name of the FX
+fn parse_track_phase_args(msg: &rosc::OscMessage) -> Result<TrackPhaseArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackPhaseArgs {
+        phase: decode::required(&osc_args, 0)?,
+    })
 }
 
-pub type TrackFxNameHandler = Box<dyn FnMut(TrackFxNameArgs) + 'static>;
-
-pub struct TrackFxName {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackFxNameHandler>,
-    pub track_guid: String,
-    pub fx_idx: i32,
+/// /track/{track_guid}/phase
+impl QueryWithResponse<TrackPhaseArgs> for TrackPhase {
+    fn query_response(&self) -> Result<Receiver<TrackPhaseArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/phase", self.track_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_phase_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/name
-impl Bind<TrackFxNameArgs> for TrackFxName {
-    fn bind<F>(&mut self, callback: F)
-    where
-        F: FnMut(TrackFxNameArgs) + 'static,
-    {
-        self.handler = Some(Box::new(callback));
+impl DynEndpoint for TrackPhase {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
     }
-}
 
-/// /track/{track_guid}/fx/{fx_idx}/name
-impl Query for TrackFxName {
-    type Error = OscError;
-    fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/track/{}/fx/{}/name", self.track_guid, self.fx_idx);
-        let osc_msg = rosc::OscMessage {
-            addr: osc_address,
-            args: vec![],
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let phase = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
         };
-        let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
-        Ok(())
+        self.set(TrackPhaseArgs::new(phase))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
     }
 }
 
 #[derive(Debug)]
-pub struct TrackFxEnabledArgs {
-    pub enabled: bool, // true if the FX is enabled
+pub struct TrackInputGainArgs {
+    pub input_gain: f32, // gain applied to the track's input, normalized to 0 to 1.0
 }
 
-pub type TrackFxEnabledHandler = Box<dyn FnMut(TrackFxEnabledArgs) + 'static>;
+impl TrackInputGainArgs {
+    pub fn new(input_gain: f32) -> Self {
+        Self { input_gain }
+    }
+}
 
-pub struct TrackFxEnabled {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackFxEnabledHandler>,
-    pub track_guid: String,
-    pub fx_idx: i32,
+impl From<f32> for TrackInputGainArgs {
+    fn from(input_gain: f32) -> Self {
+        Self::new(input_gain)
+    }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/enabled
-impl Set<TrackFxEnabledArgs> for TrackFxEnabled {
-    type Error = OscError;
-    fn set(&mut self, args: TrackFxEnabledArgs) -> Result<(), Self::Error> {
-        let osc_address = format!("/track/{}/fx/{}/enabled", self.track_guid, self.fx_idx);
-        let osc_msg = rosc::OscMessage {
-            addr: osc_address,
-            args: vec![rosc::OscType::Bool(args.enabled)],
-        };
-        let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
-        Ok(())
+pub type TrackInputGainHandler = Box<dyn FnMut(TrackInputGainArgs) + Send + 'static>;
+
+pub struct TrackInputGain {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackInputGain {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/input-gain";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/input-gain", self.track_guid)
     }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/enabled
-impl Bind<TrackFxEnabledArgs> for TrackFxEnabled {
-    fn bind<F>(&mut self, callback: F)
-    where
-        F: FnMut(TrackFxEnabledArgs) + 'static,
-    {
-        self.handler = Some(Box::new(callback));
+impl std::fmt::Display for TrackInputGain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
     }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/enabled
-impl Query for TrackFxEnabled {
+/// /track/{track_guid}/input-gain
+impl SetMessage<TrackInputGainArgs> for TrackInputGain {
     type Error = OscError;
-    fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/track/{}/fx/{}/enabled", self.track_guid, self.fx_idx);
+    fn set_message(&self, args: TrackInputGainArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let mut args = args;
+        args.input_gain = crate::osc::range::clamp(args.input_gain, Some(0.0), Some(1.0));
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/input-gain", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
-            args: vec![],
+            args: vec![rosc::OscType::Float(args.input_gain)],
         };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/input-gain
+impl Set<TrackInputGainArgs> for TrackInputGain {
+    type Error = OscError;
+    fn set(&mut self, args: TrackInputGainArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
-#[derive(Debug)]
-pub struct TrackFxParamCountArgs {
-    pub param_count: i32, // number of parameters for the FX
+impl TrackInputGain {
+    pub fn set_value(&mut self, value: impl Into<TrackInputGainArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
 }
 
-pub type TrackFxParamCountHandler = Box<dyn FnMut(TrackFxParamCountArgs) + 'static>;
-
-pub struct TrackFxParamCount {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackFxParamCountHandler>,
-    pub track_guid: String,
-    pub fx_idx: i32,
+/// /track/{track_guid}/input-gain
+impl Bind<TrackInputGainArgs> for TrackInputGain {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackInputGainArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/param_count
-impl Bind<TrackFxParamCountArgs> for TrackFxParamCount {
-    fn bind<F>(&mut self, callback: F)
+/// /track/{track_guid}/input-gain
+impl Subscribe<TrackInputGainArgs> for TrackInputGain {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
     where
-        F: FnMut(TrackFxParamCountArgs) + 'static,
+        F: FnMut(TrackInputGainArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/input-gain", self.track_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
     }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/param_count
-impl Query for TrackFxParamCount {
+impl TrackInputGain {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackInputGainHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/input-gain", self.track_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_input_gain_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/input-gain
+impl Query for TrackInputGain {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/track/{}/fx/{}/param_count", self.track_guid, self.fx_idx);
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/input-gain", self.track_guid);
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
-#[derive(Debug)]
-pub struct TrackFxParamNameArgs {
-    pub param_name: String, // name of the parameter
+fn parse_track_input_gain_args(msg: &rosc::OscMessage) -> Result<TrackInputGainArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = TrackInputGainArgs {
+        input_gain: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("input_gain", args.input_gain, Some(0.0), Some(1.0))?;
+    Ok(args)
 }
 
-pub type TrackFxParamNameHandler = Box<dyn FnMut(TrackFxParamNameArgs) + 'static>;
-
-pub struct TrackFxParamName {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackFxParamNameHandler>,
-    pub track_guid: String,
-    pub fx_idx: i32,
-    pub param_idx: i32,
+/// /track/{track_guid}/input-gain
+impl QueryWithResponse<TrackInputGainArgs> for TrackInputGain {
+    fn query_response(&self) -> Result<Receiver<TrackInputGainArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/input-gain", self.track_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_input_gain_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name
-impl Bind<TrackFxParamNameArgs> for TrackFxParamName {
-    fn bind<F>(&mut self, callback: F)
-    where
-        F: FnMut(TrackFxParamNameArgs) + 'static,
-    {
-        self.handler = Some(Box::new(callback));
+impl DynEndpoint for TrackInputGain {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
     }
-}
 
-/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name
-impl Query for TrackFxParamName {
-    type Error = OscError;
-    fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!(
-            "/track/{}/fx/{}/param/{}/name",
-            self.track_guid, self.fx_idx, self.param_idx
-        );
-        let osc_msg = rosc::OscMessage {
-            addr: osc_address,
-            args: vec![],
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let input_gain = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
         };
-        let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
-        Ok(())
+        self.set(TrackInputGainArgs::new(input_gain))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
     }
 }
 
 #[derive(Debug)]
-pub struct TrackFxParamValueArgs {
-    pub value: f32, // value of the parameter
+pub struct MasterVolumeArgs {
+    pub volume: f32, // volume of the master track, normalized to 0 to 1.0
 }
 
-pub type TrackFxParamValueHandler = Box<dyn FnMut(TrackFxParamValueArgs) + 'static>;
+impl MasterVolumeArgs {
+    pub fn new(volume: f32) -> Self {
+        Self { volume }
+    }
+}
 
-pub struct TrackFxParamValue {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackFxParamValueHandler>,
-    pub track_guid: String,
-    pub fx_idx: i32,
-    pub param_idx: i32,
+impl From<f32> for MasterVolumeArgs {
+    fn from(volume: f32) -> Self {
+        Self::new(volume)
+    }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
-impl Set<TrackFxParamValueArgs> for TrackFxParamValue {
-    type Error = OscError;
-    fn set(&mut self, args: TrackFxParamValueArgs) -> Result<(), Self::Error> {
-        let osc_address = format!(
-            "/track/{}/fx/{}/param/{}/value",
-            self.track_guid, self.fx_idx, self.param_idx
-        );
-        let osc_msg = rosc::OscMessage {
-            addr: osc_address,
-            args: vec![rosc::OscType::Float(args.value)],
-        };
-        let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
-        Ok(())
+pub type MasterVolumeHandler = Box<dyn FnMut(MasterVolumeArgs) + Send + 'static>;
+
+pub struct MasterVolume {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+}
+
+impl MasterVolume {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/master/volume";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
     }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
-impl Bind<TrackFxParamValueArgs> for TrackFxParamValue {
-    fn bind<F>(&mut self, callback: F)
-    where
-        F: FnMut(TrackFxParamValueArgs) + 'static,
-    {
-        self.handler = Some(Box::new(callback));
+impl std::fmt::Display for MasterVolume {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
     }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
-impl Query for TrackFxParamValue {
+/// /master/volume
+impl SetMessage<MasterVolumeArgs> for MasterVolume {
     type Error = OscError;
-    fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!(
-            "/track/{}/fx/{}/param/{}/value",
-            self.track_guid, self.fx_idx, self.param_idx
-        );
+    fn set_message(&self, args: MasterVolumeArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let mut args = args;
+        args.volume = crate::osc::range::clamp(args.volume, Some(0.0), Some(1.0));
+        let osc_address = format!("/master/volume");
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
-            args: vec![],
+            args: vec![rosc::OscType::Float(args.volume)],
         };
+        Ok(osc_msg)
+    }
+}
+
+/// /master/volume
+impl Set<MasterVolumeArgs> for MasterVolume {
+    type Error = OscError;
+    fn set(&mut self, args: MasterVolumeArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
-#[derive(Debug)]
-pub struct TrackFxParamMinArgs {
-    pub min: f32, // minimum value of the parameter
+impl MasterVolume {
+    pub fn set_value(&mut self, value: impl Into<MasterVolumeArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
 }
 
-pub type TrackFxParamMinHandler = Box<dyn FnMut(TrackFxParamMinArgs) + 'static>;
-
-pub struct TrackFxParamMin {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackFxParamMinHandler>,
-    pub track_guid: String,
-    pub fx_idx: i32,
-    pub param_idx: i32,
+/// /master/volume
+impl Bind<MasterVolumeArgs> for MasterVolume {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(MasterVolumeArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min
-impl Bind<TrackFxParamMinArgs> for TrackFxParamMin {
-    fn bind<F>(&mut self, callback: F)
+/// /master/volume
+impl Subscribe<MasterVolumeArgs> for MasterVolume {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
     where
-        F: FnMut(TrackFxParamMinArgs) + 'static,
+        F: FnMut(MasterVolumeArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/master/volume");
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
     }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min
-impl Query for TrackFxParamMin {
+impl MasterVolume {
+    fn add_handler(&mut self, subscription: Subscription, handler: MasterVolumeHandler) {
+        let osc_address = format!("/master/volume");
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_master_volume_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /master/volume
+impl Query for MasterVolume {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!(
-            "/track/{}/fx/{}/param/{}/min",
-            self.track_guid, self.fx_idx, self.param_idx
-        );
+        let osc_address = format!("/master/volume");
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
-#[derive(Debug)]
-pub struct TrackFxParamMaxArgs {
-    pub max: f32, // maximum value of the parameter
+fn parse_master_volume_args(msg: &rosc::OscMessage) -> Result<MasterVolumeArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = MasterVolumeArgs {
+        volume: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("volume", args.volume, Some(0.0), Some(1.0))?;
+    Ok(args)
 }
 
-pub type TrackFxParamMaxHandler = Box<dyn FnMut(TrackFxParamMaxArgs) + 'static>;
-
-pub struct TrackFxParamMax {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackFxParamMaxHandler>,
-    pub track_guid: String,
-    pub fx_idx: i32,
-    pub param_idx: i32,
+/// /master/volume
+impl QueryWithResponse<MasterVolumeArgs> for MasterVolume {
+    fn query_response(&self) -> Result<Receiver<MasterVolumeArgs>, Self::Error> {
+        let osc_address = format!("/master/volume");
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_master_volume_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
 }
 
-/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max
-impl Bind<TrackFxParamMaxArgs> for TrackFxParamMax {
-    fn bind<F>(&mut self, callback: F)
-    where
-        F: FnMut(TrackFxParamMaxArgs) + 'static,
-    {
-        self.handler = Some(Box::new(callback));
+impl DynEndpoint for MasterVolume {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
     }
-}
 
-/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max
-impl Query for TrackFxParamMax {
-    type Error = OscError;
-    fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!(
-            "/track/{}/fx/{}/param/{}/max",
-            self.track_guid, self.fx_idx, self.param_idx
-        );
-        let osc_msg = rosc::OscMessage {
-            addr: osc_address,
-            args: vec![],
-        };
-        let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
-        Ok(())
+    fn address(&self) -> String {
+        self.osc_address()
     }
-}
 
-#[derive(Debug)]
-pub struct TrackFxInfoArgs {}
+    fn is_settable(&self) -> bool {
+        true
+    }
 
-pub type TrackFxInfoHandler = Box<dyn FnMut(TrackFxInfoArgs) + 'static>;
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let volume = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(MasterVolumeArgs::new(volume))
+    }
 
-pub struct TrackFxInfo {
-    socket: Arc<UdpSocket>,
-    handler: Option<TrackFxInfoHandler>,
-    pub track_guid: String,
-    pub fx_idx: i32,
-}
+    fn is_queryable(&self) -> bool {
+        true
+    }
 
-/// /track/{track_guid}/fx/{fx_idx}/info
-impl Query for TrackFxInfo {
-    type Error = OscError;
-    fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/track/{}/fx/{}/info", self.track_guid, self.fx_idx);
-        let osc_msg = rosc::OscMessage {
-            addr: osc_address,
-            args: vec![],
-        };
-        let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
-        Ok(())
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
     }
 }
 
 #[derive(Debug)]
-pub struct FxinfoNameArgs {
-    pub name: String, // name of the FX
+pub struct MasterPanArgs {
+    pub pan: f32, // pan of the master track, normalized to -1.0 to 1.0
 }
 
-pub type FxinfoNameHandler = Box<dyn FnMut(FxinfoNameArgs) + 'static>;
-
-pub struct FxinfoName {
-    socket: Arc<UdpSocket>,
-    handler: Option<FxinfoNameHandler>,
-    pub ident: String,
+impl MasterPanArgs {
+    pub fn new(pan: f32) -> Self {
+        Self { pan }
+    }
 }
 
-/// /fxinfo/{ident}/name
-impl Bind<FxinfoNameArgs> for FxinfoName {
-    fn bind<F>(&mut self, callback: F)
-    where
-        F: FnMut(FxinfoNameArgs) + 'static,
-    {
-        self.handler = Some(Box::new(callback));
+impl From<f32> for MasterPanArgs {
+    fn from(pan: f32) -> Self {
+        Self::new(pan)
     }
 }
 
-#[derive(Debug)]
-pub struct FxinfoParamCountArgs {
-    pub param_count: i32, // number of parameters for the FX
+pub type MasterPanHandler = Box<dyn FnMut(MasterPanArgs) + Send + 'static>;
+
+pub struct MasterPan {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
 }
 
-pub type FxinfoParamCountHandler = Box<dyn FnMut(FxinfoParamCountArgs) + 'static>;
+impl MasterPan {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/master/pan";
 
-pub struct FxinfoParamCount {
-    socket: Arc<UdpSocket>,
-    handler: Option<FxinfoParamCountHandler>,
-    pub ident: String,
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
 }
 
-/// /fxinfo/{ident}/param_count
-impl Bind<FxinfoParamCountArgs> for FxinfoParamCount {
-    fn bind<F>(&mut self, callback: F)
-    where
-        F: FnMut(FxinfoParamCountArgs) + 'static,
-    {
-        self.handler = Some(Box::new(callback));
+impl std::fmt::Display for MasterPan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
     }
 }
 
-/// /fxinfo/{ident}/param_count
-impl Query for FxinfoParamCount {
+/// /master/pan
+impl SetMessage<MasterPanArgs> for MasterPan {
     type Error = OscError;
-    fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/fxinfo/{}/param_count", self.ident);
+    fn set_message(&self, args: MasterPanArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let mut args = args;
+        args.pan = crate::osc::range::clamp(args.pan, Some(-1.0), Some(1.0));
+        let osc_address = format!("/master/pan");
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
-            args: vec![],
+            args: vec![rosc::OscType::Float(args.pan)],
         };
+        Ok(osc_msg)
+    }
+}
+
+/// /master/pan
+impl Set<MasterPanArgs> for MasterPan {
+    type Error = OscError;
+    fn set(&mut self, args: MasterPanArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
-#[derive(Debug)]
-pub struct FxinfoParamNameArgs {
-    pub param_name: String, // name of the parameter
+impl MasterPan {
+    pub fn set_value(&mut self, value: impl Into<MasterPanArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
 }
 
-pub type FxinfoParamNameHandler = Box<dyn FnMut(FxinfoParamNameArgs) + 'static>;
-
-pub struct FxinfoParamName {
-    socket: Arc<UdpSocket>,
-    handler: Option<FxinfoParamNameHandler>,
-    pub ident: String,
-    pub param_idx: i32,
+/// /master/pan
+impl Bind<MasterPanArgs> for MasterPan {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(MasterPanArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
 }
 
-/// /fxinfo/{ident}/param/{param_idx}/name
-impl Bind<FxinfoParamNameArgs> for FxinfoParamName {
-    fn bind<F>(&mut self, callback: F)
+/// /master/pan
+impl Subscribe<MasterPanArgs> for MasterPan {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
     where
-        F: FnMut(FxinfoParamNameArgs) + 'static,
+        F: FnMut(MasterPanArgs) + Send + 'static,
     {
-        self.handler = Some(Box::new(callback));
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/master/pan");
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
     }
 }
 
-/// /fxinfo/{ident}/param/{param_idx}/name
-impl Query for FxinfoParamName {
+impl MasterPan {
+    fn add_handler(&mut self, subscription: Subscription, handler: MasterPanHandler) {
+        let osc_address = format!("/master/pan");
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_master_pan_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /master/pan
+impl Query for MasterPan {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/fxinfo/{}/param/{}/name", self.ident, self.param_idx);
+        let osc_address = format!("/master/pan");
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
-#[derive(Debug)]
-pub struct FxinfoParamMinArgs {
-    pub param_min: f32, // minimum raw value of the parameter
+fn parse_master_pan_args(msg: &rosc::OscMessage) -> Result<MasterPanArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = MasterPanArgs {
+        pan: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("pan", args.pan, Some(-1.0), Some(1.0))?;
+    Ok(args)
 }
 
-pub type FxinfoParamMinHandler = Box<dyn FnMut(FxinfoParamMinArgs) + 'static>;
-
-pub struct FxinfoParamMin {
-    socket: Arc<UdpSocket>,
-    handler: Option<FxinfoParamMinHandler>,
-    pub ident: String,
-    pub param_idx: i32,
+/// /master/pan
+impl QueryWithResponse<MasterPanArgs> for MasterPan {
+    fn query_response(&self) -> Result<Receiver<MasterPanArgs>, Self::Error> {
+        let osc_address = format!("/master/pan");
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_master_pan_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
 }
 
-/// /fxinfo/{ident}/param/{param_idx}/min
-impl Bind<FxinfoParamMinArgs> for FxinfoParamMin {
-    fn bind<F>(&mut self, callback: F)
-    where
-        F: FnMut(FxinfoParamMinArgs) + 'static,
-    {
-        self.handler = Some(Box::new(callback));
+impl DynEndpoint for MasterPan {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
     }
-}
 
-/// /fxinfo/{ident}/param/{param_idx}/min
-impl Query for FxinfoParamMin {
-    type Error = OscError;
-    fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/fxinfo/{}/param/{}/min", self.ident, self.param_idx);
-        let osc_msg = rosc::OscMessage {
-            addr: osc_address,
-            args: vec![],
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let pan = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
         };
-        let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
-        Ok(())
+        self.set(MasterPanArgs::new(pan))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
     }
 }
 
 #[derive(Debug)]
-pub struct FxinfoParamMaxArgs {
-    pub param_max: f32, // maximum raw value of the parameter
+pub struct MasterMuteArgs {
+    pub mute: bool, // true means master track is muted
 }
 
-pub type FxinfoParamMaxHandler = Box<dyn FnMut(FxinfoParamMaxArgs) + 'static>;
+impl MasterMuteArgs {
+    pub fn new(mute: bool) -> Self {
+        Self { mute }
+    }
+}
 
-pub struct FxinfoParamMax {
-    socket: Arc<UdpSocket>,
-    handler: Option<FxinfoParamMaxHandler>,
-    pub ident: String,
-    pub param_idx: i32,
+impl From<bool> for MasterMuteArgs {
+    fn from(mute: bool) -> Self {
+        Self::new(mute)
+    }
 }
 
-/// /fxinfo/{ident}/param/{param_idx}/max
-impl Bind<FxinfoParamMaxArgs> for FxinfoParamMax {
-    fn bind<F>(&mut self, callback: F)
-    where
-        F: FnMut(FxinfoParamMaxArgs) + 'static,
-    {
-        self.handler = Some(Box::new(callback));
+pub type MasterMuteHandler = Box<dyn FnMut(MasterMuteArgs) + Send + 'static>;
+
+pub struct MasterMute {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+}
+
+impl MasterMute {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/master/mute";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
     }
 }
 
-/// /fxinfo/{ident}/param/{param_idx}/max
-impl Query for FxinfoParamMax {
+impl std::fmt::Display for MasterMute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /master/mute
+impl SetMessage<MasterMuteArgs> for MasterMute {
     type Error = OscError;
-    fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/fxinfo/{}/param/{}/max", self.ident, self.param_idx);
+    fn set_message(&self, args: MasterMuteArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/master/mute");
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
-            args: vec![],
+            args: vec![rosc::OscType::Bool(args.mute)],
         };
+        Ok(osc_msg)
+    }
+}
+
+/// /master/mute
+impl Set<MasterMuteArgs> for MasterMute {
+    type Error = OscError;
+    fn set(&mut self, args: MasterMuteArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
-#[derive(Debug)]
-pub struct FxinfoArgs {}
+impl MasterMute {
+    pub fn set_value(&mut self, value: impl Into<MasterMuteArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
 
-pub type FxinfoHandler = Box<dyn FnMut(FxinfoArgs) + 'static>;
+/// /master/mute
+impl Bind<MasterMuteArgs> for MasterMute {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(MasterMuteArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
 
-pub struct Fxinfo {
-    socket: Arc<UdpSocket>,
-    handler: Option<FxinfoHandler>,
+/// /master/mute
+impl Subscribe<MasterMuteArgs> for MasterMute {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(MasterMuteArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/master/mute");
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
 }
 
-/// /fxinfo
-impl Query for Fxinfo {
+impl MasterMute {
+    fn add_handler(&mut self, subscription: Subscription, handler: MasterMuteHandler) {
+        let osc_address = format!("/master/mute");
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_master_mute_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /master/mute
+impl Query for MasterMute {
     type Error = OscError;
     fn query(&self) -> Result<(), Self::Error> {
-        let osc_address = format!("/fxinfo");
+        let osc_address = format!("/master/mute");
         let osc_msg = rosc::OscMessage {
             addr: osc_address,
             args: vec![],
         };
         let packet = rosc::OscPacket::Message(osc_msg);
-        let buf = rosc::encoder::encode(&packet).map_err(|_| OscError)?;
-        self.socket.send(&buf).map_err(|_| OscError)?;
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
         Ok(())
     }
 }
 
-pub mod context {
-    use crate::osc::generated_osc::ContextTrait;
+fn parse_master_mute_args(msg: &rosc::OscMessage) -> Result<MasterMuteArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(MasterMuteArgs {
+        mute: decode::required(&osc_args, 0)?,
+    })
+}
 
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-    pub struct Fxinfo {
-        pub ident: String,
+/// /master/mute
+impl QueryWithResponse<MasterMuteArgs> for MasterMute {
+    fn query_response(&self) -> Result<Receiver<MasterMuteArgs>, Self::Error> {
+        let osc_address = format!("/master/mute");
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_master_mute_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
     }
+}
 
-    impl ContextTrait for Fxinfo {}
-
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-    pub struct FxinfoParam {
-        pub ident: String,
-        pub param_idx: i32,
+impl DynEndpoint for MasterMute {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
     }
 
-    impl ContextTrait for FxinfoParam {}
-
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-    pub struct Track {
-        pub track_guid: String,
+    fn address(&self) -> String {
+        self.osc_address()
     }
 
-    impl ContextTrait for Track {}
+    fn is_settable(&self) -> bool {
+        true
+    }
 
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-    pub struct TrackFx {
-        pub track_guid: String,
-        pub fx_idx: i32,
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let mute = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(MasterMuteArgs::new(mute))
     }
 
-    impl ContextTrait for TrackFx {}
+    fn is_queryable(&self) -> bool {
+        true
+    }
 
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-    pub struct TrackFxParam {
-        pub track_guid: String,
-        pub fx_idx: i32,
-        pub param_idx: i32,
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
     }
+}
 
-    impl ContextTrait for TrackFxParam {}
+#[derive(Debug)]
+pub struct TrackSendCountArgs {
+    pub send_count: i32, // number of sends on the track
+}
 
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-    pub struct TrackSend {
-        pub track_guid: String,
-        pub send_index: i32,
+impl TrackSendCountArgs {
+    pub fn new(send_count: i32) -> Self {
+        Self { send_count }
     }
-
-    impl ContextTrait for TrackSend {}
 }
 
-pub mod context_kind {
-    use super::context;
-    use crate::osc::route_context::ContextKindTrait;
-    use regex::Regex;
+impl From<i32> for TrackSendCountArgs {
+    fn from(send_count: i32) -> Self {
+        Self::new(send_count)
+    }
+}
 
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-    pub struct Fxinfo {}
+pub type TrackSendCountHandler = Box<dyn FnMut(TrackSendCountArgs) + Send + 'static>;
 
-    impl ContextKindTrait for Fxinfo {
-        type Context = context::Fxinfo;
+pub struct TrackSendCount {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+}
 
-        fn context_name() -> &'static str {
-            "Fxinfo"
-        }
+impl TrackSendCount {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/send_count";
 
-        fn parse(osc_address: &str) -> Option<context::Fxinfo> {
-            let re = Regex::new(r"^/fxinfo/([^/]+)/name$").unwrap();
-            re.captures(osc_address).map(|caps| context::Fxinfo {
-                ident: caps[1].to_string(),
-            })
-        }
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/send_count", self.track_guid)
     }
+}
 
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-    pub struct FxinfoParam {}
+impl std::fmt::Display for TrackSendCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
 
-    impl ContextKindTrait for FxinfoParam {
-        type Context = context::FxinfoParam;
+/// /track/{track_guid}/send_count
+impl Bind<TrackSendCountArgs> for TrackSendCount {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackSendCountArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
 
-        fn context_name() -> &'static str {
-            "FxinfoParam"
+/// /track/{track_guid}/send_count
+impl Subscribe<TrackSendCountArgs> for TrackSendCount {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackSendCountArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/send_count", self.track_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
         }
+        removed
+    }
+}
 
-        fn parse(osc_address: &str) -> Option<context::FxinfoParam> {
-            let re = Regex::new(r"^/fxinfo/([^/]+)/param/([^/]+)/name$").unwrap();
-            re.captures(osc_address).map(|caps| context::FxinfoParam {
-                ident: caps[1].to_string(),
-                param_idx: caps[2].parse().unwrap(),
-            })
+impl TrackSendCount {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSendCountHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
         }
+        let osc_address = format!("/track/{}/send_count", self.track_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_send_count_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
+}
 
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-    pub struct Track {}
+/// /track/{track_guid}/send_count
+impl Query for TrackSendCount {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send_count", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
 
-    impl ContextKindTrait for Track {
-        type Context = context::Track;
+fn parse_track_send_count_args(msg: &rosc::OscMessage) -> Result<TrackSendCountArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(TrackSendCountArgs {
+        send_count: decode::required(&osc_args, 0)?,
+    })
+}
 
-        fn context_name() -> &'static str {
-            "Track"
-        }
+/// /track/{track_guid}/send_count
+impl QueryWithResponse<TrackSendCountArgs> for TrackSendCount {
+    fn query_response(&self) -> Result<Receiver<TrackSendCountArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send_count", self.track_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_send_count_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
 
-        fn parse(osc_address: &str) -> Option<context::Track> {
-            let re = Regex::new(r"^/track/([^/]+)/index$").unwrap();
-            re.captures(osc_address).map(|caps| context::Track {
-                track_guid: caps[1].to_string(),
-            })
-        }
+impl DynEndpoint for TrackSendCount {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
     }
 
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-    pub struct TrackFx {}
+    fn address(&self) -> String {
+        self.osc_address()
+    }
 
-    impl ContextKindTrait for TrackFx {
-        type Context = context::TrackFx;
+    fn is_queryable(&self) -> bool {
+        true
+    }
 
-        fn context_name() -> &'static str {
-            "TrackFx"
-        }
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
 
-        fn parse(osc_address: &str) -> Option<context::TrackFx> {
-            let re = Regex::new(r"^/track/([^/]+)/fx/([^/]+)/guid$").unwrap();
-            re.captures(osc_address).map(|caps| context::TrackFx {
-                track_guid: caps[1].to_string(),
-                fx_idx: caps[2].parse().unwrap(),
-            })
-        }
+#[derive(Debug)]
+pub struct TrackSendGuidArgs {
+    pub guid: String, // unique identifier for the send
+}
+
+impl TrackSendGuidArgs {
+    pub fn new(guid: impl Into<String>) -> Self {
+        Self { guid: guid.into() }
     }
+}
 
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-    pub struct TrackFxParam {}
+impl From<String> for TrackSendGuidArgs {
+    fn from(guid: String) -> Self {
+        Self::new(guid)
+    }
+}
 
-    impl ContextKindTrait for TrackFxParam {
-        type Context = context::TrackFxParam;
+impl From<&str> for TrackSendGuidArgs {
+    fn from(guid: &str) -> Self {
+        Self::new(guid)
+    }
+}
 
-        fn context_name() -> &'static str {
-            "TrackFxParam"
-        }
+pub type TrackSendGuidHandler = Box<dyn FnMut(TrackSendGuidArgs) + Send + 'static>;
 
-        fn parse(osc_address: &str) -> Option<context::TrackFxParam> {
-            let re = Regex::new(r"^/track/([^/]+)/fx/([^/]+)/param/([^/]+)/name$").unwrap();
-            re.captures(osc_address).map(|caps| context::TrackFxParam {
-                track_guid: caps[1].to_string(),
-                fx_idx: caps[2].parse().unwrap(),
-                param_idx: caps[3].parse().unwrap(),
-            })
-        }
+pub struct TrackSendGuid {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub send_index: i32,
+}
+
+impl TrackSendGuid {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/send/{send_index}/guid";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/send/{}/guid", self.track_guid, self.send_index)
     }
+}
 
-    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-    pub struct TrackSend {}
+impl std::fmt::Display for TrackSendGuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
 
-    impl ContextKindTrait for TrackSend {
-        type Context = context::TrackSend;
+/// /track/{track_guid}/send/{send_index}/guid
+impl Bind<TrackSendGuidArgs> for TrackSendGuid {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackSendGuidArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
 
-        fn context_name() -> &'static str {
-            "TrackSend"
+/// /track/{track_guid}/send/{send_index}/guid
+impl Subscribe<TrackSendGuidArgs> for TrackSendGuid {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackSendGuidArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/send/{}/guid", self.track_guid, self.send_index);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
         }
+        removed
+    }
+}
 
-        fn parse(osc_address: &str) -> Option<context::TrackSend> {
-            let re = Regex::new(r"^/track/([^/]+)/send/([^/]+)/guid$").unwrap();
-            re.captures(osc_address).map(|caps| context::TrackSend {
-                track_guid: caps[1].to_string(),
-                send_index: caps[2].parse().unwrap(),
-            })
+impl TrackSendGuid {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSendGuidHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
         }
+        let osc_address = format!("/track/{}/send/{}/guid", self.track_guid, self.send_index);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_send_guid_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
 }
 
-pub struct Reaper {
-    socket: Arc<UdpSocket>,
+/// /track/{track_guid}/send/{send_index}/guid
+impl Query for TrackSendGuid {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/guid", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
 }
 
-impl Reaper {
-    pub fn new(socket: Arc<UdpSocket>) -> Self {
-        Self { socket }
+fn parse_track_send_guid_args(msg: &rosc::OscMessage) -> Result<TrackSendGuidArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(TrackSendGuidArgs {
+        guid: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/send/{send_index}/guid
+impl QueryWithResponse<TrackSendGuidArgs> for TrackSendGuid {
+    fn query_response(&self) -> Result<Receiver<TrackSendGuidArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/guid", self.track_guid, self.send_index);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_send_guid_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
     }
 }
 
-impl Reaper {
-    pub fn num_tracks(&self) -> NumTracks {
-        NumTracks {
-            socket: self.socket.clone(),
-            handler: None,
-        }
+impl DynEndpoint for TrackSendGuid {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
     }
-    pub fn track_all_guids(&self) -> TrackAllGuids {
-        TrackAllGuids {
-            socket: self.socket.clone(),
-            handler: None,
-        }
+
+    fn address(&self) -> String {
+        self.osc_address()
     }
-    pub fn track_index(&self, track_guid: String) -> TrackIndex {
-        TrackIndex {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
-        }
+
+    fn is_queryable(&self) -> bool {
+        true
     }
-    pub fn track_delete(&self, track_guid: String) -> TrackDelete {
-        TrackDelete {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
-        }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
     }
-    pub fn track_name(&self, track_guid: String) -> TrackName {
-        TrackName {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
-        }
+}
+
+#[derive(Debug)]
+pub struct TrackSendVolumeArgs {
+    pub volume: f32, // volume of the send, normalized to 0 to 1.
+}
+
+impl TrackSendVolumeArgs {
+    pub fn new(volume: f32) -> Self {
+        Self { volume }
     }
-    pub fn track_selected(&self, track_guid: String) -> TrackSelected {
-        TrackSelected {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
-        }
+}
+
+impl From<f32> for TrackSendVolumeArgs {
+    fn from(volume: f32) -> Self {
+        Self::new(volume)
     }
-    pub fn track_volume(&self, track_guid: String) -> TrackVolume {
-        TrackVolume {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
-        }
+}
+
+pub type TrackSendVolumeHandler = Box<dyn FnMut(TrackSendVolumeArgs) + Send + 'static>;
+
+pub struct TrackSendVolume {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub send_index: i32,
+}
+
+impl TrackSendVolume {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/send/{send_index}/volume";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/send/{}/volume", self.track_guid, self.send_index)
     }
-    pub fn track_pan(&self, track_guid: String) -> TrackPan {
-        TrackPan {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
-        }
+}
+
+impl std::fmt::Display for TrackSendVolume {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
     }
-    pub fn track_mute(&self, track_guid: String) -> TrackMute {
-        TrackMute {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
-        }
+}
+
+/// /track/{track_guid}/send/{send_index}/volume
+impl SetMessage<TrackSendVolumeArgs> for TrackSendVolume {
+    type Error = OscError;
+    fn set_message(&self, args: TrackSendVolumeArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let mut args = args;
+        args.volume = crate::osc::range::clamp(args.volume, Some(0.0), Some(1.0));
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/volume", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.volume)],
+        };
+        Ok(osc_msg)
     }
-    pub fn track_solo(&self, track_guid: String) -> TrackSolo {
-        TrackSolo {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
-        }
+}
+
+/// /track/{track_guid}/send/{send_index}/volume
+impl Set<TrackSendVolumeArgs> for TrackSendVolume {
+    type Error = OscError;
+    fn set(&mut self, args: TrackSendVolumeArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
     }
-    pub fn track_rec_arm(&self, track_guid: String) -> TrackRecArm {
-        TrackRecArm {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
-        }
+}
+
+impl TrackSendVolume {
+    pub fn set_value(&mut self, value: impl Into<TrackSendVolumeArgs>) -> Result<(), OscError> {
+        self.set(value.into())
     }
-    pub fn track_send_guid(&self, track_guid: String, send_index: i32) -> TrackSendGuid {
-        TrackSendGuid {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
-            send_index: send_index,
-        }
+}
+
+/// /track/{track_guid}/send/{send_index}/volume
+impl Bind<TrackSendVolumeArgs> for TrackSendVolume {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackSendVolumeArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
     }
-    pub fn track_send_volume(&self, track_guid: String, send_index: i32) -> TrackSendVolume {
-        TrackSendVolume {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
-            send_index: send_index,
-        }
+}
+
+/// /track/{track_guid}/send/{send_index}/volume
+impl Subscribe<TrackSendVolumeArgs> for TrackSendVolume {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackSendVolumeArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
     }
-    pub fn track_send_pan(&self, track_guid: String, send_index: i32) -> TrackSendPan {
-        TrackSendPan {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
-            send_index: send_index,
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
         }
-    }
-    pub fn track_color(&self, track_guid: String) -> TrackColor {
-        TrackColor {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
+        let osc_address = format!("/track/{}/send/{}/volume", self.track_guid, self.send_index);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
         }
+        removed
     }
-    pub fn track_fx_guid(&self, track_guid: String, fx_idx: i32) -> TrackFxGuid {
-        TrackFxGuid {
-            socket: self.socket.clone(),
-            handler: None,
-            track_guid: track_guid,
-            fx_idx: fx_idx,
+}
+
+impl TrackSendVolume {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSendVolumeHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
         }
+        let osc_address = format!("/track/{}/send/{}/volume", self.track_guid, self.send_index);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_send_volume_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
     }
-    pub fn track_fx_name(&self, track_guid: String, fx_idx: i32) -> TrackFxName {
-        TrackFxName {
+}
+
+/// /track/{track_guid}/send/{send_index}/volume
+impl Query for TrackSendVolume {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/volume", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_send_volume_args(msg: &rosc::OscMessage) -> Result<TrackSendVolumeArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = TrackSendVolumeArgs {
+        volume: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("volume", args.volume, Some(0.0), Some(1.0))?;
+    Ok(args)
+}
+
+/// /track/{track_guid}/send/{send_index}/volume
+impl QueryWithResponse<TrackSendVolumeArgs> for TrackSendVolume {
+    fn query_response(&self) -> Result<Receiver<TrackSendVolumeArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/volume", self.track_guid, self.send_index);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_send_volume_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackSendVolume {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let volume = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackSendVolumeArgs::new(volume))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackSendPanArgs {
+    pub pan: f32, // pan of the send, normalized to -1.0 to 1.0
+}
+
+impl TrackSendPanArgs {
+    pub fn new(pan: f32) -> Self {
+        Self { pan }
+    }
+}
+
+impl From<f32> for TrackSendPanArgs {
+    fn from(pan: f32) -> Self {
+        Self::new(pan)
+    }
+}
+
+pub type TrackSendPanHandler = Box<dyn FnMut(TrackSendPanArgs) + Send + 'static>;
+
+pub struct TrackSendPan {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub send_index: i32,
+}
+
+impl TrackSendPan {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/send/{send_index}/pan";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/send/{}/pan", self.track_guid, self.send_index)
+    }
+}
+
+impl std::fmt::Display for TrackSendPan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/pan
+impl SetMessage<TrackSendPanArgs> for TrackSendPan {
+    type Error = OscError;
+    fn set_message(&self, args: TrackSendPanArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let mut args = args;
+        args.pan = crate::osc::range::clamp(args.pan, Some(-1.0), Some(1.0));
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/pan", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.pan)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/pan
+impl Set<TrackSendPanArgs> for TrackSendPan {
+    type Error = OscError;
+    fn set(&mut self, args: TrackSendPanArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackSendPan {
+    pub fn set_value(&mut self, value: impl Into<TrackSendPanArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/pan
+impl Bind<TrackSendPanArgs> for TrackSendPan {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackSendPanArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/pan
+impl Subscribe<TrackSendPanArgs> for TrackSendPan {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackSendPanArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/send/{}/pan", self.track_guid, self.send_index);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackSendPan {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSendPanHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/send/{}/pan", self.track_guid, self.send_index);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_send_pan_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/pan
+impl Query for TrackSendPan {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/pan", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_send_pan_args(msg: &rosc::OscMessage) -> Result<TrackSendPanArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = TrackSendPanArgs {
+        pan: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("pan", args.pan, Some(-1.0), Some(1.0))?;
+    Ok(args)
+}
+
+/// /track/{track_guid}/send/{send_index}/pan
+impl QueryWithResponse<TrackSendPanArgs> for TrackSendPan {
+    fn query_response(&self) -> Result<Receiver<TrackSendPanArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/pan", self.track_guid, self.send_index);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_send_pan_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackSendPan {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let pan = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackSendPanArgs::new(pan))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackSendMuteArgs {
+    pub mute: bool, // true means the send is muted
+}
+
+impl TrackSendMuteArgs {
+    pub fn new(mute: bool) -> Self {
+        Self { mute }
+    }
+}
+
+impl From<bool> for TrackSendMuteArgs {
+    fn from(mute: bool) -> Self {
+        Self::new(mute)
+    }
+}
+
+pub type TrackSendMuteHandler = Box<dyn FnMut(TrackSendMuteArgs) + Send + 'static>;
+
+pub struct TrackSendMute {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub send_index: i32,
+}
+
+impl TrackSendMute {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/send/{send_index}/mute";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/send/{}/mute", self.track_guid, self.send_index)
+    }
+}
+
+impl std::fmt::Display for TrackSendMute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/mute
+impl SetMessage<TrackSendMuteArgs> for TrackSendMute {
+    type Error = OscError;
+    fn set_message(&self, args: TrackSendMuteArgs) -> Result<rosc::OscMessage, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/mute", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.mute)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/mute
+impl Set<TrackSendMuteArgs> for TrackSendMute {
+    type Error = OscError;
+    fn set(&mut self, args: TrackSendMuteArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackSendMute {
+    pub fn set_value(&mut self, value: impl Into<TrackSendMuteArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/mute
+impl Bind<TrackSendMuteArgs> for TrackSendMute {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackSendMuteArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/mute
+impl Subscribe<TrackSendMuteArgs> for TrackSendMute {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackSendMuteArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/send/{}/mute", self.track_guid, self.send_index);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackSendMute {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSendMuteHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/send/{}/mute", self.track_guid, self.send_index);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_send_mute_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/mute
+impl Query for TrackSendMute {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/mute", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_send_mute_args(msg: &rosc::OscMessage) -> Result<TrackSendMuteArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackSendMuteArgs {
+        mute: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/send/{send_index}/mute
+impl QueryWithResponse<TrackSendMuteArgs> for TrackSendMute {
+    fn query_response(&self) -> Result<Receiver<TrackSendMuteArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/mute", self.track_guid, self.send_index);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_send_mute_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackSendMute {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let mute = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackSendMuteArgs::new(mute))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackColorArgs {
+    pub color: i32, // color of the track, represented as an RGB integer
+}
+
+impl TrackColorArgs {
+    pub fn new(color: i32) -> Self {
+        Self { color }
+    }
+}
+
+impl From<i32> for TrackColorArgs {
+    fn from(color: i32) -> Self {
+        Self::new(color)
+    }
+}
+
+pub type TrackColorHandler = Box<dyn FnMut(TrackColorArgs) + Send + 'static>;
+
+pub struct TrackColor {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackColor {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/color";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/color", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/color
+impl SetMessage<TrackColorArgs> for TrackColor {
+    type Error = OscError;
+    fn set_message(&self, args: TrackColorArgs) -> Result<rosc::OscMessage, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/color", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Int(args.color)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/color
+impl Set<TrackColorArgs> for TrackColor {
+    type Error = OscError;
+    fn set(&mut self, args: TrackColorArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackColor {
+    pub fn set_value(&mut self, value: impl Into<TrackColorArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /track/{track_guid}/color
+impl Bind<TrackColorArgs> for TrackColor {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackColorArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/color
+impl Subscribe<TrackColorArgs> for TrackColor {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackColorArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/color", self.track_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackColor {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackColorHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/color", self.track_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_color_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/color
+impl Query for TrackColor {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/color", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_color_args(msg: &rosc::OscMessage) -> Result<TrackColorArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(TrackColorArgs {
+        color: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/color
+impl QueryWithResponse<TrackColorArgs> for TrackColor {
+    fn query_response(&self) -> Result<Receiver<TrackColorArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/color", self.track_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_color_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackColor {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let color = match value {
+            ParamValue::Int(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes int, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackColorArgs::new(color))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackFxGuidArgs {
+    pub guid: String, // unique identifier for the FX
+}
+
+impl TrackFxGuidArgs {
+    pub fn new(guid: impl Into<String>) -> Self {
+        Self { guid: guid.into() }
+    }
+}
+
+impl From<String> for TrackFxGuidArgs {
+    fn from(guid: String) -> Self {
+        Self::new(guid)
+    }
+}
+
+impl From<&str> for TrackFxGuidArgs {
+    fn from(guid: &str) -> Self {
+        Self::new(guid)
+    }
+}
+
+pub type TrackFxGuidHandler = Box<dyn FnMut(TrackFxGuidArgs) + Send + 'static>;
+
+pub struct TrackFxGuid {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxGuid {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/guid";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/guid", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxGuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/guid
+impl Bind<TrackFxGuidArgs> for TrackFxGuid {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxGuidArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/guid
+impl Subscribe<TrackFxGuidArgs> for TrackFxGuid {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxGuidArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/fx/{}/guid", self.track_guid, self.fx_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackFxGuid {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxGuidHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/fx/{}/guid", self.track_guid, self.fx_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_fx_guid_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/guid
+impl Query for TrackFxGuid {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/guid", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_guid_args(msg: &rosc::OscMessage) -> Result<TrackFxGuidArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(TrackFxGuidArgs {
+        guid: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/guid
+impl QueryWithResponse<TrackFxGuidArgs> for TrackFxGuid {
+    fn query_response(&self) -> Result<Receiver<TrackFxGuidArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/guid", self.track_guid, self.fx_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_fx_guid_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackFxGuid {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackFxNameArgs {
+    pub name: String, // name of the FX
+}
+
+impl TrackFxNameArgs {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl From<String> for TrackFxNameArgs {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&str> for TrackFxNameArgs {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+pub type TrackFxNameHandler = Box<dyn FnMut(TrackFxNameArgs) + Send + 'static>;
+
+pub struct TrackFxName {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/name", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/name
+impl Bind<TrackFxNameArgs> for TrackFxName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxNameArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/name
+impl Subscribe<TrackFxNameArgs> for TrackFxName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxNameArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/fx/{}/name", self.track_guid, self.fx_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackFxName {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxNameHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/fx/{}/name", self.track_guid, self.fx_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_fx_name_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/name
+impl Query for TrackFxName {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/name", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_name_args(msg: &rosc::OscMessage) -> Result<TrackFxNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(TrackFxNameArgs {
+        name: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/name
+impl QueryWithResponse<TrackFxNameArgs> for TrackFxName {
+    fn query_response(&self) -> Result<Receiver<TrackFxNameArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/name", self.track_guid, self.fx_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_fx_name_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackFxName {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackFxEnabledArgs {
+    pub enabled: bool, // true if the FX is enabled
+}
+
+impl TrackFxEnabledArgs {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
+impl From<bool> for TrackFxEnabledArgs {
+    fn from(enabled: bool) -> Self {
+        Self::new(enabled)
+    }
+}
+
+pub type TrackFxEnabledHandler = Box<dyn FnMut(TrackFxEnabledArgs) + Send + 'static>;
+
+pub struct TrackFxEnabled {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxEnabled {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/enabled";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/enabled", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxEnabled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/enabled
+impl SetMessage<TrackFxEnabledArgs> for TrackFxEnabled {
+    type Error = OscError;
+    fn set_message(&self, args: TrackFxEnabledArgs) -> Result<rosc::OscMessage, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/enabled", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.enabled)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/enabled
+impl Set<TrackFxEnabledArgs> for TrackFxEnabled {
+    type Error = OscError;
+    fn set(&mut self, args: TrackFxEnabledArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackFxEnabled {
+    pub fn set_value(&mut self, value: impl Into<TrackFxEnabledArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/enabled
+impl Bind<TrackFxEnabledArgs> for TrackFxEnabled {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxEnabledArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/enabled
+impl Subscribe<TrackFxEnabledArgs> for TrackFxEnabled {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxEnabledArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/fx/{}/enabled", self.track_guid, self.fx_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackFxEnabled {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxEnabledHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/fx/{}/enabled", self.track_guid, self.fx_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_fx_enabled_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/enabled
+impl Query for TrackFxEnabled {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/enabled", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_enabled_args(msg: &rosc::OscMessage) -> Result<TrackFxEnabledArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackFxEnabledArgs {
+        enabled: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/enabled
+impl QueryWithResponse<TrackFxEnabledArgs> for TrackFxEnabled {
+    fn query_response(&self) -> Result<Receiver<TrackFxEnabledArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/enabled", self.track_guid, self.fx_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_fx_enabled_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackFxEnabled {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let enabled = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackFxEnabledArgs::new(enabled))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackFxBypassArgs {
+    pub bypass: bool, // true if the FX is bypassed
+}
+
+impl TrackFxBypassArgs {
+    pub fn new(bypass: bool) -> Self {
+        Self { bypass }
+    }
+}
+
+impl From<bool> for TrackFxBypassArgs {
+    fn from(bypass: bool) -> Self {
+        Self::new(bypass)
+    }
+}
+
+pub type TrackFxBypassHandler = Box<dyn FnMut(TrackFxBypassArgs) + Send + 'static>;
+
+pub struct TrackFxBypass {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxBypass {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/bypass";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/bypass", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxBypass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/bypass
+impl SetMessage<TrackFxBypassArgs> for TrackFxBypass {
+    type Error = OscError;
+    fn set_message(&self, args: TrackFxBypassArgs) -> Result<rosc::OscMessage, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/bypass", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.bypass)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/bypass
+impl Set<TrackFxBypassArgs> for TrackFxBypass {
+    type Error = OscError;
+    fn set(&mut self, args: TrackFxBypassArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackFxBypass {
+    pub fn set_value(&mut self, value: impl Into<TrackFxBypassArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/bypass
+impl Bind<TrackFxBypassArgs> for TrackFxBypass {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxBypassArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/bypass
+impl Subscribe<TrackFxBypassArgs> for TrackFxBypass {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxBypassArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/fx/{}/bypass", self.track_guid, self.fx_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackFxBypass {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxBypassHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/fx/{}/bypass", self.track_guid, self.fx_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_fx_bypass_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/bypass
+impl Query for TrackFxBypass {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/bypass", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_bypass_args(msg: &rosc::OscMessage) -> Result<TrackFxBypassArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackFxBypassArgs {
+        bypass: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/bypass
+impl QueryWithResponse<TrackFxBypassArgs> for TrackFxBypass {
+    fn query_response(&self) -> Result<Receiver<TrackFxBypassArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/bypass", self.track_guid, self.fx_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_fx_bypass_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackFxBypass {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let bypass = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackFxBypassArgs::new(bypass))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackFxWetArgs {
+    pub wet: f32, // wet/dry mix of the FX, from 0 (dry) to 1 (wet)
+}
+
+impl TrackFxWetArgs {
+    pub fn new(wet: f32) -> Self {
+        Self { wet }
+    }
+}
+
+impl From<f32> for TrackFxWetArgs {
+    fn from(wet: f32) -> Self {
+        Self::new(wet)
+    }
+}
+
+pub type TrackFxWetHandler = Box<dyn FnMut(TrackFxWetArgs) + Send + 'static>;
+
+pub struct TrackFxWet {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxWet {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/wet";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/wet", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxWet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/wet
+impl SetMessage<TrackFxWetArgs> for TrackFxWet {
+    type Error = OscError;
+    fn set_message(&self, args: TrackFxWetArgs) -> Result<rosc::OscMessage, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/wet", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.wet)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/wet
+impl Set<TrackFxWetArgs> for TrackFxWet {
+    type Error = OscError;
+    fn set(&mut self, args: TrackFxWetArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackFxWet {
+    pub fn set_value(&mut self, value: impl Into<TrackFxWetArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/wet
+impl Bind<TrackFxWetArgs> for TrackFxWet {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxWetArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/wet
+impl Subscribe<TrackFxWetArgs> for TrackFxWet {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxWetArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/fx/{}/wet", self.track_guid, self.fx_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackFxWet {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxWetHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/fx/{}/wet", self.track_guid, self.fx_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_fx_wet_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/wet
+impl Query for TrackFxWet {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/wet", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_wet_args(msg: &rosc::OscMessage) -> Result<TrackFxWetArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(TrackFxWetArgs {
+        wet: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/wet
+impl QueryWithResponse<TrackFxWetArgs> for TrackFxWet {
+    fn query_response(&self) -> Result<Receiver<TrackFxWetArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/wet", self.track_guid, self.fx_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_fx_wet_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackFxWet {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let wet = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackFxWetArgs::new(wet))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackFxParamCountArgs {
+    pub param_count: i32, // number of parameters for the FX
+}
+
+impl TrackFxParamCountArgs {
+    pub fn new(param_count: i32) -> Self {
+        Self { param_count }
+    }
+}
+
+impl From<i32> for TrackFxParamCountArgs {
+    fn from(param_count: i32) -> Self {
+        Self::new(param_count)
+    }
+}
+
+pub type TrackFxParamCountHandler = Box<dyn FnMut(TrackFxParamCountArgs) + Send + 'static>;
+
+pub struct TrackFxParamCount {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxParamCount {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/param_count";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/param_count", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxParamCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param_count
+impl Bind<TrackFxParamCountArgs> for TrackFxParamCount {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxParamCountArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param_count
+impl Subscribe<TrackFxParamCountArgs> for TrackFxParamCount {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxParamCountArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/track/{}/fx/{}/param_count", self.track_guid, self.fx_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackFxParamCount {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxParamCountHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/track/{}/fx/{}/param_count", self.track_guid, self.fx_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_fx_param_count_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param_count
+impl Query for TrackFxParamCount {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/param_count", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_param_count_args(
+    msg: &rosc::OscMessage,
+) -> Result<TrackFxParamCountArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(TrackFxParamCountArgs {
+        param_count: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param_count
+impl QueryWithResponse<TrackFxParamCountArgs> for TrackFxParamCount {
+    fn query_response(&self) -> Result<Receiver<TrackFxParamCountArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/param_count", self.track_guid, self.fx_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_fx_param_count_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackFxParamCount {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackFxParamNameArgs {
+    pub param_name: String, // name of the parameter
+}
+
+impl TrackFxParamNameArgs {
+    pub fn new(param_name: impl Into<String>) -> Self {
+        Self {
+            param_name: param_name.into(),
+        }
+    }
+}
+
+impl From<String> for TrackFxParamNameArgs {
+    fn from(param_name: String) -> Self {
+        Self::new(param_name)
+    }
+}
+
+impl From<&str> for TrackFxParamNameArgs {
+    fn from(param_name: &str) -> Self {
+        Self::new(param_name)
+    }
+}
+
+pub type TrackFxParamNameHandler = Box<dyn FnMut(TrackFxParamNameArgs) + Send + 'static>;
+
+pub struct TrackFxParamName {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+    pub param_idx: i32,
+}
+
+impl TrackFxParamName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!(
+            "/track/{}/fx/{}/param/{}/name",
+            self.track_guid, self.fx_idx, self.param_idx
+        )
+    }
+}
+
+impl std::fmt::Display for TrackFxParamName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name
+impl Bind<TrackFxParamNameArgs> for TrackFxParamName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxParamNameArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name
+impl Subscribe<TrackFxParamNameArgs> for TrackFxParamName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxParamNameArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/name",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackFxParamName {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxParamNameHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/name",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_fx_param_name_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name
+impl Query for TrackFxParamName {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/name",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_param_name_args(msg: &rosc::OscMessage) -> Result<TrackFxParamNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(TrackFxParamNameArgs {
+        param_name: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name
+impl QueryWithResponse<TrackFxParamNameArgs> for TrackFxParamName {
+    fn query_response(&self) -> Result<Receiver<TrackFxParamNameArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/name",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_fx_param_name_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackFxParamName {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackFxParamValueArgs {
+    pub value: f32, // value of the parameter
+}
+
+impl TrackFxParamValueArgs {
+    pub fn new(value: f32) -> Self {
+        Self { value }
+    }
+}
+
+impl From<f32> for TrackFxParamValueArgs {
+    fn from(value: f32) -> Self {
+        Self::new(value)
+    }
+}
+
+pub type TrackFxParamValueHandler = Box<dyn FnMut(TrackFxParamValueArgs) + Send + 'static>;
+
+pub struct TrackFxParamValue {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+    pub param_idx: i32,
+}
+
+impl TrackFxParamValue {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!(
+            "/track/{}/fx/{}/param/{}/value",
+            self.track_guid, self.fx_idx, self.param_idx
+        )
+    }
+}
+
+impl std::fmt::Display for TrackFxParamValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
+impl SetMessage<TrackFxParamValueArgs> for TrackFxParamValue {
+    type Error = OscError;
+    fn set_message(&self, args: TrackFxParamValueArgs) -> Result<rosc::OscMessage, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/value",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.value)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
+impl Set<TrackFxParamValueArgs> for TrackFxParamValue {
+    type Error = OscError;
+    fn set(&mut self, args: TrackFxParamValueArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackFxParamValue {
+    pub fn set_value(&mut self, value: impl Into<TrackFxParamValueArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
+impl Bind<TrackFxParamValueArgs> for TrackFxParamValue {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxParamValueArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
+impl Subscribe<TrackFxParamValueArgs> for TrackFxParamValue {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxParamValueArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/value",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackFxParamValue {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxParamValueHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/value",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_fx_param_value_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
+impl Query for TrackFxParamValue {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/value",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_param_value_args(
+    msg: &rosc::OscMessage,
+) -> Result<TrackFxParamValueArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(TrackFxParamValueArgs {
+        value: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
+impl QueryWithResponse<TrackFxParamValueArgs> for TrackFxParamValue {
+    fn query_response(&self) -> Result<Receiver<TrackFxParamValueArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/value",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_fx_param_value_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackFxParamValue {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let value = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TrackFxParamValueArgs::new(value))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackFxParamMinArgs {
+    pub min: f32, // minimum value of the parameter
+}
+
+impl TrackFxParamMinArgs {
+    pub fn new(min: f32) -> Self {
+        Self { min }
+    }
+}
+
+impl From<f32> for TrackFxParamMinArgs {
+    fn from(min: f32) -> Self {
+        Self::new(min)
+    }
+}
+
+pub type TrackFxParamMinHandler = Box<dyn FnMut(TrackFxParamMinArgs) + Send + 'static>;
+
+pub struct TrackFxParamMin {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+    pub param_idx: i32,
+}
+
+impl TrackFxParamMin {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!(
+            "/track/{}/fx/{}/param/{}/min",
+            self.track_guid, self.fx_idx, self.param_idx
+        )
+    }
+}
+
+impl std::fmt::Display for TrackFxParamMin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min
+impl Bind<TrackFxParamMinArgs> for TrackFxParamMin {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxParamMinArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min
+impl Subscribe<TrackFxParamMinArgs> for TrackFxParamMin {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxParamMinArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/min",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackFxParamMin {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxParamMinHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/min",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_fx_param_min_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min
+impl Query for TrackFxParamMin {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/min",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_param_min_args(msg: &rosc::OscMessage) -> Result<TrackFxParamMinArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(TrackFxParamMinArgs {
+        min: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min
+impl QueryWithResponse<TrackFxParamMinArgs> for TrackFxParamMin {
+    fn query_response(&self) -> Result<Receiver<TrackFxParamMinArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/min",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_fx_param_min_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackFxParamMin {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackFxParamMaxArgs {
+    pub max: f32, // maximum value of the parameter
+}
+
+impl TrackFxParamMaxArgs {
+    pub fn new(max: f32) -> Self {
+        Self { max }
+    }
+}
+
+impl From<f32> for TrackFxParamMaxArgs {
+    fn from(max: f32) -> Self {
+        Self::new(max)
+    }
+}
+
+pub type TrackFxParamMaxHandler = Box<dyn FnMut(TrackFxParamMaxArgs) + Send + 'static>;
+
+pub struct TrackFxParamMax {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+    pub param_idx: i32,
+}
+
+impl TrackFxParamMax {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!(
+            "/track/{}/fx/{}/param/{}/max",
+            self.track_guid, self.fx_idx, self.param_idx
+        )
+    }
+}
+
+impl std::fmt::Display for TrackFxParamMax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max
+impl Bind<TrackFxParamMaxArgs> for TrackFxParamMax {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxParamMaxArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max
+impl Subscribe<TrackFxParamMaxArgs> for TrackFxParamMax {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxParamMaxArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/max",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl TrackFxParamMax {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxParamMaxHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.track_guid).is_err() {
+            return;
+        }
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/max",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_track_fx_param_max_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max
+impl Query for TrackFxParamMax {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/max",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_param_max_args(msg: &rosc::OscMessage) -> Result<TrackFxParamMaxArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(TrackFxParamMaxArgs {
+        max: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max
+impl QueryWithResponse<TrackFxParamMaxArgs> for TrackFxParamMax {
+    fn query_response(&self) -> Result<Receiver<TrackFxParamMaxArgs>, Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/max",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_track_fx_param_max_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for TrackFxParamMax {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TrackFxInfoArgs {}
+
+pub type TrackFxInfoHandler = Box<dyn FnMut(TrackFxInfoArgs) + Send + 'static>;
+
+pub struct TrackFxInfo {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxInfo {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/info";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/info", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/info
+impl Query for TrackFxInfo {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/info", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_info_args(_msg: &rosc::OscMessage) -> Result<TrackFxInfoArgs, String> {
+    Ok(TrackFxInfoArgs {})
+}
+
+impl DynEndpoint for TrackFxInfo {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct FxinfoNameArgs {
+    pub name: String, // name of the FX
+}
+
+impl FxinfoNameArgs {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl From<String> for FxinfoNameArgs {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&str> for FxinfoNameArgs {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+pub type FxinfoNameHandler = Box<dyn FnMut(FxinfoNameArgs) + Send + 'static>;
+
+pub struct FxinfoName {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pub ident: String,
+}
+
+impl FxinfoName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/fxinfo/{ident}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/fxinfo/{}/name", self.ident)
+    }
+}
+
+impl std::fmt::Display for FxinfoName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /fxinfo/{ident}/name
+impl Bind<FxinfoNameArgs> for FxinfoName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(FxinfoNameArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /fxinfo/{ident}/name
+impl Subscribe<FxinfoNameArgs> for FxinfoName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(FxinfoNameArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.ident).is_err() {
+            return false;
+        }
+        let osc_address = format!("/fxinfo/{}/name", self.ident);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl FxinfoName {
+    fn add_handler(&mut self, subscription: Subscription, handler: FxinfoNameHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.ident).is_err() {
+            return;
+        }
+        let osc_address = format!("/fxinfo/{}/name", self.ident);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_fxinfo_name_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+fn parse_fxinfo_name_args(msg: &rosc::OscMessage) -> Result<FxinfoNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(FxinfoNameArgs {
+        name: decode::required(&osc_args, 0)?,
+    })
+}
+
+impl DynEndpoint for FxinfoName {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+}
+
+#[derive(Debug)]
+pub struct FxinfoParamCountArgs {
+    pub param_count: i32, // number of parameters for the FX
+}
+
+impl FxinfoParamCountArgs {
+    pub fn new(param_count: i32) -> Self {
+        Self { param_count }
+    }
+}
+
+impl From<i32> for FxinfoParamCountArgs {
+    fn from(param_count: i32) -> Self {
+        Self::new(param_count)
+    }
+}
+
+pub type FxinfoParamCountHandler = Box<dyn FnMut(FxinfoParamCountArgs) + Send + 'static>;
+
+pub struct FxinfoParamCount {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub ident: String,
+}
+
+impl FxinfoParamCount {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/fxinfo/{ident}/param_count";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/fxinfo/{}/param_count", self.ident)
+    }
+}
+
+impl std::fmt::Display for FxinfoParamCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /fxinfo/{ident}/param_count
+impl Bind<FxinfoParamCountArgs> for FxinfoParamCount {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(FxinfoParamCountArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /fxinfo/{ident}/param_count
+impl Subscribe<FxinfoParamCountArgs> for FxinfoParamCount {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(FxinfoParamCountArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.ident).is_err() {
+            return false;
+        }
+        let osc_address = format!("/fxinfo/{}/param_count", self.ident);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl FxinfoParamCount {
+    fn add_handler(&mut self, subscription: Subscription, handler: FxinfoParamCountHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.ident).is_err() {
+            return;
+        }
+        let osc_address = format!("/fxinfo/{}/param_count", self.ident);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_fxinfo_param_count_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /fxinfo/{ident}/param_count
+impl Query for FxinfoParamCount {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.ident)?;
+        let osc_address = format!("/fxinfo/{}/param_count", self.ident);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_fxinfo_param_count_args(msg: &rosc::OscMessage) -> Result<FxinfoParamCountArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(FxinfoParamCountArgs {
+        param_count: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /fxinfo/{ident}/param_count
+impl QueryWithResponse<FxinfoParamCountArgs> for FxinfoParamCount {
+    fn query_response(&self) -> Result<Receiver<FxinfoParamCountArgs>, Self::Error> {
+        check_address_segment(&self.ident)?;
+        let osc_address = format!("/fxinfo/{}/param_count", self.ident);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_fxinfo_param_count_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for FxinfoParamCount {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct FxinfoParamNameArgs {
+    pub param_name: String, // name of the parameter
+}
+
+impl FxinfoParamNameArgs {
+    pub fn new(param_name: impl Into<String>) -> Self {
+        Self {
+            param_name: param_name.into(),
+        }
+    }
+}
+
+impl From<String> for FxinfoParamNameArgs {
+    fn from(param_name: String) -> Self {
+        Self::new(param_name)
+    }
+}
+
+impl From<&str> for FxinfoParamNameArgs {
+    fn from(param_name: &str) -> Self {
+        Self::new(param_name)
+    }
+}
+
+pub type FxinfoParamNameHandler = Box<dyn FnMut(FxinfoParamNameArgs) + Send + 'static>;
+
+pub struct FxinfoParamName {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub ident: String,
+    pub param_idx: i32,
+}
+
+impl FxinfoParamName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/fxinfo/{ident}/param/{param_idx}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/fxinfo/{}/param/{}/name", self.ident, self.param_idx)
+    }
+}
+
+impl std::fmt::Display for FxinfoParamName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/name
+impl Bind<FxinfoParamNameArgs> for FxinfoParamName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(FxinfoParamNameArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/name
+impl Subscribe<FxinfoParamNameArgs> for FxinfoParamName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(FxinfoParamNameArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.ident).is_err() {
+            return false;
+        }
+        let osc_address = format!("/fxinfo/{}/param/{}/name", self.ident, self.param_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl FxinfoParamName {
+    fn add_handler(&mut self, subscription: Subscription, handler: FxinfoParamNameHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.ident).is_err() {
+            return;
+        }
+        let osc_address = format!("/fxinfo/{}/param/{}/name", self.ident, self.param_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_fxinfo_param_name_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/name
+impl Query for FxinfoParamName {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.ident)?;
+        let osc_address = format!("/fxinfo/{}/param/{}/name", self.ident, self.param_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_fxinfo_param_name_args(msg: &rosc::OscMessage) -> Result<FxinfoParamNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(FxinfoParamNameArgs {
+        param_name: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/name
+impl QueryWithResponse<FxinfoParamNameArgs> for FxinfoParamName {
+    fn query_response(&self) -> Result<Receiver<FxinfoParamNameArgs>, Self::Error> {
+        check_address_segment(&self.ident)?;
+        let osc_address = format!("/fxinfo/{}/param/{}/name", self.ident, self.param_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_fxinfo_param_name_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for FxinfoParamName {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct FxinfoParamMinArgs {
+    pub param_min: f32, // minimum raw value of the parameter
+}
+
+impl FxinfoParamMinArgs {
+    pub fn new(param_min: f32) -> Self {
+        Self { param_min }
+    }
+}
+
+impl From<f32> for FxinfoParamMinArgs {
+    fn from(param_min: f32) -> Self {
+        Self::new(param_min)
+    }
+}
+
+pub type FxinfoParamMinHandler = Box<dyn FnMut(FxinfoParamMinArgs) + Send + 'static>;
+
+pub struct FxinfoParamMin {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub ident: String,
+    pub param_idx: i32,
+}
+
+impl FxinfoParamMin {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/fxinfo/{ident}/param/{param_idx}/min";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/fxinfo/{}/param/{}/min", self.ident, self.param_idx)
+    }
+}
+
+impl std::fmt::Display for FxinfoParamMin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/min
+impl Bind<FxinfoParamMinArgs> for FxinfoParamMin {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(FxinfoParamMinArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/min
+impl Subscribe<FxinfoParamMinArgs> for FxinfoParamMin {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(FxinfoParamMinArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.ident).is_err() {
+            return false;
+        }
+        let osc_address = format!("/fxinfo/{}/param/{}/min", self.ident, self.param_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl FxinfoParamMin {
+    fn add_handler(&mut self, subscription: Subscription, handler: FxinfoParamMinHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.ident).is_err() {
+            return;
+        }
+        let osc_address = format!("/fxinfo/{}/param/{}/min", self.ident, self.param_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_fxinfo_param_min_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/min
+impl Query for FxinfoParamMin {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.ident)?;
+        let osc_address = format!("/fxinfo/{}/param/{}/min", self.ident, self.param_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_fxinfo_param_min_args(msg: &rosc::OscMessage) -> Result<FxinfoParamMinArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(FxinfoParamMinArgs {
+        param_min: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/min
+impl QueryWithResponse<FxinfoParamMinArgs> for FxinfoParamMin {
+    fn query_response(&self) -> Result<Receiver<FxinfoParamMinArgs>, Self::Error> {
+        check_address_segment(&self.ident)?;
+        let osc_address = format!("/fxinfo/{}/param/{}/min", self.ident, self.param_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_fxinfo_param_min_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for FxinfoParamMin {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct FxinfoParamMaxArgs {
+    pub param_max: f32, // maximum raw value of the parameter
+}
+
+impl FxinfoParamMaxArgs {
+    pub fn new(param_max: f32) -> Self {
+        Self { param_max }
+    }
+}
+
+impl From<f32> for FxinfoParamMaxArgs {
+    fn from(param_max: f32) -> Self {
+        Self::new(param_max)
+    }
+}
+
+pub type FxinfoParamMaxHandler = Box<dyn FnMut(FxinfoParamMaxArgs) + Send + 'static>;
+
+pub struct FxinfoParamMax {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub ident: String,
+    pub param_idx: i32,
+}
+
+impl FxinfoParamMax {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/fxinfo/{ident}/param/{param_idx}/max";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/fxinfo/{}/param/{}/max", self.ident, self.param_idx)
+    }
+}
+
+impl std::fmt::Display for FxinfoParamMax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/max
+impl Bind<FxinfoParamMaxArgs> for FxinfoParamMax {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(FxinfoParamMaxArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/max
+impl Subscribe<FxinfoParamMaxArgs> for FxinfoParamMax {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(FxinfoParamMaxArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.ident).is_err() {
+            return false;
+        }
+        let osc_address = format!("/fxinfo/{}/param/{}/max", self.ident, self.param_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl FxinfoParamMax {
+    fn add_handler(&mut self, subscription: Subscription, handler: FxinfoParamMaxHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.ident).is_err() {
+            return;
+        }
+        let osc_address = format!("/fxinfo/{}/param/{}/max", self.ident, self.param_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_fxinfo_param_max_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/max
+impl Query for FxinfoParamMax {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.ident)?;
+        let osc_address = format!("/fxinfo/{}/param/{}/max", self.ident, self.param_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_fxinfo_param_max_args(msg: &rosc::OscMessage) -> Result<FxinfoParamMaxArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(FxinfoParamMaxArgs {
+        param_max: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/max
+impl QueryWithResponse<FxinfoParamMaxArgs> for FxinfoParamMax {
+    fn query_response(&self) -> Result<Receiver<FxinfoParamMaxArgs>, Self::Error> {
+        check_address_segment(&self.ident)?;
+        let osc_address = format!("/fxinfo/{}/param/{}/max", self.ident, self.param_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_fxinfo_param_max_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for FxinfoParamMax {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct FxinfoArgs {}
+
+pub type FxinfoHandler = Box<dyn FnMut(FxinfoArgs) + Send + 'static>;
+
+pub struct Fxinfo {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+}
+
+impl Fxinfo {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/fxinfo";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Fxinfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /fxinfo
+impl Query for Fxinfo {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/fxinfo");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_fxinfo_args(_msg: &rosc::OscMessage) -> Result<FxinfoArgs, String> {
+    Ok(FxinfoArgs {})
+}
+
+impl DynEndpoint for Fxinfo {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct PlayArgs {
+    pub playing: bool, // true means the transport is playing
+}
+
+impl PlayArgs {
+    pub fn new(playing: bool) -> Self {
+        Self { playing }
+    }
+}
+
+impl From<bool> for PlayArgs {
+    fn from(playing: bool) -> Self {
+        Self::new(playing)
+    }
+}
+
+pub type PlayHandler = Box<dyn FnMut(PlayArgs) + Send + 'static>;
+
+pub struct Play {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+}
+
+impl Play {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/play";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Play {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /play
+impl SetMessage<PlayArgs> for Play {
+    type Error = OscError;
+    fn set_message(&self, args: PlayArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/play");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.playing)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /play
+impl Set<PlayArgs> for Play {
+    type Error = OscError;
+    fn set(&mut self, args: PlayArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl Play {
+    pub fn set_value(&mut self, value: impl Into<PlayArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /play
+impl Bind<PlayArgs> for Play {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(PlayArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /play
+impl Subscribe<PlayArgs> for Play {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(PlayArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/play");
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl Play {
+    fn add_handler(&mut self, subscription: Subscription, handler: PlayHandler) {
+        let osc_address = format!("/play");
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_play_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /play
+impl Query for Play {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/play");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_play_args(msg: &rosc::OscMessage) -> Result<PlayArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(PlayArgs {
+        playing: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /play
+impl QueryWithResponse<PlayArgs> for Play {
+    fn query_response(&self) -> Result<Receiver<PlayArgs>, Self::Error> {
+        let osc_address = format!("/play");
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_play_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for Play {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let playing = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(PlayArgs::new(playing))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct StopArgs {}
+
+pub type StopHandler = Box<dyn FnMut(StopArgs) + Send + 'static>;
+
+pub struct Stop {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+}
+
+impl Stop {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/stop";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Stop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /stop
+impl SetMessage<StopArgs> for Stop {
+    type Error = OscError;
+    fn set_message(&self, args: StopArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/stop");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /stop
+impl Set<StopArgs> for Stop {
+    type Error = OscError;
+    fn set(&mut self, args: StopArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_stop_args(_msg: &rosc::OscMessage) -> Result<StopArgs, String> {
+    Ok(StopArgs {})
+}
+
+impl DynEndpoint for Stop {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, _value: ParamValue) -> Result<(), OscError> {
+        self.set(StopArgs {})
+    }
+}
+
+#[derive(Debug)]
+pub struct RecordArgs {
+    pub recording: bool, // true means the transport is recording
+}
+
+impl RecordArgs {
+    pub fn new(recording: bool) -> Self {
+        Self { recording }
+    }
+}
+
+impl From<bool> for RecordArgs {
+    fn from(recording: bool) -> Self {
+        Self::new(recording)
+    }
+}
+
+pub type RecordHandler = Box<dyn FnMut(RecordArgs) + Send + 'static>;
+
+pub struct Record {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+}
+
+impl Record {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/record";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /record
+impl SetMessage<RecordArgs> for Record {
+    type Error = OscError;
+    fn set_message(&self, args: RecordArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/record");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.recording)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /record
+impl Set<RecordArgs> for Record {
+    type Error = OscError;
+    fn set(&mut self, args: RecordArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl Record {
+    pub fn set_value(&mut self, value: impl Into<RecordArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /record
+impl Bind<RecordArgs> for Record {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(RecordArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /record
+impl Subscribe<RecordArgs> for Record {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(RecordArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/record");
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl Record {
+    fn add_handler(&mut self, subscription: Subscription, handler: RecordHandler) {
+        let osc_address = format!("/record");
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_record_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /record
+impl Query for Record {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/record");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_record_args(msg: &rosc::OscMessage) -> Result<RecordArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(RecordArgs {
+        recording: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /record
+impl QueryWithResponse<RecordArgs> for Record {
+    fn query_response(&self) -> Result<Receiver<RecordArgs>, Self::Error> {
+        let osc_address = format!("/record");
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_record_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for Record {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let recording = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(RecordArgs::new(recording))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct RepeatArgs {
+    pub repeat: bool, // true means playback loops over the time selection
+}
+
+impl RepeatArgs {
+    pub fn new(repeat: bool) -> Self {
+        Self { repeat }
+    }
+}
+
+impl From<bool> for RepeatArgs {
+    fn from(repeat: bool) -> Self {
+        Self::new(repeat)
+    }
+}
+
+pub type RepeatHandler = Box<dyn FnMut(RepeatArgs) + Send + 'static>;
+
+pub struct Repeat {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+}
+
+impl Repeat {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/repeat";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Repeat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /repeat
+impl SetMessage<RepeatArgs> for Repeat {
+    type Error = OscError;
+    fn set_message(&self, args: RepeatArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/repeat");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.repeat)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /repeat
+impl Set<RepeatArgs> for Repeat {
+    type Error = OscError;
+    fn set(&mut self, args: RepeatArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl Repeat {
+    pub fn set_value(&mut self, value: impl Into<RepeatArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /repeat
+impl Bind<RepeatArgs> for Repeat {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(RepeatArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /repeat
+impl Subscribe<RepeatArgs> for Repeat {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(RepeatArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/repeat");
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl Repeat {
+    fn add_handler(&mut self, subscription: Subscription, handler: RepeatHandler) {
+        let osc_address = format!("/repeat");
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_repeat_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /repeat
+impl Query for Repeat {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/repeat");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_repeat_args(msg: &rosc::OscMessage) -> Result<RepeatArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(RepeatArgs {
+        repeat: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /repeat
+impl QueryWithResponse<RepeatArgs> for Repeat {
+    fn query_response(&self) -> Result<Receiver<RepeatArgs>, Self::Error> {
+        let osc_address = format!("/repeat");
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_repeat_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for Repeat {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let repeat = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(RepeatArgs::new(repeat))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct RewindArgs {
+    pub held: bool, // true rewinds until sent again with false
+}
+
+impl RewindArgs {
+    pub fn new(held: bool) -> Self {
+        Self { held }
+    }
+}
+
+impl From<bool> for RewindArgs {
+    fn from(held: bool) -> Self {
+        Self::new(held)
+    }
+}
+
+pub type RewindHandler = Box<dyn FnMut(RewindArgs) + Send + 'static>;
+
+pub struct Rewind {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+}
+
+impl Rewind {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/rewind";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Rewind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /rewind
+impl SetMessage<RewindArgs> for Rewind {
+    type Error = OscError;
+    fn set_message(&self, args: RewindArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/rewind");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.held)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /rewind
+impl Set<RewindArgs> for Rewind {
+    type Error = OscError;
+    fn set(&mut self, args: RewindArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl Rewind {
+    pub fn set_value(&mut self, value: impl Into<RewindArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+fn parse_rewind_args(msg: &rosc::OscMessage) -> Result<RewindArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(RewindArgs {
+        held: decode::required(&osc_args, 0)?,
+    })
+}
+
+impl DynEndpoint for Rewind {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let held = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(RewindArgs::new(held))
+    }
+}
+
+#[derive(Debug)]
+pub struct ForwardArgs {
+    pub held: bool, // true fast forwards until sent again with false
+}
+
+impl ForwardArgs {
+    pub fn new(held: bool) -> Self {
+        Self { held }
+    }
+}
+
+impl From<bool> for ForwardArgs {
+    fn from(held: bool) -> Self {
+        Self::new(held)
+    }
+}
+
+pub type ForwardHandler = Box<dyn FnMut(ForwardArgs) + Send + 'static>;
+
+pub struct Forward {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+}
+
+impl Forward {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/forward";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Forward {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /forward
+impl SetMessage<ForwardArgs> for Forward {
+    type Error = OscError;
+    fn set_message(&self, args: ForwardArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/forward");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.held)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /forward
+impl Set<ForwardArgs> for Forward {
+    type Error = OscError;
+    fn set(&mut self, args: ForwardArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl Forward {
+    pub fn set_value(&mut self, value: impl Into<ForwardArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+fn parse_forward_args(msg: &rosc::OscMessage) -> Result<ForwardArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(ForwardArgs {
+        held: decode::required(&osc_args, 0)?,
+    })
+}
+
+impl DynEndpoint for Forward {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let held = match value {
+            ParamValue::Bool(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes bool, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(ForwardArgs::new(held))
+    }
+}
+
+#[derive(Debug)]
+pub struct TimeArgs {
+    pub time: f32, // position of the play cursor in seconds
+}
+
+impl TimeArgs {
+    pub fn new(time: f32) -> Self {
+        Self { time }
+    }
+}
+
+impl From<f32> for TimeArgs {
+    fn from(time: f32) -> Self {
+        Self::new(time)
+    }
+}
+
+pub type TimeHandler = Box<dyn FnMut(TimeArgs) + Send + 'static>;
+
+pub struct Time {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+}
+
+impl Time {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/time";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /time
+impl SetMessage<TimeArgs> for Time {
+    type Error = OscError;
+    fn set_message(&self, args: TimeArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/time");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.time)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /time
+impl Set<TimeArgs> for Time {
+    type Error = OscError;
+    fn set(&mut self, args: TimeArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl Time {
+    pub fn set_value(&mut self, value: impl Into<TimeArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /time
+impl Bind<TimeArgs> for Time {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TimeArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /time
+impl Subscribe<TimeArgs> for Time {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TimeArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/time");
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl Time {
+    fn add_handler(&mut self, subscription: Subscription, handler: TimeHandler) {
+        let osc_address = format!("/time");
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_time_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /time
+impl Query for Time {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/time");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_time_args(msg: &rosc::OscMessage) -> Result<TimeArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(TimeArgs {
+        time: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /time
+impl QueryWithResponse<TimeArgs> for Time {
+    fn query_response(&self) -> Result<Receiver<TimeArgs>, Self::Error> {
+        let osc_address = format!("/time");
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_time_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for Time {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let time = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TimeArgs::new(time))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct TempoArgs {
+    pub bpm: f32, // tempo of the project at the play cursor, in beats per minute
+}
+
+impl TempoArgs {
+    pub fn new(bpm: f32) -> Self {
+        Self { bpm }
+    }
+}
+
+impl From<f32> for TempoArgs {
+    fn from(bpm: f32) -> Self {
+        Self::new(bpm)
+    }
+}
+
+pub type TempoHandler = Box<dyn FnMut(TempoArgs) + Send + 'static>;
+
+pub struct Tempo {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+}
+
+impl Tempo {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/tempo";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Tempo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /tempo
+impl SetMessage<TempoArgs> for Tempo {
+    type Error = OscError;
+    fn set_message(&self, args: TempoArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/tempo");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.bpm)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /tempo
+impl Set<TempoArgs> for Tempo {
+    type Error = OscError;
+    fn set(&mut self, args: TempoArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl Tempo {
+    pub fn set_value(&mut self, value: impl Into<TempoArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /tempo
+impl Bind<TempoArgs> for Tempo {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TempoArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /tempo
+impl Subscribe<TempoArgs> for Tempo {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TempoArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/tempo");
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl Tempo {
+    fn add_handler(&mut self, subscription: Subscription, handler: TempoHandler) {
+        let osc_address = format!("/tempo");
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_tempo_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /tempo
+impl Query for Tempo {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/tempo");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_tempo_args(msg: &rosc::OscMessage) -> Result<TempoArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(TempoArgs {
+        bpm: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /tempo
+impl QueryWithResponse<TempoArgs> for Tempo {
+    fn query_response(&self) -> Result<Receiver<TempoArgs>, Self::Error> {
+        let osc_address = format!("/tempo");
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_tempo_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for Tempo {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let bpm = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(TempoArgs::new(bpm))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct ActiveProjectArgs {
+    pub project_guid: String, // GUID of the project in the active tab, reported again whenever another tab becomes active
+}
+
+impl ActiveProjectArgs {
+    pub fn new(project_guid: impl Into<String>) -> Self {
+        Self {
+            project_guid: project_guid.into(),
+        }
+    }
+}
+
+impl From<String> for ActiveProjectArgs {
+    fn from(project_guid: String) -> Self {
+        Self::new(project_guid)
+    }
+}
+
+impl From<&str> for ActiveProjectArgs {
+    fn from(project_guid: &str) -> Self {
+        Self::new(project_guid)
+    }
+}
+
+pub type ActiveProjectHandler = Box<dyn FnMut(ActiveProjectArgs) + Send + 'static>;
+
+pub struct ActiveProject {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+}
+
+impl ActiveProject {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/project/guid";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for ActiveProject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /project/guid
+impl Bind<ActiveProjectArgs> for ActiveProject {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(ActiveProjectArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /project/guid
+impl Subscribe<ActiveProjectArgs> for ActiveProject {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(ActiveProjectArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/project/guid");
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl ActiveProject {
+    fn add_handler(&mut self, subscription: Subscription, handler: ActiveProjectHandler) {
+        let osc_address = format!("/project/guid");
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_active_project_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /project/guid
+impl Query for ActiveProject {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/project/guid");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_active_project_args(msg: &rosc::OscMessage) -> Result<ActiveProjectArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(ActiveProjectArgs {
+        project_guid: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /project/guid
+impl QueryWithResponse<ActiveProjectArgs> for ActiveProject {
+    fn query_response(&self) -> Result<Receiver<ActiveProjectArgs>, Self::Error> {
+        let osc_address = format!("/project/guid");
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_active_project_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for ActiveProject {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct ProjectNameArgs {
+    pub name: String, // name of the project, as shown on its tab
+}
+
+impl ProjectNameArgs {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl From<String> for ProjectNameArgs {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&str> for ProjectNameArgs {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+pub type ProjectNameHandler = Box<dyn FnMut(ProjectNameArgs) + Send + 'static>;
+
+pub struct ProjectName {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub project_guid: crate::guid::ProjectGuid,
+}
+
+impl ProjectName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/project/{project_guid}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/project/{}/name", self.project_guid)
+    }
+}
+
+impl std::fmt::Display for ProjectName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /project/{project_guid}/name
+impl Bind<ProjectNameArgs> for ProjectName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(ProjectNameArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /project/{project_guid}/name
+impl Subscribe<ProjectNameArgs> for ProjectName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(ProjectNameArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.project_guid).is_err() {
+            return false;
+        }
+        let osc_address = format!("/project/{}/name", self.project_guid);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl ProjectName {
+    fn add_handler(&mut self, subscription: Subscription, handler: ProjectNameHandler) {
+        // Nothing can arrive on an address this segment can't be part of
+        if check_address_segment(&self.project_guid).is_err() {
+            return;
+        }
+        let osc_address = format!("/project/{}/name", self.project_guid);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_project_name_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /project/{project_guid}/name
+impl Query for ProjectName {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.project_guid)?;
+        let osc_address = format!("/project/{}/name", self.project_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_project_name_args(msg: &rosc::OscMessage) -> Result<ProjectNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(ProjectNameArgs {
+        name: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /project/{project_guid}/name
+impl QueryWithResponse<ProjectNameArgs> for ProjectName {
+    fn query_response(&self) -> Result<Receiver<ProjectNameArgs>, Self::Error> {
+        check_address_segment(&self.project_guid)?;
+        let osc_address = format!("/project/{}/name", self.project_guid);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_project_name_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for ProjectName {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct ActionArgs {
+    pub command_id: i32, // command ID of an action in the main section of Reaper's action list
+}
+
+impl ActionArgs {
+    pub fn new(command_id: i32) -> Self {
+        Self { command_id }
+    }
+}
+
+impl From<i32> for ActionArgs {
+    fn from(command_id: i32) -> Self {
+        Self::new(command_id)
+    }
+}
+
+pub type ActionHandler = Box<dyn FnMut(ActionArgs) + Send + 'static>;
+
+pub struct Action {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+}
+
+impl Action {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/action";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /action
+impl SetMessage<ActionArgs> for Action {
+    type Error = OscError;
+    fn set_message(&self, args: ActionArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/action");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Int(args.command_id)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /action
+impl Set<ActionArgs> for Action {
+    type Error = OscError;
+    fn set(&mut self, args: ActionArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl Action {
+    pub fn set_value(&mut self, value: impl Into<ActionArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+fn parse_action_args(msg: &rosc::OscMessage) -> Result<ActionArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(ActionArgs {
+        command_id: decode::required(&osc_args, 0)?,
+    })
+}
+
+impl DynEndpoint for Action {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let command_id = match value {
+            ParamValue::Int(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes int, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(ActionArgs::new(command_id))
+    }
+}
+
+#[derive(Debug)]
+pub struct RunActionArgs {}
+
+pub type RunActionHandler = Box<dyn FnMut(RunActionArgs) + Send + 'static>;
+
+pub struct RunAction {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    pub command_id: i32,
+}
+
+impl RunAction {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/action/{command_id}";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/action/{}", self.command_id)
+    }
+}
+
+impl std::fmt::Display for RunAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /action/{command_id}
+impl SetMessage<RunActionArgs> for RunAction {
+    type Error = OscError;
+    fn set_message(&self, args: RunActionArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/action/{}", self.command_id);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /action/{command_id}
+impl Set<RunActionArgs> for RunAction {
+    type Error = OscError;
+    fn set(&mut self, args: RunActionArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_run_action_args(_msg: &rosc::OscMessage) -> Result<RunActionArgs, String> {
+    Ok(RunActionArgs {})
+}
+
+impl DynEndpoint for RunAction {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, _value: ParamValue) -> Result<(), OscError> {
+        self.set(RunActionArgs {})
+    }
+}
+
+#[derive(Debug)]
+pub struct ActionStateArgs {
+    pub on: bool, // true means the action's toggle is on, e.g. the metronome is enabled
+}
+
+impl ActionStateArgs {
+    pub fn new(on: bool) -> Self {
+        Self { on }
+    }
+}
+
+impl From<bool> for ActionStateArgs {
+    fn from(on: bool) -> Self {
+        Self::new(on)
+    }
+}
+
+pub type ActionStateHandler = Box<dyn FnMut(ActionStateArgs) + Send + 'static>;
+
+pub struct ActionState {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub command_id: i32,
+}
+
+impl ActionState {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/action/{command_id}/state";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/action/{}/state", self.command_id)
+    }
+}
+
+impl std::fmt::Display for ActionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /action/{command_id}/state
+impl Bind<ActionStateArgs> for ActionState {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(ActionStateArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /action/{command_id}/state
+impl Subscribe<ActionStateArgs> for ActionState {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(ActionStateArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/action/{}/state", self.command_id);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl ActionState {
+    fn add_handler(&mut self, subscription: Subscription, handler: ActionStateHandler) {
+        let osc_address = format!("/action/{}/state", self.command_id);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_action_state_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /action/{command_id}/state
+impl Query for ActionState {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/action/{}/state", self.command_id);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_action_state_args(msg: &rosc::OscMessage) -> Result<ActionStateArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(ActionStateArgs {
+        on: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /action/{command_id}/state
+impl QueryWithResponse<ActionStateArgs> for ActionState {
+    fn query_response(&self) -> Result<Receiver<ActionStateArgs>, Self::Error> {
+        let osc_address = format!("/action/{}/state", self.command_id);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_action_state_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for ActionState {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct MarkerAllArgs {}
+
+pub type MarkerAllHandler = Box<dyn FnMut(MarkerAllArgs) + Send + 'static>;
+
+pub struct MarkerAll {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+}
+
+impl MarkerAll {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/marker/all";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for MarkerAll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /marker/all
+impl Query for MarkerAll {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/marker/all");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_marker_all_args(_msg: &rosc::OscMessage) -> Result<MarkerAllArgs, String> {
+    Ok(MarkerAllArgs {})
+}
+
+impl DynEndpoint for MarkerAll {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct MarkerNameArgs {
+    pub name: String, // name of the marker
+}
+
+impl MarkerNameArgs {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl From<String> for MarkerNameArgs {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&str> for MarkerNameArgs {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+pub type MarkerNameHandler = Box<dyn FnMut(MarkerNameArgs) + Send + 'static>;
+
+pub struct MarkerName {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub marker_idx: i32,
+}
+
+impl MarkerName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/marker/{marker_idx}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/marker/{}/name", self.marker_idx)
+    }
+}
+
+impl std::fmt::Display for MarkerName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /marker/{marker_idx}/name
+impl SetMessage<MarkerNameArgs> for MarkerName {
+    type Error = OscError;
+    fn set_message(&self, args: MarkerNameArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/marker/{}/name", self.marker_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::String(args.name)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /marker/{marker_idx}/name
+impl Set<MarkerNameArgs> for MarkerName {
+    type Error = OscError;
+    fn set(&mut self, args: MarkerNameArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl MarkerName {
+    pub fn set_value(&mut self, value: impl Into<MarkerNameArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /marker/{marker_idx}/name
+impl Bind<MarkerNameArgs> for MarkerName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(MarkerNameArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /marker/{marker_idx}/name
+impl Subscribe<MarkerNameArgs> for MarkerName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(MarkerNameArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/marker/{}/name", self.marker_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl MarkerName {
+    fn add_handler(&mut self, subscription: Subscription, handler: MarkerNameHandler) {
+        let osc_address = format!("/marker/{}/name", self.marker_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_marker_name_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /marker/{marker_idx}/name
+impl Query for MarkerName {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/marker/{}/name", self.marker_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_marker_name_args(msg: &rosc::OscMessage) -> Result<MarkerNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(MarkerNameArgs {
+        name: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /marker/{marker_idx}/name
+impl QueryWithResponse<MarkerNameArgs> for MarkerName {
+    fn query_response(&self) -> Result<Receiver<MarkerNameArgs>, Self::Error> {
+        let osc_address = format!("/marker/{}/name", self.marker_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_marker_name_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for MarkerName {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let name = match value {
+            ParamValue::String(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes string, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(MarkerNameArgs::new(name))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct MarkerPositionArgs {
+    pub position: f32, // position of the marker in seconds
+}
+
+impl MarkerPositionArgs {
+    pub fn new(position: f32) -> Self {
+        Self { position }
+    }
+}
+
+impl From<f32> for MarkerPositionArgs {
+    fn from(position: f32) -> Self {
+        Self::new(position)
+    }
+}
+
+pub type MarkerPositionHandler = Box<dyn FnMut(MarkerPositionArgs) + Send + 'static>;
+
+pub struct MarkerPosition {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub marker_idx: i32,
+}
+
+impl MarkerPosition {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/marker/{marker_idx}/position";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/marker/{}/position", self.marker_idx)
+    }
+}
+
+impl std::fmt::Display for MarkerPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /marker/{marker_idx}/position
+impl SetMessage<MarkerPositionArgs> for MarkerPosition {
+    type Error = OscError;
+    fn set_message(&self, args: MarkerPositionArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/marker/{}/position", self.marker_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.position)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /marker/{marker_idx}/position
+impl Set<MarkerPositionArgs> for MarkerPosition {
+    type Error = OscError;
+    fn set(&mut self, args: MarkerPositionArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl MarkerPosition {
+    pub fn set_value(&mut self, value: impl Into<MarkerPositionArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /marker/{marker_idx}/position
+impl Bind<MarkerPositionArgs> for MarkerPosition {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(MarkerPositionArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /marker/{marker_idx}/position
+impl Subscribe<MarkerPositionArgs> for MarkerPosition {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(MarkerPositionArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/marker/{}/position", self.marker_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl MarkerPosition {
+    fn add_handler(&mut self, subscription: Subscription, handler: MarkerPositionHandler) {
+        let osc_address = format!("/marker/{}/position", self.marker_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_marker_position_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /marker/{marker_idx}/position
+impl Query for MarkerPosition {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/marker/{}/position", self.marker_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_marker_position_args(msg: &rosc::OscMessage) -> Result<MarkerPositionArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(MarkerPositionArgs {
+        position: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /marker/{marker_idx}/position
+impl QueryWithResponse<MarkerPositionArgs> for MarkerPosition {
+    fn query_response(&self) -> Result<Receiver<MarkerPositionArgs>, Self::Error> {
+        let osc_address = format!("/marker/{}/position", self.marker_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_marker_position_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for MarkerPosition {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let position = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(MarkerPositionArgs::new(position))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct RegionAllArgs {}
+
+pub type RegionAllHandler = Box<dyn FnMut(RegionAllArgs) + Send + 'static>;
+
+pub struct RegionAll {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+}
+
+impl RegionAll {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/region/all";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for RegionAll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /region/all
+impl Query for RegionAll {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/region/all");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_region_all_args(_msg: &rosc::OscMessage) -> Result<RegionAllArgs, String> {
+    Ok(RegionAllArgs {})
+}
+
+impl DynEndpoint for RegionAll {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct RegionNameArgs {
+    pub name: String, // name of the region
+}
+
+impl RegionNameArgs {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl From<String> for RegionNameArgs {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&str> for RegionNameArgs {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+pub type RegionNameHandler = Box<dyn FnMut(RegionNameArgs) + Send + 'static>;
+
+pub struct RegionName {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub region_idx: i32,
+}
+
+impl RegionName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/region/{region_idx}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/region/{}/name", self.region_idx)
+    }
+}
+
+impl std::fmt::Display for RegionName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /region/{region_idx}/name
+impl SetMessage<RegionNameArgs> for RegionName {
+    type Error = OscError;
+    fn set_message(&self, args: RegionNameArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/region/{}/name", self.region_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::String(args.name)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /region/{region_idx}/name
+impl Set<RegionNameArgs> for RegionName {
+    type Error = OscError;
+    fn set(&mut self, args: RegionNameArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl RegionName {
+    pub fn set_value(&mut self, value: impl Into<RegionNameArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /region/{region_idx}/name
+impl Bind<RegionNameArgs> for RegionName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(RegionNameArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /region/{region_idx}/name
+impl Subscribe<RegionNameArgs> for RegionName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(RegionNameArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/region/{}/name", self.region_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl RegionName {
+    fn add_handler(&mut self, subscription: Subscription, handler: RegionNameHandler) {
+        let osc_address = format!("/region/{}/name", self.region_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_region_name_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /region/{region_idx}/name
+impl Query for RegionName {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/region/{}/name", self.region_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_region_name_args(msg: &rosc::OscMessage) -> Result<RegionNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(RegionNameArgs {
+        name: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /region/{region_idx}/name
+impl QueryWithResponse<RegionNameArgs> for RegionName {
+    fn query_response(&self) -> Result<Receiver<RegionNameArgs>, Self::Error> {
+        let osc_address = format!("/region/{}/name", self.region_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_region_name_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for RegionName {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let name = match value {
+            ParamValue::String(value) => value,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes string, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(RegionNameArgs::new(name))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct RegionStartArgs {
+    pub start: f32, // where the region starts, in seconds
+}
+
+impl RegionStartArgs {
+    pub fn new(start: f32) -> Self {
+        Self { start }
+    }
+}
+
+impl From<f32> for RegionStartArgs {
+    fn from(start: f32) -> Self {
+        Self::new(start)
+    }
+}
+
+pub type RegionStartHandler = Box<dyn FnMut(RegionStartArgs) + Send + 'static>;
+
+pub struct RegionStart {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub region_idx: i32,
+}
+
+impl RegionStart {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/region/{region_idx}/start";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/region/{}/start", self.region_idx)
+    }
+}
+
+impl std::fmt::Display for RegionStart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /region/{region_idx}/start
+impl SetMessage<RegionStartArgs> for RegionStart {
+    type Error = OscError;
+    fn set_message(&self, args: RegionStartArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/region/{}/start", self.region_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.start)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /region/{region_idx}/start
+impl Set<RegionStartArgs> for RegionStart {
+    type Error = OscError;
+    fn set(&mut self, args: RegionStartArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl RegionStart {
+    pub fn set_value(&mut self, value: impl Into<RegionStartArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /region/{region_idx}/start
+impl Bind<RegionStartArgs> for RegionStart {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(RegionStartArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /region/{region_idx}/start
+impl Subscribe<RegionStartArgs> for RegionStart {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(RegionStartArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/region/{}/start", self.region_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl RegionStart {
+    fn add_handler(&mut self, subscription: Subscription, handler: RegionStartHandler) {
+        let osc_address = format!("/region/{}/start", self.region_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_region_start_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /region/{region_idx}/start
+impl Query for RegionStart {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/region/{}/start", self.region_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_region_start_args(msg: &rosc::OscMessage) -> Result<RegionStartArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(RegionStartArgs {
+        start: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /region/{region_idx}/start
+impl QueryWithResponse<RegionStartArgs> for RegionStart {
+    fn query_response(&self) -> Result<Receiver<RegionStartArgs>, Self::Error> {
+        let osc_address = format!("/region/{}/start", self.region_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_region_start_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for RegionStart {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let start = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(RegionStartArgs::new(start))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+#[derive(Debug)]
+pub struct RegionEndArgs {
+    pub end: f32, // where the region ends, in seconds
+}
+
+impl RegionEndArgs {
+    pub fn new(end: f32) -> Self {
+        Self { end }
+    }
+}
+
+impl From<f32> for RegionEndArgs {
+    fn from(end: f32) -> Self {
+        Self::new(end)
+    }
+}
+
+pub type RegionEndHandler = Box<dyn FnMut(RegionEndArgs) + Send + 'static>;
+
+pub struct RegionEnd {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    handlers: Handlers,
+    pending: PendingResponses,
+    pub region_idx: i32,
+}
+
+impl RegionEnd {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/region/{region_idx}/end";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/region/{}/end", self.region_idx)
+    }
+}
+
+impl std::fmt::Display for RegionEnd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /region/{region_idx}/end
+impl SetMessage<RegionEndArgs> for RegionEnd {
+    type Error = OscError;
+    fn set_message(&self, args: RegionEndArgs) -> Result<rosc::OscMessage, Self::Error> {
+        let osc_address = format!("/region/{}/end", self.region_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.end)],
+        };
+        Ok(osc_msg)
+    }
+}
+
+/// /region/{region_idx}/end
+impl Set<RegionEndArgs> for RegionEnd {
+    type Error = OscError;
+    fn set(&mut self, args: RegionEndArgs) -> Result<(), Self::Error> {
+        let osc_msg = self.set_message(args)?;
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+impl RegionEnd {
+    pub fn set_value(&mut self, value: impl Into<RegionEndArgs>) -> Result<(), OscError> {
+        self.set(value.into())
+    }
+}
+
+/// /region/{region_idx}/end
+impl Bind<RegionEndArgs> for RegionEnd {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(RegionEndArgs) + Send + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /region/{region_idx}/end
+impl Subscribe<RegionEndArgs> for RegionEnd {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(RegionEndArgs) + Send + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let osc_address = format!("/region/{}/end", self.region_idx);
+        let mut handlers = self.handlers.lock().unwrap();
+        let Some(bound) = handlers.get_mut(&osc_address) else {
+            return false;
+        };
+        let count = bound.len();
+        bound.retain(|(other, _)| *other != subscription);
+        let removed = bound.len() != count;
+        if bound.is_empty() {
+            handlers.remove(&osc_address);
+        }
+        removed
+    }
+}
+
+impl RegionEnd {
+    fn add_handler(&mut self, subscription: Subscription, handler: RegionEndHandler) {
+        let osc_address = format!("/region/{}/end", self.region_idx);
+        let mut handler = handler;
+        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {
+            if let Ok(args) = parse_region_end_args(msg) {
+                handler(args);
+            }
+        })));
+        self.handlers
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push((subscription, handler));
+    }
+}
+
+/// /region/{region_idx}/end
+impl Query for RegionEnd {
+    type Error = OscError;
+    fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/region/{}/end", self.region_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_region_end_args(msg: &rosc::OscMessage) -> Result<RegionEndArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(RegionEndArgs {
+        end: decode::required(&osc_args, 0)?,
+    })
+}
+
+/// /region/{region_idx}/end
+impl QueryWithResponse<RegionEndArgs> for RegionEnd {
+    fn query_response(&self) -> Result<Receiver<RegionEndArgs>, Self::Error> {
+        let osc_address = format!("/region/{}/end", self.region_idx);
+        let (tx, rx) = bounded(1);
+        // Register before sending so a fast response can't slip past us
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(osc_address)
+            .or_default()
+            .push(Box::new(move |msg| {
+                if let Ok(args) = parse_region_end_args(msg) {
+                    let _ = tx.send(args);
+                }
+            }));
+        self.query()?;
+        Ok(rx)
+    }
+}
+
+impl DynEndpoint for RegionEnd {
+    fn address_template(&self) -> &'static str {
+        Self::ADDRESS_TEMPLATE
+    }
+
+    fn address(&self) -> String {
+        self.osc_address()
+    }
+
+    fn is_settable(&self) -> bool {
+        true
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        let end = match value {
+            ParamValue::Float(value) => value,
+            ParamValue::Double(value) => value as f32,
+            value => {
+                return Err(OscError::Unsupported(format!(
+                    "{} takes float, not {:?}",
+                    Self::ADDRESS_TEMPLATE,
+                    value
+                )));
+            }
+        };
+        self.set(RegionEndArgs::new(end))
+    }
+
+    fn is_queryable(&self) -> bool {
+        true
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        self.query()
+    }
+}
+
+pub mod context {
+    use crate::osc::route_context::ContextTrait;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Action {
+        pub command_id: i32,
+    }
+
+    impl ContextTrait for Action {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Fxinfo {
+        pub ident: String,
+    }
+
+    impl ContextTrait for Fxinfo {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct FxinfoParam {
+        pub ident: String,
+        pub param_idx: i32,
+    }
+
+    impl ContextTrait for FxinfoParam {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Marker {
+        pub marker_idx: i32,
+    }
+
+    impl ContextTrait for Marker {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Project {
+        pub project_guid: crate::guid::ProjectGuid,
+    }
+
+    impl ContextTrait for Project {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Region {
+        pub region_idx: i32,
+    }
+
+    impl ContextTrait for Region {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Track {
+        pub track_guid: crate::guid::TrackGuid,
+    }
+
+    impl ContextTrait for Track {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct TrackFx {
+        pub track_guid: crate::guid::TrackGuid,
+        pub fx_idx: i32,
+    }
+
+    impl ContextTrait for TrackFx {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct TrackFxParam {
+        pub track_guid: crate::guid::TrackGuid,
+        pub fx_idx: i32,
+        pub param_idx: i32,
+    }
+
+    impl ContextTrait for TrackFxParam {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct TrackSend {
+        pub track_guid: crate::guid::TrackGuid,
+        pub send_index: i32,
+    }
+
+    impl ContextTrait for TrackSend {}
+}
+
+pub mod context_kind {
+    use super::context;
+    use crate::osc::route_context::ContextKindTrait;
+    use regex::Regex;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Action {}
+
+    impl ContextKindTrait for Action {
+        type Context = context::Action;
+
+        fn context_name() -> &'static str {
+            "Action"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::Action> {
+            static RE: once_cell::sync::Lazy<Regex> =
+                once_cell::sync::Lazy::new(|| Regex::new(r"^/action/([^/]+)$").unwrap());
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::Action {
+                    command_id: caps[1].parse().ok()?,
+                })
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Fxinfo {}
+
+    impl ContextKindTrait for Fxinfo {
+        type Context = context::Fxinfo;
+
+        fn context_name() -> &'static str {
+            "Fxinfo"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::Fxinfo> {
+            static RE: once_cell::sync::Lazy<Regex> =
+                once_cell::sync::Lazy::new(|| Regex::new(r"^/fxinfo/([^/]+)/name$").unwrap());
+            RE.captures(osc_address).map(|caps| context::Fxinfo {
+                ident: caps[1].to_string(),
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct FxinfoParam {}
+
+    impl ContextKindTrait for FxinfoParam {
+        type Context = context::FxinfoParam;
+
+        fn context_name() -> &'static str {
+            "FxinfoParam"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::FxinfoParam> {
+            static RE: once_cell::sync::Lazy<Regex> = once_cell::sync::Lazy::new(|| {
+                Regex::new(r"^/fxinfo/([^/]+)/param/([^/]+)/name$").unwrap()
+            });
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::FxinfoParam {
+                    ident: caps[1].to_string(),
+                    param_idx: caps[2].parse().ok()?,
+                })
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Marker {}
+
+    impl ContextKindTrait for Marker {
+        type Context = context::Marker;
+
+        fn context_name() -> &'static str {
+            "Marker"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::Marker> {
+            static RE: once_cell::sync::Lazy<Regex> =
+                once_cell::sync::Lazy::new(|| Regex::new(r"^/marker/([^/]+)/name$").unwrap());
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::Marker {
+                    marker_idx: caps[1].parse().ok()?,
+                })
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Project {}
+
+    impl ContextKindTrait for Project {
+        type Context = context::Project;
+
+        fn context_name() -> &'static str {
+            "Project"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::Project> {
+            static RE: once_cell::sync::Lazy<Regex> =
+                once_cell::sync::Lazy::new(|| Regex::new(r"^/project/([^/]+)/name$").unwrap());
+            RE.captures(osc_address).map(|caps| context::Project {
+                project_guid: caps[1].into(),
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Region {}
+
+    impl ContextKindTrait for Region {
+        type Context = context::Region;
+
+        fn context_name() -> &'static str {
+            "Region"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::Region> {
+            static RE: once_cell::sync::Lazy<Regex> =
+                once_cell::sync::Lazy::new(|| Regex::new(r"^/region/([^/]+)/name$").unwrap());
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::Region {
+                    region_idx: caps[1].parse().ok()?,
+                })
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Track {}
+
+    impl ContextKindTrait for Track {
+        type Context = context::Track;
+
+        fn context_name() -> &'static str {
+            "Track"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::Track> {
+            static RE: once_cell::sync::Lazy<Regex> =
+                once_cell::sync::Lazy::new(|| Regex::new(r"^/track/([^/]+)/index$").unwrap());
+            RE.captures(osc_address).map(|caps| context::Track {
+                track_guid: caps[1].into(),
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct TrackFx {}
+
+    impl ContextKindTrait for TrackFx {
+        type Context = context::TrackFx;
+
+        fn context_name() -> &'static str {
+            "TrackFx"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::TrackFx> {
+            static RE: once_cell::sync::Lazy<Regex> = once_cell::sync::Lazy::new(|| {
+                Regex::new(r"^/track/([^/]+)/fx/([^/]+)/guid$").unwrap()
+            });
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::TrackFx {
+                    track_guid: caps[1].into(),
+                    fx_idx: caps[2].parse().ok()?,
+                })
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct TrackFxParam {}
+
+    impl ContextKindTrait for TrackFxParam {
+        type Context = context::TrackFxParam;
+
+        fn context_name() -> &'static str {
+            "TrackFxParam"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::TrackFxParam> {
+            static RE: once_cell::sync::Lazy<Regex> = once_cell::sync::Lazy::new(|| {
+                Regex::new(r"^/track/([^/]+)/fx/([^/]+)/param/([^/]+)/name$").unwrap()
+            });
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::TrackFxParam {
+                    track_guid: caps[1].into(),
+                    fx_idx: caps[2].parse().ok()?,
+                    param_idx: caps[3].parse().ok()?,
+                })
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct TrackSend {}
+
+    impl ContextKindTrait for TrackSend {
+        type Context = context::TrackSend;
+
+        fn context_name() -> &'static str {
+            "TrackSend"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::TrackSend> {
+            static RE: once_cell::sync::Lazy<Regex> = once_cell::sync::Lazy::new(|| {
+                Regex::new(r"^/track/([^/]+)/send/([^/]+)/guid$").unwrap()
+            });
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::TrackSend {
+                    track_guid: caps[1].into(),
+                    send_index: caps[2].parse().ok()?,
+                })
+            })
+        }
+    }
+}
+
+pub struct Reaper {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    pending: PendingResponses,
+    handlers: Handlers,
+}
+
+impl Reaper {
+    /// Sends everything over `socket` as it is, e.g. to whatever it's connected to.
+    pub fn new(socket: Arc<dyn OscTransport>) -> Self {
+        Self {
+            socket,
+            destination: None,
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            handlers: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Sends everything over `socket` to `destination`, so the socket can be left unconnected
+    /// and still hear from anyone.
+    pub fn with_destination(socket: Arc<dyn OscTransport>, destination: SocketAddr) -> Self {
+        Self {
+            destination: Some(destination),
+            ..Self::new(socket)
+        }
+    }
+}
+
+impl Reaper {
+    /// Starts a bundle of sets to send together.
+    pub fn bundle(&self) -> BundleBuilder {
+        BundleBuilder {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            messages: Vec::new(),
+        }
+    }
+}
+
+/// Sets gathered up to go to Reaper in a single OSC bundle, so they all take effect at once,
+/// e.g. a track's volume, pan and mute when recalling a scene.
+pub struct BundleBuilder {
+    socket: Arc<dyn OscTransport>,
+    destination: Option<SocketAddr>,
+    // The message for each set along with where its binary arguments are, or why it couldn't
+    // be built
+    messages: Vec<Result<(rosc::OscMessage, &'static [usize]), OscError>>,
+}
+
+impl BundleBuilder {
+    /// Adds setting `endpoint` to `args`. Nothing is sent until the bundle is committed.
+    pub fn set<A, E>(&mut self, endpoint: &E, args: A) -> &mut Self
+    where
+        E: SetMessage<A, Error = OscError>,
+    {
+        self.messages
+            .push(endpoint.set_message(args).map(|msg| (msg, E::BINARY_ARGS)));
+        self
+    }
+
+    /// How many sets have been added.
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Sends every set whose message could be built, returning how each set went in the order
+    /// they were added. Errs only if the bundle couldn't be sent at all.
+    ///
+    /// A lone message is sent as it is, since there's nothing to keep it together with.
+    pub fn commit(self) -> Result<Vec<Result<(), OscError>>, OscError> {
+        let mut content = Vec::new();
+        let mut binary = Vec::new();
+        let results: Vec<Result<(), OscError>> = self
+            .messages
+            .into_iter()
+            .map(|msg| {
+                let (msg, binary_args) = msg?;
+                content.push(rosc::OscPacket::Message(msg));
+                binary.push(binary_args);
+                Ok(())
+            })
+            .collect();
+        let packet = match content.len() {
+            0 => return Ok(results),
+            1 => content.remove(0),
+            // Timetag 1 means "immediately"
+            _ => rosc::OscPacket::Bundle(rosc::OscBundle {
+                timetag: rosc::OscTime {
+                    seconds: 0,
+                    fractional: 1,
+                },
+                content,
+            }),
+        };
+        let buf = decode::encode_packet(&packet, &binary)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination)?,
+            None => self.socket.send(&buf)?,
+        };
+        Ok(results)
+    }
+}
+
+/// The value of any endpoint's argument
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParamValue {
+    Int(i32),
+    Float(f32),
+    Double(f64),
+    Bool(bool),
+    String(String),
+}
+
+impl From<i32> for ParamValue {
+    fn from(value: i32) -> Self {
+        ParamValue::Int(value)
+    }
+}
+
+impl From<f32> for ParamValue {
+    fn from(value: f32) -> Self {
+        ParamValue::Float(value)
+    }
+}
+
+impl From<f64> for ParamValue {
+    fn from(value: f64) -> Self {
+        ParamValue::Double(value)
+    }
+}
+
+impl From<bool> for ParamValue {
+    fn from(value: bool) -> Self {
+        ParamValue::Bool(value)
+    }
+}
+
+impl From<String> for ParamValue {
+    fn from(value: String) -> Self {
+        ParamValue::String(value)
+    }
+}
+
+impl From<&str> for ParamValue {
+    fn from(value: &str) -> Self {
+        ParamValue::String(value.to_string())
+    }
+}
+
+impl std::fmt::Display for ParamValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamValue::Int(value) => write!(f, "{}", value),
+            ParamValue::Float(value) => write!(f, "{}", value),
+            ParamValue::Double(value) => write!(f, "{}", value),
+            ParamValue::Bool(value) => write!(f, "{}", value),
+            ParamValue::String(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
+/// Any endpoint, set and queried without knowing its Args struct.
+///
+/// An endpoint is set from the value of its one required argument, leaving any optional ones out.
+/// Endpoints without arguments ignore the value. Setting an endpoint that can't be set, or
+/// querying one that can't be queried, fails with OscError::Unsupported.
+pub trait DynEndpoint {
+    /// The OSC address of this endpoint, with its parameters in braces
+    fn address_template(&self) -> &'static str;
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    fn address(&self) -> String;
+
+    fn is_settable(&self) -> bool {
+        false
+    }
+
+    fn is_queryable(&self) -> bool {
+        false
+    }
+
+    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {
+        Err(OscError::Unsupported(format!(
+            "{} can't be set to {:?}",
+            self.address_template(),
+            value
+        )))
+    }
+
+    fn query_dyn(&self) -> Result<(), OscError> {
+        Err(OscError::Unsupported(format!(
+            "{} can't be queried",
+            self.address_template()
+        )))
+    }
+}
+
+impl Reaper {
+    pub fn num_tracks(&self) -> NumTracks {
+        NumTracks {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+    pub fn track_all_guids(&self) -> TrackAllGuids {
+        TrackAllGuids {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+    pub fn track_index(&self, track_guid: crate::guid::TrackGuid) -> TrackIndex {
+        TrackIndex {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_delete(&self, track_guid: crate::guid::TrackGuid) -> TrackDelete {
+        TrackDelete {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_name(&self, track_guid: crate::guid::TrackGuid) -> TrackName {
+        TrackName {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_selected(&self, track_guid: crate::guid::TrackGuid) -> TrackSelected {
+        TrackSelected {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_volume(&self, track_guid: crate::guid::TrackGuid) -> TrackVolume {
+        TrackVolume {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_pan(&self, track_guid: crate::guid::TrackGuid) -> TrackPan {
+        TrackPan {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_mute(&self, track_guid: crate::guid::TrackGuid) -> TrackMute {
+        TrackMute {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_solo(&self, track_guid: crate::guid::TrackGuid) -> TrackSolo {
+        TrackSolo {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_rec_arm(&self, track_guid: crate::guid::TrackGuid) -> TrackRecArm {
+        TrackRecArm {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_width(&self, track_guid: crate::guid::TrackGuid) -> TrackWidth {
+        TrackWidth {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_phase(&self, track_guid: crate::guid::TrackGuid) -> TrackPhase {
+        TrackPhase {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_input_gain(&self, track_guid: crate::guid::TrackGuid) -> TrackInputGain {
+        TrackInputGain {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn master_volume(&self) -> MasterVolume {
+        MasterVolume {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+    pub fn master_pan(&self) -> MasterPan {
+        MasterPan {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+    pub fn master_mute(&self) -> MasterMute {
+        MasterMute {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+    pub fn track_send_count(&self, track_guid: crate::guid::TrackGuid) -> TrackSendCount {
+        TrackSendCount {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_send_guid(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        send_index: i32,
+    ) -> TrackSendGuid {
+        TrackSendGuid {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+            send_index: send_index,
+        }
+    }
+    pub fn track_send_volume(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        send_index: i32,
+    ) -> TrackSendVolume {
+        TrackSendVolume {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+            send_index: send_index,
+        }
+    }
+    pub fn track_send_pan(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        send_index: i32,
+    ) -> TrackSendPan {
+        TrackSendPan {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+            send_index: send_index,
+        }
+    }
+    pub fn track_send_mute(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        send_index: i32,
+    ) -> TrackSendMute {
+        TrackSendMute {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+            send_index: send_index,
+        }
+    }
+    pub fn track_color(&self, track_guid: crate::guid::TrackGuid) -> TrackColor {
+        TrackColor {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_fx_guid(&self, track_guid: crate::guid::TrackGuid, fx_idx: i32) -> TrackFxGuid {
+        TrackFxGuid {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+        }
+    }
+    pub fn track_fx_name(&self, track_guid: crate::guid::TrackGuid, fx_idx: i32) -> TrackFxName {
+        TrackFxName {
             socket: self.socket.clone(),
-            handler: None,
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
             track_guid: track_guid,
             fx_idx: fx_idx,
         }
     }
-    pub fn track_fx_enabled(&self, track_guid: String, fx_idx: i32) -> TrackFxEnabled {
+    pub fn track_fx_enabled(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        fx_idx: i32,
+    ) -> TrackFxEnabled {
         TrackFxEnabled {
             socket: self.socket.clone(),
-            handler: None,
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+        }
+    }
+    pub fn track_fx_bypass(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        fx_idx: i32,
+    ) -> TrackFxBypass {
+        TrackFxBypass {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+        }
+    }
+    pub fn track_fx_wet(&self, track_guid: crate::guid::TrackGuid, fx_idx: i32) -> TrackFxWet {
+        TrackFxWet {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
             track_guid: track_guid,
             fx_idx: fx_idx,
         }
     }
-    pub fn track_fx_param_count(&self, track_guid: String, fx_idx: i32) -> TrackFxParamCount {
+    pub fn track_fx_param_count(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        fx_idx: i32,
+    ) -> TrackFxParamCount {
         TrackFxParamCount {
             socket: self.socket.clone(),
-            handler: None,
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
             track_guid: track_guid,
             fx_idx: fx_idx,
         }
     }
     pub fn track_fx_param_name(
         &self,
-        track_guid: String,
+        track_guid: crate::guid::TrackGuid,
         fx_idx: i32,
         param_idx: i32,
     ) -> TrackFxParamName {
         TrackFxParamName {
             socket: self.socket.clone(),
-            handler: None,
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
             track_guid: track_guid,
             fx_idx: fx_idx,
             param_idx: param_idx,
@@ -1684,13 +11904,15 @@ impl Reaper {
     }
     pub fn track_fx_param_value(
         &self,
-        track_guid: String,
+        track_guid: crate::guid::TrackGuid,
         fx_idx: i32,
         param_idx: i32,
     ) -> TrackFxParamValue {
         TrackFxParamValue {
             socket: self.socket.clone(),
-            handler: None,
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
             track_guid: track_guid,
             fx_idx: fx_idx,
             param_idx: param_idx,
@@ -1698,13 +11920,15 @@ impl Reaper {
     }
     pub fn track_fx_param_min(
         &self,
-        track_guid: String,
+        track_guid: crate::guid::TrackGuid,
         fx_idx: i32,
         param_idx: i32,
     ) -> TrackFxParamMin {
         TrackFxParamMin {
             socket: self.socket.clone(),
-            handler: None,
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
             track_guid: track_guid,
             fx_idx: fx_idx,
             param_idx: param_idx,
@@ -1712,22 +11936,24 @@ impl Reaper {
     }
     pub fn track_fx_param_max(
         &self,
-        track_guid: String,
+        track_guid: crate::guid::TrackGuid,
         fx_idx: i32,
         param_idx: i32,
     ) -> TrackFxParamMax {
         TrackFxParamMax {
             socket: self.socket.clone(),
-            handler: None,
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
             track_guid: track_guid,
             fx_idx: fx_idx,
             param_idx: param_idx,
         }
     }
-    pub fn track_fx_info(&self, track_guid: String, fx_idx: i32) -> TrackFxInfo {
+    pub fn track_fx_info(&self, track_guid: crate::guid::TrackGuid, fx_idx: i32) -> TrackFxInfo {
         TrackFxInfo {
             socket: self.socket.clone(),
-            handler: None,
+            destination: self.destination,
             track_guid: track_guid,
             fx_idx: fx_idx,
         }
@@ -1735,436 +11961,3043 @@ impl Reaper {
     pub fn fxinfo_name(&self, ident: String) -> FxinfoName {
         FxinfoName {
             socket: self.socket.clone(),
-            handler: None,
+            destination: self.destination,
+            handlers: self.handlers.clone(),
             ident: ident,
         }
     }
     pub fn fxinfo_param_count(&self, ident: String) -> FxinfoParamCount {
         FxinfoParamCount {
             socket: self.socket.clone(),
-            handler: None,
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
             ident: ident,
         }
     }
     pub fn fxinfo_param_name(&self, ident: String, param_idx: i32) -> FxinfoParamName {
         FxinfoParamName {
             socket: self.socket.clone(),
-            handler: None,
-            ident: ident,
-            param_idx: param_idx,
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            ident: ident,
+            param_idx: param_idx,
+        }
+    }
+    pub fn fxinfo_param_min(&self, ident: String, param_idx: i32) -> FxinfoParamMin {
+        FxinfoParamMin {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            ident: ident,
+            param_idx: param_idx,
+        }
+    }
+    pub fn fxinfo_param_max(&self, ident: String, param_idx: i32) -> FxinfoParamMax {
+        FxinfoParamMax {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            ident: ident,
+            param_idx: param_idx,
+        }
+    }
+    pub fn fxinfo(&self) -> Fxinfo {
+        Fxinfo {
+            socket: self.socket.clone(),
+            destination: self.destination,
+        }
+    }
+    pub fn play(&self) -> Play {
+        Play {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+    pub fn stop(&self) -> Stop {
+        Stop {
+            socket: self.socket.clone(),
+            destination: self.destination,
+        }
+    }
+    pub fn record(&self) -> Record {
+        Record {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+    pub fn repeat(&self) -> Repeat {
+        Repeat {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+    pub fn rewind(&self) -> Rewind {
+        Rewind {
+            socket: self.socket.clone(),
+            destination: self.destination,
+        }
+    }
+    pub fn forward(&self) -> Forward {
+        Forward {
+            socket: self.socket.clone(),
+            destination: self.destination,
+        }
+    }
+    pub fn time(&self) -> Time {
+        Time {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+    pub fn tempo(&self) -> Tempo {
+        Tempo {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+    pub fn active_project(&self) -> ActiveProject {
+        ActiveProject {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+        }
+    }
+    pub fn project_name(&self, project_guid: crate::guid::ProjectGuid) -> ProjectName {
+        ProjectName {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            project_guid: project_guid,
+        }
+    }
+    pub fn action(&self) -> Action {
+        Action {
+            socket: self.socket.clone(),
+            destination: self.destination,
+        }
+    }
+    pub fn run_action(&self, command_id: i32) -> RunAction {
+        RunAction {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            command_id: command_id,
+        }
+    }
+    pub fn action_state(&self, command_id: i32) -> ActionState {
+        ActionState {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            command_id: command_id,
+        }
+    }
+    pub fn marker_all(&self) -> MarkerAll {
+        MarkerAll {
+            socket: self.socket.clone(),
+            destination: self.destination,
+        }
+    }
+    pub fn marker_name(&self, marker_idx: i32) -> MarkerName {
+        MarkerName {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            marker_idx: marker_idx,
+        }
+    }
+    pub fn marker_position(&self, marker_idx: i32) -> MarkerPosition {
+        MarkerPosition {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            marker_idx: marker_idx,
+        }
+    }
+    pub fn region_all(&self) -> RegionAll {
+        RegionAll {
+            socket: self.socket.clone(),
+            destination: self.destination,
         }
     }
-    pub fn fxinfo_param_min(&self, ident: String, param_idx: i32) -> FxinfoParamMin {
-        FxinfoParamMin {
+    pub fn region_name(&self, region_idx: i32) -> RegionName {
+        RegionName {
             socket: self.socket.clone(),
-            handler: None,
-            ident: ident,
-            param_idx: param_idx,
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            region_idx: region_idx,
         }
     }
-    pub fn fxinfo_param_max(&self, ident: String, param_idx: i32) -> FxinfoParamMax {
-        FxinfoParamMax {
+    pub fn region_start(&self, region_idx: i32) -> RegionStart {
+        RegionStart {
             socket: self.socket.clone(),
-            handler: None,
-            ident: ident,
-            param_idx: param_idx,
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            region_idx: region_idx,
         }
     }
-    pub fn fxinfo(&self) -> Fxinfo {
-        Fxinfo {
+    pub fn region_end(&self, region_idx: i32) -> RegionEnd {
+        RegionEnd {
             socket: self.socket.clone(),
-            handler: None,
+            destination: self.destination,
+            handlers: self.handlers.clone(),
+            pending: self.pending.clone(),
+            region_idx: region_idx,
         }
     }
 }
 
-/// Try to match an OSC address against a pattern, extracting arguments.
-/// E.g. addr: "/track/abc123/pan", pattern: "/track/{}/pan" -> Some(vec!["abc123"])
-fn match_addr(addr: &str, pattern: &str) -> Option<Vec<String>> {
-    let addr_parts: Vec<&str> = addr.split('/').filter(|s| !s.is_empty()).collect();
-    let pat_parts: Vec<&str> = pattern.split('/').filter(|s| !s.is_empty()).collect();
-    if addr_parts.len() != pat_parts.len() {
-        return None;
+impl Reaper {
+    /// The Action context, if anything is bound to one of its routes
+    pub fn try_action(&self, command_id: i32) -> Option<context::Action> {
+        let prefix = format!("/action/{}/", command_id);
+        let bound = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|addr| addr.starts_with(&prefix));
+        bound.then_some(context::Action { command_id })
+    }
+
+    /// Every Action context something is bound to one of the routes of, in no particular order
+    pub fn actions(&self) -> impl Iterator<Item = context::Action> {
+        static RE: once_cell::sync::Lazy<regex::Regex> =
+            once_cell::sync::Lazy::new(|| regex::Regex::new(r"^/action/([^/]+)/").unwrap());
+        let found: std::collections::HashSet<context::Action> = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .filter_map(|addr| {
+                let caps = RE.captures(addr)?;
+                Some(context::Action {
+                    command_id: caps[1].parse().ok()?,
+                })
+            })
+            .collect();
+        found.into_iter()
+    }
+
+    /// The Fxinfo context, if anything is bound to one of its routes
+    pub fn try_fxinfo(&self, ident: String) -> Option<context::Fxinfo> {
+        let prefix = format!("/fxinfo/{}/", ident);
+        let bound = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|addr| addr.starts_with(&prefix));
+        bound.then_some(context::Fxinfo { ident })
+    }
+
+    /// Every Fxinfo context something is bound to one of the routes of, in no particular order
+    pub fn fxinfos(&self) -> impl Iterator<Item = context::Fxinfo> {
+        static RE: once_cell::sync::Lazy<regex::Regex> =
+            once_cell::sync::Lazy::new(|| regex::Regex::new(r"^/fxinfo/([^/]+)/").unwrap());
+        let found: std::collections::HashSet<context::Fxinfo> = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .filter_map(|addr| {
+                let caps = RE.captures(addr)?;
+                Some(context::Fxinfo {
+                    ident: caps[1].to_string(),
+                })
+            })
+            .collect();
+        found.into_iter()
+    }
+
+    /// The FxinfoParam context, if anything is bound to one of its routes
+    pub fn try_fxinfo_param(&self, ident: String, param_idx: i32) -> Option<context::FxinfoParam> {
+        let prefix = format!("/fxinfo/{}/param/{}/", ident, param_idx);
+        let bound = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|addr| addr.starts_with(&prefix));
+        bound.then_some(context::FxinfoParam { ident, param_idx })
+    }
+
+    /// Every FxinfoParam context something is bound to one of the routes of, in no particular order
+    pub fn fxinfo_params(&self) -> impl Iterator<Item = context::FxinfoParam> {
+        static RE: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+            regex::Regex::new(r"^/fxinfo/([^/]+)/param/([^/]+)/").unwrap()
+        });
+        let found: std::collections::HashSet<context::FxinfoParam> = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .filter_map(|addr| {
+                let caps = RE.captures(addr)?;
+                Some(context::FxinfoParam {
+                    ident: caps[1].to_string(),
+                    param_idx: caps[2].parse().ok()?,
+                })
+            })
+            .collect();
+        found.into_iter()
+    }
+
+    /// The Marker context, if anything is bound to one of its routes
+    pub fn try_marker(&self, marker_idx: i32) -> Option<context::Marker> {
+        let prefix = format!("/marker/{}/", marker_idx);
+        let bound = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|addr| addr.starts_with(&prefix));
+        bound.then_some(context::Marker { marker_idx })
+    }
+
+    /// Every Marker context something is bound to one of the routes of, in no particular order
+    pub fn markers(&self) -> impl Iterator<Item = context::Marker> {
+        static RE: once_cell::sync::Lazy<regex::Regex> =
+            once_cell::sync::Lazy::new(|| regex::Regex::new(r"^/marker/([^/]+)/").unwrap());
+        let found: std::collections::HashSet<context::Marker> = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .filter_map(|addr| {
+                let caps = RE.captures(addr)?;
+                Some(context::Marker {
+                    marker_idx: caps[1].parse().ok()?,
+                })
+            })
+            .collect();
+        found.into_iter()
+    }
+
+    /// The Project context, if anything is bound to one of its routes
+    pub fn try_project(&self, project_guid: crate::guid::ProjectGuid) -> Option<context::Project> {
+        let prefix = format!("/project/{}/", project_guid);
+        let bound = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|addr| addr.starts_with(&prefix));
+        bound.then_some(context::Project { project_guid })
+    }
+
+    /// Every Project context something is bound to one of the routes of, in no particular order
+    pub fn projects(&self) -> impl Iterator<Item = context::Project> {
+        static RE: once_cell::sync::Lazy<regex::Regex> =
+            once_cell::sync::Lazy::new(|| regex::Regex::new(r"^/project/([^/]+)/").unwrap());
+        let found: std::collections::HashSet<context::Project> = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .filter_map(|addr| {
+                let caps = RE.captures(addr)?;
+                Some(context::Project {
+                    project_guid: caps[1].into(),
+                })
+            })
+            .collect();
+        found.into_iter()
+    }
+
+    /// The Region context, if anything is bound to one of its routes
+    pub fn try_region(&self, region_idx: i32) -> Option<context::Region> {
+        let prefix = format!("/region/{}/", region_idx);
+        let bound = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|addr| addr.starts_with(&prefix));
+        bound.then_some(context::Region { region_idx })
+    }
+
+    /// Every Region context something is bound to one of the routes of, in no particular order
+    pub fn regions(&self) -> impl Iterator<Item = context::Region> {
+        static RE: once_cell::sync::Lazy<regex::Regex> =
+            once_cell::sync::Lazy::new(|| regex::Regex::new(r"^/region/([^/]+)/").unwrap());
+        let found: std::collections::HashSet<context::Region> = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .filter_map(|addr| {
+                let caps = RE.captures(addr)?;
+                Some(context::Region {
+                    region_idx: caps[1].parse().ok()?,
+                })
+            })
+            .collect();
+        found.into_iter()
+    }
+
+    /// The Track context, if anything is bound to one of its routes
+    pub fn try_track(&self, track_guid: crate::guid::TrackGuid) -> Option<context::Track> {
+        let prefix = format!("/track/{}/", track_guid);
+        let bound = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|addr| addr.starts_with(&prefix));
+        bound.then_some(context::Track { track_guid })
+    }
+
+    /// Every Track context something is bound to one of the routes of, in no particular order
+    pub fn tracks(&self) -> impl Iterator<Item = context::Track> {
+        static RE: once_cell::sync::Lazy<regex::Regex> =
+            once_cell::sync::Lazy::new(|| regex::Regex::new(r"^/track/([^/]+)/").unwrap());
+        let found: std::collections::HashSet<context::Track> = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .filter_map(|addr| {
+                let caps = RE.captures(addr)?;
+                Some(context::Track {
+                    track_guid: caps[1].into(),
+                })
+            })
+            .collect();
+        found.into_iter()
+    }
+
+    /// The TrackFx context, if anything is bound to one of its routes
+    pub fn try_track_fx(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        fx_idx: i32,
+    ) -> Option<context::TrackFx> {
+        let prefix = format!("/track/{}/fx/{}/", track_guid, fx_idx);
+        let bound = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|addr| addr.starts_with(&prefix));
+        bound.then_some(context::TrackFx { track_guid, fx_idx })
+    }
+
+    /// Every TrackFx context something is bound to one of the routes of, in no particular order
+    pub fn track_fxs(&self) -> impl Iterator<Item = context::TrackFx> {
+        static RE: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+            regex::Regex::new(r"^/track/([^/]+)/fx/([^/]+)/").unwrap()
+        });
+        let found: std::collections::HashSet<context::TrackFx> = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .filter_map(|addr| {
+                let caps = RE.captures(addr)?;
+                Some(context::TrackFx {
+                    track_guid: caps[1].into(),
+                    fx_idx: caps[2].parse().ok()?,
+                })
+            })
+            .collect();
+        found.into_iter()
     }
-    let mut args = Vec::new();
-    for (a, p) in addr_parts.iter().zip(pat_parts.iter()) {
-        if *p == "{}" {
-            args.push((*a).to_string());
-        } else if *p != *a {
-            return None;
-        }
+
+    /// The TrackFxParam context, if anything is bound to one of its routes
+    pub fn try_track_fx_param(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        fx_idx: i32,
+        param_idx: i32,
+    ) -> Option<context::TrackFxParam> {
+        let prefix = format!("/track/{}/fx/{}/param/{}/", track_guid, fx_idx, param_idx);
+        let bound = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|addr| addr.starts_with(&prefix));
+        bound.then_some(context::TrackFxParam {
+            track_guid,
+            fx_idx,
+            param_idx,
+        })
+    }
+
+    /// Every TrackFxParam context something is bound to one of the routes of, in no particular order
+    pub fn track_fx_params(&self) -> impl Iterator<Item = context::TrackFxParam> {
+        static RE: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+            regex::Regex::new(r"^/track/([^/]+)/fx/([^/]+)/param/([^/]+)/").unwrap()
+        });
+        let found: std::collections::HashSet<context::TrackFxParam> = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .filter_map(|addr| {
+                let caps = RE.captures(addr)?;
+                Some(context::TrackFxParam {
+                    track_guid: caps[1].into(),
+                    fx_idx: caps[2].parse().ok()?,
+                    param_idx: caps[3].parse().ok()?,
+                })
+            })
+            .collect();
+        found.into_iter()
     }
-    Some(args)
-}
 
-pub fn dispatch_osc<F>(reaper: &mut Reaper, msg: rosc::OscMessage, log_unknown: F)
+    /// The TrackSend context, if anything is bound to one of its routes
+    pub fn try_track_send(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        send_index: i32,
+    ) -> Option<context::TrackSend> {
+        let prefix = format!("/track/{}/send/{}/", track_guid, send_index);
+        let bound = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|addr| addr.starts_with(&prefix));
+        bound.then_some(context::TrackSend {
+            track_guid,
+            send_index,
+        })
+    }
+
+    /// Every TrackSend context something is bound to one of the routes of, in no particular order
+    pub fn track_sends(&self) -> impl Iterator<Item = context::TrackSend> {
+        static RE: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+            regex::Regex::new(r"^/track/([^/]+)/send/([^/]+)/").unwrap()
+        });
+        let found: std::collections::HashSet<context::TrackSend> = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .filter_map(|addr| {
+                let caps = RE.captures(addr)?;
+                Some(context::TrackSend {
+                    track_guid: caps[1].into(),
+                    send_index: caps[2].parse().ok()?,
+                })
+            })
+            .collect();
+        found.into_iter()
+    }
+
+    /// Drops every handler bound on a route with params, i.e. within some context such as a
+    /// track, keeping those on routes of the project as a whole. For when the contexts stop
+    /// meaning what they did, e.g. when Reaper switches to another project.
+    pub fn unbind_contexts(&self) {
+        self.handlers.lock().unwrap().retain(|addr, _| {
+            !ROUTES.iter().any(|route| {
+                !route.params.is_empty() && match_address(route.address, addr).is_some()
+            })
+        });
+    }
+}
+
+/// Which way messages to a route go between Reaper and us.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RouteDirection {
+    /// Reaper reports it, and it can't be set
+    FromReaper,
+    /// It's sent to Reaper, which never reports it
+    ToReaper,
+    /// Reaper reports it, and it can be set too
+    Both,
+}
+
+/// A route of the spec, as data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RouteInfo {
+    /// Name of the route's accessor on Reaper
+    pub name: &'static str,
+    /// Name of the struct the accessor returns, which the route's Args are named after
+    pub struct_name: &'static str,
+    /// The route's address, with a `{param}` segment standing in for each param
+    pub address: &'static str,
+    pub direction: RouteDirection,
+    /// Whether Reaper answers a query to it
+    pub queryable: bool,
+    /// Each param's name and type, in the order the address has them
+    pub params: &'static [(&'static str, &'static str)],
+    /// Each argument's name and type, in the order messages carry them
+    pub arguments: &'static [(&'static str, &'static str)],
+}
+
+/// Every route in the spec, in the order it lists them.
+pub const ROUTES: &[RouteInfo] = &[
+    RouteInfo {
+        name: "num_tracks",
+        struct_name: "NumTracks",
+        address: "/num_tracks",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[],
+        arguments: &[("num_tracks", "int")],
+    },
+    RouteInfo {
+        name: "track_all_guids",
+        struct_name: "TrackAllGuids",
+        address: "/track/all_guids",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "track_index",
+        struct_name: "TrackIndex",
+        address: "/track/{track_guid}/index",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("index", "int")],
+    },
+    RouteInfo {
+        name: "track_delete",
+        struct_name: "TrackDelete",
+        address: "/track/{track_guid}/delete",
+        direction: RouteDirection::ToReaper,
+        queryable: false,
+        params: &[("track_guid", "string")],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "track_name",
+        struct_name: "TrackName",
+        address: "/track/{track_guid}/name",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("name", "string")],
+    },
+    RouteInfo {
+        name: "track_selected",
+        struct_name: "TrackSelected",
+        address: "/track/{track_guid}/selected",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("selected", "bool")],
+    },
+    RouteInfo {
+        name: "track_volume",
+        struct_name: "TrackVolume",
+        address: "/track/{track_guid}/volume",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("volume", "float")],
+    },
+    RouteInfo {
+        name: "track_pan",
+        struct_name: "TrackPan",
+        address: "/track/{track_guid}/pan",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("pan", "float")],
+    },
+    RouteInfo {
+        name: "track_mute",
+        struct_name: "TrackMute",
+        address: "/track/{track_guid}/mute",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("mute", "bool")],
+    },
+    RouteInfo {
+        name: "track_solo",
+        struct_name: "TrackSolo",
+        address: "/track/{track_guid}/solo",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("solo", "bool")],
+    },
+    RouteInfo {
+        name: "track_rec_arm",
+        struct_name: "TrackRecArm",
+        address: "/track/{track_guid}/rec-arm",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("rec_arm", "bool")],
+    },
+    RouteInfo {
+        name: "track_width",
+        struct_name: "TrackWidth",
+        address: "/track/{track_guid}/width",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("width", "float")],
+    },
+    RouteInfo {
+        name: "track_phase",
+        struct_name: "TrackPhase",
+        address: "/track/{track_guid}/phase",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("phase", "bool")],
+    },
+    RouteInfo {
+        name: "track_input_gain",
+        struct_name: "TrackInputGain",
+        address: "/track/{track_guid}/input-gain",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("input_gain", "float")],
+    },
+    RouteInfo {
+        name: "master_volume",
+        struct_name: "MasterVolume",
+        address: "/master/volume",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("volume", "float")],
+    },
+    RouteInfo {
+        name: "master_pan",
+        struct_name: "MasterPan",
+        address: "/master/pan",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("pan", "float")],
+    },
+    RouteInfo {
+        name: "master_mute",
+        struct_name: "MasterMute",
+        address: "/master/mute",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("mute", "bool")],
+    },
+    RouteInfo {
+        name: "track_send_count",
+        struct_name: "TrackSendCount",
+        address: "/track/{track_guid}/send_count",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("send_count", "int")],
+    },
+    RouteInfo {
+        name: "track_send_guid",
+        struct_name: "TrackSendGuid",
+        address: "/track/{track_guid}/send/{send_index}/guid",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("track_guid", "string"), ("send_index", "int")],
+        arguments: &[("guid", "string")],
+    },
+    RouteInfo {
+        name: "track_send_volume",
+        struct_name: "TrackSendVolume",
+        address: "/track/{track_guid}/send/{send_index}/volume",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string"), ("send_index", "int")],
+        arguments: &[("volume", "float")],
+    },
+    RouteInfo {
+        name: "track_send_pan",
+        struct_name: "TrackSendPan",
+        address: "/track/{track_guid}/send/{send_index}/pan",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string"), ("send_index", "int")],
+        arguments: &[("pan", "float")],
+    },
+    RouteInfo {
+        name: "track_send_mute",
+        struct_name: "TrackSendMute",
+        address: "/track/{track_guid}/send/{send_index}/mute",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string"), ("send_index", "int")],
+        arguments: &[("mute", "bool")],
+    },
+    RouteInfo {
+        name: "track_color",
+        struct_name: "TrackColor",
+        address: "/track/{track_guid}/color",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("color", "int")],
+    },
+    RouteInfo {
+        name: "track_fx_guid",
+        struct_name: "TrackFxGuid",
+        address: "/track/{track_guid}/fx/{fx_idx}/guid",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[("guid", "string")],
+    },
+    RouteInfo {
+        name: "track_fx_name",
+        struct_name: "TrackFxName",
+        address: "/track/{track_guid}/fx/{fx_idx}/name",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[("name", "string")],
+    },
+    RouteInfo {
+        name: "track_fx_enabled",
+        struct_name: "TrackFxEnabled",
+        address: "/track/{track_guid}/fx/{fx_idx}/enabled",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[("enabled", "bool")],
+    },
+    RouteInfo {
+        name: "track_fx_bypass",
+        struct_name: "TrackFxBypass",
+        address: "/track/{track_guid}/fx/{fx_idx}/bypass",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[("bypass", "bool")],
+    },
+    RouteInfo {
+        name: "track_fx_wet",
+        struct_name: "TrackFxWet",
+        address: "/track/{track_guid}/fx/{fx_idx}/wet",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[("wet", "float")],
+    },
+    RouteInfo {
+        name: "track_fx_param_count",
+        struct_name: "TrackFxParamCount",
+        address: "/track/{track_guid}/fx/{fx_idx}/param_count",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[("param_count", "int")],
+    },
+    RouteInfo {
+        name: "track_fx_param_name",
+        struct_name: "TrackFxParamName",
+        address: "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[
+            ("track_guid", "string"),
+            ("fx_idx", "int"),
+            ("param_idx", "int"),
+        ],
+        arguments: &[("param_name", "string")],
+    },
+    RouteInfo {
+        name: "track_fx_param_value",
+        struct_name: "TrackFxParamValue",
+        address: "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[
+            ("track_guid", "string"),
+            ("fx_idx", "int"),
+            ("param_idx", "int"),
+        ],
+        arguments: &[("value", "float")],
+    },
+    RouteInfo {
+        name: "track_fx_param_min",
+        struct_name: "TrackFxParamMin",
+        address: "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[
+            ("track_guid", "string"),
+            ("fx_idx", "int"),
+            ("param_idx", "int"),
+        ],
+        arguments: &[("min", "float")],
+    },
+    RouteInfo {
+        name: "track_fx_param_max",
+        struct_name: "TrackFxParamMax",
+        address: "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[
+            ("track_guid", "string"),
+            ("fx_idx", "int"),
+            ("param_idx", "int"),
+        ],
+        arguments: &[("max", "float")],
+    },
+    RouteInfo {
+        name: "track_fx_info",
+        struct_name: "TrackFxInfo",
+        address: "/track/{track_guid}/fx/{fx_idx}/info",
+        direction: RouteDirection::ToReaper,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "fxinfo_name",
+        struct_name: "FxinfoName",
+        address: "/fxinfo/{ident}/name",
+        direction: RouteDirection::FromReaper,
+        queryable: false,
+        params: &[("ident", "string")],
+        arguments: &[("name", "string")],
+    },
+    RouteInfo {
+        name: "fxinfo_param_count",
+        struct_name: "FxinfoParamCount",
+        address: "/fxinfo/{ident}/param_count",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("ident", "string")],
+        arguments: &[("param_count", "int")],
+    },
+    RouteInfo {
+        name: "fxinfo_param_name",
+        struct_name: "FxinfoParamName",
+        address: "/fxinfo/{ident}/param/{param_idx}/name",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("ident", "string"), ("param_idx", "int")],
+        arguments: &[("param_name", "string")],
+    },
+    RouteInfo {
+        name: "fxinfo_param_min",
+        struct_name: "FxinfoParamMin",
+        address: "/fxinfo/{ident}/param/{param_idx}/min",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("ident", "string"), ("param_idx", "int")],
+        arguments: &[("param_min", "float")],
+    },
+    RouteInfo {
+        name: "fxinfo_param_max",
+        struct_name: "FxinfoParamMax",
+        address: "/fxinfo/{ident}/param/{param_idx}/max",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("ident", "string"), ("param_idx", "int")],
+        arguments: &[("param_max", "float")],
+    },
+    RouteInfo {
+        name: "fxinfo",
+        struct_name: "Fxinfo",
+        address: "/fxinfo",
+        direction: RouteDirection::ToReaper,
+        queryable: true,
+        params: &[],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "play",
+        struct_name: "Play",
+        address: "/play",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("playing", "bool")],
+    },
+    RouteInfo {
+        name: "stop",
+        struct_name: "Stop",
+        address: "/stop",
+        direction: RouteDirection::ToReaper,
+        queryable: false,
+        params: &[],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "record",
+        struct_name: "Record",
+        address: "/record",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("recording", "bool")],
+    },
+    RouteInfo {
+        name: "repeat",
+        struct_name: "Repeat",
+        address: "/repeat",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("repeat", "bool")],
+    },
+    RouteInfo {
+        name: "rewind",
+        struct_name: "Rewind",
+        address: "/rewind",
+        direction: RouteDirection::ToReaper,
+        queryable: false,
+        params: &[],
+        arguments: &[("held", "bool")],
+    },
+    RouteInfo {
+        name: "forward",
+        struct_name: "Forward",
+        address: "/forward",
+        direction: RouteDirection::ToReaper,
+        queryable: false,
+        params: &[],
+        arguments: &[("held", "bool")],
+    },
+    RouteInfo {
+        name: "time",
+        struct_name: "Time",
+        address: "/time",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("time", "float")],
+    },
+    RouteInfo {
+        name: "tempo",
+        struct_name: "Tempo",
+        address: "/tempo",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("bpm", "float")],
+    },
+    RouteInfo {
+        name: "active_project",
+        struct_name: "ActiveProject",
+        address: "/project/guid",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[],
+        arguments: &[("project_guid", "string")],
+    },
+    RouteInfo {
+        name: "project_name",
+        struct_name: "ProjectName",
+        address: "/project/{project_guid}/name",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("project_guid", "string")],
+        arguments: &[("name", "string")],
+    },
+    RouteInfo {
+        name: "action",
+        struct_name: "Action",
+        address: "/action",
+        direction: RouteDirection::ToReaper,
+        queryable: false,
+        params: &[],
+        arguments: &[("command_id", "int")],
+    },
+    RouteInfo {
+        name: "run_action",
+        struct_name: "RunAction",
+        address: "/action/{command_id}",
+        direction: RouteDirection::ToReaper,
+        queryable: false,
+        params: &[("command_id", "int")],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "action_state",
+        struct_name: "ActionState",
+        address: "/action/{command_id}/state",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("command_id", "int")],
+        arguments: &[("on", "bool")],
+    },
+    RouteInfo {
+        name: "marker_all",
+        struct_name: "MarkerAll",
+        address: "/marker/all",
+        direction: RouteDirection::ToReaper,
+        queryable: true,
+        params: &[],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "marker_name",
+        struct_name: "MarkerName",
+        address: "/marker/{marker_idx}/name",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("marker_idx", "int")],
+        arguments: &[("name", "string")],
+    },
+    RouteInfo {
+        name: "marker_position",
+        struct_name: "MarkerPosition",
+        address: "/marker/{marker_idx}/position",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("marker_idx", "int")],
+        arguments: &[("position", "float")],
+    },
+    RouteInfo {
+        name: "region_all",
+        struct_name: "RegionAll",
+        address: "/region/all",
+        direction: RouteDirection::ToReaper,
+        queryable: true,
+        params: &[],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "region_name",
+        struct_name: "RegionName",
+        address: "/region/{region_idx}/name",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("region_idx", "int")],
+        arguments: &[("name", "string")],
+    },
+    RouteInfo {
+        name: "region_start",
+        struct_name: "RegionStart",
+        address: "/region/{region_idx}/start",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("region_idx", "int")],
+        arguments: &[("start", "float")],
+    },
+    RouteInfo {
+        name: "region_end",
+        struct_name: "RegionEnd",
+        address: "/region/{region_idx}/end",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("region_idx", "int")],
+        arguments: &[("end", "float")],
+    },
+];
+
+/// Every route in the spec, for tools that work with any route rather than particular ones.
+pub fn all_routes() -> &'static [RouteInfo] {
+    ROUTES
+}
+
+pub fn dispatch_osc<F>(reaper: &Reaper, msg: rosc::OscMessage, log_unknown: F)
 where
-    F: Fn(&str),
+    F: Fn(&str, &Unhandled),
 {
+    // Resolve any queries waiting on this address before the bound handlers run
+    let waiters = reaper.pending.lock().unwrap().remove(&msg.addr);
+    for waiter in waiters.into_iter().flatten() {
+        waiter(&msg);
+    }
+    call_bound_handlers(reaper, &msg);
     let addr = msg.addr.as_str();
-    if let Some(args) = match_addr(addr, "/num_tracks") {
-        let mut endpoint = reaper.num_tracks();
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(num_tracks) = msg.args.get(0) {
-                handler(NumTracksArgs {
-                    num_tracks: num_tracks.clone().int().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/all_guids") {
-        let mut endpoint = reaper.track_all_guids();
-        if let Some(handler) = &mut endpoint.handler {}
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/index") {
-        let track_guid = args[0].clone();
-        let mut endpoint = reaper.track_index(track_guid);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(index) = msg.args.get(0) {
-                handler(TrackIndexArgs {
-                    index: index.clone().int().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/delete") {
-        let track_guid = args[0].clone();
-        let mut endpoint = reaper.track_delete(track_guid);
-        if let Some(handler) = &mut endpoint.handler {}
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/name") {
-        let track_guid = args[0].clone();
-        let mut endpoint = reaper.track_name(track_guid);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(name) = msg.args.get(0) {
-                handler(TrackNameArgs {
-                    name: name.clone().string().unwrap().clone(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/selected") {
-        let track_guid = args[0].clone();
-        let mut endpoint = reaper.track_selected(track_guid);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(selected) = msg.args.get(0) {
-                handler(TrackSelectedArgs {
-                    selected: selected.clone().bool().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/volume") {
-        let track_guid = args[0].clone();
-        let mut endpoint = reaper.track_volume(track_guid);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(volume) = msg.args.get(0) {
-                handler(TrackVolumeArgs {
-                    volume: volume.clone().float().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/pan") {
-        let track_guid = args[0].clone();
-        let mut endpoint = reaper.track_pan(track_guid);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(pan) = msg.args.get(0) {
-                handler(TrackPanArgs {
-                    pan: pan.clone().float().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/mute") {
-        let track_guid = args[0].clone();
-        let mut endpoint = reaper.track_mute(track_guid);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(mute) = msg.args.get(0) {
-                handler(TrackMuteArgs {
-                    mute: mute.clone().bool().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/solo") {
-        let track_guid = args[0].clone();
-        let mut endpoint = reaper.track_solo(track_guid);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(solo) = msg.args.get(0) {
-                handler(TrackSoloArgs {
-                    solo: solo.clone().bool().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/rec-arm") {
-        let track_guid = args[0].clone();
-        let mut endpoint = reaper.track_rec_arm(track_guid);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(rec_arm) = msg.args.get(0) {
-                handler(TrackRecArmArgs {
-                    rec_arm: rec_arm.clone().bool().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/send/{send_index}/guid") {
-        let send_index: i32 = args[0].parse().unwrap();
-        let track_guid = args[1].clone();
-        let mut endpoint = reaper.track_send_guid(track_guid, send_index);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(guid) = msg.args.get(0) {
-                handler(TrackSendGuidArgs {
-                    guid: guid.clone().string().unwrap().clone(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/send/{send_index}/volume") {
-        let send_index: i32 = args[0].parse().unwrap();
-        let track_guid = args[1].clone();
-        let mut endpoint = reaper.track_send_volume(track_guid, send_index);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(volume) = msg.args.get(0) {
-                handler(TrackSendVolumeArgs {
-                    volume: volume.clone().float().unwrap(),
-                });
+    let mut undecodable = Vec::new();
+    let matched = match is_pattern(addr) {
+        true => dispatch_every_route(reaper, &msg, addr, &mut undecodable),
+        false => dispatch_by_segment(reaper, &msg, addr, &mut undecodable),
+    };
+    if !matched {
+        log_unknown(addr, &Unhandled::UnknownAddress);
+    }
+    for reason in undecodable {
+        log_unknown(addr, &Unhandled::BadArgs(reason));
+    }
+}
+
+fn dispatch_every_route(
+    reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let mut matched = false;
+    matched |= dispatch_num_tracks_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_all_guids_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_index_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_delete_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_selected_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_volume_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_pan_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_mute_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_solo_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_rec_arm_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_width_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_phase_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_input_gain_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_master_volume_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_master_pan_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_master_mute_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_send_count_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_send_guid_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_send_volume_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_send_pan_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_send_mute_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_color_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_guid_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_enabled_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_bypass_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_wet_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_param_count_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_param_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_param_value_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_param_min_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_param_max_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_info_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_fxinfo_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_fxinfo_param_count_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_fxinfo_param_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_fxinfo_param_min_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_fxinfo_param_max_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_fxinfo_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_play_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_stop_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_record_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_repeat_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_rewind_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_forward_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_time_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_tempo_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_active_project_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_project_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_action_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_run_action_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_action_state_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_marker_all_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_marker_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_marker_position_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_region_all_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_region_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_region_start_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_region_end_route(reaper, msg, addr, undecodable);
+    matched
+}
+
+// Only tries the routes whose literal segments match the address's, so the cost of a
+// dispatch doesn't grow with the number of routes
+fn dispatch_by_segment(
+    reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let segments: Vec<&str> = addr.split('/').filter(|s| !s.is_empty()).collect();
+    let mut matched = false;
+    match segments.first().copied() {
+        Some("action") => {
+            matched |= dispatch_action_route(reaper, msg, addr, undecodable);
+            matched |= dispatch_run_action_route(reaper, msg, addr, undecodable);
+            if let Some("state") = segments.get(2).copied() {
+                matched |= dispatch_action_state_route(reaper, msg, addr, undecodable);
             }
         }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/send/{send_index}/pan") {
-        let send_index: i32 = args[0].parse().unwrap();
-        let track_guid = args[1].clone();
-        let mut endpoint = reaper.track_send_pan(track_guid, send_index);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(pan) = msg.args.get(0) {
-                handler(TrackSendPanArgs {
-                    pan: pan.clone().float().unwrap(),
-                });
+        Some("forward") => {
+            matched |= dispatch_forward_route(reaper, msg, addr, undecodable);
+        }
+        Some("fxinfo") => {
+            matched |= dispatch_fxinfo_route(reaper, msg, addr, undecodable);
+            match segments.get(2).copied() {
+                Some("name") => {
+                    matched |= dispatch_fxinfo_name_route(reaper, msg, addr, undecodable);
+                }
+                Some("param") => match segments.get(4).copied() {
+                    Some("max") => {
+                        matched |= dispatch_fxinfo_param_max_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("min") => {
+                        matched |= dispatch_fxinfo_param_min_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("name") => {
+                        matched |= dispatch_fxinfo_param_name_route(reaper, msg, addr, undecodable);
+                    }
+                    _ => {}
+                },
+                Some("param_count") => {
+                    matched |= dispatch_fxinfo_param_count_route(reaper, msg, addr, undecodable);
+                }
+                _ => {}
             }
         }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/color") {
-        let track_guid = args[0].clone();
-        let mut endpoint = reaper.track_color(track_guid);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(color) = msg.args.get(0) {
-                handler(TrackColorArgs {
-                    color: color.clone().int().unwrap(),
-                });
+        Some("marker") => {
+            if let Some("all") = segments.get(1).copied() {
+                matched |= dispatch_marker_all_route(reaper, msg, addr, undecodable);
+            }
+            match segments.get(2).copied() {
+                Some("name") => {
+                    matched |= dispatch_marker_name_route(reaper, msg, addr, undecodable);
+                }
+                Some("position") => {
+                    matched |= dispatch_marker_position_route(reaper, msg, addr, undecodable);
+                }
+                _ => {}
             }
         }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/fx/{fx_idx}/guid") {
-        let fx_idx: i32 = args[0].parse().unwrap();
-        let track_guid = args[1].clone();
-        let mut endpoint = reaper.track_fx_guid(track_guid, fx_idx);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(guid) = msg.args.get(0) {
-                handler(TrackFxGuidArgs {
-                    guid: guid.clone().string().unwrap().clone(),
-                });
+        Some("master") => match segments.get(1).copied() {
+            Some("mute") => {
+                matched |= dispatch_master_mute_route(reaper, msg, addr, undecodable);
+            }
+            Some("pan") => {
+                matched |= dispatch_master_pan_route(reaper, msg, addr, undecodable);
             }
+            Some("volume") => {
+                matched |= dispatch_master_volume_route(reaper, msg, addr, undecodable);
+            }
+            _ => {}
+        },
+        Some("num_tracks") => {
+            matched |= dispatch_num_tracks_route(reaper, msg, addr, undecodable);
+        }
+        Some("play") => {
+            matched |= dispatch_play_route(reaper, msg, addr, undecodable);
         }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/fx/{fx_idx}/name") {
-        let fx_idx: i32 = args[0].parse().unwrap();
-        let track_guid = args[1].clone();
-        let mut endpoint = reaper.track_fx_name(track_guid, fx_idx);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(name) = msg.args.get(0) {
-                handler(TrackFxNameArgs {
-                    name: name.clone().string().unwrap().clone(),
-                });
+        Some("project") => {
+            if let Some("guid") = segments.get(1).copied() {
+                matched |= dispatch_active_project_route(reaper, msg, addr, undecodable);
             }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/fx/{fx_idx}/enabled") {
-        let fx_idx: i32 = args[0].parse().unwrap();
-        let track_guid = args[1].clone();
-        let mut endpoint = reaper.track_fx_enabled(track_guid, fx_idx);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(enabled) = msg.args.get(0) {
-                handler(TrackFxEnabledArgs {
-                    enabled: enabled.clone().bool().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/fx/{fx_idx}/param_count") {
-        let fx_idx: i32 = args[0].parse().unwrap();
-        let track_guid = args[1].clone();
-        let mut endpoint = reaper.track_fx_param_count(track_guid, fx_idx);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(param_count) = msg.args.get(0) {
-                handler(TrackFxParamCountArgs {
-                    param_count: param_count.clone().int().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(
-        addr,
-        "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name",
-    ) {
-        let param_idx: i32 = args[0].parse().unwrap();
-        let fx_idx: i32 = args[1].parse().unwrap();
-        let track_guid = args[2].clone();
-        let mut endpoint = reaper.track_fx_param_name(track_guid, fx_idx, param_idx);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(param_name) = msg.args.get(0) {
-                handler(TrackFxParamNameArgs {
-                    param_name: param_name.clone().string().unwrap().clone(),
-                });
+            if let Some("name") = segments.get(2).copied() {
+                matched |= dispatch_project_name_route(reaper, msg, addr, undecodable);
             }
         }
-        return;
-    }
-    if let Some(args) = match_addr(
-        addr,
-        "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value",
-    ) {
-        let param_idx: i32 = args[0].parse().unwrap();
-        let fx_idx: i32 = args[1].parse().unwrap();
-        let track_guid = args[2].clone();
-        let mut endpoint = reaper.track_fx_param_value(track_guid, fx_idx, param_idx);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(value) = msg.args.get(0) {
-                handler(TrackFxParamValueArgs {
-                    value: value.clone().float().unwrap(),
-                });
+        Some("record") => {
+            matched |= dispatch_record_route(reaper, msg, addr, undecodable);
+        }
+        Some("region") => {
+            if let Some("all") = segments.get(1).copied() {
+                matched |= dispatch_region_all_route(reaper, msg, addr, undecodable);
+            }
+            match segments.get(2).copied() {
+                Some("end") => {
+                    matched |= dispatch_region_end_route(reaper, msg, addr, undecodable);
+                }
+                Some("name") => {
+                    matched |= dispatch_region_name_route(reaper, msg, addr, undecodable);
+                }
+                Some("start") => {
+                    matched |= dispatch_region_start_route(reaper, msg, addr, undecodable);
+                }
+                _ => {}
             }
         }
-        return;
-    }
-    if let Some(args) = match_addr(
-        addr,
-        "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min",
-    ) {
-        let param_idx: i32 = args[0].parse().unwrap();
-        let fx_idx: i32 = args[1].parse().unwrap();
-        let track_guid = args[2].clone();
-        let mut endpoint = reaper.track_fx_param_min(track_guid, fx_idx, param_idx);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(min) = msg.args.get(0) {
-                handler(TrackFxParamMinArgs {
-                    min: min.clone().float().unwrap(),
-                });
+        Some("repeat") => {
+            matched |= dispatch_repeat_route(reaper, msg, addr, undecodable);
+        }
+        Some("rewind") => {
+            matched |= dispatch_rewind_route(reaper, msg, addr, undecodable);
+        }
+        Some("stop") => {
+            matched |= dispatch_stop_route(reaper, msg, addr, undecodable);
+        }
+        Some("tempo") => {
+            matched |= dispatch_tempo_route(reaper, msg, addr, undecodable);
+        }
+        Some("time") => {
+            matched |= dispatch_time_route(reaper, msg, addr, undecodable);
+        }
+        Some("track") => {
+            if let Some("all_guids") = segments.get(1).copied() {
+                matched |= dispatch_track_all_guids_route(reaper, msg, addr, undecodable);
+            }
+            match segments.get(2).copied() {
+                Some("color") => {
+                    matched |= dispatch_track_color_route(reaper, msg, addr, undecodable);
+                }
+                Some("delete") => {
+                    matched |= dispatch_track_delete_route(reaper, msg, addr, undecodable);
+                }
+                Some("fx") => match segments.get(4).copied() {
+                    Some("bypass") => {
+                        matched |= dispatch_track_fx_bypass_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("enabled") => {
+                        matched |= dispatch_track_fx_enabled_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("guid") => {
+                        matched |= dispatch_track_fx_guid_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("info") => {
+                        matched |= dispatch_track_fx_info_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("name") => {
+                        matched |= dispatch_track_fx_name_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("param") => match segments.get(6).copied() {
+                        Some("max") => {
+                            matched |=
+                                dispatch_track_fx_param_max_route(reaper, msg, addr, undecodable);
+                        }
+                        Some("min") => {
+                            matched |=
+                                dispatch_track_fx_param_min_route(reaper, msg, addr, undecodable);
+                        }
+                        Some("name") => {
+                            matched |=
+                                dispatch_track_fx_param_name_route(reaper, msg, addr, undecodable);
+                        }
+                        Some("value") => {
+                            matched |=
+                                dispatch_track_fx_param_value_route(reaper, msg, addr, undecodable);
+                        }
+                        _ => {}
+                    },
+                    Some("param_count") => {
+                        matched |=
+                            dispatch_track_fx_param_count_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("wet") => {
+                        matched |= dispatch_track_fx_wet_route(reaper, msg, addr, undecodable);
+                    }
+                    _ => {}
+                },
+                Some("index") => {
+                    matched |= dispatch_track_index_route(reaper, msg, addr, undecodable);
+                }
+                Some("input-gain") => {
+                    matched |= dispatch_track_input_gain_route(reaper, msg, addr, undecodable);
+                }
+                Some("mute") => {
+                    matched |= dispatch_track_mute_route(reaper, msg, addr, undecodable);
+                }
+                Some("name") => {
+                    matched |= dispatch_track_name_route(reaper, msg, addr, undecodable);
+                }
+                Some("pan") => {
+                    matched |= dispatch_track_pan_route(reaper, msg, addr, undecodable);
+                }
+                Some("phase") => {
+                    matched |= dispatch_track_phase_route(reaper, msg, addr, undecodable);
+                }
+                Some("rec-arm") => {
+                    matched |= dispatch_track_rec_arm_route(reaper, msg, addr, undecodable);
+                }
+                Some("selected") => {
+                    matched |= dispatch_track_selected_route(reaper, msg, addr, undecodable);
+                }
+                Some("send") => match segments.get(4).copied() {
+                    Some("guid") => {
+                        matched |= dispatch_track_send_guid_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("mute") => {
+                        matched |= dispatch_track_send_mute_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("pan") => {
+                        matched |= dispatch_track_send_pan_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("volume") => {
+                        matched |= dispatch_track_send_volume_route(reaper, msg, addr, undecodable);
+                    }
+                    _ => {}
+                },
+                Some("send_count") => {
+                    matched |= dispatch_track_send_count_route(reaper, msg, addr, undecodable);
+                }
+                Some("solo") => {
+                    matched |= dispatch_track_solo_route(reaper, msg, addr, undecodable);
+                }
+                Some("volume") => {
+                    matched |= dispatch_track_volume_route(reaper, msg, addr, undecodable);
+                }
+                Some("width") => {
+                    matched |= dispatch_track_width_route(reaper, msg, addr, undecodable);
+                }
+                _ => {}
             }
         }
-        return;
+        _ => {}
+    }
+    matched
+}
+
+fn call_bound_handlers(reaper: &Reaper, msg: &rosc::OscMessage) {
+    let bound: Vec<BoundHandler> = {
+        let handlers = reaper.handlers.lock().unwrap();
+        let matching: Vec<&Vec<(Subscription, BoundHandler)>> = match is_pattern(&msg.addr) {
+            true => handlers
+                .iter()
+                .filter(|(bound, _)| match_address(bound, &msg.addr).is_some())
+                .map(|(_, bound)| bound)
+                .collect(),
+            false => handlers.get(&msg.addr).into_iter().collect(),
+        };
+        matching
+            .into_iter()
+            .flatten()
+            .map(|(_, handler)| handler.clone())
+            .collect()
+    };
+    for handler in bound {
+        let mut handler = handler.lock().unwrap();
+        (*handler)(msg);
+    }
+}
+
+/// /num_tracks
+fn dispatch_num_tracks_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/num_tracks", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_num_tracks_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/all_guids
+fn dispatch_track_all_guids_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/all_guids", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_all_guids_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/index
+fn dispatch_track_index_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/index", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_index_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/delete
+fn dispatch_track_delete_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/delete", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_delete_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/name
+fn dispatch_track_name_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/name", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/selected
+fn dispatch_track_selected_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/selected", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_selected_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/volume
+fn dispatch_track_volume_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/volume", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_volume_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/pan
+fn dispatch_track_pan_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/pan", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_pan_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/mute
+fn dispatch_track_mute_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/mute", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_mute_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/solo
+fn dispatch_track_solo_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/solo", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_solo_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/rec-arm
+fn dispatch_track_rec_arm_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/rec-arm", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_rec_arm_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/width
+fn dispatch_track_width_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/width", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_width_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/phase
+fn dispatch_track_phase_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/phase", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_phase_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/input-gain
+fn dispatch_track_input_gain_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/input-gain", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_input_gain_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /master/volume
+fn dispatch_master_volume_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/master/volume", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_master_volume_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /master/pan
+fn dispatch_master_pan_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/master/pan", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_master_pan_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /master/mute
+fn dispatch_master_mute_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/master/mute", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_master_mute_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/send_count
+fn dispatch_track_send_count_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/send_count", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_send_count_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/send/{send_index}/guid
+fn dispatch_track_send_guid_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/send/{send_index}/guid", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_send_guid_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/send/{send_index}/volume
+fn dispatch_track_send_volume_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/send/{send_index}/volume", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_send_volume_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/send/{send_index}/pan
+fn dispatch_track_send_pan_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/send/{send_index}/pan", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_send_pan_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/send/{send_index}/mute
+fn dispatch_track_send_mute_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/send/{send_index}/mute", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_send_mute_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/color
+fn dispatch_track_color_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/color", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_color_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/guid
+fn dispatch_track_fx_guid_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/fx/{fx_idx}/guid", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_fx_guid_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/name
+fn dispatch_track_fx_name_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/fx/{fx_idx}/name", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_fx_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/enabled
+fn dispatch_track_fx_enabled_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/fx/{fx_idx}/enabled", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_fx_enabled_args(msg) {
+        undecodable.push(reason);
+        return true;
     }
-    if let Some(args) = match_addr(
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/bypass
+fn dispatch_track_fx_bypass_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/fx/{fx_idx}/bypass", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_fx_bypass_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/wet
+fn dispatch_track_fx_wet_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/fx/{fx_idx}/wet", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_fx_wet_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param_count
+fn dispatch_track_fx_param_count_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/fx/{fx_idx}/param_count", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_fx_param_count_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name
+fn dispatch_track_fx_param_name_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address(
+        "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name",
+        addr,
+    )
+    .is_none()
+    {
+        return false;
+    }
+    if let Err(reason) = parse_track_fx_param_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
+fn dispatch_track_fx_param_value_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address(
+        "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value",
+        addr,
+    )
+    .is_none()
+    {
+        return false;
+    }
+    if let Err(reason) = parse_track_fx_param_value_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min
+fn dispatch_track_fx_param_min_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address(
+        "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min",
         addr,
+    )
+    .is_none()
+    {
+        return false;
+    }
+    if let Err(reason) = parse_track_fx_param_min_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max
+fn dispatch_track_fx_param_max_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address(
         "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max",
-    ) {
-        let param_idx: i32 = args[0].parse().unwrap();
-        let fx_idx: i32 = args[1].parse().unwrap();
-        let track_guid = args[2].clone();
-        let mut endpoint = reaper.track_fx_param_max(track_guid, fx_idx, param_idx);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(max) = msg.args.get(0) {
-                handler(TrackFxParamMaxArgs {
-                    max: max.clone().float().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/track/{track_guid}/fx/{fx_idx}/info") {
-        let fx_idx: i32 = args[0].parse().unwrap();
-        let track_guid = args[1].clone();
-        let mut endpoint = reaper.track_fx_info(track_guid, fx_idx);
-        if let Some(handler) = &mut endpoint.handler {}
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/fxinfo/{ident}/name") {
-        let ident = args[0].clone();
-        let mut endpoint = reaper.fxinfo_name(ident);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(name) = msg.args.get(0) {
-                handler(FxinfoNameArgs {
-                    name: name.clone().string().unwrap().clone(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/fxinfo/{ident}/param_count") {
-        let ident = args[0].clone();
-        let mut endpoint = reaper.fxinfo_param_count(ident);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(param_count) = msg.args.get(0) {
-                handler(FxinfoParamCountArgs {
-                    param_count: param_count.clone().int().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/fxinfo/{ident}/param/{param_idx}/name") {
-        let param_idx: i32 = args[0].parse().unwrap();
-        let ident = args[1].clone();
-        let mut endpoint = reaper.fxinfo_param_name(ident, param_idx);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(param_name) = msg.args.get(0) {
-                handler(FxinfoParamNameArgs {
-                    param_name: param_name.clone().string().unwrap().clone(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/fxinfo/{ident}/param/{param_idx}/min") {
-        let param_idx: i32 = args[0].parse().unwrap();
-        let ident = args[1].clone();
-        let mut endpoint = reaper.fxinfo_param_min(ident, param_idx);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(param_min) = msg.args.get(0) {
-                handler(FxinfoParamMinArgs {
-                    param_min: param_min.clone().float().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/fxinfo/{ident}/param/{param_idx}/max") {
-        let param_idx: i32 = args[0].parse().unwrap();
-        let ident = args[1].clone();
-        let mut endpoint = reaper.fxinfo_param_max(ident, param_idx);
-        if let Some(handler) = &mut endpoint.handler {
-            if let Some(param_max) = msg.args.get(0) {
-                handler(FxinfoParamMaxArgs {
-                    param_max: param_max.clone().float().unwrap(),
-                });
-            }
-        }
-        return;
-    }
-    if let Some(args) = match_addr(addr, "/fxinfo") {
-        let mut endpoint = reaper.fxinfo();
-        if let Some(handler) = &mut endpoint.handler {}
-        return;
-    }
-    log_unknown(addr);
+        addr,
+    )
+    .is_none()
+    {
+        return false;
+    }
+    if let Err(reason) = parse_track_fx_param_max_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/info
+fn dispatch_track_fx_info_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/track/{track_guid}/fx/{fx_idx}/info", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_track_fx_info_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /fxinfo/{ident}/name
+fn dispatch_fxinfo_name_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/fxinfo/{ident}/name", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_fxinfo_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /fxinfo/{ident}/param_count
+fn dispatch_fxinfo_param_count_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/fxinfo/{ident}/param_count", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_fxinfo_param_count_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/name
+fn dispatch_fxinfo_param_name_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/fxinfo/{ident}/param/{param_idx}/name", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_fxinfo_param_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/min
+fn dispatch_fxinfo_param_min_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/fxinfo/{ident}/param/{param_idx}/min", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_fxinfo_param_min_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/max
+fn dispatch_fxinfo_param_max_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/fxinfo/{ident}/param/{param_idx}/max", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_fxinfo_param_max_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /fxinfo
+fn dispatch_fxinfo_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/fxinfo", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_fxinfo_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /play
+fn dispatch_play_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/play", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_play_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /stop
+fn dispatch_stop_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/stop", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_stop_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /record
+fn dispatch_record_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/record", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_record_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /repeat
+fn dispatch_repeat_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/repeat", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_repeat_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /rewind
+fn dispatch_rewind_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/rewind", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_rewind_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /forward
+fn dispatch_forward_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/forward", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_forward_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /time
+fn dispatch_time_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/time", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_time_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /tempo
+fn dispatch_tempo_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/tempo", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_tempo_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /project/guid
+fn dispatch_active_project_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/project/guid", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_active_project_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /project/{project_guid}/name
+fn dispatch_project_name_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/project/{project_guid}/name", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_project_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /action
+fn dispatch_action_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/action", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_action_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /action/{command_id}
+fn dispatch_run_action_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/action/{command_id}", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_run_action_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /action/{command_id}/state
+fn dispatch_action_state_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/action/{command_id}/state", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_action_state_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /marker/all
+fn dispatch_marker_all_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/marker/all", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_marker_all_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /marker/{marker_idx}/name
+fn dispatch_marker_name_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/marker/{marker_idx}/name", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_marker_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /marker/{marker_idx}/position
+fn dispatch_marker_position_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/marker/{marker_idx}/position", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_marker_position_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /region/all
+fn dispatch_region_all_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/region/all", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_region_all_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /region/{region_idx}/name
+fn dispatch_region_name_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/region/{region_idx}/name", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_region_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /region/{region_idx}/start
+fn dispatch_region_start_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/region/{region_idx}/start", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_region_start_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+/// /region/{region_idx}/end
+fn dispatch_region_end_route(
+    _reaper: &Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    if match_address("/region/{region_idx}/end", addr).is_none() {
+        return false;
+    }
+    if let Err(reason) = parse_region_end_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    true
+}
+
+#[cfg(test)]
+mod generated_tests {
+    use super::*;
+
+    use std::sync::mpsc;
+
+    // Sends nowhere; the tests dispatch the messages they build themselves
+    struct NoTransport;
+
+    impl OscTransport for NoTransport {
+        fn send(&self, _packet: &[u8]) -> std::io::Result<()> {
+            Ok(())
+        }
+
+        fn recv(&self) -> std::io::Result<(Vec<u8>, SocketAddr)> {
+            Err(std::io::ErrorKind::Unsupported.into())
+        }
+    }
+
+    #[test]
+    fn test_track_name_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_name("test".into());
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(TrackNameArgs {
+                name: b"test".to_vec(),
+            })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_selected_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_selected("test".into());
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(TrackSelectedArgs { selected: true })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_volume_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_volume("test".into());
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(TrackVolumeArgs { volume: 0.0 })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_pan_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_pan("test".into());
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint.set_message(TrackPanArgs { pan: -1.0 }).unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_mute_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_mute("test".into());
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint.set_message(TrackMuteArgs { mute: true }).unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_solo_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_solo("test".into());
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint.set_message(TrackSoloArgs { solo: true }).unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_rec_arm_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_rec_arm("test".into());
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(TrackRecArmArgs { rec_arm: true })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_width_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_width("test".into());
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(TrackWidthArgs { width: -1.0 })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_phase_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_phase("test".into());
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(TrackPhaseArgs { phase: true })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_input_gain_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_input_gain("test".into());
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(TrackInputGainArgs { input_gain: 0.0 })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_master_volume_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.master_volume();
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(MasterVolumeArgs { volume: 0.0 })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_master_pan_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.master_pan();
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint.set_message(MasterPanArgs { pan: -1.0 }).unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_master_mute_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.master_mute();
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint.set_message(MasterMuteArgs { mute: true }).unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_send_volume_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_send_volume("test".into(), 7);
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(TrackSendVolumeArgs { volume: 0.0 })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_send_pan_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_send_pan("test".into(), 7);
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(TrackSendPanArgs { pan: -1.0 })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_send_mute_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_send_mute("test".into(), 7);
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(TrackSendMuteArgs { mute: true })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_color_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_color("test".into());
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint.set_message(TrackColorArgs { color: 7 }).unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_fx_enabled_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_fx_enabled("test".into(), 7);
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(TrackFxEnabledArgs { enabled: true })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_fx_bypass_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_fx_bypass("test".into(), 7);
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(TrackFxBypassArgs { bypass: true })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_fx_wet_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_fx_wet("test".into(), 7);
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint.set_message(TrackFxWetArgs { wet: 0.5 }).unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_track_fx_param_value_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.track_fx_param_value("test".into(), 7, 7);
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(TrackFxParamValueArgs { value: 0.5 })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_play_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.play();
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint.set_message(PlayArgs { playing: true }).unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_record_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.record();
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(RecordArgs { recording: true })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_repeat_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.repeat();
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint.set_message(RepeatArgs { repeat: true }).unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_time_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.time();
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint.set_message(TimeArgs { time: 0.5 }).unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_tempo_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.tempo();
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint.set_message(TempoArgs { bpm: 0.5 }).unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_marker_name_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.marker_name(7);
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(MarkerNameArgs {
+                name: "test".to_string(),
+            })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_marker_position_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.marker_position(7);
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(MarkerPositionArgs { position: 0.5 })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_region_name_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.region_name(7);
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(RegionNameArgs {
+                name: "test".to_string(),
+            })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_region_start_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.region_start(7);
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint
+            .set_message(RegionStartArgs { start: 0.5 })
+            .unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_region_end_round_trips() {
+        let reaper = Reaper::new(Arc::new(NoTransport));
+        let mut endpoint = reaper.region_end(7);
+        let (tx, rx) = mpsc::channel();
+        endpoint.bind(move |args| tx.send(args).unwrap());
+        let msg = endpoint.set_message(RegionEndArgs { end: 0.5 }).unwrap();
+        dispatch_osc(&reaper, msg.clone(), |addr, reason| {
+            panic!("{} wasn't handled: {}", addr, reason)
+        });
+        let received = rx.try_recv().expect("the handler wasn't called");
+        assert_eq!(endpoint.set_message(received).unwrap(), msg);
+    }
 }
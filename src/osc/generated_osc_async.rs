@@ -0,0 +1,9954 @@
+// AUTO-GENERATED CODE. DO NOT EDIT!
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use tokio::net::UdpSocket;
+
+use crate::traits::{AsyncQuery, AsyncSet, Bind, Subscribe, Subscription};
+
+use crate::osc::decode::{self, Unhandled};
+use crate::osc::error::{OscError, check_address_segment};
+use crate::osc::pattern::{is_pattern, match_address};
+use crate::osc::route_context::ContextTrait;
+
+#[derive(Debug)]
+pub struct NumTracksArgs {
+    pub num_tracks: i32, // number of tracks in the current project
+}
+
+impl NumTracksArgs {
+    pub fn new(num_tracks: i32) -> Self {
+        Self { num_tracks }
+    }
+}
+
+impl From<i32> for NumTracksArgs {
+    fn from(num_tracks: i32) -> Self {
+        Self::new(num_tracks)
+    }
+}
+
+pub type NumTracksHandler = Box<dyn FnMut(NumTracksArgs) + 'static>;
+
+pub struct NumTracks {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, NumTracksHandler)>,
+}
+
+impl NumTracks {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/num_tracks";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for NumTracks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /num_tracks
+impl Bind<NumTracksArgs> for NumTracks {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(NumTracksArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /num_tracks
+impl Subscribe<NumTracksArgs> for NumTracks {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(NumTracksArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl NumTracks {
+    fn add_handler(&mut self, subscription: Subscription, handler: NumTracksHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /num_tracks
+impl AsyncQuery for NumTracks {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/num_tracks");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_num_tracks_args(msg: &rosc::OscMessage) -> Result<NumTracksArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(NumTracksArgs {
+        num_tracks: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackAllGuidsArgs {}
+
+pub type TrackAllGuidsHandler = Box<dyn FnMut(TrackAllGuidsArgs) + 'static>;
+
+pub struct TrackAllGuids {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackAllGuidsHandler)>,
+}
+
+impl TrackAllGuids {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/all_guids";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for TrackAllGuids {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/all_guids
+impl Bind<TrackAllGuidsArgs> for TrackAllGuids {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackAllGuidsArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/all_guids
+impl Subscribe<TrackAllGuidsArgs> for TrackAllGuids {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackAllGuidsArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackAllGuids {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackAllGuidsHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/all_guids
+impl AsyncQuery for TrackAllGuids {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/track/all_guids");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_all_guids_args(_msg: &rosc::OscMessage) -> Result<TrackAllGuidsArgs, String> {
+    Ok(TrackAllGuidsArgs {})
+}
+
+#[derive(Debug)]
+pub struct TrackIndexArgs {
+    pub index: i32, // index of the track in the project according to reaper's mixer view
+}
+
+impl TrackIndexArgs {
+    pub fn new(index: i32) -> Self {
+        Self { index }
+    }
+}
+
+impl From<i32> for TrackIndexArgs {
+    fn from(index: i32) -> Self {
+        Self::new(index)
+    }
+}
+
+pub type TrackIndexHandler = Box<dyn FnMut(TrackIndexArgs) + 'static>;
+
+pub struct TrackIndex {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackIndexHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackIndex {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/index";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/index", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/index
+impl Bind<TrackIndexArgs> for TrackIndex {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackIndexArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/index
+impl Subscribe<TrackIndexArgs> for TrackIndex {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackIndexArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackIndex {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackIndexHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/index
+impl AsyncQuery for TrackIndex {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/index", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_index_args(msg: &rosc::OscMessage) -> Result<TrackIndexArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(TrackIndexArgs {
+        index: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackDeleteArgs {}
+
+pub type TrackDeleteHandler = Box<dyn FnMut(TrackDeleteArgs) + 'static>;
+
+pub struct TrackDelete {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackDeleteHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackDelete {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/delete";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/delete", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackDelete {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/delete
+impl AsyncSet<TrackDeleteArgs> for TrackDelete {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackDeleteArgs) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/delete", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_delete_args(_msg: &rosc::OscMessage) -> Result<TrackDeleteArgs, String> {
+    Ok(TrackDeleteArgs {})
+}
+
+#[derive(Debug)]
+pub struct TrackNameArgs {
+    pub name: Vec<u8>, // name of the track
+}
+
+impl TrackNameArgs {
+    pub fn new(name: impl Into<Vec<u8>>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl From<Vec<u8>> for TrackNameArgs {
+    fn from(name: Vec<u8>) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&str> for TrackNameArgs {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+pub type TrackNameHandler = Box<dyn FnMut(TrackNameArgs) + 'static>;
+
+pub struct TrackName {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackNameHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/name", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/name
+impl AsyncSet<TrackNameArgs> for TrackName {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackNameArgs) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/name", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![decode::binary_arg(args.name)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = decode::encode_packet(&packet, &[&[0]])?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackName {
+    pub async fn set_value(&mut self, value: impl Into<TrackNameArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/name
+impl Bind<TrackNameArgs> for TrackName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackNameArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/name
+impl Subscribe<TrackNameArgs> for TrackName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackNameArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackName {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackNameHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/name
+impl AsyncQuery for TrackName {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/name", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_name_args(msg: &rosc::OscMessage) -> Result<TrackNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<Vec<u8>>()]);
+    Ok(TrackNameArgs {
+        name: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackSelectedArgs {
+    pub selected: bool, // true means track is selected
+}
+
+impl TrackSelectedArgs {
+    pub fn new(selected: bool) -> Self {
+        Self { selected }
+    }
+}
+
+impl From<bool> for TrackSelectedArgs {
+    fn from(selected: bool) -> Self {
+        Self::new(selected)
+    }
+}
+
+pub type TrackSelectedHandler = Box<dyn FnMut(TrackSelectedArgs) + 'static>;
+
+pub struct TrackSelected {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackSelectedHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackSelected {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/selected";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/selected", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackSelected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/selected
+impl AsyncSet<TrackSelectedArgs> for TrackSelected {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackSelectedArgs) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/selected", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.selected)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackSelected {
+    pub async fn set_value(&mut self, value: impl Into<TrackSelectedArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/selected
+impl Bind<TrackSelectedArgs> for TrackSelected {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackSelectedArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/selected
+impl Subscribe<TrackSelectedArgs> for TrackSelected {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackSelectedArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackSelected {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSelectedHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/selected
+impl AsyncQuery for TrackSelected {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/selected", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_selected_args(msg: &rosc::OscMessage) -> Result<TrackSelectedArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackSelectedArgs {
+        selected: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackVolumeArgs {
+    pub volume: f32, // volume of the track, normalized to 0 to 1.0
+}
+
+impl TrackVolumeArgs {
+    pub fn new(volume: f32) -> Self {
+        Self { volume }
+    }
+}
+
+impl From<f32> for TrackVolumeArgs {
+    fn from(volume: f32) -> Self {
+        Self::new(volume)
+    }
+}
+
+pub type TrackVolumeHandler = Box<dyn FnMut(TrackVolumeArgs) + 'static>;
+
+pub struct TrackVolume {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackVolumeHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackVolume {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/volume";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/volume", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackVolume {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/volume
+impl AsyncSet<TrackVolumeArgs> for TrackVolume {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackVolumeArgs) -> Result<(), Self::Error> {
+        let mut args = args;
+        args.volume = crate::osc::range::clamp(args.volume, Some(0.0), Some(1.0));
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/volume", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.volume)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackVolume {
+    pub async fn set_value(&mut self, value: impl Into<TrackVolumeArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/volume
+impl Bind<TrackVolumeArgs> for TrackVolume {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackVolumeArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/volume
+impl Subscribe<TrackVolumeArgs> for TrackVolume {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackVolumeArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackVolume {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackVolumeHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/volume
+impl AsyncQuery for TrackVolume {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/volume", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_volume_args(msg: &rosc::OscMessage) -> Result<TrackVolumeArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = TrackVolumeArgs {
+        volume: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("volume", args.volume, Some(0.0), Some(1.0))?;
+    Ok(args)
+}
+
+#[derive(Debug)]
+pub struct TrackPanArgs {
+    pub pan: f32, // pan of the track, normalized to -1.0 to 1.0
+}
+
+impl TrackPanArgs {
+    pub fn new(pan: f32) -> Self {
+        Self { pan }
+    }
+}
+
+impl From<f32> for TrackPanArgs {
+    fn from(pan: f32) -> Self {
+        Self::new(pan)
+    }
+}
+
+pub type TrackPanHandler = Box<dyn FnMut(TrackPanArgs) + 'static>;
+
+pub struct TrackPan {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackPanHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackPan {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/pan";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/pan", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackPan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/pan
+impl AsyncSet<TrackPanArgs> for TrackPan {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackPanArgs) -> Result<(), Self::Error> {
+        let mut args = args;
+        args.pan = crate::osc::range::clamp(args.pan, Some(-1.0), Some(1.0));
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/pan", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.pan)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackPan {
+    pub async fn set_value(&mut self, value: impl Into<TrackPanArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/pan
+impl Bind<TrackPanArgs> for TrackPan {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackPanArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/pan
+impl Subscribe<TrackPanArgs> for TrackPan {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackPanArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackPan {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackPanHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/pan
+impl AsyncQuery for TrackPan {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/pan", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_pan_args(msg: &rosc::OscMessage) -> Result<TrackPanArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = TrackPanArgs {
+        pan: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("pan", args.pan, Some(-1.0), Some(1.0))?;
+    Ok(args)
+}
+
+#[derive(Debug)]
+pub struct TrackMuteArgs {
+    pub mute: bool, // true means track is muted
+}
+
+impl TrackMuteArgs {
+    pub fn new(mute: bool) -> Self {
+        Self { mute }
+    }
+}
+
+impl From<bool> for TrackMuteArgs {
+    fn from(mute: bool) -> Self {
+        Self::new(mute)
+    }
+}
+
+pub type TrackMuteHandler = Box<dyn FnMut(TrackMuteArgs) + 'static>;
+
+pub struct TrackMute {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackMuteHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackMute {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/mute";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/mute", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackMute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/mute
+impl AsyncSet<TrackMuteArgs> for TrackMute {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackMuteArgs) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/mute", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.mute)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackMute {
+    pub async fn set_value(&mut self, value: impl Into<TrackMuteArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/mute
+impl Bind<TrackMuteArgs> for TrackMute {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackMuteArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/mute
+impl Subscribe<TrackMuteArgs> for TrackMute {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackMuteArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackMute {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackMuteHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/mute
+impl AsyncQuery for TrackMute {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/mute", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_mute_args(msg: &rosc::OscMessage) -> Result<TrackMuteArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackMuteArgs {
+        mute: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackSoloArgs {
+    pub solo: bool, // true means track is soloed
+}
+
+impl TrackSoloArgs {
+    pub fn new(solo: bool) -> Self {
+        Self { solo }
+    }
+}
+
+impl From<bool> for TrackSoloArgs {
+    fn from(solo: bool) -> Self {
+        Self::new(solo)
+    }
+}
+
+pub type TrackSoloHandler = Box<dyn FnMut(TrackSoloArgs) + 'static>;
+
+pub struct TrackSolo {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackSoloHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackSolo {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/solo";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/solo", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackSolo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/solo
+impl AsyncSet<TrackSoloArgs> for TrackSolo {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackSoloArgs) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/solo", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.solo)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackSolo {
+    pub async fn set_value(&mut self, value: impl Into<TrackSoloArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/solo
+impl Bind<TrackSoloArgs> for TrackSolo {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackSoloArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/solo
+impl Subscribe<TrackSoloArgs> for TrackSolo {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackSoloArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackSolo {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSoloHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/solo
+impl AsyncQuery for TrackSolo {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/solo", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_solo_args(msg: &rosc::OscMessage) -> Result<TrackSoloArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackSoloArgs {
+        solo: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackRecArmArgs {
+    pub rec_arm: bool, // true means track is armed for recording
+}
+
+impl TrackRecArmArgs {
+    pub fn new(rec_arm: bool) -> Self {
+        Self { rec_arm }
+    }
+}
+
+impl From<bool> for TrackRecArmArgs {
+    fn from(rec_arm: bool) -> Self {
+        Self::new(rec_arm)
+    }
+}
+
+pub type TrackRecArmHandler = Box<dyn FnMut(TrackRecArmArgs) + 'static>;
+
+pub struct TrackRecArm {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackRecArmHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackRecArm {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/rec-arm";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/rec-arm", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackRecArm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/rec-arm
+impl AsyncSet<TrackRecArmArgs> for TrackRecArm {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackRecArmArgs) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/rec-arm", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.rec_arm)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackRecArm {
+    pub async fn set_value(&mut self, value: impl Into<TrackRecArmArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/rec-arm
+impl Bind<TrackRecArmArgs> for TrackRecArm {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackRecArmArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/rec-arm
+impl Subscribe<TrackRecArmArgs> for TrackRecArm {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackRecArmArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackRecArm {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackRecArmHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/rec-arm
+impl AsyncQuery for TrackRecArm {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/rec-arm", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_rec_arm_args(msg: &rosc::OscMessage) -> Result<TrackRecArmArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackRecArmArgs {
+        rec_arm: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackWidthArgs {
+    pub width: f32, // stereo width of the track, normalized to -1.0 to 1.0
+}
+
+impl TrackWidthArgs {
+    pub fn new(width: f32) -> Self {
+        Self { width }
+    }
+}
+
+impl From<f32> for TrackWidthArgs {
+    fn from(width: f32) -> Self {
+        Self::new(width)
+    }
+}
+
+pub type TrackWidthHandler = Box<dyn FnMut(TrackWidthArgs) + 'static>;
+
+pub struct TrackWidth {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackWidthHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackWidth {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/width";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/width", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/width
+impl AsyncSet<TrackWidthArgs> for TrackWidth {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackWidthArgs) -> Result<(), Self::Error> {
+        let mut args = args;
+        args.width = crate::osc::range::clamp(args.width, Some(-1.0), Some(1.0));
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/width", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.width)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackWidth {
+    pub async fn set_value(&mut self, value: impl Into<TrackWidthArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/width
+impl Bind<TrackWidthArgs> for TrackWidth {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackWidthArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/width
+impl Subscribe<TrackWidthArgs> for TrackWidth {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackWidthArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackWidth {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackWidthHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/width
+impl AsyncQuery for TrackWidth {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/width", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_width_args(msg: &rosc::OscMessage) -> Result<TrackWidthArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = TrackWidthArgs {
+        width: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("width", args.width, Some(-1.0), Some(1.0))?;
+    Ok(args)
+}
+
+#[derive(Debug)]
+pub struct TrackPhaseArgs {
+    pub phase: bool, // true means the track's polarity is inverted
+}
+
+impl TrackPhaseArgs {
+    pub fn new(phase: bool) -> Self {
+        Self { phase }
+    }
+}
+
+impl From<bool> for TrackPhaseArgs {
+    fn from(phase: bool) -> Self {
+        Self::new(phase)
+    }
+}
+
+pub type TrackPhaseHandler = Box<dyn FnMut(TrackPhaseArgs) + 'static>;
+
+pub struct TrackPhase {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackPhaseHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackPhase {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/phase";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/phase", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/phase
+impl AsyncSet<TrackPhaseArgs> for TrackPhase {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackPhaseArgs) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/phase", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.phase)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackPhase {
+    pub async fn set_value(&mut self, value: impl Into<TrackPhaseArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/phase
+impl Bind<TrackPhaseArgs> for TrackPhase {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackPhaseArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/phase
+impl Subscribe<TrackPhaseArgs> for TrackPhase {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackPhaseArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackPhase {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackPhaseHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/phase
+impl AsyncQuery for TrackPhase {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/phase", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_phase_args(msg: &rosc::OscMessage) -> Result<TrackPhaseArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackPhaseArgs {
+        phase: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackInputGainArgs {
+    pub input_gain: f32, // gain applied to the track's input, normalized to 0 to 1.0
+}
+
+impl TrackInputGainArgs {
+    pub fn new(input_gain: f32) -> Self {
+        Self { input_gain }
+    }
+}
+
+impl From<f32> for TrackInputGainArgs {
+    fn from(input_gain: f32) -> Self {
+        Self::new(input_gain)
+    }
+}
+
+pub type TrackInputGainHandler = Box<dyn FnMut(TrackInputGainArgs) + 'static>;
+
+pub struct TrackInputGain {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackInputGainHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackInputGain {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/input-gain";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/input-gain", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackInputGain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/input-gain
+impl AsyncSet<TrackInputGainArgs> for TrackInputGain {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackInputGainArgs) -> Result<(), Self::Error> {
+        let mut args = args;
+        args.input_gain = crate::osc::range::clamp(args.input_gain, Some(0.0), Some(1.0));
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/input-gain", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.input_gain)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackInputGain {
+    pub async fn set_value(
+        &mut self,
+        value: impl Into<TrackInputGainArgs>,
+    ) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/input-gain
+impl Bind<TrackInputGainArgs> for TrackInputGain {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackInputGainArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/input-gain
+impl Subscribe<TrackInputGainArgs> for TrackInputGain {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackInputGainArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackInputGain {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackInputGainHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/input-gain
+impl AsyncQuery for TrackInputGain {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/input-gain", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_input_gain_args(msg: &rosc::OscMessage) -> Result<TrackInputGainArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = TrackInputGainArgs {
+        input_gain: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("input_gain", args.input_gain, Some(0.0), Some(1.0))?;
+    Ok(args)
+}
+
+#[derive(Debug)]
+pub struct MasterVolumeArgs {
+    pub volume: f32, // volume of the master track, normalized to 0 to 1.0
+}
+
+impl MasterVolumeArgs {
+    pub fn new(volume: f32) -> Self {
+        Self { volume }
+    }
+}
+
+impl From<f32> for MasterVolumeArgs {
+    fn from(volume: f32) -> Self {
+        Self::new(volume)
+    }
+}
+
+pub type MasterVolumeHandler = Box<dyn FnMut(MasterVolumeArgs) + 'static>;
+
+pub struct MasterVolume {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, MasterVolumeHandler)>,
+}
+
+impl MasterVolume {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/master/volume";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for MasterVolume {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /master/volume
+impl AsyncSet<MasterVolumeArgs> for MasterVolume {
+    type Error = OscError;
+    async fn set(&mut self, args: MasterVolumeArgs) -> Result<(), Self::Error> {
+        let mut args = args;
+        args.volume = crate::osc::range::clamp(args.volume, Some(0.0), Some(1.0));
+        let osc_address = format!("/master/volume");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.volume)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl MasterVolume {
+    pub async fn set_value(&mut self, value: impl Into<MasterVolumeArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /master/volume
+impl Bind<MasterVolumeArgs> for MasterVolume {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(MasterVolumeArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /master/volume
+impl Subscribe<MasterVolumeArgs> for MasterVolume {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(MasterVolumeArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl MasterVolume {
+    fn add_handler(&mut self, subscription: Subscription, handler: MasterVolumeHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /master/volume
+impl AsyncQuery for MasterVolume {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/master/volume");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_master_volume_args(msg: &rosc::OscMessage) -> Result<MasterVolumeArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = MasterVolumeArgs {
+        volume: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("volume", args.volume, Some(0.0), Some(1.0))?;
+    Ok(args)
+}
+
+#[derive(Debug)]
+pub struct MasterPanArgs {
+    pub pan: f32, // pan of the master track, normalized to -1.0 to 1.0
+}
+
+impl MasterPanArgs {
+    pub fn new(pan: f32) -> Self {
+        Self { pan }
+    }
+}
+
+impl From<f32> for MasterPanArgs {
+    fn from(pan: f32) -> Self {
+        Self::new(pan)
+    }
+}
+
+pub type MasterPanHandler = Box<dyn FnMut(MasterPanArgs) + 'static>;
+
+pub struct MasterPan {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, MasterPanHandler)>,
+}
+
+impl MasterPan {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/master/pan";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for MasterPan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /master/pan
+impl AsyncSet<MasterPanArgs> for MasterPan {
+    type Error = OscError;
+    async fn set(&mut self, args: MasterPanArgs) -> Result<(), Self::Error> {
+        let mut args = args;
+        args.pan = crate::osc::range::clamp(args.pan, Some(-1.0), Some(1.0));
+        let osc_address = format!("/master/pan");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.pan)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl MasterPan {
+    pub async fn set_value(&mut self, value: impl Into<MasterPanArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /master/pan
+impl Bind<MasterPanArgs> for MasterPan {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(MasterPanArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /master/pan
+impl Subscribe<MasterPanArgs> for MasterPan {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(MasterPanArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl MasterPan {
+    fn add_handler(&mut self, subscription: Subscription, handler: MasterPanHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /master/pan
+impl AsyncQuery for MasterPan {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/master/pan");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_master_pan_args(msg: &rosc::OscMessage) -> Result<MasterPanArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = MasterPanArgs {
+        pan: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("pan", args.pan, Some(-1.0), Some(1.0))?;
+    Ok(args)
+}
+
+#[derive(Debug)]
+pub struct MasterMuteArgs {
+    pub mute: bool, // true means master track is muted
+}
+
+impl MasterMuteArgs {
+    pub fn new(mute: bool) -> Self {
+        Self { mute }
+    }
+}
+
+impl From<bool> for MasterMuteArgs {
+    fn from(mute: bool) -> Self {
+        Self::new(mute)
+    }
+}
+
+pub type MasterMuteHandler = Box<dyn FnMut(MasterMuteArgs) + 'static>;
+
+pub struct MasterMute {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, MasterMuteHandler)>,
+}
+
+impl MasterMute {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/master/mute";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for MasterMute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /master/mute
+impl AsyncSet<MasterMuteArgs> for MasterMute {
+    type Error = OscError;
+    async fn set(&mut self, args: MasterMuteArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/master/mute");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.mute)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl MasterMute {
+    pub async fn set_value(&mut self, value: impl Into<MasterMuteArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /master/mute
+impl Bind<MasterMuteArgs> for MasterMute {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(MasterMuteArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /master/mute
+impl Subscribe<MasterMuteArgs> for MasterMute {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(MasterMuteArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl MasterMute {
+    fn add_handler(&mut self, subscription: Subscription, handler: MasterMuteHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /master/mute
+impl AsyncQuery for MasterMute {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/master/mute");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_master_mute_args(msg: &rosc::OscMessage) -> Result<MasterMuteArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(MasterMuteArgs {
+        mute: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackSendCountArgs {
+    pub send_count: i32, // number of sends on the track
+}
+
+impl TrackSendCountArgs {
+    pub fn new(send_count: i32) -> Self {
+        Self { send_count }
+    }
+}
+
+impl From<i32> for TrackSendCountArgs {
+    fn from(send_count: i32) -> Self {
+        Self::new(send_count)
+    }
+}
+
+pub type TrackSendCountHandler = Box<dyn FnMut(TrackSendCountArgs) + 'static>;
+
+pub struct TrackSendCount {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackSendCountHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackSendCount {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/send_count";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/send_count", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackSendCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/send_count
+impl Bind<TrackSendCountArgs> for TrackSendCount {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackSendCountArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/send_count
+impl Subscribe<TrackSendCountArgs> for TrackSendCount {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackSendCountArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackSendCount {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSendCountHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/send_count
+impl AsyncQuery for TrackSendCount {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send_count", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_send_count_args(msg: &rosc::OscMessage) -> Result<TrackSendCountArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(TrackSendCountArgs {
+        send_count: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackSendGuidArgs {
+    pub guid: String, // unique identifier for the send
+}
+
+impl TrackSendGuidArgs {
+    pub fn new(guid: impl Into<String>) -> Self {
+        Self { guid: guid.into() }
+    }
+}
+
+impl From<String> for TrackSendGuidArgs {
+    fn from(guid: String) -> Self {
+        Self::new(guid)
+    }
+}
+
+impl From<&str> for TrackSendGuidArgs {
+    fn from(guid: &str) -> Self {
+        Self::new(guid)
+    }
+}
+
+pub type TrackSendGuidHandler = Box<dyn FnMut(TrackSendGuidArgs) + 'static>;
+
+pub struct TrackSendGuid {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackSendGuidHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub send_index: i32,
+}
+
+impl TrackSendGuid {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/send/{send_index}/guid";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/send/{}/guid", self.track_guid, self.send_index)
+    }
+}
+
+impl std::fmt::Display for TrackSendGuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/guid
+impl Bind<TrackSendGuidArgs> for TrackSendGuid {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackSendGuidArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/guid
+impl Subscribe<TrackSendGuidArgs> for TrackSendGuid {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackSendGuidArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackSendGuid {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSendGuidHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/guid
+impl AsyncQuery for TrackSendGuid {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/guid", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_send_guid_args(msg: &rosc::OscMessage) -> Result<TrackSendGuidArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(TrackSendGuidArgs {
+        guid: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackSendVolumeArgs {
+    pub volume: f32, // volume of the send, normalized to 0 to 1.
+}
+
+impl TrackSendVolumeArgs {
+    pub fn new(volume: f32) -> Self {
+        Self { volume }
+    }
+}
+
+impl From<f32> for TrackSendVolumeArgs {
+    fn from(volume: f32) -> Self {
+        Self::new(volume)
+    }
+}
+
+pub type TrackSendVolumeHandler = Box<dyn FnMut(TrackSendVolumeArgs) + 'static>;
+
+pub struct TrackSendVolume {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackSendVolumeHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub send_index: i32,
+}
+
+impl TrackSendVolume {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/send/{send_index}/volume";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/send/{}/volume", self.track_guid, self.send_index)
+    }
+}
+
+impl std::fmt::Display for TrackSendVolume {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/volume
+impl AsyncSet<TrackSendVolumeArgs> for TrackSendVolume {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackSendVolumeArgs) -> Result<(), Self::Error> {
+        let mut args = args;
+        args.volume = crate::osc::range::clamp(args.volume, Some(0.0), Some(1.0));
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/volume", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.volume)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackSendVolume {
+    pub async fn set_value(
+        &mut self,
+        value: impl Into<TrackSendVolumeArgs>,
+    ) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/volume
+impl Bind<TrackSendVolumeArgs> for TrackSendVolume {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackSendVolumeArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/volume
+impl Subscribe<TrackSendVolumeArgs> for TrackSendVolume {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackSendVolumeArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackSendVolume {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSendVolumeHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/volume
+impl AsyncQuery for TrackSendVolume {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/volume", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_send_volume_args(msg: &rosc::OscMessage) -> Result<TrackSendVolumeArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = TrackSendVolumeArgs {
+        volume: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("volume", args.volume, Some(0.0), Some(1.0))?;
+    Ok(args)
+}
+
+#[derive(Debug)]
+pub struct TrackSendPanArgs {
+    pub pan: f32, // pan of the send, normalized to -1.0 to 1.0
+}
+
+impl TrackSendPanArgs {
+    pub fn new(pan: f32) -> Self {
+        Self { pan }
+    }
+}
+
+impl From<f32> for TrackSendPanArgs {
+    fn from(pan: f32) -> Self {
+        Self::new(pan)
+    }
+}
+
+pub type TrackSendPanHandler = Box<dyn FnMut(TrackSendPanArgs) + 'static>;
+
+pub struct TrackSendPan {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackSendPanHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub send_index: i32,
+}
+
+impl TrackSendPan {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/send/{send_index}/pan";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/send/{}/pan", self.track_guid, self.send_index)
+    }
+}
+
+impl std::fmt::Display for TrackSendPan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/pan
+impl AsyncSet<TrackSendPanArgs> for TrackSendPan {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackSendPanArgs) -> Result<(), Self::Error> {
+        let mut args = args;
+        args.pan = crate::osc::range::clamp(args.pan, Some(-1.0), Some(1.0));
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/pan", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.pan)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackSendPan {
+    pub async fn set_value(&mut self, value: impl Into<TrackSendPanArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/pan
+impl Bind<TrackSendPanArgs> for TrackSendPan {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackSendPanArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/pan
+impl Subscribe<TrackSendPanArgs> for TrackSendPan {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackSendPanArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackSendPan {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSendPanHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/pan
+impl AsyncQuery for TrackSendPan {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/pan", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_send_pan_args(msg: &rosc::OscMessage) -> Result<TrackSendPanArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    let args = TrackSendPanArgs {
+        pan: decode::required(&osc_args, 0)?,
+    };
+    crate::osc::range::check_range("pan", args.pan, Some(-1.0), Some(1.0))?;
+    Ok(args)
+}
+
+#[derive(Debug)]
+pub struct TrackSendMuteArgs {
+    pub mute: bool, // true means the send is muted
+}
+
+impl TrackSendMuteArgs {
+    pub fn new(mute: bool) -> Self {
+        Self { mute }
+    }
+}
+
+impl From<bool> for TrackSendMuteArgs {
+    fn from(mute: bool) -> Self {
+        Self::new(mute)
+    }
+}
+
+pub type TrackSendMuteHandler = Box<dyn FnMut(TrackSendMuteArgs) + 'static>;
+
+pub struct TrackSendMute {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackSendMuteHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub send_index: i32,
+}
+
+impl TrackSendMute {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/send/{send_index}/mute";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/send/{}/mute", self.track_guid, self.send_index)
+    }
+}
+
+impl std::fmt::Display for TrackSendMute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/mute
+impl AsyncSet<TrackSendMuteArgs> for TrackSendMute {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackSendMuteArgs) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/mute", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.mute)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackSendMute {
+    pub async fn set_value(&mut self, value: impl Into<TrackSendMuteArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/mute
+impl Bind<TrackSendMuteArgs> for TrackSendMute {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackSendMuteArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/mute
+impl Subscribe<TrackSendMuteArgs> for TrackSendMute {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackSendMuteArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackSendMute {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackSendMuteHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/send/{send_index}/mute
+impl AsyncQuery for TrackSendMute {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/send/{}/mute", self.track_guid, self.send_index);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_send_mute_args(msg: &rosc::OscMessage) -> Result<TrackSendMuteArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackSendMuteArgs {
+        mute: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackColorArgs {
+    pub color: i32, // color of the track, represented as an RGB integer
+}
+
+impl TrackColorArgs {
+    pub fn new(color: i32) -> Self {
+        Self { color }
+    }
+}
+
+impl From<i32> for TrackColorArgs {
+    fn from(color: i32) -> Self {
+        Self::new(color)
+    }
+}
+
+pub type TrackColorHandler = Box<dyn FnMut(TrackColorArgs) + 'static>;
+
+pub struct TrackColor {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackColorHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+}
+
+impl TrackColor {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/color";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/color", self.track_guid)
+    }
+}
+
+impl std::fmt::Display for TrackColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/color
+impl AsyncSet<TrackColorArgs> for TrackColor {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackColorArgs) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/color", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Int(args.color)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackColor {
+    pub async fn set_value(&mut self, value: impl Into<TrackColorArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/color
+impl Bind<TrackColorArgs> for TrackColor {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackColorArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/color
+impl Subscribe<TrackColorArgs> for TrackColor {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackColorArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackColor {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackColorHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/color
+impl AsyncQuery for TrackColor {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/color", self.track_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_color_args(msg: &rosc::OscMessage) -> Result<TrackColorArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(TrackColorArgs {
+        color: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackFxGuidArgs {
+    pub guid: String, // unique identifier for the FX
+}
+
+impl TrackFxGuidArgs {
+    pub fn new(guid: impl Into<String>) -> Self {
+        Self { guid: guid.into() }
+    }
+}
+
+impl From<String> for TrackFxGuidArgs {
+    fn from(guid: String) -> Self {
+        Self::new(guid)
+    }
+}
+
+impl From<&str> for TrackFxGuidArgs {
+    fn from(guid: &str) -> Self {
+        Self::new(guid)
+    }
+}
+
+pub type TrackFxGuidHandler = Box<dyn FnMut(TrackFxGuidArgs) + 'static>;
+
+pub struct TrackFxGuid {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackFxGuidHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxGuid {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/guid";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/guid", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxGuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/guid
+impl Bind<TrackFxGuidArgs> for TrackFxGuid {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxGuidArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/guid
+impl Subscribe<TrackFxGuidArgs> for TrackFxGuid {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxGuidArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackFxGuid {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxGuidHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/guid
+impl AsyncQuery for TrackFxGuid {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/guid", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_guid_args(msg: &rosc::OscMessage) -> Result<TrackFxGuidArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(TrackFxGuidArgs {
+        guid: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackFxNameArgs {
+    pub name: String, // name of the FX
+}
+
+impl TrackFxNameArgs {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl From<String> for TrackFxNameArgs {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&str> for TrackFxNameArgs {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+pub type TrackFxNameHandler = Box<dyn FnMut(TrackFxNameArgs) + 'static>;
+
+pub struct TrackFxName {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackFxNameHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/name", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/name
+impl Bind<TrackFxNameArgs> for TrackFxName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxNameArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/name
+impl Subscribe<TrackFxNameArgs> for TrackFxName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxNameArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackFxName {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxNameHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/name
+impl AsyncQuery for TrackFxName {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/name", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_name_args(msg: &rosc::OscMessage) -> Result<TrackFxNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(TrackFxNameArgs {
+        name: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackFxEnabledArgs {
+    pub enabled: bool, // true if the FX is enabled
+}
+
+impl TrackFxEnabledArgs {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
+impl From<bool> for TrackFxEnabledArgs {
+    fn from(enabled: bool) -> Self {
+        Self::new(enabled)
+    }
+}
+
+pub type TrackFxEnabledHandler = Box<dyn FnMut(TrackFxEnabledArgs) + 'static>;
+
+pub struct TrackFxEnabled {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackFxEnabledHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxEnabled {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/enabled";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/enabled", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxEnabled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/enabled
+impl AsyncSet<TrackFxEnabledArgs> for TrackFxEnabled {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackFxEnabledArgs) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/enabled", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.enabled)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackFxEnabled {
+    pub async fn set_value(
+        &mut self,
+        value: impl Into<TrackFxEnabledArgs>,
+    ) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/enabled
+impl Bind<TrackFxEnabledArgs> for TrackFxEnabled {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxEnabledArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/enabled
+impl Subscribe<TrackFxEnabledArgs> for TrackFxEnabled {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxEnabledArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackFxEnabled {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxEnabledHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/enabled
+impl AsyncQuery for TrackFxEnabled {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/enabled", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_enabled_args(msg: &rosc::OscMessage) -> Result<TrackFxEnabledArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackFxEnabledArgs {
+        enabled: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackFxBypassArgs {
+    pub bypass: bool, // true if the FX is bypassed
+}
+
+impl TrackFxBypassArgs {
+    pub fn new(bypass: bool) -> Self {
+        Self { bypass }
+    }
+}
+
+impl From<bool> for TrackFxBypassArgs {
+    fn from(bypass: bool) -> Self {
+        Self::new(bypass)
+    }
+}
+
+pub type TrackFxBypassHandler = Box<dyn FnMut(TrackFxBypassArgs) + 'static>;
+
+pub struct TrackFxBypass {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackFxBypassHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxBypass {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/bypass";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/bypass", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxBypass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/bypass
+impl AsyncSet<TrackFxBypassArgs> for TrackFxBypass {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackFxBypassArgs) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/bypass", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.bypass)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackFxBypass {
+    pub async fn set_value(&mut self, value: impl Into<TrackFxBypassArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/bypass
+impl Bind<TrackFxBypassArgs> for TrackFxBypass {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxBypassArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/bypass
+impl Subscribe<TrackFxBypassArgs> for TrackFxBypass {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxBypassArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackFxBypass {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxBypassHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/bypass
+impl AsyncQuery for TrackFxBypass {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/bypass", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_bypass_args(msg: &rosc::OscMessage) -> Result<TrackFxBypassArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(TrackFxBypassArgs {
+        bypass: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackFxWetArgs {
+    pub wet: f32, // wet/dry mix of the FX, from 0 (dry) to 1 (wet)
+}
+
+impl TrackFxWetArgs {
+    pub fn new(wet: f32) -> Self {
+        Self { wet }
+    }
+}
+
+impl From<f32> for TrackFxWetArgs {
+    fn from(wet: f32) -> Self {
+        Self::new(wet)
+    }
+}
+
+pub type TrackFxWetHandler = Box<dyn FnMut(TrackFxWetArgs) + 'static>;
+
+pub struct TrackFxWet {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackFxWetHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxWet {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/wet";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/wet", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxWet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/wet
+impl AsyncSet<TrackFxWetArgs> for TrackFxWet {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackFxWetArgs) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/wet", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.wet)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackFxWet {
+    pub async fn set_value(&mut self, value: impl Into<TrackFxWetArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/wet
+impl Bind<TrackFxWetArgs> for TrackFxWet {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxWetArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/wet
+impl Subscribe<TrackFxWetArgs> for TrackFxWet {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxWetArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackFxWet {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxWetHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/wet
+impl AsyncQuery for TrackFxWet {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/wet", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_wet_args(msg: &rosc::OscMessage) -> Result<TrackFxWetArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(TrackFxWetArgs {
+        wet: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackFxParamCountArgs {
+    pub param_count: i32, // number of parameters for the FX
+}
+
+impl TrackFxParamCountArgs {
+    pub fn new(param_count: i32) -> Self {
+        Self { param_count }
+    }
+}
+
+impl From<i32> for TrackFxParamCountArgs {
+    fn from(param_count: i32) -> Self {
+        Self::new(param_count)
+    }
+}
+
+pub type TrackFxParamCountHandler = Box<dyn FnMut(TrackFxParamCountArgs) + 'static>;
+
+pub struct TrackFxParamCount {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackFxParamCountHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxParamCount {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/param_count";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/param_count", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxParamCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param_count
+impl Bind<TrackFxParamCountArgs> for TrackFxParamCount {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxParamCountArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param_count
+impl Subscribe<TrackFxParamCountArgs> for TrackFxParamCount {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxParamCountArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackFxParamCount {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxParamCountHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param_count
+impl AsyncQuery for TrackFxParamCount {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/param_count", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_param_count_args(
+    msg: &rosc::OscMessage,
+) -> Result<TrackFxParamCountArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(TrackFxParamCountArgs {
+        param_count: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackFxParamNameArgs {
+    pub param_name: String, // name of the parameter
+}
+
+impl TrackFxParamNameArgs {
+    pub fn new(param_name: impl Into<String>) -> Self {
+        Self {
+            param_name: param_name.into(),
+        }
+    }
+}
+
+impl From<String> for TrackFxParamNameArgs {
+    fn from(param_name: String) -> Self {
+        Self::new(param_name)
+    }
+}
+
+impl From<&str> for TrackFxParamNameArgs {
+    fn from(param_name: &str) -> Self {
+        Self::new(param_name)
+    }
+}
+
+pub type TrackFxParamNameHandler = Box<dyn FnMut(TrackFxParamNameArgs) + 'static>;
+
+pub struct TrackFxParamName {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackFxParamNameHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+    pub param_idx: i32,
+}
+
+impl TrackFxParamName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!(
+            "/track/{}/fx/{}/param/{}/name",
+            self.track_guid, self.fx_idx, self.param_idx
+        )
+    }
+}
+
+impl std::fmt::Display for TrackFxParamName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name
+impl Bind<TrackFxParamNameArgs> for TrackFxParamName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxParamNameArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name
+impl Subscribe<TrackFxParamNameArgs> for TrackFxParamName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxParamNameArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackFxParamName {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxParamNameHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name
+impl AsyncQuery for TrackFxParamName {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/name",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_param_name_args(msg: &rosc::OscMessage) -> Result<TrackFxParamNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(TrackFxParamNameArgs {
+        param_name: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackFxParamValueArgs {
+    pub value: f32, // value of the parameter
+}
+
+impl TrackFxParamValueArgs {
+    pub fn new(value: f32) -> Self {
+        Self { value }
+    }
+}
+
+impl From<f32> for TrackFxParamValueArgs {
+    fn from(value: f32) -> Self {
+        Self::new(value)
+    }
+}
+
+pub type TrackFxParamValueHandler = Box<dyn FnMut(TrackFxParamValueArgs) + 'static>;
+
+pub struct TrackFxParamValue {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackFxParamValueHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+    pub param_idx: i32,
+}
+
+impl TrackFxParamValue {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!(
+            "/track/{}/fx/{}/param/{}/value",
+            self.track_guid, self.fx_idx, self.param_idx
+        )
+    }
+}
+
+impl std::fmt::Display for TrackFxParamValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
+impl AsyncSet<TrackFxParamValueArgs> for TrackFxParamValue {
+    type Error = OscError;
+    async fn set(&mut self, args: TrackFxParamValueArgs) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/value",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.value)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl TrackFxParamValue {
+    pub async fn set_value(
+        &mut self,
+        value: impl Into<TrackFxParamValueArgs>,
+    ) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
+impl Bind<TrackFxParamValueArgs> for TrackFxParamValue {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxParamValueArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
+impl Subscribe<TrackFxParamValueArgs> for TrackFxParamValue {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxParamValueArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackFxParamValue {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxParamValueHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
+impl AsyncQuery for TrackFxParamValue {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/value",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_param_value_args(
+    msg: &rosc::OscMessage,
+) -> Result<TrackFxParamValueArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(TrackFxParamValueArgs {
+        value: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackFxParamMinArgs {
+    pub min: f32, // minimum value of the parameter
+}
+
+impl TrackFxParamMinArgs {
+    pub fn new(min: f32) -> Self {
+        Self { min }
+    }
+}
+
+impl From<f32> for TrackFxParamMinArgs {
+    fn from(min: f32) -> Self {
+        Self::new(min)
+    }
+}
+
+pub type TrackFxParamMinHandler = Box<dyn FnMut(TrackFxParamMinArgs) + 'static>;
+
+pub struct TrackFxParamMin {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackFxParamMinHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+    pub param_idx: i32,
+}
+
+impl TrackFxParamMin {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!(
+            "/track/{}/fx/{}/param/{}/min",
+            self.track_guid, self.fx_idx, self.param_idx
+        )
+    }
+}
+
+impl std::fmt::Display for TrackFxParamMin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min
+impl Bind<TrackFxParamMinArgs> for TrackFxParamMin {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxParamMinArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min
+impl Subscribe<TrackFxParamMinArgs> for TrackFxParamMin {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxParamMinArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackFxParamMin {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxParamMinHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min
+impl AsyncQuery for TrackFxParamMin {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/min",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_param_min_args(msg: &rosc::OscMessage) -> Result<TrackFxParamMinArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(TrackFxParamMinArgs {
+        min: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackFxParamMaxArgs {
+    pub max: f32, // maximum value of the parameter
+}
+
+impl TrackFxParamMaxArgs {
+    pub fn new(max: f32) -> Self {
+        Self { max }
+    }
+}
+
+impl From<f32> for TrackFxParamMaxArgs {
+    fn from(max: f32) -> Self {
+        Self::new(max)
+    }
+}
+
+pub type TrackFxParamMaxHandler = Box<dyn FnMut(TrackFxParamMaxArgs) + 'static>;
+
+pub struct TrackFxParamMax {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackFxParamMaxHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+    pub param_idx: i32,
+}
+
+impl TrackFxParamMax {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!(
+            "/track/{}/fx/{}/param/{}/max",
+            self.track_guid, self.fx_idx, self.param_idx
+        )
+    }
+}
+
+impl std::fmt::Display for TrackFxParamMax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max
+impl Bind<TrackFxParamMaxArgs> for TrackFxParamMax {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TrackFxParamMaxArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max
+impl Subscribe<TrackFxParamMaxArgs> for TrackFxParamMax {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TrackFxParamMaxArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl TrackFxParamMax {
+    fn add_handler(&mut self, subscription: Subscription, handler: TrackFxParamMaxHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max
+impl AsyncQuery for TrackFxParamMax {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!(
+            "/track/{}/fx/{}/param/{}/max",
+            self.track_guid, self.fx_idx, self.param_idx
+        );
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_param_max_args(msg: &rosc::OscMessage) -> Result<TrackFxParamMaxArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(TrackFxParamMaxArgs {
+        max: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TrackFxInfoArgs {}
+
+pub type TrackFxInfoHandler = Box<dyn FnMut(TrackFxInfoArgs) + 'static>;
+
+pub struct TrackFxInfo {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TrackFxInfoHandler)>,
+    pub track_guid: crate::guid::TrackGuid,
+    pub fx_idx: i32,
+}
+
+impl TrackFxInfo {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/track/{track_guid}/fx/{fx_idx}/info";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/track/{}/fx/{}/info", self.track_guid, self.fx_idx)
+    }
+}
+
+impl std::fmt::Display for TrackFxInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/info
+impl AsyncQuery for TrackFxInfo {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.track_guid)?;
+        let osc_address = format!("/track/{}/fx/{}/info", self.track_guid, self.fx_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_track_fx_info_args(_msg: &rosc::OscMessage) -> Result<TrackFxInfoArgs, String> {
+    Ok(TrackFxInfoArgs {})
+}
+
+#[derive(Debug)]
+pub struct FxinfoNameArgs {
+    pub name: String, // name of the FX
+}
+
+impl FxinfoNameArgs {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl From<String> for FxinfoNameArgs {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&str> for FxinfoNameArgs {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+pub type FxinfoNameHandler = Box<dyn FnMut(FxinfoNameArgs) + 'static>;
+
+pub struct FxinfoName {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, FxinfoNameHandler)>,
+    pub ident: String,
+}
+
+impl FxinfoName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/fxinfo/{ident}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/fxinfo/{}/name", self.ident)
+    }
+}
+
+impl std::fmt::Display for FxinfoName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /fxinfo/{ident}/name
+impl Bind<FxinfoNameArgs> for FxinfoName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(FxinfoNameArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /fxinfo/{ident}/name
+impl Subscribe<FxinfoNameArgs> for FxinfoName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(FxinfoNameArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl FxinfoName {
+    fn add_handler(&mut self, subscription: Subscription, handler: FxinfoNameHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+fn parse_fxinfo_name_args(msg: &rosc::OscMessage) -> Result<FxinfoNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(FxinfoNameArgs {
+        name: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct FxinfoParamCountArgs {
+    pub param_count: i32, // number of parameters for the FX
+}
+
+impl FxinfoParamCountArgs {
+    pub fn new(param_count: i32) -> Self {
+        Self { param_count }
+    }
+}
+
+impl From<i32> for FxinfoParamCountArgs {
+    fn from(param_count: i32) -> Self {
+        Self::new(param_count)
+    }
+}
+
+pub type FxinfoParamCountHandler = Box<dyn FnMut(FxinfoParamCountArgs) + 'static>;
+
+pub struct FxinfoParamCount {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, FxinfoParamCountHandler)>,
+    pub ident: String,
+}
+
+impl FxinfoParamCount {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/fxinfo/{ident}/param_count";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/fxinfo/{}/param_count", self.ident)
+    }
+}
+
+impl std::fmt::Display for FxinfoParamCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /fxinfo/{ident}/param_count
+impl Bind<FxinfoParamCountArgs> for FxinfoParamCount {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(FxinfoParamCountArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /fxinfo/{ident}/param_count
+impl Subscribe<FxinfoParamCountArgs> for FxinfoParamCount {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(FxinfoParamCountArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl FxinfoParamCount {
+    fn add_handler(&mut self, subscription: Subscription, handler: FxinfoParamCountHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /fxinfo/{ident}/param_count
+impl AsyncQuery for FxinfoParamCount {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.ident)?;
+        let osc_address = format!("/fxinfo/{}/param_count", self.ident);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_fxinfo_param_count_args(msg: &rosc::OscMessage) -> Result<FxinfoParamCountArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(FxinfoParamCountArgs {
+        param_count: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct FxinfoParamNameArgs {
+    pub param_name: String, // name of the parameter
+}
+
+impl FxinfoParamNameArgs {
+    pub fn new(param_name: impl Into<String>) -> Self {
+        Self {
+            param_name: param_name.into(),
+        }
+    }
+}
+
+impl From<String> for FxinfoParamNameArgs {
+    fn from(param_name: String) -> Self {
+        Self::new(param_name)
+    }
+}
+
+impl From<&str> for FxinfoParamNameArgs {
+    fn from(param_name: &str) -> Self {
+        Self::new(param_name)
+    }
+}
+
+pub type FxinfoParamNameHandler = Box<dyn FnMut(FxinfoParamNameArgs) + 'static>;
+
+pub struct FxinfoParamName {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, FxinfoParamNameHandler)>,
+    pub ident: String,
+    pub param_idx: i32,
+}
+
+impl FxinfoParamName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/fxinfo/{ident}/param/{param_idx}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/fxinfo/{}/param/{}/name", self.ident, self.param_idx)
+    }
+}
+
+impl std::fmt::Display for FxinfoParamName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/name
+impl Bind<FxinfoParamNameArgs> for FxinfoParamName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(FxinfoParamNameArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/name
+impl Subscribe<FxinfoParamNameArgs> for FxinfoParamName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(FxinfoParamNameArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl FxinfoParamName {
+    fn add_handler(&mut self, subscription: Subscription, handler: FxinfoParamNameHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/name
+impl AsyncQuery for FxinfoParamName {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.ident)?;
+        let osc_address = format!("/fxinfo/{}/param/{}/name", self.ident, self.param_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_fxinfo_param_name_args(msg: &rosc::OscMessage) -> Result<FxinfoParamNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(FxinfoParamNameArgs {
+        param_name: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct FxinfoParamMinArgs {
+    pub param_min: f32, // minimum raw value of the parameter
+}
+
+impl FxinfoParamMinArgs {
+    pub fn new(param_min: f32) -> Self {
+        Self { param_min }
+    }
+}
+
+impl From<f32> for FxinfoParamMinArgs {
+    fn from(param_min: f32) -> Self {
+        Self::new(param_min)
+    }
+}
+
+pub type FxinfoParamMinHandler = Box<dyn FnMut(FxinfoParamMinArgs) + 'static>;
+
+pub struct FxinfoParamMin {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, FxinfoParamMinHandler)>,
+    pub ident: String,
+    pub param_idx: i32,
+}
+
+impl FxinfoParamMin {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/fxinfo/{ident}/param/{param_idx}/min";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/fxinfo/{}/param/{}/min", self.ident, self.param_idx)
+    }
+}
+
+impl std::fmt::Display for FxinfoParamMin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/min
+impl Bind<FxinfoParamMinArgs> for FxinfoParamMin {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(FxinfoParamMinArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/min
+impl Subscribe<FxinfoParamMinArgs> for FxinfoParamMin {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(FxinfoParamMinArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl FxinfoParamMin {
+    fn add_handler(&mut self, subscription: Subscription, handler: FxinfoParamMinHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/min
+impl AsyncQuery for FxinfoParamMin {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.ident)?;
+        let osc_address = format!("/fxinfo/{}/param/{}/min", self.ident, self.param_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_fxinfo_param_min_args(msg: &rosc::OscMessage) -> Result<FxinfoParamMinArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(FxinfoParamMinArgs {
+        param_min: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct FxinfoParamMaxArgs {
+    pub param_max: f32, // maximum raw value of the parameter
+}
+
+impl FxinfoParamMaxArgs {
+    pub fn new(param_max: f32) -> Self {
+        Self { param_max }
+    }
+}
+
+impl From<f32> for FxinfoParamMaxArgs {
+    fn from(param_max: f32) -> Self {
+        Self::new(param_max)
+    }
+}
+
+pub type FxinfoParamMaxHandler = Box<dyn FnMut(FxinfoParamMaxArgs) + 'static>;
+
+pub struct FxinfoParamMax {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, FxinfoParamMaxHandler)>,
+    pub ident: String,
+    pub param_idx: i32,
+}
+
+impl FxinfoParamMax {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/fxinfo/{ident}/param/{param_idx}/max";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/fxinfo/{}/param/{}/max", self.ident, self.param_idx)
+    }
+}
+
+impl std::fmt::Display for FxinfoParamMax {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/max
+impl Bind<FxinfoParamMaxArgs> for FxinfoParamMax {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(FxinfoParamMaxArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/max
+impl Subscribe<FxinfoParamMaxArgs> for FxinfoParamMax {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(FxinfoParamMaxArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl FxinfoParamMax {
+    fn add_handler(&mut self, subscription: Subscription, handler: FxinfoParamMaxHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/max
+impl AsyncQuery for FxinfoParamMax {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.ident)?;
+        let osc_address = format!("/fxinfo/{}/param/{}/max", self.ident, self.param_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_fxinfo_param_max_args(msg: &rosc::OscMessage) -> Result<FxinfoParamMaxArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(FxinfoParamMaxArgs {
+        param_max: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct FxinfoArgs {}
+
+pub type FxinfoHandler = Box<dyn FnMut(FxinfoArgs) + 'static>;
+
+pub struct Fxinfo {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, FxinfoHandler)>,
+}
+
+impl Fxinfo {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/fxinfo";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Fxinfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /fxinfo
+impl AsyncQuery for Fxinfo {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/fxinfo");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_fxinfo_args(_msg: &rosc::OscMessage) -> Result<FxinfoArgs, String> {
+    Ok(FxinfoArgs {})
+}
+
+#[derive(Debug)]
+pub struct PlayArgs {
+    pub playing: bool, // true means the transport is playing
+}
+
+impl PlayArgs {
+    pub fn new(playing: bool) -> Self {
+        Self { playing }
+    }
+}
+
+impl From<bool> for PlayArgs {
+    fn from(playing: bool) -> Self {
+        Self::new(playing)
+    }
+}
+
+pub type PlayHandler = Box<dyn FnMut(PlayArgs) + 'static>;
+
+pub struct Play {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, PlayHandler)>,
+}
+
+impl Play {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/play";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Play {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /play
+impl AsyncSet<PlayArgs> for Play {
+    type Error = OscError;
+    async fn set(&mut self, args: PlayArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/play");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.playing)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl Play {
+    pub async fn set_value(&mut self, value: impl Into<PlayArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /play
+impl Bind<PlayArgs> for Play {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(PlayArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /play
+impl Subscribe<PlayArgs> for Play {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(PlayArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl Play {
+    fn add_handler(&mut self, subscription: Subscription, handler: PlayHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /play
+impl AsyncQuery for Play {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/play");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_play_args(msg: &rosc::OscMessage) -> Result<PlayArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(PlayArgs {
+        playing: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct StopArgs {}
+
+pub type StopHandler = Box<dyn FnMut(StopArgs) + 'static>;
+
+pub struct Stop {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, StopHandler)>,
+}
+
+impl Stop {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/stop";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Stop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /stop
+impl AsyncSet<StopArgs> for Stop {
+    type Error = OscError;
+    async fn set(&mut self, args: StopArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/stop");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_stop_args(_msg: &rosc::OscMessage) -> Result<StopArgs, String> {
+    Ok(StopArgs {})
+}
+
+#[derive(Debug)]
+pub struct RecordArgs {
+    pub recording: bool, // true means the transport is recording
+}
+
+impl RecordArgs {
+    pub fn new(recording: bool) -> Self {
+        Self { recording }
+    }
+}
+
+impl From<bool> for RecordArgs {
+    fn from(recording: bool) -> Self {
+        Self::new(recording)
+    }
+}
+
+pub type RecordHandler = Box<dyn FnMut(RecordArgs) + 'static>;
+
+pub struct Record {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, RecordHandler)>,
+}
+
+impl Record {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/record";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Record {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /record
+impl AsyncSet<RecordArgs> for Record {
+    type Error = OscError;
+    async fn set(&mut self, args: RecordArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/record");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.recording)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl Record {
+    pub async fn set_value(&mut self, value: impl Into<RecordArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /record
+impl Bind<RecordArgs> for Record {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(RecordArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /record
+impl Subscribe<RecordArgs> for Record {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(RecordArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl Record {
+    fn add_handler(&mut self, subscription: Subscription, handler: RecordHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /record
+impl AsyncQuery for Record {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/record");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_record_args(msg: &rosc::OscMessage) -> Result<RecordArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(RecordArgs {
+        recording: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct RepeatArgs {
+    pub repeat: bool, // true means playback loops over the time selection
+}
+
+impl RepeatArgs {
+    pub fn new(repeat: bool) -> Self {
+        Self { repeat }
+    }
+}
+
+impl From<bool> for RepeatArgs {
+    fn from(repeat: bool) -> Self {
+        Self::new(repeat)
+    }
+}
+
+pub type RepeatHandler = Box<dyn FnMut(RepeatArgs) + 'static>;
+
+pub struct Repeat {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, RepeatHandler)>,
+}
+
+impl Repeat {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/repeat";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Repeat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /repeat
+impl AsyncSet<RepeatArgs> for Repeat {
+    type Error = OscError;
+    async fn set(&mut self, args: RepeatArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/repeat");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.repeat)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl Repeat {
+    pub async fn set_value(&mut self, value: impl Into<RepeatArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /repeat
+impl Bind<RepeatArgs> for Repeat {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(RepeatArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /repeat
+impl Subscribe<RepeatArgs> for Repeat {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(RepeatArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl Repeat {
+    fn add_handler(&mut self, subscription: Subscription, handler: RepeatHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /repeat
+impl AsyncQuery for Repeat {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/repeat");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_repeat_args(msg: &rosc::OscMessage) -> Result<RepeatArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(RepeatArgs {
+        repeat: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct RewindArgs {
+    pub held: bool, // true rewinds until sent again with false
+}
+
+impl RewindArgs {
+    pub fn new(held: bool) -> Self {
+        Self { held }
+    }
+}
+
+impl From<bool> for RewindArgs {
+    fn from(held: bool) -> Self {
+        Self::new(held)
+    }
+}
+
+pub type RewindHandler = Box<dyn FnMut(RewindArgs) + 'static>;
+
+pub struct Rewind {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, RewindHandler)>,
+}
+
+impl Rewind {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/rewind";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Rewind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /rewind
+impl AsyncSet<RewindArgs> for Rewind {
+    type Error = OscError;
+    async fn set(&mut self, args: RewindArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/rewind");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.held)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl Rewind {
+    pub async fn set_value(&mut self, value: impl Into<RewindArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+fn parse_rewind_args(msg: &rosc::OscMessage) -> Result<RewindArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(RewindArgs {
+        held: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct ForwardArgs {
+    pub held: bool, // true fast forwards until sent again with false
+}
+
+impl ForwardArgs {
+    pub fn new(held: bool) -> Self {
+        Self { held }
+    }
+}
+
+impl From<bool> for ForwardArgs {
+    fn from(held: bool) -> Self {
+        Self::new(held)
+    }
+}
+
+pub type ForwardHandler = Box<dyn FnMut(ForwardArgs) + 'static>;
+
+pub struct Forward {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, ForwardHandler)>,
+}
+
+impl Forward {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/forward";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Forward {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /forward
+impl AsyncSet<ForwardArgs> for Forward {
+    type Error = OscError;
+    async fn set(&mut self, args: ForwardArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/forward");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Bool(args.held)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl Forward {
+    pub async fn set_value(&mut self, value: impl Into<ForwardArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+fn parse_forward_args(msg: &rosc::OscMessage) -> Result<ForwardArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(ForwardArgs {
+        held: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TimeArgs {
+    pub time: f32, // position of the play cursor in seconds
+}
+
+impl TimeArgs {
+    pub fn new(time: f32) -> Self {
+        Self { time }
+    }
+}
+
+impl From<f32> for TimeArgs {
+    fn from(time: f32) -> Self {
+        Self::new(time)
+    }
+}
+
+pub type TimeHandler = Box<dyn FnMut(TimeArgs) + 'static>;
+
+pub struct Time {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TimeHandler)>,
+}
+
+impl Time {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/time";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /time
+impl AsyncSet<TimeArgs> for Time {
+    type Error = OscError;
+    async fn set(&mut self, args: TimeArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/time");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.time)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl Time {
+    pub async fn set_value(&mut self, value: impl Into<TimeArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /time
+impl Bind<TimeArgs> for Time {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TimeArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /time
+impl Subscribe<TimeArgs> for Time {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TimeArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl Time {
+    fn add_handler(&mut self, subscription: Subscription, handler: TimeHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /time
+impl AsyncQuery for Time {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/time");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_time_args(msg: &rosc::OscMessage) -> Result<TimeArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(TimeArgs {
+        time: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct TempoArgs {
+    pub bpm: f32, // tempo of the project at the play cursor, in beats per minute
+}
+
+impl TempoArgs {
+    pub fn new(bpm: f32) -> Self {
+        Self { bpm }
+    }
+}
+
+impl From<f32> for TempoArgs {
+    fn from(bpm: f32) -> Self {
+        Self::new(bpm)
+    }
+}
+
+pub type TempoHandler = Box<dyn FnMut(TempoArgs) + 'static>;
+
+pub struct Tempo {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, TempoHandler)>,
+}
+
+impl Tempo {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/tempo";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Tempo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /tempo
+impl AsyncSet<TempoArgs> for Tempo {
+    type Error = OscError;
+    async fn set(&mut self, args: TempoArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/tempo");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.bpm)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl Tempo {
+    pub async fn set_value(&mut self, value: impl Into<TempoArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /tempo
+impl Bind<TempoArgs> for Tempo {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(TempoArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /tempo
+impl Subscribe<TempoArgs> for Tempo {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(TempoArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl Tempo {
+    fn add_handler(&mut self, subscription: Subscription, handler: TempoHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /tempo
+impl AsyncQuery for Tempo {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/tempo");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_tempo_args(msg: &rosc::OscMessage) -> Result<TempoArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(TempoArgs {
+        bpm: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct ActiveProjectArgs {
+    pub project_guid: String, // GUID of the project in the active tab, reported again whenever another tab becomes active
+}
+
+impl ActiveProjectArgs {
+    pub fn new(project_guid: impl Into<String>) -> Self {
+        Self {
+            project_guid: project_guid.into(),
+        }
+    }
+}
+
+impl From<String> for ActiveProjectArgs {
+    fn from(project_guid: String) -> Self {
+        Self::new(project_guid)
+    }
+}
+
+impl From<&str> for ActiveProjectArgs {
+    fn from(project_guid: &str) -> Self {
+        Self::new(project_guid)
+    }
+}
+
+pub type ActiveProjectHandler = Box<dyn FnMut(ActiveProjectArgs) + 'static>;
+
+pub struct ActiveProject {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, ActiveProjectHandler)>,
+}
+
+impl ActiveProject {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/project/guid";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for ActiveProject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /project/guid
+impl Bind<ActiveProjectArgs> for ActiveProject {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(ActiveProjectArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /project/guid
+impl Subscribe<ActiveProjectArgs> for ActiveProject {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(ActiveProjectArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl ActiveProject {
+    fn add_handler(&mut self, subscription: Subscription, handler: ActiveProjectHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /project/guid
+impl AsyncQuery for ActiveProject {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/project/guid");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_active_project_args(msg: &rosc::OscMessage) -> Result<ActiveProjectArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(ActiveProjectArgs {
+        project_guid: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct ProjectNameArgs {
+    pub name: String, // name of the project, as shown on its tab
+}
+
+impl ProjectNameArgs {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl From<String> for ProjectNameArgs {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&str> for ProjectNameArgs {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+pub type ProjectNameHandler = Box<dyn FnMut(ProjectNameArgs) + 'static>;
+
+pub struct ProjectName {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, ProjectNameHandler)>,
+    pub project_guid: crate::guid::ProjectGuid,
+}
+
+impl ProjectName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/project/{project_guid}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/project/{}/name", self.project_guid)
+    }
+}
+
+impl std::fmt::Display for ProjectName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /project/{project_guid}/name
+impl Bind<ProjectNameArgs> for ProjectName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(ProjectNameArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /project/{project_guid}/name
+impl Subscribe<ProjectNameArgs> for ProjectName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(ProjectNameArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl ProjectName {
+    fn add_handler(&mut self, subscription: Subscription, handler: ProjectNameHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /project/{project_guid}/name
+impl AsyncQuery for ProjectName {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        check_address_segment(&self.project_guid)?;
+        let osc_address = format!("/project/{}/name", self.project_guid);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_project_name_args(msg: &rosc::OscMessage) -> Result<ProjectNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(ProjectNameArgs {
+        name: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct ActionArgs {
+    pub command_id: i32, // command ID of an action in the main section of Reaper's action list
+}
+
+impl ActionArgs {
+    pub fn new(command_id: i32) -> Self {
+        Self { command_id }
+    }
+}
+
+impl From<i32> for ActionArgs {
+    fn from(command_id: i32) -> Self {
+        Self::new(command_id)
+    }
+}
+
+pub type ActionHandler = Box<dyn FnMut(ActionArgs) + 'static>;
+
+pub struct Action {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, ActionHandler)>,
+}
+
+impl Action {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/action";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /action
+impl AsyncSet<ActionArgs> for Action {
+    type Error = OscError;
+    async fn set(&mut self, args: ActionArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/action");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Int(args.command_id)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl Action {
+    pub async fn set_value(&mut self, value: impl Into<ActionArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+fn parse_action_args(msg: &rosc::OscMessage) -> Result<ActionArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<i32>()]);
+    Ok(ActionArgs {
+        command_id: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct RunActionArgs {}
+
+pub type RunActionHandler = Box<dyn FnMut(RunActionArgs) + 'static>;
+
+pub struct RunAction {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, RunActionHandler)>,
+    pub command_id: i32,
+}
+
+impl RunAction {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/action/{command_id}";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/action/{}", self.command_id)
+    }
+}
+
+impl std::fmt::Display for RunAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /action/{command_id}
+impl AsyncSet<RunActionArgs> for RunAction {
+    type Error = OscError;
+    async fn set(&mut self, args: RunActionArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/action/{}", self.command_id);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_run_action_args(_msg: &rosc::OscMessage) -> Result<RunActionArgs, String> {
+    Ok(RunActionArgs {})
+}
+
+#[derive(Debug)]
+pub struct ActionStateArgs {
+    pub on: bool, // true means the action's toggle is on, e.g. the metronome is enabled
+}
+
+impl ActionStateArgs {
+    pub fn new(on: bool) -> Self {
+        Self { on }
+    }
+}
+
+impl From<bool> for ActionStateArgs {
+    fn from(on: bool) -> Self {
+        Self::new(on)
+    }
+}
+
+pub type ActionStateHandler = Box<dyn FnMut(ActionStateArgs) + 'static>;
+
+pub struct ActionState {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, ActionStateHandler)>,
+    pub command_id: i32,
+}
+
+impl ActionState {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/action/{command_id}/state";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/action/{}/state", self.command_id)
+    }
+}
+
+impl std::fmt::Display for ActionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /action/{command_id}/state
+impl Bind<ActionStateArgs> for ActionState {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(ActionStateArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /action/{command_id}/state
+impl Subscribe<ActionStateArgs> for ActionState {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(ActionStateArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl ActionState {
+    fn add_handler(&mut self, subscription: Subscription, handler: ActionStateHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /action/{command_id}/state
+impl AsyncQuery for ActionState {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/action/{}/state", self.command_id);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_action_state_args(msg: &rosc::OscMessage) -> Result<ActionStateArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<bool>()]);
+    Ok(ActionStateArgs {
+        on: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct MarkerAllArgs {}
+
+pub type MarkerAllHandler = Box<dyn FnMut(MarkerAllArgs) + 'static>;
+
+pub struct MarkerAll {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, MarkerAllHandler)>,
+}
+
+impl MarkerAll {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/marker/all";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for MarkerAll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /marker/all
+impl AsyncQuery for MarkerAll {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/marker/all");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_marker_all_args(_msg: &rosc::OscMessage) -> Result<MarkerAllArgs, String> {
+    Ok(MarkerAllArgs {})
+}
+
+#[derive(Debug)]
+pub struct MarkerNameArgs {
+    pub name: String, // name of the marker
+}
+
+impl MarkerNameArgs {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl From<String> for MarkerNameArgs {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&str> for MarkerNameArgs {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+pub type MarkerNameHandler = Box<dyn FnMut(MarkerNameArgs) + 'static>;
+
+pub struct MarkerName {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, MarkerNameHandler)>,
+    pub marker_idx: i32,
+}
+
+impl MarkerName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/marker/{marker_idx}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/marker/{}/name", self.marker_idx)
+    }
+}
+
+impl std::fmt::Display for MarkerName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /marker/{marker_idx}/name
+impl AsyncSet<MarkerNameArgs> for MarkerName {
+    type Error = OscError;
+    async fn set(&mut self, args: MarkerNameArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/marker/{}/name", self.marker_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::String(args.name)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl MarkerName {
+    pub async fn set_value(&mut self, value: impl Into<MarkerNameArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /marker/{marker_idx}/name
+impl Bind<MarkerNameArgs> for MarkerName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(MarkerNameArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /marker/{marker_idx}/name
+impl Subscribe<MarkerNameArgs> for MarkerName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(MarkerNameArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl MarkerName {
+    fn add_handler(&mut self, subscription: Subscription, handler: MarkerNameHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /marker/{marker_idx}/name
+impl AsyncQuery for MarkerName {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/marker/{}/name", self.marker_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_marker_name_args(msg: &rosc::OscMessage) -> Result<MarkerNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(MarkerNameArgs {
+        name: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct MarkerPositionArgs {
+    pub position: f32, // position of the marker in seconds
+}
+
+impl MarkerPositionArgs {
+    pub fn new(position: f32) -> Self {
+        Self { position }
+    }
+}
+
+impl From<f32> for MarkerPositionArgs {
+    fn from(position: f32) -> Self {
+        Self::new(position)
+    }
+}
+
+pub type MarkerPositionHandler = Box<dyn FnMut(MarkerPositionArgs) + 'static>;
+
+pub struct MarkerPosition {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, MarkerPositionHandler)>,
+    pub marker_idx: i32,
+}
+
+impl MarkerPosition {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/marker/{marker_idx}/position";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/marker/{}/position", self.marker_idx)
+    }
+}
+
+impl std::fmt::Display for MarkerPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /marker/{marker_idx}/position
+impl AsyncSet<MarkerPositionArgs> for MarkerPosition {
+    type Error = OscError;
+    async fn set(&mut self, args: MarkerPositionArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/marker/{}/position", self.marker_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.position)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl MarkerPosition {
+    pub async fn set_value(
+        &mut self,
+        value: impl Into<MarkerPositionArgs>,
+    ) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /marker/{marker_idx}/position
+impl Bind<MarkerPositionArgs> for MarkerPosition {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(MarkerPositionArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /marker/{marker_idx}/position
+impl Subscribe<MarkerPositionArgs> for MarkerPosition {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(MarkerPositionArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl MarkerPosition {
+    fn add_handler(&mut self, subscription: Subscription, handler: MarkerPositionHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /marker/{marker_idx}/position
+impl AsyncQuery for MarkerPosition {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/marker/{}/position", self.marker_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_marker_position_args(msg: &rosc::OscMessage) -> Result<MarkerPositionArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(MarkerPositionArgs {
+        position: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct RegionAllArgs {}
+
+pub type RegionAllHandler = Box<dyn FnMut(RegionAllArgs) + 'static>;
+
+pub struct RegionAll {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, RegionAllHandler)>,
+}
+
+impl RegionAll {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/region/all";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        Self::ADDRESS_TEMPLATE.to_string()
+    }
+}
+
+impl std::fmt::Display for RegionAll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /region/all
+impl AsyncQuery for RegionAll {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/region/all");
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_region_all_args(_msg: &rosc::OscMessage) -> Result<RegionAllArgs, String> {
+    Ok(RegionAllArgs {})
+}
+
+#[derive(Debug)]
+pub struct RegionNameArgs {
+    pub name: String, // name of the region
+}
+
+impl RegionNameArgs {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl From<String> for RegionNameArgs {
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&str> for RegionNameArgs {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+pub type RegionNameHandler = Box<dyn FnMut(RegionNameArgs) + 'static>;
+
+pub struct RegionName {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, RegionNameHandler)>,
+    pub region_idx: i32,
+}
+
+impl RegionName {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/region/{region_idx}/name";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/region/{}/name", self.region_idx)
+    }
+}
+
+impl std::fmt::Display for RegionName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /region/{region_idx}/name
+impl AsyncSet<RegionNameArgs> for RegionName {
+    type Error = OscError;
+    async fn set(&mut self, args: RegionNameArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/region/{}/name", self.region_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::String(args.name)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl RegionName {
+    pub async fn set_value(&mut self, value: impl Into<RegionNameArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /region/{region_idx}/name
+impl Bind<RegionNameArgs> for RegionName {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(RegionNameArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /region/{region_idx}/name
+impl Subscribe<RegionNameArgs> for RegionName {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(RegionNameArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl RegionName {
+    fn add_handler(&mut self, subscription: Subscription, handler: RegionNameHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /region/{region_idx}/name
+impl AsyncQuery for RegionName {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/region/{}/name", self.region_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_region_name_args(msg: &rosc::OscMessage) -> Result<RegionNameArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<String>()]);
+    Ok(RegionNameArgs {
+        name: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct RegionStartArgs {
+    pub start: f32, // where the region starts, in seconds
+}
+
+impl RegionStartArgs {
+    pub fn new(start: f32) -> Self {
+        Self { start }
+    }
+}
+
+impl From<f32> for RegionStartArgs {
+    fn from(start: f32) -> Self {
+        Self::new(start)
+    }
+}
+
+pub type RegionStartHandler = Box<dyn FnMut(RegionStartArgs) + 'static>;
+
+pub struct RegionStart {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, RegionStartHandler)>,
+    pub region_idx: i32,
+}
+
+impl RegionStart {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/region/{region_idx}/start";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/region/{}/start", self.region_idx)
+    }
+}
+
+impl std::fmt::Display for RegionStart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /region/{region_idx}/start
+impl AsyncSet<RegionStartArgs> for RegionStart {
+    type Error = OscError;
+    async fn set(&mut self, args: RegionStartArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/region/{}/start", self.region_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.start)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl RegionStart {
+    pub async fn set_value(&mut self, value: impl Into<RegionStartArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /region/{region_idx}/start
+impl Bind<RegionStartArgs> for RegionStart {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(RegionStartArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /region/{region_idx}/start
+impl Subscribe<RegionStartArgs> for RegionStart {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(RegionStartArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl RegionStart {
+    fn add_handler(&mut self, subscription: Subscription, handler: RegionStartHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /region/{region_idx}/start
+impl AsyncQuery for RegionStart {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/region/{}/start", self.region_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_region_start_args(msg: &rosc::OscMessage) -> Result<RegionStartArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(RegionStartArgs {
+        start: decode::required(&osc_args, 0)?,
+    })
+}
+
+#[derive(Debug)]
+pub struct RegionEndArgs {
+    pub end: f32, // where the region ends, in seconds
+}
+
+impl RegionEndArgs {
+    pub fn new(end: f32) -> Self {
+        Self { end }
+    }
+}
+
+impl From<f32> for RegionEndArgs {
+    fn from(end: f32) -> Self {
+        Self::new(end)
+    }
+}
+
+pub type RegionEndHandler = Box<dyn FnMut(RegionEndArgs) + 'static>;
+
+pub struct RegionEnd {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+    handlers: Vec<(Subscription, RegionEndHandler)>,
+    pub region_idx: i32,
+}
+
+impl RegionEnd {
+    /// The OSC address of this endpoint, with its parameters in braces
+    pub const ADDRESS_TEMPLATE: &str = "/region/{region_idx}/end";
+
+    /// The OSC address of this endpoint, with its parameters filled in
+    pub fn osc_address(&self) -> String {
+        format!("/region/{}/end", self.region_idx)
+    }
+}
+
+impl std::fmt::Display for RegionEnd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.osc_address())
+    }
+}
+
+/// /region/{region_idx}/end
+impl AsyncSet<RegionEndArgs> for RegionEnd {
+    type Error = OscError;
+    async fn set(&mut self, args: RegionEndArgs) -> Result<(), Self::Error> {
+        let osc_address = format!("/region/{}/end", self.region_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![rosc::OscType::Float(args.end)],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+impl RegionEnd {
+    pub async fn set_value(&mut self, value: impl Into<RegionEndArgs>) -> Result<(), OscError> {
+        self.set(value.into()).await
+    }
+}
+
+/// /region/{region_idx}/end
+impl Bind<RegionEndArgs> for RegionEnd {
+    fn bind<F>(&mut self, callback: F)
+    where
+        F: FnMut(RegionEndArgs) + 'static,
+    {
+        self.add_handler(Subscription::new(), Box::new(callback));
+    }
+}
+
+/// /region/{region_idx}/end
+impl Subscribe<RegionEndArgs> for RegionEnd {
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(RegionEndArgs) + 'static,
+    {
+        let subscription = Subscription::new();
+        self.add_handler(subscription, Box::new(callback));
+        subscription
+    }
+
+    fn unbind(&mut self, subscription: Subscription) -> bool {
+        let bound = self.handlers.len();
+        self.handlers.retain(|(other, _)| *other != subscription);
+        self.handlers.len() != bound
+    }
+}
+
+impl RegionEnd {
+    fn add_handler(&mut self, subscription: Subscription, handler: RegionEndHandler) {
+        self.handlers.push((subscription, handler));
+    }
+}
+
+/// /region/{region_idx}/end
+impl AsyncQuery for RegionEnd {
+    type Error = OscError;
+    async fn query(&self) -> Result<(), Self::Error> {
+        let osc_address = format!("/region/{}/end", self.region_idx);
+        let osc_msg = rosc::OscMessage {
+            addr: osc_address,
+            args: vec![],
+        };
+        let packet = rosc::OscPacket::Message(osc_msg);
+        let buf = rosc::encoder::encode(&packet)?;
+        match self.destination {
+            Some(destination) => self.socket.send_to(&buf, destination).await?,
+            None => self.socket.send(&buf).await?,
+        };
+        Ok(())
+    }
+}
+
+fn parse_region_end_args(msg: &rosc::OscMessage) -> Result<RegionEndArgs, String> {
+    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>()]);
+    Ok(RegionEndArgs {
+        end: decode::required(&osc_args, 0)?,
+    })
+}
+
+pub mod context {
+    use crate::osc::route_context::ContextTrait;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Action {
+        pub command_id: i32,
+    }
+
+    impl ContextTrait for Action {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Fxinfo {
+        pub ident: String,
+    }
+
+    impl ContextTrait for Fxinfo {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct FxinfoParam {
+        pub ident: String,
+        pub param_idx: i32,
+    }
+
+    impl ContextTrait for FxinfoParam {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Marker {
+        pub marker_idx: i32,
+    }
+
+    impl ContextTrait for Marker {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Project {
+        pub project_guid: crate::guid::ProjectGuid,
+    }
+
+    impl ContextTrait for Project {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Region {
+        pub region_idx: i32,
+    }
+
+    impl ContextTrait for Region {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Track {
+        pub track_guid: crate::guid::TrackGuid,
+    }
+
+    impl ContextTrait for Track {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct TrackFx {
+        pub track_guid: crate::guid::TrackGuid,
+        pub fx_idx: i32,
+    }
+
+    impl ContextTrait for TrackFx {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct TrackFxParam {
+        pub track_guid: crate::guid::TrackGuid,
+        pub fx_idx: i32,
+        pub param_idx: i32,
+    }
+
+    impl ContextTrait for TrackFxParam {}
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct TrackSend {
+        pub track_guid: crate::guid::TrackGuid,
+        pub send_index: i32,
+    }
+
+    impl ContextTrait for TrackSend {}
+}
+
+pub mod context_kind {
+    use super::context;
+    use crate::osc::route_context::ContextKindTrait;
+    use regex::Regex;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Action {}
+
+    impl ContextKindTrait for Action {
+        type Context = context::Action;
+
+        fn context_name() -> &'static str {
+            "Action"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::Action> {
+            static RE: once_cell::sync::Lazy<Regex> =
+                once_cell::sync::Lazy::new(|| Regex::new(r"^/action/([^/]+)$").unwrap());
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::Action {
+                    command_id: caps[1].parse().ok()?,
+                })
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Fxinfo {}
+
+    impl ContextKindTrait for Fxinfo {
+        type Context = context::Fxinfo;
+
+        fn context_name() -> &'static str {
+            "Fxinfo"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::Fxinfo> {
+            static RE: once_cell::sync::Lazy<Regex> =
+                once_cell::sync::Lazy::new(|| Regex::new(r"^/fxinfo/([^/]+)/name$").unwrap());
+            RE.captures(osc_address).map(|caps| context::Fxinfo {
+                ident: caps[1].to_string(),
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct FxinfoParam {}
+
+    impl ContextKindTrait for FxinfoParam {
+        type Context = context::FxinfoParam;
+
+        fn context_name() -> &'static str {
+            "FxinfoParam"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::FxinfoParam> {
+            static RE: once_cell::sync::Lazy<Regex> = once_cell::sync::Lazy::new(|| {
+                Regex::new(r"^/fxinfo/([^/]+)/param/([^/]+)/name$").unwrap()
+            });
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::FxinfoParam {
+                    ident: caps[1].to_string(),
+                    param_idx: caps[2].parse().ok()?,
+                })
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Marker {}
+
+    impl ContextKindTrait for Marker {
+        type Context = context::Marker;
+
+        fn context_name() -> &'static str {
+            "Marker"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::Marker> {
+            static RE: once_cell::sync::Lazy<Regex> =
+                once_cell::sync::Lazy::new(|| Regex::new(r"^/marker/([^/]+)/name$").unwrap());
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::Marker {
+                    marker_idx: caps[1].parse().ok()?,
+                })
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Project {}
+
+    impl ContextKindTrait for Project {
+        type Context = context::Project;
+
+        fn context_name() -> &'static str {
+            "Project"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::Project> {
+            static RE: once_cell::sync::Lazy<Regex> =
+                once_cell::sync::Lazy::new(|| Regex::new(r"^/project/([^/]+)/name$").unwrap());
+            RE.captures(osc_address).map(|caps| context::Project {
+                project_guid: caps[1].into(),
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Region {}
+
+    impl ContextKindTrait for Region {
+        type Context = context::Region;
+
+        fn context_name() -> &'static str {
+            "Region"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::Region> {
+            static RE: once_cell::sync::Lazy<Regex> =
+                once_cell::sync::Lazy::new(|| Regex::new(r"^/region/([^/]+)/name$").unwrap());
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::Region {
+                    region_idx: caps[1].parse().ok()?,
+                })
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct Track {}
+
+    impl ContextKindTrait for Track {
+        type Context = context::Track;
+
+        fn context_name() -> &'static str {
+            "Track"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::Track> {
+            static RE: once_cell::sync::Lazy<Regex> =
+                once_cell::sync::Lazy::new(|| Regex::new(r"^/track/([^/]+)/index$").unwrap());
+            RE.captures(osc_address).map(|caps| context::Track {
+                track_guid: caps[1].into(),
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct TrackFx {}
+
+    impl ContextKindTrait for TrackFx {
+        type Context = context::TrackFx;
+
+        fn context_name() -> &'static str {
+            "TrackFx"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::TrackFx> {
+            static RE: once_cell::sync::Lazy<Regex> = once_cell::sync::Lazy::new(|| {
+                Regex::new(r"^/track/([^/]+)/fx/([^/]+)/guid$").unwrap()
+            });
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::TrackFx {
+                    track_guid: caps[1].into(),
+                    fx_idx: caps[2].parse().ok()?,
+                })
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct TrackFxParam {}
+
+    impl ContextKindTrait for TrackFxParam {
+        type Context = context::TrackFxParam;
+
+        fn context_name() -> &'static str {
+            "TrackFxParam"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::TrackFxParam> {
+            static RE: once_cell::sync::Lazy<Regex> = once_cell::sync::Lazy::new(|| {
+                Regex::new(r"^/track/([^/]+)/fx/([^/]+)/param/([^/]+)/name$").unwrap()
+            });
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::TrackFxParam {
+                    track_guid: caps[1].into(),
+                    fx_idx: caps[2].parse().ok()?,
+                    param_idx: caps[3].parse().ok()?,
+                })
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct TrackSend {}
+
+    impl ContextKindTrait for TrackSend {
+        type Context = context::TrackSend;
+
+        fn context_name() -> &'static str {
+            "TrackSend"
+        }
+
+        fn parse(osc_address: &str) -> Option<context::TrackSend> {
+            static RE: once_cell::sync::Lazy<Regex> = once_cell::sync::Lazy::new(|| {
+                Regex::new(r"^/track/([^/]+)/send/([^/]+)/guid$").unwrap()
+            });
+            RE.captures(osc_address).and_then(|caps| {
+                Some(context::TrackSend {
+                    track_guid: caps[1].into(),
+                    send_index: caps[2].parse().ok()?,
+                })
+            })
+        }
+    }
+}
+
+pub struct Reaper {
+    socket: Arc<UdpSocket>,
+    destination: Option<SocketAddr>,
+}
+
+impl Reaper {
+    /// Sends everything over `socket` as it is, e.g. to whatever it's connected to.
+    pub fn new(socket: Arc<UdpSocket>) -> Self {
+        Self {
+            socket,
+            destination: None,
+        }
+    }
+
+    /// Sends everything over `socket` to `destination`, so the socket can be left unconnected
+    /// and still hear from anyone.
+    pub fn with_destination(socket: Arc<UdpSocket>, destination: SocketAddr) -> Self {
+        Self {
+            destination: Some(destination),
+            ..Self::new(socket)
+        }
+    }
+}
+
+impl Reaper {
+    pub fn num_tracks(&self) -> NumTracks {
+        NumTracks {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn track_all_guids(&self) -> TrackAllGuids {
+        TrackAllGuids {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn track_index(&self, track_guid: crate::guid::TrackGuid) -> TrackIndex {
+        TrackIndex {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_delete(&self, track_guid: crate::guid::TrackGuid) -> TrackDelete {
+        TrackDelete {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_name(&self, track_guid: crate::guid::TrackGuid) -> TrackName {
+        TrackName {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_selected(&self, track_guid: crate::guid::TrackGuid) -> TrackSelected {
+        TrackSelected {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_volume(&self, track_guid: crate::guid::TrackGuid) -> TrackVolume {
+        TrackVolume {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_pan(&self, track_guid: crate::guid::TrackGuid) -> TrackPan {
+        TrackPan {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_mute(&self, track_guid: crate::guid::TrackGuid) -> TrackMute {
+        TrackMute {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_solo(&self, track_guid: crate::guid::TrackGuid) -> TrackSolo {
+        TrackSolo {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_rec_arm(&self, track_guid: crate::guid::TrackGuid) -> TrackRecArm {
+        TrackRecArm {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_width(&self, track_guid: crate::guid::TrackGuid) -> TrackWidth {
+        TrackWidth {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_phase(&self, track_guid: crate::guid::TrackGuid) -> TrackPhase {
+        TrackPhase {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_input_gain(&self, track_guid: crate::guid::TrackGuid) -> TrackInputGain {
+        TrackInputGain {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn master_volume(&self) -> MasterVolume {
+        MasterVolume {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn master_pan(&self) -> MasterPan {
+        MasterPan {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn master_mute(&self) -> MasterMute {
+        MasterMute {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn track_send_count(&self, track_guid: crate::guid::TrackGuid) -> TrackSendCount {
+        TrackSendCount {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_send_guid(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        send_index: i32,
+    ) -> TrackSendGuid {
+        TrackSendGuid {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            send_index: send_index,
+        }
+    }
+    pub fn track_send_volume(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        send_index: i32,
+    ) -> TrackSendVolume {
+        TrackSendVolume {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            send_index: send_index,
+        }
+    }
+    pub fn track_send_pan(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        send_index: i32,
+    ) -> TrackSendPan {
+        TrackSendPan {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            send_index: send_index,
+        }
+    }
+    pub fn track_send_mute(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        send_index: i32,
+    ) -> TrackSendMute {
+        TrackSendMute {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            send_index: send_index,
+        }
+    }
+    pub fn track_color(&self, track_guid: crate::guid::TrackGuid) -> TrackColor {
+        TrackColor {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+        }
+    }
+    pub fn track_fx_guid(&self, track_guid: crate::guid::TrackGuid, fx_idx: i32) -> TrackFxGuid {
+        TrackFxGuid {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+        }
+    }
+    pub fn track_fx_name(&self, track_guid: crate::guid::TrackGuid, fx_idx: i32) -> TrackFxName {
+        TrackFxName {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+        }
+    }
+    pub fn track_fx_enabled(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        fx_idx: i32,
+    ) -> TrackFxEnabled {
+        TrackFxEnabled {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+        }
+    }
+    pub fn track_fx_bypass(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        fx_idx: i32,
+    ) -> TrackFxBypass {
+        TrackFxBypass {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+        }
+    }
+    pub fn track_fx_wet(&self, track_guid: crate::guid::TrackGuid, fx_idx: i32) -> TrackFxWet {
+        TrackFxWet {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+        }
+    }
+    pub fn track_fx_param_count(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        fx_idx: i32,
+    ) -> TrackFxParamCount {
+        TrackFxParamCount {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+        }
+    }
+    pub fn track_fx_param_name(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        fx_idx: i32,
+        param_idx: i32,
+    ) -> TrackFxParamName {
+        TrackFxParamName {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+            param_idx: param_idx,
+        }
+    }
+    pub fn track_fx_param_value(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        fx_idx: i32,
+        param_idx: i32,
+    ) -> TrackFxParamValue {
+        TrackFxParamValue {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+            param_idx: param_idx,
+        }
+    }
+    pub fn track_fx_param_min(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        fx_idx: i32,
+        param_idx: i32,
+    ) -> TrackFxParamMin {
+        TrackFxParamMin {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+            param_idx: param_idx,
+        }
+    }
+    pub fn track_fx_param_max(
+        &self,
+        track_guid: crate::guid::TrackGuid,
+        fx_idx: i32,
+        param_idx: i32,
+    ) -> TrackFxParamMax {
+        TrackFxParamMax {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+            param_idx: param_idx,
+        }
+    }
+    pub fn track_fx_info(&self, track_guid: crate::guid::TrackGuid, fx_idx: i32) -> TrackFxInfo {
+        TrackFxInfo {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            track_guid: track_guid,
+            fx_idx: fx_idx,
+        }
+    }
+    pub fn fxinfo_name(&self, ident: String) -> FxinfoName {
+        FxinfoName {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            ident: ident,
+        }
+    }
+    pub fn fxinfo_param_count(&self, ident: String) -> FxinfoParamCount {
+        FxinfoParamCount {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            ident: ident,
+        }
+    }
+    pub fn fxinfo_param_name(&self, ident: String, param_idx: i32) -> FxinfoParamName {
+        FxinfoParamName {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            ident: ident,
+            param_idx: param_idx,
+        }
+    }
+    pub fn fxinfo_param_min(&self, ident: String, param_idx: i32) -> FxinfoParamMin {
+        FxinfoParamMin {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            ident: ident,
+            param_idx: param_idx,
+        }
+    }
+    pub fn fxinfo_param_max(&self, ident: String, param_idx: i32) -> FxinfoParamMax {
+        FxinfoParamMax {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            ident: ident,
+            param_idx: param_idx,
+        }
+    }
+    pub fn fxinfo(&self) -> Fxinfo {
+        Fxinfo {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn play(&self) -> Play {
+        Play {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn stop(&self) -> Stop {
+        Stop {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn record(&self) -> Record {
+        Record {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn repeat(&self) -> Repeat {
+        Repeat {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn rewind(&self) -> Rewind {
+        Rewind {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn forward(&self) -> Forward {
+        Forward {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn time(&self) -> Time {
+        Time {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn tempo(&self) -> Tempo {
+        Tempo {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn active_project(&self) -> ActiveProject {
+        ActiveProject {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn project_name(&self, project_guid: crate::guid::ProjectGuid) -> ProjectName {
+        ProjectName {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            project_guid: project_guid,
+        }
+    }
+    pub fn action(&self) -> Action {
+        Action {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn run_action(&self, command_id: i32) -> RunAction {
+        RunAction {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            command_id: command_id,
+        }
+    }
+    pub fn action_state(&self, command_id: i32) -> ActionState {
+        ActionState {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            command_id: command_id,
+        }
+    }
+    pub fn marker_all(&self) -> MarkerAll {
+        MarkerAll {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn marker_name(&self, marker_idx: i32) -> MarkerName {
+        MarkerName {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            marker_idx: marker_idx,
+        }
+    }
+    pub fn marker_position(&self, marker_idx: i32) -> MarkerPosition {
+        MarkerPosition {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            marker_idx: marker_idx,
+        }
+    }
+    pub fn region_all(&self) -> RegionAll {
+        RegionAll {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+        }
+    }
+    pub fn region_name(&self, region_idx: i32) -> RegionName {
+        RegionName {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            region_idx: region_idx,
+        }
+    }
+    pub fn region_start(&self, region_idx: i32) -> RegionStart {
+        RegionStart {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            region_idx: region_idx,
+        }
+    }
+    pub fn region_end(&self, region_idx: i32) -> RegionEnd {
+        RegionEnd {
+            socket: self.socket.clone(),
+            destination: self.destination,
+            handlers: Vec::new(),
+            region_idx: region_idx,
+        }
+    }
+}
+
+/// Which way messages to a route go between Reaper and us.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RouteDirection {
+    /// Reaper reports it, and it can't be set
+    FromReaper,
+    /// It's sent to Reaper, which never reports it
+    ToReaper,
+    /// Reaper reports it, and it can be set too
+    Both,
+}
+
+/// A route of the spec, as data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RouteInfo {
+    /// Name of the route's accessor on Reaper
+    pub name: &'static str,
+    /// Name of the struct the accessor returns, which the route's Args are named after
+    pub struct_name: &'static str,
+    /// The route's address, with a `{param}` segment standing in for each param
+    pub address: &'static str,
+    pub direction: RouteDirection,
+    /// Whether Reaper answers a query to it
+    pub queryable: bool,
+    /// Each param's name and type, in the order the address has them
+    pub params: &'static [(&'static str, &'static str)],
+    /// Each argument's name and type, in the order messages carry them
+    pub arguments: &'static [(&'static str, &'static str)],
+}
+
+/// Every route in the spec, in the order it lists them.
+pub const ROUTES: &[RouteInfo] = &[
+    RouteInfo {
+        name: "num_tracks",
+        struct_name: "NumTracks",
+        address: "/num_tracks",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[],
+        arguments: &[("num_tracks", "int")],
+    },
+    RouteInfo {
+        name: "track_all_guids",
+        struct_name: "TrackAllGuids",
+        address: "/track/all_guids",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "track_index",
+        struct_name: "TrackIndex",
+        address: "/track/{track_guid}/index",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("index", "int")],
+    },
+    RouteInfo {
+        name: "track_delete",
+        struct_name: "TrackDelete",
+        address: "/track/{track_guid}/delete",
+        direction: RouteDirection::ToReaper,
+        queryable: false,
+        params: &[("track_guid", "string")],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "track_name",
+        struct_name: "TrackName",
+        address: "/track/{track_guid}/name",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("name", "string")],
+    },
+    RouteInfo {
+        name: "track_selected",
+        struct_name: "TrackSelected",
+        address: "/track/{track_guid}/selected",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("selected", "bool")],
+    },
+    RouteInfo {
+        name: "track_volume",
+        struct_name: "TrackVolume",
+        address: "/track/{track_guid}/volume",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("volume", "float")],
+    },
+    RouteInfo {
+        name: "track_pan",
+        struct_name: "TrackPan",
+        address: "/track/{track_guid}/pan",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("pan", "float")],
+    },
+    RouteInfo {
+        name: "track_mute",
+        struct_name: "TrackMute",
+        address: "/track/{track_guid}/mute",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("mute", "bool")],
+    },
+    RouteInfo {
+        name: "track_solo",
+        struct_name: "TrackSolo",
+        address: "/track/{track_guid}/solo",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("solo", "bool")],
+    },
+    RouteInfo {
+        name: "track_rec_arm",
+        struct_name: "TrackRecArm",
+        address: "/track/{track_guid}/rec-arm",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("rec_arm", "bool")],
+    },
+    RouteInfo {
+        name: "track_width",
+        struct_name: "TrackWidth",
+        address: "/track/{track_guid}/width",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("width", "float")],
+    },
+    RouteInfo {
+        name: "track_phase",
+        struct_name: "TrackPhase",
+        address: "/track/{track_guid}/phase",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("phase", "bool")],
+    },
+    RouteInfo {
+        name: "track_input_gain",
+        struct_name: "TrackInputGain",
+        address: "/track/{track_guid}/input-gain",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("input_gain", "float")],
+    },
+    RouteInfo {
+        name: "master_volume",
+        struct_name: "MasterVolume",
+        address: "/master/volume",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("volume", "float")],
+    },
+    RouteInfo {
+        name: "master_pan",
+        struct_name: "MasterPan",
+        address: "/master/pan",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("pan", "float")],
+    },
+    RouteInfo {
+        name: "master_mute",
+        struct_name: "MasterMute",
+        address: "/master/mute",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("mute", "bool")],
+    },
+    RouteInfo {
+        name: "track_send_count",
+        struct_name: "TrackSendCount",
+        address: "/track/{track_guid}/send_count",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("send_count", "int")],
+    },
+    RouteInfo {
+        name: "track_send_guid",
+        struct_name: "TrackSendGuid",
+        address: "/track/{track_guid}/send/{send_index}/guid",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("track_guid", "string"), ("send_index", "int")],
+        arguments: &[("guid", "string")],
+    },
+    RouteInfo {
+        name: "track_send_volume",
+        struct_name: "TrackSendVolume",
+        address: "/track/{track_guid}/send/{send_index}/volume",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string"), ("send_index", "int")],
+        arguments: &[("volume", "float")],
+    },
+    RouteInfo {
+        name: "track_send_pan",
+        struct_name: "TrackSendPan",
+        address: "/track/{track_guid}/send/{send_index}/pan",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string"), ("send_index", "int")],
+        arguments: &[("pan", "float")],
+    },
+    RouteInfo {
+        name: "track_send_mute",
+        struct_name: "TrackSendMute",
+        address: "/track/{track_guid}/send/{send_index}/mute",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string"), ("send_index", "int")],
+        arguments: &[("mute", "bool")],
+    },
+    RouteInfo {
+        name: "track_color",
+        struct_name: "TrackColor",
+        address: "/track/{track_guid}/color",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string")],
+        arguments: &[("color", "int")],
+    },
+    RouteInfo {
+        name: "track_fx_guid",
+        struct_name: "TrackFxGuid",
+        address: "/track/{track_guid}/fx/{fx_idx}/guid",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[("guid", "string")],
+    },
+    RouteInfo {
+        name: "track_fx_name",
+        struct_name: "TrackFxName",
+        address: "/track/{track_guid}/fx/{fx_idx}/name",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[("name", "string")],
+    },
+    RouteInfo {
+        name: "track_fx_enabled",
+        struct_name: "TrackFxEnabled",
+        address: "/track/{track_guid}/fx/{fx_idx}/enabled",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[("enabled", "bool")],
+    },
+    RouteInfo {
+        name: "track_fx_bypass",
+        struct_name: "TrackFxBypass",
+        address: "/track/{track_guid}/fx/{fx_idx}/bypass",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[("bypass", "bool")],
+    },
+    RouteInfo {
+        name: "track_fx_wet",
+        struct_name: "TrackFxWet",
+        address: "/track/{track_guid}/fx/{fx_idx}/wet",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[("wet", "float")],
+    },
+    RouteInfo {
+        name: "track_fx_param_count",
+        struct_name: "TrackFxParamCount",
+        address: "/track/{track_guid}/fx/{fx_idx}/param_count",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[("param_count", "int")],
+    },
+    RouteInfo {
+        name: "track_fx_param_name",
+        struct_name: "TrackFxParamName",
+        address: "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[
+            ("track_guid", "string"),
+            ("fx_idx", "int"),
+            ("param_idx", "int"),
+        ],
+        arguments: &[("param_name", "string")],
+    },
+    RouteInfo {
+        name: "track_fx_param_value",
+        struct_name: "TrackFxParamValue",
+        address: "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[
+            ("track_guid", "string"),
+            ("fx_idx", "int"),
+            ("param_idx", "int"),
+        ],
+        arguments: &[("value", "float")],
+    },
+    RouteInfo {
+        name: "track_fx_param_min",
+        struct_name: "TrackFxParamMin",
+        address: "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[
+            ("track_guid", "string"),
+            ("fx_idx", "int"),
+            ("param_idx", "int"),
+        ],
+        arguments: &[("min", "float")],
+    },
+    RouteInfo {
+        name: "track_fx_param_max",
+        struct_name: "TrackFxParamMax",
+        address: "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[
+            ("track_guid", "string"),
+            ("fx_idx", "int"),
+            ("param_idx", "int"),
+        ],
+        arguments: &[("max", "float")],
+    },
+    RouteInfo {
+        name: "track_fx_info",
+        struct_name: "TrackFxInfo",
+        address: "/track/{track_guid}/fx/{fx_idx}/info",
+        direction: RouteDirection::ToReaper,
+        queryable: true,
+        params: &[("track_guid", "string"), ("fx_idx", "int")],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "fxinfo_name",
+        struct_name: "FxinfoName",
+        address: "/fxinfo/{ident}/name",
+        direction: RouteDirection::FromReaper,
+        queryable: false,
+        params: &[("ident", "string")],
+        arguments: &[("name", "string")],
+    },
+    RouteInfo {
+        name: "fxinfo_param_count",
+        struct_name: "FxinfoParamCount",
+        address: "/fxinfo/{ident}/param_count",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("ident", "string")],
+        arguments: &[("param_count", "int")],
+    },
+    RouteInfo {
+        name: "fxinfo_param_name",
+        struct_name: "FxinfoParamName",
+        address: "/fxinfo/{ident}/param/{param_idx}/name",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("ident", "string"), ("param_idx", "int")],
+        arguments: &[("param_name", "string")],
+    },
+    RouteInfo {
+        name: "fxinfo_param_min",
+        struct_name: "FxinfoParamMin",
+        address: "/fxinfo/{ident}/param/{param_idx}/min",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("ident", "string"), ("param_idx", "int")],
+        arguments: &[("param_min", "float")],
+    },
+    RouteInfo {
+        name: "fxinfo_param_max",
+        struct_name: "FxinfoParamMax",
+        address: "/fxinfo/{ident}/param/{param_idx}/max",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("ident", "string"), ("param_idx", "int")],
+        arguments: &[("param_max", "float")],
+    },
+    RouteInfo {
+        name: "fxinfo",
+        struct_name: "Fxinfo",
+        address: "/fxinfo",
+        direction: RouteDirection::ToReaper,
+        queryable: true,
+        params: &[],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "play",
+        struct_name: "Play",
+        address: "/play",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("playing", "bool")],
+    },
+    RouteInfo {
+        name: "stop",
+        struct_name: "Stop",
+        address: "/stop",
+        direction: RouteDirection::ToReaper,
+        queryable: false,
+        params: &[],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "record",
+        struct_name: "Record",
+        address: "/record",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("recording", "bool")],
+    },
+    RouteInfo {
+        name: "repeat",
+        struct_name: "Repeat",
+        address: "/repeat",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("repeat", "bool")],
+    },
+    RouteInfo {
+        name: "rewind",
+        struct_name: "Rewind",
+        address: "/rewind",
+        direction: RouteDirection::ToReaper,
+        queryable: false,
+        params: &[],
+        arguments: &[("held", "bool")],
+    },
+    RouteInfo {
+        name: "forward",
+        struct_name: "Forward",
+        address: "/forward",
+        direction: RouteDirection::ToReaper,
+        queryable: false,
+        params: &[],
+        arguments: &[("held", "bool")],
+    },
+    RouteInfo {
+        name: "time",
+        struct_name: "Time",
+        address: "/time",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("time", "float")],
+    },
+    RouteInfo {
+        name: "tempo",
+        struct_name: "Tempo",
+        address: "/tempo",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[],
+        arguments: &[("bpm", "float")],
+    },
+    RouteInfo {
+        name: "active_project",
+        struct_name: "ActiveProject",
+        address: "/project/guid",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[],
+        arguments: &[("project_guid", "string")],
+    },
+    RouteInfo {
+        name: "project_name",
+        struct_name: "ProjectName",
+        address: "/project/{project_guid}/name",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("project_guid", "string")],
+        arguments: &[("name", "string")],
+    },
+    RouteInfo {
+        name: "action",
+        struct_name: "Action",
+        address: "/action",
+        direction: RouteDirection::ToReaper,
+        queryable: false,
+        params: &[],
+        arguments: &[("command_id", "int")],
+    },
+    RouteInfo {
+        name: "run_action",
+        struct_name: "RunAction",
+        address: "/action/{command_id}",
+        direction: RouteDirection::ToReaper,
+        queryable: false,
+        params: &[("command_id", "int")],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "action_state",
+        struct_name: "ActionState",
+        address: "/action/{command_id}/state",
+        direction: RouteDirection::FromReaper,
+        queryable: true,
+        params: &[("command_id", "int")],
+        arguments: &[("on", "bool")],
+    },
+    RouteInfo {
+        name: "marker_all",
+        struct_name: "MarkerAll",
+        address: "/marker/all",
+        direction: RouteDirection::ToReaper,
+        queryable: true,
+        params: &[],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "marker_name",
+        struct_name: "MarkerName",
+        address: "/marker/{marker_idx}/name",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("marker_idx", "int")],
+        arguments: &[("name", "string")],
+    },
+    RouteInfo {
+        name: "marker_position",
+        struct_name: "MarkerPosition",
+        address: "/marker/{marker_idx}/position",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("marker_idx", "int")],
+        arguments: &[("position", "float")],
+    },
+    RouteInfo {
+        name: "region_all",
+        struct_name: "RegionAll",
+        address: "/region/all",
+        direction: RouteDirection::ToReaper,
+        queryable: true,
+        params: &[],
+        arguments: &[],
+    },
+    RouteInfo {
+        name: "region_name",
+        struct_name: "RegionName",
+        address: "/region/{region_idx}/name",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("region_idx", "int")],
+        arguments: &[("name", "string")],
+    },
+    RouteInfo {
+        name: "region_start",
+        struct_name: "RegionStart",
+        address: "/region/{region_idx}/start",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("region_idx", "int")],
+        arguments: &[("start", "float")],
+    },
+    RouteInfo {
+        name: "region_end",
+        struct_name: "RegionEnd",
+        address: "/region/{region_idx}/end",
+        direction: RouteDirection::Both,
+        queryable: true,
+        params: &[("region_idx", "int")],
+        arguments: &[("end", "float")],
+    },
+];
+
+/// Every route in the spec, for tools that work with any route rather than particular ones.
+pub fn all_routes() -> &'static [RouteInfo] {
+    ROUTES
+}
+
+pub fn dispatch_osc<F>(reaper: &mut Reaper, msg: rosc::OscMessage, log_unknown: F)
+where
+    F: Fn(&str, &Unhandled),
+{
+    let addr = msg.addr.as_str();
+    let mut undecodable = Vec::new();
+    let matched = match is_pattern(addr) {
+        true => dispatch_every_route(reaper, &msg, addr, &mut undecodable),
+        false => dispatch_by_segment(reaper, &msg, addr, &mut undecodable),
+    };
+    if !matched {
+        log_unknown(addr, &Unhandled::UnknownAddress);
+    }
+    for reason in undecodable {
+        log_unknown(addr, &Unhandled::BadArgs(reason));
+    }
+}
+
+fn dispatch_every_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let mut matched = false;
+    matched |= dispatch_num_tracks_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_all_guids_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_index_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_delete_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_selected_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_volume_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_pan_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_mute_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_solo_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_rec_arm_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_width_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_phase_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_input_gain_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_master_volume_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_master_pan_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_master_mute_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_send_count_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_send_guid_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_send_volume_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_send_pan_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_send_mute_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_color_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_guid_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_enabled_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_bypass_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_wet_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_param_count_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_param_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_param_value_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_param_min_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_param_max_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_track_fx_info_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_fxinfo_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_fxinfo_param_count_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_fxinfo_param_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_fxinfo_param_min_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_fxinfo_param_max_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_fxinfo_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_play_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_stop_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_record_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_repeat_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_rewind_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_forward_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_time_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_tempo_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_active_project_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_project_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_action_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_run_action_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_action_state_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_marker_all_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_marker_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_marker_position_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_region_all_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_region_name_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_region_start_route(reaper, msg, addr, undecodable);
+    matched |= dispatch_region_end_route(reaper, msg, addr, undecodable);
+    matched
+}
+
+// Only tries the routes whose literal segments match the address's, so the cost of a
+// dispatch doesn't grow with the number of routes
+fn dispatch_by_segment(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let segments: Vec<&str> = addr.split('/').filter(|s| !s.is_empty()).collect();
+    let mut matched = false;
+    match segments.first().copied() {
+        Some("action") => {
+            matched |= dispatch_action_route(reaper, msg, addr, undecodable);
+            matched |= dispatch_run_action_route(reaper, msg, addr, undecodable);
+            if let Some("state") = segments.get(2).copied() {
+                matched |= dispatch_action_state_route(reaper, msg, addr, undecodable);
+            }
+        }
+        Some("forward") => {
+            matched |= dispatch_forward_route(reaper, msg, addr, undecodable);
+        }
+        Some("fxinfo") => {
+            matched |= dispatch_fxinfo_route(reaper, msg, addr, undecodable);
+            match segments.get(2).copied() {
+                Some("name") => {
+                    matched |= dispatch_fxinfo_name_route(reaper, msg, addr, undecodable);
+                }
+                Some("param") => match segments.get(4).copied() {
+                    Some("max") => {
+                        matched |= dispatch_fxinfo_param_max_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("min") => {
+                        matched |= dispatch_fxinfo_param_min_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("name") => {
+                        matched |= dispatch_fxinfo_param_name_route(reaper, msg, addr, undecodable);
+                    }
+                    _ => {}
+                },
+                Some("param_count") => {
+                    matched |= dispatch_fxinfo_param_count_route(reaper, msg, addr, undecodable);
+                }
+                _ => {}
+            }
+        }
+        Some("marker") => {
+            if let Some("all") = segments.get(1).copied() {
+                matched |= dispatch_marker_all_route(reaper, msg, addr, undecodable);
+            }
+            match segments.get(2).copied() {
+                Some("name") => {
+                    matched |= dispatch_marker_name_route(reaper, msg, addr, undecodable);
+                }
+                Some("position") => {
+                    matched |= dispatch_marker_position_route(reaper, msg, addr, undecodable);
+                }
+                _ => {}
+            }
+        }
+        Some("master") => match segments.get(1).copied() {
+            Some("mute") => {
+                matched |= dispatch_master_mute_route(reaper, msg, addr, undecodable);
+            }
+            Some("pan") => {
+                matched |= dispatch_master_pan_route(reaper, msg, addr, undecodable);
+            }
+            Some("volume") => {
+                matched |= dispatch_master_volume_route(reaper, msg, addr, undecodable);
+            }
+            _ => {}
+        },
+        Some("num_tracks") => {
+            matched |= dispatch_num_tracks_route(reaper, msg, addr, undecodable);
+        }
+        Some("play") => {
+            matched |= dispatch_play_route(reaper, msg, addr, undecodable);
+        }
+        Some("project") => {
+            if let Some("guid") = segments.get(1).copied() {
+                matched |= dispatch_active_project_route(reaper, msg, addr, undecodable);
+            }
+            if let Some("name") = segments.get(2).copied() {
+                matched |= dispatch_project_name_route(reaper, msg, addr, undecodable);
+            }
+        }
+        Some("record") => {
+            matched |= dispatch_record_route(reaper, msg, addr, undecodable);
+        }
+        Some("region") => {
+            if let Some("all") = segments.get(1).copied() {
+                matched |= dispatch_region_all_route(reaper, msg, addr, undecodable);
+            }
+            match segments.get(2).copied() {
+                Some("end") => {
+                    matched |= dispatch_region_end_route(reaper, msg, addr, undecodable);
+                }
+                Some("name") => {
+                    matched |= dispatch_region_name_route(reaper, msg, addr, undecodable);
+                }
+                Some("start") => {
+                    matched |= dispatch_region_start_route(reaper, msg, addr, undecodable);
+                }
+                _ => {}
+            }
+        }
+        Some("repeat") => {
+            matched |= dispatch_repeat_route(reaper, msg, addr, undecodable);
+        }
+        Some("rewind") => {
+            matched |= dispatch_rewind_route(reaper, msg, addr, undecodable);
+        }
+        Some("stop") => {
+            matched |= dispatch_stop_route(reaper, msg, addr, undecodable);
+        }
+        Some("tempo") => {
+            matched |= dispatch_tempo_route(reaper, msg, addr, undecodable);
+        }
+        Some("time") => {
+            matched |= dispatch_time_route(reaper, msg, addr, undecodable);
+        }
+        Some("track") => {
+            if let Some("all_guids") = segments.get(1).copied() {
+                matched |= dispatch_track_all_guids_route(reaper, msg, addr, undecodable);
+            }
+            match segments.get(2).copied() {
+                Some("color") => {
+                    matched |= dispatch_track_color_route(reaper, msg, addr, undecodable);
+                }
+                Some("delete") => {
+                    matched |= dispatch_track_delete_route(reaper, msg, addr, undecodable);
+                }
+                Some("fx") => match segments.get(4).copied() {
+                    Some("bypass") => {
+                        matched |= dispatch_track_fx_bypass_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("enabled") => {
+                        matched |= dispatch_track_fx_enabled_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("guid") => {
+                        matched |= dispatch_track_fx_guid_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("info") => {
+                        matched |= dispatch_track_fx_info_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("name") => {
+                        matched |= dispatch_track_fx_name_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("param") => match segments.get(6).copied() {
+                        Some("max") => {
+                            matched |=
+                                dispatch_track_fx_param_max_route(reaper, msg, addr, undecodable);
+                        }
+                        Some("min") => {
+                            matched |=
+                                dispatch_track_fx_param_min_route(reaper, msg, addr, undecodable);
+                        }
+                        Some("name") => {
+                            matched |=
+                                dispatch_track_fx_param_name_route(reaper, msg, addr, undecodable);
+                        }
+                        Some("value") => {
+                            matched |=
+                                dispatch_track_fx_param_value_route(reaper, msg, addr, undecodable);
+                        }
+                        _ => {}
+                    },
+                    Some("param_count") => {
+                        matched |=
+                            dispatch_track_fx_param_count_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("wet") => {
+                        matched |= dispatch_track_fx_wet_route(reaper, msg, addr, undecodable);
+                    }
+                    _ => {}
+                },
+                Some("index") => {
+                    matched |= dispatch_track_index_route(reaper, msg, addr, undecodable);
+                }
+                Some("input-gain") => {
+                    matched |= dispatch_track_input_gain_route(reaper, msg, addr, undecodable);
+                }
+                Some("mute") => {
+                    matched |= dispatch_track_mute_route(reaper, msg, addr, undecodable);
+                }
+                Some("name") => {
+                    matched |= dispatch_track_name_route(reaper, msg, addr, undecodable);
+                }
+                Some("pan") => {
+                    matched |= dispatch_track_pan_route(reaper, msg, addr, undecodable);
+                }
+                Some("phase") => {
+                    matched |= dispatch_track_phase_route(reaper, msg, addr, undecodable);
+                }
+                Some("rec-arm") => {
+                    matched |= dispatch_track_rec_arm_route(reaper, msg, addr, undecodable);
+                }
+                Some("selected") => {
+                    matched |= dispatch_track_selected_route(reaper, msg, addr, undecodable);
+                }
+                Some("send") => match segments.get(4).copied() {
+                    Some("guid") => {
+                        matched |= dispatch_track_send_guid_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("mute") => {
+                        matched |= dispatch_track_send_mute_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("pan") => {
+                        matched |= dispatch_track_send_pan_route(reaper, msg, addr, undecodable);
+                    }
+                    Some("volume") => {
+                        matched |= dispatch_track_send_volume_route(reaper, msg, addr, undecodable);
+                    }
+                    _ => {}
+                },
+                Some("send_count") => {
+                    matched |= dispatch_track_send_count_route(reaper, msg, addr, undecodable);
+                }
+                Some("solo") => {
+                    matched |= dispatch_track_solo_route(reaper, msg, addr, undecodable);
+                }
+                Some("volume") => {
+                    matched |= dispatch_track_volume_route(reaper, msg, addr, undecodable);
+                }
+                Some("width") => {
+                    matched |= dispatch_track_width_route(reaper, msg, addr, undecodable);
+                }
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+    matched
+}
+
+/// /num_tracks
+fn dispatch_num_tracks_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/num_tracks", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_num_tracks_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.num_tracks();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_num_tracks_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/all_guids
+fn dispatch_track_all_guids_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/track/all_guids", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_all_guids_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.track_all_guids();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_all_guids_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/index
+fn dispatch_track_index_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/index", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_index_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_index(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_index_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/delete
+fn dispatch_track_delete_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/delete", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_delete_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_delete(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_delete_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/name
+fn dispatch_track_name_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/name", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_name(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_name_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/selected
+fn dispatch_track_selected_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/selected", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_selected_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_selected(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_selected_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/volume
+fn dispatch_track_volume_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/volume", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_volume_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_volume(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_volume_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/pan
+fn dispatch_track_pan_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/pan", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_pan_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_pan(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_pan_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/mute
+fn dispatch_track_mute_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/mute", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_mute_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_mute(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_mute_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/solo
+fn dispatch_track_solo_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/solo", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_solo_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_solo(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_solo_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/rec-arm
+fn dispatch_track_rec_arm_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/rec-arm", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_rec_arm_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_rec_arm(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_rec_arm_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/width
+fn dispatch_track_width_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/width", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_width_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_width(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_width_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/phase
+fn dispatch_track_phase_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/phase", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_phase_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_phase(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_phase_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/input-gain
+fn dispatch_track_input_gain_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/input-gain", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_input_gain_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_input_gain(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_input_gain_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /master/volume
+fn dispatch_master_volume_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/master/volume", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_master_volume_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.master_volume();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_master_volume_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /master/pan
+fn dispatch_master_pan_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/master/pan", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_master_pan_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.master_pan();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_master_pan_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /master/mute
+fn dispatch_master_mute_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/master/mute", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_master_mute_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.master_mute();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_master_mute_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/send_count
+fn dispatch_track_send_count_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/send_count", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_send_count_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_send_count(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_send_count_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/send/{send_index}/guid
+fn dispatch_track_send_guid_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/send/{send_index}/guid", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_send_guid_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(send_index) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_send_guid(track_guid, send_index);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_send_guid_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/send/{send_index}/volume
+fn dispatch_track_send_volume_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/send/{send_index}/volume", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_send_volume_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(send_index) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_send_volume(track_guid, send_index);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_send_volume_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/send/{send_index}/pan
+fn dispatch_track_send_pan_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/send/{send_index}/pan", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_send_pan_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(send_index) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_send_pan(track_guid, send_index);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_send_pan_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/send/{send_index}/mute
+fn dispatch_track_send_mute_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/send/{send_index}/mute", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_send_mute_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(send_index) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_send_mute(track_guid, send_index);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_send_mute_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/color
+fn dispatch_track_color_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/color", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_color_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let mut endpoint = reaper.track_color(track_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_color_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/guid
+fn dispatch_track_fx_guid_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/fx/{fx_idx}/guid", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_fx_guid_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(fx_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_fx_guid(track_guid, fx_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_fx_guid_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/name
+fn dispatch_track_fx_name_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/fx/{fx_idx}/name", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_fx_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(fx_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_fx_name(track_guid, fx_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_fx_name_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/enabled
+fn dispatch_track_fx_enabled_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/fx/{fx_idx}/enabled", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_fx_enabled_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(fx_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_fx_enabled(track_guid, fx_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_fx_enabled_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/bypass
+fn dispatch_track_fx_bypass_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/fx/{fx_idx}/bypass", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_fx_bypass_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(fx_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_fx_bypass(track_guid, fx_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_fx_bypass_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/wet
+fn dispatch_track_fx_wet_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/fx/{fx_idx}/wet", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_fx_wet_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(fx_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_fx_wet(track_guid, fx_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_fx_wet_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param_count
+fn dispatch_track_fx_param_count_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/fx/{fx_idx}/param_count", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_fx_param_count_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(fx_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_fx_param_count(track_guid, fx_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_fx_param_count_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name
+fn dispatch_track_fx_param_name_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address(
+        "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/name",
+        addr,
+    ) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_fx_param_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(fx_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let Ok(param_idx) = args[2].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_fx_param_name(track_guid, fx_idx, param_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_fx_param_name_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value
+fn dispatch_track_fx_param_value_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address(
+        "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/value",
+        addr,
+    ) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_fx_param_value_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(fx_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let Ok(param_idx) = args[2].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_fx_param_value(track_guid, fx_idx, param_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_fx_param_value_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min
+fn dispatch_track_fx_param_min_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address(
+        "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/min",
+        addr,
+    ) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_fx_param_min_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(fx_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let Ok(param_idx) = args[2].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_fx_param_min(track_guid, fx_idx, param_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_fx_param_min_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max
+fn dispatch_track_fx_param_max_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address(
+        "/track/{track_guid}/fx/{fx_idx}/param/{param_idx}/max",
+        addr,
+    ) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_fx_param_max_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(fx_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let Ok(param_idx) = args[2].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_fx_param_max(track_guid, fx_idx, param_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_fx_param_max_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /track/{track_guid}/fx/{fx_idx}/info
+fn dispatch_track_fx_info_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/track/{track_guid}/fx/{fx_idx}/info", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_track_fx_info_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let track_guid = crate::guid::TrackGuid::from(args[0].as_str());
+    let Ok(fx_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.track_fx_info(track_guid, fx_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_track_fx_info_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /fxinfo/{ident}/name
+fn dispatch_fxinfo_name_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/fxinfo/{ident}/name", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_fxinfo_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let ident = args[0].clone();
+    let mut endpoint = reaper.fxinfo_name(ident);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_fxinfo_name_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /fxinfo/{ident}/param_count
+fn dispatch_fxinfo_param_count_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/fxinfo/{ident}/param_count", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_fxinfo_param_count_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let ident = args[0].clone();
+    let mut endpoint = reaper.fxinfo_param_count(ident);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_fxinfo_param_count_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/name
+fn dispatch_fxinfo_param_name_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/fxinfo/{ident}/param/{param_idx}/name", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_fxinfo_param_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let ident = args[0].clone();
+    let Ok(param_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.fxinfo_param_name(ident, param_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_fxinfo_param_name_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/min
+fn dispatch_fxinfo_param_min_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/fxinfo/{ident}/param/{param_idx}/min", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_fxinfo_param_min_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let ident = args[0].clone();
+    let Ok(param_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.fxinfo_param_min(ident, param_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_fxinfo_param_min_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /fxinfo/{ident}/param/{param_idx}/max
+fn dispatch_fxinfo_param_max_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/fxinfo/{ident}/param/{param_idx}/max", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_fxinfo_param_max_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let ident = args[0].clone();
+    let Ok(param_idx) = args[1].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.fxinfo_param_max(ident, param_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_fxinfo_param_max_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /fxinfo
+fn dispatch_fxinfo_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/fxinfo", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_fxinfo_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.fxinfo();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_fxinfo_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /play
+fn dispatch_play_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/play", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_play_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.play();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_play_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /stop
+fn dispatch_stop_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/stop", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_stop_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.stop();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_stop_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /record
+fn dispatch_record_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/record", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_record_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.record();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_record_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /repeat
+fn dispatch_repeat_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/repeat", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_repeat_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.repeat();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_repeat_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /rewind
+fn dispatch_rewind_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/rewind", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_rewind_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.rewind();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_rewind_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /forward
+fn dispatch_forward_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/forward", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_forward_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.forward();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_forward_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /time
+fn dispatch_time_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/time", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_time_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.time();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_time_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /tempo
+fn dispatch_tempo_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/tempo", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_tempo_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.tempo();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_tempo_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /project/guid
+fn dispatch_active_project_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/project/guid", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_active_project_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.active_project();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_active_project_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /project/{project_guid}/name
+fn dispatch_project_name_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/project/{project_guid}/name", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_project_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let project_guid = crate::guid::ProjectGuid::from(args[0].as_str());
+    let mut endpoint = reaper.project_name(project_guid);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_project_name_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /action
+fn dispatch_action_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/action", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_action_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.action();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_action_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /action/{command_id}
+fn dispatch_run_action_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/action/{command_id}", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_run_action_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let Ok(command_id) = args[0].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.run_action(command_id);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_run_action_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /action/{command_id}/state
+fn dispatch_action_state_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/action/{command_id}/state", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_action_state_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let Ok(command_id) = args[0].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.action_state(command_id);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_action_state_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /marker/all
+fn dispatch_marker_all_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/marker/all", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_marker_all_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.marker_all();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_marker_all_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /marker/{marker_idx}/name
+fn dispatch_marker_name_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/marker/{marker_idx}/name", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_marker_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let Ok(marker_idx) = args[0].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.marker_name(marker_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_marker_name_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /marker/{marker_idx}/position
+fn dispatch_marker_position_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/marker/{marker_idx}/position", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_marker_position_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let Ok(marker_idx) = args[0].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.marker_position(marker_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_marker_position_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /region/all
+fn dispatch_region_all_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(_args) = match_address("/region/all", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_region_all_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let mut endpoint = reaper.region_all();
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_region_all_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /region/{region_idx}/name
+fn dispatch_region_name_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/region/{region_idx}/name", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_region_name_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let Ok(region_idx) = args[0].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.region_name(region_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_region_name_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /region/{region_idx}/start
+fn dispatch_region_start_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/region/{region_idx}/start", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_region_start_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let Ok(region_idx) = args[0].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.region_start(region_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_region_start_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+/// /region/{region_idx}/end
+fn dispatch_region_end_route(
+    reaper: &mut Reaper,
+    msg: &rosc::OscMessage,
+    addr: &str,
+    undecodable: &mut Vec<String>,
+) -> bool {
+    let Some(args) = match_address("/region/{region_idx}/end", addr) else {
+        return false;
+    };
+    if let Err(reason) = parse_region_end_args(msg) {
+        undecodable.push(reason);
+        return true;
+    }
+    let Ok(region_idx) = args[0].parse::<i32>() else {
+        return true;
+    };
+    let mut endpoint = reaper.region_end(region_idx);
+    for (_, handler) in endpoint.handlers.iter_mut() {
+        if let Ok(args) = parse_region_end_args(msg) {
+            handler(args);
+        }
+    }
+    true
+}
+
+fn dispatch_packet<F>(reaper: &mut Reaper, packet: rosc::OscPacket, log_unknown: &F)
+where
+    F: Fn(&str, &Unhandled),
+{
+    match packet {
+        rosc::OscPacket::Message(msg) => dispatch_osc(reaper, msg, log_unknown),
+        rosc::OscPacket::Bundle(bundle) => {
+            for packet in bundle.content {
+                dispatch_packet(reaper, packet, log_unknown);
+            }
+        }
+    }
+}
+
+/// Receives packets on the Reaper socket and dispatches them until the socket errors out.
+pub async fn receive_loop<F>(reaper: &mut Reaper, log_unknown: F) -> std::io::Result<()>
+where
+    F: Fn(&str, &Unhandled),
+{
+    let socket = reaper.socket.clone();
+    let mut buf = [0u8; rosc::decoder::MTU];
+    loop {
+        let (size, _) = socket.recv_from(&mut buf).await?;
+        match decode::decode_packet(&buf[..size]) {
+            Ok(packet) => dispatch_packet(reaper, packet, &log_unknown),
+            Err(_) => log_unknown("<undecodable packet>", &Unhandled::BadPacket),
+        }
+    }
+}
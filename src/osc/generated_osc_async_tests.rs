@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use rosc::{OscMessage, OscPacket, OscType};
+use tokio::net::UdpSocket;
+
+use super::generated_osc_async::{Reaper, TrackVolumeArgs};
+use crate::traits::{AsyncQuery, AsyncSet};
+
+// A Reaper sending to a socket the test reads back from
+async fn reaper_and_listener() -> (Reaper, UdpSocket) {
+    let listener = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+    let reaper = Reaper::with_destination(Arc::new(socket), listener.local_addr().unwrap());
+    (reaper, listener)
+}
+
+async fn receive(listener: &UdpSocket) -> OscPacket {
+    let mut buf = [0u8; rosc::decoder::MTU];
+    let (size, _) = listener.recv_from(&mut buf).await.unwrap();
+    rosc::decoder::decode_udp(&buf[..size]).unwrap().1
+}
+
+#[tokio::test]
+async fn test_set_sends_the_message_to_reaper() {
+    let (reaper, listener) = reaper_and_listener().await;
+    reaper
+        .track_volume("abc".into())
+        .set(TrackVolumeArgs::new(0.5))
+        .await
+        .unwrap();
+    assert_eq!(
+        receive(&listener).await,
+        OscPacket::Message(OscMessage {
+            addr: "/track/abc/volume".to_string(),
+            args: vec![OscType::Float(0.5)],
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_query_sends_an_empty_message() {
+    let (reaper, listener) = reaper_and_listener().await;
+    reaper.num_tracks().query().await.unwrap();
+    assert_eq!(
+        receive(&listener).await,
+        OscPacket::Message(OscMessage {
+            addr: "/num_tracks".to_string(),
+            args: vec![],
+        })
+    );
+}
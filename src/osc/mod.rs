@@ -1,2 +1,20 @@
+pub mod bridge_api;
+pub mod coalesce;
+pub mod decode;
+pub mod echo;
+pub mod error;
 pub mod generated_osc;
+#[cfg(any(test, feature = "async"))]
+pub mod generated_osc_async;
+pub mod monitor;
+pub mod pattern;
+pub mod prefix;
+pub mod range;
 pub mod route_context;
+pub mod spec;
+pub mod subscribers;
+pub mod supervisor;
+pub mod transport;
+
+#[cfg(test)]
+mod generated_osc_async_tests;
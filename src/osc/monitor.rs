@@ -0,0 +1,145 @@
+use std::io::{self, Write};
+use std::time::Instant;
+
+use rosc::{OscMessage, OscPacket, OscType};
+
+use crate::osc::decode::decode_packet;
+use crate::osc::generated_osc::{RouteInfo, all_routes};
+use crate::osc::pattern::{is_placeholder, match_address};
+use crate::osc::transport::OscTransport;
+
+/// Which messages the monitor shows, by OSC address pattern, e.g. "/track/*/volume".
+#[derive(Clone, Debug, Default)]
+pub struct MonitorFilter {
+    patterns: Vec<String>,
+}
+
+impl MonitorFilter {
+    pub fn new(patterns: Vec<String>) -> Self {
+        MonitorFilter { patterns }
+    }
+
+    /// Whether a message to `addr` is shown. Without any patterns, everything is.
+    pub fn allows(&self, addr: &str) -> bool {
+        self.patterns.is_empty()
+            || self
+                .patterns
+                .iter()
+                .any(|pattern| match_address(pattern, addr).is_some())
+    }
+}
+
+/// The routes of the spec a message to `addr` matches.
+pub fn matching_routes(addr: &str) -> Vec<&'static RouteInfo> {
+    all_routes()
+        .iter()
+        .filter(|route| match_address(route.address, addr).is_some())
+        .collect()
+}
+
+/// A message on one line: its address, the routes it matches with the value of each of their
+/// params, and its arguments, named after the first route's.
+///
+/// E.g. `/track/abc/volume  track_volume(track_guid=abc)  volume=0.75`
+pub fn describe(msg: &OscMessage) -> String {
+    let routes = matching_routes(&msg.addr);
+    let names = match routes.is_empty() {
+        true => "?".to_string(),
+        false => routes
+            .iter()
+            .map(|route| describe_route(route, &msg.addr))
+            .collect::<Vec<_>>()
+            .join(" | "),
+    };
+    let arg_names = routes.first().map_or(&[][..], |route| route.arguments);
+    let args: Vec<String> = msg
+        .args
+        .iter()
+        .enumerate()
+        .map(|(idx, arg)| match arg_names.get(idx) {
+            Some((name, _)) => format!("{}={}", name, format_arg(arg)),
+            None => format_arg(arg),
+        })
+        .collect();
+    match args.is_empty() {
+        true => format!("{}  {}", msg.addr, names),
+        false => format!("{}  {}  {}", msg.addr, names, args.join(" ")),
+    }
+}
+
+// The route's name, followed by its params in `addr` if it has any
+fn describe_route(route: &RouteInfo, addr: &str) -> String {
+    let values = match_address(route.address, addr).unwrap_or_default();
+    let params: Vec<String> = route
+        .address
+        .split('/')
+        .filter(|segment| is_placeholder(segment))
+        .zip(values)
+        .map(|(param, value)| format!("{}={}", &param[1..param.len() - 1], value))
+        .collect();
+    match params.is_empty() {
+        true => route.name.to_string(),
+        false => format!("{}({})", route.name, params.join(", ")),
+    }
+}
+
+fn format_arg(arg: &OscType) -> String {
+    match arg {
+        OscType::Int(value) => value.to_string(),
+        OscType::Long(value) => value.to_string(),
+        OscType::Float(value) => value.to_string(),
+        OscType::Double(value) => value.to_string(),
+        OscType::String(value) => format!("{:?}", value),
+        OscType::Bool(value) => value.to_string(),
+        OscType::Blob(bytes) => format!("<{} bytes>", bytes.len()),
+        OscType::Nil => "nil".to_string(),
+        OscType::Inf => "inf".to_string(),
+        OscType::Array(array) => format!(
+            "[{}]",
+            array
+                .content
+                .iter()
+                .map(format_arg)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        other => format!("{:?}", other),
+    }
+}
+
+// Every message in a packet, bundles unpacked
+fn messages(packet: &OscPacket) -> Vec<&OscMessage> {
+    match packet {
+        OscPacket::Message(msg) => vec![msg],
+        OscPacket::Bundle(bundle) => bundle.content.iter().flat_map(messages).collect(),
+    }
+}
+
+/// Writes a line to `out` for each message arriving on `transport` that `filter` lets through,
+/// prefixed with the seconds since the monitor started. Runs until receiving fails.
+pub fn run(
+    transport: &dyn OscTransport,
+    filter: &MonitorFilter,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let start = Instant::now();
+    loop {
+        let (packet, from) = transport.recv()?;
+        let at = start.elapsed().as_secs_f64();
+        match decode_packet(&packet) {
+            Ok(packet) => {
+                for msg in messages(&packet) {
+                    if filter.allows(&msg.addr) {
+                        writeln!(out, "{:>10.3}  {}", at, describe(msg))?;
+                    }
+                }
+            }
+            Err(e) => writeln!(
+                out,
+                "{:>10.3}  undecodable packet from {}: {:?}",
+                at, from, e
+            )?,
+        }
+        out.flush()?;
+    }
+}
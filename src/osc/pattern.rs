@@ -0,0 +1,109 @@
+/// Matches an OSC address against a route, extracting the segments standing in for its params.
+///
+/// Route segments like `{track_guid}` are placeholders and capture whatever the address has in
+/// their place. Every other segment is compared with OSC 1.0 pattern matching in both
+/// directions, so wildcards work whether they're written in the route or sent in the address.
+///
+/// E.g. addr: "/track/abc123/pan", route: "/track/{track_guid}/pan" -> Some(vec!["abc123"])
+pub fn match_address(route: &str, addr: &str) -> Option<Vec<String>> {
+    let route_parts: Vec<&str> = route.split('/').filter(|s| !s.is_empty()).collect();
+    let addr_parts: Vec<&str> = addr.split('/').filter(|s| !s.is_empty()).collect();
+    if route_parts.len() != addr_parts.len() {
+        return None;
+    }
+    let mut captures = Vec::new();
+    for (route_part, addr_part) in route_parts.iter().zip(addr_parts.iter()) {
+        if is_placeholder(route_part) {
+            captures.push(addr_part.to_string());
+        } else if !matches_segment(route_part, addr_part) && !matches_segment(addr_part, route_part)
+        {
+            return None;
+        }
+    }
+    Some(captures)
+}
+
+/// Returns true if an address uses any OSC pattern syntax, and so might match routes that don't
+/// share its literal segments.
+pub fn is_pattern(addr: &str) -> bool {
+    addr.contains(['*', '?', '[', '{'])
+}
+
+/// Returns true if a route segment names a param, i.e. is wrapped in braces with no commas.
+///
+/// A single-alternative `{foo}` is therefore never treated as an OSC alternation.
+pub fn is_placeholder(segment: &str) -> bool {
+    segment.len() >= 2
+        && segment.starts_with('{')
+        && segment.ends_with('}')
+        && !segment.contains(',')
+}
+
+/// Returns true if one level of an OSC address matches an OSC 1.0 pattern.
+///
+/// `?` matches any single character, `*` any run of characters, `[abc]` or `[a-c]` one character
+/// from a set (`[!abc]` one character outside it) and `{foo,bar}` any of the listed strings.
+pub fn matches_segment(pattern: &str, segment: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let segment: Vec<char> = segment.chars().collect();
+    matches_chars(&pattern, &segment)
+}
+
+fn matches_chars(pattern: &[char], segment: &[char]) -> bool {
+    let Some((&first, rest)) = pattern.split_first() else {
+        return segment.is_empty();
+    };
+    match first {
+        '*' => (0..=segment.len()).any(|skip| matches_chars(rest, &segment[skip..])),
+        '?' => !segment.is_empty() && matches_chars(rest, &segment[1..]),
+        '[' => {
+            // An unclosed bracket is just a character
+            let Some(close) = rest.iter().position(|&c| c == ']') else {
+                return matches_literal(first, rest, segment);
+            };
+            let Some((&c, segment_rest)) = segment.split_first() else {
+                return false;
+            };
+            in_set(&rest[..close], c) && matches_chars(&rest[close + 1..], segment_rest)
+        }
+        '{' => {
+            let Some(close) = rest.iter().position(|&c| c == '}') else {
+                return matches_literal(first, rest, segment);
+            };
+            let after = &rest[close + 1..];
+            rest[..close].split(|&c| c == ',').any(|alternative| {
+                segment.starts_with(alternative)
+                    && matches_chars(after, &segment[alternative.len()..])
+            })
+        }
+        _ => matches_literal(first, rest, segment),
+    }
+}
+
+fn matches_literal(first: char, rest: &[char], segment: &[char]) -> bool {
+    match segment.split_first() {
+        Some((&c, segment_rest)) => c == first && matches_chars(rest, segment_rest),
+        None => false,
+    }
+}
+
+// The inside of a `[...]` pattern; a '-' between two characters is a range, anywhere else it's
+// a literal
+fn in_set(set: &[char], c: char) -> bool {
+    let (negated, set) = match set.split_first() {
+        Some(('!', set)) => (true, set),
+        _ => (false, set),
+    };
+    let mut found = false;
+    let mut i = 0;
+    while i < set.len() {
+        if i + 2 < set.len() && set[i + 1] == '-' {
+            found |= set[i] <= c && c <= set[i + 2];
+            i += 3;
+        } else {
+            found |= set[i] == c;
+            i += 1;
+        }
+    }
+    found != negated
+}
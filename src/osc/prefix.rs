@@ -0,0 +1,134 @@
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use rosc::OscPacket;
+use tracing::trace;
+
+use crate::osc::decode::decode_packet;
+use crate::osc::transport::OscTransport;
+
+/// Puts every address sent under a namespace of its own, e.g. "/reaper1/track/{guid}/volume"
+/// for "/track/{guid}/volume", and takes it back off what's received, so the client and its
+/// dispatcher never see it. For when Reaper's OSC is prefixed, e.g. to tell several instances of
+/// it apart on one port.
+///
+/// Received messages outside the namespace are someone else's and are dropped. Messages in
+/// bundles are dropped one by one, and a bundle left empty isn't received at all. A packet that
+/// can't be decoded is only received if it's a message under the prefix, with the prefix taken off.
+pub struct PrefixedTransport {
+    inner: Arc<dyn OscTransport>,
+    prefix: String,
+}
+
+impl PrefixedTransport {
+    /// `prefix` is an address of its own like "/reaper1"; a trailing slash is ignored.
+    pub fn new(inner: Arc<dyn OscTransport>, prefix: &str) -> Self {
+        PrefixedTransport {
+            inner,
+            prefix: prefix.trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn with_prefix(&self, packet: OscPacket) -> OscPacket {
+        match packet {
+            OscPacket::Message(mut msg) => {
+                msg.addr = format!("{}{}", self.prefix, msg.addr);
+                OscPacket::Message(msg)
+            }
+            OscPacket::Bundle(mut bundle) => {
+                bundle.content = bundle
+                    .content
+                    .into_iter()
+                    .map(|packet| self.with_prefix(packet))
+                    .collect();
+                OscPacket::Bundle(bundle)
+            }
+        }
+    }
+
+    // The packet with the prefix taken off, or None if none of it was under the prefix
+    fn without_prefix(&self, packet: OscPacket) -> Option<OscPacket> {
+        match packet {
+            OscPacket::Message(mut msg) => {
+                let Some(addr) = msg
+                    .addr
+                    .strip_prefix(&self.prefix)
+                    .filter(|addr| addr.starts_with('/'))
+                else {
+                    trace!(addr = %msg.addr, "Dropping a message from outside our namespace");
+                    return None;
+                };
+                msg.addr = addr.to_string();
+                Some(OscPacket::Message(msg))
+            }
+            OscPacket::Bundle(mut bundle) => {
+                bundle.content = bundle
+                    .content
+                    .into_iter()
+                    .filter_map(|packet| self.without_prefix(packet))
+                    .collect();
+                match bundle.content.is_empty() {
+                    true => None,
+                    false => Some(OscPacket::Bundle(bundle)),
+                }
+            }
+        }
+    }
+
+    // The unreadable packet in `buf` with the prefix taken off its address, or None if it isn't a
+    // message under the prefix. Only the address is touched, so the rest is as wrong as it was.
+    fn raw_without_prefix(&self, buf: &[u8]) -> Option<Vec<u8>> {
+        let end = buf.iter().position(|byte| *byte == 0)?;
+        let addr = buf[..end].strip_prefix(self.prefix.as_bytes())?;
+        if !addr.starts_with(b"/") {
+            return None;
+        }
+        // OSC strings end in at least one nul and are padded to a multiple of 4 bytes
+        let rest = buf.get((end + 4) / 4 * 4..).unwrap_or_default();
+        let mut stripped = addr.to_vec();
+        stripped.resize((addr.len() + 4) / 4 * 4, 0);
+        stripped.extend_from_slice(rest);
+        Some(stripped)
+    }
+
+    fn prefixed(&self, packet: &[u8]) -> io::Result<Vec<u8>> {
+        let (_, packet) = rosc::decoder::decode_udp(packet)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        rosc::encoder::encode(&self.with_prefix(packet))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+    }
+}
+
+impl OscTransport for PrefixedTransport {
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        self.inner.send(&self.prefixed(packet)?)
+    }
+
+    fn send_to(&self, packet: &[u8], to: SocketAddr) -> io::Result<()> {
+        self.inner.send_to(&self.prefixed(packet)?, to)
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        loop {
+            let (buf, from) = self.inner.recv()?;
+            // Anything unreadable is left for the receive loop to complain about, if it's ours
+            let Ok(packet) = decode_packet(&buf) else {
+                match self.raw_without_prefix(&buf) {
+                    Some(buf) => return Ok((buf, from)),
+                    None => {
+                        trace!("Dropping an unreadable packet from outside our namespace");
+                        continue;
+                    }
+                }
+            };
+            let Some(stripped) = self.without_prefix(packet) else {
+                continue;
+            };
+            match rosc::encoder::encode(&stripped) {
+                Ok(buf) => return Ok((buf, from)),
+                Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+            }
+        }
+    }
+}
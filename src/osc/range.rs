@@ -0,0 +1,58 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+
+// Whether `value` is on the wrong side of `bound`; values that can't be compared, like NaN, are
+// never on the right side
+fn beyond<T: PartialOrd>(value: &T, bound: &T, wrong_side: Ordering) -> bool {
+    match value.partial_cmp(bound) {
+        Some(ordering) => ordering == wrong_side,
+        None => true,
+    }
+}
+
+/// Checks that an argument is within the range the spec gives it, saying why not otherwise.
+pub fn check_range<T>(name: &str, value: T, min: Option<T>, max: Option<T>) -> Result<(), String>
+where
+    T: PartialOrd + Display,
+{
+    if let Some(min) = min.filter(|min| beyond(&value, min, Ordering::Less)) {
+        return Err(format!(
+            "{} is {}, below its minimum of {}",
+            name, value, min
+        ));
+    }
+    if let Some(max) = max.filter(|max| beyond(&value, max, Ordering::Greater)) {
+        return Err(format!(
+            "{} is {}, above its maximum of {}",
+            name, value, max
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that an argument is one of the values the spec allows it, saying why not otherwise.
+pub fn check_one_of<T, A>(name: &str, value: &T, allowed: &[A]) -> Result<(), String>
+where
+    T: PartialEq<A> + Debug + ?Sized,
+    A: Debug,
+{
+    match allowed.iter().any(|allowed| value == allowed) {
+        true => Ok(()),
+        false => Err(format!(
+            "{} is {:?}, which isn't one of {:?}",
+            name, value, allowed
+        )),
+    }
+}
+
+/// Brings an argument into the range the spec gives it. A value that can't be compared to the
+/// range, like NaN, is taken as the minimum, or failing that the maximum.
+pub fn clamp<T: PartialOrd>(value: T, min: Option<T>, max: Option<T>) -> T {
+    if let Some(min) = min.filter(|min| beyond(&value, min, Ordering::Less)) {
+        return min;
+    }
+    match max.filter(|max| beyond(&value, max, Ordering::Greater)) {
+        Some(max) => max,
+        None => value,
+    }
+}
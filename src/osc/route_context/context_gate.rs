@@ -1,10 +1,16 @@
+use std::any::{Any, TypeId};
 use std::collections::{HashMap, VecDeque};
 use std::fmt::Debug;
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::marker::PhantomData;
 use std::time::{Duration, Instant};
 
+use crossbeam_channel::{Receiver, Sender, TryRecvError, bounded};
 use rosc::{OscMessage, OscPacket};
+use tracing::{debug, debug_span, trace, warn};
+
+use crate::osc::pattern::match_address;
+use crate::osc::route_context::key_messages::KeyMessages;
 
 fn hash_to_u64<T: std::hash::Hash>(hashable: T) -> u64 {
     let mut hasher = std::hash::DefaultHasher::new();
@@ -30,6 +36,59 @@ pub trait ContextKindTrait: Debug + Eq + Clone + std::hash::Hash {
     fn context_name() -> &'static str;
 }
 
+/// What to do with a message for a context whose buffer is already full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Make room by dropping the oldest buffered message
+    DropOldest,
+    /// Drop the incoming message, keeping what's already buffered
+    DropNewest,
+    /// Drop the incoming message and log a warning, since a full buffer usually means a key
+    /// message is never coming
+    FailAndLog,
+}
+
+/// How many messages each context may buffer while it waits on its key messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BufferLimit {
+    max_messages: usize,
+    policy: OverflowPolicy,
+}
+
+/// The context a context of kind K lives under, e.g. the track a send belongs to, which has to be
+/// initialized before it is.
+struct ParentDependency<K: ContextKindTrait> {
+    kind: TypeId,
+    kind_name: &'static str,
+    parent_of: Box<dyn Fn(&K::Context) -> Box<dyn Any>>,
+}
+
+/// Handed back by an initialization callback that has set something going elsewhere, e.g.
+/// TrackManager creating the track, to hold the context's buffered messages back until that's
+/// done.
+pub struct InitReceipt {
+    acknowledged: Receiver<()>,
+}
+
+impl InitReceipt {
+    /// A receipt along with what acknowledges it, by sending on it or by being dropped.
+    pub fn new() -> (Sender<()>, Self) {
+        let (acknowledge, acknowledged) = bounded(1);
+        (acknowledge, InitReceipt { acknowledged })
+    }
+
+    // Whether the receipt has been acknowledged, or been given up on by whatever held it
+    fn settled(&self) -> bool {
+        !matches!(self.acknowledged.try_recv(), Err(TryRecvError::Empty))
+    }
+}
+
+type InitializationCallback<C> =
+    Box<dyn FnMut(C, &HashMap<String, OscMessage>) -> Option<InitReceipt>>;
+
+// How long a context waits on its receipt by default before its messages are let through anyway
+const DEFAULT_ACK_TIMEOUT: Duration = Duration::from_millis(500);
+
 pub trait ContextGateBuilderTrait {
     fn build_boxed(self: Box<Self>) -> Box<dyn ContextualDispatcher>;
 }
@@ -37,7 +96,10 @@ pub trait ContextGateBuilderTrait {
 // Builder for a single context gate layer
 pub struct ContextGateBuilder<K: ContextKindTrait> {
     key_routes: Vec<String>,
-    on_initialized: Option<Box<dyn FnMut(K::Context, &HashMap<String, OscMessage>)>>,
+    on_initialized: Option<InitializationCallback<K::Context>>,
+    ack_timeout: Duration,
+    buffer_limit: Option<BufferLimit>,
+    parent: Option<ParentDependency<K>>,
 
     _marker: PhantomData<K>,
 }
@@ -47,6 +109,9 @@ impl<K: ContextKindTrait> ContextGateBuilder<K> {
         Self {
             key_routes: Vec::new(),
             on_initialized: None,
+            ack_timeout: DEFAULT_ACK_TIMEOUT,
+            buffer_limit: None,
+            parent: None,
             _marker: PhantomData,
         }
     }
@@ -63,11 +128,102 @@ impl<K: ContextKindTrait> ContextGateBuilder<K> {
         self
     }
 
-    pub fn with_initialization_callback<F>(mut self, callback: F) -> Self
+    pub fn with_initialization_callback<F>(mut self, mut callback: F) -> Self
     where
         F: FnMut(K::Context, &HashMap<String, OscMessage>) + 'static,
     {
-        self.on_initialized = Some(Box::new(callback));
+        self.on_initialized = Some(Box::new(move |context, messages| {
+            callback(context, messages);
+            None
+        }));
+        self
+    }
+
+    /// Like with_initialization_callback, but the context's buffered messages are only flushed
+    /// once the receipt the callback returns is acknowledged, or after the acknowledgment
+    /// timeout, whichever comes first.
+    ///
+    /// Nothing waits on the receipt: the context's messages go on being buffered until the
+    /// router next dispatches or is asked to flush_acknowledged, after the acknowledgment.
+    pub fn with_acknowledged_initialization<F>(mut self, mut callback: F) -> Self
+    where
+        F: FnMut(K::Context, &HashMap<String, OscMessage>) -> InitReceipt + 'static,
+    {
+        self.on_initialized = Some(Box::new(move |context, messages| {
+            Some(callback(context, messages))
+        }));
+        self
+    }
+
+    /// Waits on each of M's key routes, and hands the callback their decoded values once they've
+    /// all arrived. A context whose key messages can't be decoded is still initialized, but the
+    /// callback is skipped and the failure logged.
+    pub fn with_key_messages<M, F>(mut self, mut callback: F) -> Self
+    where
+        M: KeyMessages,
+        F: FnMut(K::Context, M) + 'static,
+    {
+        self = self.add_key_routes(M::KEY_ROUTES.to_vec());
+        self.with_initialization_callback(move |context, messages| match M::decode(messages) {
+            Ok(decoded) => callback(context, decoded),
+            Err(e) => warn!(?context, "Couldn't decode key messages: {}", e),
+        })
+    }
+
+    /// Like with_key_messages, but with the callback's receipt held to as in
+    /// with_acknowledged_initialization.
+    pub fn with_acknowledged_key_messages<M, F>(mut self, mut callback: F) -> Self
+    where
+        M: KeyMessages,
+        F: FnMut(K::Context, M) -> InitReceipt + 'static,
+    {
+        self = self.add_key_routes(M::KEY_ROUTES.to_vec());
+        self.on_initialized = Some(Box::new(move |context, messages| {
+            match M::decode(messages) {
+                Ok(decoded) => Some(callback(context, decoded)),
+                Err(e) => {
+                    warn!(?context, "Couldn't decode key messages: {}", e);
+                    None
+                }
+            }
+        }));
+        self
+    }
+
+    /// How long a context waits on the receipt from its initialization callback before its
+    /// messages are let through anyway. Defaults to 500ms.
+    pub fn with_ack_timeout(mut self, timeout: Duration) -> Self {
+        self.ack_timeout = timeout;
+        self
+    }
+
+    /// Caps how many messages (at least one) each context of this kind may buffer before its key
+    /// messages arrive, applying `policy` to messages beyond that. Buffers are unbounded by
+    /// default.
+    ///
+    /// When a message waits on several layers, the tightest cap among them applies.
+    pub fn with_max_buffered(mut self, max_messages: usize, policy: OverflowPolicy) -> Self {
+        self.buffer_limit = Some(BufferLimit {
+            max_messages: max_messages.max(1),
+            policy,
+        });
+        self
+    }
+
+    /// Makes each context of this kind wait on the context of kind P it lives under, which
+    /// `parent_of` picks out, e.g. a send on its track.
+    ///
+    /// A context whose key messages have all arrived is only initialized once its parent is, and
+    /// its messages are only flushed after its parent's. The router needs a layer of kind P.
+    pub fn requires_parent<P: ContextKindTrait + 'static>(
+        mut self,
+        parent_of: impl Fn(&K::Context) -> P::Context + 'static,
+    ) -> Self {
+        self.parent = Some(ParentDependency {
+            kind: TypeId::of::<P>(),
+            kind_name: P::context_name(),
+            parent_of: Box::new(move |context| Box::new(parent_of(context))),
+        });
         self
     }
 
@@ -76,7 +232,12 @@ impl<K: ContextKindTrait> ContextGateBuilder<K> {
             key_routes: self.key_routes,
             initialized: HashMap::new(),
             on_initialized: self.on_initialized,
+            ack_timeout: self.ack_timeout,
+            awaiting_ack: HashMap::new(),
             key_messages: HashMap::new(),
+            buffer_limit: self.buffer_limit,
+            parent: self.parent,
+            waiting_on_parent: Vec::new(),
             _marker: PhantomData,
         }
     }
@@ -100,6 +261,42 @@ trait ContextualDispatcher {
         msg: &OscMessage,
     ) -> Option<(InitializationState, Option<u64>)>;
 
+    /// Whether a message to this address has to wait on one of this layer's contexts.
+    fn is_gating(&self, addr: &str) -> bool;
+
+    /// Whether this layer has initialized the given context.
+    fn is_initialized(&self, context: &dyn Any) -> bool;
+
+    /// The kind of context this layer gates on, and its name.
+    fn kind(&self) -> (TypeId, &'static str);
+
+    /// The kind of context this layer's contexts live under, and its name, if any.
+    fn parent_kind(&self) -> Option<(TypeId, &'static str)>;
+
+    /// Initializes the contexts that only lacked their parent, if `parent` (the layer of their
+    /// parent's kind) has since initialized it. Returns whether any were.
+    fn initialize_waiting(&mut self, parent: &dyn ContextualDispatcher) -> bool;
+
+    /// Initializes the contexts whose initialization has been acknowledged, or has gone
+    /// unacknowledged past the timeout as of `now`. Returns whether any were.
+    fn settle_acknowledged(&mut self, now: Instant) -> bool;
+
+    /// Whether any of this layer's contexts is waiting on its initialization to be acknowledged.
+    fn awaiting_acknowledgment(&self) -> bool;
+
+    /// Forgets every context, so each has to see its key messages again before passing through.
+    fn reset_all(&mut self);
+
+    /// Forgets one context if it belongs to this layer, returning whether this layer knew it.
+    fn reset_context(&mut self, context: &dyn Any) -> bool;
+
+    /// How many messages each of this layer's contexts may buffer, if it's capped at all.
+    fn buffer_limit(&self) -> Option<BufferLimit>;
+
+    /// How many contexts this layer has seen key messages for, and how many of those it has
+    /// initialized.
+    fn context_counts(&self) -> (usize, usize);
+
     #[cfg(test)]
     fn test_info(&self, ctx_str: &str) -> HashMap<String, usize>;
 }
@@ -118,22 +315,66 @@ struct ContextGate<K: ContextKindTrait + 'static> {
     // We buffer messages if this is false. When it's true, we pass messages through.
     // At the moment we set it true, we also flush the buffer.
     initialized: HashMap<K::Context, bool>,
-    // Called when a specific context is initialized
-    on_initialized: Option<Box<dyn FnMut(K::Context, &HashMap<String, OscMessage>)>>,
+    // Called when a specific context is initialized, with what it returns waited on before the
+    // context's messages are let through
+    on_initialized: Option<InitializationCallback<K::Context>>,
+    ack_timeout: Duration,
+    // Contexts whose receipt hasn't been acknowledged yet, each with when to stop waiting on it
+    awaiting_ack: HashMap<K::Context, (InitReceipt, Instant)>,
     key_messages: HashMap<K::Context, HashMap<String, OscMessage>>,
+    buffer_limit: Option<BufferLimit>,
+    parent: Option<ParentDependency<K>>,
+    // Contexts with all their key messages whose parent isn't initialized yet, in the order they
+    // got them
+    waiting_on_parent: Vec<K::Context>,
 
     _marker: PhantomData<K>,
 }
 
 impl<K: ContextKindTrait> ContextGate<K> {
-    /// Mark a specific concrete OscContext as initialized
-    pub fn initialize(&mut self, context: K::Context) {
+    /// Mark a specific concrete OscContext as initialized, unless its callback hands back a
+    /// receipt still to be acknowledged. Returns whether it was.
+    pub fn initialize(&mut self, context: K::Context) -> bool {
         let key_messages = self.key_messages.get(&context).unwrap();
 
-        if let Some(callback) = &mut self.on_initialized {
-            callback(context.clone(), key_messages);
+        debug!(?context, "Context initialized");
+        let receipt = match &mut self.on_initialized {
+            Some(callback) => callback(context.clone(), key_messages),
+            None => None,
+        };
+        match receipt {
+            Some(receipt) if !receipt.settled() => {
+                trace!(?context, "Waiting on the initialization to be acknowledged");
+                let deadline = Instant::now() + self.ack_timeout;
+                self.awaiting_ack.insert(context, (receipt, deadline));
+                false
+            }
+            _ => {
+                self.initialized.insert(context, true);
+                true
+            }
+        }
+    }
+
+    /// Forgets a concrete OscContext, so it has to see its key messages again before passing
+    /// through. Returns whether the context had been seen at all.
+    pub fn reset_context(&mut self, context: &K::Context) -> bool {
+        self.waiting_on_parent.retain(|waiting| waiting != context);
+        self.awaiting_ack.remove(context);
+        let initialized = self.initialized.remove(context).is_some();
+        let key_messages = self.key_messages.remove(context).is_some();
+        if initialized || key_messages {
+            debug!(?context, "Context reset");
         }
-        self.initialized.insert(context.clone(), true);
+        initialized || key_messages
+    }
+
+    /// Forgets every concrete OscContext.
+    pub fn reset_all(&mut self) {
+        self.initialized.clear();
+        self.awaiting_ack.clear();
+        self.key_messages.clear();
+        self.waiting_on_parent.clear();
     }
 }
 
@@ -144,6 +385,11 @@ impl<K: ContextKindTrait + 'static> ContextualDispatcher for ContextGate<K> {
     ) -> Option<(InitializationState, Option<u64>)> {
         match K::parse(&msg.addr) {
             None => None,
+            // Its key messages are all in, it only waits on the acknowledgment now
+            Some(context) if self.awaiting_ack.contains_key(&context) => Some((
+                InitializationState::Uninitialized,
+                Some(hash_to_u64(&context)),
+            )),
             Some(context) => {
                 // If this message is relevant to this layer...
                 match self.initialized.get(&context) {
@@ -177,13 +423,23 @@ impl<K: ContextKindTrait + 'static> ContextualDispatcher for ContextGate<K> {
                                 .iter()
                                 .all(|route| key_msgs.contains_key(route));
 
-                            if has_all_key_messages {
-                                // Initialize the context
-                                self.initialize(context.clone());
+                            if has_all_key_messages && self.parent.is_some() {
+                                // The router initializes it once its parent is
+                                if !self.waiting_on_parent.contains(&context) {
+                                    trace!(?context, "Context waiting on its parent");
+                                    self.waiting_on_parent.push(context.clone());
+                                }
                                 Some((
-                                    InitializationState::NewlyInitialized,
+                                    InitializationState::Uninitialized,
                                     Some(hash_to_u64(&context)),
                                 ))
+                            } else if has_all_key_messages {
+                                // Initialize the context
+                                let state = match self.initialize(context.clone()) {
+                                    true => InitializationState::NewlyInitialized,
+                                    false => InitializationState::Uninitialized,
+                                };
+                                Some((state, Some(hash_to_u64(&context))))
                             } else {
                                 Some((
                                     InitializationState::Uninitialized,
@@ -202,6 +458,98 @@ impl<K: ContextKindTrait + 'static> ContextualDispatcher for ContextGate<K> {
         }
     }
 
+    fn is_gating(&self, addr: &str) -> bool {
+        match K::parse(addr) {
+            Some(context) => self.initialized.get(&context) != Some(&true),
+            None => false,
+        }
+    }
+
+    fn is_initialized(&self, context: &dyn Any) -> bool {
+        match context.downcast_ref::<K::Context>() {
+            Some(context) => self.initialized.get(context) == Some(&true),
+            None => false,
+        }
+    }
+
+    fn kind(&self) -> (TypeId, &'static str) {
+        (TypeId::of::<K>(), K::context_name())
+    }
+
+    fn parent_kind(&self) -> Option<(TypeId, &'static str)> {
+        self.parent
+            .as_ref()
+            .map(|parent| (parent.kind, parent.kind_name))
+    }
+
+    fn initialize_waiting(&mut self, parent: &dyn ContextualDispatcher) -> bool {
+        let Some(dependency) = &self.parent else {
+            return false;
+        };
+        let (ready, waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.waiting_on_parent)
+            .into_iter()
+            .partition(|context| parent.is_initialized((dependency.parent_of)(context).as_ref()));
+        self.waiting_on_parent = waiting;
+        let mut initialized_any = false;
+        for context in ready {
+            initialized_any |= self.initialize(context);
+        }
+        initialized_any
+    }
+
+    fn settle_acknowledged(&mut self, now: Instant) -> bool {
+        let settled: Vec<K::Context> = self
+            .awaiting_ack
+            .iter()
+            .filter(|(context, (receipt, deadline))| {
+                if receipt.settled() {
+                    return true;
+                }
+                if now < *deadline {
+                    return false;
+                }
+                warn!(
+                    ?context,
+                    timeout_ms = self.ack_timeout.as_millis() as u64,
+                    "Initialization wasn't acknowledged in time, letting its messages through"
+                );
+                true
+            })
+            .map(|(context, _)| context.clone())
+            .collect();
+        for context in &settled {
+            self.awaiting_ack.remove(context);
+            self.initialized.insert(context.clone(), true);
+        }
+        !settled.is_empty()
+    }
+
+    fn awaiting_acknowledgment(&self) -> bool {
+        !self.awaiting_ack.is_empty()
+    }
+
+    fn reset_all(&mut self) {
+        ContextGate::reset_all(self);
+    }
+
+    fn reset_context(&mut self, context: &dyn Any) -> bool {
+        match context.downcast_ref::<K::Context>() {
+            Some(context) => ContextGate::reset_context(self, context),
+            None => false,
+        }
+    }
+
+    fn buffer_limit(&self) -> Option<BufferLimit> {
+        self.buffer_limit
+    }
+
+    fn context_counts(&self) -> (usize, usize) {
+        // A context is only initialized once it has all its key messages, and forgetting it
+        // forgets both
+        let initialized = self.initialized.values().filter(|init| **init).count();
+        (self.key_messages.len(), initialized)
+    }
+
     #[cfg(test)]
     fn test_info(&self, ctx_str: &str) -> HashMap<String, usize> {
         let mut info = HashMap::new();
@@ -220,11 +568,25 @@ impl<K: ContextKindTrait + 'static> ContextualDispatcher for ContextGate<K> {
 
 pub type Dispatcher = Box<dyn FnMut(OscMessage)>;
 
+/// Receives messages rejected by a strict router, along with the number of times a message with
+/// the same address has been rejected so far (including this one).
+pub type DiagnosticsSink = Box<dyn FnMut(&OscMessage, u64)>;
+
+// Addresses a strict router expects every message to belong to some context for, along with
+// where to report messages that don't
+struct StrictMode {
+    prefixes: Vec<String>,
+    sink: DiagnosticsSink,
+    rejected_counts: HashMap<String, u64>,
+}
+
 // Main builder for the router
 pub struct OscGatedRouterBuilder {
     layers: Vec<Box<dyn ContextGateBuilderTrait>>,
     dispatcher: Dispatcher,
     buffer_timeout: Duration,
+    purge_interval: Option<Duration>,
+    strict: Option<(Vec<String>, DiagnosticsSink)>,
 }
 
 impl OscGatedRouterBuilder {
@@ -236,6 +598,8 @@ impl OscGatedRouterBuilder {
             layers: Vec::new(),
             dispatcher: Box::new(dispatcher),
             buffer_timeout: Duration::from_secs(60), // Default 1 minute timeout
+            purge_interval: None,
+            strict: None,
         }
     }
 
@@ -244,6 +608,32 @@ impl OscGatedRouterBuilder {
         self
     }
 
+    /// Purges stale buffers automatically, at most once per `interval`, as messages are
+    /// dispatched. Without it, stale buffers are only purged by calling purge_stale_buffers().
+    ///
+    /// Buffers only grow as messages arrive, so checking on dispatch is enough to keep them
+    /// bounded by the buffer timeout.
+    pub fn with_purge_interval(mut self, interval: Duration) -> Self {
+        self.purge_interval = Some(interval);
+        self
+    }
+
+    /// Rejects messages under any of the given address prefixes (e.g. "/track/") that no layer
+    /// recognizes, sending them to the sink instead of dispatching them ungated. The sink is
+    /// given each along with how many times its address has been rejected so far.
+    ///
+    /// Such messages usually mean the spec and Reaper disagree about an address.
+    pub fn with_strict_prefixes<F>(mut self, prefixes: Vec<impl Into<String>>, sink: F) -> Self
+    where
+        F: FnMut(&OscMessage, u64) + 'static,
+    {
+        let prefixes = prefixes.into_iter().map(Into::into).collect();
+        self.strict = Some((prefixes, Box::new(sink)));
+        self
+    }
+
+    /// Adds a layer gating on one kind of context. Layers of different kinds mix freely, each
+    /// keeping its typed initialization callback, e.g. a Track layer followed by a Send layer.
     pub fn add_layer(mut self, layer: Box<dyn ContextGateBuilderTrait>) -> Self {
         self.layers.push(layer);
         self
@@ -259,11 +649,40 @@ impl OscGatedRouterBuilder {
             layers.push(layer_builder.build_boxed());
         }
 
+        // Each layer with a parent, along with the index of its parent's layer
+        let mut parents = Vec::new();
+        for (child, layer) in layers.iter().enumerate() {
+            let Some((parent_kind, parent_name)) = layer.parent_kind() else {
+                continue;
+            };
+            match layers
+                .iter()
+                .position(|layer| layer.kind().0 == parent_kind)
+            {
+                Some(parent) => parents.push((child, parent)),
+                None => {
+                    return Err(RouterBuildError::MissingParentLayer {
+                        child: layer.kind().1,
+                        parent: parent_name,
+                    });
+                }
+            }
+        }
+
         Ok(OscGatedRouter {
             layers,
+            parents,
             dispatcher: self.dispatcher,
             buffer_timeout: self.buffer_timeout,
+            purge_interval: self.purge_interval,
+            last_purge: Instant::now(),
             buffer: HashMap::new(),
+            next_seq: 0,
+            strict: self.strict.map(|(prefixes, sink)| StrictMode {
+                prefixes,
+                sink,
+                rejected_counts: HashMap::new(),
+            }),
         })
     }
 }
@@ -271,30 +690,61 @@ impl OscGatedRouterBuilder {
 #[derive(Debug)]
 pub enum RouterBuildError {
     NoDispatcherProvided,
+    /// A layer requires a parent of a kind that no layer gates on
+    MissingParentLayer {
+        child: &'static str,
+        parent: &'static str,
+    },
 }
 
-/// Returns true if the OSC address matches a pattern expressed as a pattern.
+impl std::fmt::Display for RouterBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            RouterBuildError::NoDispatcherProvided => write!(f, "no dispatcher was provided"),
+            RouterBuildError::MissingParentLayer { child, parent } => write!(
+                f,
+                "the {} layer needs a {} layer for its parents, but there isn't one",
+                child, parent
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RouterBuildError {}
+
+// Picks whichever limit caps the buffer sooner, where None means unbounded
+fn tightest_limit(a: Option<BufferLimit>, b: Option<BufferLimit>) -> Option<BufferLimit> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(if b.max_messages < a.max_messages {
+            b
+        } else {
+            a
+        }),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+/// Returns true if the OSC address matches a key route.
 ///
 /// E.g. for "/track/{track_guid}/index", this will match "/track/1234567890/index" but not
-/// "/track/1234567890/
+/// "/track/1234567890/name". Either side may use OSC 1.0 wildcards, e.g.
+/// "/track/{track_guid}/{index,name}".
 fn matches_key_pattern(osc_addr: &str, key_route: &str) -> bool {
-    let osc_parts: Vec<&str> = osc_addr.split('/').filter(|s| !s.is_empty()).collect();
-    let key_parts: Vec<&str> = key_route.split('/').filter(|s| !s.is_empty()).collect();
-
-    if osc_parts.len() != key_parts.len() {
-        return false;
-    }
+    match_address(key_route, osc_addr).is_some()
+}
 
-    for (osc, key) in osc_parts.iter().zip(key_parts.iter()) {
-        if key.starts_with('{') && key.ends_with('}') {
-            // Wildcard segment, always matches
-            continue;
-        }
-        if osc != key {
-            return false;
-        }
-    }
-    true
+/// A snapshot of what an [`OscGatedRouter`]'s gates are doing
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GateStats {
+    /// Contexts that have seen key messages, across every layer
+    pub contexts: usize,
+    /// Those of them that are initialized and letting their messages through
+    pub initialized: usize,
+    /// Messages held back waiting on their contexts
+    pub buffered: usize,
+    /// How long the longest waiting of those has waited, if there are any
+    pub oldest_buffer_age: Option<Duration>,
 }
 
 /// OscGatedRouter allows gating a set of OSC messages until certain conditions are met.
@@ -310,21 +760,212 @@ fn matches_key_pattern(osc_addr: &str, key_route: &str) -> bool {
 pub struct OscGatedRouter {
     // Each layer represents some field in the OSC address we may need to filter on
     layers: Vec<Box<dyn ContextualDispatcher>>,
+    // Pairs of (child, parent) layer indices, for layers whose contexts wait on a parent
+    parents: Vec<(usize, usize)>,
     dispatcher: Box<dyn FnMut(OscMessage)>,
     buffer_timeout: Duration,
-    buffer: HashMap<u64, VecDeque<(OscMessage, Instant)>>,
+    purge_interval: Option<Duration>,
+    last_purge: Instant,
+    // Buffered messages by the contexts they wait on, each with when it arrived and where it came
+    // in the order of everything buffered
+    buffer: HashMap<u64, VecDeque<(OscMessage, Instant, u64)>>,
+    next_seq: u64,
+    strict: Option<StrictMode>,
 }
 
 impl OscGatedRouter {
+    /// Returns how many contexts have messages waiting on their key messages.
+    pub fn buffered_context_count(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns how many messages are waiting on their contexts to be initialized, across all of
+    /// them.
+    pub fn buffered_message_count(&self) -> usize {
+        self.buffer.values().map(|messages| messages.len()).sum()
+    }
+
+    /// Returns what the gates are doing right now, for metrics and logs.
+    pub fn stats(&self) -> GateStats {
+        let (contexts, initialized) = self
+            .layers
+            .iter()
+            .map(|layer| layer.context_counts())
+            .fold((0, 0), |(contexts, initialized), (c, i)| {
+                (contexts + c, initialized + i)
+            });
+        let oldest = self
+            .buffer
+            .values()
+            .flat_map(|messages| messages.iter().map(|(_, arrived, _)| *arrived))
+            .min();
+        GateStats {
+            contexts,
+            initialized,
+            buffered: self.buffered_message_count(),
+            oldest_buffer_age: oldest.map(|arrived| arrived.elapsed()),
+        }
+    }
+
+    /// Returns every layer to its uninitialized state and drops any buffered messages.
+    ///
+    /// For when Reaper has restarted and the contexts we knew about may no longer mean the same
+    /// thing; they initialize again as their key messages arrive.
+    pub fn reset_all(&mut self) {
+        for layer in self.layers.iter_mut() {
+            layer.reset_all();
+        }
+        self.buffer.clear();
+    }
+
+    /// Returns one context to its uninitialized state, e.g. when the track it stands for has been
+    /// deleted. Its messages are buffered again until its key messages arrive once more.
+    ///
+    /// Contexts nested under it (such as a track's sends) live in layers of their own and are
+    /// left alone, but their messages are held back along with it since they pass through its
+    /// layer too. Returns whether any layer knew the context.
+    pub fn reset_context<C: ContextTrait + 'static>(&mut self, context: &C) -> bool {
+        let mut known = false;
+        for layer in self.layers.iter_mut() {
+            known |= layer.reset_context(context);
+        }
+        known
+    }
+
+    /// Drops buffered messages that have waited longer than the buffer timeout, along with any
+    /// context left with nothing buffered.
     pub fn purge_stale_buffers(&mut self) {
         let now = Instant::now();
-        // TODO: this needs to take timestamps on the keys in buffer and update those when
-        // messages get buffered inside dispatch_osc
+        self.last_purge = now;
+        let before = self.buffered_message_count();
         for (_, messages) in self.buffer.iter_mut() {
-            messages.retain(|(_, timestamp)| now.duration_since(*timestamp) <= self.buffer_timeout);
+            messages
+                .retain(|(_, timestamp, _)| now.duration_since(*timestamp) <= self.buffer_timeout);
+        }
+        self.buffer.retain(|_, messages| !messages.is_empty());
+        let purged = before - self.buffered_message_count();
+        if purged > 0 {
+            debug!(purged, "Purged stale buffered messages");
+        }
+    }
+
+    // Purges stale buffers if a purge interval is set and it has passed since the last purge
+    fn purge_if_due(&mut self) {
+        match self.purge_interval {
+            Some(interval) if self.last_purge.elapsed() >= interval => self.purge_stale_buffers(),
+            _ => {}
         }
     }
 
+    /// Lets through the messages of contexts whose initialization has been acknowledged since,
+    /// or has gone unacknowledged past the timeout. Every dispatch starts with this, but while
+    /// awaiting_acknowledgment, whoever drives the router should call it too rather than leave
+    /// those messages until the next one arrives.
+    pub fn flush_acknowledged(&mut self) {
+        let now = Instant::now();
+        let mut settled = false;
+        for layer in self.layers.iter_mut() {
+            settled |= layer.settle_acknowledged(now);
+        }
+        if settled {
+            self.initialize_waiting_children();
+            self.flush_ready();
+        }
+    }
+
+    /// Whether any context is waiting on its initialization to be acknowledged.
+    pub fn awaiting_acknowledgment(&self) -> bool {
+        self.layers
+            .iter()
+            .any(|layer| layer.awaiting_acknowledgment())
+    }
+
+    // Initializes the contexts that were only waiting on their parent, now that it may have been
+    // initialized. Repeats until nothing changes, so grandchildren follow their parents in the
+    // same message. Returns whether any context was initialized.
+    fn initialize_waiting_children(&mut self) -> bool {
+        let mut initialized_any = false;
+        loop {
+            let mut initialized = false;
+            for &(child, parent) in &self.parents {
+                let (child, parent) = match child < parent {
+                    true => {
+                        let (before, after) = self.layers.split_at_mut(parent);
+                        (&mut before[child], &after[0])
+                    }
+                    false => {
+                        let (before, after) = self.layers.split_at_mut(child);
+                        (&mut after[0], &before[parent])
+                    }
+                };
+                initialized |= child.initialize_waiting(parent.as_ref());
+            }
+            if !initialized {
+                return initialized_any;
+            }
+            initialized_any = true;
+        }
+    }
+
+    // Whether a message to this address still waits on some context, and the tightest limit on
+    // how many of its messages may be buffered
+    fn gating(&self, addr: &str) -> (bool, Option<BufferLimit>) {
+        let mut gated = false;
+        let mut limit = None;
+        for layer in self.layers.iter().filter(|layer| layer.is_gating(addr)) {
+            gated = true;
+            limit = tightest_limit(limit, layer.buffer_limit());
+        }
+        (gated, limit)
+    }
+
+    // Dispatches every buffered message no longer waiting on any context, in the order they
+    // arrived
+    fn flush_ready(&mut self) {
+        let ready: Vec<u64> = self
+            .buffer
+            .iter()
+            .filter(|(_, messages)| {
+                messages
+                    .front()
+                    .is_none_or(|(msg, _, _)| !self.gating(&msg.addr).0)
+            })
+            .map(|(hash, _)| *hash)
+            .collect();
+        let mut messages: Vec<(OscMessage, Instant, u64)> = ready
+            .iter()
+            .filter_map(|hash| self.buffer.remove(hash))
+            .flatten()
+            .collect();
+        if messages.is_empty() {
+            return;
+        }
+        messages.sort_by_key(|(_, _, seq)| *seq);
+        trace!(count = messages.len(), "Flushing buffered messages");
+        for (msg, _, _) in messages {
+            (self.dispatcher)(msg);
+        }
+    }
+
+    // Reports a message that no layer recognized to the diagnostics sink if it falls under one of
+    // the strict prefixes. Returns true if the message was rejected.
+    fn reject_unrecognized(&mut self, msg: &OscMessage) -> bool {
+        let Some(strict) = &mut self.strict else {
+            return false;
+        };
+        if !strict
+            .prefixes
+            .iter()
+            .any(|prefix| msg.addr.starts_with(prefix.as_str()))
+        {
+            return false;
+        }
+        let count = strict.rejected_counts.entry(msg.addr.clone()).or_insert(0);
+        *count += 1;
+        (strict.sink)(msg, *count);
+        true
+    }
+
     /// dispatch_osc gates messages until their initialization condition is met and then passes
     /// messages through to self.dispatcher.
     pub fn dispatch_osc(&mut self, packet: OscPacket) {
@@ -332,30 +973,71 @@ impl OscGatedRouter {
             OscPacket::Message(msg) => msg,
             _ => return,
         };
+        let _span = debug_span!("osc_message", addr = %msg.addr).entered();
+        self.purge_if_due();
+        self.flush_acknowledged();
 
         let mut hasher = DefaultHasher::new();
-        let mut gated = false;
+        let mut recognized = false;
+        let mut initialized_any = false;
         self.layers.iter_mut().for_each(|layer| {
             if let Some(res) = layer.initialization_state(msg) {
+                recognized = true;
                 if let Some(hash) = res.1 {
                     hash.hash(&mut hasher)
                 }
                 match res.0 {
-                    InitializationState::Uninitialized => gated = true,
+                    InitializationState::Uninitialized => {}
                     InitializationState::AlreadyInitialized => {}
-                    InitializationState::NewlyInitialized => {}
+                    InitializationState::NewlyInitialized => initialized_any = true,
                 }
             }
         });
+        if !recognized && self.reject_unrecognized(msg) {
+            return;
+        }
+        initialized_any |= self.initialize_waiting_children();
+        // Contexts initialized just now can release messages buffered under other contexts too,
+        // such as a track's sends waiting on the track
+        if initialized_any {
+            self.flush_ready();
+        }
+        let (gated, limit) = self.gating(&msg.addr);
         let hash = hasher.finish();
         if gated {
             // Buffer the message
+            trace!("Buffering until its context is initialized");
             let buffer = self.buffer.entry(hash).or_default();
-            buffer.push_back((msg.to_owned(), Instant::now()));
+            match limit {
+                Some(limit) if buffer.len() >= limit.max_messages => match limit.policy {
+                    OverflowPolicy::DropOldest => {
+                        trace!("Buffer full, dropping its oldest message");
+                        buffer.pop_front();
+                    }
+                    OverflowPolicy::DropNewest => {
+                        trace!("Buffer full, dropping message");
+                        return;
+                    }
+                    OverflowPolicy::FailAndLog => {
+                        warn!(
+                            max_messages = limit.max_messages,
+                            "Buffer full while waiting on key messages, dropping message"
+                        );
+                        return;
+                    }
+                },
+                _ => {}
+            }
+            buffer.push_back((msg.to_owned(), Instant::now(), self.next_seq));
+            self.next_seq += 1;
         } else {
             // First, flush any buffered messages for this hash to preserve ordering
             if let Some(buffered_messages) = self.buffer.get(&hash) {
-                for (buffered_msg, _) in buffered_messages {
+                trace!(
+                    count = buffered_messages.len(),
+                    "Flushing buffered messages"
+                );
+                for (buffered_msg, _, _) in buffered_messages {
                     (self.dispatcher)(buffered_msg.to_owned());
                 }
                 self.buffer.remove(&hash);
@@ -2,8 +2,10 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use super::context_gate::{
-    ContextGateBuilder, ContextKindTrait, ContextTrait, OscGatedRouter, OscGatedRouterBuilder,
+    ContextGateBuilder, ContextKindTrait, ContextTrait, GateStats, InitReceipt, OscGatedRouter,
+    OscGatedRouterBuilder, OverflowPolicy, RouterBuildError,
 };
+use super::key_messages::{KeyMessages, key_arg};
 
 #[cfg(test)]
 mod tests {
@@ -251,6 +253,41 @@ mod tests {
         assert_eq!(router.get_buffered_messages_count(vec![&context]), 0);
     }
 
+    #[test]
+    fn test_purges_automatically_on_dispatch() {
+        use std::thread::sleep;
+
+        let mut router = OscGatedRouterBuilder::new(|_msg| {})
+            .with_buffer_timeout(Duration::from_millis(10))
+            .with_purge_interval(Duration::from_millis(10))
+            .add_layer(Box::new(
+                ContextGateBuilder::<TrackContextKind>::new()
+                    .add_key_route("/track/{track_guid}/index"),
+            ))
+            .build()
+            .unwrap();
+
+        router.dispatch_osc(create_test_message(
+            "/track/stale/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        assert_eq!(router.buffered_context_count(), 1);
+
+        sleep(Duration::from_millis(20));
+
+        // The stale context's buffer goes with the next dispatch, whatever it's for
+        router.dispatch_osc(create_test_message(
+            "/track/fresh/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        let stale = TrackContext {
+            track_guid: "stale".to_string(),
+        };
+        assert_eq!(router.get_buffered_messages_count(vec![&stale]), 0);
+        assert_eq!(router.buffered_context_count(), 1);
+        assert_eq!(router.buffered_message_count(), 1);
+    }
+
     #[test]
     fn test_multiple_key_routes() {
         let (mut router, received) = create_test_router_with_multiple_keys(vec![
@@ -287,6 +324,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_reset_requires_key_messages_again() {
+        let (mut router, received) =
+            create_test_router_with_multiple_keys(vec!["/track/{track_guid}/index"]);
+        let context = TrackContext {
+            track_guid: "reset123".to_string(),
+        };
+
+        router.dispatch_osc(create_test_message(
+            "/track/reset123/index",
+            vec![OscType::Int(0)],
+        ));
+        assert!(router.is_context_initialized(&context));
+
+        // A buffered message is dropped along with the initialization state
+        router.reset_all();
+        assert!(!router.is_context_initialized(&context));
+        router.dispatch_osc(create_test_message(
+            "/track/reset123/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        assert_eq!(received.borrow().len(), 1);
+        router.reset_all();
+
+        router.dispatch_osc(create_test_message(
+            "/track/reset123/index",
+            vec![OscType::Int(1)],
+        ));
+        assert!(router.is_context_initialized(&context));
+        assert_eq!(received.borrow().len(), 2);
+    }
+
+    #[test]
+    fn test_wildcard_key_route() {
+        let (mut router, received) =
+            create_test_router_with_multiple_keys(vec!["/track/{track_guid}/{index,name}"]);
+        let context = TrackContext {
+            track_guid: "wild123".to_string(),
+        };
+
+        router.dispatch_osc(create_test_message(
+            "/track/wild123/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        assert!(!router.is_context_initialized(&context));
+
+        // Either alternative satisfies the key route
+        router.dispatch_osc(create_test_message(
+            "/track/wild123/name",
+            vec![OscType::String("Track 1".to_string())],
+        ));
+        assert!(router.is_context_initialized(&context));
+        assert_eq!(received.borrow().len(), 2);
+    }
+
     fn create_test_router_with_multiple_keys(
         keys: Vec<&str>,
     ) -> (OscGatedRouter, Rc<RefCell<Vec<OscMessage>>>) {
@@ -355,6 +447,249 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn test_buffered_totals_across_contexts() {
+        let (mut router, _received) = create_test_router();
+
+        router.dispatch_osc(create_test_message(
+            "/track/track1/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/track1/pan",
+            vec![OscType::Float(0.0)],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/track2/volume",
+            vec![OscType::Float(0.7)],
+        ));
+        assert_eq!(router.buffered_context_count(), 2);
+        assert_eq!(router.buffered_message_count(), 3);
+
+        // Initializing a context flushes only its own messages
+        router.dispatch_osc(create_test_message(
+            "/track/track1/index",
+            vec![OscType::Int(1)],
+        ));
+        assert_eq!(router.buffered_context_count(), 1);
+        assert_eq!(router.buffered_message_count(), 1);
+    }
+
+    #[test]
+    fn test_reset_context_gates_only_that_context() {
+        let (mut router, received) = create_test_router();
+        let removed = TrackContext {
+            track_guid: "removed".to_string(),
+        };
+        let kept = TrackContext {
+            track_guid: "kept".to_string(),
+        };
+
+        router.dispatch_osc(create_test_message(
+            "/track/removed/index",
+            vec![OscType::Int(0)],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/kept/index",
+            vec![OscType::Int(1)],
+        ));
+        assert_eq!(received.borrow().len(), 2);
+
+        assert!(router.reset_context(&removed));
+        assert!(!router.is_context_initialized(&removed));
+        assert!(router.is_context_initialized(&kept));
+
+        // The reset context is buffered again while the other still passes through
+        router.dispatch_osc(create_test_message(
+            "/track/removed/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/kept/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        assert_eq!(received.borrow().len(), 3);
+        assert_eq!(router.get_buffered_messages_count(vec![&removed]), 1);
+
+        // Until its key message arrives again
+        router.dispatch_osc(create_test_message(
+            "/track/removed/index",
+            vec![OscType::Int(0)],
+        ));
+        assert!(router.is_context_initialized(&removed));
+        assert_eq!(received.borrow().len(), 5);
+        assert_eq!(received.borrow()[3].addr, "/track/removed/volume");
+    }
+
+    #[test]
+    fn test_removed_track_initializes_afresh_when_it_returns() {
+        // Like the bridge, the dispatcher notes tracks reported at index -1, and their contexts
+        // are reset once the message has been dispatched
+        let removed = Rc::new(RefCell::new(Vec::new()));
+        let initializations = Rc::new(RefCell::new(0));
+        let dispatcher = {
+            let removed = removed.clone();
+            Box::new(move |msg: OscMessage| {
+                if msg.addr.ends_with("/index") && msg.args == vec![OscType::Int(-1)] {
+                    let guid = msg.addr.split('/').nth(2).unwrap().to_string();
+                    removed.borrow_mut().push(guid);
+                }
+            })
+        };
+        let mut router = OscGatedRouterBuilder::new(dispatcher)
+            .add_layer(Box::new(
+                ContextGateBuilder::<TrackContextKind>::new()
+                    .add_key_route("/track/{track_guid}/index")
+                    .with_initialization_callback({
+                        let initializations = initializations.clone();
+                        move |_, _| *initializations.borrow_mut() += 1
+                    }),
+            ))
+            .build()
+            .unwrap();
+        let track = TrackContext {
+            track_guid: "deleted".to_string(),
+        };
+
+        router.dispatch_osc(create_test_message(
+            "/track/deleted/index",
+            vec![OscType::Int(2)],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/deleted/index",
+            vec![OscType::Int(-1)],
+        ));
+        for track_guid in removed.borrow_mut().drain(..) {
+            assert!(router.reset_context(&TrackContext { track_guid }));
+        }
+        assert!(!router.is_context_initialized(&track));
+        assert_eq!(*initializations.borrow(), 1);
+
+        // Undoing the delete brings the track back, and it's initialized like a new one
+        router.dispatch_osc(create_test_message(
+            "/track/deleted/index",
+            vec![OscType::Int(2)],
+        ));
+        assert!(router.is_context_initialized(&track));
+        assert_eq!(*initializations.borrow(), 2);
+    }
+
+    #[test]
+    fn test_reset_unknown_context() {
+        let (mut router, _received) = create_test_router();
+
+        assert!(!router.reset_context(&TrackContext {
+            track_guid: "never_seen".to_string(),
+        }));
+        // A context type no layer handles is not known either
+        assert!(!router.reset_context(&SendContext {
+            track_guid: "never_seen".to_string(),
+            send_index: "0".to_string(),
+        }));
+    }
+
+    fn create_bounded_router(
+        max_messages: usize,
+        policy: OverflowPolicy,
+    ) -> (OscGatedRouter, Rc<RefCell<Vec<OscMessage>>>) {
+        let received_messages = Rc::new(RefCell::new(Vec::new()));
+        let received_messages_clone = received_messages.clone();
+
+        let router = OscGatedRouterBuilder::new(move |msg: OscMessage| {
+            received_messages.borrow_mut().push(msg);
+        })
+        .add_layer(Box::new(
+            ContextGateBuilder::<TrackContextKind>::new()
+                .add_key_route("/track/{track_guid}/index")
+                .with_max_buffered(max_messages, policy),
+        ))
+        .build()
+        .unwrap();
+
+        (router, received_messages_clone)
+    }
+
+    #[test]
+    fn test_bounded_buffer_overflow_policies() {
+        struct TestScenario {
+            policy: OverflowPolicy,
+            expected_volumes: Vec<f32>,
+        }
+
+        let scenarios = vec![
+            TestScenario {
+                policy: OverflowPolicy::DropOldest,
+                expected_volumes: vec![0.3, 0.4],
+            },
+            TestScenario {
+                policy: OverflowPolicy::DropNewest,
+                expected_volumes: vec![0.1, 0.2],
+            },
+            TestScenario {
+                policy: OverflowPolicy::FailAndLog,
+                expected_volumes: vec![0.1, 0.2],
+            },
+        ];
+
+        for scenario in scenarios {
+            let (mut router, received) = create_bounded_router(2, scenario.policy);
+            let context = TrackContext {
+                track_guid: "chatty".to_string(),
+            };
+
+            for volume in [0.1, 0.2, 0.3, 0.4] {
+                router.dispatch_osc(create_test_message(
+                    "/track/chatty/volume",
+                    vec![OscType::Float(volume)],
+                ));
+            }
+            assert_eq!(
+                router.get_buffered_messages_count(vec![&context]),
+                2,
+                "{:?} should cap the buffer",
+                scenario.policy
+            );
+
+            router.dispatch_osc(create_test_message(
+                "/track/chatty/index",
+                vec![OscType::Int(0)],
+            ));
+            let volumes: Vec<f32> = received
+                .borrow()
+                .iter()
+                .filter_map(|msg| match msg.args.first() {
+                    Some(OscType::Float(volume)) => Some(*volume),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(
+                volumes, scenario.expected_volumes,
+                "{:?} kept the wrong messages",
+                scenario.policy
+            );
+        }
+    }
+
+    #[test]
+    fn test_bounded_buffer_is_per_context() {
+        let (mut router, _received) = create_bounded_router(1, OverflowPolicy::DropNewest);
+
+        router.dispatch_osc(create_test_message(
+            "/track/track1/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/track2/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/track2/pan",
+            vec![OscType::Float(0.5)],
+        ));
+        assert_eq!(router.buffered_context_count(), 2);
+        assert_eq!(router.buffered_message_count(), 2);
+    }
+
     #[test]
     fn test_multiple_layers() {
         // Create a multi-layer router
@@ -477,6 +812,260 @@ mod tests {
         }
     }
 
+    // The index and name a test track is keyed on, decoded
+    #[derive(Debug, PartialEq)]
+    struct IndexAndName {
+        index: i32,
+        name: String,
+    }
+
+    impl KeyMessages for IndexAndName {
+        const KEY_ROUTES: &'static [&'static str] =
+            &["/track/{track_guid}/index", "/track/{track_guid}/name"];
+
+        fn decode(messages: &HashMap<String, OscMessage>) -> Result<Self, String> {
+            Ok(IndexAndName {
+                index: key_arg(messages, "/track/{track_guid}/index", 0)?,
+                name: key_arg(messages, "/track/{track_guid}/name", 0)?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_typed_key_messages_wait_on_every_route() {
+        let decoded = Rc::new(RefCell::new(Vec::new()));
+        let mut router = OscGatedRouterBuilder::new(|_| {})
+            .add_layer(Box::new(
+                ContextGateBuilder::<TrackContextKind>::new().with_key_messages({
+                    let decoded = decoded.clone();
+                    move |ctx, key: IndexAndName| decoded.borrow_mut().push((ctx, key))
+                }),
+            ))
+            .build()
+            .unwrap();
+
+        router.dispatch_osc(create_test_message(
+            "/track/typed/index",
+            vec![OscType::Float(3.0)],
+        ));
+        assert!(decoded.borrow().is_empty());
+        router.dispatch_osc(create_test_message(
+            "/track/typed/name",
+            vec![OscType::String("Bass".to_string())],
+        ));
+        assert_eq!(
+            *decoded.borrow(),
+            vec![(
+                TrackContext {
+                    track_guid: "typed".to_string()
+                },
+                IndexAndName {
+                    index: 3,
+                    name: "Bass".to_string()
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn test_undecodable_key_messages_skip_the_callback() {
+        let called = Rc::new(RefCell::new(false));
+        let mut router = OscGatedRouterBuilder::new(|_| {})
+            .add_layer(Box::new(
+                ContextGateBuilder::<TrackContextKind>::new().with_key_messages({
+                    let called = called.clone();
+                    move |_, _: IndexAndName| *called.borrow_mut() = true
+                }),
+            ))
+            .build()
+            .unwrap();
+
+        router.dispatch_osc(create_test_message(
+            "/track/bad/index",
+            vec![OscType::String("first".to_string())],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/bad/name",
+            vec![OscType::String("Bass".to_string())],
+        ));
+        let context = TrackContext {
+            track_guid: "bad".to_string(),
+        };
+        assert!(router.is_context_initialized(&context));
+        assert!(!*called.borrow());
+    }
+
+    // A router whose track contexts are initialized by `callback`, recording what it dispatches
+    fn acknowledging_router<F>(
+        callback: F,
+        ack_timeout: Duration,
+    ) -> (OscGatedRouter, Rc<RefCell<Vec<OscMessage>>>)
+    where
+        F: FnMut(TrackContext, &HashMap<String, OscMessage>) -> InitReceipt + 'static,
+    {
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let router = OscGatedRouterBuilder::new({
+            let received = received.clone();
+            move |msg| received.borrow_mut().push(msg)
+        })
+        .add_layer(Box::new(
+            ContextGateBuilder::<TrackContextKind>::new()
+                .add_key_route("/track/{track_guid}/index")
+                .with_acknowledged_initialization(callback)
+                .with_ack_timeout(ack_timeout),
+        ))
+        .build()
+        .unwrap();
+        (router, received)
+    }
+
+    #[test]
+    fn test_buffered_messages_wait_on_the_acknowledgment() {
+        // Held by the test until it acknowledges
+        let pending = Rc::new(RefCell::new(Vec::new()));
+        let (mut router, received) = acknowledging_router(
+            {
+                let pending = pending.clone();
+                move |_, _| {
+                    let (acknowledge, receipt) = InitReceipt::new();
+                    pending.borrow_mut().push(acknowledge);
+                    receipt
+                }
+            },
+            Duration::from_secs(5),
+        );
+
+        router.dispatch_osc(create_test_message(
+            "/track/ack/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/ack/index",
+            vec![OscType::Int(0)],
+        ));
+        // Even the key message waits, without the dispatch waiting along with it
+        assert!(received.borrow().is_empty());
+        assert!(router.awaiting_acknowledgment());
+
+        pending.borrow()[0].send(()).unwrap();
+        router.flush_acknowledged();
+        let addrs: Vec<String> = received.borrow().iter().map(|m| m.addr.clone()).collect();
+        assert_eq!(addrs, vec!["/track/ack/volume", "/track/ack/index"]);
+        assert!(!router.awaiting_acknowledgment());
+    }
+
+    #[test]
+    fn test_unacknowledged_initialization_times_out() {
+        // Kept alive so the receipt is never acknowledged
+        let pending = Rc::new(RefCell::new(Vec::new()));
+        let (mut router, received) = acknowledging_router(
+            {
+                let pending = pending.clone();
+                move |_, _| {
+                    let (acknowledge, receipt) = InitReceipt::new();
+                    pending.borrow_mut().push(acknowledge);
+                    receipt
+                }
+            },
+            Duration::from_millis(20),
+        );
+
+        router.dispatch_osc(create_test_message(
+            "/track/slow/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/slow/index",
+            vec![OscType::Int(0)],
+        ));
+        router.flush_acknowledged();
+        assert!(received.borrow().is_empty());
+
+        std::thread::sleep(Duration::from_millis(30));
+        router.flush_acknowledged();
+        assert_eq!(received.borrow().len(), 2);
+        assert_eq!(pending.borrow().len(), 1);
+        assert!(!router.awaiting_acknowledgment());
+    }
+
+    #[test]
+    fn test_contexts_initializing_together_dont_wait_on_each_other() {
+        let pending = Rc::new(RefCell::new(HashMap::new()));
+        let (mut router, received) = acknowledging_router(
+            {
+                let pending = pending.clone();
+                move |ctx: TrackContext, _| {
+                    let (acknowledge, receipt) = InitReceipt::new();
+                    pending.borrow_mut().insert(ctx.track_guid, acknowledge);
+                    receipt
+                }
+            },
+            Duration::from_secs(60),
+        );
+        let addrs =
+            || -> Vec<String> { received.borrow().iter().map(|m| m.addr.clone()).collect() };
+
+        for guid in ["a", "b", "c"] {
+            router.dispatch_osc(create_test_message(
+                &format!("/track/{}/volume", guid),
+                vec![OscType::Float(0.5)],
+            ));
+            router.dispatch_osc(create_test_message(
+                &format!("/track/{}/index", guid),
+                vec![OscType::Int(0)],
+            ));
+        }
+        assert!(addrs().is_empty());
+        assert_eq!(pending.borrow().len(), 3);
+
+        // Each context's messages go through once its own initialization is acknowledged
+        pending.borrow_mut().remove("b").unwrap().send(()).unwrap();
+        router.flush_acknowledged();
+        assert_eq!(addrs(), vec!["/track/b/volume", "/track/b/index"]);
+
+        // A dropped acknowledgment is noticed by the next dispatch, ahead of what it dispatches
+        pending.borrow_mut().remove("c");
+        router.dispatch_osc(create_test_message(
+            "/track/c/pan",
+            vec![OscType::Float(0.5)],
+        ));
+        assert_eq!(
+            addrs(),
+            vec![
+                "/track/b/volume",
+                "/track/b/index",
+                "/track/c/volume",
+                "/track/c/index",
+                "/track/c/pan",
+            ]
+        );
+        assert!(router.awaiting_acknowledgment());
+
+        pending.borrow_mut().remove("a").unwrap().send(()).unwrap();
+        router.flush_acknowledged();
+        assert_eq!(addrs().len(), 7);
+        assert_eq!(&addrs()[5..], ["/track/a/volume", "/track/a/index"]);
+        assert!(!router.awaiting_acknowledgment());
+    }
+
+    #[test]
+    fn test_dropped_acknowledgment_counts_as_acknowledged() {
+        let (mut router, received) =
+            acknowledging_router(|_, _| InitReceipt::new().1, Duration::from_secs(60));
+
+        router.dispatch_osc(create_test_message(
+            "/track/gone/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        let started = std::time::Instant::now();
+        router.dispatch_osc(create_test_message(
+            "/track/gone/index",
+            vec![OscType::Int(0)],
+        ));
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(received.borrow().len(), 2);
+    }
+
     #[test]
     fn test_key_message_access_in_callback() {
         let key_message_values = Rc::new(RefCell::new(HashMap::new()));
@@ -584,6 +1173,56 @@ mod tests {
         assert_eq!(received.borrow().len(), 1);
     }
 
+    #[test]
+    fn test_strict_mode_rejects_unrecognized_prefixed_messages() {
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+        let rejected = Rc::new(RefCell::new(Vec::new()));
+        let rejected_clone = rejected.clone();
+
+        // Only sends are known, so track-level messages can't be parsed by any layer
+        let mut router = OscGatedRouterBuilder::new(move |msg: OscMessage| {
+            received_clone.borrow_mut().push(msg);
+        })
+        .with_strict_prefixes(vec!["/track/"], move |msg: &OscMessage, count| {
+            rejected_clone.borrow_mut().push((msg.addr.clone(), count));
+        })
+        .add_layer(Box::new(
+            ContextGateBuilder::<SendContextKind>::new()
+                .add_key_route("/track/{track_guid}/send/{send_index}/guid"),
+        ))
+        .build()
+        .unwrap();
+
+        router.dispatch_osc(create_test_message(
+            "/track/12345/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/12345/volume",
+            vec![OscType::Float(0.6)],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/12345/send/0/guid",
+            vec![OscType::String("abc".to_string())],
+        ));
+        // Addresses outside the strict prefixes still pass through ungated
+        router.dispatch_osc(create_test_message("/unrelated/message", vec![]));
+
+        assert_eq!(
+            *rejected.borrow(),
+            vec![
+                ("/track/12345/volume".to_string(), 1),
+                ("/track/12345/volume".to_string(), 2)
+            ]
+        );
+        let received: Vec<String> = received.borrow().iter().map(|m| m.addr.clone()).collect();
+        assert_eq!(
+            received,
+            vec!["/track/12345/send/0/guid", "/unrelated/message"]
+        );
+    }
+
     #[test]
     fn test_bulk_messages() {
         let (mut router, received) = create_test_router();
@@ -707,6 +1346,12 @@ mod tests {
 
     // Hierarchical Context Interaction Tests
 
+    fn send_track(send: &SendContext) -> TrackContext {
+        TrackContext {
+            track_guid: send.track_guid.clone(),
+        }
+    }
+
     #[test]
     fn test_hierarchical_message_gating() {
         // Create a router with both track and send contexts
@@ -724,7 +1369,8 @@ mod tests {
             ))
             .add_layer(Box::new(
                 ContextGateBuilder::<SendContextKind>::new()
-                    .add_key_route("/track/{track_guid}/send/{send_index}/guid"),
+                    .add_key_route("/track/{track_guid}/send/{send_index}/guid")
+                    .requires_parent::<TrackContextKind>(send_track),
             ))
             .build()
             .unwrap();
@@ -766,6 +1412,146 @@ mod tests {
         assert_eq!(received_messages.borrow()[2].addr, "/track/123/send/0/guid");
     }
 
+    #[test]
+    fn test_child_waits_on_parent_initialization() {
+        let initialized = Rc::new(RefCell::new(Vec::new()));
+        let received_messages = Rc::new(RefCell::new(Vec::new()));
+        let received_messages_clone = received_messages.clone();
+
+        let dispatcher = Box::new(move |msg: OscMessage| {
+            received_messages_clone.borrow_mut().push(msg);
+        });
+
+        let mut router = OscGatedRouterBuilder::new(dispatcher)
+            .add_layer(Box::new(
+                ContextGateBuilder::<SendContextKind>::new()
+                    .add_key_route("/track/{track_guid}/send/{send_index}/guid")
+                    .requires_parent::<TrackContextKind>(send_track)
+                    .with_initialization_callback({
+                        let initialized = initialized.clone();
+                        move |ctx: SendContext, _| {
+                            initialized
+                                .borrow_mut()
+                                .push(format!("send {}", ctx.send_index))
+                        }
+                    }),
+            ))
+            .add_layer(Box::new(
+                ContextGateBuilder::<TrackContextKind>::new()
+                    .add_key_route("/track/{track_guid}/index")
+                    .with_initialization_callback({
+                        let initialized = initialized.clone();
+                        move |ctx: TrackContext, _| {
+                            initialized
+                                .borrow_mut()
+                                .push(format!("track {}", ctx.track_guid))
+                        }
+                    }),
+            ))
+            .build()
+            .unwrap();
+
+        // The send's key message arrives before the track's
+        router.dispatch_osc(create_test_message(
+            "/track/123/send/0/guid",
+            vec![OscType::String("send-guid".to_string())],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/123/send/0/volume",
+            vec![OscType::Float(0.75)],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/123/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        assert!(initialized.borrow().is_empty());
+        assert!(received_messages.borrow().is_empty());
+
+        router.dispatch_osc(create_test_message(
+            "/track/123/index",
+            vec![OscType::Int(1)],
+        ));
+
+        // The send is initialized right after its track, and its messages flushed after the
+        // track's, in the order they arrived
+        assert_eq!(*initialized.borrow(), vec!["track 123", "send 0"]);
+        let addrs: Vec<String> = received_messages
+            .borrow()
+            .iter()
+            .map(|msg| msg.addr.clone())
+            .collect();
+        assert_eq!(
+            addrs,
+            vec![
+                "/track/123/send/0/guid",
+                "/track/123/send/0/volume",
+                "/track/123/volume",
+                "/track/123/index",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_child_of_other_parent_keeps_waiting() {
+        let received_messages = Rc::new(RefCell::new(Vec::new()));
+        let received_messages_clone = received_messages.clone();
+
+        let dispatcher = Box::new(move |msg: OscMessage| {
+            received_messages_clone.borrow_mut().push(msg);
+        });
+
+        let mut router = OscGatedRouterBuilder::new(dispatcher)
+            .add_layer(Box::new(
+                ContextGateBuilder::<TrackContextKind>::new()
+                    .add_key_route("/track/{track_guid}/index"),
+            ))
+            .add_layer(Box::new(
+                ContextGateBuilder::<SendContextKind>::new()
+                    .add_key_route("/track/{track_guid}/send/{send_index}/guid")
+                    .requires_parent::<TrackContextKind>(send_track),
+            ))
+            .build()
+            .unwrap();
+
+        router.dispatch_osc(create_test_message(
+            "/track/b/send/0/guid",
+            vec![OscType::String("send-guid".to_string())],
+        ));
+        router.dispatch_osc(create_test_message("/track/a/index", vec![OscType::Int(1)]));
+        assert_eq!(received_messages.borrow().len(), 1);
+        assert_eq!(received_messages.borrow()[0].addr, "/track/a/index");
+
+        router.dispatch_osc(create_test_message("/track/b/index", vec![OscType::Int(2)]));
+        assert_eq!(received_messages.borrow().len(), 3);
+        assert_eq!(received_messages.borrow()[1].addr, "/track/b/send/0/guid");
+        assert_eq!(received_messages.borrow()[2].addr, "/track/b/index");
+    }
+
+    #[test]
+    fn test_missing_parent_layer_fails_to_build() {
+        let result = OscGatedRouterBuilder::new(Box::new(|_: OscMessage| {}))
+            .add_layer(Box::new(
+                ContextGateBuilder::<SendContextKind>::new()
+                    .add_key_route("/track/{track_guid}/send/{send_index}/guid")
+                    .requires_parent::<TrackContextKind>(send_track),
+            ))
+            .build();
+        let Err(e) = result else {
+            panic!("the router shouldn't build without its Track layer");
+        };
+        assert!(matches!(
+            e,
+            RouterBuildError::MissingParentLayer {
+                child: "Send",
+                parent: "Track"
+            }
+        ));
+        assert_eq!(
+            e.to_string(),
+            "the Send layer needs a Track layer for its parents, but there isn't one"
+        );
+    }
+
     #[test]
     fn test_no_duplicate_dispatch() {
         // Create a special context type that could match the same message in multiple layers
@@ -894,7 +1680,8 @@ mod tests {
             ))
             .add_layer(Box::new(
                 ContextGateBuilder::<SendContextKind>::new()
-                    .add_key_route("/track/{track_guid}/send/{send_index}/guid"),
+                    .add_key_route("/track/{track_guid}/send/{send_index}/guid")
+                    .requires_parent::<TrackContextKind>(send_track),
             ))
             .build()
             .unwrap();
@@ -1366,4 +2153,36 @@ mod tests {
         // Initialization callback should still only be called once
         assert_eq!(*callback_count_clone.borrow(), 1);
     }
+
+    #[test]
+    fn test_stats_show_what_the_gates_are_doing() {
+        let (mut router, _received) = create_test_router();
+        assert_eq!(router.stats(), GateStats::default());
+
+        router.dispatch_osc(create_test_message(
+            "/track/a/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        router.dispatch_osc(create_test_message(
+            "/track/b/volume",
+            vec![OscType::Float(0.5)],
+        ));
+        std::thread::sleep(Duration::from_millis(5));
+        let stats = router.stats();
+        assert_eq!(
+            (stats.contexts, stats.initialized, stats.buffered),
+            (0, 0, 2)
+        );
+        assert!(stats.oldest_buffer_age.unwrap() >= Duration::from_millis(5));
+
+        router.dispatch_osc(create_test_message("/track/a/index", vec![OscType::Int(0)]));
+        let stats = router.stats();
+        assert_eq!(
+            (stats.contexts, stats.initialized, stats.buffered),
+            (1, 1, 1)
+        );
+
+        router.reset_all();
+        assert_eq!(router.stats(), GateStats::default());
+    }
 }
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use rosc::OscMessage;
+
+use crate::osc::decode::{FromOscArg, required};
+use crate::osc::generated_osc::{
+    MarkerName, RegionName, TrackFxGuid, TrackFxParamName, TrackIndex, TrackSendGuid,
+};
+
+/// The key messages a context is initialized from, decoded into the values they carry.
+///
+/// A gate built with `ContextGateBuilder::with_key_messages` waits on each of the key routes and
+/// hands its initialization callback the decoded set, rather than the raw messages.
+pub trait KeyMessages: Sized {
+    /// The routes whose messages make up the set, e.g. "/track/{track_guid}/index"
+    const KEY_ROUTES: &'static [&'static str];
+
+    /// Decodes the set from its key messages, keyed by the route each arrived on.
+    fn decode(messages: &HashMap<String, OscMessage>) -> Result<Self, String>;
+}
+
+/// Decodes the argument at `idx` of the message that arrived on `route`.
+pub fn key_arg<T: FromOscArg>(
+    messages: &HashMap<String, OscMessage>,
+    route: &str,
+    idx: usize,
+) -> Result<T, String> {
+    let msg = messages
+        .get(route)
+        .ok_or_else(|| format!("no key message on {}", route))?;
+    required(&msg.args, idx).map_err(|e| format!("{}: {}", msg.addr, e))
+}
+
+/// A track is known once Reaper has told us where it sits in the mixer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackKeyMessages {
+    pub index: i32,
+}
+
+impl KeyMessages for TrackKeyMessages {
+    const KEY_ROUTES: &'static [&'static str] = &[TrackIndex::ADDRESS_TEMPLATE];
+
+    fn decode(messages: &HashMap<String, OscMessage>) -> Result<Self, String> {
+        Ok(TrackKeyMessages {
+            index: key_arg(messages, TrackIndex::ADDRESS_TEMPLATE, 0)?,
+        })
+    }
+}
+
+/// A send is known once Reaper has told us which track it sends to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackSendKeyMessages {
+    pub guid: String,
+}
+
+impl KeyMessages for TrackSendKeyMessages {
+    const KEY_ROUTES: &'static [&'static str] = &[TrackSendGuid::ADDRESS_TEMPLATE];
+
+    fn decode(messages: &HashMap<String, OscMessage>) -> Result<Self, String> {
+        Ok(TrackSendKeyMessages {
+            guid: key_arg(messages, TrackSendGuid::ADDRESS_TEMPLATE, 0)?,
+        })
+    }
+}
+
+/// An FX is known once Reaper has told us its GUID.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackFxKeyMessages {
+    pub guid: String,
+}
+
+impl KeyMessages for TrackFxKeyMessages {
+    const KEY_ROUTES: &'static [&'static str] = &[TrackFxGuid::ADDRESS_TEMPLATE];
+
+    fn decode(messages: &HashMap<String, OscMessage>) -> Result<Self, String> {
+        Ok(TrackFxKeyMessages {
+            guid: key_arg(messages, TrackFxGuid::ADDRESS_TEMPLATE, 0)?,
+        })
+    }
+}
+
+/// An FX parameter is known once Reaper has told us its name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackFxParamKeyMessages {
+    pub name: String,
+}
+
+impl KeyMessages for TrackFxParamKeyMessages {
+    const KEY_ROUTES: &'static [&'static str] = &[TrackFxParamName::ADDRESS_TEMPLATE];
+
+    fn decode(messages: &HashMap<String, OscMessage>) -> Result<Self, String> {
+        Ok(TrackFxParamKeyMessages {
+            name: key_arg(messages, TrackFxParamName::ADDRESS_TEMPLATE, 0)?,
+        })
+    }
+}
+
+/// A marker is known once Reaper has told us its name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkerKeyMessages {
+    pub name: String,
+}
+
+impl KeyMessages for MarkerKeyMessages {
+    const KEY_ROUTES: &'static [&'static str] = &[MarkerName::ADDRESS_TEMPLATE];
+
+    fn decode(messages: &HashMap<String, OscMessage>) -> Result<Self, String> {
+        Ok(MarkerKeyMessages {
+            name: key_arg(messages, MarkerName::ADDRESS_TEMPLATE, 0)?,
+        })
+    }
+}
+
+/// A region is known once Reaper has told us its name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegionKeyMessages {
+    pub name: String,
+}
+
+impl KeyMessages for RegionKeyMessages {
+    const KEY_ROUTES: &'static [&'static str] = &[RegionName::ADDRESS_TEMPLATE];
+
+    fn decode(messages: &HashMap<String, OscMessage>) -> Result<Self, String> {
+        Ok(RegionKeyMessages {
+            name: key_arg(messages, RegionName::ADDRESS_TEMPLATE, 0)?,
+        })
+    }
+}
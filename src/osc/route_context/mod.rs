@@ -1,6 +1,9 @@
 pub mod context_gate;
+pub mod key_messages;
 
-pub use context_gate::{ContextGateBuilder, ContextKindTrait, ContextTrait, OscGatedRouterBuilder};
+pub use context_gate::{
+    ContextGateBuilder, ContextKindTrait, ContextTrait, InitReceipt, OscGatedRouterBuilder,
+};
 
 #[cfg(test)]
 mod context_gate_tests;
@@ -0,0 +1,65 @@
+use std::path::Path;
+
+use reaper_oscgen::SpecLoadError;
+
+use crate::config::DEFAULT_SPEC;
+use crate::osc::generated_osc::all_routes;
+use crate::osc::pattern::match_address;
+
+/// The routes the bridge serves, read from an OSC spec at runtime.
+///
+/// The generated client can only handle the routes it was built from, so a spec given in place of
+/// the built-in one narrows what's dispatched to those it lists. Routes it adds that the client
+/// doesn't have can't be served until the client is generated from it with `gen-osc`.
+#[derive(Debug, Clone)]
+pub struct RouteSpec {
+    addresses: Vec<String>,
+}
+
+impl RouteSpec {
+    /// The spec built into the binary, which the generated client was built from.
+    pub fn embedded() -> Self {
+        let yaml = std::str::from_utf8(DEFAULT_SPEC).expect("the built-in spec isn't UTF-8");
+        Self::parse(yaml).expect("the built-in spec is invalid")
+    }
+
+    /// Reads the spec from YAML, in the format `gen-osc` takes.
+    pub fn parse(yaml: &str) -> Result<Self, SpecLoadError> {
+        Ok(Self::from_addresses(
+            reaper_oscgen::parse_spec(yaml)?
+                .iter()
+                .map(|route| route.osc_address().to_string()),
+        ))
+    }
+
+    /// Like parse, but for the spec in the file at `path`.
+    pub fn load(path: &Path) -> Result<Self, SpecLoadError> {
+        Ok(Self::from_addresses(
+            reaper_oscgen::load_spec(path)?
+                .iter()
+                .map(|route| route.osc_address().to_string()),
+        ))
+    }
+
+    fn from_addresses(addresses: impl Iterator<Item = String>) -> Self {
+        RouteSpec {
+            addresses: addresses.collect(),
+        }
+    }
+
+    /// Whether a message to `addr` matches any of the spec's routes.
+    pub fn allows(&self, addr: &str) -> bool {
+        self.addresses
+            .iter()
+            .any(|route| match_address(route, addr).is_some())
+    }
+
+    /// The addresses of the spec's routes that the generated client has no route for.
+    pub fn unsupported(&self) -> Vec<&str> {
+        self.addresses
+            .iter()
+            .filter(|address| !all_routes().iter().any(|route| route.address == *address))
+            .map(String::as_str)
+            .collect()
+    }
+}
@@ -0,0 +1,150 @@
+use std::io;
+use std::net::{SocketAddr, UdpSocket};
+use std::sync::{Arc, Mutex};
+
+use rosc::{OscMessage, OscType};
+use tracing::{info, warn};
+
+use crate::osc::transport::OscTransport;
+
+/// Address a controller sends to start receiving a copy of our feedback.
+pub const SUBSCRIBE_ADDR: &str = "/subscribe";
+/// Address a controller sends to stop receiving our feedback.
+pub const UNSUBSCRIBE_ADDR: &str = "/unsubscribe";
+
+/// The controllers that get a copy of every message we send to Reaper.
+///
+/// Subscribers are registered up front (e.g. from the command line) or by sending us a
+/// `/subscribe` message. With no arguments the sender's own address is subscribed; an int
+/// argument subscribes that port on the sender's host, and a string argument subscribes the
+/// "host:port" it names. `/unsubscribe` takes the same arguments.
+#[derive(Debug, Default)]
+pub struct SubscriberRegistry {
+    subscribers: Mutex<Vec<SocketAddr>>,
+}
+
+impl SubscriberRegistry {
+    pub fn new(subscribers: impl IntoIterator<Item = SocketAddr>) -> Self {
+        let registry = SubscriberRegistry::default();
+        for addr in subscribers {
+            registry.subscribe(addr);
+        }
+        registry
+    }
+
+    /// Adds a subscriber, returning false if it was already subscribed.
+    pub fn subscribe(&self, addr: SocketAddr) -> bool {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        if subscribers.contains(&addr) {
+            return false;
+        }
+        subscribers.push(addr);
+        true
+    }
+
+    /// Removes a subscriber, returning false if it wasn't subscribed.
+    pub fn unsubscribe(&self, addr: SocketAddr) -> bool {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        let before = subscribers.len();
+        subscribers.retain(|subscriber| *subscriber != addr);
+        subscribers.len() != before
+    }
+
+    /// The current subscribers, in the order they subscribed.
+    pub fn subscribers(&self) -> Vec<SocketAddr> {
+        self.subscribers.lock().unwrap().clone()
+    }
+
+    /// Handles `/subscribe` and `/unsubscribe` messages from `sender`.
+    ///
+    /// Returns true if the message was one of those, in which case it shouldn't be passed on to
+    /// the rest of the bridge. A request naming an address we can't parse is dropped.
+    pub fn handle_message(&self, msg: &OscMessage, sender: SocketAddr) -> bool {
+        let subscribing = match msg.addr.as_str() {
+            SUBSCRIBE_ADDR => true,
+            UNSUBSCRIBE_ADDR => false,
+            _ => return false,
+        };
+        let Some(addr) = requested_addr(msg, sender) else {
+            warn!("Ignoring {} with arguments {:?}", msg.addr, msg.args);
+            return true;
+        };
+        match subscribing {
+            true => {
+                if self.subscribe(addr) {
+                    info!("Subscribed {} to OSC feedback", addr);
+                }
+            }
+            false => {
+                if self.unsubscribe(addr) {
+                    info!("Unsubscribed {} from OSC feedback", addr);
+                }
+            }
+        }
+        true
+    }
+}
+
+// The address a (un)subscribe message refers to
+fn requested_addr(msg: &OscMessage, sender: SocketAddr) -> Option<SocketAddr> {
+    match msg.args.first() {
+        None => Some(sender),
+        Some(OscType::Int(port)) => {
+            let port = u16::try_from(*port).ok()?;
+            Some(SocketAddr::new(sender.ip(), port))
+        }
+        Some(OscType::String(addr)) => addr.parse().ok(),
+        Some(_) => None,
+    }
+}
+
+/// Sends every packet to Reaper over `inner`, and a copy of it over UDP to every subscriber.
+///
+/// Receiving is left to `inner`; subscribers' own messages arrive the same way as Reaper's.
+pub struct FanOutTransport {
+    inner: Arc<dyn OscTransport>,
+    socket: UdpSocket,
+    subscribers: Arc<SubscriberRegistry>,
+}
+
+impl FanOutTransport {
+    pub fn new(
+        inner: Arc<dyn OscTransport>,
+        socket: UdpSocket,
+        subscribers: Arc<SubscriberRegistry>,
+    ) -> Self {
+        FanOutTransport {
+            inner,
+            socket,
+            subscribers,
+        }
+    }
+
+    // Copies a packet that's gone to Reaper with `result` out to the subscribers
+    fn fan_out(&self, packet: &[u8], result: io::Result<()>) -> io::Result<()> {
+        let mut result = result;
+        for subscriber in self.subscribers.subscribers() {
+            let sent = self.socket.send_to(packet, subscriber).map(|_| ());
+            if result.is_ok() {
+                result = sent;
+            }
+        }
+        result
+    }
+}
+
+impl OscTransport for FanOutTransport {
+    /// A subscriber that can't be reached doesn't stop the others getting the packet; the first
+    /// error is returned once everyone has been tried.
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        self.fan_out(packet, self.inner.send(packet))
+    }
+
+    fn send_to(&self, packet: &[u8], to: SocketAddr) -> io::Result<()> {
+        self.fan_out(packet, self.inner.send_to(packet, to))
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        self.inner.recv()
+    }
+}
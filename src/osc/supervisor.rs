@@ -0,0 +1,116 @@
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// A change in whether Reaper is talking to us.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionEvent {
+    /// Nothing has arrived from Reaper for longer than the silence timeout
+    Lost,
+    /// Reaper was lost and has started sending again, e.g. after a restart
+    Restored,
+}
+
+/// Watches the traffic from Reaper to tell when it has gone away and when it comes back.
+///
+/// Reaper only talks when something changes, so a heartbeat query is sent periodically to keep a
+/// live connection from ever looking silent. Once Reaper's address is known, only packets from it
+/// count as hearing from Reaper; other OSC senders say nothing about whether it's still there.
+pub struct ConnectionSupervisor {
+    silence_timeout: Duration,
+    reaper: Option<SocketAddr>,
+    state: Mutex<SupervisorState>,
+}
+
+struct SupervisorState {
+    last_heard: Instant,
+    connected: bool,
+}
+
+impl ConnectionSupervisor {
+    /// Starts out assuming Reaper is connected, as if it had just been heard from.
+    pub fn new(silence_timeout: Duration) -> Self {
+        ConnectionSupervisor {
+            silence_timeout,
+            reaper: None,
+            state: Mutex::new(SupervisorState {
+                last_heard: Instant::now(),
+                connected: true,
+            }),
+        }
+    }
+
+    /// Only counts packets sent from `reaper`, e.g. when the socket listens to other controllers
+    /// too.
+    pub fn with_reaper_address(mut self, reaper: SocketAddr) -> Self {
+        self.reaper = Some(reaper);
+        self
+    }
+
+    /// Records a packet that arrived from `from`, returning Restored if it was Reaper's and Reaper
+    /// had been lost. Packets from anyone but Reaper are ignored.
+    pub fn heard_from(&self, from: SocketAddr, now: Instant) -> Option<ConnectionEvent> {
+        match self.reaper {
+            Some(reaper) if reaper != from => None,
+            _ => self.heard(now),
+        }
+    }
+
+    /// Records a packet from Reaper, returning Restored if it had been lost.
+    pub fn heard(&self, now: Instant) -> Option<ConnectionEvent> {
+        let mut state = self.state.lock().unwrap();
+        state.last_heard = now;
+        match state.connected {
+            true => None,
+            false => {
+                state.connected = true;
+                Some(ConnectionEvent::Restored)
+            }
+        }
+    }
+
+    /// Returns Lost the first time Reaper has been silent for longer than the timeout.
+    pub fn check(&self, now: Instant) -> Option<ConnectionEvent> {
+        let mut state = self.state.lock().unwrap();
+        let silent = now.saturating_duration_since(state.last_heard) > self.silence_timeout;
+        match (state.connected, silent) {
+            (true, true) => {
+                state.connected = false;
+                Some(ConnectionEvent::Lost)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether Reaper has been heard from since it was last reported lost.
+    pub fn is_connected(&self) -> bool {
+        self.state.lock().unwrap().connected
+    }
+
+    /// Spawns a thread that sends a heartbeat every `interval` and reports when Reaper is lost.
+    ///
+    /// Reaper coming back is only noticed when a packet arrives, so Restored is reported by
+    /// `heard` on the receiving thread instead.
+    pub fn start_heartbeat<H, L>(
+        self: &Arc<Self>,
+        interval: Duration,
+        mut heartbeat: H,
+        mut on_lost: L,
+    ) -> JoinHandle<()>
+    where
+        H: FnMut() + Send + 'static,
+        L: FnMut() + Send + 'static,
+    {
+        let supervisor = self.clone();
+        thread::spawn(move || {
+            loop {
+                heartbeat();
+                thread::sleep(interval);
+                if supervisor.check(Instant::now()) == Some(ConnectionEvent::Lost) {
+                    on_lost();
+                }
+            }
+        })
+    }
+}
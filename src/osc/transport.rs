@@ -0,0 +1,220 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpStream, UdpSocket};
+use std::sync::{Arc, Mutex};
+
+use rosc::OscPacket;
+use tracing::info;
+
+use crate::osc::decode::decode_packet;
+
+/// Moves encoded OSC packets between us and Reaper.
+///
+/// Implementations deal only in whole packets: `send` takes one encoded packet and `recv` blocks
+/// until one complete packet has arrived, returning it along with the address of the sender.
+pub trait OscTransport: Send + Sync {
+    fn send(&self, packet: &[u8]) -> io::Result<()>;
+
+    /// Sends one encoded packet to `to`. A transport with only the one peer, like a stream, has
+    /// nowhere else to send it, so by default this is the same as `send`.
+    fn send_to(&self, packet: &[u8], to: SocketAddr) -> io::Result<()> {
+        let _ = to;
+        self.send(packet)
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)>;
+}
+
+/// OSC over UDP, one packet per datagram.
+pub struct UdpTransport {
+    socket: UdpSocket,
+    peer: Option<SocketAddr>,
+}
+
+impl UdpTransport {
+    /// Sends to whatever `socket` is connected to.
+    pub fn new(socket: UdpSocket) -> Self {
+        UdpTransport { socket, peer: None }
+    }
+
+    /// Sends to `peer`, while still receiving from anyone. Reaper and the controllers that
+    /// subscribe all talk to the same socket, so it can't be connected to Reaper alone.
+    pub fn with_peer(socket: UdpSocket, peer: SocketAddr) -> Self {
+        UdpTransport {
+            socket,
+            peer: Some(peer),
+        }
+    }
+}
+
+impl OscTransport for UdpTransport {
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        match self.peer {
+            Some(peer) => self.socket.send_to(packet, peer),
+            None => self.socket.send(packet),
+        }
+        .map(|_| ())
+    }
+
+    fn send_to(&self, packet: &[u8], to: SocketAddr) -> io::Result<()> {
+        self.socket.send_to(packet, to).map(|_| ())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        let mut buf = [0u8; rosc::decoder::MTU];
+        let (size, addr) = self.socket.recv_from(&mut buf)?;
+        Ok((buf[..size].to_vec(), addr))
+    }
+}
+
+/// Goes nowhere: sends are dropped and nothing is ever received. For running the bridge without
+/// Reaper, e.g. while replaying a capture.
+pub struct NullTransport;
+
+impl OscTransport for NullTransport {
+    fn send(&self, _packet: &[u8]) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        Err(io::Error::new(
+            io::ErrorKind::NotConnected,
+            "nothing is ever received without a connection",
+        ))
+    }
+}
+
+/// Hears from Reaper over `inner` as usual, but logs everything that would be sent instead of
+/// sending it. For trying out a spec and config against Reaper without anything changing in it.
+pub struct DryRunTransport {
+    inner: Arc<dyn OscTransport>,
+}
+
+impl DryRunTransport {
+    pub fn new(inner: Arc<dyn OscTransport>) -> Self {
+        DryRunTransport { inner }
+    }
+}
+
+impl OscTransport for DryRunTransport {
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        log_dry_run(packet, None);
+        Ok(())
+    }
+
+    fn send_to(&self, packet: &[u8], to: SocketAddr) -> io::Result<()> {
+        log_dry_run(packet, Some(to));
+        Ok(())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        self.inner.recv()
+    }
+}
+
+// Logs each message in a packet on its own, bundles included
+fn log_dry_run(packet: &[u8], to: Option<SocketAddr>) {
+    fn log_packet(packet: OscPacket, to: Option<SocketAddr>) {
+        match packet {
+            OscPacket::Message(msg) => {
+                info!(to = ?to, addr = %msg.addr, args = ?msg.args, "Would send OSC")
+            }
+            OscPacket::Bundle(bundle) => {
+                for packet in bundle.content {
+                    log_packet(packet, to);
+                }
+            }
+        }
+    }
+    match decode_packet(packet) {
+        Ok(packet) => log_packet(packet, to),
+        Err(e) => info!(to = ?to, len = packet.len(), error = ?e, "Would send undecodable OSC"),
+    }
+}
+
+/// OSC 1.1 stream framing: each packet is SLIP-encoded and delimited by END bytes.
+pub struct TcpSlipTransport {
+    writer: Mutex<TcpStream>,
+    reader: Mutex<BufReader<TcpStream>>,
+}
+
+impl TcpSlipTransport {
+    pub fn new(stream: TcpStream) -> io::Result<Self> {
+        let reader = BufReader::new(stream.try_clone()?);
+        Ok(TcpSlipTransport {
+            writer: Mutex::new(stream),
+            reader: Mutex::new(reader),
+        })
+    }
+}
+
+impl OscTransport for TcpSlipTransport {
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        self.writer.lock().unwrap().write_all(&slip::encode(packet))
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        let mut reader = self.reader.lock().unwrap();
+        loop {
+            let mut frame = Vec::new();
+            reader.read_until(slip::END, &mut frame)?;
+            if frame.pop() != Some(slip::END) {
+                // Either the stream closed cleanly or it closed partway through a packet
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            // Senders may put an END before each packet as well as after, which shows up here as
+            // an empty frame
+            if frame.is_empty() {
+                continue;
+            }
+            let packet = slip::decode(&frame)?;
+            return Ok((packet, reader.get_ref().peer_addr()?));
+        }
+    }
+}
+
+/// SLIP framing as described in RFC 1055.
+pub mod slip {
+    use std::io;
+
+    pub const END: u8 = 0xC0;
+    pub const ESC: u8 = 0xDB;
+    pub const ESC_END: u8 = 0xDC;
+    pub const ESC_ESC: u8 = 0xDD;
+
+    /// Encodes one packet as a frame, with an END byte on both sides.
+    pub fn encode(packet: &[u8]) -> Vec<u8> {
+        let mut frame = Vec::with_capacity(packet.len() + 2);
+        frame.push(END);
+        for &byte in packet {
+            match byte {
+                END => frame.extend_from_slice(&[ESC, ESC_END]),
+                ESC => frame.extend_from_slice(&[ESC, ESC_ESC]),
+                _ => frame.push(byte),
+            }
+        }
+        frame.push(END);
+        frame
+    }
+
+    /// Decodes the contents of one frame, not including its END delimiters.
+    pub fn decode(frame: &[u8]) -> io::Result<Vec<u8>> {
+        let mut packet = Vec::with_capacity(frame.len());
+        let mut bytes = frame.iter();
+        while let Some(&byte) = bytes.next() {
+            match byte {
+                ESC => match bytes.next() {
+                    Some(&ESC_END) => packet.push(END),
+                    Some(&ESC_ESC) => packet.push(ESC),
+                    other => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("invalid SLIP escape sequence: {:?}", other),
+                        ));
+                    }
+                },
+                _ => packet.push(byte),
+            }
+        }
+        Ok(packet)
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::BTreeMap;
+use std::thread;
+
+use crossbeam_channel::{Receiver, SendError, Sender};
+use tracing::{debug, info};
+
+use crate::guid::ProjectGuid;
+
+/// Set of messages about the project as a whole rather than any one track.
+///
+/// Reaper reports everything but [`ProjectMsg::CurrentMarker`], which only ProjectStateManager
+/// sends.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProjectMsg {
+    /// Beats per minute at the play cursor
+    Tempo(f32),
+    MarkerName {
+        idx: i32,
+        name: String,
+    },
+    /// Where a marker sits, in seconds
+    MarkerPosition {
+        idx: i32,
+        position: f32,
+    },
+    RegionName {
+        idx: i32,
+        name: String,
+    },
+    /// Where a region starts, in seconds
+    RegionStart {
+        idx: i32,
+        start: f32,
+    },
+    /// Where a region ends, in seconds
+    RegionEnd {
+        idx: i32,
+        end: f32,
+    },
+    /// Where the play cursor is, in seconds
+    Position(f32),
+    /// Whether the transport is recording
+    Recording(bool),
+    /// The marker the play cursor last passed, or None before the first one
+    CurrentMarker(Option<Marker>),
+}
+
+/// A marker as far as Reaper has told us about it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Marker {
+    pub name: String,
+    /// In seconds; None until Reaper reports it
+    pub position: Option<f32>,
+}
+
+/// A region as far as Reaper has told us about it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Region {
+    pub name: String,
+    /// In seconds; None until Reaper reports it
+    pub start: Option<f32>,
+    /// In seconds; None until Reaper reports it
+    pub end: Option<f32>,
+}
+
+/// Everything we know about the project outside its tracks, built up from what Reaper reports.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProjectState {
+    pub tempo: Option<f32>,
+    pub markers: BTreeMap<i32, Marker>,
+    pub regions: BTreeMap<i32, Region>,
+    /// Where the play cursor is, in seconds
+    pub position: f32,
+    pub recording: bool,
+}
+
+impl ProjectState {
+    pub fn new() -> Self {
+        ProjectState::default()
+    }
+
+    /// Takes in a message from Reaper. [`ProjectMsg::CurrentMarker`] is worked out from the rest,
+    /// so it's ignored.
+    pub fn apply(&mut self, msg: &ProjectMsg) {
+        match msg {
+            ProjectMsg::Tempo(bpm) => self.tempo = Some(*bpm),
+            ProjectMsg::MarkerName { idx, name } => {
+                self.markers.entry(*idx).or_default().name = name.clone()
+            }
+            ProjectMsg::MarkerPosition { idx, position } => {
+                self.markers.entry(*idx).or_default().position = Some(*position)
+            }
+            ProjectMsg::RegionName { idx, name } => {
+                self.regions.entry(*idx).or_default().name = name.clone()
+            }
+            ProjectMsg::RegionStart { idx, start } => {
+                self.regions.entry(*idx).or_default().start = Some(*start)
+            }
+            ProjectMsg::RegionEnd { idx, end } => {
+                self.regions.entry(*idx).or_default().end = Some(*end)
+            }
+            ProjectMsg::Position(position) => self.position = *position,
+            ProjectMsg::Recording(recording) => self.recording = *recording,
+            ProjectMsg::CurrentMarker(_) => {}
+        }
+    }
+
+    /// The last marker at or before the play cursor. Markers whose position isn't known yet are
+    /// left out.
+    pub fn current_marker(&self) -> Option<&Marker> {
+        self.markers
+            .values()
+            .filter(|marker| marker.position.is_some_and(|at| at <= self.position))
+            // The first of several markers in the same place wins
+            .fold(None, |current: Option<&Marker>, marker| match current {
+                Some(current) if current.position >= marker.position => Some(current),
+                _ => Some(marker),
+            })
+    }
+
+    /// The region the play cursor is inside, picking the one that starts first where regions
+    /// overlap.
+    pub fn current_region(&self) -> Option<&Region> {
+        self.regions
+            .values()
+            .filter(|region| match (region.start, region.end) {
+                (Some(start), Some(end)) => (start..end).contains(&self.position),
+                _ => false,
+            })
+            .min_by(|a, b| a.start.partial_cmp(&b.start).unwrap())
+    }
+}
+
+/// Keeps track of the project's tempo, markers and regions, and of which marker the play cursor
+/// is at, so the modes can show them.
+///
+/// Sits alongside TrackManager between Reaper and the ModeManager. Everything Reaper reports is
+/// passed on downstream except the play cursor, which moves far too often to be worth it; the
+/// modes hear about it through [`ProjectMsg::CurrentMarker`] whenever the cursor moves onto a
+/// different marker instead.
+pub struct ProjectStateManager {
+    state: ProjectState,
+    // What we last told the modes the current marker was
+    current_marker: Option<Marker>,
+    downstream: Sender<ProjectMsg>,
+}
+
+impl ProjectStateManager {
+    /// Runs until Reaper or the modes go away.
+    pub fn start(input: Receiver<ProjectMsg>, downstream: Sender<ProjectMsg>) {
+        thread::spawn(move || {
+            let mut manager = ProjectStateManager {
+                state: ProjectState::new(),
+                current_marker: None,
+                downstream,
+            };
+            for msg in input {
+                if manager.handle_message(msg).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    fn handle_message(&mut self, msg: ProjectMsg) -> Result<(), SendError<ProjectMsg>> {
+        self.state.apply(&msg);
+        match msg {
+            ProjectMsg::Position(_) | ProjectMsg::CurrentMarker(_) => {}
+            msg => self.downstream.send(msg)?,
+        }
+        // Moving or renaming a marker can change which one we're at as much as the cursor can
+        let current_marker = self.state.current_marker().cloned();
+        if current_marker != self.current_marker {
+            debug!("Current marker is now {:?}", current_marker);
+            self.current_marker = current_marker.clone();
+            self.downstream
+                .send(ProjectMsg::CurrentMarker(current_marker))?;
+        }
+        Ok(())
+    }
+}
+
+/// Follows which of Reaper's project tabs is active, to tell when it switches to another.
+///
+/// Every GUID in the project changes along with the tab, so whatever was learned about the tracks
+/// of the old one has to be thrown away and learned again for the new one.
+#[derive(Debug, Default)]
+pub struct ProjectTabs {
+    active: Option<ProjectGuid>,
+}
+
+impl ProjectTabs {
+    pub fn new() -> Self {
+        ProjectTabs::default()
+    }
+
+    /// Takes in the project Reaper reports as active, returning whether it switched from another
+    /// one. The first project reported isn't a switch, as nothing is known about any other yet.
+    pub fn report(&mut self, guid: ProjectGuid) -> bool {
+        let switched = self.active.as_ref().is_some_and(|active| *active != guid);
+        if switched {
+            info!("Reaper switched to project {}", guid);
+        }
+        self.active = Some(guid);
+        switched
+    }
+
+    /// The project in the active tab, once Reaper has reported it.
+    pub fn active(&self) -> Option<&ProjectGuid> {
+        self.active.as_ref()
+    }
+}
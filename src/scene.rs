@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::guid::TrackGuid;
+use crate::track::track::{DataPayload, MASTER_GUID, SendLevel, SendMute, SendPan};
+
+/// The mix at the moment it was captured: the volume, pan, mute, solo and sends of every track.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Scene {
+    /// By GUID
+    pub tracks: BTreeMap<TrackGuid, TrackScene>,
+}
+
+/// The mix of one track
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TrackScene {
+    pub volume: f32,
+    pub pan: f32,
+    pub muted: bool,
+    pub soloed: bool,
+    pub sends: Vec<SendScene>,
+}
+
+/// The mix of one of a track's sends
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SendScene {
+    pub send_index: i32,
+    pub level: f32,
+    pub pan: f32,
+    pub muted: bool,
+}
+
+impl TrackScene {
+    /// What recalling the track called `guid` sets, in order. The master track can't be soloed, so
+    /// its solo is left alone.
+    pub fn recall_payloads(&self, guid: &TrackGuid) -> Vec<DataPayload> {
+        let mut payloads = vec![
+            DataPayload::Volume(self.volume),
+            DataPayload::Pan(self.pan),
+            DataPayload::Muted(self.muted),
+        ];
+        if *guid != MASTER_GUID {
+            payloads.push(DataPayload::Soloed(self.soloed));
+        }
+        for send in &self.sends {
+            payloads.push(DataPayload::SendLevel(SendLevel {
+                send_index: send.send_index,
+                level: send.level,
+            }));
+            payloads.push(DataPayload::SendPan(SendPan {
+                send_index: send.send_index,
+                pan: send.pan,
+            }));
+            payloads.push(DataPayload::SendMute(SendMute {
+                send_index: send.send_index,
+                muted: send.muted,
+            }));
+        }
+        payloads
+    }
+}
+
+#[derive(Debug)]
+pub enum SceneError {
+    Io(io::Error),
+    Parse(serde_yaml::Error),
+}
+
+/// Scenes by name, kept in a file if there is one so they outlive the bridge.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SceneStore {
+    path: Option<PathBuf>,
+    scenes: BTreeMap<String, Scene>,
+}
+
+impl SceneStore {
+    /// Keeps scenes only for as long as the bridge runs.
+    pub fn in_memory() -> Self {
+        SceneStore::default()
+    }
+
+    /// Reads the scenes saved in `path`, starting with none if nothing has been saved there yet.
+    /// Scenes captured from then on are saved there too.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, SceneError> {
+        let path = path.into();
+        let scenes = match std::fs::read(&path) {
+            Ok(contents) => serde_yaml::from_slice(&contents).map_err(SceneError::Parse)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(SceneError::Io(e)),
+        };
+        Ok(SceneStore {
+            path: Some(path),
+            scenes,
+        })
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Scene> {
+        self.scenes.get(name)
+    }
+
+    /// The names of every scene, in order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.scenes.keys().map(String::as_str)
+    }
+
+    /// Keeps `scene` as `name`, replacing any scene already called that, and saves every scene if
+    /// there's a file to save them in.
+    ///
+    /// Like a session, the scenes are written next to the old file and moved over it, so a crash
+    /// partway through leaves the old scenes intact.
+    pub fn insert(&mut self, name: &str, scene: Scene) -> Result<(), SceneError> {
+        self.scenes.insert(name.to_string(), scene);
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let contents = serde_yaml::to_string(&self.scenes).map_err(SceneError::Parse)?;
+        let partial = path.with_extension("partial");
+        std::fs::write(&partial, contents).map_err(SceneError::Io)?;
+        std::fs::rename(&partial, path).map_err(SceneError::Io)
+    }
+}
@@ -1,9 +1,15 @@
 use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 use std::thread;
+use std::time::{Duration, Instant};
 
-use crossbeam_channel::{Receiver, Sender};
+use crossbeam_channel::{Receiver, RecvError, RecvTimeoutError, Sender};
+use tracing::{debug, info, warn};
 
+use crate::guid::TrackGuid;
+use crate::metrics::LatencyProbe;
 use crate::modes::mode_manager::Barrier;
+use crate::scene::{Scene, SceneStore, SendScene, TrackScene};
 
 // TODO: probably instead of having direction, make an enum of separate UpstreamTrackMsg and DownstreamTrackMsg like we do for XTouch? That seems cleaner
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -18,25 +24,125 @@ pub enum TrackMsg {
     Barrier(Barrier),
     TrackDataMsg(TrackDataMsg),
     TrackQuery(TrackQuery),
+    /// Asks TrackManager to resend everything it knows downstream, in the same order a fresh
+    /// project load would deliver it, followed by the given barrier so the consumer can tell when
+    /// the replay is complete.
+    Replay(Barrier),
+    /// The number of tracks in the Reaper project, used to tell when the initial sync is done
+    NumTracks(i32),
+    /// Sent downstream once, when the tracks visible at startup have all been initialized
+    InitialSync(InitialSync),
+    /// Asks TrackManager to keep the mix of every track it knows as a scene with this name,
+    /// replacing any scene already called that
+    CaptureScene(String),
+    /// Asks TrackManager to set every track in the named scene back to how it was captured, in
+    /// Reaper and on the surface alike
+    RecallScene(String),
+    /// Sent downstream when the track with this GUID is gone from the project. The tracks after it
+    /// in Reaper's order have each moved up one.
+    TrackRemoved(TrackGuid),
+    /// Times the way from Reaper to the surface. Passed downstream like a barrier, but not
+    /// reflected.
+    Probe(LatencyProbe),
+    /// Asks TrackManager to forget every track, e.g. when Reaper has switched to another project,
+    /// sending [`TrackMsg::TrackRemoved`] downstream for each of them
+    Reset,
+    /// Answered by TrackManager once it has handled everything sent to it before this, e.g. to
+    /// know a track it was just told about exists
+    Acknowledge(Sender<()>),
+    /// Asks Reaper which sends a track has, passed on upstream by TrackManager. The answers come
+    /// back downstream as [`DataPayload::SendCount`] and [`DataPayload::SendIndex`].
+    DiscoverSends(SendDiscovery),
 }
 
+impl TrackMsg {
+    /// What a message sets, for keeping only the latest when messages back up. Everything a
+    /// TrackDataMsg carries is state, so a later value for the same thing on the same track
+    /// supersedes an earlier one. Other messages have no key, and are always kept.
+    pub fn coalesce_key(&self) -> Option<String> {
+        let TrackMsg::TrackDataMsg(msg) = self else {
+            return None;
+        };
+        let what = match &msg.data {
+            DataPayload::Name(_) => "name".to_string(),
+            DataPayload::ReaperTrackIndex(_) => "index".to_string(),
+            DataPayload::Selected(_) => "selected".to_string(),
+            DataPayload::Muted(_) => "mute".to_string(),
+            DataPayload::Soloed(_) => "solo".to_string(),
+            DataPayload::Armed(_) => "rec-arm".to_string(),
+            DataPayload::Volume(_) => "volume".to_string(),
+            DataPayload::Pan(_) => "pan".to_string(),
+            DataPayload::Width(_) => "width".to_string(),
+            DataPayload::Phase(_) => "phase".to_string(),
+            DataPayload::InputGain(_) => "input-gain".to_string(),
+            DataPayload::Color(_) => "color".to_string(),
+            DataPayload::SendCount(_) => "send_count".to_string(),
+            DataPayload::SendIndex(send) => format!("send/{}/guid", send.send_index),
+            DataPayload::SendLevel(send) => format!("send/{}/volume", send.send_index),
+            DataPayload::SendPan(send) => format!("send/{}/pan", send.send_index),
+            DataPayload::SendMute(send) => format!("send/{}/mute", send.send_index),
+            DataPayload::FXGuid(fx) => format!("fx/{}/guid", fx.fx_index),
+            DataPayload::FXName(fx) => format!("fx/{}/name", fx.fx_index),
+            DataPayload::FXEnabled(fx) => format!("fx/{}/enabled", fx.fx_index),
+            DataPayload::FXBypass(fx) => format!("fx/{}/bypass", fx.fx_index),
+            DataPayload::FXWet(fx) => format!("fx/{}/wet", fx.fx_index),
+            DataPayload::FXParamName(param) => {
+                format!("fx/{}/param/{}/name", param.fx_index, param.param_index)
+            }
+            DataPayload::FXParamValue(param) => {
+                format!("fx/{}/param/{}/value", param.fx_index, param.param_index)
+            }
+            DataPayload::FXParamMin(param) => {
+                format!("fx/{}/param/{}/min", param.fx_index, param.param_index)
+            }
+            DataPayload::FXParamMax(param) => {
+                format!("fx/{}/param/{}/max", param.fx_index, param.param_index)
+            }
+            DataPayload::TrackData(_) => "all".to_string(),
+        };
+        Some(format!("{:?} {}/{}", msg.direction, msg.guid, what))
+    }
+}
+
+/// How the initial sync with Reaper finished.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InitialSync {
+    /// Every track visible at startup has been initialized
+    Complete,
+    /// The timeout elapsed first; some visible tracks may still be missing
+    TimedOut,
+}
+
+/// Stands in for the GUID of Reaper's master track, which the OSC spec addresses by its own routes
+/// rather than by GUID. Its data is kept and forwarded like any other track's, but having no track
+/// index it is never laid out on the channel strips.
+pub const MASTER_GUID: &str = "master";
+
 #[derive(Clone, Debug)]
 pub struct TrackDataMsg {
-    pub guid: String,
+    pub guid: TrackGuid,
     pub direction: Direction,
     pub data: DataPayload,
 }
 
 #[derive(Clone, Debug)]
 pub struct TrackQuery {
-    pub guid: String,
+    pub guid: TrackGuid,
     pub direction: Direction,
 }
 
+#[derive(Clone, Debug)]
+pub struct SendDiscovery {
+    pub guid: TrackGuid,
+    /// How many sends to ask after, counting from the first
+    pub num_sends: usize,
+}
+
 #[derive(Clone, Debug)]
 pub struct SendIndex {
     pub send_index: i32,
-    pub guid: String,
+    /// The track the send goes to
+    pub guid: TrackGuid,
 }
 
 #[derive(Clone, Debug)]
@@ -51,6 +157,12 @@ pub struct SendPan {
     pub pan: f32,
 }
 
+#[derive(Clone, Debug)]
+pub struct SendMute {
+    pub send_index: i32,
+    pub muted: bool,
+}
+
 #[derive(Clone, Debug)]
 pub struct FXName {
     pub fx_index: i32,
@@ -69,6 +181,18 @@ pub struct FXEnabled {
     pub enabled: bool,
 }
 
+#[derive(Clone, Debug)]
+pub struct FXBypass {
+    pub fx_index: i32,
+    pub bypass: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct FXWet {
+    pub fx_index: i32,
+    pub wet: f32,
+}
+
 #[derive(Clone, Debug)]
 pub struct FXParamName {
     pub fx_index: i32,
@@ -107,12 +231,23 @@ pub enum DataPayload {
     Armed(bool),
     Volume(f32),
     Pan(f32),
+    Width(f32),
+    /// Whether the track's polarity is inverted
+    Phase(bool),
+    InputGain(f32),
+    /// The track's color as 0xRRGGBB, or 0 if it has none of its own
+    Color(i32),
+    /// How many sends the track has
+    SendCount(i32),
     SendIndex(SendIndex),
     SendLevel(SendLevel),
     SendPan(SendPan),
+    SendMute(SendMute),
     FXGuid(FXGuid),
     FXName(FXName),
     FXEnabled(FXEnabled),
+    FXBypass(FXBypass),
+    FXWet(FXWet),
     FXParamName(FXParamName),
     FXParamValue(FXParamValue),
     FXParamMin(FXParamMin),
@@ -122,10 +257,11 @@ pub enum DataPayload {
 
 #[derive(Clone, Debug)]
 pub struct SendData {
-    pub target_guid: String,
+    pub target_guid: TrackGuid,
     pub send_index: i32,
     pub level: f32,
     pub pan: f32,
+    pub muted: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -134,6 +270,8 @@ pub struct FXData {
     pub guid: String,
     pub name: String,
     pub enabled: bool,
+    pub bypass: bool,
+    pub wet: f32,
     pub params: Vec<FXParamData>,
 }
 
@@ -143,6 +281,7 @@ impl FXData {
         while self.params.len() <= param_index as usize {
             self.params.push(FXParamData {
                 param_index: self.params.len() as i32,
+                name: String::new(),
                 value: 0.0,
                 min: 0.0,
                 max: 1.0,
@@ -155,6 +294,7 @@ impl FXData {
 #[derive(Clone, Debug)]
 pub struct FXParamData {
     pub param_index: i32,
+    pub name: String,
     pub value: f32,
     pub min: f32,
     pub max: f32,
@@ -163,7 +303,7 @@ pub struct FXParamData {
 /// Maintains state for a given track to the best of our knowledge
 #[derive(Clone, Debug)]
 pub struct TrackData {
-    guid: String,
+    guid: TrackGuid,
     name: String,
     reaper_track_index: Option<i32>,
     selected: bool,
@@ -172,14 +312,18 @@ pub struct TrackData {
     armed: bool,
     volume: f32,
     pan: f32,
+    width: f32,
+    phase: bool,
+    input_gain: f32,
+    color: i32,
     sends: Vec<SendData>,
     fx: Vec<FXData>,
 }
 
 impl TrackData {
-    fn new(guid: &str) -> Self {
+    fn new(guid: &TrackGuid) -> Self {
         Self {
-            guid: guid.to_string(),
+            guid: guid.clone(),
             name: String::new(),
             reaper_track_index: None,
             selected: false,
@@ -188,6 +332,10 @@ impl TrackData {
             armed: false,
             volume: 0.0,
             pan: 0.0,
+            width: 1.0,
+            phase: false,
+            input_gain: 0.0,
+            color: 0,
             sends: Vec::new(),
             fx: Vec::new(),
         }
@@ -201,15 +349,126 @@ impl TrackData {
         // Ensure the sends vector is large enough
         while self.sends.len() <= send_index.send_index as usize {
             self.sends.push(SendData {
-                target_guid: String::new(),
+                target_guid: TrackGuid::default(),
                 send_index: self.sends.len() as i32,
                 level: 0.0,
                 pan: 0.0,
+                muted: false,
             });
         }
         self.sends[send_index.send_index as usize].target_guid = send_index.guid;
     }
 
+    /// Expands this track into the individual payloads Reaper sends for it when a project loads.
+    ///
+    /// Within each context (track, send, fx, fx param) the payload carrying the context's key
+    /// route comes first so that a consumer gating on those contexts sees them initialized before
+    /// any other data arrives for them.
+    pub fn replay_payloads(&self) -> Vec<DataPayload> {
+        let mut payloads = vec![
+            DataPayload::ReaperTrackIndex(self.reaper_track_index),
+            DataPayload::Name(self.name.clone()),
+            DataPayload::Selected(self.selected),
+            DataPayload::Muted(self.muted),
+            DataPayload::Soloed(self.soloed),
+            DataPayload::Armed(self.armed),
+            DataPayload::Volume(self.volume),
+            DataPayload::Pan(self.pan),
+            DataPayload::Width(self.width),
+            DataPayload::Phase(self.phase),
+            DataPayload::InputGain(self.input_gain),
+            DataPayload::Color(self.color),
+        ];
+        for send in &self.sends {
+            payloads.push(DataPayload::SendIndex(SendIndex {
+                send_index: send.send_index,
+                guid: send.target_guid.clone(),
+            }));
+            payloads.push(DataPayload::SendLevel(SendLevel {
+                send_index: send.send_index,
+                level: send.level,
+            }));
+            payloads.push(DataPayload::SendPan(SendPan {
+                send_index: send.send_index,
+                pan: send.pan,
+            }));
+            payloads.push(DataPayload::SendMute(SendMute {
+                send_index: send.send_index,
+                muted: send.muted,
+            }));
+        }
+        for fx in &self.fx {
+            payloads.push(DataPayload::FXGuid(FXGuid {
+                fx_index: fx.fx_index,
+                guid: fx.guid.clone(),
+            }));
+            payloads.push(DataPayload::FXName(FXName {
+                fx_index: fx.fx_index,
+                name: fx.name.clone(),
+            }));
+            payloads.push(DataPayload::FXEnabled(FXEnabled {
+                fx_index: fx.fx_index,
+                enabled: fx.enabled,
+            }));
+            payloads.push(DataPayload::FXBypass(FXBypass {
+                fx_index: fx.fx_index,
+                bypass: fx.bypass,
+            }));
+            payloads.push(DataPayload::FXWet(FXWet {
+                fx_index: fx.fx_index,
+                wet: fx.wet,
+            }));
+            for param in &fx.params {
+                payloads.push(DataPayload::FXParamName(FXParamName {
+                    fx_index: fx.fx_index,
+                    param_index: param.param_index,
+                    name: param.name.clone(),
+                }));
+                payloads.push(DataPayload::FXParamValue(FXParamValue {
+                    fx_index: fx.fx_index,
+                    param_index: param.param_index,
+                    value: param.value,
+                }));
+                payloads.push(DataPayload::FXParamMin(FXParamMin {
+                    fx_index: fx.fx_index,
+                    param_index: param.param_index,
+                    min: param.min,
+                }));
+                payloads.push(DataPayload::FXParamMax(FXParamMax {
+                    fx_index: fx.fx_index,
+                    param_index: param.param_index,
+                    max: param.max,
+                }));
+            }
+        }
+        payloads
+    }
+
+    /// Returns the state of each FX on the track, by FX index.
+    pub fn fx(&self) -> &[FXData] {
+        &self.fx
+    }
+
+    /// The track's part of a scene
+    pub fn scene(&self) -> TrackScene {
+        TrackScene {
+            volume: self.volume,
+            pan: self.pan,
+            muted: self.muted,
+            soloed: self.soloed,
+            sends: self
+                .sends
+                .iter()
+                .map(|send| SendScene {
+                    send_index: send.send_index,
+                    level: send.level,
+                    pan: send.pan,
+                    muted: send.muted,
+                })
+                .collect(),
+        }
+    }
+
     fn get_fx_data(&mut self, fx_index: i32) -> Option<&mut FXData> {
         // Ensure the fx vector is large enough
         while self.fx.len() <= fx_index as usize {
@@ -218,6 +477,8 @@ impl TrackData {
                 fx_index: self.fx.len() as i32,
                 name: String::new(),
                 enabled: false,
+                bypass: false,
+                wet: 1.0,
                 params: Vec::new(),
             });
         }
@@ -225,9 +486,126 @@ impl TrackData {
     }
 }
 
+/// Everything known about a track at one moment, as handed out by [`TrackManagerHandle`].
+#[derive(Clone, Debug)]
+pub struct TrackSnapshot {
+    pub guid: TrackGuid,
+    pub name: String,
+    /// None until Reaper has told us where the track is, or while it's moving
+    pub reaper_track_index: Option<i32>,
+    pub selected: bool,
+    pub muted: bool,
+    pub soloed: bool,
+    pub armed: bool,
+    pub volume: f32,
+    pub pan: f32,
+    pub width: f32,
+    pub phase: bool,
+    pub input_gain: f32,
+    /// 0xRRGGBB, or 0 if the track has no color of its own
+    pub color: i32,
+    pub sends: Vec<SendData>,
+    pub fx: Vec<FXData>,
+}
+
+impl From<&TrackData> for TrackSnapshot {
+    fn from(track: &TrackData) -> Self {
+        TrackSnapshot {
+            guid: track.guid.clone(),
+            name: track.name.clone(),
+            reaper_track_index: track.reaper_track_index,
+            selected: track.selected,
+            muted: track.muted,
+            soloed: track.soloed,
+            armed: track.armed,
+            volume: track.volume,
+            pan: track.pan,
+            width: track.width,
+            phase: track.phase,
+            input_gain: track.input_gain,
+            color: track.color,
+            sends: track.sends.clone(),
+            fx: track.fx.clone(),
+        }
+    }
+}
+
+// Every track in Reaper's order, with tracks whose index isn't known yet last
+fn in_reaper_order(tracks: &HashMap<TrackGuid, TrackData>) -> Vec<&TrackData> {
+    let mut tracks: Vec<&TrackData> = tracks.values().collect();
+    tracks.sort_by(|a, b| {
+        let a_index = a.reaper_track_index.unwrap_or(i32::MAX);
+        let b_index = b.reaper_track_index.unwrap_or(i32::MAX);
+        a_index.cmp(&b_index).then_with(|| a.guid.cmp(&b.guid))
+    });
+    tracks
+}
+
+/// Reads what a running TrackManager knows about the tracks, from any thread.
+///
+/// The tracks are updated as TrackManager takes in each message, before passing it on, so whoever
+/// gets the message sees the change here too.
+#[derive(Clone)]
+pub struct TrackManagerHandle {
+    tracks: Arc<RwLock<HashMap<TrackGuid, TrackData>>>,
+}
+
+impl TrackManagerHandle {
+    /// The track with this GUID, if TrackManager has heard of it.
+    pub fn get_track(&self, guid: &str) -> Option<TrackSnapshot> {
+        self.tracks
+            .read()
+            .unwrap()
+            .get(guid)
+            .map(TrackSnapshot::from)
+    }
+
+    /// Every known track, in Reaper's order, with tracks whose index isn't known yet last.
+    pub fn list_tracks(&self) -> Vec<TrackSnapshot> {
+        let tracks = self.tracks.read().unwrap();
+        in_reaper_order(&tracks)
+            .into_iter()
+            .map(TrackSnapshot::from)
+            .collect()
+    }
+}
+
+// Tracks progress towards the initial sync milestone
+struct InitialSyncTracker {
+    // How many tracks fit on the surface at startup
+    visible_tracks: usize,
+    num_tracks: Option<usize>,
+    deadline: Instant,
+}
+
+impl InitialSyncTracker {
+    // Returns the milestone once it has been reached
+    fn check(&self, tracks: &HashMap<TrackGuid, TrackData>) -> Option<InitialSync> {
+        if let Some(num_tracks) = self.num_tracks {
+            let expected = num_tracks.min(self.visible_tracks);
+            let initialized = tracks
+                .values()
+                .filter_map(|track| usize::try_from(track.reaper_track_index?).ok())
+                .filter(|index| *index < expected)
+                .count();
+            if initialized >= expected {
+                return Some(InitialSync::Complete);
+            }
+        }
+        if Instant::now() >= self.deadline {
+            return Some(InitialSync::TimedOut);
+        }
+        None
+    }
+}
+
 pub struct TrackManager {
-    tracks: HashMap<String, TrackData>,
-    selected_track: Option<String>,
+    // Shared with every TrackManagerHandle, which only ever reads it
+    tracks: Arc<RwLock<HashMap<TrackGuid, TrackData>>>,
+    selected_track: Option<TrackGuid>,
+    // Present until the initial sync milestone has been sent
+    initial_sync: Option<InitialSyncTracker>,
+    scenes: SceneStore,
     input: Receiver<TrackMsg>,
     downstream: Sender<TrackMsg>,
     upstream: Sender<TrackMsg>,
@@ -238,11 +616,67 @@ impl TrackManager {
         input: Receiver<TrackMsg>,
         upstream: Sender<TrackMsg>,
         downstream: Sender<TrackMsg>,
-    ) {
+    ) -> TrackManagerHandle {
+        Self::spawn(input, upstream, downstream, None, SceneStore::in_memory())
+    }
+
+    /// Like [`TrackManager::start`], but also sends [`TrackMsg::InitialSync`] downstream once the
+    /// first `visible_tracks` tracks have all been initialized, or once `timeout` has elapsed.
+    ///
+    /// The sync can only complete after [`TrackMsg::NumTracks`] has told us how many tracks the
+    /// project has.
+    pub fn start_with_initial_sync(
+        input: Receiver<TrackMsg>,
+        upstream: Sender<TrackMsg>,
+        downstream: Sender<TrackMsg>,
+        visible_tracks: usize,
+        timeout: Duration,
+    ) -> TrackManagerHandle {
+        Self::start_with_scenes(
+            input,
+            upstream,
+            downstream,
+            visible_tracks,
+            timeout,
+            SceneStore::in_memory(),
+        )
+    }
+
+    /// Like [`TrackManager::start_with_initial_sync`], but capturing scenes into and recalling
+    /// them from `scenes`.
+    pub fn start_with_scenes(
+        input: Receiver<TrackMsg>,
+        upstream: Sender<TrackMsg>,
+        downstream: Sender<TrackMsg>,
+        visible_tracks: usize,
+        timeout: Duration,
+        scenes: SceneStore,
+    ) -> TrackManagerHandle {
+        let initial_sync = InitialSyncTracker {
+            visible_tracks,
+            num_tracks: None,
+            deadline: Instant::now() + timeout,
+        };
+        Self::spawn(input, upstream, downstream, Some(initial_sync), scenes)
+    }
+
+    fn spawn(
+        input: Receiver<TrackMsg>,
+        upstream: Sender<TrackMsg>,
+        downstream: Sender<TrackMsg>,
+        initial_sync: Option<InitialSyncTracker>,
+        scenes: SceneStore,
+    ) -> TrackManagerHandle {
+        let tracks = Arc::new(RwLock::new(HashMap::new()));
+        let handle = TrackManagerHandle {
+            tracks: tracks.clone(),
+        };
         thread::spawn(move || {
             let mut manager = Self {
-                tracks: HashMap::new(),
+                tracks,
                 selected_track: None,
+                initial_sync,
+                scenes,
                 input,
                 downstream,
                 upstream,
@@ -251,190 +685,137 @@ impl TrackManager {
                 manager.handle_messages();
             }
         });
+        handle
+    }
+
+    /// Builds a downstream snapshot of every known track, ordered like a fresh project load:
+    /// tracks in Reaper's order (tracks without an index last), each expanded by
+    /// [`TrackData::replay_payloads`].
+    pub fn snapshot(&self) -> Vec<TrackDataMsg> {
+        let tracks = self.tracks.read().unwrap();
+        in_reaper_order(&tracks)
+            .into_iter()
+            .flat_map(|track| {
+                track
+                    .replay_payloads()
+                    .into_iter()
+                    .map(|data| TrackDataMsg {
+                        guid: track.guid.clone(),
+                        direction: Direction::Downstream,
+                        data,
+                    })
+            })
+            .collect()
+    }
+
+    /// The mix of every known track, as it stands.
+    pub fn capture_scene(&self) -> Scene {
+        Scene {
+            tracks: self
+                .tracks
+                .read()
+                .unwrap()
+                .values()
+                .map(|track| (track.guid.clone(), track.scene()))
+                .collect(),
+        }
+    }
+
+    // Sets every track in the scene that's still around back to how it was, telling Reaper and
+    // the modes alike since neither of them made the change
+    fn recall_scene(&mut self, name: &str) {
+        let Some(scene) = self.scenes.get(name).cloned() else {
+            warn!("There's no scene called {:?} to recall", name);
+            return;
+        };
+        info!("Recalling scene {:?}", name);
+        for (guid, track) in scene.tracks {
+            if !self.tracks.read().unwrap().contains_key(&guid) {
+                debug!("Track {} in scene {:?} is gone, skipping it", guid, name);
+                continue;
+            }
+            for data in track.recall_payloads(&guid) {
+                self.handle_track_data(TrackDataMsg {
+                    guid: guid.clone(),
+                    direction: Direction::Upstream,
+                    data: data.clone(),
+                });
+                let _ = self.downstream.send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                    guid: guid.clone(),
+                    direction: Direction::Downstream,
+                    data,
+                }));
+            }
+        }
+    }
+
+    // Waits for the next message, sending the initial sync milestone if it times out first
+    fn next_message(&mut self) -> Result<TrackMsg, RecvError> {
+        loop {
+            let Some(deadline) = self.initial_sync.as_ref().map(|sync| sync.deadline) else {
+                return self.input.recv();
+            };
+            match self.input.recv_deadline(deadline) {
+                Ok(msg) => return Ok(msg),
+                Err(RecvTimeoutError::Timeout) => self.check_initial_sync(),
+                Err(RecvTimeoutError::Disconnected) => return Err(RecvError),
+            }
+        }
+    }
+
+    fn check_initial_sync(&mut self) {
+        let Some(sync) = &self.initial_sync else {
+            return;
+        };
+        let Some(event) = sync.check(&self.tracks.read().unwrap()) else {
+            return;
+        };
+        self.initial_sync = None;
+        let _ = self.downstream.send(TrackMsg::InitialSync(event));
     }
 
     pub fn handle_messages(&mut self) {
-        while let Ok(msg) = self.input.recv() {
+        while let Ok(msg) = self.next_message() {
             match msg {
                 TrackMsg::Barrier(barrier) => {
                     self.downstream.send(TrackMsg::Barrier(barrier)).unwrap();
                 }
-                TrackMsg::TrackDataMsg(msg) => {
-                    let msg_cloned = msg.clone();
-                    // If we've never seen this track before, create a new entry
-                    let track = self
-                        .tracks
-                        .entry(msg.guid.to_string())
-                        .or_insert_with(|| TrackData::new(&msg.guid));
-                    // TODO: this really should also be forwarding all messages downstream as well
-                    // as accumulating state internally
-                    match msg.data {
-                        DataPayload::Name(name) => {
-                            track.name = name.clone();
-                            println!("Track {} name set to {}", msg.guid, name);
-                        }
-                        DataPayload::ReaperTrackIndex(index) => {
-                            track.reaper_track_index = index;
-                            println!("Track {} Reaper index set to {:?}", msg.guid, index);
-                        }
-                        DataPayload::Selected(selected) => {
-                            track.selected = selected;
-                            if selected {
-                                self.selected_track = Some(msg.guid.clone());
-                            }
-                            println!("Track {} selected set to {}", msg.guid, selected);
-                        }
-                        DataPayload::Muted(muted) => {
-                            track.muted = muted;
-                            println!("Track {} muted set to {}", msg.guid, muted);
-                        }
-                        DataPayload::Soloed(soloed) => {
-                            track.soloed = soloed;
-                            println!("Track {} soloed set to {}", msg.guid, soloed);
-                        }
-                        DataPayload::Armed(armed) => {
-                            track.armed = armed;
-                            println!("Track {} armed set to {}", msg.guid, armed);
-                        }
-                        DataPayload::Volume(volume) => {
-                            track.volume = volume;
-                            println!("Track {} volume set to {}", msg.guid, volume);
-                        }
-                        DataPayload::Pan(pan) => {
-                            track.pan = pan;
-                            println!("Track {} pan set to {}", msg.guid, pan);
-                        }
-                        // Update everything!
-                        DataPayload::TrackData(track_data) => {
-                            *track = track_data;
-                        }
-                        DataPayload::SendIndex(send_index) => {
-                            track.set_send_index(send_index.clone());
-                            println!(
-                                "Track {} send {} target GUID set to {}",
-                                msg.guid, send_index.send_index, send_index.guid
-                            );
-                        }
-                        DataPayload::SendLevel(send_level) => {
-                            if let Some(send) = track.get_send_state(send_level.send_index) {
-                                send.level = send_level.level;
-                                println!(
-                                    "Track {} send {} level set to {}",
-                                    msg.guid, send_level.send_index, send_level.level
-                                );
-                            }
-                        }
-                        DataPayload::SendPan(send_pan) => {
-                            if let Some(send) = track.get_send_state(send_pan.send_index) {
-                                send.pan = send_pan.pan;
-                                println!(
-                                    "Track {} send {} pan set to {}",
-                                    msg.guid, send.send_index, send_pan.pan
-                                );
-                            }
-                        }
-                        DataPayload::FXGuid(fx_guid) => {
-                            if let Some(fx) = track.get_fx_data(fx_guid.fx_index) {
-                                fx.guid = fx_guid.guid.clone();
-                                println!(
-                                    "Track {} FX {} GUID set to {}",
-                                    msg.guid, fx_guid.fx_index, fx_guid.guid
-                                );
-                            }
-                        }
-                        DataPayload::FXName(fx_name) => {
-                            if let Some(fx) = track.get_fx_data(fx_name.fx_index) {
-                                fx.name = fx_name.name.clone();
-                                println!(
-                                    "Track {} FX {} name set to {}",
-                                    msg.guid, fx_name.fx_index, fx_name.name
-                                );
-                            }
-                        }
-                        DataPayload::FXEnabled(fx_enabled) => {
-                            if let Some(fx) = track.get_fx_data(fx_enabled.fx_index) {
-                                fx.enabled = fx_enabled.enabled;
-                                println!(
-                                    "Track {} FX {} enabled set to {}",
-                                    msg.guid, fx_enabled.fx_index, fx_enabled.enabled
-                                );
-                            }
-                        }
-                        DataPayload::FXParamName(fx_param_name) => {
-                            if let Some(fx) = track.get_fx_data(fx_param_name.fx_index) {
-                                if let Some(param) = fx.get_param_data(fx_param_name.param_index) {
-                                    // We don't store the name in FXParamData currently
-                                    println!(
-                                        "Track {} FX {} Param {} name set to {}",
-                                        msg.guid,
-                                        fx_param_name.fx_index,
-                                        fx_param_name.param_index,
-                                        fx_param_name.name
-                                    );
-                                }
-                            }
-                        }
-                        DataPayload::FXParamValue(fx_param_value) => {
-                            if let Some(fx) = track.get_fx_data(fx_param_value.fx_index) {
-                                if let Some(param) = fx.get_param_data(fx_param_value.param_index) {
-                                    param.value = fx_param_value.value;
-                                    println!(
-                                        "Track {} FX {} Param {} value set to {}",
-                                        msg.guid,
-                                        fx_param_value.fx_index,
-                                        fx_param_value.param_index,
-                                        fx_param_value.value
-                                    );
-                                }
-                            }
-                        }
-                        DataPayload::FXParamMin(fx_param_min) => {
-                            if let Some(fx) = track.get_fx_data(fx_param_min.fx_index) {
-                                if let Some(param) = fx.get_param_data(fx_param_min.param_index) {
-                                    param.min = fx_param_min.min;
-                                    println!(
-                                        "Track {} FX {} Param {} min set to {}",
-                                        msg.guid,
-                                        fx_param_min.fx_index,
-                                        fx_param_min.param_index,
-                                        fx_param_min.min
-                                    );
-                                }
-                            }
-                        }
-                        DataPayload::FXParamMax(fx_param_max) => {
-                            if let Some(fx) = track.get_fx_data(fx_param_max.fx_index) {
-                                if let Some(param) = fx.get_param_data(fx_param_max.param_index) {
-                                    param.max = fx_param_max.max;
-                                    println!(
-                                        "Track {} FX {} Param {} max set to {}",
-                                        msg.guid,
-                                        fx_param_max.fx_index,
-                                        fx_param_max.param_index,
-                                        fx_param_max.max
-                                    );
-                                }
-                            }
-                        }
+                TrackMsg::Probe(probe) => {
+                    let _ = self.downstream.send(TrackMsg::Probe(probe));
+                }
+                TrackMsg::NumTracks(num_tracks) => {
+                    if let Some(sync) = &mut self.initial_sync {
+                        sync.num_tracks = usize::try_from(num_tracks).ok();
                     }
-                    // Forward the message to the appropriate place
-                    match msg.direction {
-                        Direction::Upstream => {
-                            self.upstream
-                                .send(TrackMsg::TrackDataMsg(msg_cloned))
-                                .unwrap();
-                        }
-                        Direction::Downstream => {
-                            self.downstream
-                                .send(TrackMsg::TrackDataMsg(msg_cloned))
-                                .unwrap();
-                        }
+                }
+                // Only we send these
+                TrackMsg::InitialSync(_) | TrackMsg::TrackRemoved(_) => {}
+                TrackMsg::Reset => self.remove_all_tracks(),
+                TrackMsg::Acknowledge(acknowledge) => {
+                    let _ = acknowledge.try_send(());
+                }
+                TrackMsg::DiscoverSends(discovery) => {
+                    let _ = self.upstream.send(TrackMsg::DiscoverSends(discovery));
+                }
+                TrackMsg::TrackDataMsg(msg) => self.handle_track_data(msg),
+                TrackMsg::CaptureScene(name) => {
+                    let scene = self.capture_scene();
+                    info!("Captured scene {:?} of {} tracks", name, scene.tracks.len());
+                    if let Err(e) = self.scenes.insert(&name, scene) {
+                        warn!("Failed to save scene {:?}: {:?}", name, e);
+                    }
+                }
+                TrackMsg::RecallScene(name) => self.recall_scene(&name),
+                TrackMsg::Replay(barrier) => {
+                    for msg in self.snapshot() {
+                        let _ = self.downstream.send(TrackMsg::TrackDataMsg(msg));
                     }
+                    let _ = self.downstream.send(TrackMsg::Barrier(barrier));
                 }
                 TrackMsg::TrackQuery(msg) => match msg.direction {
                     // Respond with ALL of the current track data
                     Direction::Upstream => {
-                        if let Some(track) = self.tracks.get(&msg.guid) {
+                        if let Some(track) = self.tracks.read().unwrap().get(&msg.guid) {
                             let response = TrackMsg::TrackDataMsg(TrackDataMsg {
                                 guid: msg.guid.clone(),
                                 direction: Direction::Upstream, // Don't care?
@@ -444,7 +825,7 @@ impl TrackManager {
                         }
                     }
                     Direction::Downstream => {
-                        if let Some(track) = self.tracks.get(&msg.guid) {
+                        if let Some(track) = self.tracks.read().unwrap().get(&msg.guid) {
                             let response = TrackMsg::TrackDataMsg(TrackDataMsg {
                                 guid: msg.guid.clone(),
                                 direction: Direction::Downstream, // Don't care?
@@ -455,6 +836,285 @@ impl TrackManager {
                     }
                 },
             }
+            self.check_initial_sync();
+        }
+    }
+
+    // Forgets a track Reaper no longer has, moving the tracks after it up into its place as
+    // Reaper does, and tells the modes it's gone
+    fn remove_track(&mut self, guid: &TrackGuid) {
+        let mut tracks = self.tracks.write().unwrap();
+        let Some(removed) = tracks.remove(guid) else {
+            return;
+        };
+        info!("Track {} was removed", guid);
+        if let Some(removed_index) = removed.reaper_track_index {
+            for index in tracks
+                .values_mut()
+                .filter_map(|track| track.reaper_track_index.as_mut())
+            {
+                if *index > removed_index {
+                    *index -= 1;
+                }
+            }
+        }
+        drop(tracks);
+        if self.selected_track.as_deref() == Some(guid) {
+            self.selected_track = None;
+        }
+        let _ = self.downstream.send(TrackMsg::TrackRemoved(guid.clone()));
+    }
+
+    // Forgets every track, last first so the indices of those left never need moving up
+    fn remove_all_tracks(&mut self) {
+        let guids: Vec<TrackGuid> = in_reaper_order(&self.tracks.read().unwrap())
+            .into_iter()
+            .rev()
+            .map(|track| track.guid.clone())
+            .collect();
+        info!("Forgetting all {} tracks", guids.len());
+        for guid in guids {
+            self.remove_track(&guid);
+        }
+    }
+
+    // Takes in what a track's data was set to, keeping track of it and passing it on
+    fn handle_track_data(&mut self, msg: TrackDataMsg) {
+        // Reaper reports a track it no longer has without an index
+        if let DataPayload::ReaperTrackIndex(None) = msg.data {
+            self.remove_track(&msg.guid);
+            return;
+        }
+        // Reaper indices are unique, so any other track still at this one's new index has moved
+        // too, and its own index is unknown until it arrives
+        let mut tracks = self.tracks.write().unwrap();
+        if let DataPayload::ReaperTrackIndex(Some(index)) = msg.data {
+            for (guid, track) in tracks.iter_mut() {
+                if *guid != msg.guid && track.reaper_track_index == Some(index) {
+                    debug!("Track {} moved off index {}", guid, index);
+                    track.reaper_track_index = None;
+                }
+            }
+        }
+        let msg_cloned = msg.clone();
+        // If we've never seen this track before, create a new entry
+        let track = tracks
+            .entry(msg.guid.clone())
+            .or_insert_with(|| TrackData::new(&msg.guid));
+        // TODO: this really should also be forwarding all messages downstream as well
+        // as accumulating state internally
+        match msg.data {
+            DataPayload::Name(name) => {
+                track.name = name.clone();
+                debug!("Track {} name set to {}", msg.guid, name);
+            }
+            DataPayload::ReaperTrackIndex(index) => {
+                track.reaper_track_index = index;
+                debug!("Track {} Reaper index set to {:?}", msg.guid, index);
+            }
+            DataPayload::Selected(selected) => {
+                track.selected = selected;
+                if selected {
+                    self.selected_track = Some(msg.guid.clone());
+                }
+                debug!("Track {} selected set to {}", msg.guid, selected);
+            }
+            DataPayload::Muted(muted) => {
+                track.muted = muted;
+                debug!("Track {} muted set to {}", msg.guid, muted);
+            }
+            DataPayload::Soloed(soloed) => {
+                track.soloed = soloed;
+                debug!("Track {} soloed set to {}", msg.guid, soloed);
+            }
+            DataPayload::Armed(armed) => {
+                track.armed = armed;
+                debug!("Track {} armed set to {}", msg.guid, armed);
+            }
+            DataPayload::Volume(volume) => {
+                track.volume = volume;
+                debug!("Track {} volume set to {}", msg.guid, volume);
+            }
+            DataPayload::Pan(pan) => {
+                track.pan = pan;
+                debug!("Track {} pan set to {}", msg.guid, pan);
+            }
+            DataPayload::Width(width) => {
+                track.width = width;
+                debug!("Track {} width set to {}", msg.guid, width);
+            }
+            DataPayload::Phase(phase) => {
+                track.phase = phase;
+                debug!("Track {} phase inverted set to {}", msg.guid, phase);
+            }
+            DataPayload::InputGain(input_gain) => {
+                track.input_gain = input_gain;
+                debug!("Track {} input gain set to {}", msg.guid, input_gain);
+            }
+            DataPayload::Color(color) => {
+                track.color = color;
+                debug!("Track {} color set to {:06x}", msg.guid, color);
+            }
+            // Update everything!
+            DataPayload::TrackData(track_data) => {
+                *track = track_data;
+            }
+            // Sends past the end are gone
+            DataPayload::SendCount(send_count) => {
+                track
+                    .sends
+                    .truncate(usize::try_from(send_count).unwrap_or_default());
+                debug!("Track {} send count set to {}", msg.guid, send_count);
+            }
+            DataPayload::SendIndex(send_index) => {
+                track.set_send_index(send_index.clone());
+                debug!(
+                    "Track {} send {} target GUID set to {}",
+                    msg.guid, send_index.send_index, send_index.guid
+                );
+            }
+            DataPayload::SendLevel(send_level) => {
+                if let Some(send) = track.get_send_state(send_level.send_index) {
+                    send.level = send_level.level;
+                    debug!(
+                        "Track {} send {} level set to {}",
+                        msg.guid, send_level.send_index, send_level.level
+                    );
+                }
+            }
+            DataPayload::SendPan(send_pan) => {
+                if let Some(send) = track.get_send_state(send_pan.send_index) {
+                    send.pan = send_pan.pan;
+                    debug!(
+                        "Track {} send {} pan set to {}",
+                        msg.guid, send.send_index, send_pan.pan
+                    );
+                }
+            }
+            DataPayload::SendMute(send_mute) => {
+                if let Some(send) = track.get_send_state(send_mute.send_index) {
+                    send.muted = send_mute.muted;
+                    debug!(
+                        "Track {} send {} muted set to {}",
+                        msg.guid, send_mute.send_index, send_mute.muted
+                    );
+                }
+            }
+            DataPayload::FXGuid(fx_guid) => {
+                if let Some(fx) = track.get_fx_data(fx_guid.fx_index) {
+                    fx.guid = fx_guid.guid.clone();
+                    debug!(
+                        "Track {} FX {} GUID set to {}",
+                        msg.guid, fx_guid.fx_index, fx_guid.guid
+                    );
+                }
+            }
+            DataPayload::FXName(fx_name) => {
+                if let Some(fx) = track.get_fx_data(fx_name.fx_index) {
+                    fx.name = fx_name.name.clone();
+                    debug!(
+                        "Track {} FX {} name set to {}",
+                        msg.guid, fx_name.fx_index, fx_name.name
+                    );
+                }
+            }
+            DataPayload::FXEnabled(fx_enabled) => {
+                if let Some(fx) = track.get_fx_data(fx_enabled.fx_index) {
+                    fx.enabled = fx_enabled.enabled;
+                    debug!(
+                        "Track {} FX {} enabled set to {}",
+                        msg.guid, fx_enabled.fx_index, fx_enabled.enabled
+                    );
+                }
+            }
+            DataPayload::FXBypass(fx_bypass) => {
+                if let Some(fx) = track.get_fx_data(fx_bypass.fx_index) {
+                    fx.bypass = fx_bypass.bypass;
+                    debug!(
+                        "Track {} FX {} bypass set to {}",
+                        msg.guid, fx_bypass.fx_index, fx_bypass.bypass
+                    );
+                }
+            }
+            DataPayload::FXWet(fx_wet) => {
+                if let Some(fx) = track.get_fx_data(fx_wet.fx_index) {
+                    fx.wet = fx_wet.wet;
+                    debug!(
+                        "Track {} FX {} wet set to {}",
+                        msg.guid, fx_wet.fx_index, fx_wet.wet
+                    );
+                }
+            }
+            DataPayload::FXParamName(fx_param_name) => {
+                if let Some(fx) = track.get_fx_data(fx_param_name.fx_index) {
+                    if let Some(param) = fx.get_param_data(fx_param_name.param_index) {
+                        param.name = fx_param_name.name.clone();
+                        debug!(
+                            "Track {} FX {} Param {} name set to {}",
+                            msg.guid,
+                            fx_param_name.fx_index,
+                            fx_param_name.param_index,
+                            fx_param_name.name
+                        );
+                    }
+                }
+            }
+            DataPayload::FXParamValue(fx_param_value) => {
+                if let Some(fx) = track.get_fx_data(fx_param_value.fx_index) {
+                    if let Some(param) = fx.get_param_data(fx_param_value.param_index) {
+                        param.value = fx_param_value.value;
+                        debug!(
+                            "Track {} FX {} Param {} value set to {}",
+                            msg.guid,
+                            fx_param_value.fx_index,
+                            fx_param_value.param_index,
+                            fx_param_value.value
+                        );
+                    }
+                }
+            }
+            DataPayload::FXParamMin(fx_param_min) => {
+                if let Some(fx) = track.get_fx_data(fx_param_min.fx_index) {
+                    if let Some(param) = fx.get_param_data(fx_param_min.param_index) {
+                        param.min = fx_param_min.min;
+                        debug!(
+                            "Track {} FX {} Param {} min set to {}",
+                            msg.guid,
+                            fx_param_min.fx_index,
+                            fx_param_min.param_index,
+                            fx_param_min.min
+                        );
+                    }
+                }
+            }
+            DataPayload::FXParamMax(fx_param_max) => {
+                if let Some(fx) = track.get_fx_data(fx_param_max.fx_index) {
+                    if let Some(param) = fx.get_param_data(fx_param_max.param_index) {
+                        param.max = fx_param_max.max;
+                        debug!(
+                            "Track {} FX {} Param {} max set to {}",
+                            msg.guid,
+                            fx_param_max.fx_index,
+                            fx_param_max.param_index,
+                            fx_param_max.max
+                        );
+                    }
+                }
+            }
+        }
+        drop(tracks);
+        // Forward the message to the appropriate place
+        match msg.direction {
+            Direction::Upstream => {
+                self.upstream
+                    .send(TrackMsg::TrackDataMsg(msg_cloned))
+                    .unwrap();
+            }
+            Direction::Downstream => {
+                self.downstream
+                    .send(TrackMsg::TrackDataMsg(msg_cloned))
+                    .unwrap();
+            }
         }
     }
 }
@@ -1,15 +1,98 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crossbeam_channel::Receiver;
+
 pub trait Bind<Args> {
     fn bind<F>(&mut self, callback: F)
     where
         F: FnMut(Args) + Send + 'static;
 }
 
+/// Like Bind, but each handler can be unbound again without disturbing any others bound to the
+/// same endpoint.
+pub trait Subscribe<Args> {
+    /// Adds a handler alongside any already bound, returning the subscription that unbinds it.
+    fn subscribe<F>(&mut self, callback: F) -> Subscription
+    where
+        F: FnMut(Args) + Send + 'static;
+
+    /// Removes the handler `subscription` was returned for. Returns false if it isn't bound here,
+    /// e.g. because it was already unbound.
+    fn unbind(&mut self, subscription: Subscription) -> bool;
+}
+
+static NEXT_SUBSCRIPTION: AtomicU64 = AtomicU64::new(0);
+
+/// Identifies one handler added with [`Subscribe::subscribe`]. Subscriptions are unique.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Subscription {
+    id: u64,
+}
+
+impl Subscription {
+    pub fn new() -> Self {
+        Subscription {
+            id: NEXT_SUBSCRIPTION.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+impl Default for Subscription {
+    fn default() -> Self {
+        Subscription::new()
+    }
+}
+
 pub trait Set<Args> {
     type Error;
     fn set(&mut self, args: Args) -> Result<(), Self::Error>;
 }
 
+/// Builds the message a [`Set`] would send without sending it, so it can go out alongside others.
+pub trait SetMessage<Args> {
+    type Error;
+    /// Where the message's binary arguments are, which have to be encoded with
+    /// [`crate::osc::decode::encode_packet`] to go out as they are
+    const BINARY_ARGS: &'static [usize] = &[];
+    fn set_message(&self, args: Args) -> Result<rosc::OscMessage, Self::Error>;
+}
+
 pub trait Query {
     type Error;
     fn query(&self) -> Result<(), Self::Error>;
 }
+
+/// A query whose response can be waited on directly instead of through a bound handler.
+pub trait QueryWithResponse<Args>: Query {
+    /// Sends the query, returning a receiver that yields the response once it arrives.
+    fn query_response(&self) -> Result<Receiver<Args>, Self::Error>;
+
+    /// Sends the query and waits up to `timeout` for the response.
+    fn query_value(&self, timeout: Duration) -> Result<Args, QueryError<Self::Error>> {
+        let response = self.query_response().map_err(QueryError::Send)?;
+        response
+            .recv_timeout(timeout)
+            .map_err(|_| QueryError::Timeout)
+    }
+}
+
+#[derive(Debug)]
+pub enum QueryError<E> {
+    /// The query couldn't be sent
+    Send(E),
+    /// No response arrived in time
+    Timeout,
+}
+
+#[cfg(any(test, feature = "async"))]
+pub trait AsyncSet<Args> {
+    type Error;
+    fn set(&mut self, args: Args) -> impl Future<Output = Result<(), Self::Error>>;
+}
+
+#[cfg(any(test, feature = "async"))]
+pub trait AsyncQuery {
+    type Error;
+    fn query(&self) -> impl Future<Output = Result<(), Self::Error>>;
+}
@@ -0,0 +1,314 @@
+use std::thread;
+
+use crossbeam_channel::{Receiver, Sender, select};
+use serde::Deserialize;
+
+use crate::midi::xtouch::{
+    ActionButton, ActionPress, ActionRelease, LEDState, XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
+
+/// How far one click of the jog wheel moves the play cursor, in seconds
+pub const JOG_STEP: f32 = 0.1;
+
+/// Command IDs of Reaper's "View: Move cursor left/right one pixel" actions
+pub const SCRUB_LEFT_ACTION: i32 = 40104;
+pub const SCRUB_RIGHT_ACTION: i32 = 40105;
+
+/// Command IDs of Reaper's "View: Zoom out/in horizontal" actions
+pub const ZOOM_OUT_ACTION: i32 = 1011;
+pub const ZOOM_IN_ACTION: i32 = 1012;
+
+/// What turning the jog wheel does.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JogMode {
+    /// Moves the play cursor by JOG_STEP a click
+    #[default]
+    Navigate,
+    /// Moves the edit cursor a pixel at a time, so how far it goes follows the zoom
+    Scrub,
+    /// Zooms the arrange view in and out
+    Zoom,
+}
+
+impl JogMode {
+    /// The mode a button next to the jog wheel switches to.
+    pub fn for_button(msg: &XTouchUpstreamMsg) -> Option<JogMode> {
+        match msg {
+            XTouchUpstreamMsg::ScrubPress => Some(JogMode::Scrub),
+            XTouchUpstreamMsg::ZoomPress => Some(JogMode::Zoom),
+            _ => None,
+        }
+    }
+
+    /// Sets the LED of the button that switches to this mode, if it has one.
+    pub fn button_led(self, state: LEDState) -> Option<XTouchDownstreamMsg> {
+        match self {
+            JogMode::Navigate => None,
+            JogMode::Scrub => Some(XTouchDownstreamMsg::Scrub(state)),
+            JogMode::Zoom => Some(XTouchDownstreamMsg::Zoom(state)),
+        }
+    }
+
+    /// The Reaper action one click of the wheel runs, when it runs one.
+    fn action(self, clockwise: bool) -> Option<i32> {
+        match (self, clockwise) {
+            (JogMode::Navigate, _) => None,
+            (JogMode::Scrub, true) => Some(SCRUB_RIGHT_ACTION),
+            (JogMode::Scrub, false) => Some(SCRUB_LEFT_ACTION),
+            (JogMode::Zoom, true) => Some(ZOOM_IN_ACTION),
+            (JogMode::Zoom, false) => Some(ZOOM_OUT_ACTION),
+        }
+    }
+}
+
+/// A Reaper action run by one of the buttons nothing else uses.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ActionBinding {
+    pub button: ActionButton,
+    /// Command ID of the action in the Main section of Reaper's action list
+    pub command_id: i32,
+    /// Light the button while Reaper reports the action's toggle as on, e.g. for the metronome
+    #[serde(default)]
+    pub toggle: bool,
+}
+
+/// Set of messages exchanged between Reaper and TransportManager.
+///
+/// From Reaper these report the state of the transport; towards Reaper they ask for it to change.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransportMsg {
+    Playing(bool),
+    Stop,
+    Recording(bool),
+    /// Whether playback loops over the time selection
+    Repeat(bool),
+    /// Rewinding for as long as this is true
+    Rewind(bool),
+    /// Fast forwarding for as long as this is true
+    FastForward(bool),
+    /// Where the play cursor is, in seconds
+    Position(f32),
+    /// Runs the Reaper action with this command ID; only ever sent towards Reaper
+    Action(i32),
+    /// Whether the toggle of the action with this command ID is on; only ever sent from Reaper
+    ActionToggled(i32, bool),
+}
+
+/// Maps the surface's transport section to Reaper's transport.
+///
+/// TransportManager sits between the surface and the ModeManager. The transport buttons and jog
+/// wheel work the same whichever mode is active, so it handles them itself and passes everything
+/// else from the surface through untouched, in the order it arrived. The play, record and cycle
+/// LEDs follow what Reaper reports rather than the buttons, so they stay right when the transport
+/// is driven from Reaper itself.
+///
+/// The Scrub and Zoom buttons switch what the jog wheel does, and pressing the lit one again
+/// switches it back to moving the play cursor.
+///
+/// The buttons given an ActionBinding run their action when pressed, and those bound with
+/// `toggle` light up while it's on. Any left unbound pass through to the modes like the rest.
+pub struct TransportManager {
+    playing: bool,
+    recording: bool,
+    repeat: bool,
+    position: f32,
+    jog_mode: JogMode,
+    actions: Vec<ActionBinding>,
+    to_reaper: Sender<TransportMsg>,
+    to_xtouch: Sender<XTouchDownstreamMsg>,
+    to_modes: Sender<XTouchUpstreamMsg>,
+}
+
+impl TransportManager {
+    /// Runs until either the surface or Reaper goes away.
+    ///
+    /// `to_xtouch` is usually a clone of the ModeManager's channel to the surface; the LEDs this
+    /// drives are never touched by the modes.
+    pub fn start(
+        from_reaper: Receiver<TransportMsg>,
+        to_reaper: Sender<TransportMsg>,
+        from_xtouch: Receiver<XTouchUpstreamMsg>,
+        to_xtouch: Sender<XTouchDownstreamMsg>,
+        to_modes: Sender<XTouchUpstreamMsg>,
+    ) {
+        Self::start_with_actions(
+            from_reaper,
+            to_reaper,
+            from_xtouch,
+            to_xtouch,
+            to_modes,
+            Vec::new(),
+        );
+    }
+
+    /// Like `start`, with buttons set up to run the Reaper actions in `actions`.
+    pub fn start_with_actions(
+        from_reaper: Receiver<TransportMsg>,
+        to_reaper: Sender<TransportMsg>,
+        from_xtouch: Receiver<XTouchUpstreamMsg>,
+        to_xtouch: Sender<XTouchDownstreamMsg>,
+        to_modes: Sender<XTouchUpstreamMsg>,
+        actions: Vec<ActionBinding>,
+    ) {
+        thread::spawn(move || {
+            let mut manager = TransportManager {
+                playing: false,
+                recording: false,
+                repeat: false,
+                position: 0.0,
+                jog_mode: JogMode::default(),
+                actions,
+                to_reaper,
+                to_xtouch,
+                to_modes,
+            };
+            loop {
+                select! {
+                    recv(from_reaper) -> msg => match msg {
+                        Ok(msg) => manager.handle_reaper_msg(msg),
+                        Err(_) => break,
+                    },
+                    recv(from_xtouch) -> msg => match msg {
+                        Ok(msg) => manager.handle_xtouch_msg(msg),
+                        Err(_) => break,
+                    },
+                }
+            }
+        });
+    }
+
+    fn handle_reaper_msg(&mut self, msg: TransportMsg) {
+        match msg {
+            TransportMsg::Playing(playing) => {
+                self.playing = playing;
+                self.send_downstream(XTouchDownstreamMsg::Play(playing.into()));
+            }
+            TransportMsg::Recording(recording) => {
+                self.recording = recording;
+                self.send_downstream(XTouchDownstreamMsg::Record(recording.into()));
+            }
+            TransportMsg::Repeat(repeat) => {
+                self.repeat = repeat;
+                self.send_downstream(XTouchDownstreamMsg::Cycle(repeat.into()));
+            }
+            TransportMsg::Position(position) => self.position = position,
+            TransportMsg::ActionToggled(command_id, on) => {
+                let lit: Vec<ActionButton> = self
+                    .actions
+                    .iter()
+                    .filter(|binding| binding.toggle && binding.command_id == command_id)
+                    .map(|binding| binding.button)
+                    .collect();
+                for button in lit {
+                    self.send_downstream(XTouchDownstreamMsg::Action(button, on.into()));
+                }
+            }
+            // Reaper reports stopping by no longer playing
+            TransportMsg::Stop
+            | TransportMsg::Rewind(_)
+            | TransportMsg::FastForward(_)
+            | TransportMsg::Action(_) => {}
+        }
+    }
+
+    fn handle_xtouch_msg(&mut self, msg: XTouchUpstreamMsg) {
+        if let Some(mode) = JogMode::for_button(&msg) {
+            self.switch_jog_mode(mode);
+            return;
+        }
+        match msg {
+            XTouchUpstreamMsg::PlayPress => self.send_upstream(TransportMsg::Playing(true)),
+            XTouchUpstreamMsg::StopPress => self.send_upstream(TransportMsg::Stop),
+            XTouchUpstreamMsg::RecordPress => {
+                self.send_upstream(TransportMsg::Recording(!self.recording))
+            }
+            XTouchUpstreamMsg::CyclePress => self.send_upstream(TransportMsg::Repeat(!self.repeat)),
+            // Rewind and fast forward only run while they're held, so they light up while they do
+            XTouchUpstreamMsg::RewindPress => {
+                self.send_upstream(TransportMsg::Rewind(true));
+                self.send_downstream(XTouchDownstreamMsg::Rewind(LEDState::On));
+            }
+            XTouchUpstreamMsg::RewindRelease => {
+                self.send_upstream(TransportMsg::Rewind(false));
+                self.send_downstream(XTouchDownstreamMsg::Rewind(LEDState::Off));
+            }
+            XTouchUpstreamMsg::FastForwardPress => {
+                self.send_upstream(TransportMsg::FastForward(true));
+                self.send_downstream(XTouchDownstreamMsg::FastForward(LEDState::On));
+            }
+            XTouchUpstreamMsg::FastForwardRelease => {
+                self.send_upstream(TransportMsg::FastForward(false));
+                self.send_downstream(XTouchDownstreamMsg::FastForward(LEDState::Off));
+            }
+            // Move our idea of the cursor straight away, so a quick spin adds up even before
+            // Reaper reports where it ended up
+            XTouchUpstreamMsg::JogCW => self.jog(true),
+            XTouchUpstreamMsg::JogCCW => self.jog(false),
+            XTouchUpstreamMsg::ActionPress(ActionPress { button }) if self.is_bound(button) => {
+                for command_id in self.command_ids(button) {
+                    self.send_upstream(TransportMsg::Action(command_id));
+                }
+            }
+            XTouchUpstreamMsg::ActionRelease(ActionRelease { button }) if self.is_bound(button) => {
+            }
+            XTouchUpstreamMsg::PlayRelease
+            | XTouchUpstreamMsg::StopRelease
+            | XTouchUpstreamMsg::RecordRelease
+            | XTouchUpstreamMsg::CycleRelease
+            | XTouchUpstreamMsg::ScrubRelease
+            | XTouchUpstreamMsg::ZoomRelease => {}
+            msg => {
+                // The modes going away leaves the surface with nothing to drive
+                let _ = self.to_modes.send(msg);
+            }
+        }
+    }
+
+    fn is_bound(&self, button: ActionButton) -> bool {
+        self.actions.iter().any(|binding| binding.button == button)
+    }
+
+    // A button bound more than once runs each of its actions in the order they were bound
+    fn command_ids(&self, button: ActionButton) -> Vec<i32> {
+        self.actions
+            .iter()
+            .filter(|binding| binding.button == button)
+            .map(|binding| binding.command_id)
+            .collect()
+    }
+
+    fn jog(&mut self, clockwise: bool) {
+        if let Some(action) = self.jog_mode.action(clockwise) {
+            self.send_upstream(TransportMsg::Action(action));
+            return;
+        }
+        let step = match clockwise {
+            true => JOG_STEP,
+            false => -JOG_STEP,
+        };
+        self.position = (self.position + step).max(0.0);
+        self.send_upstream(TransportMsg::Position(self.position));
+    }
+
+    fn switch_jog_mode(&mut self, mode: JogMode) {
+        let mode = match mode == self.jog_mode {
+            true => JogMode::Navigate,
+            false => mode,
+        };
+        let unlit = self.jog_mode.button_led(LEDState::Off);
+        let lit = mode.button_led(LEDState::On);
+        for msg in unlit.into_iter().chain(lit) {
+            self.send_downstream(msg);
+        }
+        self.jog_mode = mode;
+    }
+
+    fn send_upstream(&self, msg: TransportMsg) {
+        let _ = self.to_reaper.send(msg);
+    }
+
+    fn send_downstream(&self, msg: XTouchDownstreamMsg) {
+        let _ = self.to_xtouch.send(msg);
+    }
+}
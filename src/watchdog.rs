@@ -0,0 +1,262 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::Sender;
+use tracing::{error, info};
+
+/// Shows that a consumer is still getting through its messages, by being beaten each time round
+/// its loop.
+///
+/// A consumer that's waiting on an empty channel doesn't beat, so a heartbeat only counts for
+/// something while there are messages for the consumer to get to.
+///
+/// The heartbeat also tells a consumer when it has been started over in another thread, by
+/// counting up a generation the consumer started at.
+#[derive(Clone)]
+pub struct Heartbeat {
+    last: Arc<Mutex<Instant>>,
+    generation: Arc<AtomicU64>,
+}
+
+impl Heartbeat {
+    /// Starts out as if the consumer had just beaten it.
+    pub fn new() -> Self {
+        Heartbeat {
+            last: Arc::new(Mutex::new(Instant::now())),
+            generation: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// The generation of the consumer beating the heartbeat now, for it to hold on to.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    /// Whether the consumer that started at `generation` has since been started over, in which
+    /// case it should stop and leave its channels to the one started in its place.
+    pub fn superseded(&self, generation: u64) -> bool {
+        self.generation() != generation
+    }
+
+    /// Marks the consumer beating the heartbeat as started over.
+    pub fn supersede(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn beat(&self) {
+        *self.last.lock().unwrap() = Instant::now();
+    }
+
+    /// When the consumer last beat.
+    pub fn last(&self) -> Instant {
+        *self.last.lock().unwrap()
+    }
+}
+
+impl Default for Heartbeat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Every copy of a heartbeat is beaten by the same consumer, so only when matters
+impl fmt::Debug for Heartbeat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Heartbeat")
+            .field("last", &self.last())
+            .finish()
+    }
+}
+
+/// A thread the watchdog keeps an eye on: the channels it reads from and the heartbeat it beats
+/// as it reads them.
+pub struct Consumer {
+    name: String,
+    heartbeat: Heartbeat,
+    channels: Vec<WatchedChannel>,
+    restart: Option<Box<dyn FnMut() + Send>>,
+    stalled: bool,
+}
+
+struct WatchedChannel {
+    name: String,
+    capacity: Option<usize>,
+    len: Box<dyn Fn() -> usize + Send>,
+}
+
+impl Consumer {
+    pub fn new(name: &str, heartbeat: &Heartbeat) -> Self {
+        Consumer {
+            name: name.to_string(),
+            heartbeat: heartbeat.clone(),
+            channels: Vec::new(),
+            restart: None,
+            stalled: false,
+        }
+    }
+
+    /// Counts messages waiting in the channel `sender` feeds, under `name`, as waiting on this
+    /// consumer.
+    ///
+    /// This holds on to a clone of `sender`, so the channel's receiver never sees it disconnect
+    /// while the watchdog is alive.
+    pub fn with_channel<T: Send + 'static>(mut self, name: &str, sender: &Sender<T>) -> Self {
+        let sender = sender.clone();
+        self.channels.push(WatchedChannel {
+            name: name.to_string(),
+            capacity: sender.capacity(),
+            len: Box::new(move || sender.len()),
+        });
+        self
+    }
+
+    /// Runs `restart` each time the consumer is found stuck, to start over a thread in its place.
+    ///
+    /// The stuck thread can't be stopped from outside, so the heartbeat is superseded first. The
+    /// consumer has to check [`Heartbeat::superseded`] each time round its loop and stop once it
+    /// is, or a thread that was only slow would go on sharing its channels with the new one.
+    /// Whatever `restart` starts should read from the same channels and beat the same heartbeat.
+    pub fn with_restart(mut self, restart: impl FnMut() + Send + 'static) -> Self {
+        self.restart = Some(Box::new(restart));
+        self
+    }
+}
+
+/// How full one channel of a stuck consumer was when it was found stuck
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChannelDepth {
+    pub name: String,
+    pub len: usize,
+    /// None for unbounded channels
+    pub capacity: Option<usize>,
+}
+
+/// A change in whether a watched consumer is keeping up with its messages.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WatchdogEvent {
+    /// Messages have been waiting on the consumer for longer than the stall timeout without it
+    /// beating its heartbeat
+    Stalled {
+        consumer: String,
+        silent_for: Duration,
+        channels: Vec<ChannelDepth>,
+        /// Whether a thread was started over in its place
+        restarted: bool,
+    },
+    /// A stalled consumer has beaten its heartbeat again, or has nothing left waiting on it
+    Recovered { consumer: String },
+}
+
+/// Tells when a thread has stopped getting through the messages sent to it, e.g. because it has
+/// deadlocked, which otherwise only shows as the messages piling up.
+pub struct Watchdog {
+    stall_timeout: Duration,
+    consumers: Mutex<Vec<Consumer>>,
+}
+
+impl Watchdog {
+    pub fn new(stall_timeout: Duration) -> Self {
+        Watchdog {
+            stall_timeout,
+            consumers: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn watch(&self, consumer: Consumer) {
+        self.consumers.lock().unwrap().push(consumer);
+    }
+
+    /// Returns Stalled the first time a consumer is found stuck, and Recovered once it isn't
+    /// anymore. A consumer with a restart has it run when it's found stuck.
+    pub fn check(&self, now: Instant) -> Vec<WatchdogEvent> {
+        let mut events = Vec::new();
+        for consumer in self.consumers.lock().unwrap().iter_mut() {
+            let silent_for = now.saturating_duration_since(consumer.heartbeat.last());
+            let channels: Vec<ChannelDepth> = consumer
+                .channels
+                .iter()
+                .map(|channel| ChannelDepth {
+                    name: channel.name.clone(),
+                    len: (channel.len)(),
+                    capacity: channel.capacity,
+                })
+                .collect();
+            let waiting = channels.iter().any(|channel| channel.len > 0);
+            match (consumer.stalled, waiting && silent_for > self.stall_timeout) {
+                (false, true) => {
+                    consumer.stalled = true;
+                    let restarted = match &mut consumer.restart {
+                        Some(restart) => {
+                            // The new thread gets a stall timeout of its own to catch up in
+                            consumer.heartbeat.supersede();
+                            consumer.heartbeat.beat();
+                            restart();
+                            true
+                        }
+                        None => false,
+                    };
+                    events.push(WatchdogEvent::Stalled {
+                        consumer: consumer.name.clone(),
+                        silent_for,
+                        channels,
+                        restarted,
+                    });
+                }
+                (true, false) => {
+                    consumer.stalled = false;
+                    events.push(WatchdogEvent::Recovered {
+                        consumer: consumer.name.clone(),
+                    });
+                }
+                _ => {}
+            }
+        }
+        events
+    }
+
+    /// Spawns a thread that checks on the consumers every `interval` and logs what it finds.
+    pub fn start(self: &Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        let watchdog = self.clone();
+        thread::spawn(move || {
+            loop {
+                thread::sleep(interval);
+                for event in watchdog.check(Instant::now()) {
+                    log_event(&event);
+                }
+            }
+        })
+    }
+}
+
+fn log_event(event: &WatchdogEvent) {
+    match event {
+        WatchdogEvent::Stalled {
+            consumer,
+            silent_for,
+            channels,
+            restarted,
+        } => {
+            let channels = channels
+                .iter()
+                .map(|channel| match channel.capacity {
+                    Some(capacity) => format!("{} {}/{}", channel.name, channel.len, capacity),
+                    None => format!("{} {}", channel.name, channel.len),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            error!(
+                consumer = %consumer,
+                silent_ms = silent_for.as_millis() as u64,
+                channels = %channels,
+                restarted,
+                "Consumer is stuck with messages waiting on it"
+            );
+        }
+        WatchdogEvent::Recovered { consumer } => {
+            info!(consumer = %consumer, "Consumer is getting through its messages again");
+        }
+    }
+}
@@ -0,0 +1,21 @@
+// Tests for encoding the mode and bank onto the XTouch assignment display
+
+use arpad_rust::midi::xtouch::{AssignmentDisplayMsg, seven_segment_code};
+
+#[test]
+fn test_mode_bank_abbreviations() {
+    assert_eq!(AssignmentDisplayMsg::mode_bank('V', 0).text, ['V', '1']);
+    assert_eq!(AssignmentDisplayMsg::mode_bank('S', 1).text, ['S', '2']);
+    // Two-digit banks don't leave room for the mode
+    assert_eq!(AssignmentDisplayMsg::mode_bank('V', 9).text, ['1', '0']);
+    assert_eq!(AssignmentDisplayMsg::mode_bank('V', 99).text, ['-', '-']);
+}
+
+#[test]
+fn test_seven_segment_codes() {
+    assert_eq!(seven_segment_code('A'), 0x01);
+    assert_eq!(seven_segment_code('v'), 0x16);
+    assert_eq!(seven_segment_code('1'), 0x31);
+    assert_eq!(seven_segment_code('-'), 0x2D);
+    assert_eq!(seven_segment_code('~'), 0x20);
+}
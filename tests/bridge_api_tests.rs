@@ -0,0 +1,162 @@
+// Tests for the bridge's own OSC API
+//
+// These check what each route replies with, that the modes are switched as if from the surface,
+// and that the API answers over UDP whoever asks.
+
+use std::net::UdpSocket;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crossbeam_channel::unbounded;
+use rosc::{OscMessage, OscPacket, OscType};
+
+use arpad_rust::midi::xtouch::XTouchUpstreamMsg;
+use arpad_rust::modes::mode_manager::{ModeBridge, ModeManager, ModeOptions, ModeStatus};
+use arpad_rust::osc::bridge_api::{BridgeApi, BridgeState};
+use arpad_rust::osc::transport::UdpTransport;
+use arpad_rust::track::track::{DataPayload, Direction, TrackDataMsg, TrackMsg};
+
+const TIMEOUT: Duration = Duration::from_millis(200);
+
+#[derive(Clone, Default)]
+struct FakeState {
+    switched: Arc<Mutex<Vec<String>>>,
+}
+
+impl BridgeState for FakeState {
+    fn mode(&self) -> String {
+        "sends".to_string()
+    }
+
+    fn channel_tracks(&self) -> Vec<String> {
+        vec!["track-a".to_string(), String::new()]
+    }
+
+    fn switch_mode(&self, name: &str) -> bool {
+        self.switched.lock().unwrap().push(name.to_string());
+        name == "fx"
+    }
+}
+
+fn request(addr: &str, args: Vec<OscType>) -> OscMessage {
+    OscMessage {
+        addr: addr.to_string(),
+        args,
+    }
+}
+
+fn reply(addr: &str, value: &str) -> OscMessage {
+    request(addr, vec![OscType::String(value.to_string())])
+}
+
+#[test]
+fn test_queries_are_replied_to_on_their_own_address() {
+    let api = BridgeApi::new(FakeState::default());
+    assert_eq!(
+        api.handle(&request("/arpad/mode", vec![])),
+        vec![reply("/arpad/mode", "sends")]
+    );
+    assert_eq!(
+        api.handle(&request("/arpad/channels", vec![])),
+        vec![
+            reply("/arpad/channel/0/track", "track-a"),
+            reply("/arpad/channel/1/track", ""),
+        ]
+    );
+    assert_eq!(
+        api.handle(&request("/arpad/channel/0/track", vec![])),
+        vec![reply("/arpad/channel/0/track", "track-a")]
+    );
+    assert_eq!(
+        api.handle(&request("/arpad/channel/2/track", vec![])),
+        vec![]
+    );
+    assert_eq!(api.handle(&request("/track/abc/volume", vec![])), vec![]);
+}
+
+#[test]
+fn test_mode_is_switched_by_name() {
+    let state = FakeState::default();
+    let api = BridgeApi::new(state.clone());
+    assert_eq!(api.handle(&reply("/arpad/mode", "fx")), vec![]);
+    assert_eq!(api.handle(&reply("/arpad/mode", "nonsense")), vec![]);
+    assert_eq!(
+        api.handle(&request("/arpad/mode", vec![OscType::Int(2)])),
+        vec![]
+    );
+    assert_eq!(*state.switched.lock().unwrap(), vec!["fx", "nonsense"]);
+}
+
+#[test]
+fn test_mode_bridge_presses_the_modes_button() {
+    let (to_modes, from_api) = unbounded();
+    let bridge = ModeBridge::new(ModeStatus::new(), to_modes);
+    assert!(bridge.switch_mode("sends"));
+    assert!(matches!(
+        from_api.try_recv(),
+        Ok(XTouchUpstreamMsg::SendPress)
+    ));
+    assert!(matches!(
+        from_api.try_recv(),
+        Ok(XTouchUpstreamMsg::SendRelease)
+    ));
+
+    // Neither of these has a button to press
+    assert!(!bridge.switch_mode("motu_vol_pan"));
+    assert!(!bridge.switch_mode("nonsense"));
+    assert!(from_api.try_recv().is_err());
+}
+
+#[test]
+fn test_mode_bridge_shows_what_the_modes_are_showing() {
+    let (reaper_tx, reaper_rx) = unbounded();
+    let (xtouch_tx, xtouch_rx) = unbounded();
+    let (to_reaper_tx, _to_reaper_rx) = unbounded();
+    let (to_xtouch_tx, _to_xtouch_rx) = unbounded();
+    let status = ModeStatus::new();
+    ModeManager::start_with_options(
+        reaper_rx,
+        to_reaper_tx,
+        xtouch_rx,
+        to_xtouch_tx,
+        ModeOptions {
+            num_channels: 2,
+            status: Some(status.clone()),
+            ..Default::default()
+        },
+    );
+    reaper_tx
+        .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: "track-a".into(),
+            direction: Direction::Downstream,
+            data: DataPayload::ReaperTrackIndex(Some(0)),
+        }))
+        .unwrap();
+    std::thread::sleep(Duration::from_millis(50));
+
+    let bridge = ModeBridge::new(status, xtouch_tx);
+    assert_eq!(bridge.mode(), "vol_pan");
+    assert_eq!(
+        bridge.channel_tracks(),
+        vec!["track-a".to_string(), String::new()]
+    );
+}
+
+#[test]
+fn test_api_replies_over_udp_to_whoever_asked() {
+    let server = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let server_addr = server.local_addr().unwrap();
+    BridgeApi::new(FakeState::default()).start(Arc::new(UdpTransport::new(server)));
+
+    let client = UdpSocket::bind("127.0.0.1:0").unwrap();
+    client.set_read_timeout(Some(TIMEOUT)).unwrap();
+    let packet =
+        rosc::encoder::encode(&OscPacket::Message(request("/arpad/mode", vec![]))).unwrap();
+    client.send_to(&packet, server_addr).unwrap();
+
+    let mut buf = [0u8; rosc::decoder::MTU];
+    let (size, from) = client.recv_from(&mut buf).unwrap();
+    assert_eq!(from, server_addr);
+    let (_, packet) = rosc::decoder::decode_udp(&buf[..size]).unwrap();
+    assert_eq!(packet, OscPacket::Message(reply("/arpad/mode", "sends")));
+}
@@ -0,0 +1,113 @@
+// Tests for sending several sets to Reaper in one bundle
+
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use rosc::{OscPacket, OscType};
+
+use arpad_rust::osc::error::OscError;
+use arpad_rust::osc::generated_osc::{Reaper, TrackMuteArgs, TrackPanArgs, TrackVolumeArgs};
+use arpad_rust::osc::transport::OscTransport;
+
+// Keeps every packet sent
+#[derive(Default)]
+struct RecordingTransport {
+    sent: Mutex<Vec<OscPacket>>,
+}
+
+impl OscTransport for RecordingTransport {
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        let (_, packet) = rosc::decoder::decode_udp(packet).unwrap();
+        self.sent.lock().unwrap().push(packet);
+        Ok(())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+fn setup() -> (Reaper, Arc<RecordingTransport>) {
+    let transport = Arc::new(RecordingTransport::default());
+    (Reaper::new(transport.clone()), transport)
+}
+
+// The address and arguments of each message in a packet
+fn messages(packet: &OscPacket) -> Vec<(String, Vec<OscType>)> {
+    match packet {
+        OscPacket::Message(msg) => vec![(msg.addr.clone(), msg.args.clone())],
+        OscPacket::Bundle(bundle) => bundle.content.iter().flat_map(messages).collect(),
+    }
+}
+
+#[test]
+fn test_commit_sends_sets_in_one_bundle() {
+    let (reaper, transport) = setup();
+    let mut bundle = reaper.bundle();
+    bundle
+        .set(
+            &reaper.track_volume("abc".into()),
+            TrackVolumeArgs::new(0.5),
+        )
+        .set(&reaper.track_pan("abc".into()), TrackPanArgs::new(-0.25))
+        .set(&reaper.track_mute("abc".into()), TrackMuteArgs::new(true));
+    assert_eq!(bundle.len(), 3);
+    let results = bundle.commit().unwrap();
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(Result::is_ok));
+
+    let sent = transport.sent.lock().unwrap();
+    assert_eq!(sent.len(), 1);
+    assert!(matches!(sent[0], OscPacket::Bundle(_)));
+    assert_eq!(
+        messages(&sent[0]),
+        vec![
+            ("/track/abc/volume".to_string(), vec![OscType::Float(0.5)]),
+            ("/track/abc/pan".to_string(), vec![OscType::Float(-0.25)]),
+            ("/track/abc/mute".to_string(), vec![OscType::Bool(true)]),
+        ]
+    );
+}
+
+#[test]
+fn test_commit_reports_each_set_that_cant_be_built() {
+    let (reaper, transport) = setup();
+    let mut bundle = reaper.bundle();
+    bundle
+        .set(
+            &reaper.track_volume("abc".into()),
+            TrackVolumeArgs::new(0.5),
+        )
+        // A GUID with a '/' in it would address some other route
+        .set(
+            &reaper.track_volume("a/b".into()),
+            TrackVolumeArgs::new(0.1),
+        )
+        .set(&reaper.track_pan("abc".into()), TrackPanArgs::new(0.0));
+    let results = bundle.commit().unwrap();
+    assert!(results[0].is_ok());
+    assert!(matches!(results[1], Err(OscError::AddressFormat(_))));
+    assert!(results[2].is_ok());
+
+    let sent = transport.sent.lock().unwrap();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(messages(&sent[0]).len(), 2);
+}
+
+#[test]
+fn test_lone_set_is_sent_as_plain_message() {
+    let (reaper, transport) = setup();
+    assert!(reaper.bundle().commit().unwrap().is_empty());
+    assert!(transport.sent.lock().unwrap().is_empty());
+
+    let mut bundle = reaper.bundle();
+    bundle.set(
+        &reaper.track_volume("abc".into()),
+        TrackVolumeArgs::new(0.5),
+    );
+    bundle.commit().unwrap();
+    let sent = transport.sent.lock().unwrap();
+    assert_eq!(sent.len(), 1);
+    assert!(matches!(sent[0], OscPacket::Message(_)));
+}
@@ -0,0 +1,136 @@
+// Tests for capturing OSC and MIDI traffic and replaying it
+
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use arpad_rust::capture::{Captured, CapturedEvent, Recorder, parse_speed, read_capture, replay};
+
+// Collects everything written to it where the test can see it
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn event(at_ms: u64, data: Captured) -> CapturedEvent {
+    CapturedEvent {
+        at: Duration::from_millis(at_ms),
+        data,
+    }
+}
+
+#[test]
+fn test_events_round_trip_through_lines() {
+    let osc = event(1, Captured::Osc(vec![0x2f, 0x00, 0xff]));
+    assert_eq!(osc.to_line(), "1000 osc 2f00ff");
+    assert_eq!(CapturedEvent::from_line(&osc.to_line()), Some(osc));
+
+    let midi = event(
+        250,
+        Captured::Midi {
+            unit: 1,
+            message: vec![0x90, 0x10, 0x7f],
+        },
+    );
+    assert_eq!(midi.to_line(), "250000 midi:1 90107f");
+    assert_eq!(CapturedEvent::from_line(&midi.to_line()), Some(midi));
+
+    for bad in [
+        "",
+        "12",
+        "12 sysex 00",
+        "12 osc 0",
+        "12 midi 90107f",
+        "12 midi:x 90107f",
+        "12 osc:1 00",
+        "12 osc zz",
+        "x osc 00",
+        "1 osc 00 00",
+    ] {
+        assert_eq!(CapturedEvent::from_line(bad), None, "{:?}", bad);
+    }
+}
+
+#[test]
+fn test_recorder_writes_a_readable_capture() {
+    let buffer = SharedBuffer::default();
+    let recorder = Recorder::new(buffer.clone());
+    recorder.record_osc(b"/num_tracks").unwrap();
+    // Clones share the capture
+    recorder
+        .clone()
+        .record_midi(0, &[0xb0, 0x10, 0x01])
+        .unwrap();
+
+    let written = buffer.0.lock().unwrap().clone();
+    let events = read_capture(written.as_slice()).unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].data, Captured::Osc(b"/num_tracks".to_vec()));
+    assert_eq!(
+        events[1].data,
+        Captured::Midi {
+            unit: 0,
+            message: vec![0xb0, 0x10, 0x01]
+        }
+    );
+    assert!(events[0].at <= events[1].at);
+}
+
+#[test]
+fn test_read_capture_reports_the_bad_line() {
+    let err = read_capture("1 osc 00\n\n3 osc nope\n".as_bytes()).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(err.to_string().contains("line 3"), "{}", err);
+}
+
+#[test]
+fn test_replay_keeps_spacing_at_the_given_speed() {
+    let events = vec![
+        event(0, Captured::Osc(vec![1])),
+        event(
+            200,
+            Captured::Midi {
+                unit: 0,
+                message: vec![2],
+            },
+        ),
+        event(400, Captured::Osc(vec![3])),
+    ];
+    let start = Instant::now();
+    let mut seen = Vec::new();
+    replay(&events, 4.0, |event| {
+        seen.push((start.elapsed(), event.clone()))
+    });
+
+    assert_eq!(
+        seen.iter().map(|(_, event)| event).collect::<Vec<_>>(),
+        events.iter().collect::<Vec<_>>()
+    );
+    // 400ms of capture at 4x takes 100ms
+    assert!(seen[1].0 >= Duration::from_millis(50));
+    assert!(seen[2].0 >= Duration::from_millis(100));
+    assert!(seen[2].0 < Duration::from_millis(400));
+}
+
+#[test]
+#[should_panic(expected = "replay speed must be positive")]
+fn test_replay_rejects_non_positive_speed() {
+    replay(&[], 0.0, |_| {});
+}
+
+#[test]
+fn test_parse_speed_only_takes_positive_speeds() {
+    assert_eq!(parse_speed("2"), Ok(2.0));
+    assert_eq!(parse_speed("inf"), Ok(f64::INFINITY));
+    for bad in ["0", "-1", "NaN", "fast"] {
+        assert!(parse_speed(bad).is_err(), "{:?}", bad);
+    }
+}
@@ -0,0 +1,126 @@
+// Tests for feeding bounded channels under each backpressure policy
+
+use crossbeam_channel::bounded;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use arpad_rust::channel::{Backpressure, BoundedSender};
+use arpad_rust::track::track::{
+    DataPayload, Direction, SendLevel, TrackDataMsg, TrackMsg, TrackQuery,
+};
+
+fn data(guid: &str, data: DataPayload) -> TrackMsg {
+    TrackMsg::TrackDataMsg(TrackDataMsg {
+        guid: guid.into(),
+        direction: Direction::Downstream,
+        data,
+    })
+}
+
+fn send_level(send_index: i32, level: f32) -> DataPayload {
+    DataPayload::SendLevel(SendLevel { send_index, level })
+}
+
+#[test]
+fn test_drop_policy_counts_what_doesnt_fit() {
+    let (tx, rx) = bounded(1);
+    let sender = BoundedSender::new("test", tx, Backpressure::Drop);
+    sender.send(1);
+    sender.send(2);
+    sender.send(3);
+    assert_eq!(sender.dropped(), 2);
+    assert_eq!(rx.try_iter().collect::<Vec<_>>(), vec![1]);
+
+    // Once the receiver has gone, everything is dropped
+    drop(rx);
+    sender.send(4);
+    assert_eq!(sender.dropped(), 3);
+}
+
+#[test]
+fn test_block_policy_waits_for_room_before_dropping() {
+    let (tx, rx) = bounded(1);
+    let sender = BoundedSender::new("test", tx, Backpressure::Block(20));
+    sender.send(1);
+
+    let start = Instant::now();
+    sender.send(2);
+    assert!(start.elapsed() >= Duration::from_millis(20));
+    assert_eq!(sender.dropped(), 1);
+
+    // Room that frees up in time is used
+    let consumer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(5));
+        rx.recv_timeout(Duration::from_secs(1)).unwrap();
+        rx
+    });
+    let sender = BoundedSender::new("test", sender.sender().clone(), Backpressure::Block(1000));
+    sender.send(3);
+    let rx = consumer.join().unwrap();
+    assert_eq!(sender.dropped(), 0);
+    assert_eq!(rx.try_iter().collect::<Vec<_>>(), vec![3]);
+}
+
+#[test]
+fn test_coalesce_policy_keeps_latest_per_key_in_order() {
+    let (tx, rx) = bounded(1);
+    let sender = BoundedSender::new("test", tx, Backpressure::CoalesceLatest)
+        .with_coalesce_key(TrackMsg::coalesce_key);
+    sender.send(data("a", DataPayload::Volume(0.1)));
+    sender.send(data("a", DataPayload::Volume(0.2)));
+    sender.send(data("a", DataPayload::Name("Drums".to_string())));
+    sender.send(data("a", DataPayload::Volume(0.3)));
+    sender.send(data("b", DataPayload::Volume(0.4)));
+    assert_eq!(sender.held(), 3);
+    assert_eq!(sender.dropped(), 0);
+
+    let received: Vec<String> = (0..4)
+        .map(|_| {
+            let msg = rx.recv_timeout(Duration::from_secs(1)).unwrap();
+            let TrackMsg::TrackDataMsg(msg) = msg else {
+                panic!("Expected track data but got {:?}", msg);
+            };
+            format!("{} {:?}", msg.guid, msg.data)
+        })
+        .collect();
+    assert_eq!(
+        received,
+        vec![
+            "a Volume(0.1)",
+            "a Volume(0.3)",
+            "a Name(\"Drums\")",
+            "b Volume(0.4)"
+        ]
+    );
+    assert!(rx.recv_timeout(Duration::from_millis(200)).is_err());
+}
+
+#[test]
+fn test_coalesce_keys_tell_apart_what_messages_set() {
+    let key = |msg: TrackMsg| msg.coalesce_key();
+    assert_eq!(
+        key(data("a", DataPayload::Volume(0.1))),
+        key(data("a", DataPayload::Volume(0.9)))
+    );
+    assert_ne!(
+        key(data("a", DataPayload::Volume(0.1))),
+        key(data("b", DataPayload::Volume(0.1)))
+    );
+    assert_ne!(
+        key(data("a", DataPayload::Volume(0.1))),
+        key(data("a", DataPayload::Pan(0.1)))
+    );
+    // Each send has its own level
+    assert_ne!(
+        key(data("a", send_level(0, 0.5))),
+        key(data("a", send_level(1, 0.5)))
+    );
+    // Anything that isn't a track's state is always kept
+    assert_eq!(
+        key(TrackMsg::TrackQuery(TrackQuery {
+            guid: "a".into(),
+            direction: Direction::Downstream,
+        })),
+        None
+    );
+}
@@ -0,0 +1,125 @@
+// Tests for the commands of the arpad binary besides running the bridge
+
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+fn arpad(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_arpad"))
+        .args(args)
+        .output()
+        .expect("couldn't run arpad")
+}
+
+// A file of its own for each test, so they can run in parallel
+fn temp_path(test: &str, extension: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "arpad-cli-{}-{}.{}",
+        std::process::id(),
+        test,
+        extension
+    ));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn test_inspect_spec_lists_the_built_in_routes() {
+    let output = arpad(&["inspect-spec"]);
+    assert!(output.status.success());
+    let listing = String::from_utf8(output.stdout).unwrap();
+    assert!(listing.contains(
+        "/track/{track_guid}/volume -> track_volume(volume: float) [queryable, readable, writeable]\n"
+    ));
+    assert!(listing.contains("context Track(track_guid) under /track/{}\n"));
+}
+
+#[test]
+fn test_inspect_spec_reports_what_is_wrong_with_a_spec() {
+    let spec = temp_path("inspect-bad", "yaml");
+    std::fs::write(
+        &spec,
+        "- osc_address: \"stop\"\n  params: []\n  arguments: []\n  access_tags: [writeable]\n",
+    )
+    .unwrap();
+    let output = arpad(&["inspect-spec", spec.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("error: stop: the address must start with '/'\n")
+    );
+}
+
+#[test]
+fn test_gen_osc_generates_the_checked_in_reaperosc() {
+    let out = temp_path("gen-osc", "ReaperOSC");
+    let output = arpad(&[
+        "gen-osc",
+        "spec/reaper_osc.yaml",
+        "--emit",
+        "reaperosc",
+        "-o",
+        out.to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+    assert_eq!(
+        std::fs::read_to_string(&out).unwrap(),
+        std::fs::read_to_string("spec/reaper_osc.ReaperOSC").unwrap()
+    );
+}
+
+#[test]
+fn test_headless_flag_can_turn_headless_off() {
+    // The bridge gets as far as the bad OSC address, so the flags themselves were accepted
+    for flag in ["--headless", "--headless=true", "--headless=false"] {
+        let output = arpad(&[flag, "--osc-address", "nowhere"]);
+        assert!(!output.status.success());
+        assert!(
+            String::from_utf8(output.stderr)
+                .unwrap()
+                .contains("couldn't parse address \"nowhere\""),
+            "{} wasn't accepted",
+            flag
+        );
+    }
+    let output = arpad(&["--headless=maybe", "--osc-address", "nowhere"]);
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("invalid value 'maybe' for '--headless")
+    );
+}
+
+#[test]
+fn test_bridge_refuses_a_spec_it_cannot_load() {
+    let spec = temp_path("run-bad-spec", "yaml");
+    std::fs::write(
+        &spec,
+        "- osc_address: \"stop\"\n  params: []\n  arguments: []\n  access_tags: [writeable]\n",
+    )
+    .unwrap();
+    let output = arpad(&["--spec", spec.to_str().unwrap()]);
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("error: stop: the address must start with '/'")
+    );
+}
+
+#[test]
+fn test_replay_needs_a_positive_speed() {
+    let capture = temp_path("replay-speed", "log");
+    std::fs::write(&capture, "0 osc 2f6e756d5f747261636b730000000000\n").unwrap();
+    for bad in ["0", "-2", "NaN"] {
+        let output = arpad(&["replay", capture.to_str().unwrap(), "--speed", bad]);
+        assert!(!output.status.success());
+        assert!(
+            String::from_utf8(output.stderr)
+                .unwrap()
+                .contains("invalid value"),
+            "--speed {} was accepted",
+            bad
+        );
+    }
+}
@@ -0,0 +1,155 @@
+// Tests for layering config files over the embedded defaults
+
+use std::collections::BTreeMap;
+
+use arpad_rust::channel::Backpressure;
+use arpad_rust::config::{Config, DEFAULT_SPEC};
+use arpad_rust::midi::xtouch::ActionButton;
+use arpad_rust::modes::mode_manager::BarrierRecovery;
+use arpad_rust::modes::taper::Taper;
+use arpad_rust::modes::track_mapping::MappingPolicy;
+use arpad_rust::transport::ActionBinding;
+
+#[test]
+fn test_embedded_defaults_load_without_config_file() {
+    let config = Config::load(None).unwrap();
+    assert_eq!(
+        config,
+        Config {
+            osc_address: "0.0.0.0:9000".to_string(),
+            reaper_host: "127.0.0.1".to_string(),
+            reaper_port: 8000,
+            address_prefix: None,
+            transport: "udp".to_string(),
+            headless: false,
+            dry_run: false,
+            surface_ports: vec![],
+            strict_routing: false,
+            spec_file: None,
+            gate_max_buffered: 0,
+            gate_overflow_policy: "drop_oldest".to_string(),
+            gate_purge_interval_secs: 10,
+            mapping_policy: MappingPolicy::ByIndex,
+            fader_taper: Taper::ReaperDb,
+            subscribers: vec![],
+            coalesce_window_ms: 20,
+            echo_ttl_ms: 100,
+            backpressure: Backpressure::CoalesceLatest,
+            follow_selection: false,
+            show_markers: false,
+            barrier_timeout_ms: 5000,
+            barrier_recovery: BarrierRecovery::Retry(2),
+            resync_interval_ms: 2000,
+            action_buttons: vec![],
+            log_level: "info".to_string(),
+            log_format: "text".to_string(),
+            metrics_interval_secs: 0,
+            watchdog_stall_ms: 5000,
+            watchdog_restart: false,
+            session_file: None,
+            session_save_interval_secs: 5,
+            scenes_file: None,
+            api_address: None,
+        }
+    );
+}
+
+#[test]
+fn test_config_file_overrides_only_keys_it_sets() {
+    let config = Config::from_layers(Some(b"transport: tcp\nheadless: true\n")).unwrap();
+    assert_eq!(config.osc_address, "0.0.0.0:9000");
+    assert_eq!(config.transport, "tcp");
+    assert!(config.headless);
+    assert!(!config.strict_routing);
+
+    // An empty file is the same as no file at all
+    assert_eq!(
+        Config::from_layers(Some(b"")).unwrap(),
+        Config::load(None).unwrap()
+    );
+}
+
+#[test]
+fn test_config_file_sets_mapping_policy() {
+    let config = Config::from_layers(Some(b"mapping_policy: !first_tracks 16\n")).unwrap();
+    assert_eq!(config.mapping_policy, MappingPolicy::FirstTracks(16));
+
+    let config =
+        Config::from_layers(Some(b"mapping_policy: !restore {0: track-a, 3: track-b}\n")).unwrap();
+    assert_eq!(
+        config.mapping_policy,
+        MappingPolicy::Restore(BTreeMap::from([
+            (0, "track-a".into()),
+            (3, "track-b".into())
+        ]))
+    );
+}
+
+#[test]
+fn test_config_file_sets_subscribers() {
+    let config = Config::from_layers(Some(b"subscribers: [\"127.0.0.1:9001\"]\n")).unwrap();
+    assert_eq!(config.subscribers, vec!["127.0.0.1:9001"]);
+}
+
+#[test]
+fn test_config_file_caps_gate_buffers() {
+    let config = Config::from_layers(Some(
+        b"gate_max_buffered: 64\ngate_overflow_policy: fail_and_log\n",
+    ))
+    .unwrap();
+    assert_eq!(config.gate_max_buffered, 64);
+    assert_eq!(config.gate_overflow_policy, "fail_and_log");
+}
+
+#[test]
+fn test_config_file_sets_backpressure() {
+    let config = Config::from_layers(Some(b"backpressure: !block 50\n")).unwrap();
+    assert_eq!(config.backpressure, Backpressure::Block(50));
+    let config = Config::from_layers(Some(b"backpressure: drop\n")).unwrap();
+    assert_eq!(config.backpressure, Backpressure::Drop);
+}
+
+#[test]
+fn test_config_file_sets_action_buttons() {
+    let config = Config::from_layers(Some(
+        br#"
+action_buttons:
+  - button: click
+    command_id: 40364
+    toggle: true
+  - button: save
+    command_id: 40026
+"#,
+    ))
+    .unwrap();
+    assert_eq!(
+        config.action_buttons,
+        vec![
+            ActionBinding {
+                button: ActionButton::Click,
+                command_id: 40364,
+                toggle: true,
+            },
+            ActionBinding {
+                button: ActionButton::Save,
+                command_id: 40026,
+                toggle: false,
+            },
+        ]
+    );
+    assert!(
+        Config::from_layers(Some(b"action_buttons: [{button: pan, command_id: 1}]\n")).is_err()
+    );
+}
+
+#[test]
+fn test_config_file_rejects_unknown_keys() {
+    assert!(Config::from_layers(Some(b"osc_adress: 127.0.0.1:9000\n")).is_err());
+    assert!(Config::from_layers(Some(b"headless: maybe\n")).is_err());
+}
+
+#[test]
+fn test_embedded_spec_is_included() {
+    let spec = std::str::from_utf8(DEFAULT_SPEC).unwrap();
+    assert!(spec.contains("osc_address: \"/track/{track_guid}/index\""));
+}
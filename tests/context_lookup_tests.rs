@@ -0,0 +1,101 @@
+// Tests for looking up the contexts something is bound under, without an accessor
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use arpad_rust::guid::TrackGuid;
+use arpad_rust::osc::generated_osc::{Reaper, context, dispatch_osc};
+use arpad_rust::osc::transport::NullTransport;
+use arpad_rust::traits::{Bind, Subscribe};
+
+fn reaper() -> Reaper {
+    Reaper::new(Arc::new(NullTransport))
+}
+
+#[test]
+fn test_track_is_found_once_anything_is_bound_to_it() {
+    let reaper = reaper();
+    let guid = TrackGuid::from("track-a");
+    assert_eq!(reaper.try_track(guid.clone()), None);
+
+    reaper.track_volume(guid.clone()).bind(|_| {});
+    assert_eq!(
+        reaper.try_track(guid.clone()),
+        Some(context::Track {
+            track_guid: guid.clone()
+        })
+    );
+    assert_eq!(reaper.try_track(TrackGuid::from("track-b")), None);
+}
+
+#[test]
+fn test_tracks_lists_each_bound_track_once() {
+    let reaper = reaper();
+    for guid in ["track-a", "track-b"] {
+        reaper.track_volume(guid.into()).bind(|_| {});
+        reaper.track_pan(guid.into()).bind(|_| {});
+    }
+    let tracks: HashSet<context::Track> = reaper.tracks().collect();
+    assert_eq!(
+        tracks,
+        HashSet::from([
+            context::Track {
+                track_guid: "track-a".into()
+            },
+            context::Track {
+                track_guid: "track-b".into()
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_sends_are_told_apart_by_index() {
+    let reaper = reaper();
+    reaper.track_send_volume("track-a".into(), 1).bind(|_| {});
+    let sends: Vec<context::TrackSend> = reaper.track_sends().collect();
+    assert_eq!(
+        sends,
+        vec![context::TrackSend {
+            track_guid: "track-a".into(),
+            send_index: 1,
+        }]
+    );
+    assert!(reaper.try_track_send("track-a".into(), 0).is_none());
+}
+
+#[test]
+fn test_unbinding_everything_forgets_the_track() {
+    let reaper = reaper();
+    let mut volume = reaper.track_volume("track-a".into());
+    let subscription = volume.subscribe(|_| {});
+    assert_eq!(reaper.tracks().count(), 1);
+
+    volume.unbind(subscription);
+    assert_eq!(reaper.tracks().count(), 0);
+}
+
+#[test]
+fn test_unbinding_contexts_keeps_the_project_wide_routes() {
+    let reaper = reaper();
+    let (tx, rx) = std::sync::mpsc::channel();
+    reaper.track_volume("track-a".into()).bind(|_| {});
+    reaper.track_send_volume("track-a".into(), 0).bind(|_| {});
+    reaper
+        .master_volume()
+        .bind(move |args| tx.send(args.volume).unwrap());
+
+    reaper.unbind_contexts();
+    assert_eq!(reaper.tracks().count(), 0);
+    assert_eq!(reaper.track_sends().count(), 0);
+    // The master track isn't a context, so its handler is still bound
+    dispatch_osc(
+        &reaper,
+        rosc::OscMessage {
+            addr: "/master/volume".to_string(),
+            args: vec![rosc::OscType::Float(0.5)],
+        },
+        |_, _| {},
+    );
+    assert_eq!(rx.try_iter().collect::<Vec<_>>(), vec![0.5]);
+}
@@ -0,0 +1,164 @@
+// Tests for keeping the XTouch going across being unplugged
+//
+// Connecting to a real device needs the hardware, so these cover the parts that don't: the
+// backoff between reconnection attempts, the state replayed once the device returns, the messages
+// kept off the wire for changing nothing, and the manager standing in for a device that isn't
+// there.
+
+use crossbeam_channel::unbounded;
+use std::time::Duration;
+
+use arpad_rust::midi::device_manager::{Backoff, SurfaceCache, XTouchDeviceManager};
+use arpad_rust::midi::xtouch::{
+    ActionButton, AssignmentDisplayMsg, ChannelIndex, FaderAbsMsg, LEDState, MuteLEDMsg,
+    ScribbleColor, ScribbleStripMsg, XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
+use arpad_rust::modes::mode_manager::Barrier;
+
+fn fader(idx: u8, value: f64) -> XTouchDownstreamMsg {
+    XTouchDownstreamMsg::FaderAbs(FaderAbsMsg {
+        idx: ChannelIndex::new(idx),
+        value,
+    })
+}
+
+#[test]
+fn test_backoff_doubles_up_to_its_ceiling() {
+    let mut backoff = Backoff::new(Duration::from_millis(100), Duration::from_millis(500));
+    let delays: Vec<_> = (0..5).map(|_| backoff.next_delay()).collect();
+    assert_eq!(
+        delays,
+        [100, 200, 400, 500, 500]
+            .map(Duration::from_millis)
+            .to_vec()
+    );
+
+    backoff.reset();
+    assert_eq!(backoff.next_delay(), Duration::from_millis(100));
+}
+
+#[test]
+fn test_cache_replays_latest_state_of_each_element() {
+    let mut cache = SurfaceCache::new();
+    cache.record(&XTouchDownstreamMsg::Barrier(Barrier::new()));
+    assert!(cache.is_empty(), "barriers don't leave anything shown");
+
+    cache.record(&fader(0, 0.2));
+    cache.record(&fader(1, 0.4));
+    cache.record(&fader(0, 0.7));
+    cache.record(&XTouchDownstreamMsg::MuteLED(MuteLEDMsg {
+        idx: ChannelIndex::new(0),
+        state: LEDState::On,
+    }));
+    cache.record(&XTouchDownstreamMsg::ScribbleStrip(ScribbleStripMsg {
+        idx: ChannelIndex::new(1),
+        top_line: "Drums".to_string(),
+        bottom_line: String::new(),
+        color: ScribbleColor::Red,
+    }));
+    cache.record(&XTouchDownstreamMsg::AssignmentDisplay(
+        AssignmentDisplayMsg::mode_bank('V', 0),
+    ));
+    cache.record(&XTouchDownstreamMsg::Play(LEDState::On));
+    cache.record(&XTouchDownstreamMsg::Stop(LEDState::On));
+    cache.record(&XTouchDownstreamMsg::Stop(LEDState::Off));
+
+    let replayed = cache.replay();
+    assert_eq!(replayed.len(), 7, "{:?}", replayed);
+
+    let mut faders: Vec<_> = replayed
+        .iter()
+        .filter_map(|msg| match msg {
+            XTouchDownstreamMsg::FaderAbs(msg) => Some((msg.idx.get(), msg.value)),
+            _ => None,
+        })
+        .collect();
+    faders.sort_by_key(|(idx, _)| *idx);
+    assert_eq!(faders, [(0, 0.7), (1, 0.4)]);
+
+    assert!(replayed.iter().any(|msg| matches!(
+        msg,
+        XTouchDownstreamMsg::MuteLED(msg) if msg.state == LEDState::On
+    )));
+    assert!(replayed.iter().any(|msg| matches!(
+        msg,
+        XTouchDownstreamMsg::ScribbleStrip(msg) if msg.top_line == "Drums"
+    )));
+    assert!(
+        replayed
+            .iter()
+            .any(|msg| matches!(msg, XTouchDownstreamMsg::Play(LEDState::On)))
+    );
+    assert!(
+        replayed
+            .iter()
+            .any(|msg| matches!(msg, XTouchDownstreamMsg::Stop(LEDState::Off)))
+    );
+}
+
+#[test]
+fn test_cache_only_lets_through_what_changes_the_surface() {
+    let mut cache = SurfaceCache::new();
+    assert!(cache.record(&fader(0, 0.5)));
+    assert!(!cache.record(&fader(0, 0.5)), "the fader is already there");
+    assert!(cache.record(&fader(1, 0.5)));
+    assert!(cache.record(&fader(0, 0.6)));
+
+    assert!(cache.record(&XTouchDownstreamMsg::Play(LEDState::On)));
+    assert!(!cache.record(&XTouchDownstreamMsg::Play(LEDState::On)));
+    assert!(cache.record(&XTouchDownstreamMsg::Stop(LEDState::On)));
+
+    // Every action button has an LED of its own
+    assert!(cache.record(&XTouchDownstreamMsg::Action(
+        ActionButton::Click,
+        LEDState::On
+    )));
+    assert!(cache.record(&XTouchDownstreamMsg::Action(
+        ActionButton::Save,
+        LEDState::On
+    )));
+    assert!(!cache.record(&XTouchDownstreamMsg::Action(
+        ActionButton::Click,
+        LEDState::On
+    )));
+
+    let barrier = XTouchDownstreamMsg::Barrier(Barrier::new());
+    assert!(cache.record(&barrier));
+    assert!(cache.record(&barrier), "barriers always go out");
+}
+
+#[test]
+fn test_cache_follows_faders_moved_by_hand() {
+    let mut cache = SurfaceCache::new();
+    cache.record(&fader(0, 0.5));
+    cache.record_from_surface(&XTouchUpstreamMsg::from(FaderAbsMsg {
+        idx: ChannelIndex::new(0),
+        value: 0.9,
+    }));
+
+    // A mode putting the fader back where it was has to move it
+    assert!(cache.record(&fader(0, 0.5)));
+    assert!(!cache.record(&fader(0, 0.5)));
+}
+
+#[test]
+fn test_manager_answers_barriers_while_device_is_away() {
+    let (to_surface, from_modes) = unbounded();
+    let (to_modes, from_surface) = unbounded();
+    let mut manager = XTouchDeviceManager::new("no such device", 8);
+    manager.poll_interval = Duration::from_millis(10);
+    manager.start(from_modes, to_modes);
+
+    // Everything else is held on to for when the device turns up
+    to_surface.send(fader(0, 0.5)).unwrap();
+    let barrier = Barrier::new();
+    to_surface
+        .send(XTouchDownstreamMsg::Barrier(barrier))
+        .unwrap();
+
+    match from_surface.recv_timeout(Duration::from_millis(500)) {
+        Ok(XTouchUpstreamMsg::Barrier(answered)) => assert_eq!(answered, barrier),
+        Ok(_) => panic!("Expected the barrier back"),
+        Err(e) => panic!("Expected the barrier back but got {:?}", e),
+    }
+}
@@ -0,0 +1,315 @@
+// Fuzz tests for the generated dispatcher and the context gates in front of it
+//
+// These generate OSC messages from the route table, some well formed and plenty not, and check
+// that nothing on the way in panics on them, that the dispatcher finds the route of any address a
+// route has and no other, and that the gates only let a message through once its context has
+// been initialized.
+
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use proptest::prelude::*;
+use rosc::{OscMessage, OscPacket, OscType};
+
+use arpad_rust::osc::decode::Unhandled;
+use arpad_rust::osc::generated_osc::{Reaper, all_routes, context, context_kind, dispatch_osc};
+use arpad_rust::osc::pattern::{is_placeholder, match_address};
+use arpad_rust::osc::route_context::context_gate::OscGatedRouter;
+use arpad_rust::osc::route_context::key_messages::{
+    KeyMessages, MarkerKeyMessages, RegionKeyMessages, TrackFxKeyMessages, TrackFxParamKeyMessages,
+    TrackKeyMessages, TrackSendKeyMessages,
+};
+use arpad_rust::osc::route_context::{ContextGateBuilder, ContextKindTrait, OscGatedRouterBuilder};
+use arpad_rust::osc::transport::NullTransport;
+use arpad_rust::traits::Bind;
+
+// Every literal segment of the spec's addresses, e.g. "track" and "volume"
+fn vocabulary() -> Vec<String> {
+    let segments: BTreeSet<&str> = all_routes()
+        .iter()
+        .flat_map(|route| route.address.split('/'))
+        .filter(|segment| !segment.is_empty() && !is_placeholder(segment))
+        .collect();
+    segments.into_iter().map(String::from).collect()
+}
+
+// A value for a param of type `typ` that fits in one address segment
+fn param_value(typ: &str) -> BoxedStrategy<String> {
+    match typ {
+        "int" => any::<i32>().prop_map(|value| value.to_string()).boxed(),
+        _ => "[A-Za-z0-9_.-]{1,12}".boxed(),
+    }
+}
+
+// Anything that fits in one address segment, including OSC pattern syntax and values that aren't
+// of the param's type
+fn any_segment() -> BoxedStrategy<String> {
+    prop_oneof![
+        any::<i64>().prop_map(|value| value.to_string()),
+        "[^/]{1,8}",
+        "[*?\\[\\]{},!a-z0-9-]{1,6}",
+    ]
+    .boxed()
+}
+
+// The address of one of the spec's routes, with each param filled in by `segment`
+fn route_address(segment: fn(&str) -> BoxedStrategy<String>) -> BoxedStrategy<String> {
+    (0..all_routes().len())
+        .prop_flat_map(move |idx| {
+            let route = &all_routes()[idx];
+            let params: Vec<BoxedStrategy<String>> =
+                route.params.iter().map(|(_, typ)| segment(typ)).collect();
+            (Just(route.address), params)
+        })
+        .prop_map(|(template, mut params)| {
+            params.reverse();
+            template
+                .split('/')
+                .map(|segment| match is_placeholder(segment) {
+                    true => params.pop().unwrap(),
+                    false => segment.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .boxed()
+}
+
+// An address made of the spec's segments and some that aren't, which may or may not be a route's
+fn vocabulary_address() -> BoxedStrategy<String> {
+    let segment = prop_oneof![
+        3 => proptest::sample::select(vocabulary()),
+        1 => (0..4i32).prop_map(|value| value.to_string()),
+        1 => "[a-z]{1,6}",
+    ];
+    proptest::collection::vec(segment, 1..=6)
+        .prop_map(|segments| format!("/{}", segments.join("/")))
+        .boxed()
+}
+
+fn osc_arg() -> impl Strategy<Value = OscType> {
+    prop_oneof![
+        any::<i32>().prop_map(OscType::Int),
+        any::<f32>().prop_map(OscType::Float),
+        any::<bool>().prop_map(OscType::Bool),
+        ".{0,8}".prop_map(OscType::String),
+        any::<i64>().prop_map(OscType::Long),
+        any::<f64>().prop_map(OscType::Double),
+        proptest::collection::vec(any::<u8>(), 0..4).prop_map(OscType::Blob),
+        Just(OscType::Nil),
+        Just(OscType::Inf),
+    ]
+}
+
+// Arguments that compare equal to themselves, so messages can be told apart after dispatch
+fn comparable_arg() -> impl Strategy<Value = OscType> {
+    prop_oneof![
+        any::<i32>().prop_map(OscType::Int),
+        (-2.0f32..2.0).prop_map(OscType::Float),
+        any::<bool>().prop_map(OscType::Bool),
+        "[a-z]{0,4}".prop_map(OscType::String),
+    ]
+}
+
+// A Reaper with handlers bound on a few routes, so dispatching runs them too
+fn bound_reaper() -> Reaper {
+    let reaper = Reaper::new(Arc::new(NullTransport));
+    reaper.track_volume("track-a".into()).bind(|_| {});
+    reaper.track_send_volume("track-a".into(), 0).bind(|_| {});
+    reaper
+        .track_fx_param_value("track-a".into(), 0, 1)
+        .bind(|_| {});
+    reaper.master_volume().bind(|_| {});
+    reaper.marker_name(0).bind(|_| {});
+    reaper.action_state(40364).bind(|_| {});
+    reaper
+}
+
+// Dispatches `msg`, keeping what the dispatcher couldn't handle
+fn dispatch(reaper: &Reaper, msg: OscMessage) -> Vec<Unhandled> {
+    let unhandled = Mutex::new(Vec::new());
+    dispatch_osc(reaper, msg, |_, reason| {
+        unhandled.lock().unwrap().push(reason.clone())
+    });
+    unhandled.into_inner().unwrap()
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Event {
+    Initialized(&'static str, String),
+    Delivered(OscMessage),
+}
+
+type Log = Rc<RefCell<Vec<Event>>>;
+
+// A gate on contexts of kind K that waits on M's key routes and logs each context it initializes
+fn gate<K: ContextKindTrait + 'static, M: KeyMessages>(log: &Log) -> ContextGateBuilder<K> {
+    let log = log.clone();
+    ContextGateBuilder::<K>::new()
+        .add_key_routes(M::KEY_ROUTES.to_vec())
+        .with_initialization_callback(move |ctx, _| {
+            log.borrow_mut()
+                .push(Event::Initialized(K::context_name(), format!("{:?}", ctx)))
+        })
+}
+
+// A router with the layers the app runs with, delivering into the log
+fn logging_router(log: &Log) -> OscGatedRouter {
+    let delivered = log.clone();
+    OscGatedRouterBuilder::new(move |msg| delivered.borrow_mut().push(Event::Delivered(msg)))
+        .add_layer(Box::new(gate::<context_kind::Track, TrackKeyMessages>(log)))
+        .add_layer(Box::new(
+            gate::<context_kind::TrackSend, TrackSendKeyMessages>(log)
+                .requires_parent::<context_kind::Track>(|ctx| context::Track {
+                    track_guid: ctx.track_guid.clone(),
+                }),
+        ))
+        .add_layer(Box::new(
+            gate::<context_kind::TrackFx, TrackFxKeyMessages>(log)
+                .requires_parent::<context_kind::Track>(|ctx| context::Track {
+                    track_guid: ctx.track_guid.clone(),
+                }),
+        ))
+        .add_layer(Box::new(
+            gate::<context_kind::TrackFxParam, TrackFxParamKeyMessages>(log)
+                .requires_parent::<context_kind::TrackFx>(|ctx| context::TrackFx {
+                    track_guid: ctx.track_guid.clone(),
+                    fx_idx: ctx.fx_idx,
+                }),
+        ))
+        .add_layer(Box::new(gate::<context_kind::Marker, MarkerKeyMessages>(
+            log,
+        )))
+        .add_layer(Box::new(gate::<context_kind::Region, RegionKeyMessages>(
+            log,
+        )))
+        .build()
+        .unwrap()
+}
+
+// The context each kind the router gates on finds in `addr`, named as the log names them
+fn contexts_of(addr: &str) -> Vec<(&'static str, String)> {
+    fn parsed<K: ContextKindTrait>(addr: &str) -> Option<(&'static str, String)> {
+        K::parse(addr).map(|ctx| (K::context_name(), format!("{:?}", ctx)))
+    }
+    [
+        parsed::<context_kind::Track>(addr),
+        parsed::<context_kind::TrackSend>(addr),
+        parsed::<context_kind::TrackFx>(addr),
+        parsed::<context_kind::TrackFxParam>(addr),
+        parsed::<context_kind::Marker>(addr),
+        parsed::<context_kind::Region>(addr),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+// Every context kind in the spec, whether or not the app gates on it
+fn parse_with_every_kind(addr: &str) {
+    context_kind::Action::parse(addr);
+    context_kind::Fxinfo::parse(addr);
+    context_kind::FxinfoParam::parse(addr);
+    context_kind::Marker::parse(addr);
+    context_kind::Project::parse(addr);
+    context_kind::Region::parse(addr);
+    context_kind::Track::parse(addr);
+    context_kind::TrackFx::parse(addr);
+    context_kind::TrackFxParam::parse(addr);
+    context_kind::TrackSend::parse(addr);
+}
+
+// Routes of a couple of tracks, sends, FX and markers, so that contexts come up more than once
+fn small_project_address() -> BoxedStrategy<String> {
+    fn small_param(typ: &str) -> BoxedStrategy<String> {
+        match typ {
+            "int" => (0..2i32).prop_map(|value| value.to_string()).boxed(),
+            _ => {
+                proptest::sample::select(vec!["track-a".to_string(), "track-b".to_string()]).boxed()
+            }
+        }
+    }
+    route_address(small_param)
+}
+
+proptest! {
+    // Enough to shake out the dispatcher while keeping debug builds of the suite quick
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_arbitrary_messages_never_panic(
+        addr in prop_oneof![
+            route_address(|_| any_segment()),
+            vocabulary_address(),
+            ".{0,24}",
+        ],
+        args in proptest::collection::vec(osc_arg(), 0..4),
+    ) {
+        let msg = OscMessage { addr, args };
+        parse_with_every_kind(&msg.addr);
+        dispatch(&bound_reaper(), msg.clone());
+
+        let log = Log::default();
+        logging_router(&log).dispatch_osc(OscPacket::Message(msg));
+    }
+
+    #[test]
+    fn test_route_addresses_always_reach_a_route(
+        addr in route_address(param_value),
+        args in proptest::collection::vec(osc_arg(), 0..4),
+    ) {
+        let unhandled = dispatch(&bound_reaper(), OscMessage { addr, args });
+        prop_assert!(!unhandled.contains(&Unhandled::UnknownAddress));
+    }
+
+    #[test]
+    fn test_only_addresses_no_route_has_are_unknown(addr in vocabulary_address()) {
+        let routed = all_routes()
+            .iter()
+            .any(|route| match_address(route.address, &addr).is_some());
+        let unhandled = dispatch(&bound_reaper(), OscMessage { addr, args: vec![] });
+        prop_assert_eq!(unhandled.contains(&Unhandled::UnknownAddress), !routed);
+    }
+
+    #[test]
+    fn test_gates_only_deliver_messages_of_initialized_contexts(
+        msgs in proptest::collection::vec(
+            (small_project_address(), proptest::collection::vec(comparable_arg(), 0..2)),
+            1..24,
+        ),
+    ) {
+        let log = Log::default();
+        let mut router = logging_router(&log);
+        let sent: Vec<OscMessage> = msgs
+            .into_iter()
+            .map(|(addr, args)| OscMessage { addr, args })
+            .collect();
+        for msg in &sent {
+            router.dispatch_osc(OscPacket::Message(msg.clone()));
+        }
+
+        let log = log.borrow();
+        let mut unsent = sent.clone();
+        for (idx, event) in log.iter().enumerate() {
+            let Event::Delivered(msg) = event else {
+                continue;
+            };
+            // Each message sent is delivered at most once, and as it was sent
+            let pos = unsent.iter().position(|sent| sent == msg);
+            prop_assert!(pos.is_some(), "{:?} was never sent, or delivered twice", msg);
+            unsent.remove(pos.unwrap());
+            for (kind, ctx) in contexts_of(&msg.addr) {
+                let initialized = Event::Initialized(kind, ctx.clone());
+                prop_assert!(
+                    log[..idx].contains(&initialized),
+                    "{} was delivered before {} context {} was initialized",
+                    msg.addr,
+                    kind,
+                    ctx
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+// Tests for setting and querying generated endpoints through `dyn DynEndpoint`
+
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use rosc::{OscPacket, OscType};
+
+use arpad_rust::osc::error::OscError;
+use arpad_rust::osc::generated_osc::{DynEndpoint, ParamValue, Reaper};
+use arpad_rust::osc::transport::OscTransport;
+
+// Keeps every message sent
+#[derive(Default)]
+struct RecordingTransport {
+    sent: Mutex<Vec<rosc::OscMessage>>,
+}
+
+impl OscTransport for RecordingTransport {
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        match rosc::decoder::decode_udp(packet).unwrap().1 {
+            OscPacket::Message(msg) => self.sent.lock().unwrap().push(msg),
+            OscPacket::Bundle(_) => panic!("Expected a message"),
+        }
+        Ok(())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+fn setup() -> (Reaper, Arc<RecordingTransport>) {
+    let transport = Arc::new(RecordingTransport::default());
+    (Reaper::new(transport.clone()), transport)
+}
+
+#[test]
+fn test_set_dyn_sends_like_set() {
+    let (reaper, transport) = setup();
+    let mut endpoints: Vec<(Box<dyn DynEndpoint>, ParamValue)> = vec![
+        (Box::new(reaper.track_volume("abc".into())), 0.5.into()),
+        (Box::new(reaper.track_name("abc".into())), "Drums".into()),
+        // Triggers don't take a value
+        (Box::new(reaper.stop()), true.into()),
+    ];
+    // Generic middleware only needs writing once
+    let mut log = Vec::new();
+    for (endpoint, value) in endpoints.iter_mut() {
+        assert!(endpoint.is_settable());
+        log.push(format!("{} = {}", endpoint.address(), value));
+        endpoint.set_dyn(value.clone()).unwrap();
+    }
+    assert_eq!(
+        log,
+        vec![
+            "/track/abc/volume = 0.5",
+            "/track/abc/name = \"Drums\"",
+            "/stop = true",
+        ]
+    );
+
+    let sent = transport.sent.lock().unwrap();
+    let sent: Vec<_> = sent
+        .iter()
+        .map(|msg| (msg.addr.as_str(), &msg.args))
+        .collect();
+    assert_eq!(
+        sent,
+        vec![
+            ("/track/abc/volume", &vec![OscType::Float(0.5)]),
+            (
+                "/track/abc/name",
+                &vec![OscType::String("Drums".to_string())]
+            ),
+            ("/stop", &vec![]),
+        ]
+    );
+}
+
+#[test]
+fn test_set_dyn_rejects_value_of_wrong_type() {
+    let (reaper, transport) = setup();
+    let mut volume = reaper.track_volume("abc".into());
+    let endpoint: &mut dyn DynEndpoint = &mut volume;
+
+    let err = endpoint.set_dyn(ParamValue::Bool(true)).unwrap_err();
+    assert!(matches!(err, OscError::Unsupported(_)), "{:?}", err);
+    assert_eq!(endpoint.address_template(), "/track/{track_guid}/volume");
+    assert!(transport.sent.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_query_dyn_only_for_queryable_endpoints() {
+    let (reaper, transport) = setup();
+    let mut num_tracks = reaper.num_tracks();
+    let stop = reaper.stop();
+
+    assert!(num_tracks.is_queryable());
+    num_tracks.query_dyn().unwrap();
+    assert!(!num_tracks.is_settable());
+    assert!(matches!(
+        num_tracks.set_dyn(ParamValue::Int(3)),
+        Err(OscError::Unsupported(_))
+    ));
+
+    assert!(!stop.is_queryable());
+    assert!(matches!(stop.query_dyn(), Err(OscError::Unsupported(_))));
+
+    let sent = transport.sent.lock().unwrap();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].addr, "/num_tracks");
+    assert!(sent[0].args.is_empty());
+}
+
+#[test]
+fn test_set_dyn_takes_either_width_of_float() {
+    let (reaper, transport) = setup();
+    let mut volume = reaper.track_volume("abc".into());
+    volume.set_dyn(ParamValue::Double(0.25)).unwrap();
+    volume.set_dyn(ParamValue::Float(0.75)).unwrap();
+
+    let sent = transport.sent.lock().unwrap();
+    let args: Vec<_> = sent.iter().map(|msg| msg.args.clone()).collect();
+    assert_eq!(
+        args,
+        vec![vec![OscType::Float(0.25)], vec![OscType::Float(0.75)]]
+    );
+}
@@ -0,0 +1,61 @@
+// End-to-end tests running the bridge binary against a fake Reaper
+//
+// These go through everything main.rs wires up: the OSC socket, the context gates, the track
+// manager and the modes, with the simulated XTouch standing in for the hardware.
+
+mod support;
+
+use std::time::{Duration, Instant};
+
+use rosc::OscType;
+
+use support::{Bridge, FakeReaper, osc_message};
+
+const TRACK_GUID: &str = "drums-guid";
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[test]
+fn test_fader_move_sets_track_volume_in_reaper() {
+    let reaper = FakeReaper::start();
+    reaper.respond_to(
+        "/num_tracks",
+        vec![osc_message("/num_tracks", vec![OscType::Int(1)])],
+    );
+    let mut bridge = Bridge::start(&reaper, &["--coalesce-window-ms", "0"]);
+
+    // The bridge asks how many tracks there are as soon as it's up
+    assert!(
+        reaper
+            .wait_for(TIMEOUT, |msg| msg.addr == "/num_tracks")
+            .is_some(),
+        "the bridge never asked for the track count"
+    );
+
+    // Reaper reports its one track, starting with the index that opens the track's context
+    let track = |route: &str| format!("/track/{}/{}", TRACK_GUID, route);
+    reaper.send(
+        bridge.addr,
+        osc_message(&track("index"), vec![OscType::Int(0)]),
+    );
+    reaper.send(
+        bridge.addr,
+        osc_message(&track("name"), vec![OscType::String("Drums".to_string())]),
+    );
+    reaper.send(
+        bridge.addr,
+        osc_message(&track("volume"), vec![OscType::Float(0.716)]),
+    );
+
+    // The modes start once the initial sync is done, and drop surface input until then, so keep
+    // moving the fader until it gets through
+    let volume_set = |msg: &rosc::OscMessage| {
+        msg.addr == track("volume") && msg.args == vec![OscType::Float(0.5)]
+    };
+    let deadline = Instant::now() + TIMEOUT;
+    let mut sent = None;
+    while sent.is_none() && Instant::now() < deadline {
+        bridge.command("fader 0 0.5");
+        sent = reaper.wait_for(Duration::from_millis(250), volume_set);
+    }
+    assert!(sent.is_some(), "the fader move never reached Reaper");
+}
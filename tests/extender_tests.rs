@@ -0,0 +1,245 @@
+// Tests for driving an X-Touch along with its extenders as one surface
+
+use std::time::Duration;
+
+use crossbeam_channel::{Receiver, Sender, unbounded};
+
+use arpad_rust::midi::xtouch::surface::{ExtendedSurface, SurfaceLayout};
+use arpad_rust::midi::xtouch::{
+    ChannelIndex, FaderAbsMsg, LEDState, Model, MuteLEDMsg, ScribbleColor, ScribbleStripMsg,
+    SelectPress, XTouchDownstreamMsg, XTouchUpstreamMsg, keepalive::SurfaceStatus,
+    model_scribble_strip_sysex,
+};
+use arpad_rust::modes::mode_manager::Barrier;
+
+const TIMEOUT: Duration = Duration::from_millis(500);
+
+struct Units {
+    to_surface: Sender<XTouchDownstreamMsg>,
+    from_surface: Receiver<XTouchUpstreamMsg>,
+    // What each unit was sent, and where to send what each unit reports
+    unit_inputs: Vec<Receiver<XTouchDownstreamMsg>>,
+    unit_upstreams: Vec<Sender<XTouchUpstreamMsg>>,
+}
+
+fn start(layout: SurfaceLayout) -> Units {
+    let (to_surface, input) = unbounded();
+    let (upstream, from_surface) = unbounded();
+    let mut units = Vec::new();
+    let mut unit_inputs = Vec::new();
+    let mut unit_upstreams = Vec::new();
+    for _ in 0..layout.num_units() {
+        let (to_unit, unit_input) = unbounded();
+        let (unit_upstream, from_unit) = unbounded();
+        units.push((to_unit, from_unit));
+        unit_inputs.push(unit_input);
+        unit_upstreams.push(unit_upstream);
+    }
+    ExtendedSurface::start(layout, units, input, upstream);
+    Units {
+        to_surface,
+        from_surface,
+        unit_inputs,
+        unit_upstreams,
+    }
+}
+
+#[test]
+fn test_layout_translates_channels_between_surface_and_units() {
+    let layout = SurfaceLayout::xtouch_with_extenders(2);
+    assert_eq!(layout.num_units(), 3);
+    assert_eq!(layout.num_channels(), 24);
+
+    assert_eq!(
+        layout.to_unit(ChannelIndex::new(3)),
+        Some((0, ChannelIndex::new(3)))
+    );
+    assert_eq!(
+        layout.to_unit(ChannelIndex::new(8)),
+        Some((1, ChannelIndex::new(0)))
+    );
+    assert_eq!(
+        layout.to_unit(ChannelIndex::new(23)),
+        Some((2, ChannelIndex::new(7)))
+    );
+    assert_eq!(layout.to_unit(ChannelIndex::new(24)), None);
+
+    assert_eq!(
+        layout.from_unit(2, ChannelIndex::new(5)),
+        Some(ChannelIndex::new(21))
+    );
+    assert_eq!(layout.from_unit(1, ChannelIndex::new(8)), None);
+    assert_eq!(layout.from_unit(3, ChannelIndex::new(0)), None);
+}
+
+#[test]
+fn test_strip_messages_go_to_the_unit_holding_the_channel() {
+    let units = start(SurfaceLayout::xtouch_with_extenders(1));
+
+    units
+        .to_surface
+        .send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg {
+            idx: ChannelIndex::new(10),
+            value: 0.5,
+        }))
+        .unwrap();
+    match units.unit_inputs[1].recv_timeout(TIMEOUT).unwrap() {
+        XTouchDownstreamMsg::FaderAbs(msg) => {
+            assert_eq!(msg.idx, ChannelIndex::new(2));
+            assert_eq!(msg.value, 0.5);
+        }
+        other => panic!("Expected a FaderAbs but got {:?}", other),
+    }
+
+    units
+        .to_surface
+        .send(XTouchDownstreamMsg::MuteLED(MuteLEDMsg {
+            idx: ChannelIndex::new(7),
+            state: LEDState::On,
+        }))
+        .unwrap();
+    match units.unit_inputs[0].recv_timeout(TIMEOUT).unwrap() {
+        XTouchDownstreamMsg::MuteLED(msg) => assert_eq!(msg.idx, ChannelIndex::new(7)),
+        other => panic!("Expected a MuteLED but got {:?}", other),
+    }
+
+    // Only the X-Touch has the transport buttons
+    units
+        .to_surface
+        .send(XTouchDownstreamMsg::Play(LEDState::On))
+        .unwrap();
+    assert!(matches!(
+        units.unit_inputs[0].recv_timeout(TIMEOUT).unwrap(),
+        XTouchDownstreamMsg::Play(LEDState::On)
+    ));
+    assert!(units.unit_inputs[1].try_recv().is_err());
+}
+
+#[test]
+fn test_strip_messages_from_an_extender_are_offset_by_the_units_before_it() {
+    let units = start(SurfaceLayout::xtouch_with_extenders(2));
+
+    units.unit_upstreams[2]
+        .send(XTouchUpstreamMsg::SelectPress(SelectPress {
+            idx: ChannelIndex::new(1),
+        }))
+        .unwrap();
+    match units.from_surface.recv_timeout(TIMEOUT).unwrap() {
+        XTouchUpstreamMsg::SelectPress(msg) => assert_eq!(msg.idx, ChannelIndex::new(17)),
+        _ => panic!("Expected a SelectPress"),
+    }
+}
+
+#[test]
+fn test_barrier_is_reflected_once_every_unit_has_reflected_it() {
+    let units = start(SurfaceLayout::xtouch_with_extenders(1));
+    let barrier = Barrier::new();
+
+    units
+        .to_surface
+        .send(XTouchDownstreamMsg::Barrier(barrier))
+        .unwrap();
+    for unit_input in &units.unit_inputs {
+        match unit_input.recv_timeout(TIMEOUT).unwrap() {
+            XTouchDownstreamMsg::Barrier(b) => assert_eq!(b, barrier),
+            other => panic!("Expected the barrier but got {:?}", other),
+        }
+    }
+
+    units.unit_upstreams[1]
+        .send(XTouchUpstreamMsg::Barrier(barrier))
+        .unwrap();
+    assert!(
+        units
+            .from_surface
+            .recv_timeout(Duration::from_millis(100))
+            .is_err()
+    );
+    units.unit_upstreams[0]
+        .send(XTouchUpstreamMsg::Barrier(barrier))
+        .unwrap();
+    match units.from_surface.recv_timeout(TIMEOUT).unwrap() {
+        XTouchUpstreamMsg::Barrier(b) => assert_eq!(b, barrier),
+        _ => panic!("Expected the barrier"),
+    }
+}
+
+fn expect_status(units: &Units, expected: SurfaceStatus) {
+    match units.from_surface.recv_timeout(TIMEOUT).unwrap() {
+        XTouchUpstreamMsg::SurfaceStatus(status) => assert_eq!(status, expected),
+        _ => panic!("Expected a SurfaceStatus"),
+    }
+}
+
+#[test]
+fn test_surface_is_online_once_every_unit_is() {
+    let units = start(SurfaceLayout::xtouch_with_extenders(1));
+
+    units.unit_upstreams[0]
+        .send(XTouchUpstreamMsg::SurfaceStatus(SurfaceStatus::Online))
+        .unwrap();
+    units.unit_upstreams[1]
+        .send(XTouchUpstreamMsg::SurfaceStatus(SurfaceStatus::Online))
+        .unwrap();
+    expect_status(&units, SurfaceStatus::Online);
+
+    // The extender going away takes the surface with it
+    units.unit_upstreams[1]
+        .send(XTouchUpstreamMsg::SurfaceStatus(SurfaceStatus::Offline))
+        .unwrap();
+    expect_status(&units, SurfaceStatus::Offline);
+    units.unit_upstreams[0]
+        .send(XTouchUpstreamMsg::SurfaceStatus(SurfaceStatus::Offline))
+        .unwrap();
+    units.unit_upstreams[1]
+        .send(XTouchUpstreamMsg::SurfaceStatus(SurfaceStatus::Online))
+        .unwrap();
+    assert!(
+        units
+            .from_surface
+            .recv_timeout(Duration::from_millis(100))
+            .is_err()
+    );
+    units.unit_upstreams[0]
+        .send(XTouchUpstreamMsg::SurfaceStatus(SurfaceStatus::Online))
+        .unwrap();
+    expect_status(&units, SurfaceStatus::Online);
+}
+
+#[test]
+fn test_extender_scribble_strips_use_its_sysex_device() {
+    let msg = ScribbleStripMsg {
+        idx: ChannelIndex::new(0),
+        top_line: "Bass".to_string(),
+        bottom_line: String::new(),
+        color: ScribbleColor::Red,
+    };
+    assert_eq!(model_scribble_strip_sysex(Model::XTouch, 0, &msg)[4], 0x14);
+    assert_eq!(
+        model_scribble_strip_sysex(Model::Extender, 0, &msg)[4],
+        0x15
+    );
+}
+
+#[test]
+fn test_surface_lets_go_once_a_unit_is_gone() {
+    let mut units = start(SurfaceLayout::xtouch_with_extenders(1));
+    // The extender's XTouch stops taking messages, as it does once it can't write to the device
+    units.unit_inputs.pop();
+    units
+        .to_surface
+        .send(XTouchDownstreamMsg::Barrier(Barrier::new()))
+        .unwrap();
+    let deadline = std::time::Instant::now() + TIMEOUT;
+    while units
+        .to_surface
+        .send(XTouchDownstreamMsg::Barrier(Barrier::new()))
+        .is_ok()
+    {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "the surface kept going"
+        );
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
@@ -0,0 +1,27 @@
+// Tests for converting fader positions between the hardware, the modes and Reaper
+
+use arpad_rust::midi::xtouch::{
+    ChannelIndex, FaderAbsMsg, MasterFaderAbsMsg, fader_from_midi, fader_to_midi,
+};
+
+#[test]
+fn test_hardware_values_round_trip() {
+    for value in [0, 1, 8191, 8192, 16382, 16383] {
+        assert_eq!(fader_to_midi(fader_from_midi(value)), value as i32);
+    }
+    assert_eq!(fader_from_midi(16383), 1.0);
+    // Halfway goes to the nearer step rather than always down
+    assert_eq!(fader_to_midi(0.5), 8192);
+    // The motor can't go past either end
+    assert_eq!(fader_to_midi(-0.1), 0);
+    assert_eq!(fader_to_midi(1.5), 16383);
+}
+
+#[test]
+fn test_positions_keep_what_f32_can_hold() {
+    let fader = FaderAbsMsg::at(ChannelIndex::new(2), 0.716);
+    assert_eq!(fader.idx, ChannelIndex::new(2));
+    assert_eq!(fader.value, 0.716f32 as f64);
+    assert_eq!(fader.position(), 0.716);
+    assert_eq!(MasterFaderAbsMsg::at(0.25).position(), 0.25);
+}
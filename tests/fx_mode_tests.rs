@@ -0,0 +1,222 @@
+// Integration tests for FxMode
+//
+// These tests drive FxMode directly, covering entering the mode for the selected track, showing
+// its FX on the strips, and controlling bypass and wet/dry from the encoders.
+
+use crossbeam_channel::{Receiver, unbounded};
+use std::time::Duration;
+
+use arpad_rust::midi::xtouch::{
+    ChannelIndex, EncoderPressMsg, EncoderRingLEDMsg, EncoderTurnCW, ScribbleColor,
+    XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
+use arpad_rust::modes::mode_manager::{Mode, ModeHandler, ModeState, State};
+use arpad_rust::modes::reaper_fx::FxMode;
+use arpad_rust::track::track::{
+    DataPayload, Direction, FXBypass, FXName, TrackDataMsg, TrackManager, TrackMsg,
+};
+
+const TRACK_GUID: &str = "fx-track";
+
+const ACTIVE: ModeState = ModeState {
+    mode: Mode::ReaperFX,
+    state: State::Active,
+};
+
+/// Enters FxMode for a track with a compressor in slot 0, returning the mode along with the
+/// channels to Reaper and the hardware
+fn setup_fx_mode() -> (FxMode, Receiver<TrackMsg>, Receiver<XTouchDownstreamMsg>) {
+    let (to_reaper_tx, to_reaper_rx) = unbounded();
+    let (to_xtouch_tx, to_xtouch_rx) = unbounded();
+    let mut mode = FxMode::new(2, to_reaper_tx, to_xtouch_tx);
+
+    // Entering the mode asks TrackManager for the selected track's state
+    let (upstream_tx, upstream_rx) = unbounded();
+    let result = mode.initiate_mode_transition(upstream_tx, &TRACK_GUID.into());
+    assert_eq!(result.mode, Mode::ReaperFX);
+    let query = upstream_rx
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+    assert!(matches!(&query, TrackMsg::TrackQuery(q) if q.guid == TRACK_GUID));
+    assert!(matches!(
+        upstream_rx.recv_timeout(Duration::from_millis(100)),
+        Ok(TrackMsg::Barrier(_))
+    ));
+    assert!(matches!(
+        to_xtouch_rx.recv_timeout(Duration::from_millis(100)),
+        Ok(XTouchDownstreamMsg::AssignmentDisplay(_))
+    ));
+
+    // Answer the query the way TrackManager would
+    let (input_tx, input_rx) = unbounded();
+    let (tm_upstream_tx, _tm_upstream_rx) = unbounded();
+    let (tm_downstream_tx, tm_downstream_rx) = unbounded();
+    TrackManager::start(input_rx, tm_upstream_tx, tm_downstream_tx);
+    input_tx
+        .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: TRACK_GUID.into(),
+            direction: Direction::Downstream,
+            data: DataPayload::FXName(FXName {
+                fx_index: 0,
+                name: "Comp".to_string(),
+            }),
+        }))
+        .unwrap();
+    input_tx.send(query).unwrap();
+    let response = tm_downstream_rx
+        .iter()
+        .find(|msg| {
+            matches!(
+                msg,
+                TrackMsg::TrackDataMsg(TrackDataMsg {
+                    data: DataPayload::TrackData(_),
+                    ..
+                })
+            )
+        })
+        .unwrap();
+    mode.handle_downstream_messages(response, ACTIVE);
+
+    (mode, to_reaper_rx, to_xtouch_rx)
+}
+
+/// Asserts the ring and scribble strip for one strip
+fn assert_fx_strip(
+    to_xtouch_rx: &Receiver<XTouchDownstreamMsg>,
+    hw_channel: i32,
+    wet: f32,
+    top: &str,
+    bottom: &str,
+) {
+    match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::EncoderRingLED(EncoderRingLEDMsg::RangeFill(msg))) => {
+            assert_eq!(msg.idx, hw_channel);
+            assert!((msg.pos - wet).abs() < 0.0001, "wet was {}", msg.pos);
+        }
+        other => panic!(
+            "Expected EncoderRingLED RangeFill message but got {:?}",
+            other
+        ),
+    }
+    match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::ScribbleStrip(msg)) => {
+            assert_eq!(msg.idx, hw_channel);
+            assert_eq!(msg.top_line, top);
+            assert_eq!(msg.bottom_line, bottom);
+        }
+        other => panic!("Expected ScribbleStrip message but got {:?}", other),
+    }
+}
+
+#[test]
+fn test_fx_mode_shows_selected_track_fx() {
+    let (_mode, _to_reaper_rx, to_xtouch_rx) = setup_fx_mode();
+
+    assert_fx_strip(&to_xtouch_rx, 0, 1.0, "Comp", "Active");
+    // The track has no second FX, so that strip is blanked
+    assert!(matches!(
+        to_xtouch_rx.recv_timeout(Duration::from_millis(100)),
+        Ok(XTouchDownstreamMsg::EncoderRingLED(
+            EncoderRingLEDMsg::Blank(_)
+        ))
+    ));
+    match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::ScribbleStrip(msg)) => {
+            assert_eq!(msg.idx, 1);
+            assert_eq!(msg.color, ScribbleColor::Off);
+        }
+        other => panic!("Expected ScribbleStrip message but got {:?}", other),
+    }
+}
+
+#[test]
+fn test_fx_mode_encoder_press_toggles_bypass() {
+    let (mut mode, to_reaper_rx, to_xtouch_rx) = setup_fx_mode();
+    while to_xtouch_rx.try_recv().is_ok() {}
+
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::EncoderPress(EncoderPressMsg {
+            idx: ChannelIndex::new(0),
+        }),
+        ACTIVE,
+    );
+    match to_reaper_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid,
+            direction: Direction::Upstream,
+            data: DataPayload::FXBypass(FXBypass { fx_index, bypass }),
+        })) => {
+            assert_eq!(guid, TRACK_GUID);
+            assert_eq!(fx_index, 0);
+            assert!(bypass);
+        }
+        other => panic!("Expected FXBypass message but got {:?}", other),
+    }
+    assert_fx_strip(&to_xtouch_rx, 0, 1.0, "Comp", "Bypass");
+
+    // Pressing an encoder with no FX behind it does nothing
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::EncoderPress(EncoderPressMsg {
+            idx: ChannelIndex::new(1),
+        }),
+        ACTIVE,
+    );
+    assert!(
+        to_reaper_rx
+            .recv_timeout(Duration::from_millis(50))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_fx_mode_shift_encoder_adjusts_wet() {
+    let (mut mode, to_reaper_rx, to_xtouch_rx) = setup_fx_mode();
+    while to_xtouch_rx.try_recv().is_ok() {}
+    let turn = || {
+        XTouchUpstreamMsg::EncoderTurnInc(EncoderTurnCW {
+            idx: ChannelIndex::new(0),
+        })
+    };
+
+    // Without the modifier, turning the encoder does nothing
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: TRACK_GUID.into(),
+            direction: Direction::Downstream,
+            data: DataPayload::FXWet(arpad_rust::track::track::FXWet {
+                fx_index: 0,
+                wet: 0.5,
+            }),
+        }),
+        ACTIVE,
+    );
+    assert_fx_strip(&to_xtouch_rx, 0, 0.5, "Comp", "Active");
+    mode.handle_upstream_messages(turn(), ACTIVE);
+    assert!(
+        to_reaper_rx
+            .recv_timeout(Duration::from_millis(50))
+            .is_err()
+    );
+
+    mode.handle_upstream_messages(XTouchUpstreamMsg::ShiftPress, ACTIVE);
+    mode.handle_upstream_messages(turn(), ACTIVE);
+    match to_reaper_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(TrackMsg::TrackDataMsg(TrackDataMsg {
+            data: DataPayload::FXWet(fx_wet),
+            ..
+        })) => {
+            assert_eq!(fx_wet.fx_index, 0);
+            assert!((fx_wet.wet - 0.55).abs() < 0.0001);
+        }
+        other => panic!("Expected FXWet message but got {:?}", other),
+    }
+    assert_fx_strip(&to_xtouch_rx, 0, 0.55, "Comp", "Active");
+
+    mode.handle_upstream_messages(XTouchUpstreamMsg::ShiftRelease, ACTIVE);
+    mode.handle_upstream_messages(turn(), ACTIVE);
+    assert!(
+        to_reaper_rx
+            .recv_timeout(Duration::from_millis(50))
+            .is_err()
+    );
+}
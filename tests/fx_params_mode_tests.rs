@@ -0,0 +1,273 @@
+// Integration tests for FxParamsMode
+//
+// These tests drive FxParamsMode directly, covering entering the mode for the selected track,
+// laying its FX parameters out on the strips, controlling them from the faders and encoders, and
+// paging through them.
+
+use crossbeam_channel::{Receiver, unbounded};
+use std::time::Duration;
+
+use arpad_rust::midi::xtouch::{
+    AssignmentDisplayMsg, ChannelIndex, EncoderRingLEDMsg, EncoderTurnCCW, FaderAbsMsg,
+    ScribbleColor, XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
+use arpad_rust::modes::mode_manager::{Mode, ModeHandler, ModeState, State};
+use arpad_rust::modes::reaper_fx_params::FxParamsMode;
+use arpad_rust::track::track::{
+    DataPayload, Direction, FXName, FXParamMax, FXParamName, FXParamValue, TrackDataMsg,
+    TrackManager, TrackMsg,
+};
+
+const TRACK_GUID: &str = "fx-params-track";
+
+const ACTIVE: ModeState = ModeState {
+    mode: Mode::ReaperFxParams,
+    state: State::Active,
+};
+
+fn track_data(data: DataPayload) -> TrackMsg {
+    TrackMsg::TrackDataMsg(TrackDataMsg {
+        guid: TRACK_GUID.into(),
+        direction: Direction::Downstream,
+        data,
+    })
+}
+
+/// Enters FxParamsMode on a two channel surface for a track with an EQ in slot 0, which has three
+/// parameters: "Freq" ranging up to 100, then "Gain" and "Q". Returns the mode along with the
+/// channels to Reaper and the hardware.
+fn setup_fx_params_mode() -> (
+    FxParamsMode,
+    Receiver<TrackMsg>,
+    Receiver<XTouchDownstreamMsg>,
+) {
+    let (to_reaper_tx, to_reaper_rx) = unbounded();
+    let (to_xtouch_tx, to_xtouch_rx) = unbounded();
+    let mut mode = FxParamsMode::new(2, to_reaper_tx, to_xtouch_tx);
+
+    // Entering the mode asks TrackManager for the selected track's state
+    let (upstream_tx, upstream_rx) = unbounded();
+    let result = mode.initiate_mode_transition(upstream_tx, &TRACK_GUID.into());
+    assert_eq!(result.mode, Mode::ReaperFxParams);
+    let query = upstream_rx
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+    assert!(matches!(&query, TrackMsg::TrackQuery(q) if q.guid == TRACK_GUID));
+    assert!(matches!(
+        upstream_rx.recv_timeout(Duration::from_millis(100)),
+        Ok(TrackMsg::Barrier(_))
+    ));
+    match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::AssignmentDisplay(display)) => {
+            assert_eq!(display, AssignmentDisplayMsg::mode_bank('P', 0));
+        }
+        other => panic!("Expected AssignmentDisplay message but got {:?}", other),
+    }
+
+    // Answer the query the way TrackManager would
+    let (input_tx, input_rx) = unbounded();
+    let (tm_upstream_tx, _tm_upstream_rx) = unbounded();
+    let (tm_downstream_tx, tm_downstream_rx) = unbounded();
+    TrackManager::start(input_rx, tm_upstream_tx, tm_downstream_tx);
+    input_tx
+        .send(track_data(DataPayload::FXName(FXName {
+            fx_index: 0,
+            name: "EQ".to_string(),
+        })))
+        .unwrap();
+    for (param_index, name) in ["Freq", "Gain", "Q"].into_iter().enumerate() {
+        input_tx
+            .send(track_data(DataPayload::FXParamName(FXParamName {
+                fx_index: 0,
+                param_index: param_index as i32,
+                name: name.to_string(),
+            })))
+            .unwrap();
+    }
+    input_tx
+        .send(track_data(DataPayload::FXParamMax(FXParamMax {
+            fx_index: 0,
+            param_index: 0,
+            max: 100.0,
+        })))
+        .unwrap();
+    input_tx
+        .send(track_data(DataPayload::FXParamValue(FXParamValue {
+            fx_index: 0,
+            param_index: 0,
+            value: 25.0,
+        })))
+        .unwrap();
+    input_tx.send(query).unwrap();
+    let response = tm_downstream_rx
+        .iter()
+        .find(|msg| {
+            matches!(
+                msg,
+                TrackMsg::TrackDataMsg(TrackDataMsg {
+                    data: DataPayload::TrackData(_),
+                    ..
+                })
+            )
+        })
+        .unwrap();
+    mode.handle_downstream_messages(response, ACTIVE);
+
+    (mode, to_reaper_rx, to_xtouch_rx)
+}
+
+/// Asserts the fader, ring and scribble strip for one strip
+fn assert_param_strip(
+    to_xtouch_rx: &Receiver<XTouchDownstreamMsg>,
+    hw_channel: i32,
+    position: f32,
+    top: &str,
+    bottom: &str,
+) {
+    match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::FaderAbs(msg)) => {
+            assert_eq!(msg.idx, hw_channel);
+            assert!(
+                (msg.value - position as f64).abs() < 0.0001,
+                "fader was {}",
+                msg.value
+            );
+        }
+        other => panic!("Expected FaderAbs message but got {:?}", other),
+    }
+    match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::EncoderRingLED(EncoderRingLEDMsg::RangeFill(msg))) => {
+            assert_eq!(msg.idx, hw_channel);
+            assert!((msg.pos - position).abs() < 0.0001, "ring was {}", msg.pos);
+        }
+        other => panic!(
+            "Expected EncoderRingLED RangeFill message but got {:?}",
+            other
+        ),
+    }
+    match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::ScribbleStrip(msg)) => {
+            assert_eq!(msg.idx, hw_channel);
+            assert_eq!(msg.top_line, top);
+            assert_eq!(msg.bottom_line, bottom);
+        }
+        other => panic!("Expected ScribbleStrip message but got {:?}", other),
+    }
+}
+
+/// Asserts the param value sent to Reaper
+fn assert_param_value_sent(to_reaper_rx: &Receiver<TrackMsg>, param_index: i32, value: f32) {
+    match to_reaper_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid,
+            direction: Direction::Upstream,
+            data: DataPayload::FXParamValue(param_value),
+        })) => {
+            assert_eq!(guid, TRACK_GUID);
+            assert_eq!(param_value.fx_index, 0);
+            assert_eq!(param_value.param_index, param_index);
+            assert!(
+                (param_value.value - value).abs() < 0.0001,
+                "value was {}",
+                param_value.value
+            );
+        }
+        other => panic!("Expected FXParamValue message but got {:?}", other),
+    }
+}
+
+#[test]
+fn test_fx_params_mode_shows_first_page_of_params() {
+    let (_mode, _to_reaper_rx, to_xtouch_rx) = setup_fx_params_mode();
+
+    // Freq sits a quarter of the way through its range
+    assert_param_strip(&to_xtouch_rx, 0, 0.25, "Freq", "EQ");
+    assert_param_strip(&to_xtouch_rx, 1, 0.0, "Gain", "EQ");
+}
+
+#[test]
+fn test_fx_params_mode_fader_sets_param_in_its_range() {
+    let (mut mode, to_reaper_rx, to_xtouch_rx) = setup_fx_params_mode();
+    while to_xtouch_rx.try_recv().is_ok() {}
+
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
+            idx: ChannelIndex::new(0),
+            value: 0.5,
+        }),
+        ACTIVE,
+    );
+    assert_param_value_sent(&to_reaper_rx, 0, 50.0);
+    // The fader is left where the user put it, and the ring follows it
+    match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::EncoderRingLED(EncoderRingLEDMsg::RangeFill(msg))) => {
+            assert_eq!(msg.idx, 0);
+            assert!((msg.pos - 0.5).abs() < 0.0001, "ring was {}", msg.pos);
+        }
+        other => panic!(
+            "Expected EncoderRingLED RangeFill message but got {:?}",
+            other
+        ),
+    }
+    assert!(
+        to_xtouch_rx
+            .recv_timeout(Duration::from_millis(50))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_fx_params_mode_encoder_nudges_param() {
+    let (mut mode, to_reaper_rx, to_xtouch_rx) = setup_fx_params_mode();
+    while to_xtouch_rx.try_recv().is_ok() {}
+
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::EncoderTurnDec(EncoderTurnCCW {
+            idx: ChannelIndex::new(0),
+        }),
+        ACTIVE,
+    );
+    // One detent is a hundredth of the range
+    assert_param_value_sent(&to_reaper_rx, 0, 24.0);
+    assert_param_strip(&to_xtouch_rx, 0, 0.24, "Freq", "EQ");
+}
+
+#[test]
+fn test_fx_params_mode_pages_through_params() {
+    let (mut mode, _to_reaper_rx, to_xtouch_rx) = setup_fx_params_mode();
+    while to_xtouch_rx.try_recv().is_ok() {}
+
+    mode.handle_upstream_messages(XTouchUpstreamMsg::BankRightPress, ACTIVE);
+    assert_eq!(mode.page(), 1);
+    assert!(matches!(
+        to_xtouch_rx.recv_timeout(Duration::from_millis(100)),
+        Ok(XTouchDownstreamMsg::AssignmentDisplay(display))
+            if display == AssignmentDisplayMsg::mode_bank('P', 1)
+    ));
+    assert_param_strip(&to_xtouch_rx, 0, 0.0, "Q", "EQ");
+    // The page runs out of parameters, so the second strip is blanked
+    assert!(matches!(
+        to_xtouch_rx.recv_timeout(Duration::from_millis(100)),
+        Ok(XTouchDownstreamMsg::FaderAbs(_))
+    ));
+    assert!(matches!(
+        to_xtouch_rx.recv_timeout(Duration::from_millis(100)),
+        Ok(XTouchDownstreamMsg::EncoderRingLED(
+            EncoderRingLEDMsg::Blank(_)
+        ))
+    ));
+    match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::ScribbleStrip(msg)) => {
+            assert_eq!(msg.idx, 1);
+            assert_eq!(msg.color, ScribbleColor::Off);
+        }
+        other => panic!("Expected ScribbleStrip message but got {:?}", other),
+    }
+
+    // There is no third page to go to
+    mode.handle_upstream_messages(XTouchUpstreamMsg::BankRightPress, ACTIVE);
+    assert_eq!(mode.page(), 1);
+
+    mode.handle_upstream_messages(XTouchUpstreamMsg::BankLeftPress, ACTIVE);
+    assert_eq!(mode.page(), 0);
+}
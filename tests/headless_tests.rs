@@ -0,0 +1,167 @@
+// Tests for running the modes without a control surface attached
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use arpad_rust::guid::TrackGuid;
+use arpad_rust::metrics::Metrics;
+use arpad_rust::midi::control_surface::ControlSurface;
+use arpad_rust::midi::dry_run::DryRunSurface;
+use arpad_rust::midi::xtouch::surface::SurfaceLayout;
+use arpad_rust::midi::xtouch::{
+    ActionButton, ChannelIndex, FaderAbsMsg, XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
+use arpad_rust::modes::mode_manager::{
+    Barrier, Mode, ModeManager, ModeOptions, ModeState, ModeStatus, State,
+};
+use arpad_rust::track::track::{DataPayload, Direction, TrackDataMsg, TrackMsg};
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender, bounded};
+
+// Starts the modes headless, with nothing on the other end of their channel to the surface.
+// Returns where Reaper's side talks to them, where they answer it, and where the bridge's API
+// talks to them.
+fn start_headless(
+    status: &ModeStatus,
+) -> (
+    Sender<TrackMsg>,
+    Receiver<TrackMsg>,
+    Sender<XTouchUpstreamMsg>,
+) {
+    let (reaper_tx, reaper_rx) = bounded(128);
+    let (to_reaper_tx, to_reaper_rx) = bounded(128);
+    let (api_tx, api_rx) = bounded(128);
+    let (to_xtouch_tx, _) = bounded(128);
+    ModeManager::start_with_options(
+        reaper_rx,
+        to_reaper_tx,
+        api_rx,
+        to_xtouch_tx,
+        ModeOptions {
+            status: Some(status.clone()),
+            headless: true,
+            ..ModeOptions::default()
+        },
+    );
+    (reaper_tx, to_reaper_rx, api_tx)
+}
+
+// Reflects the next barrier the modes send upstream as TrackManager would. There's no surface to
+// reflect it after that.
+fn reflect_next_barrier(to_reaper_rx: &Receiver<TrackMsg>, reaper_tx: &Sender<TrackMsg>) {
+    loop {
+        match to_reaper_rx.recv_timeout(Duration::from_millis(500)) {
+            Ok(TrackMsg::Barrier(barrier)) => {
+                reaper_tx.send(TrackMsg::Barrier(barrier)).unwrap();
+                return;
+            }
+            Ok(_) => {}
+            Err(e) => panic!("No barrier sent upstream: {:?}", e),
+        }
+    }
+}
+
+fn wait_for_mode(status: &ModeStatus, expected: ModeState) -> bool {
+    let deadline = Instant::now() + Duration::from_millis(500);
+    while Instant::now() < deadline {
+        if status.mode() == Some(expected) {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    false
+}
+
+#[test]
+fn test_headless_modes_finish_transitions_without_a_surface() {
+    let status = ModeStatus::new();
+    let (reaper_tx, to_reaper_rx, api_tx) = start_headless(&status);
+
+    for data in [
+        DataPayload::ReaperTrackIndex(Some(0)),
+        DataPayload::Selected(true),
+    ] {
+        reaper_tx
+            .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid: TrackGuid::from("headless"),
+                direction: Direction::Downstream,
+                data,
+            }))
+            .unwrap();
+    }
+    // Reaper and the API reach the modes on separate channels, so give the selection a head start
+    std::thread::sleep(Duration::from_millis(50));
+    api_tx.send(XTouchUpstreamMsg::SendPress).unwrap();
+
+    reflect_next_barrier(&to_reaper_rx, &reaper_tx);
+    assert!(wait_for_mode(
+        &status,
+        ModeState {
+            mode: Mode::ReaperSends,
+            state: State::Active,
+        }
+    ));
+
+    // And back again, which the mode only allows once it's active
+    api_tx.send(XTouchUpstreamMsg::PanPress).unwrap();
+    reflect_next_barrier(&to_reaper_rx, &reaper_tx);
+    assert!(wait_for_mode(
+        &status,
+        ModeState {
+            mode: Mode::ReaperVolPan,
+            state: State::Active,
+        }
+    ));
+}
+
+#[test]
+fn test_headless_modes_outlive_the_api_going_away() {
+    let status = ModeStatus::new();
+    let (reaper_tx, _to_reaper_rx, api_tx) = start_headless(&status);
+    drop(api_tx);
+
+    // Still there for Reaper's side, which has probes end at the modes with no surface to reach
+    let metrics = Arc::new(Metrics::new());
+    reaper_tx
+        .send(TrackMsg::Probe(metrics.probe(Instant::now()).unwrap()))
+        .unwrap();
+    let deadline = Instant::now() + Duration::from_millis(500);
+    let mut samples = 0;
+    while samples == 0 && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(5));
+        samples = metrics.report(Duration::from_secs(1)).latency.samples;
+    }
+    assert_eq!(samples, 1);
+}
+
+#[test]
+fn test_dry_run_surface_never_answers() {
+    let surface: Box<dyn ControlSurface> =
+        Box::new(DryRunSurface::new(SurfaceLayout::xtouch_with_extenders(1)));
+    // It has whatever the surface it stands in for would have
+    assert_eq!(surface.capabilities().channels, 16);
+    assert!(
+        surface
+            .capabilities()
+            .action_buttons
+            .contains(&ActionButton::Click)
+    );
+
+    let (to_surface, from_modes) = bounded(128);
+    let (to_modes, from_surface) = bounded(128);
+    surface.connect(from_modes, to_modes);
+    to_surface
+        .send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg {
+            idx: ChannelIndex::new(0),
+            value: 0.5,
+        }))
+        .unwrap();
+    to_surface
+        .send(XTouchDownstreamMsg::Barrier(Barrier::new()))
+        .unwrap();
+
+    // Nothing comes back, but the surface's side stays connected
+    assert!(matches!(
+        from_surface.recv_timeout(Duration::from_millis(50)),
+        Err(RecvTimeoutError::Timeout)
+    ));
+}
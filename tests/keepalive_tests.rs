@@ -0,0 +1,58 @@
+// Tests for telling whether the XTouch is answering the host's keep-alive pings
+
+use std::time::{Duration, Instant};
+
+use arpad_rust::midi::xtouch::keepalive::{
+    HOST_PING, Liveness, SurfaceStatus, XCTL_SURFACE_PING, is_surface_ping,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(6);
+
+#[test]
+fn test_recognizes_surface_pings() {
+    assert!(is_surface_ping(&XCTL_SURFACE_PING));
+    // An MC mode surface answers the device query with its serial number and a challenge
+    assert!(is_surface_ping(&[
+        0xF0, 0x00, 0x00, 0x66, 0x14, 0x01, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 0xF7
+    ]));
+
+    // Our own ping echoed back isn't the surface
+    assert!(!is_surface_ping(&HOST_PING));
+    assert!(!is_surface_ping(&[
+        0xF0, 0x00, 0x20, 0x32, 0x14, 0x4C, 0xF7
+    ]));
+}
+
+#[test]
+fn test_surface_comes_online_when_heard_from() {
+    let start = Instant::now();
+    let mut liveness = Liveness::new(TIMEOUT);
+    assert_eq!(liveness.status(), SurfaceStatus::Offline);
+    // A surface that was never there doesn't go offline again
+    assert_eq!(liveness.check(start + TIMEOUT * 2), None);
+
+    assert_eq!(liveness.heard_from(start), Some(SurfaceStatus::Online));
+    // Only the change is reported
+    assert_eq!(liveness.heard_from(start + Duration::from_secs(2)), None);
+    assert_eq!(liveness.status(), SurfaceStatus::Online);
+}
+
+#[test]
+fn test_surface_goes_offline_after_timeout() {
+    let start = Instant::now();
+    let mut liveness = Liveness::new(TIMEOUT);
+    liveness.heard_from(start);
+
+    assert_eq!(liveness.check(start + TIMEOUT), None);
+    assert_eq!(
+        liveness.check(start + TIMEOUT + Duration::from_millis(1)),
+        Some(SurfaceStatus::Offline)
+    );
+    assert_eq!(liveness.check(start + TIMEOUT * 2), None);
+
+    // And comes back with its next ping
+    assert_eq!(
+        liveness.heard_from(start + TIMEOUT * 3),
+        Some(SurfaceStatus::Online)
+    );
+}
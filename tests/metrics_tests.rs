@@ -0,0 +1,189 @@
+// Tests for the metrics the receive loop reports
+
+use crossbeam_channel::{bounded, unbounded};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use arpad_rust::metrics::{ChannelOccupancy, LatencySummary, Metrics, PROBE_INTERVAL};
+use arpad_rust::midi::xtouch::XTouchDownstreamMsg;
+use arpad_rust::modes::mode_manager::ModeManager;
+use arpad_rust::track::track::TrackMsg;
+
+#[test]
+fn test_report_covers_packets_and_dispatch_since_last_report() {
+    let metrics = Metrics::new();
+    for _ in 0..4 {
+        metrics.record_packet();
+    }
+    metrics.record_dispatch(Duration::from_micros(100));
+    metrics.record_dispatch(Duration::from_micros(300));
+
+    let report = metrics.report(Duration::from_secs(2));
+    assert_eq!(report.packets_per_sec, 2.0);
+    assert_eq!(report.mean_dispatch, Duration::from_micros(200));
+    assert_eq!(report.max_dispatch, Duration::from_micros(300));
+
+    // Each report starts counting afresh
+    let report = metrics.report(Duration::from_secs(2));
+    assert_eq!(report.packets_per_sec, 0.0);
+    assert_eq!(report.mean_dispatch, Duration::ZERO);
+    assert_eq!(report.max_dispatch, Duration::ZERO);
+}
+
+#[test]
+fn test_report_shows_gate_buffers_as_last_recorded() {
+    let metrics = Metrics::new();
+    metrics.set_gate_buffers(3, 12);
+    metrics.set_gate_buffers(2, 5);
+
+    let report = metrics.report(Duration::from_secs(1));
+    assert_eq!(report.buffered_contexts, 2);
+    assert_eq!(report.buffered_messages, 5);
+
+    // Unlike the counters, these describe the present, so they carry over
+    let report = metrics.report(Duration::from_secs(1));
+    assert_eq!(report.buffered_contexts, 2);
+    assert_eq!(report.buffered_messages, 5);
+}
+
+#[test]
+fn test_report_reads_watched_channel_occupancy() {
+    let metrics = Metrics::new();
+    let (bounded_tx, _bounded_rx) = bounded(8);
+    let (unbounded_tx, _unbounded_rx) = unbounded();
+    metrics.watch_channel("bounded", &bounded_tx);
+    metrics.watch_channel("unbounded", &unbounded_tx);
+
+    bounded_tx.send(1).unwrap();
+    bounded_tx.send(2).unwrap();
+    unbounded_tx.send("queued").unwrap();
+
+    assert_eq!(
+        metrics.report(Duration::from_secs(1)).channels,
+        vec![
+            ChannelOccupancy {
+                name: "bounded".to_string(),
+                len: 2,
+                capacity: Some(8),
+                dropped: 0,
+            },
+            ChannelOccupancy {
+                name: "unbounded".to_string(),
+                len: 1,
+                capacity: None,
+                dropped: 0,
+            },
+        ]
+    );
+}
+
+fn ms(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+#[test]
+fn test_latency_summary_percentiles_and_jitter() {
+    let latencies: Vec<Duration> = (1..=20).rev().map(ms).collect();
+    let summary = LatencySummary::of(&latencies, ms(20));
+    assert_eq!(summary.samples, 20);
+    assert_eq!(summary.p50, ms(10));
+    assert_eq!(summary.p95, ms(19));
+    assert_eq!(summary.max, ms(20));
+    assert_eq!(summary.jitter, ms(1));
+
+    let summary = LatencySummary::of(&[ms(2), ms(6), ms(2)], ms(6));
+    assert_eq!(summary.p50, ms(2));
+    assert_eq!(summary.jitter, ms(4));
+
+    assert_eq!(
+        LatencySummary::of(&[], Duration::ZERO),
+        LatencySummary::default()
+    );
+}
+
+#[test]
+fn test_probes_are_sent_at_most_once_per_interval() {
+    let metrics = Arc::new(Metrics::new());
+    let start = Instant::now();
+    assert!(metrics.probe(start).is_some());
+    assert!(metrics.probe(start + PROBE_INTERVAL / 2).is_none());
+    assert!(metrics.probe(start + PROBE_INTERVAL).is_some());
+}
+
+#[test]
+fn test_report_covers_probes_arrived_since_last_report() {
+    let metrics = Arc::new(Metrics::new());
+    let probe = metrics.probe(Instant::now() - ms(5)).unwrap();
+    probe.arrived();
+
+    let latency = metrics.report(Duration::from_secs(1)).latency;
+    assert_eq!(latency.samples, 1);
+    assert!(latency.p50 >= ms(5));
+    assert_eq!(latency.max, latency.p50);
+
+    assert_eq!(
+        metrics.report(Duration::from_secs(1)).latency,
+        LatencySummary::default()
+    );
+}
+
+#[test]
+fn test_probe_arrives_once_through_the_modes_to_the_surface() {
+    let metrics = Arc::new(Metrics::new());
+    let (reaper_tx, reaper_rx) = unbounded();
+    let (_xtouch_tx, xtouch_rx) = unbounded();
+    let (to_reaper_tx, _to_reaper_rx) = unbounded();
+    let (to_xtouch_tx, to_xtouch_rx) = unbounded();
+    ModeManager::start(reaper_rx, to_reaper_tx, xtouch_rx, to_xtouch_tx);
+    // The surface, which is where a probe's way ends
+    std::thread::spawn(move || {
+        for msg in to_xtouch_rx {
+            if let XTouchDownstreamMsg::Probe(probe) = msg {
+                probe.arrived();
+            }
+        }
+    });
+
+    reaper_tx
+        .send(TrackMsg::Probe(metrics.probe(Instant::now()).unwrap()))
+        .unwrap();
+    let deadline = Instant::now() + ms(500);
+    let mut samples = 0;
+    while samples == 0 && Instant::now() < deadline {
+        std::thread::sleep(ms(5));
+        samples = metrics.report(Duration::from_secs(1)).latency.samples;
+    }
+    assert_eq!(samples, 1);
+}
+
+#[test]
+fn test_report_shows_gate_contexts_as_last_recorded() {
+    let metrics = Metrics::new();
+    let report = metrics.report(Duration::from_secs(1));
+    assert_eq!(report.gate_contexts, 0);
+    assert_eq!(report.oldest_buffered, None);
+
+    metrics.set_gate_contexts(4, 3, Some(Duration::from_millis(20)));
+    for _ in 0..2 {
+        let report = metrics.report(Duration::from_secs(1));
+        assert_eq!(report.gate_contexts, 4);
+        assert_eq!(report.initialized_contexts, 3);
+        assert_eq!(report.oldest_buffered, Some(Duration::from_millis(20)));
+    }
+}
+
+#[test]
+fn test_gate_stats_are_wanted_once_per_report() {
+    let metrics = Metrics::new();
+    assert!(!metrics.gate_stats_wanted());
+
+    metrics.report(Duration::from_secs(1));
+    assert!(metrics.gate_stats_wanted());
+    // Until the next report, the stats recorded are good enough
+    assert!(!metrics.gate_stats_wanted());
+
+    metrics.report(Duration::from_secs(1));
+    metrics.report(Duration::from_secs(1));
+    assert!(metrics.gate_stats_wanted());
+    assert!(!metrics.gate_stats_wanted());
+}
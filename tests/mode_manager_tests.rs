@@ -4,7 +4,8 @@
 // ModeManager coordinates between upstream (Reaper) and downstream (XTouch) endpoints,
 // managing different control modes and ensuring proper state synchronization during transitions.
 
-use arpad_rust::midi::xtouch::{FaderAbsMsg, XTouchDownstreamMsg, XTouchUpstreamMsg};
+use arpad_rust::guid::TrackGuid;
+use arpad_rust::midi::xtouch::{ChannelIndex, FaderAbsMsg, XTouchDownstreamMsg, XTouchUpstreamMsg};
 use arpad_rust::modes::mode_manager::{Barrier, ModeManager};
 use arpad_rust::track::track::{DataPayload, Direction, TrackDataMsg, TrackMsg};
 use crossbeam_channel::{Receiver, Sender, bounded};
@@ -35,7 +36,7 @@ fn setup_mode_manager_channels() -> (
 fn test_mode_manager_forwards_track_messages_downstream() {
     let (reaper_tx, _to_reaper_rx, _xtouch_tx, _to_xtouch_rx) = setup_mode_manager_channels();
 
-    let test_guid = "test-track".to_string();
+    let test_guid = TrackGuid::from("test-track");
     let test_volume = 0.5;
 
     // Send a track message from Reaper
@@ -65,7 +66,7 @@ fn test_mode_manager_forwards_xtouch_fader_messages() {
     // Send a fader movement from XTouch
     xtouch_tx
         .send(XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
-            idx: 0,
+            idx: ChannelIndex::new(0),
             value: 0.75,
         }))
         .unwrap();
@@ -130,7 +131,7 @@ fn test_messages_during_startup() {
 
     // Send a message immediately without waiting
     let result = reaper_tx.try_send(TrackMsg::TrackDataMsg(TrackDataMsg {
-        guid: "test".to_string(),
+        guid: "test".into(),
         direction: Direction::Downstream,
         data: DataPayload::Volume(0.5),
     }));
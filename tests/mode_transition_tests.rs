@@ -3,11 +3,17 @@
 // These tests verify the complete mode transition flow involving ModeManager,
 // VolumePanMode, and TrackSendsMode working together.
 
-use arpad_rust::midi::xtouch::{FaderAbsMsg, XTouchDownstreamMsg, XTouchUpstreamMsg};
-use arpad_rust::modes::mode_manager::{Barrier, Mode, ModeManager, ModeState, State};
+use arpad_rust::guid::TrackGuid;
+use arpad_rust::midi::xtouch::{
+    AssignmentDisplayMsg, ChannelIndex, FaderAbsMsg, LEDState, XTouchDownstreamMsg,
+    XTouchUpstreamMsg,
+};
+use arpad_rust::modes::mode_manager::{
+    Barrier, BarrierRecovery, Mode, ModeManager, ModeOptions, ModeState, State,
+};
 use arpad_rust::track::track::{DataPayload, Direction, TrackDataMsg, TrackMsg};
 use crossbeam_channel::{Receiver, Sender, bounded};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Helper to set up channels for mode transition testing
 fn setup_mode_transition_test() -> (
@@ -35,7 +41,7 @@ fn test_mode_transition_vol_pan_to_sends() {
     let (reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx) = setup_mode_transition_test();
 
     // Setup: Send a track with index and mark it as selected
-    let test_guid = "test-track-1".to_string();
+    let test_guid = TrackGuid::from("test-track-1");
     reaper_tx
         .send(TrackMsg::TrackDataMsg(TrackDataMsg {
             guid: test_guid.clone(),
@@ -108,7 +114,7 @@ fn test_mode_transition_sends_to_vol_pan() {
     let (reaper_tx, to_reaper_rx, xtouch_tx, _to_xtouch_rx) = setup_mode_transition_test();
 
     // Setup: Assign track and mark as selected
-    let test_guid = "test-track-2".to_string();
+    let test_guid = TrackGuid::from("test-track-2");
     reaper_tx
         .send(TrackMsg::TrackDataMsg(TrackDataMsg {
             guid: test_guid.clone(),
@@ -175,7 +181,7 @@ fn test_messages_during_barrier_synchronization() {
     let (reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx) = setup_mode_transition_test();
 
     // Setup a track
-    let test_guid = "test-track-3".to_string();
+    let test_guid = TrackGuid::from("test-track-3");
     reaper_tx
         .send(TrackMsg::TrackDataMsg(TrackDataMsg {
             guid: test_guid.clone(),
@@ -196,7 +202,7 @@ fn test_messages_during_barrier_synchronization() {
     // According to ModeManager, these should be blocked in WaitingBarrierFromDownstream state
     xtouch_tx
         .send(XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
-            idx: 0,
+            idx: ChannelIndex::new(0),
             value: 0.5,
         }))
         .unwrap();
@@ -211,9 +217,20 @@ fn test_messages_during_barrier_synchronization() {
             TrackMsg::Barrier(_) => {
                 // Expected during transition
             }
-            TrackMsg::TrackQuery(_) => {
+            TrackMsg::TrackQuery(_) | TrackMsg::DiscoverSends(_) => {
                 // Expected during transition initiation
             }
+            TrackMsg::Replay(_)
+            | TrackMsg::NumTracks(_)
+            | TrackMsg::InitialSync(_)
+            | TrackMsg::CaptureScene(_)
+            | TrackMsg::RecallScene(_)
+            | TrackMsg::TrackRemoved(_)
+            | TrackMsg::Reset
+            | TrackMsg::Acknowledge(_)
+            | TrackMsg::Probe(_) => {
+                // Not used by mode transitions
+            }
             TrackMsg::TrackDataMsg(msg) => {
                 if matches!(msg.data, DataPayload::Volume(_))
                     && msg.direction == Direction::Upstream
@@ -239,7 +256,7 @@ fn test_downstream_messages_during_transition() {
     let (reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx) = setup_mode_transition_test();
 
     // Setup a track
-    let test_guid = "test-track-4".to_string();
+    let test_guid = TrackGuid::from("test-track-4");
     reaper_tx
         .send(TrackMsg::TrackDataMsg(TrackDataMsg {
             guid: test_guid.clone(),
@@ -295,7 +312,7 @@ fn test_barrier_reflection_completes_transition() {
     let (reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx) = setup_mode_transition_test();
 
     // Setup a track
-    let test_guid = "test-track-5".to_string();
+    let test_guid = TrackGuid::from("test-track-5");
     reaper_tx
         .send(TrackMsg::TrackDataMsg(TrackDataMsg {
             guid: test_guid.clone(),
@@ -337,7 +354,7 @@ fn test_barrier_reflection_completes_transition() {
     // Send a fader message
     xtouch_tx
         .send(XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
-            idx: 0,
+            idx: ChannelIndex::new(0),
             value: 0.8,
         }))
         .unwrap();
@@ -370,7 +387,7 @@ fn test_rapid_mode_transitions() {
     let (reaper_tx, _to_reaper_rx, _xtouch_tx, to_xtouch_rx) = setup_mode_transition_test();
 
     // Setup a track with index (Selected isn't handled by VolumePanMode)
-    let test_guid = "test-track-6".to_string();
+    let test_guid = TrackGuid::from("test-track-6");
     reaper_tx
         .send(TrackMsg::TrackDataMsg(TrackDataMsg {
             guid: test_guid.clone(),
@@ -433,7 +450,7 @@ fn test_mode_transition_without_selected_track() {
     std::thread::sleep(Duration::from_millis(50));
 
     // System should still be functional - send a regular message
-    let test_guid = "test-track-7".to_string();
+    let test_guid = TrackGuid::from("test-track-7");
     reaper_tx
         .send(TrackMsg::TrackDataMsg(TrackDataMsg {
             guid: test_guid.clone(),
@@ -447,6 +464,428 @@ fn test_mode_transition_without_selected_track() {
     // Just verify system is still running (doesn't assert specific behavior until implemented)
 }
 
+/// Like setup_mode_transition_test, but following the track selection in Reaper
+fn setup_follow_selection_test() -> (
+    Sender<TrackMsg>,
+    Receiver<TrackMsg>,
+    Sender<XTouchUpstreamMsg>,
+    Receiver<XTouchDownstreamMsg>,
+) {
+    let (reaper_tx, reaper_rx) = bounded(128);
+    let (xtouch_tx, xtouch_rx) = bounded(128);
+    let (to_reaper_tx, to_reaper_rx) = bounded(128);
+    let (to_xtouch_tx, to_xtouch_rx) = bounded(128);
+
+    ModeManager::start_with_options(
+        reaper_rx,
+        to_reaper_tx,
+        xtouch_rx,
+        to_xtouch_tx,
+        ModeOptions {
+            follow_selection: true,
+            ..Default::default()
+        },
+    );
+    std::thread::sleep(Duration::from_millis(50));
+
+    (reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx)
+}
+
+fn send_track_data(reaper_tx: &Sender<TrackMsg>, guid: &str, data: DataPayload) {
+    reaper_tx
+        .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: guid.into(),
+            direction: Direction::Downstream,
+            data,
+        }))
+        .unwrap();
+}
+
+/// Collects what the modes send towards Reaper until a barrier, returning the queries seen on the
+/// way as (guid, direction) and the barrier itself
+fn queries_until_barrier(
+    to_reaper_rx: &Receiver<TrackMsg>,
+) -> (Vec<(TrackGuid, Direction)>, Option<Barrier>) {
+    let mut queries = Vec::new();
+    while let Ok(msg) = to_reaper_rx.recv_timeout(Duration::from_millis(200)) {
+        match msg {
+            TrackMsg::TrackQuery(query) => queries.push((query.guid, query.direction)),
+            TrackMsg::Barrier(barrier) => return (queries, Some(barrier)),
+            _ => {}
+        }
+    }
+    (queries, None)
+}
+
+/// Passes a barrier down through the modes as TrackManager would, and reflects it back up once it
+/// reaches the surface, finishing the transition waiting on it
+fn complete_barrier_handoff(
+    barrier: Barrier,
+    reaper_tx: &Sender<TrackMsg>,
+    xtouch_tx: &Sender<XTouchUpstreamMsg>,
+    to_xtouch_rx: &Receiver<XTouchDownstreamMsg>,
+) {
+    reaper_tx.send(TrackMsg::Barrier(barrier)).unwrap();
+    while let Ok(msg) = to_xtouch_rx.recv_timeout(Duration::from_millis(200)) {
+        if matches!(msg, XTouchDownstreamMsg::Barrier(reached) if reached == barrier) {
+            xtouch_tx.send(XTouchUpstreamMsg::Barrier(barrier)).unwrap();
+            std::thread::sleep(Duration::from_millis(50));
+            return;
+        }
+    }
+    panic!("Barrier never reached the surface");
+}
+
+#[test]
+fn test_selecting_a_track_switches_to_its_sends() {
+    let (reaper_tx, to_reaper_rx, _xtouch_tx, to_xtouch_rx) = setup_follow_selection_test();
+    let test_guid = "test-track-8";
+    send_track_data(
+        &reaper_tx,
+        test_guid,
+        DataPayload::ReaperTrackIndex(Some(0)),
+    );
+    send_track_data(&reaper_tx, test_guid, DataPayload::Selected(true));
+
+    // The sends mode asks for the track's sends, with no button pressed
+    let (queries, barrier) = queries_until_barrier(&to_reaper_rx);
+    assert_eq!(
+        queries,
+        vec![(TrackGuid::from(test_guid), Direction::Downstream)]
+    );
+    assert!(
+        barrier.is_some(),
+        "Should send Barrier during mode transition"
+    );
+
+    let mut saw_sends_display = false;
+    while let Ok(msg) = to_xtouch_rx.recv_timeout(Duration::from_millis(50)) {
+        if let XTouchDownstreamMsg::AssignmentDisplay(display) = msg {
+            saw_sends_display |= display == AssignmentDisplayMsg::mode_bank('S', 0);
+        }
+    }
+    assert!(
+        saw_sends_display,
+        "Assignment display should show the sends mode"
+    );
+}
+
+#[test]
+fn test_repeated_selection_does_not_restart_transition() {
+    let (reaper_tx, to_reaper_rx, _xtouch_tx, _to_xtouch_rx) = setup_follow_selection_test();
+    let test_guid = "test-track-9";
+    send_track_data(
+        &reaper_tx,
+        test_guid,
+        DataPayload::ReaperTrackIndex(Some(0)),
+    );
+    send_track_data(&reaper_tx, test_guid, DataPayload::Selected(true));
+    let (_, barrier) = queries_until_barrier(&to_reaper_rx);
+    assert!(barrier.is_some());
+
+    // Answering the sends mode's query repeats the selection, which is nothing new
+    send_track_data(&reaper_tx, test_guid, DataPayload::Selected(true));
+    let (queries, barrier) = queries_until_barrier(&to_reaper_rx);
+    assert!(queries.is_empty(), "Unexpected queries {:?}", queries);
+    assert!(
+        barrier.is_none(),
+        "Repeated selection should not start a transition"
+    );
+}
+
+#[test]
+fn test_deselecting_the_track_returns_to_vol_pan() {
+    let (reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx) = setup_follow_selection_test();
+    let test_guid = "test-track-10";
+    send_track_data(
+        &reaper_tx,
+        test_guid,
+        DataPayload::ReaperTrackIndex(Some(0)),
+    );
+    send_track_data(&reaper_tx, test_guid, DataPayload::Selected(true));
+    let (_, barrier) = queries_until_barrier(&to_reaper_rx);
+    complete_barrier_handoff(barrier.unwrap(), &reaper_tx, &xtouch_tx, &to_xtouch_rx);
+
+    send_track_data(&reaper_tx, test_guid, DataPayload::Selected(false));
+
+    // VolumePanMode refreshes the tracks it shows, then hands over behind a barrier
+    let (queries, barrier) = queries_until_barrier(&to_reaper_rx);
+    assert_eq!(
+        queries,
+        vec![(TrackGuid::from(test_guid), Direction::Upstream)]
+    );
+    complete_barrier_handoff(barrier.unwrap(), &reaper_tx, &xtouch_tx, &to_xtouch_rx);
+
+    // Once the barrier is back, the faders control volume again
+    xtouch_tx
+        .send(XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
+            idx: ChannelIndex::new(0),
+            value: 0.8,
+        }))
+        .unwrap();
+    let mut found_volume = false;
+    while let Ok(msg) = to_reaper_rx.recv_timeout(Duration::from_millis(100)) {
+        if let TrackMsg::TrackDataMsg(data_msg) = msg {
+            found_volume |= matches!(data_msg.data, DataPayload::Volume(_));
+        }
+    }
+    assert!(
+        found_volume,
+        "Faders should control volume after deselecting"
+    );
+}
+
+#[test]
+fn test_selection_is_not_followed_by_default() {
+    let (reaper_tx, to_reaper_rx, _xtouch_tx, _to_xtouch_rx) = setup_mode_transition_test();
+    let test_guid = "test-track-11";
+    send_track_data(
+        &reaper_tx,
+        test_guid,
+        DataPayload::ReaperTrackIndex(Some(0)),
+    );
+    send_track_data(&reaper_tx, test_guid, DataPayload::Selected(true));
+
+    let (queries, barrier) = queries_until_barrier(&to_reaper_rx);
+    assert!(queries.is_empty(), "Unexpected queries {:?}", queries);
+    assert!(barrier.is_none());
+}
+
+/// The encoder assign button LEDs the modes set, by the mode each button switches to, in order
+/// until the surface goes quiet
+fn mode_button_leds(to_xtouch_rx: &Receiver<XTouchDownstreamMsg>) -> Vec<(Mode, LEDState)> {
+    let mut leds = Vec::new();
+    while let Ok(msg) = to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        match msg {
+            XTouchDownstreamMsg::Pan(led) => leds.push((Mode::ReaperVolPan, led)),
+            XTouchDownstreamMsg::Send(led) => leds.push((Mode::ReaperSends, led)),
+            XTouchDownstreamMsg::Plugin(led) => leds.push((Mode::ReaperFX, led)),
+            XTouchDownstreamMsg::Inst(led) => leds.push((Mode::ReaperFxParams, led)),
+            XTouchDownstreamMsg::Track(led) => leds.push((Mode::ReaperSelectedTrack, led)),
+            _ => {}
+        }
+    }
+    leds
+}
+
+#[test]
+fn test_mode_buttons_switch_modes_and_light_up() {
+    let (reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx) = setup_mode_transition_test();
+    assert_eq!(
+        mode_button_leds(&to_xtouch_rx),
+        vec![(Mode::ReaperVolPan, LEDState::On)]
+    );
+    let test_guid = "test-track-12";
+    send_track_data(
+        &reaper_tx,
+        test_guid,
+        DataPayload::ReaperTrackIndex(Some(0)),
+    );
+    send_track_data(&reaper_tx, test_guid, DataPayload::Selected(true));
+    std::thread::sleep(Duration::from_millis(50));
+
+    // The button flashes until the surface has caught up with the new mode
+    xtouch_tx.send(XTouchUpstreamMsg::SendPress).unwrap();
+    let (_, barrier) = queries_until_barrier(&to_reaper_rx);
+    assert_eq!(
+        mode_button_leds(&to_xtouch_rx),
+        vec![
+            (Mode::ReaperVolPan, LEDState::Off),
+            (Mode::ReaperSends, LEDState::Flash)
+        ]
+    );
+    complete_barrier_handoff(barrier.unwrap(), &reaper_tx, &xtouch_tx, &to_xtouch_rx);
+    assert_eq!(
+        mode_button_leds(&to_xtouch_rx),
+        vec![(Mode::ReaperSends, LEDState::On)]
+    );
+
+    // Pressing it again changes nothing
+    xtouch_tx.send(XTouchUpstreamMsg::SendPress).unwrap();
+    let (_, barrier) = queries_until_barrier(&to_reaper_rx);
+    assert!(barrier.is_none());
+
+    xtouch_tx.send(XTouchUpstreamMsg::PanPress).unwrap();
+    let (_, barrier) = queries_until_barrier(&to_reaper_rx);
+    assert!(
+        barrier.is_some(),
+        "PAN should switch back to volume and pan"
+    );
+    assert_eq!(
+        mode_button_leds(&to_xtouch_rx),
+        vec![
+            (Mode::ReaperSends, LEDState::Off),
+            (Mode::ReaperVolPan, LEDState::Flash)
+        ]
+    );
+}
+
+#[test]
+fn test_mode_buttons_ignored_mid_transition() {
+    let (reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx) = setup_mode_transition_test();
+    let test_guid = "test-track-13";
+    send_track_data(
+        &reaper_tx,
+        test_guid,
+        DataPayload::ReaperTrackIndex(Some(0)),
+    );
+    send_track_data(&reaper_tx, test_guid, DataPayload::Selected(true));
+    std::thread::sleep(Duration::from_millis(50));
+    xtouch_tx.send(XTouchUpstreamMsg::SendPress).unwrap();
+    let (_, barrier) = queries_until_barrier(&to_reaper_rx);
+    assert!(barrier.is_some());
+    mode_button_leds(&to_xtouch_rx);
+
+    // Still waiting on the barrier, so the sends mode's button keeps flashing
+    xtouch_tx.send(XTouchUpstreamMsg::PluginPress).unwrap();
+    let (_, barrier) = queries_until_barrier(&to_reaper_rx);
+    assert!(barrier.is_none());
+    assert!(mode_button_leds(&to_xtouch_rx).is_empty());
+}
+
+#[test]
+fn test_mode_button_without_selected_track_stays_put() {
+    let (reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx) = setup_mode_transition_test();
+    mode_button_leds(&to_xtouch_rx);
+
+    xtouch_tx.send(XTouchUpstreamMsg::SendPress).unwrap();
+    let (_, barrier) = queries_until_barrier(&to_reaper_rx);
+    assert!(barrier.is_none());
+    assert!(mode_button_leds(&to_xtouch_rx).is_empty());
+
+    // Still in VolumePanMode and taking button presses
+    send_track_data(&reaper_tx, "test-track-14", DataPayload::Selected(true));
+    std::thread::sleep(Duration::from_millis(50));
+    xtouch_tx.send(XTouchUpstreamMsg::SendPress).unwrap();
+    let (_, barrier) = queries_until_barrier(&to_reaper_rx);
+    assert!(barrier.is_some());
+}
+
+/// Like setup_mode_transition_test, but giving up on barriers after a while, with a track
+/// selected for the sends mode to switch to
+fn setup_barrier_timeout_test(
+    recovery: BarrierRecovery,
+) -> (
+    Sender<TrackMsg>,
+    Receiver<TrackMsg>,
+    Sender<XTouchUpstreamMsg>,
+    Receiver<XTouchDownstreamMsg>,
+) {
+    let (reaper_tx, reaper_rx) = bounded(128);
+    let (xtouch_tx, xtouch_rx) = bounded(128);
+    let (to_reaper_tx, to_reaper_rx) = bounded(128);
+    let (to_xtouch_tx, to_xtouch_rx) = bounded(128);
+
+    ModeManager::start_with_options(
+        reaper_rx,
+        to_reaper_tx,
+        xtouch_rx,
+        to_xtouch_tx,
+        ModeOptions {
+            barrier_timeout: Some(BARRIER_TIMEOUT),
+            barrier_recovery: recovery,
+            ..Default::default()
+        },
+    );
+    let test_guid = "test-track-15";
+    send_track_data(
+        &reaper_tx,
+        test_guid,
+        DataPayload::ReaperTrackIndex(Some(0)),
+    );
+    send_track_data(&reaper_tx, test_guid, DataPayload::Selected(true));
+    std::thread::sleep(Duration::from_millis(50));
+
+    (reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx)
+}
+
+const BARRIER_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// The next barrier the modes send towards Reaper, if there's one within `wait`
+fn barrier_within(to_reaper_rx: &Receiver<TrackMsg>, wait: Duration) -> Option<Barrier> {
+    let deadline = Instant::now() + wait;
+    while let Ok(msg) = to_reaper_rx.recv_deadline(deadline) {
+        if let TrackMsg::Barrier(barrier) = msg {
+            return Some(barrier);
+        }
+    }
+    None
+}
+
+#[test]
+fn test_barrier_timeout_retries_then_activates() {
+    let (_reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx) =
+        setup_barrier_timeout_test(BarrierRecovery::Retry(1));
+    xtouch_tx.send(XTouchUpstreamMsg::SendPress).unwrap();
+    let first = barrier_within(&to_reaper_rx, Duration::from_secs(1)).unwrap();
+
+    // The barrier never comes back, so the transition starts over once with a new one
+    let retried = barrier_within(&to_reaper_rx, Duration::from_secs(1)).unwrap();
+    assert_ne!(first, retried);
+    // That never comes back either, so the mode is activated as it is
+    assert_eq!(barrier_within(&to_reaper_rx, Duration::from_secs(1)), None);
+    assert_eq!(
+        mode_button_leds(&to_xtouch_rx),
+        vec![
+            (Mode::ReaperVolPan, LEDState::On),
+            (Mode::ReaperVolPan, LEDState::Off),
+            (Mode::ReaperSends, LEDState::Flash),
+            (Mode::ReaperSends, LEDState::On)
+        ]
+    );
+
+    // And takes button presses again
+    xtouch_tx.send(XTouchUpstreamMsg::PanPress).unwrap();
+    assert!(barrier_within(&to_reaper_rx, Duration::from_secs(1)).is_some());
+}
+
+#[test]
+fn test_barrier_timeout_reverts_to_previous_mode() {
+    let (reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx) =
+        setup_barrier_timeout_test(BarrierRecovery::Revert);
+    xtouch_tx.send(XTouchUpstreamMsg::SendPress).unwrap();
+    barrier_within(&to_reaper_rx, Duration::from_secs(1)).unwrap();
+
+    let reverted = barrier_within(&to_reaper_rx, Duration::from_secs(1)).unwrap();
+    assert_eq!(
+        mode_button_leds(&to_xtouch_rx),
+        vec![
+            (Mode::ReaperVolPan, LEDState::On),
+            (Mode::ReaperVolPan, LEDState::Off),
+            (Mode::ReaperSends, LEDState::Flash),
+            (Mode::ReaperSends, LEDState::Off),
+            (Mode::ReaperVolPan, LEDState::Flash)
+        ]
+    );
+    complete_barrier_handoff(reverted, &reaper_tx, &xtouch_tx, &to_xtouch_rx);
+    assert_eq!(
+        mode_button_leds(&to_xtouch_rx),
+        vec![(Mode::ReaperVolPan, LEDState::On)]
+    );
+}
+
+#[test]
+fn test_barrier_timeout_forces_mode_active() {
+    let (reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx) =
+        setup_barrier_timeout_test(BarrierRecovery::ForceActivate);
+    xtouch_tx.send(XTouchUpstreamMsg::SendPress).unwrap();
+    let barrier = barrier_within(&to_reaper_rx, Duration::from_secs(1)).unwrap();
+    assert_eq!(barrier_within(&to_reaper_rx, Duration::from_secs(1)), None);
+    assert_eq!(
+        mode_button_leds(&to_xtouch_rx),
+        vec![
+            (Mode::ReaperVolPan, LEDState::On),
+            (Mode::ReaperVolPan, LEDState::Off),
+            (Mode::ReaperSends, LEDState::Flash),
+            (Mode::ReaperSends, LEDState::On)
+        ]
+    );
+
+    // The barrier turning up late changes nothing
+    complete_barrier_handoff(barrier, &reaper_tx, &xtouch_tx, &to_xtouch_rx);
+    assert!(mode_button_leds(&to_xtouch_rx).is_empty());
+}
+
 // TODO: Additional edge cases to test once mode transitions are fully implemented:
 // - Test message ordering guarantees during transition
 // - Test concurrent track updates during transition
@@ -0,0 +1,120 @@
+// Tests for holding back rapid repeats of the same OSC address
+
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use rosc::{OscMessage, OscPacket, OscType};
+
+use arpad_rust::osc::coalesce::{CoalescingTransport, DISCRETE_ROUTES};
+use arpad_rust::osc::transport::OscTransport;
+
+const WINDOW: Duration = Duration::from_millis(50);
+
+// Records the decoded messages that get through
+#[derive(Default)]
+struct RecordingTransport {
+    sent: Mutex<Vec<OscMessage>>,
+}
+
+impl RecordingTransport {
+    fn sent(&self) -> Vec<(String, OscType)> {
+        self.sent
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|msg| (msg.addr.clone(), msg.args[0].clone()))
+            .collect()
+    }
+}
+
+impl OscTransport for RecordingTransport {
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        match rosc::decoder::decode_udp(packet).unwrap().1 {
+            OscPacket::Message(msg) => self.sent.lock().unwrap().push(msg),
+            OscPacket::Bundle(_) => panic!("Expected a message"),
+        }
+        Ok(())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+fn coalescing() -> (Arc<RecordingTransport>, CoalescingTransport) {
+    let inner = Arc::new(RecordingTransport::default());
+    let transport = CoalescingTransport::new(
+        inner.clone(),
+        WINDOW,
+        DISCRETE_ROUTES
+            .iter()
+            .map(|route| route.to_string())
+            .collect(),
+    );
+    (inner, transport)
+}
+
+fn send(transport: &CoalescingTransport, addr: &str, arg: OscType) {
+    let packet = OscPacket::Message(OscMessage {
+        addr: addr.to_string(),
+        args: vec![arg],
+    });
+    transport
+        .send(&rosc::encoder::encode(&packet).unwrap())
+        .unwrap();
+}
+
+#[test]
+fn test_repeats_within_the_window_collapse_to_the_latest() {
+    let (inner, transport) = coalescing();
+    for volume in [0.1, 0.2, 0.3, 0.4] {
+        send(&transport, "/track/a/volume", OscType::Float(volume));
+    }
+    // Other addresses have windows of their own
+    send(&transport, "/track/b/volume", OscType::Float(0.9));
+    assert_eq!(
+        inner.sent(),
+        vec![
+            ("/track/a/volume".to_string(), OscType::Float(0.1)),
+            ("/track/b/volume".to_string(), OscType::Float(0.9)),
+        ]
+    );
+
+    thread::sleep(WINDOW * 3);
+    assert_eq!(
+        inner.sent()[2..],
+        [("/track/a/volume".to_string(), OscType::Float(0.4))]
+    );
+}
+
+#[test]
+fn test_toggles_bypass_the_window() {
+    let (inner, transport) = coalescing();
+    send(&transport, "/track/a/mute", OscType::Bool(true));
+    send(&transport, "/track/a/mute", OscType::Bool(false));
+    send(&transport, "/track/a/fx/0/bypass", OscType::Bool(true));
+    send(&transport, "/track/a/fx/0/bypass", OscType::Bool(false));
+    assert_eq!(inner.sent().len(), 4);
+}
+
+#[test]
+fn test_sends_after_the_window_go_straight_out() {
+    let (inner, transport) = coalescing();
+    send(&transport, "/track/a/pan", OscType::Float(0.1));
+    thread::sleep(WINDOW * 2);
+    send(&transport, "/track/a/pan", OscType::Float(0.2));
+    assert_eq!(inner.sent().len(), 2);
+}
+
+#[test]
+fn test_zero_window_sends_everything() {
+    let inner = Arc::new(RecordingTransport::default());
+    let transport = CoalescingTransport::new(inner.clone(), Duration::ZERO, vec![]);
+    for volume in [0.1, 0.2, 0.3] {
+        send(&transport, "/track/a/volume", OscType::Float(volume));
+    }
+    assert_eq!(inner.sent().len(), 3);
+}
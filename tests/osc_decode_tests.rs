@@ -0,0 +1,369 @@
+// Tests for decoding the arguments of incoming messages, and what the dispatcher does with ones
+// that can't be decoded, down to packets whose strings aren't UTF-8
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use rosc::{OscArray, OscMessage, OscPacket, OscType};
+
+use arpad_rust::osc::decode::{
+    FromOscArg, Unhandled, binary_arg, decode_packet, encode_packet, expect, line_up,
+};
+use arpad_rust::osc::generated_osc::{Reaper, TrackNameArgs, TrackVolumeArgs, dispatch_osc};
+use arpad_rust::osc::transport::OscTransport;
+use arpad_rust::traits::Bind;
+
+struct NullTransport;
+
+impl OscTransport for NullTransport {
+    fn send(&self, _packet: &[u8]) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+#[test]
+fn test_numbers_decode_across_types_without_losing_anything() {
+    assert_eq!(i32::from_osc_arg(&OscType::Float(3.0)), Some(3));
+    assert_eq!(i32::from_osc_arg(&OscType::Float(3.5)), None);
+    assert_eq!(i32::from_osc_arg(&OscType::Long(i64::MAX)), None);
+    assert_eq!(
+        i32::from_osc_arg(&OscType::String(" 7".to_string())),
+        Some(7)
+    );
+    assert_eq!(f32::from_osc_arg(&OscType::Int(2)), Some(2.0));
+    assert_eq!(f64::from_osc_arg(&OscType::Float(0.5)), Some(0.5));
+    assert_eq!(
+        Vec::<i32>::from_osc_arg(&OscType::Array(OscArray {
+            content: vec![OscType::Int(1), OscType::Float(2.0)],
+        })),
+        Some(vec![1, 2])
+    );
+    assert_eq!(String::from_osc_arg(&OscType::Int(1)), None);
+}
+
+#[test]
+fn test_bools_decode_from_zero_and_one() {
+    assert_eq!(bool::from_osc_arg(&OscType::Float(1.0)), Some(true));
+    assert_eq!(bool::from_osc_arg(&OscType::Int(0)), Some(false));
+    assert_eq!(
+        bool::from_osc_arg(&OscType::String("1".to_string())),
+        Some(true)
+    );
+    assert_eq!(
+        bool::from_osc_arg(&OscType::String("False".to_string())),
+        Some(false)
+    );
+    // Anything else is more likely a mistake than a toggle
+    assert_eq!(bool::from_osc_arg(&OscType::Float(0.7)), None);
+    assert_eq!(
+        bool::from_osc_arg(&OscType::String("yes".to_string())),
+        None
+    );
+}
+
+#[test]
+fn test_dispatcher_coerces_arguments_for_bound_handlers() {
+    let mut reaper = Reaper::new(Arc::new(NullTransport));
+    let muted = Arc::new(Mutex::new(Vec::new()));
+    reaper.track_mute("abc".into()).bind({
+        let muted = muted.clone();
+        move |args| muted.lock().unwrap().push(args.mute)
+    });
+    let unhandled = RefCell::new(Vec::new());
+    for arg in [
+        OscType::Float(1.0),
+        OscType::Int(0),
+        OscType::String("1".to_string()),
+    ] {
+        let msg = OscMessage {
+            addr: "/track/abc/mute".to_string(),
+            args: vec![arg],
+        };
+        dispatch_osc(&mut reaper, msg, |addr, reason| {
+            unhandled
+                .borrow_mut()
+                .push((addr.to_string(), reason.clone()))
+        });
+    }
+    assert_eq!(*muted.lock().unwrap(), vec![true, false, true]);
+    assert!(unhandled.borrow().is_empty(), "{:?}", unhandled.borrow());
+}
+
+#[test]
+fn test_dispatcher_reports_undecodable_arguments() {
+    let mut reaper = Reaper::new(Arc::new(NullTransport));
+    let volumes = Arc::new(Mutex::new(Vec::new()));
+    reaper.track_volume("abc".into()).bind({
+        let volumes = volumes.clone();
+        move |args| volumes.lock().unwrap().push(args.volume)
+    });
+    let unhandled = RefCell::new(Vec::new());
+    for args in [vec![OscType::String("loud".to_string())], vec![]] {
+        let msg = OscMessage {
+            addr: "/track/abc/volume".to_string(),
+            args,
+        };
+        dispatch_osc(&mut reaper, msg, |addr, reason| {
+            unhandled
+                .borrow_mut()
+                .push((addr.to_string(), reason.clone()))
+        });
+    }
+    assert!(volumes.lock().unwrap().is_empty());
+    assert_eq!(
+        *unhandled.borrow(),
+        vec![
+            (
+                "/track/abc/volume".to_string(),
+                Unhandled::BadArgs("argument 0 should be float, not String(\"loud\")".to_string())
+            ),
+            (
+                "/track/abc/volume".to_string(),
+                Unhandled::BadArgs("argument 0 is missing".to_string())
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_arguments_in_order_are_taken_as_they_are() {
+    let args = vec![OscType::Float(1.0), OscType::String("db".to_string())];
+    let lined_up = line_up(&args, &[expect::<bool>(), expect::<String>()]);
+    assert!(matches!(lined_up, Cow::Borrowed(_)));
+}
+
+#[test]
+fn test_reordered_arguments_are_lined_up_by_type() {
+    let args = vec![
+        OscType::String("db".to_string()),
+        OscType::Int(3),
+        OscType::Float(0.5),
+    ];
+    assert_eq!(
+        *line_up(&args, &[expect::<f32>(), expect::<String>()]),
+        [OscType::Float(0.5), OscType::String("db".to_string())]
+    );
+    // Nothing of the type to take, so the message fails to decode as it was sent
+    assert_eq!(*line_up(&args, &[expect::<f32>(), expect::<bool>()]), *args);
+}
+
+#[test]
+fn test_dispatcher_finds_arguments_among_ones_it_doesnt_know() {
+    let mut reaper = Reaper::new(Arc::new(NullTransport));
+    let volumes = Arc::new(Mutex::new(Vec::new()));
+    reaper.track_volume("abc".into()).bind({
+        let volumes = volumes.clone();
+        move |args| volumes.lock().unwrap().push(args.volume)
+    });
+    let unhandled = RefCell::new(Vec::new());
+    for args in [
+        vec![OscType::Float(0.25), OscType::String("extra".to_string())],
+        vec![OscType::String("extra".to_string()), OscType::Float(0.5)],
+    ] {
+        let msg = OscMessage {
+            addr: "/track/abc/volume".to_string(),
+            args,
+        };
+        dispatch_osc(&mut reaper, msg, |addr, reason| {
+            unhandled
+                .borrow_mut()
+                .push((addr.to_string(), reason.clone()))
+        });
+    }
+    assert_eq!(*volumes.lock().unwrap(), vec![0.25, 0.5]);
+    assert!(unhandled.borrow().is_empty(), "{:?}", unhandled.borrow());
+}
+
+// `bytes` as an OSC string: null-terminated and padded to a multiple of 4
+fn osc_string(bytes: &[u8]) -> Vec<u8> {
+    let mut out = bytes.to_vec();
+    out.resize((bytes.len() / 4 + 1) * 4, 0);
+    out
+}
+
+// A message to `addr` with a string argument for each of `strings`, whatever their bytes
+fn raw_message(addr: &[u8], strings: &[&[u8]]) -> Vec<u8> {
+    let mut packet = osc_string(addr);
+    packet.extend(osc_string(
+        format!(",{}", "s".repeat(strings.len())).as_bytes(),
+    ));
+    for string in strings {
+        packet.extend(osc_string(string));
+    }
+    packet
+}
+
+#[test]
+fn test_strings_that_arent_utf8_arrive_as_their_bytes() {
+    let packet = raw_message(b"/track/abc/name", &[b"Caf\xe9", b"ok"]);
+    assert!(rosc::decoder::decode_udp(&packet).is_err());
+    assert_eq!(
+        decode_packet(&packet).unwrap(),
+        OscPacket::Message(OscMessage {
+            addr: "/track/abc/name".to_string(),
+            args: vec![
+                OscType::Blob(b"Caf\xe9".to_vec()),
+                OscType::String("ok".to_string()),
+            ],
+        })
+    );
+    // An address is made UTF-8, as there's no other way to carry it
+    let packet = raw_message(b"/track/\xff/name", &[b"Drums"]);
+    let OscPacket::Message(msg) = decode_packet(&packet).unwrap() else {
+        panic!("expected a message");
+    };
+    assert_eq!(msg.addr, "/track/\u{FFFD}/name");
+    assert_eq!(msg.args, vec![OscType::String("Drums".to_string())]);
+}
+
+#[test]
+fn test_strings_that_arent_utf8_are_repaired_inside_bundles() {
+    let element = raw_message(b"/track/abc/name", &[b"\xc3("]);
+    let mut packet = osc_string(b"#bundle");
+    packet.extend([0, 0, 0, 0, 0, 0, 0, 1]);
+    packet.extend((element.len() as i32).to_be_bytes());
+    packet.extend(&element);
+    // rosc on its own leaves the message out of the bundle without saying so
+    let OscPacket::Bundle(bundle) = decode_packet(&packet).unwrap() else {
+        panic!("expected a bundle");
+    };
+    assert_eq!(
+        bundle.content,
+        vec![OscPacket::Message(OscMessage {
+            addr: "/track/abc/name".to_string(),
+            args: vec![OscType::Blob(b"\xc3(".to_vec())],
+        })]
+    );
+}
+
+#[test]
+fn test_packets_malformed_otherwise_are_still_errors() {
+    let mut packet = raw_message(b"/track/abc/name", &[b"Caf\xe9"]);
+    packet.truncate(packet.len() - 4);
+    assert!(decode_packet(&packet).is_err());
+    assert!(decode_packet(b"/no/terminator").is_err());
+}
+
+#[test]
+fn test_string_arguments_decode_from_bytes_lossily() {
+    assert_eq!(
+        String::from_osc_arg(&OscType::Blob(b"Caf\xe9".to_vec())),
+        Some("Caf\u{FFFD}".to_string())
+    );
+    assert_eq!(
+        <Vec<u8>>::from_osc_arg(&OscType::String("Café".to_string())),
+        Some("Café".as_bytes().to_vec())
+    );
+}
+
+#[test]
+fn test_track_names_arrive_as_their_bytes() {
+    let mut reaper = Reaper::new(Arc::new(NullTransport));
+    let names = Arc::new(Mutex::new(Vec::new()));
+    reaper.track_name("abc".into()).bind({
+        let names = names.clone();
+        move |args| names.lock().unwrap().push(args.name)
+    });
+    let OscPacket::Message(msg) =
+        decode_packet(&raw_message(b"/track/abc/name", &[b"Caf\xe9"])).unwrap()
+    else {
+        panic!("expected a message");
+    };
+    dispatch_osc(&mut reaper, msg, |addr, reason| {
+        panic!("{} wasn't handled: {}", addr, reason)
+    });
+    assert_eq!(*names.lock().unwrap(), vec![b"Caf\xe9".to_vec()]);
+}
+
+// Keeps every packet sent through it
+#[derive(Clone, Default)]
+struct RecordingTransport(Arc<Mutex<Vec<Vec<u8>>>>);
+
+impl OscTransport for RecordingTransport {
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        self.0.lock().unwrap().push(packet.to_vec());
+        Ok(())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+#[test]
+fn test_track_names_go_out_as_their_bytes() {
+    let transport = RecordingTransport::default();
+    let reaper = Reaper::new(Arc::new(transport.clone()));
+    reaper
+        .track_name("abc".into())
+        .set_value(b"Caf\xe9".to_vec())
+        .unwrap();
+    reaper.track_name("abc".into()).set_value("Drums").unwrap();
+    let mut bundle = reaper.bundle();
+    bundle
+        .set(
+            &reaper.track_volume("abc".into()),
+            TrackVolumeArgs::new(0.5),
+        )
+        .set(
+            &reaper.track_name("abc".into()),
+            TrackNameArgs::new(b"\xc3(".to_vec()),
+        );
+    bundle.commit().unwrap();
+
+    let sent = transport.0.lock().unwrap();
+    assert_eq!(sent[0], raw_message(b"/track/abc/name", &[b"Caf\xe9"]));
+    assert_eq!(sent[1], raw_message(b"/track/abc/name", &[b"Drums"]));
+    // Inside a bundle, only the message with the name has it sent as a string
+    let mut volume = osc_string(b"/track/abc/volume");
+    volume.extend(osc_string(b",f"));
+    volume.extend(0.5f32.to_be_bytes());
+    let name = raw_message(b"/track/abc/name", &[b"\xc3("]);
+    let mut packet = osc_string(b"#bundle");
+    packet.extend([0, 0, 0, 0, 0, 0, 0, 1]);
+    for element in [volume, name] {
+        packet.extend((element.len() as i32).to_be_bytes());
+        packet.extend(element);
+    }
+    assert_eq!(sent[2], packet);
+}
+
+#[test]
+fn test_only_binary_arguments_are_sent_as_strings() {
+    let msg = OscMessage {
+        addr: "/a".to_string(),
+        args: vec![
+            OscType::Array(OscArray {
+                content: vec![OscType::Blob(vec![1]), OscType::Int(2)],
+            }),
+            binary_arg(b"\xff".to_vec()),
+            OscType::Blob(vec![3]),
+        ],
+    };
+    let mut expected = osc_string(b"/a");
+    expected.extend(osc_string(b",[bi]sb"));
+    expected.extend([0, 0, 0, 1, 1, 0, 0, 0]);
+    expected.extend([0, 0, 0, 2]);
+    expected.extend(osc_string(b"\xff"));
+    expected.extend([0, 0, 0, 1, 3, 0, 0, 0]);
+    assert_eq!(
+        encode_packet(&OscPacket::Message(msg.clone()), &[&[1]]).unwrap(),
+        expected
+    );
+    // Arguments that are UTF-8 are plain strings to begin with
+    assert_eq!(
+        binary_arg(b"ok".to_vec()),
+        OscType::String("ok".to_string())
+    );
+    assert_eq!(
+        encode_packet(&OscPacket::Message(msg.clone()), &[]).unwrap(),
+        rosc::encoder::encode(&OscPacket::Message(msg)).unwrap()
+    );
+}
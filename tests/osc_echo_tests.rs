@@ -0,0 +1,134 @@
+// Tests for dropping the changes Reaper reports back to us after we make them
+
+use std::collections::VecDeque;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use rosc::{OscBundle, OscMessage, OscPacket, OscTime, OscType};
+
+use arpad_rust::osc::echo::EchoSuppressingTransport;
+use arpad_rust::osc::transport::OscTransport;
+
+const TTL: Duration = Duration::from_millis(50);
+
+// Hands out the packets queued on it, then fails like a closed socket
+#[derive(Default)]
+struct ScriptedTransport {
+    incoming: Mutex<VecDeque<Vec<u8>>>,
+}
+
+impl ScriptedTransport {
+    fn queue(&self, packet: OscPacket) {
+        let buf = rosc::encoder::encode(&packet).unwrap();
+        self.incoming.lock().unwrap().push_back(buf);
+    }
+}
+
+impl OscTransport for ScriptedTransport {
+    fn send(&self, _packet: &[u8]) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        match self.incoming.lock().unwrap().pop_front() {
+            Some(buf) => Ok((buf, "127.0.0.1:8000".parse().unwrap())),
+            None => Err(io::ErrorKind::ConnectionAborted.into()),
+        }
+    }
+}
+
+fn suppressing() -> (Arc<ScriptedTransport>, EchoSuppressingTransport) {
+    let inner = Arc::new(ScriptedTransport::default());
+    (inner.clone(), EchoSuppressingTransport::new(inner, TTL))
+}
+
+fn volume(value: f32) -> OscPacket {
+    OscPacket::Message(OscMessage {
+        addr: "/track/abc/volume".to_string(),
+        args: vec![OscType::Float(value)],
+    })
+}
+
+fn mute(value: bool) -> OscPacket {
+    OscPacket::Message(OscMessage {
+        addr: "/track/abc/mute".to_string(),
+        args: vec![OscType::Bool(value)],
+    })
+}
+
+fn bundle(content: Vec<OscPacket>) -> OscPacket {
+    OscPacket::Bundle(OscBundle {
+        timetag: OscTime::from((0, 1)),
+        content,
+    })
+}
+
+fn send(transport: &EchoSuppressingTransport, packet: OscPacket) {
+    transport
+        .send(&rosc::encoder::encode(&packet).unwrap())
+        .unwrap();
+}
+
+// Everything received until the script runs out
+fn received(transport: &EchoSuppressingTransport) -> Vec<OscPacket> {
+    let mut packets = vec![];
+    while let Ok((buf, _)) = transport.recv() {
+        packets.push(rosc::decoder::decode_udp(&buf).unwrap().1);
+    }
+    packets
+}
+
+#[test]
+fn test_echo_of_a_value_sent_is_dropped_once() {
+    let (inner, transport) = suppressing();
+    send(&transport, volume(0.5));
+    inner.queue(volume(0.5));
+    inner.queue(volume(0.5));
+    assert_eq!(received(&transport), vec![volume(0.5)]);
+}
+
+#[test]
+fn test_other_values_get_through() {
+    let (inner, transport) = suppressing();
+    send(&transport, volume(0.5));
+    inner.queue(volume(0.25));
+    inner.queue(mute(true));
+    assert_eq!(received(&transport), vec![volume(0.25), mute(true)]);
+}
+
+#[test]
+fn test_value_coming_back_after_ttl_gets_through() {
+    let (inner, transport) = suppressing();
+    send(&transport, volume(0.5));
+    thread::sleep(TTL + Duration::from_millis(20));
+    inner.queue(volume(0.5));
+    assert_eq!(received(&transport), vec![volume(0.5)]);
+}
+
+#[test]
+fn test_only_the_latest_value_sent_is_an_echo() {
+    let (inner, transport) = suppressing();
+    send(&transport, volume(0.5));
+    send(&transport, volume(0.6));
+    inner.queue(volume(0.5));
+    inner.queue(volume(0.6));
+    assert_eq!(received(&transport), vec![volume(0.5)]);
+}
+
+#[test]
+fn test_echoes_are_dropped_from_bundles() {
+    let (inner, transport) = suppressing();
+    send(&transport, bundle(vec![volume(0.5), mute(true)]));
+    inner.queue(bundle(vec![volume(0.5), mute(false)]));
+    // The volume's echo came already
+    inner.queue(bundle(vec![volume(0.5)]));
+    // Left empty, so it isn't received at all
+    inner.queue(bundle(vec![mute(true)]));
+    assert_eq!(
+        received(&transport),
+        vec![bundle(vec![mute(false)]), bundle(vec![volume(0.5)])]
+    );
+}
@@ -0,0 +1,68 @@
+// Tests for the errors the generated Reaper client reports when a message can't be sent
+
+use std::error::Error;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use arpad_rust::osc::error::{OscError, check_address_segment};
+use arpad_rust::osc::generated_osc::{Reaper, TrackVolumeArgs};
+use arpad_rust::osc::transport::OscTransport;
+use arpad_rust::traits::{Query, Set};
+
+// Fails every send, counting the attempts
+#[derive(Default)]
+struct FailingTransport {
+    attempts: Mutex<usize>,
+}
+
+impl OscTransport for FailingTransport {
+    fn send(&self, _packet: &[u8]) -> io::Result<()> {
+        *self.attempts.lock().unwrap() += 1;
+        Err(io::ErrorKind::ConnectionRefused.into())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+#[test]
+fn test_transport_failures_are_io_errors() {
+    let transport = Arc::new(FailingTransport::default());
+    let reaper = Reaper::new(transport.clone());
+
+    let err = reaper
+        .track_volume("abc".into())
+        .set(TrackVolumeArgs { volume: 0.5 })
+        .unwrap_err();
+    let OscError::Io(io_err) = &err else {
+        panic!("Expected an Io error but got {:?}", err);
+    };
+    assert_eq!(io_err.kind(), io::ErrorKind::ConnectionRefused);
+    assert!(err.source().is_some());
+
+    assert!(matches!(
+        reaper.track_volume("abc".into()).query(),
+        Err(OscError::Io(_))
+    ));
+    assert_eq!(*transport.attempts.lock().unwrap(), 2);
+}
+
+#[test]
+fn test_bad_address_segments_are_rejected_before_sending() {
+    let transport = Arc::new(FailingTransport::default());
+    let reaper = Reaper::new(transport.clone());
+
+    let err = reaper.track_volume("abc/def".into()).query().unwrap_err();
+    assert!(matches!(&err, OscError::AddressFormat(segment) if segment == "abc/def"));
+    assert_eq!(err.to_string(), "invalid OSC address segment: \"abc/def\"");
+    assert_eq!(*transport.attempts.lock().unwrap(), 0);
+}
+
+#[test]
+fn test_check_address_segment() {
+    assert!(check_address_segment("{0A1B2C3D-0000-4000-8000-000000000000}").is_ok());
+    assert!(check_address_segment("").is_err());
+    assert!(check_address_segment("a/b").is_err());
+}
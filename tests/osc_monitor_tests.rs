@@ -0,0 +1,105 @@
+// Tests for the OSC monitor: naming the routes messages match, filtering them and printing what
+// arrives
+
+use std::collections::VecDeque;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+
+use rosc::{OscBundle, OscMessage, OscPacket, OscTime, OscType};
+
+use arpad_rust::osc::monitor::{self, MonitorFilter, describe, matching_routes};
+use arpad_rust::osc::transport::OscTransport;
+
+fn message(addr: &str, args: Vec<OscType>) -> OscMessage {
+    OscMessage {
+        addr: addr.to_string(),
+        args,
+    }
+}
+
+// Hands out the packets it was made with, then fails like a closed socket
+struct ScriptedTransport {
+    packets: Mutex<VecDeque<Vec<u8>>>,
+}
+
+impl ScriptedTransport {
+    fn new(packets: Vec<Vec<u8>>) -> Self {
+        ScriptedTransport {
+            packets: Mutex::new(packets.into()),
+        }
+    }
+}
+
+impl OscTransport for ScriptedTransport {
+    fn send(&self, _packet: &[u8]) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        match self.packets.lock().unwrap().pop_front() {
+            Some(packet) => Ok((packet, "127.0.0.1:8000".parse().unwrap())),
+            None => Err(io::ErrorKind::UnexpectedEof.into()),
+        }
+    }
+}
+
+#[test]
+fn test_messages_are_described_by_the_route_they_match() {
+    assert_eq!(
+        describe(&message(
+            "/track/abc/send/2/volume",
+            vec![OscType::Float(0.75)]
+        )),
+        "/track/abc/send/2/volume  track_send_volume(track_guid=abc, send_index=2)  volume=0.75"
+    );
+    assert_eq!(describe(&message("/play", vec![])), "/play  play");
+    assert_eq!(
+        describe(&message("/nowhere", vec![OscType::String("x".to_string())])),
+        "/nowhere  ?  \"x\""
+    );
+}
+
+#[test]
+fn test_patterns_match_every_route_they_cover() {
+    let names: Vec<&str> = matching_routes("/master/*")
+        .iter()
+        .map(|route| route.name)
+        .collect();
+    assert_eq!(names, vec!["master_volume", "master_pan", "master_mute"]);
+}
+
+#[test]
+fn test_filter_lets_matching_addresses_through() {
+    let filter = MonitorFilter::new(vec!["/track/*/volume".to_string()]);
+    assert!(filter.allows("/track/abc/volume"));
+    assert!(!filter.allows("/track/abc/pan"));
+    assert!(!filter.allows("/track/abc/send/0/volume"));
+    assert!(MonitorFilter::default().allows("/anything"));
+}
+
+#[test]
+fn test_run_prints_filtered_messages_until_receiving_fails() {
+    let bundle = OscPacket::Bundle(OscBundle {
+        timetag: OscTime {
+            seconds: 0,
+            fractional: 1,
+        },
+        content: vec![
+            OscPacket::Message(message("/track/abc/mute", vec![OscType::Bool(true)])),
+            OscPacket::Message(message("/track/abc/pan", vec![OscType::Float(0.5)])),
+        ],
+    });
+    let transport =
+        ScriptedTransport::new(vec![rosc::encoder::encode(&bundle).unwrap(), vec![1, 2, 3]]);
+    let filter = MonitorFilter::new(vec!["/track/*/mute".to_string()]);
+    let mut out = Vec::new();
+
+    let result = monitor::run(&transport, &filter, &mut out);
+    assert_eq!(result.unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    let out = String::from_utf8(out).unwrap();
+    let lines: Vec<&str> = out.lines().map(str::trim_start).collect();
+    assert_eq!(lines.len(), 2, "{}", out);
+    assert!(lines[0].ends_with("  /track/abc/mute  track_mute(track_guid=abc)  mute=true"));
+    assert!(lines[1].contains("  undecodable packet from 127.0.0.1:8000: "));
+}
@@ -0,0 +1,152 @@
+// Tests for OSC 1.0 address pattern matching
+
+use std::cell::RefCell;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use rosc::{OscMessage, OscType};
+
+use arpad_rust::osc::decode::Unhandled;
+use arpad_rust::osc::generated_osc::{Reaper, dispatch_osc};
+use arpad_rust::osc::pattern::{is_placeholder, match_address, matches_segment};
+use arpad_rust::osc::transport::OscTransport;
+
+struct NullTransport;
+
+impl OscTransport for NullTransport {
+    fn send(&self, _packet: &[u8]) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+#[test]
+fn test_segment_wildcards() {
+    assert!(matches_segment("volume", "volume"));
+    assert!(!matches_segment("volume", "volumes"));
+    assert!(matches_segment("*", "volume"));
+    assert!(matches_segment("*", ""));
+    assert!(matches_segment("vol*", "volume"));
+    assert!(matches_segment("*ume", "volume"));
+    assert!(!matches_segment("*pan", "volume"));
+    assert!(matches_segment("v?lume", "volume"));
+    assert!(!matches_segment("?", ""));
+}
+
+#[test]
+fn test_segment_character_sets() {
+    assert!(matches_segment("fader[123]", "fader2"));
+    assert!(!matches_segment("fader[123]", "fader4"));
+    assert!(matches_segment("fader[1-8]", "fader8"));
+    assert!(!matches_segment("fader[!1-8]", "fader8"));
+    assert!(matches_segment("fader[!1-8]", "fader9"));
+    // A '-' at either end of the set is literal
+    assert!(matches_segment("a[-x]", "a-"));
+    // An unclosed bracket is literal
+    assert!(matches_segment("a[b", "a[b"));
+}
+
+#[test]
+fn test_segment_alternatives() {
+    assert!(matches_segment("{volume,pan}", "pan"));
+    assert!(!matches_segment("{volume,pan}", "mute"));
+    assert!(matches_segment("send_{vol,pan}*", "send_volume"));
+    assert!(matches_segment("{a,ab}c", "abc"));
+}
+
+#[test]
+fn test_placeholders() {
+    assert!(is_placeholder("{track_guid}"));
+    assert!(!is_placeholder("{volume,pan}"));
+    assert!(!is_placeholder("volume"));
+}
+
+#[test]
+fn test_match_address_captures_params() {
+    assert_eq!(
+        match_address(
+            "/track/{track_guid}/send/{send_index}/volume",
+            "/track/abc/send/2/volume"
+        ),
+        Some(vec!["abc".to_string(), "2".to_string()])
+    );
+    assert_eq!(
+        match_address("/track/{track_guid}/pan", "/track/abc/volume"),
+        None
+    );
+    assert_eq!(
+        match_address("/track/{track_guid}/pan", "/track/abc/pan/extra"),
+        None
+    );
+}
+
+#[test]
+fn test_match_address_patterns_on_either_side() {
+    // Pattern in the route
+    assert!(match_address("/track/{track_guid}/{volume,pan}", "/track/abc/pan").is_some());
+    // Pattern in the incoming address
+    assert!(match_address("/track/{track_guid}/volume", "/track/abc/vol*").is_some());
+    assert!(match_address("/track/{track_guid}/volume", "/*/abc/volume").is_some());
+    assert!(match_address("/track/{track_guid}/volume", "/track/abc/pan*").is_none());
+}
+
+#[test]
+fn test_dispatcher_accepts_pattern_addresses() {
+    let mut reaper = Reaper::new(Arc::new(NullTransport));
+    let unknown = RefCell::new(Vec::new());
+    for addr in [
+        "/track/abc/vol*",
+        "/track/abc/send/1/{volume,pan}",
+        "/track/abc/fx/[0-3]/name",
+        "/track/abc/nothing*",
+    ] {
+        let msg = OscMessage {
+            addr: addr.to_string(),
+            args: vec![OscType::Float(0.5)],
+        };
+        dispatch_osc(&mut reaper, msg, |addr, reason| {
+            if *reason == Unhandled::UnknownAddress {
+                unknown.borrow_mut().push(addr.to_string())
+            }
+        });
+    }
+    assert_eq!(*unknown.borrow(), vec!["/track/abc/nothing*"]);
+}
+
+#[test]
+fn test_dispatcher_resolves_literal_addresses_by_segment() {
+    let mut reaper = Reaper::new(Arc::new(NullTransport));
+    let unknown = RefCell::new(Vec::new());
+    for addr in [
+        "/num_tracks",
+        "/track/all_guids",
+        "/track/abc/volume",
+        "/track/abc/send/1/pan",
+        "/track/abc/fx/2/param/3/value",
+        "/track/abc",
+        "/track/abc/volume/extra",
+        "/tracks/abc/volume",
+    ] {
+        let msg = OscMessage {
+            addr: addr.to_string(),
+            args: vec![OscType::Float(0.5)],
+        };
+        dispatch_osc(&mut reaper, msg, |addr, reason| {
+            if *reason == Unhandled::UnknownAddress {
+                unknown.borrow_mut().push(addr.to_string())
+            }
+        });
+    }
+    assert_eq!(
+        *unknown.borrow(),
+        vec![
+            "/track/abc",
+            "/track/abc/volume/extra",
+            "/tracks/abc/volume"
+        ]
+    );
+}
@@ -0,0 +1,135 @@
+// Tests for putting Reaper's OSC under an address prefix of its own
+
+use std::collections::VecDeque;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use rosc::{OscBundle, OscMessage, OscPacket, OscTime, OscType};
+
+use arpad_rust::osc::generated_osc::Reaper;
+use arpad_rust::osc::prefix::PrefixedTransport;
+use arpad_rust::osc::transport::OscTransport;
+
+// Keeps what's sent, and hands out the packets queued on it, then fails like a closed socket
+#[derive(Default)]
+struct ScriptedTransport {
+    sent: Mutex<Vec<OscPacket>>,
+    incoming: Mutex<VecDeque<Vec<u8>>>,
+}
+
+impl ScriptedTransport {
+    fn queue(&self, packet: OscPacket) {
+        let buf = rosc::encoder::encode(&packet).unwrap();
+        self.incoming.lock().unwrap().push_back(buf);
+    }
+
+    fn queue_raw(&self, buf: &[u8]) {
+        self.incoming.lock().unwrap().push_back(buf.to_vec());
+    }
+}
+
+impl OscTransport for ScriptedTransport {
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        let (_, packet) = rosc::decoder::decode_udp(packet).unwrap();
+        self.sent.lock().unwrap().push(packet);
+        Ok(())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        match self.incoming.lock().unwrap().pop_front() {
+            Some(buf) => Ok((buf, "127.0.0.1:8000".parse().unwrap())),
+            None => Err(io::ErrorKind::ConnectionAborted.into()),
+        }
+    }
+}
+
+fn prefixed(prefix: &str) -> (Arc<ScriptedTransport>, PrefixedTransport) {
+    let inner = Arc::new(ScriptedTransport::default());
+    (inner.clone(), PrefixedTransport::new(inner, prefix))
+}
+
+fn message(addr: &str) -> OscPacket {
+    OscPacket::Message(OscMessage {
+        addr: addr.to_string(),
+        args: vec![OscType::Float(0.5)],
+    })
+}
+
+fn bundle(content: Vec<OscPacket>) -> OscPacket {
+    OscPacket::Bundle(OscBundle {
+        timetag: OscTime::from((0, 1)),
+        content,
+    })
+}
+
+// Everything received until the script runs out
+fn received(transport: &PrefixedTransport) -> Vec<OscPacket> {
+    let mut packets = vec![];
+    while let Ok((buf, _)) = transport.recv() {
+        packets.push(rosc::decoder::decode_udp(&buf).unwrap().1);
+    }
+    packets
+}
+
+#[test]
+fn test_the_client_sends_under_the_prefix() {
+    let (inner, transport) = prefixed("/reaper1/");
+    let reaper = Reaper::new(Arc::new(transport));
+    reaper.track_volume("abc".into()).set_value(0.5).unwrap();
+    let mut bundle_of_two = reaper.bundle();
+    bundle_of_two
+        .set(&reaper.track_volume("abc".into()), 0.5.into())
+        .set(&reaper.master_volume(), 0.5.into());
+    bundle_of_two.commit().unwrap();
+
+    let sent = inner.sent.lock().unwrap();
+    assert_eq!(sent[0], message("/reaper1/track/abc/volume"));
+    assert_eq!(
+        sent[1],
+        bundle(vec![
+            message("/reaper1/track/abc/volume"),
+            message("/reaper1/master/volume"),
+        ])
+    );
+}
+
+#[test]
+fn test_the_prefix_is_taken_off_what_is_received() {
+    let (inner, transport) = prefixed("/reaper1");
+    inner.queue(message("/reaper1/track/abc/volume"));
+    inner.queue(bundle(vec![
+        message("/reaper1/master/volume"),
+        message("/reaper2/master/volume"),
+    ]));
+    assert_eq!(
+        received(&transport),
+        vec![
+            message("/track/abc/volume"),
+            bundle(vec![message("/master/volume")]),
+        ]
+    );
+}
+
+#[test]
+fn test_what_is_outside_the_prefix_is_dropped() {
+    let (inner, transport) = prefixed("/reaper1");
+    inner.queue(message("/track/abc/volume"));
+    inner.queue(message("/reaper10/track/abc/volume"));
+    inner.queue(bundle(vec![message("/reaper2/master/volume")]));
+    inner.queue(message("/reaper1/master/volume"));
+    assert_eq!(received(&transport), vec![message("/master/volume")]);
+}
+
+#[test]
+fn test_unreadable_packets_are_only_received_under_the_prefix() {
+    let (inner, transport) = prefixed("/reaper1");
+    // Each says it has a float, but stops short of it
+    inner.queue_raw(b"/track/abc/volume\0\0\0,f\0\0");
+    inner.queue_raw(b"/reaper2/master/volume\0\0,f\0\0");
+    inner.queue_raw(b"/reaper1/master/volume\0\0,f\0\0");
+    let (buf, _) = transport.recv().unwrap();
+    // The prefix is taken off, leaving what's wrong with it for the receive loop to report
+    assert_eq!(buf, b"/master/volume\0\0,f\0\0");
+    assert!(transport.recv().is_err());
+}
@@ -0,0 +1,126 @@
+// Tests for fanning OSC feedback out to subscribed controllers
+
+use std::io;
+use std::net::{SocketAddr, UdpSocket};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rosc::{OscMessage, OscType};
+
+use arpad_rust::osc::subscribers::{FanOutTransport, SubscriberRegistry};
+use arpad_rust::osc::transport::OscTransport;
+
+// Records every packet sent to Reaper
+#[derive(Default)]
+struct RecordingTransport {
+    sent: Mutex<Vec<Vec<u8>>>,
+}
+
+impl OscTransport for RecordingTransport {
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        self.sent.lock().unwrap().push(packet.to_vec());
+        Ok(())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+fn controller() -> UdpSocket {
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    socket
+        .set_read_timeout(Some(Duration::from_secs(1)))
+        .unwrap();
+    socket
+}
+
+fn message(addr: &str, args: Vec<OscType>) -> OscMessage {
+    OscMessage {
+        addr: addr.to_string(),
+        args,
+    }
+}
+
+#[test]
+fn test_subscribe_messages_register_and_remove_subscribers() {
+    let registry = SubscriberRegistry::default();
+    let sender: SocketAddr = "10.0.0.2:5000".parse().unwrap();
+
+    // No arguments subscribes the sender; a port subscribes that port on the sender's host
+    assert!(registry.handle_message(&message("/subscribe", vec![]), sender));
+    assert!(registry.handle_message(&message("/subscribe", vec![OscType::Int(9001)]), sender));
+    assert!(registry.handle_message(
+        &message(
+            "/subscribe",
+            vec![OscType::String("10.0.0.3:9002".to_string())]
+        ),
+        sender
+    ));
+    // Subscribing twice changes nothing
+    assert!(registry.handle_message(&message("/subscribe", vec![]), sender));
+    assert_eq!(
+        registry.subscribers(),
+        vec![
+            "10.0.0.2:5000".parse().unwrap(),
+            "10.0.0.2:9001".parse().unwrap(),
+            "10.0.0.3:9002".parse().unwrap(),
+        ]
+    );
+
+    assert!(registry.handle_message(&message("/unsubscribe", vec![OscType::Int(9001)]), sender));
+    assert_eq!(registry.subscribers().len(), 2);
+
+    // Anything else is left for the rest of the bridge
+    assert!(!registry.handle_message(&message("/track/abc/volume", vec![]), sender));
+}
+
+#[test]
+fn test_unparseable_subscribe_is_consumed_but_ignored() {
+    let registry = SubscriberRegistry::default();
+    let sender: SocketAddr = "10.0.0.2:5000".parse().unwrap();
+    assert!(registry.handle_message(
+        &message(
+            "/subscribe",
+            vec![OscType::String("not an address".to_string())]
+        ),
+        sender
+    ));
+    assert!(registry.handle_message(&message("/subscribe", vec![OscType::Int(-1)]), sender));
+    assert!(registry.subscribers().is_empty());
+}
+
+#[test]
+fn test_fan_out_sends_to_reaper_and_every_subscriber() {
+    let reaper = Arc::new(RecordingTransport::default());
+    let controllers = [controller(), controller()];
+    let registry = Arc::new(SubscriberRegistry::new(
+        controllers
+            .iter()
+            .map(|controller| controller.local_addr().unwrap()),
+    ));
+    let transport = FanOutTransport::new(
+        reaper.clone(),
+        UdpSocket::bind("127.0.0.1:0").unwrap(),
+        registry.clone(),
+    );
+
+    transport.send(b"packet").unwrap();
+    assert_eq!(*reaper.sent.lock().unwrap(), vec![b"packet".to_vec()]);
+    for controller in &controllers {
+        let mut buf = [0u8; 16];
+        let (size, _) = controller.recv_from(&mut buf).unwrap();
+        assert_eq!(&buf[..size], b"packet");
+    }
+
+    // Once unsubscribed a controller hears nothing more
+    registry.unsubscribe(controllers[1].local_addr().unwrap());
+    transport.send(b"second").unwrap();
+    let mut buf = [0u8; 16];
+    let (size, _) = controllers[0].recv_from(&mut buf).unwrap();
+    assert_eq!(&buf[..size], b"second");
+    controllers[1]
+        .set_read_timeout(Some(Duration::from_millis(50)))
+        .unwrap();
+    assert!(controllers[1].recv_from(&mut buf).is_err());
+}
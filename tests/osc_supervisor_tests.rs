@@ -0,0 +1,113 @@
+// Tests for noticing when Reaper goes away and comes back
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::bounded;
+
+use arpad_rust::osc::supervisor::{ConnectionEvent, ConnectionSupervisor};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[test]
+fn test_silence_is_reported_once() {
+    let supervisor = ConnectionSupervisor::new(TIMEOUT);
+    let start = Instant::now();
+    supervisor.heard(start);
+
+    assert_eq!(supervisor.check(start + TIMEOUT), None);
+    assert_eq!(
+        supervisor.check(start + TIMEOUT * 2),
+        Some(ConnectionEvent::Lost)
+    );
+    assert!(!supervisor.is_connected());
+    assert_eq!(supervisor.check(start + TIMEOUT * 3), None);
+}
+
+#[test]
+fn test_traffic_keeps_connection_alive_and_restores_it() {
+    let supervisor = ConnectionSupervisor::new(TIMEOUT);
+    let start = Instant::now();
+    assert_eq!(supervisor.heard(start), None);
+    assert_eq!(supervisor.heard(start + TIMEOUT), None);
+    assert_eq!(supervisor.check(start + TIMEOUT * 2), None);
+
+    assert_eq!(
+        supervisor.check(start + TIMEOUT * 3),
+        Some(ConnectionEvent::Lost)
+    );
+    assert_eq!(
+        supervisor.heard(start + TIMEOUT * 4),
+        Some(ConnectionEvent::Restored)
+    );
+    assert!(supervisor.is_connected());
+    assert_eq!(supervisor.heard(start + TIMEOUT * 4), None);
+}
+
+#[test]
+fn test_heartbeat_polls_and_reports_lost() {
+    let supervisor = Arc::new(ConnectionSupervisor::new(Duration::from_millis(20)));
+    let heartbeats = Arc::new(AtomicUsize::new(0));
+    let (lost_send, lost_rec) = bounded(1);
+    supervisor.start_heartbeat(
+        Duration::from_millis(10),
+        {
+            let heartbeats = heartbeats.clone();
+            move || {
+                heartbeats.fetch_add(1, Ordering::SeqCst);
+            }
+        },
+        move || {
+            let _ = lost_send.try_send(());
+        },
+    );
+
+    // Nothing answers the heartbeats, so the connection is eventually lost
+    lost_rec.recv_timeout(Duration::from_secs(1)).unwrap();
+    assert!(heartbeats.load(Ordering::SeqCst) >= 2);
+    assert!(!supervisor.is_connected());
+
+    thread::sleep(Duration::from_millis(30));
+    assert_eq!(
+        supervisor.heard(Instant::now()),
+        Some(ConnectionEvent::Restored)
+    );
+}
+
+#[test]
+fn test_only_reapers_packets_count() {
+    let reaper: SocketAddr = "127.0.0.1:8000".parse().unwrap();
+    let controller: SocketAddr = "127.0.0.1:9000".parse().unwrap();
+    let supervisor = ConnectionSupervisor::new(TIMEOUT).with_reaper_address(reaper);
+    let start = Instant::now();
+
+    // Another sender neither keeps the connection alive nor brings it back
+    assert_eq!(supervisor.heard_from(controller, start + TIMEOUT), None);
+    assert_eq!(
+        supervisor.check(start + TIMEOUT * 2),
+        Some(ConnectionEvent::Lost)
+    );
+    assert_eq!(supervisor.heard_from(controller, start + TIMEOUT * 3), None);
+    assert!(!supervisor.is_connected());
+
+    assert_eq!(
+        supervisor.heard_from(reaper, start + TIMEOUT * 3),
+        Some(ConnectionEvent::Restored)
+    );
+    assert!(supervisor.is_connected());
+}
+
+#[test]
+fn test_every_sender_counts_without_reapers_address() {
+    let supervisor = ConnectionSupervisor::new(TIMEOUT);
+    let start = Instant::now();
+    supervisor.check(start + TIMEOUT * 2);
+
+    assert_eq!(
+        supervisor.heard_from("127.0.0.1:9000".parse().unwrap(), start + TIMEOUT * 3),
+        Some(ConnectionEvent::Restored)
+    );
+}
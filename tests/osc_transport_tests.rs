@@ -0,0 +1,159 @@
+// Tests for the OSC transports, in particular SLIP framing over TCP
+
+use std::io::Write;
+use std::net::{TcpListener, TcpStream, UdpSocket};
+use std::sync::Arc;
+use std::time::Duration;
+
+use arpad_rust::osc::generated_osc::{Reaper, TrackVolumeArgs};
+use arpad_rust::osc::transport::{
+    DryRunTransport, OscTransport, TcpSlipTransport, UdpTransport, slip,
+};
+use arpad_rust::traits::Set;
+use rosc::{OscMessage, OscPacket, OscType};
+
+fn tcp_pair() -> (TcpSlipTransport, TcpStream) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let (server, _) = listener.accept().unwrap();
+    (TcpSlipTransport::new(server).unwrap(), client)
+}
+
+#[test]
+fn test_slip_round_trip_escapes_special_bytes() {
+    let packet = vec![0x01, slip::END, 0x02, slip::ESC, slip::ESC_END, 0x03];
+    let frame = slip::encode(&packet);
+    assert_eq!(
+        frame,
+        vec![
+            slip::END,
+            0x01,
+            slip::ESC,
+            slip::ESC_END,
+            0x02,
+            slip::ESC,
+            slip::ESC_ESC,
+            slip::ESC_END,
+            0x03,
+            slip::END
+        ]
+    );
+    assert_eq!(slip::decode(&frame[1..frame.len() - 1]).unwrap(), packet);
+}
+
+#[test]
+fn test_slip_rejects_bad_escape() {
+    assert!(slip::decode(&[0x01, slip::ESC, 0x02]).is_err());
+    assert!(slip::decode(&[0x01, slip::ESC]).is_err());
+}
+
+#[test]
+fn test_tcp_transport_receives_framed_packets() {
+    let (transport, mut client) = tcp_pair();
+
+    let packet = OscPacket::Message(OscMessage {
+        addr: "/track/abc/volume".to_string(),
+        args: vec![OscType::Float(0.5)],
+    });
+    let encoded = rosc::encoder::encode(&packet).unwrap();
+
+    // Two packets back to back, the second without a leading END
+    let mut stream = slip::encode(&encoded);
+    stream.extend_from_slice(&slip::encode(&encoded)[1..]);
+    client.write_all(&stream).unwrap();
+
+    for _ in 0..2 {
+        let (received, _) = transport.recv().unwrap();
+        let (_, decoded) = rosc::decoder::decode_udp(&received).unwrap();
+        assert_eq!(decoded, packet);
+    }
+
+    // Closing the connection ends the stream
+    drop(client);
+    assert!(transport.recv().is_err());
+}
+
+#[test]
+fn test_tcp_transport_sends_framed_packets() {
+    let (transport, client) = tcp_pair();
+    let peer = TcpSlipTransport::new(client).unwrap();
+
+    transport.send(&[1, 2, slip::END, 3]).unwrap();
+    let (received, _) = peer.recv().unwrap();
+    assert_eq!(received, vec![1, 2, slip::END, 3]);
+}
+
+#[test]
+fn test_udp_transport_sends_to_its_peer_and_hears_anyone() {
+    let reaper = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let controller = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let local = socket.local_addr().unwrap();
+    let transport = UdpTransport::with_peer(socket, reaper.local_addr().unwrap());
+
+    transport.send(b"to reaper").unwrap();
+    let mut buf = [0u8; 64];
+    let (size, _) = reaper.recv_from(&mut buf).unwrap();
+    assert_eq!(&buf[..size], b"to reaper");
+
+    controller.send_to(b"from controller", local).unwrap();
+    let (packet, from) = transport.recv().unwrap();
+    assert_eq!(packet, b"from controller");
+    assert_eq!(from, controller.local_addr().unwrap());
+}
+
+#[test]
+fn test_reaper_sends_to_its_destination_over_an_unconnected_socket() {
+    let reaper_socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let transport = Arc::new(UdpTransport::new(socket));
+    // Nothing to send to without a destination
+    assert!(transport.send(b"nowhere").is_err());
+
+    let reaper = Reaper::with_destination(transport, reaper_socket.local_addr().unwrap());
+    reaper
+        .track_volume("abc".into())
+        .set(TrackVolumeArgs { volume: 0.5 })
+        .unwrap();
+    let mut buf = [0u8; rosc::decoder::MTU];
+    let (size, _) = reaper_socket.recv_from(&mut buf).unwrap();
+    let (_, packet) = rosc::decoder::decode_udp(&buf[..size]).unwrap();
+    assert_eq!(
+        packet,
+        OscPacket::Message(OscMessage {
+            addr: "/track/abc/volume".to_string(),
+            args: vec![OscType::Float(0.5)],
+        })
+    );
+}
+
+#[test]
+fn test_dry_run_transport_hears_reaper_but_sends_nothing() {
+    let reaper = UdpSocket::bind("127.0.0.1:0").unwrap();
+    reaper
+        .set_read_timeout(Some(Duration::from_millis(100)))
+        .unwrap();
+    let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let local = socket.local_addr().unwrap();
+    let transport = DryRunTransport::new(Arc::new(UdpTransport::with_peer(
+        socket,
+        reaper.local_addr().unwrap(),
+    )));
+
+    let packet = rosc::encoder::encode(&OscPacket::Message(OscMessage {
+        addr: "/track/abc/volume".to_string(),
+        args: vec![OscType::Float(0.5)],
+    }))
+    .unwrap();
+    transport.send(&packet).unwrap();
+    transport
+        .send_to(&packet, reaper.local_addr().unwrap())
+        .unwrap();
+    let mut buf = [0u8; 64];
+    assert!(reaper.recv_from(&mut buf).is_err());
+
+    reaper.send_to(b"from reaper", local).unwrap();
+    let (received, from) = transport.recv().unwrap();
+    assert_eq!(received, b"from reaper");
+    assert_eq!(from, reaper.local_addr().unwrap());
+}
@@ -0,0 +1,136 @@
+// Tests for choosing which MIDI ports feed which unit of the surface
+
+use arpad_rust::config::Config;
+use arpad_rust::midi::control_surface::ControlSurface;
+use arpad_rust::midi::ports::{MidiTap, PortRoute, PortRouting, PortRoutingError};
+use arpad_rust::midi::xtouch::surface::SurfaceLayout;
+use arpad_rust::midi::xtouch::{ActionButton, DeviceId, Model};
+
+#[test]
+fn test_config_routes_units_to_ports() {
+    let config = Config::from_layers(Some(
+        br#"
+surface_ports:
+  - input: "X-Touch"
+    output: "X-Touch MIDI Out"
+  - input: "X-Touch-Ext"
+    model: extender
+"#,
+    ))
+    .unwrap();
+    assert_eq!(
+        config.surface_ports,
+        vec![
+            PortRoute {
+                input: "X-Touch".to_string(),
+                output: Some("X-Touch MIDI Out".to_string()),
+                model: Model::XTouch,
+                device_id: DeviceId(0),
+            },
+            PortRoute::new("X-Touch-Ext", Model::Extender),
+        ]
+    );
+    assert_eq!(config.surface_ports[0].output(), "X-Touch MIDI Out");
+    assert_eq!(config.surface_ports[1].output(), "X-Touch-Ext");
+}
+
+#[test]
+fn test_units_are_laid_out_in_route_order() {
+    let routing =
+        PortRouting::xtouch_with_extenders("X-Touch", &["Ext 1".to_string(), "Ext 2".to_string()])
+            .unwrap();
+    assert_eq!(routing.layout(), SurfaceLayout::xtouch_with_extenders(2));
+    let models: Vec<Model> = routing.routes().iter().map(|route| route.model).collect();
+    assert_eq!(
+        models,
+        vec![Model::XTouch, Model::Extender, Model::Extender]
+    );
+}
+
+#[test]
+fn test_routed_units_have_the_channels_of_all_of_them() {
+    let routing = PortRouting::xtouch_with_extenders("X-Touch", &["Ext 1".to_string()]).unwrap();
+    let capabilities = routing.capabilities();
+    assert_eq!(capabilities.channels, 16);
+    assert!(capabilities.action_buttons.contains(&ActionButton::Click));
+}
+
+#[test]
+fn test_routing_needs_an_xtouch_first_and_only_first() {
+    assert_eq!(PortRouting::new(vec![]), Err(PortRoutingError::Empty));
+    assert_eq!(
+        PortRouting::new(vec![PortRoute::new("Ext", Model::Extender)]),
+        Err(PortRoutingError::FirstNotXTouch)
+    );
+    assert_eq!(
+        PortRouting::new(vec![
+            PortRoute::new("X-Touch", Model::XTouch),
+            PortRoute::new("Ext", Model::Extender),
+            PortRoute::new("Other X-Touch", Model::XTouch),
+        ]),
+        Err(PortRoutingError::SecondXTouch(2))
+    );
+}
+
+#[test]
+fn test_units_cannot_share_an_input_port() {
+    let routing = PortRouting::xtouch_with_extenders("X-Touch", &["X-Touch".to_string()]);
+    assert_eq!(
+        routing,
+        Err(PortRoutingError::SharedInput("X-Touch".to_string()))
+    );
+}
+
+#[test]
+fn test_config_chains_units_on_one_port() {
+    let config = Config::from_layers(Some(
+        br#"
+surface_ports:
+  - input: "MCU Chain"
+  - input: "MCU Chain"
+    model: extender
+    device_id: 1
+"#,
+    ))
+    .unwrap();
+    let routing = PortRouting::new(config.surface_ports).unwrap();
+    let device_ids: Vec<DeviceId> = routing.routes().iter().map(|r| r.device_id).collect();
+    assert_eq!(device_ids, vec![DeviceId(0), DeviceId(1)]);
+    // Each chained unit is a unit of the surface of its own
+    assert_eq!(routing.layout(), SurfaceLayout::xtouch_with_extenders(1));
+}
+
+#[test]
+fn test_chained_units_need_their_own_channels_next_to_each_other() {
+    let chained = |device_id| PortRoute {
+        device_id: DeviceId(device_id),
+        ..PortRoute::new("MCU Chain", Model::Extender)
+    };
+    let xtouch = PortRoute::new("MCU Chain", Model::XTouch);
+    assert_eq!(
+        PortRouting::new(vec![xtouch.clone(), chained(0)]),
+        Err(PortRoutingError::SharedInput("MCU Chain".to_string()))
+    );
+    assert_eq!(
+        PortRouting::new(vec![xtouch.clone(), chained(2)]),
+        Err(PortRoutingError::InvalidDeviceId(1))
+    );
+    assert_eq!(
+        PortRouting::new(vec![
+            xtouch,
+            PortRoute::new("Ext", Model::Extender),
+            chained(1),
+        ]),
+        Err(PortRoutingError::SplitChain("MCU Chain".to_string()))
+    );
+}
+
+#[test]
+fn test_tap_only_replays_into_connected_units() {
+    let tap = MidiTap::new(2, None);
+    // Neither unit has connected, and there's no third
+    assert!(!tap.replay(0, &[0x90, 0x10, 0x7f]));
+    assert!(!tap.replay(1, &[0x90, 0x10, 0x7f]));
+    assert!(!tap.replay(2, &[0x90, 0x10, 0x7f]));
+    assert!(!MidiTap::default().replay(0, &[0x90, 0x10, 0x7f]));
+}
@@ -0,0 +1,264 @@
+// Tests for following the project's tempo, markers and regions
+//
+// These check what ProjectState works out from Reaper's reports, what ProjectStateManager passes
+// on to the modes, and VolumePanMode showing the current marker on the scribble strips.
+
+use crossbeam_channel::{Receiver, Sender, unbounded};
+use std::time::Duration;
+
+use arpad_rust::guid::ProjectGuid;
+use arpad_rust::midi::xtouch::{LEDState, XTouchDownstreamMsg};
+use arpad_rust::modes::mode_manager::{Mode, ModeHandler, ModeState, State};
+use arpad_rust::modes::reaper_vol_pan::VolumePanMode;
+use arpad_rust::project::{Marker, ProjectMsg, ProjectState, ProjectStateManager, ProjectTabs};
+use arpad_rust::track::track::{DataPayload, Direction, TrackDataMsg, TrackMsg};
+
+const VOL_PAN: ModeState = ModeState {
+    mode: Mode::ReaperVolPan,
+    state: State::Active,
+};
+
+fn marker(idx: i32, name: &str, position: f32) -> [ProjectMsg; 2] {
+    [
+        ProjectMsg::MarkerName {
+            idx,
+            name: name.to_string(),
+        },
+        ProjectMsg::MarkerPosition { idx, position },
+    ]
+}
+
+fn project_with(msgs: impl IntoIterator<Item = ProjectMsg>) -> ProjectState {
+    let mut project = ProjectState::new();
+    for msg in msgs {
+        project.apply(&msg);
+    }
+    project
+}
+
+#[test]
+fn test_current_marker_is_last_one_passed() {
+    let mut project = project_with(
+        [marker(1, "Verse", 10.0), marker(0, "Intro", 0.0)]
+            .into_iter()
+            .flatten(),
+    );
+    // A marker whose position hasn't arrived yet can't be placed
+    project.apply(&ProjectMsg::MarkerName {
+        idx: 2,
+        name: "Chorus".to_string(),
+    });
+
+    let name_at = |project: &mut ProjectState, position| {
+        project.apply(&ProjectMsg::Position(position));
+        project.current_marker().map(|marker| marker.name.clone())
+    };
+    assert_eq!(name_at(&mut project, 0.0).as_deref(), Some("Intro"));
+    assert_eq!(name_at(&mut project, 9.9).as_deref(), Some("Intro"));
+    assert_eq!(name_at(&mut project, 10.0).as_deref(), Some("Verse"));
+    assert_eq!(name_at(&mut project, 100.0).as_deref(), Some("Verse"));
+
+    project.apply(&ProjectMsg::MarkerPosition {
+        idx: 2,
+        position: 30.0,
+    });
+    assert_eq!(name_at(&mut project, 100.0).as_deref(), Some("Chorus"));
+}
+
+#[test]
+fn test_current_region_contains_play_cursor() {
+    let mut project = project_with([
+        ProjectMsg::RegionName {
+            idx: 0,
+            name: "A".to_string(),
+        },
+        ProjectMsg::RegionStart { idx: 0, start: 4.0 },
+        ProjectMsg::RegionEnd { idx: 0, end: 8.0 },
+        ProjectMsg::Tempo(96.0),
+    ]);
+    assert_eq!(project.tempo, Some(96.0));
+
+    assert!(project.current_region().is_none());
+    project.apply(&ProjectMsg::Position(4.0));
+    assert_eq!(project.current_region().unwrap().name, "A");
+    // Regions end just before their end
+    project.apply(&ProjectMsg::Position(8.0));
+    assert!(project.current_region().is_none());
+}
+
+#[test]
+fn test_manager_reports_marker_changes_but_not_positions() {
+    let (to_manager, from_reaper) = unbounded();
+    let (to_modes, from_manager) = unbounded();
+    ProjectStateManager::start(from_reaper, to_modes);
+    let recv = || {
+        from_manager
+            .recv_timeout(Duration::from_millis(100))
+            .expect("Expected a message for the modes")
+    };
+
+    to_manager.send(ProjectMsg::Tempo(120.0)).unwrap();
+    assert_eq!(recv(), ProjectMsg::Tempo(120.0));
+
+    for msg in marker(0, "Intro", 0.0) {
+        to_manager.send(msg.clone()).unwrap();
+        assert_eq!(recv(), msg);
+    }
+    // The cursor starts at the top, right on the marker
+    let intro = Marker {
+        name: "Intro".to_string(),
+        position: Some(0.0),
+    };
+    assert_eq!(recv(), ProjectMsg::CurrentMarker(Some(intro)));
+
+    // Moving around inside the marker's stretch doesn't say anything
+    to_manager.send(ProjectMsg::Position(1.0)).unwrap();
+    to_manager.send(ProjectMsg::Position(2.0)).unwrap();
+    to_manager
+        .send(ProjectMsg::MarkerPosition {
+            idx: 0,
+            position: 5.0,
+        })
+        .unwrap();
+    assert_eq!(
+        recv(),
+        ProjectMsg::MarkerPosition {
+            idx: 0,
+            position: 5.0
+        }
+    );
+    // Until the marker moves out from under the cursor
+    assert_eq!(recv(), ProjectMsg::CurrentMarker(None));
+    assert!(from_manager.try_recv().is_err());
+}
+
+fn setup_vol_pan_mode() -> (VolumePanMode, Receiver<XTouchDownstreamMsg>) {
+    let (_, from_reaper): (Sender<TrackMsg>, _) = unbounded();
+    let (to_reaper, _) = unbounded();
+    let (_, from_xtouch) = unbounded();
+    let (to_xtouch, from_mode) = unbounded();
+    let mut mode = VolumePanMode::new(8, from_reaper, to_reaper, from_xtouch, to_xtouch);
+    mode.set_show_markers(true);
+    (mode, from_mode)
+}
+
+// The bottom line of every scribble strip the mode sent, by channel
+fn bottom_lines(from_mode: &Receiver<XTouchDownstreamMsg>) -> Vec<(usize, String)> {
+    from_mode
+        .try_iter()
+        .filter_map(|msg| match msg {
+            XTouchDownstreamMsg::ScribbleStrip(msg) => Some((msg.idx.get(), msg.bottom_line)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_vol_pan_mode_spreads_marker_name_over_scribble_strips() {
+    let (mut mode, from_mode) = setup_vol_pan_mode();
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: "drums".into(),
+            direction: Direction::Downstream,
+            data: DataPayload::ReaperTrackIndex(Some(0)),
+        }),
+        VOL_PAN,
+    );
+    let lines = bottom_lines(&from_mode);
+    assert!(lines.contains(&(0, "1".to_string())), "{:?}", lines);
+
+    let chorus = Marker {
+        name: "Second chorus".to_string(),
+        position: Some(60.0),
+    };
+    mode.handle_project_msg(ProjectMsg::CurrentMarker(Some(chorus)), VOL_PAN);
+    let lines = bottom_lines(&from_mode);
+    assert_eq!(lines.len(), 8);
+    assert_eq!(lines[0], (0, "Second ".to_string()));
+    assert_eq!(lines[1], (1, "chorus".to_string()));
+    assert!(lines[2..].iter().all(|(_, line)| line.is_empty()));
+
+    // Track numbers come back once there's no marker to show
+    mode.handle_project_msg(ProjectMsg::CurrentMarker(None), VOL_PAN);
+    let lines = bottom_lines(&from_mode);
+    assert_eq!(lines[0], (0, "1".to_string()));
+}
+
+#[test]
+fn test_vol_pan_mode_holds_marker_while_inactive() {
+    let (mut mode, from_mode) = setup_vol_pan_mode();
+    let sends = ModeState {
+        mode: Mode::ReaperSends,
+        state: State::Active,
+    };
+    let verse = Marker {
+        name: "Verse".to_string(),
+        position: Some(10.0),
+    };
+    mode.handle_project_msg(ProjectMsg::CurrentMarker(Some(verse)), sends);
+    assert!(bottom_lines(&from_mode).is_empty());
+}
+
+// The state of every arm LED the mode sent, by channel
+fn arm_leds(from_mode: &Receiver<XTouchDownstreamMsg>) -> Vec<(usize, LEDState)> {
+    from_mode
+        .try_iter()
+        .filter_map(|msg| match msg {
+            XTouchDownstreamMsg::ArmLED(msg) => Some((msg.idx.get(), msg.state)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_vol_pan_mode_blinks_armed_tracks_while_recording() {
+    let (mut mode, from_mode) = setup_vol_pan_mode();
+    for (idx, guid, armed) in [(0, "drums", true), (1, "bass", false)] {
+        for data in [
+            DataPayload::ReaperTrackIndex(Some(idx)),
+            DataPayload::Armed(armed),
+        ] {
+            mode.handle_downstream_messages(
+                TrackMsg::TrackDataMsg(TrackDataMsg {
+                    guid: guid.into(),
+                    direction: Direction::Downstream,
+                    data,
+                }),
+                VOL_PAN,
+            );
+        }
+    }
+    assert!(arm_leds(&from_mode).contains(&(0, LEDState::On)));
+
+    mode.handle_project_msg(ProjectMsg::Recording(true), VOL_PAN);
+    assert_eq!(
+        arm_leds(&from_mode),
+        vec![(0, LEDState::Flash), (1, LEDState::Off)]
+    );
+
+    // Arming a track mid-take starts it blinking too
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: "bass".into(),
+            direction: Direction::Downstream,
+            data: DataPayload::Armed(true),
+        }),
+        VOL_PAN,
+    );
+    assert_eq!(arm_leds(&from_mode), vec![(1, LEDState::Flash)]);
+
+    mode.handle_project_msg(ProjectMsg::Recording(false), VOL_PAN);
+    assert_eq!(
+        arm_leds(&from_mode),
+        vec![(0, LEDState::On), (1, LEDState::On)]
+    );
+}
+
+#[test]
+fn test_project_tabs_tell_a_switch_from_the_first_report() {
+    let mut tabs = ProjectTabs::new();
+    assert!(!tabs.report(ProjectGuid::from("project-a")));
+    assert!(!tabs.report(ProjectGuid::from("project-a")));
+    assert!(tabs.report(ProjectGuid::from("project-b")));
+    assert_eq!(tabs.active(), Some(&ProjectGuid::from("project-b")));
+}
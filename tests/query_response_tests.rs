@@ -0,0 +1,89 @@
+// Tests for waiting on query responses through the generated Reaper client
+
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use arpad_rust::osc::generated_osc::{Reaper, dispatch_osc};
+use arpad_rust::osc::transport::OscTransport;
+use arpad_rust::traits::{QueryError, QueryWithResponse};
+use rosc::{OscMessage, OscPacket, OscType};
+
+// Records the addresses of the packets sent to it
+#[derive(Default)]
+struct RecordingTransport {
+    sent: Mutex<Vec<String>>,
+}
+
+impl OscTransport for RecordingTransport {
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        let (_, packet) = rosc::decoder::decode_udp(packet).unwrap();
+        if let OscPacket::Message(msg) = packet {
+            self.sent.lock().unwrap().push(msg.addr);
+        }
+        Ok(())
+    }
+
+    fn recv(&self) -> io::Result<(Vec<u8>, SocketAddr)> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+fn volume_msg(guid: &str, volume: f32) -> OscMessage {
+    OscMessage {
+        addr: format!("/track/{}/volume", guid),
+        args: vec![OscType::Float(volume)],
+    }
+}
+
+#[test]
+fn test_query_response_resolves_with_matching_message() {
+    let transport = Arc::new(RecordingTransport::default());
+    let mut reaper = Reaper::new(transport.clone());
+
+    let response = reaper.track_volume("abc".into()).query_response().unwrap();
+    assert_eq!(*transport.sent.lock().unwrap(), vec!["/track/abc/volume"]);
+
+    // Messages for other tracks don't resolve the query
+    dispatch_osc(&mut reaper, volume_msg("def", 0.1), |_, _| {});
+    assert!(response.try_recv().is_err());
+
+    dispatch_osc(&mut reaper, volume_msg("abc", 0.7), |_, _| {});
+    assert_eq!(response.try_recv().unwrap().volume, 0.7);
+
+    // The query only resolves once
+    dispatch_osc(&mut reaper, volume_msg("abc", 0.2), |_, _| {});
+    assert!(response.try_recv().is_err());
+}
+
+#[test]
+fn test_query_value_waits_for_response() {
+    let transport = Arc::new(RecordingTransport::default());
+    let reaper = Arc::new(Mutex::new(Reaper::new(transport)));
+
+    let responder = {
+        let reaper = reaper.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            dispatch_osc(
+                &mut reaper.lock().unwrap(),
+                volume_msg("abc", 0.5),
+                |_, _| {},
+            );
+        })
+    };
+    let volume = reaper.lock().unwrap().track_volume("abc".into());
+    let args = volume.query_value(Duration::from_secs(5)).unwrap();
+    assert_eq!(args.volume, 0.5);
+    responder.join().unwrap();
+}
+
+#[test]
+fn test_query_value_times_out() {
+    let reaper = Reaper::new(Arc::new(RecordingTransport::default()));
+    let result = reaper
+        .track_volume("abc".into())
+        .query_value(Duration::from_millis(20));
+    assert!(matches!(result, Err(QueryError::Timeout)));
+}
@@ -0,0 +1,167 @@
+// Tests for resyncing the surface with Reaper from the resync button
+//
+// These check that the button asks Reaper about the tracks on the surface, repaints the surface
+// from what the modes know, and doesn't do either again too soon. A surface coming back online is
+// repainted the same way, without asking Reaper.
+
+use std::time::Duration;
+
+use crossbeam_channel::{Receiver, Sender, unbounded};
+
+use arpad_rust::guid::TrackGuid;
+use arpad_rust::midi::xtouch::keepalive::SurfaceStatus;
+use arpad_rust::midi::xtouch::{ChannelIndex, XTouchDownstreamMsg, XTouchUpstreamMsg};
+use arpad_rust::modes::mode_manager::{ModeManager, ModeOptions, ResyncRequest};
+use arpad_rust::track::track::{DataPayload, Direction, TrackDataMsg, TrackMsg};
+
+const TIMEOUT: Duration = Duration::from_millis(200);
+
+struct Surface {
+    reaper_tx: Sender<TrackMsg>,
+    to_reaper_rx: Receiver<TrackMsg>,
+    xtouch_tx: Sender<XTouchUpstreamMsg>,
+    to_xtouch_rx: Receiver<XTouchDownstreamMsg>,
+    resyncs: Receiver<ResyncRequest>,
+}
+
+impl Surface {
+    fn start(resync_interval: Duration) -> Self {
+        let (reaper_tx, reaper_rx) = unbounded();
+        let (xtouch_tx, xtouch_rx) = unbounded();
+        let (to_reaper_tx, to_reaper_rx) = unbounded();
+        let (to_xtouch_tx, to_xtouch_rx) = unbounded();
+        let (to_resync, resyncs) = unbounded();
+        ModeManager::start_with_options(
+            reaper_rx,
+            to_reaper_tx,
+            xtouch_rx,
+            to_xtouch_tx,
+            ModeOptions {
+                to_resync: Some(to_resync),
+                resync_interval,
+                ..Default::default()
+            },
+        );
+        Surface {
+            reaper_tx,
+            to_reaper_rx,
+            xtouch_tx,
+            to_xtouch_rx,
+            resyncs,
+        }
+    }
+
+    fn add_track(&self, guid: &str, index: i32) {
+        self.reaper_tx
+            .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid: guid.into(),
+                direction: Direction::Downstream,
+                data: DataPayload::ReaperTrackIndex(Some(index)),
+            }))
+            .unwrap();
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    fn press_resync(&self) {
+        self.xtouch_tx.send(XTouchUpstreamMsg::ResyncPress).unwrap();
+        self.xtouch_tx
+            .send(XTouchUpstreamMsg::ResyncRelease)
+            .unwrap();
+    }
+
+    // Passes the transition's barrier down to the surface and back up, as TrackManager and the
+    // hardware would
+    fn finish_transition(&self) {
+        let barrier = loop {
+            match self.to_reaper_rx.recv_timeout(TIMEOUT) {
+                Ok(TrackMsg::Barrier(barrier)) => break barrier,
+                Ok(_) => {}
+                Err(_) => panic!("No transition was started"),
+            }
+        };
+        self.reaper_tx.send(TrackMsg::Barrier(barrier)).unwrap();
+        self.xtouch_tx
+            .send(XTouchUpstreamMsg::Barrier(barrier))
+            .unwrap();
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    fn drain_surface(&self) -> Vec<XTouchDownstreamMsg> {
+        std::thread::sleep(Duration::from_millis(50));
+        self.to_xtouch_rx.try_iter().collect()
+    }
+}
+
+#[test]
+fn test_resync_asks_for_the_tracks_on_the_surface_and_repaints_them() {
+    let surface = Surface::start(Duration::ZERO);
+    surface.add_track("track-a", 0);
+    surface.add_track("track-b", 1);
+    surface.drain_surface();
+
+    surface.press_resync();
+    assert_eq!(
+        surface.resyncs.recv_timeout(TIMEOUT),
+        Ok(ResyncRequest {
+            tracks: vec![TrackGuid::from("track-a"), TrackGuid::from("track-b")],
+        })
+    );
+    let repainted: Vec<ChannelIndex> = surface
+        .drain_surface()
+        .into_iter()
+        .filter_map(|msg| match msg {
+            XTouchDownstreamMsg::FaderAbs(fader) => Some(fader.idx),
+            _ => None,
+        })
+        .collect();
+    assert!(repainted.contains(&ChannelIndex::new(0)));
+    assert!(repainted.contains(&ChannelIndex::new(1)));
+}
+
+#[test]
+fn test_resync_is_throttled() {
+    let surface = Surface::start(Duration::from_secs(60));
+    surface.press_resync();
+    assert!(surface.resyncs.recv_timeout(TIMEOUT).is_ok());
+    surface.finish_transition();
+
+    surface.press_resync();
+    assert!(surface.resyncs.recv_timeout(TIMEOUT).is_err());
+}
+
+#[test]
+fn test_resync_waits_out_a_transition() {
+    let surface = Surface::start(Duration::ZERO);
+    surface.press_resync();
+    assert!(surface.resyncs.recv_timeout(TIMEOUT).is_ok());
+
+    // The repaint hasn't reached the surface yet
+    surface.press_resync();
+    assert!(surface.resyncs.recv_timeout(TIMEOUT).is_err());
+    surface.finish_transition();
+    surface.press_resync();
+    assert!(surface.resyncs.recv_timeout(TIMEOUT).is_ok());
+}
+
+#[test]
+fn test_surface_coming_back_is_repainted() {
+    let surface = Surface::start(Duration::ZERO);
+    surface.add_track("track-a", 0);
+    surface.drain_surface();
+
+    surface
+        .xtouch_tx
+        .send(XTouchUpstreamMsg::SurfaceStatus(SurfaceStatus::Offline))
+        .unwrap();
+    assert!(surface.drain_surface().is_empty());
+    surface
+        .xtouch_tx
+        .send(XTouchUpstreamMsg::SurfaceStatus(SurfaceStatus::Online))
+        .unwrap();
+    assert!(surface.drain_surface().iter().any(|msg| matches!(
+        msg,
+        XTouchDownstreamMsg::FaderAbs(fader) if fader.idx == ChannelIndex::new(0)
+    )));
+    // Reaper still has everything the modes know
+    assert!(surface.resyncs.try_recv().is_err());
+}
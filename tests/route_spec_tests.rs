@@ -0,0 +1,55 @@
+// Tests for serving Reaper's routes from a spec loaded at runtime
+
+use arpad_rust::osc::spec::RouteSpec;
+
+const VOLUME_AND_TEMPO: &str = r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable]
+
+- osc_address: "/tempo/raw"
+  params: []
+  arguments:
+    - name: bpm
+      type: float
+  access_tags: [readable]
+"#;
+
+#[test]
+fn test_embedded_spec_serves_every_generated_route() {
+    let spec = RouteSpec::embedded();
+    assert!(spec.allows("/track/abc/volume"));
+    assert!(spec.allows("/track/abc/send/0/pan"));
+    assert!(!spec.allows("/nowhere"));
+    assert!(spec.unsupported().is_empty());
+}
+
+#[test]
+fn test_spec_only_serves_the_routes_it_lists() {
+    let spec = RouteSpec::parse(VOLUME_AND_TEMPO).unwrap();
+    assert!(spec.allows("/track/abc/volume"));
+    assert!(spec.allows("/track/*/volume"));
+    assert!(!spec.allows("/track/abc/pan"));
+}
+
+#[test]
+fn test_routes_the_client_was_not_generated_with_are_unsupported() {
+    let spec = RouteSpec::parse(VOLUME_AND_TEMPO).unwrap();
+    assert_eq!(spec.unsupported(), vec!["/tempo/raw"]);
+}
+
+#[test]
+fn test_invalid_spec_is_rejected() {
+    assert!(
+        RouteSpec::parse(
+            "- osc_address: \"stop\"\n  params: []\n  arguments: []\n  access_tags: [writeable]\n"
+        )
+        .is_err()
+    );
+    assert!(RouteSpec::load("no/such/spec.yaml".as_ref()).is_err());
+}
@@ -0,0 +1,46 @@
+// Tests for the table of routes generated from the spec
+
+use std::collections::HashSet;
+
+use arpad_rust::osc::generated_osc::{RouteDirection, RouteInfo, TrackVolume, all_routes};
+
+#[test]
+fn test_route_is_described_as_in_the_spec() {
+    let route = all_routes()
+        .iter()
+        .find(|route| route.name == "track_volume")
+        .unwrap();
+    assert_eq!(
+        *route,
+        RouteInfo {
+            name: "track_volume",
+            struct_name: "TrackVolume",
+            address: TrackVolume::ADDRESS_TEMPLATE,
+            direction: RouteDirection::Both,
+            queryable: true,
+            params: &[("track_guid", "string")],
+            arguments: &[("volume", "float")],
+        }
+    );
+}
+
+#[test]
+fn test_every_route_has_its_own_name_and_struct() {
+    let names: HashSet<_> = all_routes().iter().map(|route| route.name).collect();
+    let structs: HashSet<_> = all_routes().iter().map(|route| route.struct_name).collect();
+    assert_eq!(names.len(), all_routes().len());
+    assert_eq!(structs.len(), all_routes().len());
+}
+
+#[test]
+fn test_params_are_the_placeholders_of_the_address() {
+    for route in all_routes() {
+        let placeholders: Vec<&str> = route
+            .address
+            .split('/')
+            .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+            .collect();
+        let params: Vec<&str> = route.params.iter().map(|(name, _)| *name).collect();
+        assert_eq!(placeholders, params, "params of {}", route.address);
+    }
+}
@@ -0,0 +1,231 @@
+// Tests for capturing and recalling scenes
+//
+// These check TrackManager capturing the mix as a scene and setting it back, the scenes file
+// outliving a restart, and the function keys asking for both.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crossbeam_channel::{Receiver, Sender, unbounded};
+
+use arpad_rust::guid::TrackGuid;
+use arpad_rust::midi::xtouch::{FunctionPress, FunctionRelease, XTouchUpstreamMsg};
+use arpad_rust::modes::mode_manager::ModeManager;
+use arpad_rust::scene::{Scene, SceneStore, SendScene, TrackScene};
+use arpad_rust::track::track::{
+    DataPayload, Direction, MASTER_GUID, SendIndex, SendLevel, TrackDataMsg, TrackManager, TrackMsg,
+};
+
+const TIMEOUT: Duration = Duration::from_millis(200);
+
+// A scenes file of its own for each test, so they can run in parallel
+fn scenes_path(test: &str) -> PathBuf {
+    let path =
+        std::env::temp_dir().join(format!("arpad-scenes-{}-{}.yaml", std::process::id(), test));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn setup_track_manager(
+    scenes: SceneStore,
+) -> (Sender<TrackMsg>, Receiver<TrackMsg>, Receiver<TrackMsg>) {
+    let (to_manager, input) = unbounded();
+    let (upstream, from_upstream) = unbounded();
+    let (downstream, from_downstream) = unbounded();
+    TrackManager::start_with_scenes(
+        input,
+        upstream,
+        downstream,
+        8,
+        Duration::from_secs(60),
+        scenes,
+    );
+    (to_manager, from_upstream, from_downstream)
+}
+
+fn report(to_manager: &Sender<TrackMsg>, guid: &str, data: DataPayload) {
+    to_manager
+        .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: guid.into(),
+            direction: Direction::Downstream,
+            data,
+        }))
+        .unwrap();
+}
+
+// Everything that arrives until the channel goes quiet, as (guid, payload) pairs
+fn drain(rx: &Receiver<TrackMsg>) -> Vec<(TrackGuid, DataPayload)> {
+    let mut msgs = Vec::new();
+    while let Ok(msg) = rx.recv_timeout(TIMEOUT) {
+        if let TrackMsg::TrackDataMsg(msg) = msg {
+            msgs.push((msg.guid, msg.data));
+        }
+    }
+    msgs
+}
+
+fn volumes(msgs: &[(TrackGuid, DataPayload)]) -> Vec<(&str, f32)> {
+    msgs.iter()
+        .filter_map(|(guid, data)| match data {
+            DataPayload::Volume(volume) => Some((guid.as_str(), *volume)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_recall_sets_captured_mix_in_reaper_and_on_surface() {
+    let (to_manager, from_upstream, from_downstream) = setup_track_manager(SceneStore::in_memory());
+    report(&to_manager, "drums", DataPayload::Volume(0.7));
+    report(&to_manager, "drums", DataPayload::Soloed(true));
+    report(
+        &to_manager,
+        "drums",
+        DataPayload::SendIndex(SendIndex {
+            send_index: 0,
+            guid: "reverb".into(),
+        }),
+    );
+    report(
+        &to_manager,
+        "drums",
+        DataPayload::SendLevel(SendLevel {
+            send_index: 0,
+            level: 0.4,
+        }),
+    );
+    report(&to_manager, MASTER_GUID, DataPayload::Volume(0.9));
+    to_manager
+        .send(TrackMsg::CaptureScene("verse".to_string()))
+        .unwrap();
+
+    report(&to_manager, "drums", DataPayload::Volume(0.1));
+    report(&to_manager, MASTER_GUID, DataPayload::Volume(0.2));
+    drain(&from_downstream);
+
+    to_manager
+        .send(TrackMsg::RecallScene("verse".to_string()))
+        .unwrap();
+    let sent = drain(&from_upstream);
+    let mut recalled = volumes(&sent);
+    recalled.sort_by(|a, b| a.0.cmp(b.0));
+    assert_eq!(recalled, vec![("drums", 0.7), ("master", 0.9)]);
+    assert!(sent.iter().any(|(guid, data)| guid == "drums"
+        && matches!(data, DataPayload::SendLevel(send) if send.level == 0.4)));
+    assert!(
+        sent.iter()
+            .any(|(guid, data)| guid == "drums" && matches!(data, DataPayload::Soloed(true)))
+    );
+    // The master track can't be soloed
+    assert!(
+        !sent
+            .iter()
+            .any(|(guid, data)| guid == MASTER_GUID && matches!(data, DataPayload::Soloed(_)))
+    );
+
+    // The surface follows, since the modes didn't make the change themselves
+    let shown = drain(&from_downstream);
+    let mut shown_volumes = volumes(&shown);
+    shown_volumes.sort_by(|a, b| a.0.cmp(b.0));
+    assert_eq!(shown_volumes, recalled);
+}
+
+#[test]
+fn test_recall_skips_unknown_scenes() {
+    let (to_manager, from_upstream, _from_downstream) =
+        setup_track_manager(SceneStore::in_memory());
+    report(&to_manager, "drums", DataPayload::Volume(0.7));
+    to_manager
+        .send(TrackMsg::RecallScene("chorus".to_string()))
+        .unwrap();
+    assert!(drain(&from_upstream).is_empty());
+}
+
+#[test]
+fn test_captured_scenes_are_saved_to_file() {
+    let path = scenes_path("saved");
+    let (to_manager, _from_upstream, from_downstream) =
+        setup_track_manager(SceneStore::open(&path).unwrap());
+    report(&to_manager, "drums", DataPayload::Pan(0.25));
+    report(&to_manager, "drums", DataPayload::Muted(true));
+    to_manager
+        .send(TrackMsg::CaptureScene("F1".to_string()))
+        .unwrap();
+    drain(&from_downstream);
+
+    let scenes = SceneStore::open(&path).unwrap();
+    assert_eq!(scenes.names().collect::<Vec<_>>(), vec!["F1"]);
+    let drums = &scenes.get("F1").unwrap().tracks["drums"];
+    assert_eq!(drums.pan, 0.25);
+    assert!(drums.muted);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_scene_store_replaces_scene_of_same_name() {
+    let path = scenes_path("replace");
+    let mut scenes = SceneStore::open(&path).unwrap();
+    assert_eq!(scenes.names().count(), 0);
+    let scene = |volume| Scene {
+        tracks: [(
+            "drums".into(),
+            TrackScene {
+                volume,
+                sends: vec![SendScene {
+                    send_index: 1,
+                    level: 0.5,
+                    pan: 0.0,
+                    muted: false,
+                }],
+                ..Default::default()
+            },
+        )]
+        .into(),
+    };
+    scenes.insert("verse", scene(0.3)).unwrap();
+    scenes.insert("verse", scene(0.6)).unwrap();
+
+    let reopened = SceneStore::open(&path).unwrap();
+    assert_eq!(reopened.get("verse"), Some(&scene(0.6)));
+    assert_eq!(reopened.names().count(), 1);
+    let _ = std::fs::remove_file(&path);
+}
+
+// The first scene request the modes send, skipping anything else
+fn scene_request(to_reaper: &Receiver<TrackMsg>) -> TrackMsg {
+    loop {
+        match to_reaper.recv_timeout(TIMEOUT) {
+            Ok(msg @ (TrackMsg::CaptureScene(_) | TrackMsg::RecallScene(_))) => return msg,
+            Ok(_) => {}
+            Err(_) => panic!("Expected a scene request"),
+        }
+    }
+}
+
+#[test]
+fn test_function_keys_recall_and_with_shift_capture() {
+    let (_reaper_tx, reaper_rx) = unbounded();
+    let (xtouch_tx, xtouch_rx) = unbounded();
+    let (to_reaper_tx, to_reaper_rx) = unbounded();
+    let (to_xtouch_tx, _to_xtouch_rx) = unbounded();
+    ModeManager::start(reaper_rx, to_reaper_tx, xtouch_rx, to_xtouch_tx);
+
+    let tap = |idx| {
+        xtouch_tx
+            .send(XTouchUpstreamMsg::from(FunctionPress { idx }))
+            .unwrap();
+        xtouch_tx
+            .send(XTouchUpstreamMsg::from(FunctionRelease { idx }))
+            .unwrap();
+    };
+    tap(0);
+    assert!(matches!(scene_request(&to_reaper_rx), TrackMsg::RecallScene(name) if name == "F1"));
+
+    xtouch_tx.send(XTouchUpstreamMsg::ShiftPress).unwrap();
+    tap(2);
+    xtouch_tx.send(XTouchUpstreamMsg::ShiftRelease).unwrap();
+    assert!(matches!(scene_request(&to_reaper_rx), TrackMsg::CaptureScene(name) if name == "F3"));
+
+    tap(2);
+    assert!(matches!(scene_request(&to_reaper_rx), TrackMsg::RecallScene(name) if name == "F3"));
+}
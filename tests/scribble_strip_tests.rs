@@ -0,0 +1,92 @@
+// Tests for scribble strip encoding and for labelling sends with their destination track
+
+use crossbeam_channel::unbounded;
+use std::time::Duration;
+
+use arpad_rust::midi::xtouch::{
+    ChannelIndex, ScribbleColor, ScribbleStripMsg, XTouchDownstreamMsg, scribble_strip_sysex,
+};
+use arpad_rust::modes::mode_manager::{Mode, ModeHandler, ModeState, State};
+use arpad_rust::modes::reaper_track_sends::TrackSendsMode;
+use arpad_rust::track::track::{DataPayload, Direction, SendIndex, TrackDataMsg, TrackMsg};
+
+#[test]
+fn test_scribble_strip_sysex_pads_and_truncates_lines() {
+    let msg = ScribbleStripMsg {
+        idx: ChannelIndex::new(3),
+        top_line: "Vocals".to_string(),
+        bottom_line: "Überlong".to_string(),
+        color: ScribbleColor::Cyan,
+    };
+    let mut expected = vec![0xF0, 0x00, 0x20, 0x32, 0x14, 0x4C, 3, 6];
+    expected.extend_from_slice(b"Vocals ?berlon");
+    expected.push(0xF7);
+    assert_eq!(scribble_strip_sysex(3, &msg), expected);
+}
+
+#[test]
+fn test_track_colors_light_the_nearest_backlight() {
+    assert_eq!(ScribbleColor::nearest(0xFF0000), ScribbleColor::Red);
+    assert_eq!(ScribbleColor::nearest(0x20A040), ScribbleColor::Green);
+    assert_eq!(ScribbleColor::nearest(0xE08010), ScribbleColor::Yellow);
+    assert_eq!(ScribbleColor::nearest(0x8030C0), ScribbleColor::Magenta);
+    assert_eq!(ScribbleColor::nearest(0x10C0C0), ScribbleColor::Cyan);
+    assert_eq!(ScribbleColor::nearest(0x808080), ScribbleColor::White);
+    // Reaper marks custom colors with a bit above the RGB ones
+    assert_eq!(ScribbleColor::nearest(0x10000FF), ScribbleColor::Blue);
+    // No color of its own, or black, keeps the strip readable
+    assert_eq!(ScribbleColor::nearest(0), ScribbleColor::White);
+}
+
+#[test]
+fn test_track_sends_mode_labels_sends_with_destination_name() {
+    let (_from_reaper_tx, from_reaper_rx) = unbounded();
+    let (to_reaper_tx, _to_reaper_rx) = unbounded();
+    let (_from_xtouch_tx, from_xtouch_rx) = unbounded();
+    let (to_xtouch_tx, to_xtouch_rx) = unbounded();
+    let mut mode = TrackSendsMode::new(
+        8,
+        from_reaper_rx,
+        to_reaper_tx,
+        from_xtouch_rx,
+        to_xtouch_tx,
+    );
+    let curr_mode = ModeState {
+        mode: Mode::ReaperSends,
+        state: State::Active,
+    };
+
+    // Names can be learned while the mode is inactive
+    mode.set_track_name(&"reverb-bus".into(), "Reverb");
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: "source-track".into(),
+            direction: Direction::Downstream,
+            data: DataPayload::SendIndex(SendIndex {
+                send_index: 1,
+                guid: "reverb-bus".into(),
+            }),
+        }),
+        curr_mode,
+    );
+    let expect_label = |top: &str| match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::ScribbleStrip(msg)) => {
+            assert_eq!(msg.idx, 1);
+            assert_eq!(msg.top_line, top);
+            assert_eq!(msg.bottom_line, "Send 2");
+        }
+        other => panic!("Expected ScribbleStrip message but got {:?}", other),
+    };
+    expect_label("Reverb");
+
+    // Renaming the destination relabels the send
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: "reverb-bus".into(),
+            direction: Direction::Downstream,
+            data: DataPayload::Name("Plate".to_string()),
+        }),
+        curr_mode,
+    );
+    expect_label("Plate");
+}
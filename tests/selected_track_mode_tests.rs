@@ -0,0 +1,264 @@
+// Integration tests for SelectedTrackMode
+//
+// These tests drive SelectedTrackMode directly: showing the selected track and its sends across
+// the surface, sending changes from the faders, encoders and buttons back up, and leaving other
+// tracks alone.
+
+use crossbeam_channel::{Receiver, unbounded};
+use std::time::Duration;
+
+use arpad_rust::midi::xtouch::{
+    ChannelIndex, EncoderRingLEDMsg, EncoderTurnCCW, EncoderTurnCW, FaderAbsMsg, LEDState,
+    MutePress, ScribbleStripMsg, SelectPress, XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
+use arpad_rust::modes::mode_manager::{Mode, ModeHandler, ModeState, State};
+use arpad_rust::modes::reaper_selected_track::SelectedTrackMode;
+use arpad_rust::track::track::{DataPayload, Direction, SendIndex, TrackDataMsg, TrackMsg};
+
+const TRACK_GUID: &str = "vocals";
+
+const ACTIVE: ModeState = ModeState {
+    mode: Mode::ReaperSelectedTrack,
+    state: State::Active,
+};
+
+/// Enters SelectedTrackMode for a track with a send in slot 0, returning the mode along with the
+/// channels to Reaper and the hardware
+fn setup_selected_track_mode() -> (
+    SelectedTrackMode,
+    Receiver<TrackMsg>,
+    Receiver<XTouchDownstreamMsg>,
+) {
+    let (to_reaper_tx, to_reaper_rx) = unbounded();
+    let (to_xtouch_tx, to_xtouch_rx) = unbounded();
+    let mut mode = SelectedTrackMode::new(8, to_reaper_tx, to_xtouch_tx);
+    mode.set_track_name(&"reverb-bus".into(), "Reverb");
+
+    let (upstream_tx, _upstream_rx) = unbounded();
+    mode.initiate_mode_transition(upstream_tx, &TRACK_GUID.into());
+    send_downstream(
+        &mut mode,
+        TRACK_GUID,
+        DataPayload::SendIndex(SendIndex {
+            send_index: 0,
+            guid: "reverb-bus".into(),
+        }),
+    );
+    while to_xtouch_rx.try_recv().is_ok() {}
+
+    (mode, to_reaper_rx, to_xtouch_rx)
+}
+
+fn send_downstream(mode: &mut SelectedTrackMode, guid: &str, data: DataPayload) {
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: guid.into(),
+            direction: Direction::Downstream,
+            data,
+        }),
+        ACTIVE,
+    );
+}
+
+/// What the mode sends up to Reaper for the shown track, until it goes quiet
+fn sent_upstream(to_reaper_rx: &Receiver<TrackMsg>) -> Vec<DataPayload> {
+    let mut payloads = Vec::new();
+    while let Ok(msg) = to_reaper_rx.recv_timeout(Duration::from_millis(100)) {
+        if let TrackMsg::TrackDataMsg(msg) = msg {
+            assert_eq!(msg.guid, TRACK_GUID);
+            assert!(matches!(msg.direction, Direction::Upstream));
+            payloads.push(msg.data);
+        }
+    }
+    payloads
+}
+
+fn drain(to_xtouch_rx: &Receiver<XTouchDownstreamMsg>) -> Vec<XTouchDownstreamMsg> {
+    let mut msgs = Vec::new();
+    while let Ok(msg) = to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        msgs.push(msg);
+    }
+    msgs
+}
+
+#[test]
+fn test_selected_track_mode_labels_track_encoders_and_sends() {
+    let (mut mode, _to_reaper_rx, to_xtouch_rx) = setup_selected_track_mode();
+
+    send_downstream(
+        &mut mode,
+        TRACK_GUID,
+        DataPayload::Name("Vocals".to_string()),
+    );
+    send_downstream(
+        &mut mode,
+        TRACK_GUID,
+        DataPayload::SendIndex(SendIndex {
+            send_index: 1,
+            guid: "delay-bus".into(),
+        }),
+    );
+    send_downstream(
+        &mut mode,
+        "delay-bus",
+        DataPayload::Name("Delay".to_string()),
+    );
+    let strips: Vec<ScribbleStripMsg> = drain(&to_xtouch_rx)
+        .into_iter()
+        .filter_map(|msg| match msg {
+            XTouchDownstreamMsg::ScribbleStrip(strip) => Some(strip),
+            _ => None,
+        })
+        .collect();
+    let lines: Vec<(usize, &str, &str)> = strips
+        .iter()
+        .map(|strip| {
+            (
+                strip.idx.get(),
+                strip.top_line.as_str(),
+                strip.bottom_line.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            (0, "Vocals", "Pan"),
+            (2, "", "InGain"),
+            (2, "Delay", "InGain")
+        ]
+    );
+}
+
+#[test]
+fn test_selected_track_mode_faders_set_volume_and_sends() {
+    let (mut mode, to_reaper_rx, _to_xtouch_rx) = setup_selected_track_mode();
+
+    for idx in [0, 1, 2] {
+        mode.handle_upstream_messages(
+            XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
+                idx: ChannelIndex::new(idx),
+                value: 1.0,
+            }),
+            ACTIVE,
+        );
+    }
+    // The third fader has no send to set
+    let sent = sent_upstream(&to_reaper_rx);
+    assert_eq!(sent.len(), 2, "{:?}", sent);
+    assert!(matches!(sent[0], DataPayload::Volume(_)));
+    assert!(matches!(&sent[1], DataPayload::SendLevel(send) if send.send_index == 0));
+}
+
+#[test]
+fn test_selected_track_mode_encoders_set_pan_width_and_input_gain() {
+    let (mut mode, to_reaper_rx, to_xtouch_rx) = setup_selected_track_mode();
+
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::EncoderTurnInc(EncoderTurnCW {
+            idx: ChannelIndex::new(0),
+        }),
+        ACTIVE,
+    );
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::EncoderTurnDec(EncoderTurnCCW {
+            idx: ChannelIndex::new(1),
+        }),
+        ACTIVE,
+    );
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::EncoderTurnInc(EncoderTurnCW {
+            idx: ChannelIndex::new(2),
+        }),
+        ACTIVE,
+    );
+    // Encoders past the third have nothing to set
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::EncoderTurnInc(EncoderTurnCW {
+            idx: ChannelIndex::new(3),
+        }),
+        ACTIVE,
+    );
+
+    match sent_upstream(&to_reaper_rx).as_slice() {
+        [
+            DataPayload::Pan(pan),
+            DataPayload::Width(width),
+            DataPayload::InputGain(input_gain),
+        ] => {
+            assert!((pan - 0.1).abs() < 0.0001, "pan was {}", pan);
+            assert!((width - 0.9).abs() < 0.0001, "width was {}", width);
+            assert!(
+                (input_gain - 0.02).abs() < 0.0001,
+                "gain was {}",
+                input_gain
+            );
+        }
+        other => panic!("Expected pan, width and input gain but got {:?}", other),
+    }
+    let rings: Vec<usize> = drain(&to_xtouch_rx)
+        .into_iter()
+        .filter_map(|msg| match msg {
+            XTouchDownstreamMsg::EncoderRingLED(EncoderRingLEDMsg::RangePoint(ring)) => {
+                Some(ring.idx.get())
+            }
+            XTouchDownstreamMsg::EncoderRingLED(EncoderRingLEDMsg::RangeFill(ring)) => {
+                Some(ring.idx.get())
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(rings, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_selected_track_mode_select_button_toggles_phase() {
+    let (mut mode, to_reaper_rx, to_xtouch_rx) = setup_selected_track_mode();
+
+    send_downstream(&mut mode, TRACK_GUID, DataPayload::Phase(true));
+    let lit = drain(&to_xtouch_rx).into_iter().any(|msg| {
+        matches!(msg, XTouchDownstreamMsg::SelectLED(led)
+            if led.idx.get() == 0 && led.state == LEDState::On)
+    });
+    assert!(lit, "Expected the select LED to show the inverted phase");
+
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::SelectPress(SelectPress {
+            idx: ChannelIndex::new(0),
+        }),
+        ACTIVE,
+    );
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::MutePress(MutePress {
+            idx: ChannelIndex::new(0),
+        }),
+        ACTIVE,
+    );
+    // Only the track's own strip has buttons
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::MutePress(MutePress {
+            idx: ChannelIndex::new(1),
+        }),
+        ACTIVE,
+    );
+    let sent = sent_upstream(&to_reaper_rx);
+    assert!(
+        matches!(
+            sent.as_slice(),
+            [DataPayload::Phase(false), DataPayload::Muted(true)]
+        ),
+        "{:?}",
+        sent
+    );
+}
+
+#[test]
+fn test_selected_track_mode_ignores_other_tracks() {
+    let (mut mode, _to_reaper_rx, to_xtouch_rx) = setup_selected_track_mode();
+
+    send_downstream(&mut mode, "drums", DataPayload::Volume(0.8));
+    send_downstream(&mut mode, "drums", DataPayload::Muted(true));
+    send_downstream(&mut mode, "drums", DataPayload::Pan(-1.0));
+    let msgs = drain(&to_xtouch_rx);
+    assert!(msgs.is_empty(), "{:?}", msgs);
+}
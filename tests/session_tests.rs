@@ -0,0 +1,201 @@
+// Tests for saving the modes' state to disk and picking it up again after a restart
+
+use crossbeam_channel::{Receiver, Sender, bounded, unbounded};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use arpad_rust::guid::TrackGuid;
+use arpad_rust::midi::xtouch::{XTouchDownstreamMsg, XTouchUpstreamMsg};
+use arpad_rust::modes::mode_manager::{
+    Mode, ModeHandler, ModeManager, ModeOptions, ModeState, State,
+};
+use arpad_rust::modes::reaper_vol_pan::VolumePanMode;
+use arpad_rust::modes::session::{
+    SendsSession, SessionError, SessionSnapshot, SessionStore, TrackSession, VolPanSession,
+};
+use arpad_rust::track::track::{DataPayload, Direction, TrackDataMsg, TrackMsg};
+
+const ACTIVE: ModeState = ModeState {
+    mode: Mode::ReaperVolPan,
+    state: State::Active,
+};
+
+// A session file of its own for each test, so they can run in parallel
+fn session_path(test: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "arpad-session-{}-{}.yaml",
+        std::process::id(),
+        test
+    ));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn saved_session() -> SessionSnapshot {
+    SessionSnapshot {
+        vol_pan: VolPanSession {
+            pinned: BTreeMap::from([(2, "track-b".into())]),
+            bank: 0,
+            tracks: BTreeMap::from([(
+                "track-a".into(),
+                TrackSession {
+                    name: "Drums".to_string(),
+                    volume: 0.3,
+                    pan: 0.25,
+                    mute: true,
+                    solo: false,
+                    arm: true,
+                },
+            )]),
+        },
+        sends: SendsSession {
+            track_guid: Some("track-a".into()),
+            track_names: BTreeMap::from([("track-a".into(), "Drums".to_string())]),
+        },
+    }
+}
+
+fn send_track_data(reaper_tx: &Sender<TrackMsg>, guid: &str, data: DataPayload) {
+    reaper_tx
+        .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: guid.into(),
+            direction: Direction::Downstream,
+            data,
+        }))
+        .unwrap();
+}
+
+fn start_mode_manager(
+    options: ModeOptions,
+) -> (
+    Sender<TrackMsg>,
+    Receiver<TrackMsg>,
+    Sender<XTouchUpstreamMsg>,
+    Receiver<XTouchDownstreamMsg>,
+) {
+    let (reaper_tx, reaper_rx) = bounded(128);
+    let (xtouch_tx, xtouch_rx) = bounded(128);
+    let (to_reaper_tx, to_reaper_rx) = bounded(128);
+    let (to_xtouch_tx, to_xtouch_rx) = unbounded();
+    ModeManager::start_with_options(reaper_rx, to_reaper_tx, xtouch_rx, to_xtouch_tx, options);
+    (reaper_tx, to_reaper_rx, xtouch_tx, to_xtouch_rx)
+}
+
+#[test]
+fn test_session_store_round_trip() {
+    let path = session_path("round_trip");
+    let store = SessionStore::new(&path, Duration::from_secs(5));
+
+    // Nothing saved yet is not an error
+    assert!(matches!(store.load(), Ok(None)));
+
+    store.save(&saved_session()).unwrap();
+    assert_eq!(store.load().unwrap(), Some(saved_session()));
+
+    // Saving again replaces the session
+    store.save(&SessionSnapshot::default()).unwrap();
+    assert_eq!(store.load().unwrap(), Some(SessionSnapshot::default()));
+    assert!(!path.with_extension("partial").exists());
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_session_store_reports_unreadable_session() {
+    let path = session_path("unreadable");
+    std::fs::write(&path, "vol_pan: [not, a, session]").unwrap();
+
+    let store = SessionStore::new(&path, Duration::from_secs(5));
+    assert!(matches!(store.load(), Err(SessionError::Parse(_))));
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_vol_pan_restore_waits_for_reaper_to_confirm_tracks() {
+    let (_from_reaper_tx, from_reaper_rx) = unbounded();
+    let (to_reaper_tx, _to_reaper_rx) = unbounded();
+    let (_from_xtouch_tx, from_xtouch_rx) = unbounded();
+    let (to_xtouch_tx, to_xtouch_rx) = unbounded();
+    let mut mode = VolumePanMode::new(
+        8,
+        from_reaper_rx,
+        to_reaper_tx,
+        from_xtouch_rx,
+        to_xtouch_tx,
+    );
+
+    let session = saved_session().vol_pan;
+    mode.restore(session.clone());
+    assert_eq!(mode.snapshot(), session);
+
+    // Nothing is shown for a track until Reaper says it still exists
+    assert!(mode.find_hw_channel(&TrackGuid::from("track-a")).is_none());
+    while to_xtouch_rx.try_recv().is_ok() {}
+
+    // And then it shows up with its saved state
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: "track-a".into(),
+            direction: Direction::Downstream,
+            data: DataPayload::ReaperTrackIndex(Some(0)),
+        }),
+        ACTIVE,
+    );
+    assert_eq!(mode.find_hw_channel(&TrackGuid::from("track-a")), Some(0));
+    let fader = to_xtouch_rx.try_iter().find_map(|msg| match msg {
+        XTouchDownstreamMsg::FaderAbs(fader) => Some(fader),
+        _ => None,
+    });
+    let fader = fader.expect("Expected the restored track's fader to be sent");
+    assert_eq!(fader.idx, 0);
+    assert!(
+        (fader.value - 0.3).abs() < 0.0001,
+        "fader was {}",
+        fader.value
+    );
+}
+
+#[test]
+fn test_mode_manager_saves_session_as_it_changes() {
+    let path = session_path("saves");
+    let store = SessionStore::new(&path, Duration::from_millis(20));
+    let (reaper_tx, _to_reaper_rx, _xtouch_tx, _to_xtouch_rx) = start_mode_manager(ModeOptions {
+        session: Some(store.clone()),
+        ..Default::default()
+    });
+
+    send_track_data(&reaper_tx, "track-a", DataPayload::Name("Bass".to_string()));
+    send_track_data(&reaper_tx, "track-a", DataPayload::Volume(0.4));
+    std::thread::sleep(Duration::from_millis(200));
+
+    let session = store.load().unwrap().expect("Expected a saved session");
+    let track = &session.vol_pan.tracks["track-a"];
+    assert_eq!(track.name, "Bass");
+    assert!(
+        (track.volume - 0.4).abs() < 0.0001,
+        "volume was {}",
+        track.volume
+    );
+    assert_eq!(session.sends.track_names["track-a"], "Bass");
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_mode_manager_restores_selected_track_for_sends() {
+    let (_reaper_tx, to_reaper_rx, xtouch_tx, _to_xtouch_rx) = start_mode_manager(ModeOptions {
+        restored_session: Some(saved_session()),
+        ..Default::default()
+    });
+
+    // Reaper hasn't reported a selection yet, but the sends mode still has a track to show
+    xtouch_tx.send(XTouchUpstreamMsg::MIDITracksPress).unwrap();
+    let query = std::iter::from_fn(|| to_reaper_rx.recv_timeout(Duration::from_millis(200)).ok())
+        .find(|msg| matches!(msg, TrackMsg::TrackQuery(_)));
+    match query {
+        Some(TrackMsg::TrackQuery(query)) => assert_eq!(query.guid, "track-a"),
+        other => panic!("Expected a TrackQuery but got {:?}", other),
+    }
+}
@@ -0,0 +1,168 @@
+// A stand-in for Reaper on the other end of the bridge's OSC socket, and the bridge itself run as
+// its own process, for tests that go through the whole of main.rs
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::net::{SocketAddr, UdpSocket};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rosc::{OscMessage, OscPacket, OscType};
+
+pub fn osc_message(addr: &str, args: Vec<OscType>) -> OscMessage {
+    OscMessage {
+        addr: addr.to_string(),
+        args,
+    }
+}
+
+// Bundles are taken apart, since the bridge cares about the messages and not how they're packed
+fn flatten(packet: OscPacket, into: &mut Vec<OscMessage>) {
+    match packet {
+        OscPacket::Message(msg) => into.push(msg),
+        OscPacket::Bundle(bundle) => {
+            for packet in bundle.content {
+                flatten(packet, into);
+            }
+        }
+    }
+}
+
+/// Plays Reaper over loopback UDP: it records everything the bridge sends it, and answers
+/// scripted messages the way Reaper answers queries.
+pub struct FakeReaper {
+    socket: UdpSocket,
+    received: Arc<(Mutex<Vec<OscMessage>>, Condvar)>,
+    responses: Arc<Mutex<HashMap<String, Vec<OscMessage>>>>,
+}
+
+impl FakeReaper {
+    pub fn start() -> Self {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let received = Arc::new((Mutex::new(Vec::new()), Condvar::new()));
+        let responses: Arc<Mutex<HashMap<String, Vec<OscMessage>>>> = Arc::default();
+        thread::spawn({
+            let socket = socket.try_clone().unwrap();
+            let received = received.clone();
+            let responses = responses.clone();
+            move || {
+                let mut buf = [0u8; rosc::decoder::MTU];
+                while let Ok((size, from)) = socket.recv_from(&mut buf) {
+                    let Ok((_, packet)) = rosc::decoder::decode_udp(&buf[..size]) else {
+                        continue;
+                    };
+                    let mut messages = Vec::new();
+                    flatten(packet, &mut messages);
+                    for msg in &messages {
+                        for response in responses
+                            .lock()
+                            .unwrap()
+                            .get(&msg.addr)
+                            .into_iter()
+                            .flatten()
+                        {
+                            send_to(&socket, from, response.clone());
+                        }
+                    }
+                    let (log, arrived) = &*received;
+                    log.lock().unwrap().extend(messages);
+                    arrived.notify_all();
+                }
+            }
+        });
+        FakeReaper {
+            socket,
+            received,
+            responses,
+        }
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.socket.local_addr().unwrap()
+    }
+
+    /// Answers every message the bridge sends to `addr` with `responses`.
+    pub fn respond_to(&self, addr: &str, responses: Vec<OscMessage>) {
+        self.responses
+            .lock()
+            .unwrap()
+            .insert(addr.to_string(), responses);
+    }
+
+    /// Sends `msg` to the bridge listening on `to`, as though Reaper reported it.
+    pub fn send(&self, to: SocketAddr, msg: OscMessage) {
+        send_to(&self.socket, to, msg);
+    }
+
+    /// Waits for the bridge to have sent a message matching `predicate`, returning it.
+    pub fn wait_for<F>(&self, timeout: Duration, predicate: F) -> Option<OscMessage>
+    where
+        F: Fn(&OscMessage) -> bool,
+    {
+        let deadline = Instant::now() + timeout;
+        let (log, arrived) = &*self.received;
+        let mut log = log.lock().unwrap();
+        loop {
+            if let Some(msg) = log.iter().find(|msg| predicate(msg)) {
+                return Some(msg.clone());
+            }
+            let remaining = deadline.checked_duration_since(Instant::now())?;
+            log = arrived.wait_timeout(log, remaining).unwrap().0;
+        }
+    }
+}
+
+fn send_to(socket: &UdpSocket, to: SocketAddr, msg: OscMessage) {
+    let packet = rosc::encoder::encode(&OscPacket::Message(msg)).unwrap();
+    socket.send_to(&packet, to).unwrap();
+}
+
+/// The bridge binary, talking to a [`FakeReaper`] and driving a simulated XTouch that lines
+/// written to its stdin act on. It's killed when dropped.
+pub struct Bridge {
+    child: Child,
+    stdin: ChildStdin,
+    pub addr: SocketAddr,
+}
+
+impl Bridge {
+    pub fn start(reaper: &FakeReaper, args: &[&str]) -> Self {
+        // Borrow a free port for the bridge to listen on
+        let addr = UdpSocket::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let mut child = Command::new(env!("CARGO_BIN_EXE_arpad"))
+            .arg("run")
+            .arg("--osc-address")
+            .arg(addr.to_string())
+            .arg("--reaper-host")
+            .arg(reaper.addr().ip().to_string())
+            .arg("--reaper-port")
+            .arg(reaper.addr().port().to_string())
+            .arg("--simulate-xtouch")
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("couldn't start the bridge");
+        let stdin = child.stdin.take().unwrap();
+        Bridge { child, stdin, addr }
+    }
+
+    /// Acts on the simulated surface, e.g. "fader 0 0.5".
+    pub fn command(&mut self, line: &str) {
+        writeln!(self.stdin, "{}", line).unwrap();
+        self.stdin.flush().unwrap();
+    }
+}
+
+impl Drop for Bridge {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
@@ -0,0 +1,70 @@
+// Tests for the tapers laying volume out along the faders
+
+use arpad_rust::modes::taper::{FADER_0DB, LOG_MIN_DB, MAX_DB, Taper, db_to_gain, gain_to_db};
+
+const TAPERS: [Taper; 3] = [Taper::Linear, Taper::Log, Taper::ReaperDb];
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 0.001,
+        "expected {} but got {}",
+        expected,
+        actual
+    );
+}
+
+#[test]
+fn test_gain_and_db_conversions() {
+    assert_close(db_to_gain(0.0), 1.0);
+    assert_close(db_to_gain(-6.0), 0.501);
+    assert_close(gain_to_db(2.0), 6.021);
+    assert_eq!(gain_to_db(0.0), f32::NEG_INFINITY);
+}
+
+#[test]
+fn test_known_db_points() {
+    // Where each taper puts 0 dB
+    assert_close(Taper::ReaperDb.db_to_fader(0.0), FADER_0DB);
+    assert_close(Taper::Log.db_to_fader(0.0), 60.0 / 72.0);
+    assert_close(Taper::Linear.db_to_fader(0.0), 1.0 / db_to_gain(MAX_DB));
+
+    // Reaper's curve tops out at about +12 dB, like its faders
+    assert_close(Taper::ReaperDb.db_to_fader(-12.0), 0.507);
+    assert!((Taper::ReaperDb.fader_to_db(1.0) - MAX_DB).abs() < 0.5);
+
+    assert_close(Taper::Log.db_to_fader(LOG_MIN_DB), 0.0);
+    assert_close(Taper::Log.db_to_fader(MAX_DB), 1.0);
+    assert_close(Taper::Linear.db_to_fader(MAX_DB), 1.0);
+}
+
+#[test]
+fn test_fader_ends() {
+    for taper in TAPERS {
+        assert_eq!(taper.fader_to_db(0.0), f32::NEG_INFINITY, "{:?}", taper);
+        assert_eq!(taper.db_to_fader(f32::NEG_INFINITY), 0.0, "{:?}", taper);
+        // Nothing goes past the top of the fader
+        assert_eq!(taper.db_to_fader(MAX_DB + 24.0), 1.0, "{:?}", taper);
+    }
+}
+
+#[test]
+fn test_conversions_round_trip() {
+    for taper in TAPERS {
+        for db in [-40.0, -12.0, -3.0, 0.0, 6.0] {
+            assert_close(taper.fader_to_db(taper.db_to_fader(db)), db);
+        }
+        for fader in [0.1, 0.5, FADER_0DB, 0.9] {
+            assert_close(taper.volume_to_fader(taper.fader_to_volume(fader)), fader);
+        }
+    }
+}
+
+#[test]
+fn test_reaper_volume_puts_0db_on_each_taper() {
+    // Reaper reports 0 dB as FADER_0DB, wherever the taper shows it
+    for taper in TAPERS {
+        assert_close(taper.volume_to_fader(FADER_0DB), taper.db_to_fader(0.0));
+    }
+    // And on Reaper's own curve, its volumes go straight to the fader
+    assert_eq!(Taper::ReaperDb.volume_to_fader(0.3), 0.3);
+}
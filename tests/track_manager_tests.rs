@@ -1,6 +1,8 @@
+use arpad_rust::guid::TrackGuid;
 use arpad_rust::modes::mode_manager::Barrier;
 use arpad_rust::track::track::{
-    DataPayload, Direction, SendIndex, SendLevel, TrackDataMsg, TrackManager, TrackMsg, TrackQuery,
+    DataPayload, Direction, FXBypass, FXWet, InitialSync, SendDiscovery, SendIndex, SendLevel,
+    TrackDataMsg, TrackManager, TrackMsg, TrackQuery,
 };
 use crossbeam_channel::{Receiver, Sender, bounded};
 use std::time::Duration;
@@ -41,7 +43,7 @@ fn test_track_manager_forwards_barriers() {
 fn test_track_manager_handles_track_name() {
     let (input_tx, _upstream_rx, downstream_rx) = setup_track_manager();
 
-    let test_guid = "test-track-guid-1".to_string();
+    let test_guid = TrackGuid::from("test-track-guid-1");
     let test_name = "Test Track".to_string();
 
     input_tx
@@ -72,7 +74,7 @@ fn test_track_manager_handles_track_name() {
 fn test_track_manager_handles_track_volume() {
     let (input_tx, upstream_rx, _downstream_rx) = setup_track_manager();
 
-    let test_guid = "test-track-guid-2".to_string();
+    let test_guid = TrackGuid::from("test-track-guid-2");
     let test_volume = 0.75;
 
     input_tx
@@ -103,7 +105,7 @@ fn test_track_manager_handles_track_volume() {
 fn test_track_manager_responds_to_track_query() {
     let (input_tx, upstream_rx, downstream_rx) = setup_track_manager();
 
-    let test_guid = "test-track-guid-3".to_string();
+    let test_guid = TrackGuid::from("test-track-guid-3");
 
     // First, populate some track data
     input_tx
@@ -148,9 +150,9 @@ fn test_track_manager_responds_to_track_query() {
 fn test_track_manager_handles_send_data() {
     let (input_tx, _upstream_rx, downstream_rx) = setup_track_manager();
 
-    let test_guid = "test-track-guid-4".to_string();
+    let test_guid = TrackGuid::from("test-track-guid-4");
     let send_index = 2;
-    let target_guid = "target-track-guid".to_string();
+    let target_guid = TrackGuid::from("target-track-guid");
 
     // Set send index (maps send to target track)
     input_tx
@@ -190,7 +192,7 @@ fn test_track_manager_message_ordering() {
     // Test that messages are processed in the order they're sent
     let (input_tx, _upstream_rx, downstream_rx) = setup_track_manager();
 
-    let test_guid = "test-track-ordering".to_string();
+    let test_guid = TrackGuid::from("test-track-ordering");
 
     // Send multiple messages in sequence
     let messages = vec![
@@ -237,9 +239,9 @@ fn test_track_manager_concurrent_tracks() {
     // Test that TrackManager can handle messages for multiple tracks concurrently
     let (input_tx, _upstream_rx, downstream_rx) = setup_track_manager();
 
-    let track1 = "track-1".to_string();
-    let track2 = "track-2".to_string();
-    let track3 = "track-3".to_string();
+    let track1 = TrackGuid::from("track-1");
+    let track2 = TrackGuid::from("track-2");
+    let track3 = TrackGuid::from("track-3");
 
     // Send messages for multiple tracks
     input_tx
@@ -283,7 +285,7 @@ fn test_track_manager_concurrent_tracks() {
 fn test_track_manager_query_nonexistent_track() {
     let (input_tx, upstream_rx, _downstream_rx) = setup_track_manager();
 
-    let nonexistent_guid = "nonexistent-track".to_string();
+    let nonexistent_guid = TrackGuid::from("nonexistent-track");
 
     input_tx
         .send(TrackMsg::TrackQuery(TrackQuery {
@@ -304,3 +306,471 @@ fn test_track_manager_query_nonexistent_track() {
         "Query for nonexistent track currently returns nothing"
     );
 }
+
+#[test]
+fn test_track_manager_replays_state_in_project_load_order() {
+    let (input_tx, _upstream_rx, downstream_rx) = setup_track_manager();
+
+    // Learn about the second track first, and give both some state out of order
+    for (guid, data) in [
+        ("track-b", DataPayload::Volume(0.25)),
+        ("track-b", DataPayload::ReaperTrackIndex(Some(1))),
+        ("track-a", DataPayload::Name("Drums".to_string())),
+        ("track-a", DataPayload::ReaperTrackIndex(Some(0))),
+        (
+            "track-a",
+            DataPayload::SendIndex(SendIndex {
+                send_index: 0,
+                guid: "track-b".into(),
+            }),
+        ),
+    ] {
+        input_tx
+            .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid: guid.into(),
+                direction: Direction::Downstream,
+                data,
+            }))
+            .unwrap();
+    }
+    // Drain the live forwards
+    for _ in 0..5 {
+        downstream_rx
+            .recv_timeout(Duration::from_millis(100))
+            .unwrap();
+    }
+
+    let barrier = Barrier::new();
+    input_tx.send(TrackMsg::Replay(barrier)).unwrap();
+
+    let mut replayed = Vec::new();
+    loop {
+        match downstream_rx.recv_timeout(Duration::from_millis(100)) {
+            Ok(TrackMsg::TrackDataMsg(msg)) => replayed.push(msg),
+            Ok(TrackMsg::Barrier(received)) => {
+                assert_eq!(
+                    received, barrier,
+                    "Replay should end with the requested barrier"
+                );
+                break;
+            }
+            other => panic!("Unexpected message during replay: {:?}", other),
+        }
+    }
+
+    // Track A comes first because Reaper has it at index 0, and each track starts with its index
+    let first_b = replayed.iter().position(|m| m.guid == "track-b").unwrap();
+    assert!(replayed[..first_b].iter().all(|m| m.guid == "track-a"));
+    assert!(replayed[first_b..].iter().all(|m| m.guid == "track-b"));
+    assert!(matches!(
+        replayed[0].data,
+        DataPayload::ReaperTrackIndex(Some(0))
+    ));
+    assert!(matches!(
+        replayed[first_b].data,
+        DataPayload::ReaperTrackIndex(Some(1))
+    ));
+
+    // The send's key (its target GUID) precedes its level
+    let send_index_pos = replayed
+        .iter()
+        .position(|m| matches!(&m.data, DataPayload::SendIndex(s) if s.guid == "track-b"))
+        .unwrap();
+    let send_level_pos = replayed
+        .iter()
+        .position(|m| matches!(m.data, DataPayload::SendLevel(_)))
+        .unwrap();
+    assert!(send_index_pos < send_level_pos);
+
+    // Replayed values match what was accumulated
+    assert!(
+        replayed
+            .iter()
+            .any(|m| m.guid == "track-b" && matches!(m.data, DataPayload::Volume(v) if v == 0.25))
+    );
+    assert!(
+        replayed
+            .iter()
+            .all(|m| m.direction == Direction::Downstream)
+    );
+}
+
+#[test]
+fn test_track_manager_accumulates_fx_bypass_and_wet() {
+    let (input_tx, upstream_rx, _downstream_rx) = setup_track_manager();
+
+    let test_guid = TrackGuid::from("test-track-guid-fx");
+    for data in [
+        DataPayload::FXBypass(FXBypass {
+            fx_index: 1,
+            bypass: true,
+        }),
+        DataPayload::FXWet(FXWet {
+            fx_index: 1,
+            wet: 0.25,
+        }),
+    ] {
+        input_tx
+            .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid: test_guid.clone(),
+                direction: Direction::Downstream,
+                data,
+            }))
+            .unwrap();
+    }
+    input_tx
+        .send(TrackMsg::TrackQuery(TrackQuery {
+            guid: test_guid.clone(),
+            direction: Direction::Upstream,
+        }))
+        .unwrap();
+
+    match upstream_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(TrackMsg::TrackDataMsg(TrackDataMsg {
+            data: DataPayload::TrackData(track_data),
+            ..
+        })) => {
+            let fx = track_data.fx();
+            assert_eq!(fx.len(), 2);
+            // FX we haven't heard about yet default to active and fully wet
+            assert!(!fx[0].bypass);
+            assert_eq!(fx[0].wet, 1.0);
+            assert!(fx[1].bypass);
+            assert_eq!(fx[1].wet, 0.25);
+        }
+        _ => panic!("Expected TrackData payload in response to query"),
+    }
+}
+
+fn send_track_index(input_tx: &Sender<TrackMsg>, guid: &str, index: i32) {
+    input_tx
+        .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: guid.into(),
+            direction: Direction::Downstream,
+            data: DataPayload::ReaperTrackIndex(Some(index)),
+        }))
+        .unwrap();
+}
+
+#[test]
+fn test_track_manager_reports_initial_sync_once_visible_tracks_are_initialized() {
+    let (input_tx, input_rx) = bounded(128);
+    let (upstream_tx, _upstream_rx) = bounded(128);
+    let (downstream_tx, downstream_rx) = bounded(128);
+    TrackManager::start_with_initial_sync(
+        input_rx,
+        upstream_tx,
+        downstream_tx,
+        2,
+        Duration::from_secs(10),
+    );
+
+    // Three tracks in the project, but only the first two are visible
+    input_tx.send(TrackMsg::NumTracks(3)).unwrap();
+    send_track_index(&input_tx, "track-a", 0);
+    assert!(matches!(
+        downstream_rx.recv_timeout(Duration::from_millis(100)),
+        Ok(TrackMsg::TrackDataMsg(_))
+    ));
+    assert!(
+        downstream_rx
+            .recv_timeout(Duration::from_millis(100))
+            .is_err(),
+        "Sync should wait for every visible track"
+    );
+
+    send_track_index(&input_tx, "track-b", 1);
+    assert!(matches!(
+        downstream_rx.recv_timeout(Duration::from_millis(100)),
+        Ok(TrackMsg::TrackDataMsg(_))
+    ));
+    assert!(matches!(
+        downstream_rx.recv_timeout(Duration::from_millis(100)),
+        Ok(TrackMsg::InitialSync(InitialSync::Complete))
+    ));
+
+    // The milestone is only sent once
+    send_track_index(&input_tx, "track-c", 2);
+    assert!(matches!(
+        downstream_rx.recv_timeout(Duration::from_millis(100)),
+        Ok(TrackMsg::TrackDataMsg(_))
+    ));
+    assert!(
+        downstream_rx
+            .recv_timeout(Duration::from_millis(100))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_track_manager_reports_initial_sync_timeout() {
+    let (_input_tx, input_rx) = bounded(128);
+    let (upstream_tx, _upstream_rx) = bounded(128);
+    let (downstream_tx, downstream_rx) = bounded(128);
+    TrackManager::start_with_initial_sync(
+        input_rx,
+        upstream_tx,
+        downstream_tx,
+        8,
+        Duration::from_millis(50),
+    );
+
+    // Reaper never tells us anything
+    assert!(matches!(
+        downstream_rx.recv_timeout(Duration::from_millis(500)),
+        Ok(TrackMsg::InitialSync(InitialSync::TimedOut))
+    ));
+}
+
+#[test]
+fn test_reset_forgets_every_track() {
+    let (input_tx, _upstream_rx, downstream_rx) = setup_track_manager();
+    send_track_index(&input_tx, "track-a", 0);
+    send_track_index(&input_tx, "track-b", 1);
+    send_track_index(&input_tx, "track-c", 2);
+    for _ in 0..3 {
+        downstream_rx
+            .recv_timeout(Duration::from_millis(100))
+            .unwrap();
+    }
+
+    input_tx.send(TrackMsg::Reset).unwrap();
+    let mut removed = Vec::new();
+    for _ in 0..3 {
+        match downstream_rx.recv_timeout(Duration::from_millis(100)) {
+            Ok(TrackMsg::TrackRemoved(guid)) => removed.push(guid),
+            other => panic!("Expected a removed track, got {:?}", other),
+        }
+    }
+    // Last first, like deleting them from the bottom of the project up
+    assert_eq!(removed, vec!["track-c", "track-b", "track-a"]);
+
+    // Nothing's left to replay
+    let barrier = Barrier::new();
+    input_tx.send(TrackMsg::Replay(barrier)).unwrap();
+    assert!(matches!(
+        downstream_rx.recv_timeout(Duration::from_millis(100)),
+        Ok(TrackMsg::Barrier(received)) if received == barrier
+    ));
+}
+
+#[test]
+fn test_track_manager_reports_removed_tracks() {
+    let (input_tx, _upstream_rx, downstream_rx) = setup_track_manager();
+    send_track_index(&input_tx, "track-a", 0);
+    send_track_index(&input_tx, "track-b", 1);
+    send_track_index(&input_tx, "track-c", 2);
+    for _ in 0..3 {
+        downstream_rx
+            .recv_timeout(Duration::from_millis(100))
+            .unwrap();
+    }
+
+    input_tx
+        .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: "track-a".into(),
+            direction: Direction::Downstream,
+            data: DataPayload::ReaperTrackIndex(None),
+        }))
+        .unwrap();
+    assert!(matches!(
+        downstream_rx.recv_timeout(Duration::from_millis(100)),
+        Ok(TrackMsg::TrackRemoved(guid)) if guid == "track-a"
+    ));
+
+    // The removed track is gone from the replay, and the others have moved up
+    let barrier = Barrier::new();
+    input_tx.send(TrackMsg::Replay(barrier)).unwrap();
+    let mut indices = Vec::new();
+    loop {
+        match downstream_rx.recv_timeout(Duration::from_millis(100)) {
+            Ok(TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid,
+                data: DataPayload::ReaperTrackIndex(index),
+                ..
+            })) => indices.push((guid, index)),
+            Ok(TrackMsg::Barrier(received)) if received == barrier => break,
+            Ok(_) => {}
+            Err(e) => panic!("Expected the replay to finish: {:?}", e),
+        }
+    }
+    assert_eq!(
+        indices,
+        vec![
+            (TrackGuid::from("track-b"), Some(0)),
+            (TrackGuid::from("track-c"), Some(1)),
+        ]
+    );
+}
+
+#[test]
+fn test_track_manager_clears_index_taken_by_moved_track() {
+    let (input_tx, _upstream_rx, downstream_rx) = setup_track_manager();
+    send_track_index(&input_tx, "track-a", 0);
+    send_track_index(&input_tx, "track-b", 1);
+    // track-b is dragged to the top; track-a's own update hasn't arrived yet
+    send_track_index(&input_tx, "track-b", 0);
+    for _ in 0..3 {
+        downstream_rx
+            .recv_timeout(Duration::from_millis(100))
+            .unwrap();
+    }
+
+    let barrier = Barrier::new();
+    input_tx.send(TrackMsg::Replay(barrier)).unwrap();
+    let mut indices = Vec::new();
+    loop {
+        match downstream_rx.recv_timeout(Duration::from_millis(100)) {
+            Ok(TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid,
+                data: DataPayload::ReaperTrackIndex(index),
+                ..
+            })) => indices.push((guid, index)),
+            Ok(TrackMsg::Barrier(received)) if received == barrier => break,
+            Ok(_) => {}
+            Err(e) => panic!("Expected the replay to finish: {:?}", e),
+        }
+    }
+    assert_eq!(
+        indices,
+        vec![
+            (TrackGuid::from("track-b"), Some(0)),
+            (TrackGuid::from("track-a"), None)
+        ]
+    );
+}
+
+#[test]
+fn test_handle_reads_tracks_as_soon_as_changes_are_passed_on() {
+    let (input_tx, input_rx) = bounded(128);
+    let (upstream_tx, upstream_rx) = bounded(128);
+    let (downstream_tx, downstream_rx) = bounded(128);
+    let handle = TrackManager::start(input_rx, upstream_tx, downstream_tx);
+    assert!(handle.list_tracks().is_empty());
+
+    send_track_index(&input_tx, "track-a", 1);
+    send_track_index(&input_tx, "track-b", 0);
+    input_tx
+        .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: "track-a".into(),
+            direction: Direction::Upstream,
+            data: DataPayload::Volume(0.5),
+        }))
+        .unwrap();
+    for _ in 0..2 {
+        downstream_rx
+            .recv_timeout(Duration::from_millis(100))
+            .unwrap();
+    }
+    upstream_rx
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+
+    let track = handle.get_track("track-a").unwrap();
+    assert_eq!(track.reaper_track_index, Some(1));
+    assert_eq!(track.volume, 0.5);
+    assert!(handle.get_track("track-c").is_none());
+    let guids: Vec<TrackGuid> = handle
+        .list_tracks()
+        .into_iter()
+        .map(|track| track.guid)
+        .collect();
+    assert_eq!(
+        guids,
+        vec![TrackGuid::from("track-b"), TrackGuid::from("track-a")]
+    );
+
+    // Removed tracks are gone, and the ones after them move up
+    input_tx
+        .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: "track-b".into(),
+            direction: Direction::Downstream,
+            data: DataPayload::ReaperTrackIndex(None),
+        }))
+        .unwrap();
+    downstream_rx
+        .recv_timeout(Duration::from_millis(100))
+        .unwrap();
+    assert!(handle.get_track("track-b").is_none());
+    assert_eq!(
+        handle.get_track("track-a").unwrap().reaper_track_index,
+        Some(0)
+    );
+}
+
+#[test]
+fn test_track_manager_acknowledges_once_earlier_messages_are_handled() {
+    let (input_tx, _upstream_rx, downstream_rx) = setup_track_manager();
+    send_track_index(&input_tx, "track-a", 0);
+    let (acknowledge, acknowledged) = bounded(1);
+    input_tx.send(TrackMsg::Acknowledge(acknowledge)).unwrap();
+
+    assert!(
+        acknowledged
+            .recv_timeout(Duration::from_millis(100))
+            .is_ok()
+    );
+    // The track was passed on before the acknowledgment, which isn't passed on itself
+    let downstream: Vec<TrackMsg> = downstream_rx.try_iter().collect();
+    assert!(matches!(
+        downstream.as_slice(),
+        [TrackMsg::TrackDataMsg(TrackDataMsg {
+            data: DataPayload::ReaperTrackIndex(Some(0)),
+            ..
+        })]
+    ));
+}
+
+#[test]
+fn test_track_manager_asks_reaper_for_sends_and_forgets_the_missing_ones() {
+    let (input_tx, input_rx) = bounded(128);
+    let (upstream_tx, upstream_rx) = bounded(128);
+    let (downstream_tx, downstream_rx) = bounded(128);
+    let handle = TrackManager::start(input_rx, upstream_tx, downstream_tx);
+
+    input_tx
+        .send(TrackMsg::DiscoverSends(SendDiscovery {
+            guid: "track-a".into(),
+            num_sends: 8,
+        }))
+        .unwrap();
+    match upstream_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(TrackMsg::DiscoverSends(discovery)) => {
+            assert_eq!(discovery.guid, "track-a");
+            assert_eq!(discovery.num_sends, 8);
+        }
+        other => panic!(
+            "Expected the discovery to go on to Reaper but got {:?}",
+            other
+        ),
+    }
+
+    let send = |data| {
+        input_tx
+            .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid: "track-a".into(),
+                direction: Direction::Downstream,
+                data,
+            }))
+            .unwrap();
+        downstream_rx
+            .recv_timeout(Duration::from_millis(100))
+            .unwrap()
+    };
+    for (send_index, target) in [(0, "reverb-bus"), (1, "delay-bus")] {
+        send(DataPayload::SendIndex(SendIndex {
+            send_index,
+            guid: target.into(),
+        }));
+    }
+    assert!(matches!(
+        send(DataPayload::SendCount(1)),
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            data: DataPayload::SendCount(1),
+            ..
+        })
+    ));
+    let sends = handle.get_track("track-a").unwrap().sends;
+    assert_eq!(sends.len(), 1);
+    assert_eq!(sends[0].target_guid, "reverb-bus");
+}
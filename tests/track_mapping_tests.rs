@@ -0,0 +1,134 @@
+// Tests for the policies deciding which tracks VolumePanMode shows
+
+use std::collections::BTreeMap;
+
+use arpad_rust::guid::TrackGuid;
+use arpad_rust::modes::track_mapping::{MappingPolicy, TrackMapper};
+
+fn layout(mapper: &TrackMapper) -> Vec<(usize, &'static str)> {
+    let layout = mapper.layout();
+    let names = ["track-a", "track-b", "track-c", "track-d"];
+    layout
+        .iter()
+        .map(|(slot, guid)| {
+            (
+                *slot,
+                *names.iter().find(|name| guid.as_str() == **name).unwrap(),
+            )
+        })
+        .collect()
+}
+
+fn mapper_with_tracks(policy: MappingPolicy) -> TrackMapper {
+    let mut mapper = TrackMapper::new(policy);
+    mapper.set_index(&TrackGuid::from("track-a"), Some(0));
+    mapper.set_index(&TrackGuid::from("track-b"), Some(1));
+    mapper.set_index(&TrackGuid::from("track-c"), Some(2));
+    mapper.set_index(&TrackGuid::from("track-d"), Some(3));
+    mapper
+}
+
+#[test]
+fn test_by_index_places_tracks_at_their_reaper_index() {
+    let mut mapper = mapper_with_tracks(MappingPolicy::ByIndex);
+    assert_eq!(
+        layout(&mapper),
+        vec![
+            (0, "track-a"),
+            (1, "track-b"),
+            (2, "track-c"),
+            (3, "track-d")
+        ]
+    );
+
+    // A track moving onto an index takes it from the track that held it
+    mapper.set_index(&TrackGuid::from("track-d"), Some(0));
+    assert_eq!(
+        layout(&mapper),
+        vec![(0, "track-d"), (1, "track-b"), (2, "track-c")]
+    );
+    mapper.set_index(&TrackGuid::from("track-a"), Some(3));
+    assert_eq!(
+        layout(&mapper),
+        vec![
+            (0, "track-d"),
+            (1, "track-b"),
+            (2, "track-c"),
+            (3, "track-a")
+        ]
+    );
+}
+
+#[test]
+fn test_first_tracks_drops_later_tracks() {
+    let mapper = mapper_with_tracks(MappingPolicy::FirstTracks(2));
+    assert_eq!(layout(&mapper), vec![(0, "track-a"), (1, "track-b")]);
+}
+
+#[test]
+fn test_selected_only_packs_selected_tracks() {
+    let mut mapper = mapper_with_tracks(MappingPolicy::SelectedOnly);
+    assert_eq!(layout(&mapper), vec![]);
+
+    mapper.set_selected(&TrackGuid::from("track-d"), true);
+    mapper.set_selected(&TrackGuid::from("track-b"), true);
+    assert_eq!(layout(&mapper), vec![(0, "track-b"), (1, "track-d")]);
+
+    mapper.set_selected(&TrackGuid::from("track-b"), false);
+    assert_eq!(layout(&mapper), vec![(0, "track-d")]);
+}
+
+#[test]
+fn test_restore_shows_only_tracks_in_the_project() {
+    let mut mapper = TrackMapper::new(MappingPolicy::Restore(BTreeMap::from([
+        (0, "track-c".into()),
+        (5, "track-a".into()),
+    ])));
+    // Nothing is shown until the project reports the tracks
+    assert_eq!(layout(&mapper), vec![]);
+
+    mapper.set_index(&TrackGuid::from("track-a"), Some(0));
+    assert_eq!(layout(&mapper), vec![(5, "track-a")]);
+    mapper.set_index(&TrackGuid::from("track-c"), Some(7));
+    assert_eq!(layout(&mapper), vec![(0, "track-c"), (5, "track-a")]);
+}
+
+#[test]
+fn test_manual_shows_only_pinned_tracks() {
+    let mut mapper = mapper_with_tracks(MappingPolicy::Manual);
+    assert_eq!(layout(&mapper), vec![]);
+
+    mapper.pin(4, "track-b".into());
+    assert_eq!(layout(&mapper), vec![(4, "track-b")]);
+
+    // Pinning a track again moves it
+    mapper.pin(1, "track-b".into());
+    assert_eq!(layout(&mapper), vec![(1, "track-b")]);
+}
+
+#[test]
+fn test_removed_track_leaves_no_gap() {
+    let mut mapper = mapper_with_tracks(MappingPolicy::ByIndex);
+    mapper.remove(&TrackGuid::from("track-b"));
+    assert_eq!(
+        layout(&mapper),
+        vec![(0, "track-a"), (1, "track-c"), (2, "track-d")]
+    );
+    assert_eq!(mapper.index(&TrackGuid::from("track-b")), None);
+
+    // Forgetting a track we never heard of changes nothing
+    mapper.remove(&TrackGuid::from("track-z"));
+    assert_eq!(mapper.index(&TrackGuid::from("track-d")), Some(2));
+}
+
+#[test]
+fn test_removed_track_keeps_its_pin() {
+    let mut mapper = mapper_with_tracks(MappingPolicy::Manual);
+    mapper.pin(3, "track-b".into());
+    mapper.remove(&TrackGuid::from("track-b"));
+    assert_eq!(layout(&mapper), vec![]);
+
+    // It's back where it was once Reaper has it again, e.g. after an undo
+    mapper.set_index(&TrackGuid::from("track-b"), Some(1));
+    assert_eq!(layout(&mapper), vec![(3, "track-b")]);
+}
@@ -0,0 +1,260 @@
+// Integration tests for TrackSendsMode
+//
+// These tests drive TrackSendsMode directly, covering send mute and pan: showing what Reaper
+// reports on the mute buttons and encoder rings, and sending changes from the surface back up.
+
+use crossbeam_channel::{Receiver, unbounded};
+use std::time::Duration;
+
+use arpad_rust::midi::xtouch::{
+    ChannelIndex, EncoderRingLEDMsg, EncoderTurnCCW, EncoderTurnCW, LEDState, MutePress,
+    XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
+use arpad_rust::modes::mode_manager::{Mode, ModeHandler, ModeState, State};
+use arpad_rust::modes::reaper_track_sends::TrackSendsMode;
+use arpad_rust::track::track::{
+    DataPayload, Direction, SendIndex, SendMute, SendPan, TrackDataMsg, TrackMsg,
+};
+
+const TRACK_GUID: &str = "source-track";
+
+const ACTIVE: ModeState = ModeState {
+    mode: Mode::ReaperSends,
+    state: State::Active,
+};
+
+/// Enters TrackSendsMode for a track with a send in slot 0, returning the mode along with the
+/// channels to Reaper and the hardware
+fn setup_sends_mode() -> (
+    TrackSendsMode,
+    Receiver<TrackMsg>,
+    Receiver<XTouchDownstreamMsg>,
+) {
+    let (_from_reaper_tx, from_reaper_rx) = unbounded();
+    let (to_reaper_tx, to_reaper_rx) = unbounded();
+    let (_from_xtouch_tx, from_xtouch_rx) = unbounded();
+    let (to_xtouch_tx, to_xtouch_rx) = unbounded();
+    let mut mode = TrackSendsMode::new(
+        2,
+        from_reaper_rx,
+        to_reaper_tx,
+        from_xtouch_rx,
+        to_xtouch_tx,
+    );
+
+    let (upstream_tx, _upstream_rx) = unbounded();
+    mode.initiate_mode_transition(upstream_tx, &TRACK_GUID.into());
+    send_downstream(
+        &mut mode,
+        DataPayload::SendIndex(SendIndex {
+            send_index: 0,
+            guid: "reverb-bus".into(),
+        }),
+    );
+    while to_xtouch_rx.try_recv().is_ok() {}
+
+    (mode, to_reaper_rx, to_xtouch_rx)
+}
+
+fn send_downstream(mode: &mut TrackSendsMode, data: DataPayload) {
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: TRACK_GUID.into(),
+            direction: Direction::Downstream,
+            data,
+        }),
+        ACTIVE,
+    );
+}
+
+fn expect_mute_led(to_xtouch_rx: &Receiver<XTouchDownstreamMsg>, state: LEDState) {
+    match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::MuteLED(msg)) => {
+            assert_eq!(msg.idx, 0);
+            assert_eq!(msg.state, state);
+        }
+        other => panic!("Expected MuteLED message but got {:?}", other),
+    }
+}
+
+fn expect_pan_ring(to_xtouch_rx: &Receiver<XTouchDownstreamMsg>, pos: f32) {
+    match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::EncoderRingLED(EncoderRingLEDMsg::RangePoint(msg))) => {
+            assert_eq!(msg.idx, 0);
+            assert!((msg.pos - pos).abs() < 0.0001, "ring was at {}", msg.pos);
+        }
+        other => panic!(
+            "Expected EncoderRingLED RangePoint message but got {:?}",
+            other
+        ),
+    }
+}
+
+#[test]
+fn test_sends_mode_shows_send_mute_and_pan() {
+    let (mut mode, _to_reaper_rx, to_xtouch_rx) = setup_sends_mode();
+
+    send_downstream(
+        &mut mode,
+        DataPayload::SendMute(SendMute {
+            send_index: 0,
+            muted: true,
+        }),
+    );
+    expect_mute_led(&to_xtouch_rx, LEDState::On);
+
+    // A hard left pan sits at the start of the ring
+    send_downstream(
+        &mut mode,
+        DataPayload::SendPan(SendPan {
+            send_index: 0,
+            pan: -1.0,
+        }),
+    );
+    expect_pan_ring(&to_xtouch_rx, 0.0);
+}
+
+#[test]
+fn test_sends_mode_mute_press_toggles_send_mute() {
+    let (mut mode, to_reaper_rx, to_xtouch_rx) = setup_sends_mode();
+
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::MutePress(MutePress {
+            idx: ChannelIndex::new(0),
+        }),
+        ACTIVE,
+    );
+    match to_reaper_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid,
+            direction: Direction::Upstream,
+            data: DataPayload::SendMute(send_mute),
+        })) => {
+            assert_eq!(guid, TRACK_GUID);
+            assert_eq!(send_mute.send_index, 0);
+            assert!(send_mute.muted);
+        }
+        other => panic!("Expected an upstream SendMute but got {:?}", other),
+    }
+    expect_mute_led(&to_xtouch_rx, LEDState::On);
+
+    // A channel without a send has nothing to mute
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::MutePress(MutePress {
+            idx: ChannelIndex::new(1),
+        }),
+        ACTIVE,
+    );
+    assert!(to_reaper_rx.try_recv().is_err());
+}
+
+#[test]
+fn test_sends_mode_encoders_pan_sends() {
+    let (mut mode, to_reaper_rx, to_xtouch_rx) = setup_sends_mode();
+
+    let mut turn = |msg| {
+        mode.handle_upstream_messages(msg, ACTIVE);
+        match to_reaper_rx.recv_timeout(Duration::from_millis(100)) {
+            Ok(TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid,
+                data: DataPayload::SendPan(send_pan),
+                ..
+            })) => {
+                assert_eq!(guid, TRACK_GUID);
+                send_pan.pan
+            }
+            other => panic!("Expected an upstream SendPan but got {:?}", other),
+        }
+    };
+
+    let pan = turn(XTouchUpstreamMsg::EncoderTurnInc(EncoderTurnCW {
+        idx: ChannelIndex::new(0),
+    }));
+    assert!((pan - 0.1).abs() < 0.0001, "pan was {}", pan);
+    expect_pan_ring(&to_xtouch_rx, 0.55);
+
+    let pan = turn(XTouchUpstreamMsg::EncoderTurnDec(EncoderTurnCCW {
+        idx: ChannelIndex::new(0),
+    }));
+    assert!(pan.abs() < 0.0001, "pan was {}", pan);
+    expect_pan_ring(&to_xtouch_rx, 0.5);
+}
+
+#[test]
+fn test_sends_mode_discovers_the_selected_tracks_sends() {
+    let (_from_reaper_tx, from_reaper_rx) = unbounded();
+    let (to_reaper_tx, _to_reaper_rx) = unbounded();
+    let (_from_xtouch_tx, from_xtouch_rx) = unbounded();
+    let (to_xtouch_tx, to_xtouch_rx) = unbounded();
+    let mut mode = TrackSendsMode::new(
+        2,
+        from_reaper_rx,
+        to_reaper_tx,
+        from_xtouch_rx,
+        to_xtouch_tx,
+    );
+
+    let (upstream_tx, upstream_rx) = unbounded();
+    mode.initiate_mode_transition(upstream_tx, &TRACK_GUID.into());
+    let discovery = upstream_rx
+        .try_iter()
+        .find_map(|msg| match msg {
+            TrackMsg::DiscoverSends(discovery) => Some(discovery),
+            _ => None,
+        })
+        .expect("entering the mode should ask Reaper for the track's sends");
+    assert_eq!(discovery.guid, TRACK_GUID);
+    assert_eq!(discovery.num_sends, 2);
+
+    // Each answer maps a send to its channel
+    for (send_index, target) in [(0, "reverb-bus"), (1, "delay-bus")] {
+        send_downstream(
+            &mut mode,
+            DataPayload::SendIndex(SendIndex {
+                send_index,
+                guid: target.into(),
+            }),
+        );
+    }
+    // Sends of other tracks aren't
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: "other-track".into(),
+            direction: Direction::Downstream,
+            data: DataPayload::SendIndex(SendIndex {
+                send_index: 0,
+                guid: "other-bus".into(),
+            }),
+        }),
+        ACTIVE,
+    );
+    let labels: Vec<(usize, String)> = to_xtouch_rx
+        .try_iter()
+        .filter_map(|msg| match msg {
+            XTouchDownstreamMsg::ScribbleStrip(msg) => Some((msg.idx.get(), msg.bottom_line)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        labels[labels.len() - 2..],
+        [(0, "Send 1".to_string()), (1, "Send 2".to_string())]
+    );
+
+    // A track with fewer sends than channels has the rest blanked
+    send_downstream(&mut mode, DataPayload::SendCount(1));
+    match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::ScribbleStrip(msg)) => {
+            assert_eq!(msg.idx, 1);
+            assert_eq!(msg.top_line, "");
+        }
+        other => panic!("Expected a blank ScribbleStrip but got {:?}", other),
+    }
+    assert!(to_xtouch_rx.try_recv().is_err());
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::MutePress(MutePress {
+            idx: ChannelIndex::new(1),
+        }),
+        ACTIVE,
+    );
+    assert!(to_xtouch_rx.try_recv().is_err());
+}
@@ -0,0 +1,370 @@
+// Tests for the transport section
+//
+// These drive TransportManager directly, standing in for both Reaper and the surface, and check
+// that the transport buttons reach Reaper, that Reaper's transport state lights the surface up, and
+// that everything else from the surface passes through to the modes.
+
+use crossbeam_channel::{Receiver, Sender, unbounded};
+use std::time::Duration;
+
+use arpad_rust::midi::xtouch::{
+    ActionButton, ActionPress, ActionRelease, LEDState, XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
+use arpad_rust::modes::mode_manager::Barrier;
+use arpad_rust::transport::{
+    ActionBinding, JOG_STEP, SCRUB_LEFT_ACTION, SCRUB_RIGHT_ACTION, TransportManager, TransportMsg,
+    ZOOM_IN_ACTION, ZOOM_OUT_ACTION,
+};
+
+struct TransportTest {
+    from_reaper: Sender<TransportMsg>,
+    to_reaper: Receiver<TransportMsg>,
+    from_xtouch: Sender<XTouchUpstreamMsg>,
+    to_xtouch: Receiver<XTouchDownstreamMsg>,
+    to_modes: Receiver<XTouchUpstreamMsg>,
+}
+
+fn setup_transport_test() -> TransportTest {
+    setup_transport_test_with_actions(vec![])
+}
+
+fn setup_transport_test_with_actions(actions: Vec<ActionBinding>) -> TransportTest {
+    let (from_reaper_tx, from_reaper_rx) = unbounded();
+    let (to_reaper_tx, to_reaper_rx) = unbounded();
+    let (from_xtouch_tx, from_xtouch_rx) = unbounded();
+    let (to_xtouch_tx, to_xtouch_rx) = unbounded();
+    let (to_modes_tx, to_modes_rx) = unbounded();
+    TransportManager::start_with_actions(
+        from_reaper_rx,
+        to_reaper_tx,
+        from_xtouch_rx,
+        to_xtouch_tx,
+        to_modes_tx,
+        actions,
+    );
+    TransportTest {
+        from_reaper: from_reaper_tx,
+        to_reaper: to_reaper_rx,
+        from_xtouch: from_xtouch_tx,
+        to_xtouch: to_xtouch_rx,
+        to_modes: to_modes_rx,
+    }
+}
+
+fn recv_reaper(test: &TransportTest) -> TransportMsg {
+    test.to_reaper
+        .recv_timeout(Duration::from_millis(100))
+        .expect("Expected a message to Reaper")
+}
+
+/// Sends a position from Reaper and waits until it's been taken in. Positions don't show on the
+/// surface, so this follows it with a play state that does.
+fn report_position(test: &TransportTest, position: f32) {
+    test.from_reaper
+        .send(TransportMsg::Position(position))
+        .unwrap();
+    test.from_reaper.send(TransportMsg::Playing(false)).unwrap();
+    recv_xtouch(test);
+}
+
+fn recv_xtouch(test: &TransportTest) -> XTouchDownstreamMsg {
+    test.to_xtouch
+        .recv_timeout(Duration::from_millis(100))
+        .expect("Expected a message to the surface")
+}
+
+#[test]
+fn test_transport_buttons_drive_reaper() {
+    let test = setup_transport_test();
+
+    test.from_xtouch.send(XTouchUpstreamMsg::PlayPress).unwrap();
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::PlayRelease)
+        .unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Playing(true));
+
+    test.from_xtouch.send(XTouchUpstreamMsg::StopPress).unwrap();
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::StopRelease)
+        .unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Stop);
+
+    // Releasing play and stop does nothing, and none of it reaches the modes
+    assert!(
+        test.to_reaper
+            .recv_timeout(Duration::from_millis(50))
+            .is_err()
+    );
+    assert!(test.to_modes.try_recv().is_err());
+}
+
+#[test]
+fn test_transport_leds_follow_reaper() {
+    let test = setup_transport_test();
+
+    test.from_reaper.send(TransportMsg::Playing(true)).unwrap();
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::Play(LEDState::On)
+    ));
+    test.from_reaper
+        .send(TransportMsg::Recording(true))
+        .unwrap();
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::Record(LEDState::On)
+    ));
+    test.from_reaper.send(TransportMsg::Repeat(true)).unwrap();
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::Cycle(LEDState::On)
+    ));
+    test.from_reaper.send(TransportMsg::Playing(false)).unwrap();
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::Play(LEDState::Off)
+    ));
+}
+
+#[test]
+fn test_record_and_cycle_toggle_what_reaper_reported() {
+    let test = setup_transport_test();
+
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::RecordPress)
+        .unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Recording(true));
+
+    // Once Reaper says it's recording, pressing again asks it to stop
+    test.from_reaper
+        .send(TransportMsg::Recording(true))
+        .unwrap();
+    recv_xtouch(&test);
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::RecordPress)
+        .unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Recording(false));
+
+    test.from_reaper.send(TransportMsg::Repeat(true)).unwrap();
+    recv_xtouch(&test);
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::CyclePress)
+        .unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Repeat(false));
+}
+
+#[test]
+fn test_rewind_and_fast_forward_run_while_held() {
+    let test = setup_transport_test();
+
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::RewindPress)
+        .unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Rewind(true));
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::Rewind(LEDState::On)
+    ));
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::RewindRelease)
+        .unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Rewind(false));
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::Rewind(LEDState::Off)
+    ));
+
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::FastForwardPress)
+        .unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::FastForward(true));
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::FastForward(LEDState::On)
+    ));
+}
+
+#[test]
+fn test_jog_wheel_moves_play_cursor() {
+    let test = setup_transport_test();
+
+    report_position(&test, 10.0);
+    test.from_xtouch.send(XTouchUpstreamMsg::JogCW).unwrap();
+    test.from_xtouch.send(XTouchUpstreamMsg::JogCW).unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Position(10.0 + JOG_STEP));
+    // Clicks add up without waiting for Reaper to report where the cursor went
+    assert_eq!(
+        recv_reaper(&test),
+        TransportMsg::Position(10.0 + JOG_STEP + JOG_STEP)
+    );
+
+    // The cursor can't go back past the start of the project
+    report_position(&test, 0.0);
+    test.from_xtouch.send(XTouchUpstreamMsg::JogCCW).unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Position(0.0));
+}
+
+#[test]
+fn test_everything_else_passes_through_to_the_modes() {
+    let test = setup_transport_test();
+
+    let barrier = Barrier::new();
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::BankLeftPress)
+        .unwrap();
+    test.from_xtouch.send(XTouchUpstreamMsg::PlayPress).unwrap();
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::Barrier(barrier))
+        .unwrap();
+
+    assert!(matches!(
+        test.to_modes.recv_timeout(Duration::from_millis(100)),
+        Ok(XTouchUpstreamMsg::BankLeftPress)
+    ));
+    assert!(matches!(
+        test.to_modes.recv_timeout(Duration::from_millis(100)),
+        Ok(XTouchUpstreamMsg::Barrier(passed)) if passed == barrier
+    ));
+    assert_eq!(recv_reaper(&test), TransportMsg::Playing(true));
+}
+
+#[test]
+fn test_scrub_and_zoom_switch_what_the_jog_wheel_does() {
+    let test = setup_transport_test();
+
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::ScrubPress)
+        .unwrap();
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::Scrub(LEDState::On)
+    ));
+    test.from_xtouch.send(XTouchUpstreamMsg::JogCW).unwrap();
+    test.from_xtouch.send(XTouchUpstreamMsg::JogCCW).unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Action(SCRUB_RIGHT_ACTION));
+    assert_eq!(recv_reaper(&test), TransportMsg::Action(SCRUB_LEFT_ACTION));
+
+    // Switching straight to zoom puts out the scrub LED
+    test.from_xtouch.send(XTouchUpstreamMsg::ZoomPress).unwrap();
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::Scrub(LEDState::Off)
+    ));
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::Zoom(LEDState::On)
+    ));
+    test.from_xtouch.send(XTouchUpstreamMsg::JogCW).unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Action(ZOOM_IN_ACTION));
+    test.from_xtouch.send(XTouchUpstreamMsg::JogCCW).unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Action(ZOOM_OUT_ACTION));
+}
+
+#[test]
+fn test_pressing_the_lit_jog_button_goes_back_to_moving_the_play_cursor() {
+    let test = setup_transport_test();
+
+    report_position(&test, 10.0);
+    test.from_xtouch.send(XTouchUpstreamMsg::ZoomPress).unwrap();
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::ZoomRelease)
+        .unwrap();
+    test.from_xtouch.send(XTouchUpstreamMsg::ZoomPress).unwrap();
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::Zoom(LEDState::On)
+    ));
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::Zoom(LEDState::Off)
+    ));
+
+    test.from_xtouch.send(XTouchUpstreamMsg::JogCW).unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Position(10.0 + JOG_STEP));
+    // The jog buttons never reach the modes
+    assert!(test.to_modes.try_recv().is_err());
+}
+
+#[test]
+fn test_action_buttons_run_their_actions() {
+    let test = setup_transport_test_with_actions(vec![ActionBinding {
+        button: ActionButton::Save,
+        command_id: 40026,
+        toggle: false,
+    }]);
+
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::from(ActionPress {
+            button: ActionButton::Save,
+        }))
+        .unwrap();
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::from(ActionRelease {
+            button: ActionButton::Save,
+        }))
+        .unwrap();
+    assert_eq!(recv_reaper(&test), TransportMsg::Action(40026));
+    assert!(
+        test.to_reaper
+            .recv_timeout(Duration::from_millis(50))
+            .is_err()
+    );
+    assert!(test.to_modes.try_recv().is_err());
+}
+
+#[test]
+fn test_toggle_actions_light_their_buttons() {
+    let test = setup_transport_test_with_actions(vec![
+        ActionBinding {
+            button: ActionButton::Click,
+            command_id: 40364,
+            toggle: true,
+        },
+        ActionBinding {
+            button: ActionButton::Save,
+            command_id: 40026,
+            toggle: false,
+        },
+    ]);
+
+    test.from_reaper
+        .send(TransportMsg::ActionToggled(40364, true))
+        .unwrap();
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::Action(ActionButton::Click, LEDState::On)
+    ));
+    test.from_reaper
+        .send(TransportMsg::ActionToggled(40026, true))
+        .unwrap();
+    test.from_reaper
+        .send(TransportMsg::ActionToggled(40364, false))
+        .unwrap();
+    // Save isn't bound with toggle, so it stays dark
+    assert!(matches!(
+        recv_xtouch(&test),
+        XTouchDownstreamMsg::Action(ActionButton::Click, LEDState::Off)
+    ));
+}
+
+#[test]
+fn test_unbound_action_buttons_pass_through_to_the_modes() {
+    let test = setup_transport_test_with_actions(vec![ActionBinding {
+        button: ActionButton::Click,
+        command_id: 40364,
+        toggle: true,
+    }]);
+
+    test.from_xtouch
+        .send(XTouchUpstreamMsg::from(ActionPress {
+            button: ActionButton::Marker,
+        }))
+        .unwrap();
+    assert!(matches!(
+        test.to_modes.recv_timeout(Duration::from_millis(100)),
+        Ok(XTouchUpstreamMsg::ActionPress(ActionPress {
+            button: ActionButton::Marker
+        }))
+    ));
+    assert!(test.to_reaper.try_recv().is_err());
+}
@@ -0,0 +1,179 @@
+// Tests for undo and redo from the surface
+//
+// These check ActionHistory turning surface messages into undo steps, and the Undo and Redo
+// buttons asking Reaper to step through its history only as far as the surface changed things.
+
+use std::time::Duration;
+
+use crossbeam_channel::{Receiver, unbounded};
+
+use arpad_rust::midi::xtouch::{
+    ChannelIndex, FaderAbsMsg, FaderReleaseMsg, FaderTouchMsg, MasterFaderAbsMsg, MutePress,
+    XTouchUpstreamMsg,
+};
+use arpad_rust::modes::history::{
+    ActionHistory, HISTORY_LEN, REDO_ACTION, SurfaceAction, UNDO_ACTION, UndoMsg,
+};
+use arpad_rust::modes::mode_manager::{ModeManager, ModeOptions};
+
+const TIMEOUT: Duration = Duration::from_millis(200);
+
+fn touch(idx: u8) -> XTouchUpstreamMsg {
+    XTouchUpstreamMsg::from(FaderTouchMsg {
+        idx: ChannelIndex::new(idx),
+    })
+}
+
+fn fader(idx: u8, value: f64) -> XTouchUpstreamMsg {
+    XTouchUpstreamMsg::from(FaderAbsMsg {
+        idx: ChannelIndex::new(idx),
+        value,
+    })
+}
+
+fn release(idx: u8) -> XTouchUpstreamMsg {
+    XTouchUpstreamMsg::from(FaderReleaseMsg {
+        idx: ChannelIndex::new(idx),
+    })
+}
+
+fn mute(idx: u8) -> XTouchUpstreamMsg {
+    XTouchUpstreamMsg::from(MutePress {
+        idx: ChannelIndex::new(idx),
+    })
+}
+
+fn record_all(history: &mut ActionHistory, msgs: Vec<XTouchUpstreamMsg>) {
+    for msg in msgs {
+        history.record(&msg);
+    }
+}
+
+#[test]
+fn test_fader_gesture_is_one_step_once_released() {
+    let mut history = ActionHistory::new();
+    record_all(
+        &mut history,
+        vec![touch(2), fader(2, 0.1), fader(2, 0.2), fader(2, 0.3)],
+    );
+    assert_eq!(history.undo_len(), 0);
+
+    history.record(&release(2));
+    assert_eq!(history.undo_len(), 1);
+    assert_eq!(
+        history.undo(),
+        Some(SurfaceAction::Fader(ChannelIndex::new(2)))
+    );
+    assert_eq!(history.undo(), None);
+}
+
+#[test]
+fn test_undo_finishes_gestures_still_going_on() {
+    let mut history = ActionHistory::new();
+    record_all(&mut history, vec![mute(0), touch(1), fader(1, 0.5)]);
+    assert_eq!(
+        history.undo(),
+        Some(SurfaceAction::Fader(ChannelIndex::new(1)))
+    );
+    assert_eq!(
+        history.undo(),
+        Some(SurfaceAction::Mute(ChannelIndex::new(0)))
+    );
+
+    // Letting go afterwards doesn't add the gesture again
+    history.record(&release(1));
+    assert_eq!(history.undo_len(), 0);
+}
+
+#[test]
+fn test_redo_steps_back_through_what_was_undone_until_something_changes() {
+    let mut history = ActionHistory::new();
+    record_all(
+        &mut history,
+        vec![mute(0), touch(1), fader(1, 0.5), release(1)],
+    );
+    history.undo();
+    history.undo();
+    assert_eq!(
+        history.redo(),
+        Some(SurfaceAction::Mute(ChannelIndex::new(0)))
+    );
+    assert_eq!(history.undo_len(), 1);
+    assert_eq!(history.redo_len(), 1);
+
+    history.record(&mute(3));
+    assert_eq!(history.redo(), None);
+    assert_eq!(history.undo_len(), 2);
+}
+
+#[test]
+fn test_master_fader_moves_are_one_step_until_something_else_changes() {
+    let mut history = ActionHistory::new();
+    let master = |value| XTouchUpstreamMsg::from(MasterFaderAbsMsg { value });
+    record_all(
+        &mut history,
+        vec![master(0.1), master(0.2), mute(0), master(0.3)],
+    );
+    assert_eq!(history.undo_len(), 3);
+    assert_eq!(history.undo(), Some(SurfaceAction::MasterFader));
+}
+
+#[test]
+fn test_history_forgets_the_oldest_steps() {
+    let mut history = ActionHistory::new();
+    for _ in 0..HISTORY_LEN + 5 {
+        history.record(&mute(0));
+    }
+    assert_eq!(history.undo_len(), HISTORY_LEN);
+}
+
+#[test]
+fn test_undo_messages_run_reapers_actions() {
+    assert_eq!(UndoMsg::Undo.action(), UNDO_ACTION);
+    assert_eq!(UndoMsg::Redo.action(), REDO_ACTION);
+}
+
+fn next_undo(rx: &Receiver<UndoMsg>) -> Option<UndoMsg> {
+    rx.recv_timeout(TIMEOUT).ok()
+}
+
+#[test]
+fn test_undo_and_redo_buttons_step_through_surface_changes() {
+    let (_reaper_tx, reaper_rx) = unbounded();
+    let (xtouch_tx, xtouch_rx) = unbounded();
+    let (to_reaper_tx, _to_reaper_rx) = unbounded();
+    let (to_xtouch_tx, _to_xtouch_rx) = unbounded();
+    let (to_undo, from_modes) = unbounded();
+    ModeManager::start_with_options(
+        reaper_rx,
+        to_reaper_tx,
+        xtouch_rx,
+        to_xtouch_tx,
+        ModeOptions {
+            to_undo: Some(to_undo),
+            ..Default::default()
+        },
+    );
+    let tap = |press, release| {
+        xtouch_tx.send(press).unwrap();
+        xtouch_tx.send(release).unwrap();
+    };
+
+    // Nothing has been changed from the surface yet
+    tap(XTouchUpstreamMsg::UndoPress, XTouchUpstreamMsg::UndoRelease);
+    assert_eq!(next_undo(&from_modes), None);
+
+    for msg in [touch(0), fader(0, 0.2), fader(0, 0.4), release(0)] {
+        xtouch_tx.send(msg).unwrap();
+    }
+    tap(XTouchUpstreamMsg::UndoPress, XTouchUpstreamMsg::UndoRelease);
+    assert_eq!(next_undo(&from_modes), Some(UndoMsg::Undo));
+    // The whole gesture went in one step
+    tap(XTouchUpstreamMsg::UndoPress, XTouchUpstreamMsg::UndoRelease);
+    assert_eq!(next_undo(&from_modes), None);
+
+    tap(XTouchUpstreamMsg::RedoPress, XTouchUpstreamMsg::RedoRelease);
+    assert_eq!(next_undo(&from_modes), Some(UndoMsg::Redo));
+    tap(XTouchUpstreamMsg::RedoPress, XTouchUpstreamMsg::RedoRelease);
+    assert_eq!(next_undo(&from_modes), None);
+}
@@ -0,0 +1,79 @@
+// Tests for keeping values within the ranges the spec gives them, going out to Reaper and coming
+// back from it
+
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex};
+
+use rosc::{OscMessage, OscType};
+
+use arpad_rust::osc::decode::Unhandled;
+use arpad_rust::osc::generated_osc::{Reaper, TrackPanArgs, TrackVolumeArgs, dispatch_osc};
+use arpad_rust::osc::range::{check_one_of, check_range, clamp};
+use arpad_rust::osc::transport::NullTransport;
+use arpad_rust::traits::{Bind, SetMessage};
+
+#[test]
+fn test_range_helpers() {
+    assert_eq!(clamp(1.5, Some(0.0), Some(1.0)), 1.0);
+    assert_eq!(clamp(-3, Some(0), None), 0);
+    assert_eq!(clamp(f32::NAN, Some(-1.0), Some(1.0)), -1.0);
+    assert_eq!(check_range("pan", 0.5, Some(-1.0), Some(1.0)), Ok(()));
+    assert_eq!(
+        check_range("volume", 2.0, None, Some(1.0)),
+        Err("volume is 2, above its maximum of 1".to_string())
+    );
+    assert!(check_range("volume", f32::NAN, Some(0.0), None).is_err());
+    assert_eq!(
+        check_one_of("curve", &"db".to_string(), &["linear", "db"]),
+        Ok(())
+    );
+    assert_eq!(
+        check_one_of("mode", &3, &[1, 2]),
+        Err("mode is 3, which isn't one of [1, 2]".to_string())
+    );
+}
+
+#[test]
+fn test_set_clamps_values_out_of_range() {
+    let reaper = Reaper::new(Arc::new(NullTransport));
+    let msg = reaper
+        .track_volume("abc".into())
+        .set_message(TrackVolumeArgs::new(1.5))
+        .unwrap();
+    assert_eq!(msg.args, vec![OscType::Float(1.0)]);
+    let msg = reaper
+        .track_pan("abc".into())
+        .set_message(TrackPanArgs::new(-2.0))
+        .unwrap();
+    assert_eq!(msg.args, vec![OscType::Float(-1.0)]);
+}
+
+#[test]
+fn test_dispatcher_drops_values_out_of_range() {
+    let reaper = Reaper::new(Arc::new(NullTransport));
+    let volumes = Arc::new(Mutex::new(Vec::new()));
+    reaper.track_volume("abc".into()).bind({
+        let volumes = volumes.clone();
+        move |args| volumes.lock().unwrap().push(args.volume)
+    });
+    let unhandled = RefCell::new(Vec::new());
+    for volume in [0.25, 7.0] {
+        let msg = OscMessage {
+            addr: "/track/abc/volume".to_string(),
+            args: vec![OscType::Float(volume)],
+        };
+        dispatch_osc(&reaper, msg, |addr, reason| {
+            unhandled
+                .borrow_mut()
+                .push((addr.to_string(), reason.clone()))
+        });
+    }
+    assert_eq!(*volumes.lock().unwrap(), vec![0.25]);
+    assert_eq!(
+        *unhandled.borrow(),
+        vec![(
+            "/track/abc/volume".to_string(),
+            Unhandled::BadArgs("volume is 7, above its maximum of 1".to_string())
+        )]
+    );
+}
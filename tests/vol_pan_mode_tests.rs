@@ -12,13 +12,18 @@ use assert2::{assert, check};
 use crossbeam_channel::{Receiver, Sender, unbounded};
 use float_cmp::approx_eq;
 
+use arpad_rust::guid::TrackGuid;
 use arpad_rust::midi::xtouch::{
-    ArmPress, EncoderTurnCW, FaderAbsMsg, LEDState, MutePress, SoloPress, XTouchDownstreamMsg,
-    XTouchUpstreamMsg,
+    ArmPress, AssignmentDisplayMsg, ChannelIndex, EncoderRingLEDMsg, EncoderTurnCW, FaderAbsMsg,
+    FaderReleaseMsg, FaderTouchMsg, LEDState, MasterFaderAbsMsg, MutePress, ScribbleColor,
+    SoloPress, XTouchDownstreamMsg, XTouchUpstreamMsg,
 };
 use arpad_rust::modes::mode_manager::{Mode, ModeHandler, ModeState, State};
 use arpad_rust::modes::reaper_vol_pan::{FADER_0DB, VolumePanMode};
-use arpad_rust::track::track::{DataPayload, Direction, TrackDataMsg, TrackMsg};
+use arpad_rust::modes::track_mapping::MappingPolicy;
+use arpad_rust::track::track::{
+    DataPayload, Direction, InitialSync, MASTER_GUID, TrackDataMsg, TrackMsg,
+};
 
 // EPSILON constant for floating-point threshold testing
 const EPSILON: f32 = 0.01;
@@ -173,6 +178,27 @@ macro_rules! assert_downstream_arm_led_msg {
     }};
 }
 
+/// Macro to assert a ScribbleStrip message is received with the expected lines
+#[macro_export]
+macro_rules! assert_downstream_scribble_strip_msg {
+    ($rx:expr, $expected_idx:expr, $expected_top:expr, $expected_bottom:expr) => {{
+        let result = $rx.recv_timeout(std::time::Duration::from_millis(100));
+        check!(result.is_ok(), "Should receive ScribbleStrip message");
+
+        match result {
+            Ok(XTouchDownstreamMsg::ScribbleStrip(msg)) => {
+                check!(
+                    msg.idx == $expected_idx,
+                    "Scribble strip index should match"
+                );
+                check!(msg.top_line == $expected_top.to_string());
+                check!(msg.bottom_line == $expected_bottom.to_string());
+            }
+            _ => panic!("Expected ScribbleStrip message but got {:?}", result),
+        }
+    }};
+}
+
 /// Macro to assert a Volume TrackDataMsg is received upstream
 #[macro_export]
 macro_rules! assert_volume_track_msg {
@@ -280,6 +306,11 @@ macro_rules! check_no_message {
     }};
 }
 
+/// Helper to turn a Reaper track index into the hardware channel it maps to
+fn channel_index(hw_channel: i32) -> ChannelIndex {
+    ChannelIndex::try_from(hw_channel).unwrap()
+}
+
 /// Helper function to assign a track to a hardware channel
 fn assign_track_to_channel(
     mode: &mut VolumePanMode,
@@ -289,7 +320,7 @@ fn assign_track_to_channel(
 ) -> ModeState {
     mode.handle_downstream_messages(
         TrackMsg::TrackDataMsg(TrackDataMsg {
-            guid: guid.to_string(),
+            guid: guid.into(),
             direction: Direction::Downstream,
             data: DataPayload::ReaperTrackIndex(Some(hw_channel)),
         }),
@@ -308,6 +339,7 @@ fn assert_downstream_default_track_mapping(
     assert_downstream_solo_led_msg!(to_xtouch_rx, hw_channel, LEDState::Off);
     assert_downstream_arm_led_msg!(to_xtouch_rx, hw_channel, LEDState::Off);
     assert_downstream_encoder_ring_led_msg!(to_xtouch_rx, hw_channel, 0.5);
+    assert_downstream_scribble_strip_msg!(to_xtouch_rx, hw_channel, "", hw_channel + 1);
 }
 
 #[test]
@@ -315,7 +347,7 @@ fn test_vol_pan_mode_assigns_tracks_by_reaper_index() {
     let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, _to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-1".to_string();
+    let track_guid = TrackGuid::from("track-guid-1");
     let reaper_index = 2;
 
     let curr_mode = ModeState {
@@ -349,7 +381,7 @@ fn test_vol_pan_mode_volume_updates_sent_to_faders() {
     let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-2".to_string();
+    let track_guid = TrackGuid::from("track-guid-2");
     let hw_channel = 3;
     let test_volume = 0.65;
 
@@ -404,12 +436,44 @@ fn test_vol_pan_mode_volume_updates_sent_to_faders() {
     }
 }
 
+#[test]
+fn test_vol_pan_mode_ignores_track_index_past_last_channel() {
+    let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+
+    let track_guid = TrackGuid::from("track-guid-12");
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+
+    // The surface only has 8 channels, so track 12 must not be mapped (and must not panic)
+    let result_mode = assign_track_to_channel(&mut mode, &track_guid, 12, curr_mode);
+    assert_eq!(result_mode, curr_mode);
+    assert!(mode.find_hw_channel(&track_guid).is_none());
+    assert!(
+        to_xtouch_rx
+            .recv_timeout(Duration::from_millis(100))
+            .is_err(),
+        "No hardware update should be sent for an unmapped track"
+    );
+
+    // Hardware messages for a channel the surface doesn't have are ignored as well
+    let result_mode = mode.handle_upstream_messages(
+        XTouchUpstreamMsg::MutePress(MutePress {
+            idx: ChannelIndex::new(12),
+        }),
+        curr_mode,
+    );
+    assert_eq!(result_mode, curr_mode);
+}
+
 #[test]
 fn test_vol_pan_mode_fader_sends_volume_upstream() {
     let (mut mode, _from_reaper_tx, to_reaper_rx, _from_xtouch_tx, _to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-4".to_string();
+    let track_guid = TrackGuid::from("track-guid-4");
     let hw_channel = 0;
     let new_volume = 0.85;
 
@@ -430,7 +494,7 @@ fn test_vol_pan_mode_fader_sends_volume_upstream() {
 
     // Simulate fader movement
     let msg = XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
-        idx: hw_channel,
+        idx: channel_index(hw_channel),
         value: new_volume,
     });
 
@@ -471,7 +535,7 @@ fn test_01_volume_message_for_mapped_track_forwards_to_hardware() {
     let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-mapped-vol".to_string();
+    let track_guid = TrackGuid::from("track-guid-mapped-vol");
     let hw_channel = 2;
     let test_volume = 0.75;
 
@@ -503,7 +567,7 @@ fn test_02_volume_message_for_unmapped_track_is_ignored() {
     let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-unmapped-vol".to_string();
+    let track_guid = TrackGuid::from("track-guid-unmapped-vol");
     let test_volume = 0.85;
 
     let curr_mode = ModeState {
@@ -530,7 +594,7 @@ fn test_03_upstream_fader_for_mapped_channel_forwards_to_reaper() {
     let (mut mode, _from_reaper_tx, to_reaper_rx, _from_xtouch_tx, _to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-mapped-fader".to_string();
+    let track_guid = TrackGuid::from("track-guid-mapped-fader");
     let hw_channel = 1;
     let new_volume = 0.65;
 
@@ -545,7 +609,7 @@ fn test_03_upstream_fader_for_mapped_channel_forwards_to_reaper() {
     // Simulate fader movement from hardware
     mode.handle_upstream_messages(
         XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
-            idx: hw_channel,
+            idx: channel_index(hw_channel),
             value: new_volume,
         }),
         curr_mode,
@@ -571,7 +635,7 @@ fn test_04_upstream_fader_for_unmapped_channel_is_ignored() {
     // Simulate fader movement WITHOUT assigning any track to this channel
     mode.handle_upstream_messages(
         XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
-            idx: hw_channel,
+            idx: channel_index(hw_channel),
             value: new_volume,
         }),
         curr_mode,
@@ -590,7 +654,7 @@ fn test_05_volume_state_reflects_latest_value_when_remapped() {
     let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-remap".to_string();
+    let track_guid = TrackGuid::from("track-guid-remap");
     let hw_channel_1 = 2;
     let hw_channel_2 = 4;
     let volume_1 = 0.5;
@@ -608,6 +672,7 @@ fn test_05_volume_state_reflects_latest_value_when_remapped() {
     assert_downstream_solo_led_msg!(&to_xtouch_rx, hw_channel_1, LEDState::Off);
     assert_downstream_arm_led_msg!(&to_xtouch_rx, hw_channel_1, LEDState::Off);
     assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, hw_channel_1, 0.5);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, hw_channel_1, "", hw_channel_1 + 1);
     mode.handle_downstream_messages(
         TrackMsg::TrackDataMsg(TrackDataMsg {
             guid: track_guid.clone(),
@@ -636,6 +701,9 @@ fn test_05_volume_state_reflects_latest_value_when_remapped() {
     assert_downstream_solo_led_msg!(&to_xtouch_rx, hw_channel_2, LEDState::Off);
     assert_downstream_arm_led_msg!(&to_xtouch_rx, hw_channel_2, LEDState::Off);
     assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, hw_channel_2, 0.5);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, hw_channel_2, "", hw_channel_2 + 1);
+    // The channel the track left is cleared
+    assert_downstream_blank_channel(&to_xtouch_rx, hw_channel_1);
 
     // Verify the track can be found via find_hw_channel
     let found_channel = mode.find_hw_channel(&track_guid);
@@ -670,7 +738,7 @@ fn test_06_multiple_button_state_updates_accumulate_correctly() {
     let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-buttons".to_string();
+    let track_guid = TrackGuid::from("track-guid-buttons");
     let hw_channel = 3;
 
     let curr_mode = ModeState {
@@ -718,14 +786,13 @@ fn test_06_multiple_button_state_updates_accumulate_correctly() {
 
 #[test]
 fn test_pan_state_accumulates_and_applies_on_mapping() {
-    // NOTE: Current implementation limitation - pan state is only stored for mapped tracks.
-    // Ideally, state should accumulate for unmapped tracks and be sent when they're mapped.
-    // This test documents current behavior.
+    // Pan updates to a mapped track go straight to the encoder ring; see
+    // test_pan_state_accumulates_before_mapping for updates that arrive before mapping.
 
     let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-pan".to_string();
+    let track_guid = TrackGuid::from("track-guid-pan");
     let hw_channel = 1;
     let pan_value_1 = 0.3;
     let pan_value_2 = 0.7; // Most recent value
@@ -767,13 +834,12 @@ fn test_pan_state_accumulates_and_applies_on_mapping() {
 
 #[test]
 fn test_pan_state_accumulates_before_mapping() {
-    // This test demonstrates IDEAL behavior: state should accumulate for unmapped tracks
-    // and be sent downstream when the track is mapped.
+    // State accumulates for unmapped tracks and is sent downstream when the track is mapped.
 
     let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-pan-accumulate".to_string();
+    let track_guid = TrackGuid::from("track-guid-pan-accumulate");
     let hw_channel = 1;
     let pan_value_1 = 0.3;
     let pan_value_2 = 0.7; // Most recent value should be sent
@@ -815,6 +881,66 @@ fn test_pan_state_accumulates_before_mapping() {
     assert_downstream_solo_led_msg!(&to_xtouch_rx, hw_channel, LEDState::Off);
     assert_downstream_arm_led_msg!(&to_xtouch_rx, hw_channel, LEDState::Off);
     assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, hw_channel, pan_value_2);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, hw_channel, "", hw_channel + 1);
+}
+
+#[test]
+fn test_latest_state_of_every_kind_is_sent_on_mapping() {
+    let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+
+    let track_guid = TrackGuid::from("track-guid-all-state");
+    let hw_channel = 2;
+
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+
+    // Every kind of state changes at least once before the track is mapped, some of it more than
+    // once; only the latest value of each should reach the hardware
+    for data in [
+        DataPayload::Soloed(true),
+        DataPayload::Muted(true),
+        DataPayload::Armed(true),
+        DataPayload::Soloed(false),
+        DataPayload::Volume(0.25),
+        DataPayload::Pan(0.2),
+        DataPayload::Armed(false),
+        DataPayload::Soloed(true),
+        DataPayload::Volume(0.4),
+    ] {
+        mode.handle_downstream_messages(
+            TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid: track_guid.clone(),
+                direction: Direction::Downstream,
+                data,
+            }),
+            curr_mode,
+        );
+    }
+    check_no_message!(&to_xtouch_rx, 100);
+
+    assign_track_to_channel(&mut mode, &track_guid, hw_channel, curr_mode);
+    assert_downstream_fader_abs_msg!(&to_xtouch_rx, hw_channel, 0.4);
+    assert_downstream_mute_led_msg!(&to_xtouch_rx, hw_channel, LEDState::On);
+    assert_downstream_solo_led_msg!(&to_xtouch_rx, hw_channel, LEDState::On);
+    assert_downstream_arm_led_msg!(&to_xtouch_rx, hw_channel, LEDState::Off);
+    assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, hw_channel, 0.2);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, hw_channel, "", hw_channel + 1);
+
+    // The values sent on mapping count as already sent, so repeating them changes nothing
+    for data in [DataPayload::Volume(0.4), DataPayload::Pan(0.2)] {
+        mode.handle_downstream_messages(
+            TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid: track_guid.clone(),
+                direction: Direction::Downstream,
+                data,
+            }),
+            curr_mode,
+        );
+    }
+    check_no_message!(&to_xtouch_rx, 100);
 }
 
 // ----------------------------------------------------------------------------
@@ -826,7 +952,7 @@ fn test_08_mute_button_sends_correct_upstream_and_downstream_messages() {
     let (mut mode, _from_reaper_tx, to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-mute-flow".to_string();
+    let track_guid = TrackGuid::from("track-guid-mute-flow");
     let hw_channel = 2;
 
     let curr_mode = ModeState {
@@ -840,7 +966,9 @@ fn test_08_mute_button_sends_correct_upstream_and_downstream_messages() {
 
     // Simulate mute button press
     mode.handle_upstream_messages(
-        XTouchUpstreamMsg::MutePress(MutePress { idx: hw_channel }),
+        XTouchUpstreamMsg::MutePress(MutePress {
+            idx: channel_index(hw_channel),
+        }),
         curr_mode,
     );
 
@@ -856,7 +984,7 @@ fn test_09_solo_button_sends_correct_messages() {
     let (mut mode, _from_reaper_tx, to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-solo-flow".to_string();
+    let track_guid = TrackGuid::from("track-guid-solo-flow");
     let hw_channel = 4;
 
     let curr_mode = ModeState {
@@ -870,7 +998,9 @@ fn test_09_solo_button_sends_correct_messages() {
 
     // Simulate solo button press
     mode.handle_upstream_messages(
-        XTouchUpstreamMsg::SoloPress(SoloPress { idx: hw_channel }),
+        XTouchUpstreamMsg::SoloPress(SoloPress {
+            idx: channel_index(hw_channel),
+        }),
         curr_mode,
     );
 
@@ -886,7 +1016,7 @@ fn test_10_arm_button_sends_correct_messages() {
     let (mut mode, _from_reaper_tx, to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-arm-flow".to_string();
+    let track_guid = TrackGuid::from("track-guid-arm-flow");
     let hw_channel = 0;
 
     let curr_mode = ModeState {
@@ -900,7 +1030,9 @@ fn test_10_arm_button_sends_correct_messages() {
 
     // Simulate arm button press
     mode.handle_upstream_messages(
-        XTouchUpstreamMsg::ArmPress(ArmPress { idx: hw_channel }),
+        XTouchUpstreamMsg::ArmPress(ArmPress {
+            idx: channel_index(hw_channel),
+        }),
         curr_mode,
     );
 
@@ -917,7 +1049,7 @@ fn test_11_pan_encoder_changes_forward_correctly() {
     let (mut mode, _from_reaper_tx, to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-encoder".to_string();
+    let track_guid = TrackGuid::from("track-guid-encoder");
     let hw_channel = 5;
     let initial_pan = 0.5;
 
@@ -943,7 +1075,9 @@ fn test_11_pan_encoder_changes_forward_correctly() {
 
     // Simulate encoder turn clockwise
     let result_mode = mode.handle_upstream_messages(
-        XTouchUpstreamMsg::EncoderTurnInc(EncoderTurnCW { idx: hw_channel }),
+        XTouchUpstreamMsg::EncoderTurnInc(EncoderTurnCW {
+            idx: channel_index(hw_channel),
+        }),
         curr_mode,
     );
 
@@ -968,8 +1102,8 @@ fn test_12_state_propagates_correctly_during_mode_entry() {
     let (mut mode, _from_reaper_tx, to_reaper_rx, _from_xtouch_tx, _to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid_1 = "track-guid-transition-1".to_string();
-    let track_guid_2 = "track-guid-transition-2".to_string();
+    let track_guid_1 = TrackGuid::from("track-guid-transition-1");
+    let track_guid_2 = TrackGuid::from("track-guid-transition-2");
     let hw_channel_1 = 0;
     let hw_channel_2 = 1;
 
@@ -1013,7 +1147,7 @@ fn test_15_downstream_messages_sent_in_correct_order() {
     let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-ordering-downstream".to_string();
+    let track_guid = TrackGuid::from("track-guid-ordering-downstream");
     let hw_channel = 1;
 
     let curr_mode = ModeState {
@@ -1028,6 +1162,7 @@ fn test_15_downstream_messages_sent_in_correct_order() {
     assert_downstream_solo_led_msg!(&to_xtouch_rx, hw_channel, LEDState::Off);
     assert_downstream_arm_led_msg!(&to_xtouch_rx, hw_channel, LEDState::Off);
     assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, hw_channel, 0.5);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, hw_channel, "", hw_channel + 1);
 
     // Send multiple messages in order
     mode.handle_downstream_messages(
@@ -1082,7 +1217,7 @@ fn test_16_upstream_messages_processed_in_correct_order() {
     let (mut mode, _from_reaper_tx, to_reaper_rx, _from_xtouch_tx, _to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-ordering-upstream".to_string();
+    let track_guid = TrackGuid::from("track-guid-ordering-upstream");
     let hw_channel = 3;
 
     let curr_mode = ModeState {
@@ -1097,18 +1232,21 @@ fn test_16_upstream_messages_processed_in_correct_order() {
     assert_downstream_solo_led_msg!(&_to_xtouch_rx, hw_channel, LEDState::Off);
     assert_downstream_arm_led_msg!(&_to_xtouch_rx, hw_channel, LEDState::Off);
     assert_downstream_encoder_ring_led_msg!(&_to_xtouch_rx, hw_channel, 0.5);
+    assert_downstream_scribble_strip_msg!(&_to_xtouch_rx, hw_channel, "", hw_channel + 1);
 
     // Send multiple upstream messages in order
     mode.handle_upstream_messages(
         XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
-            idx: hw_channel,
+            idx: channel_index(hw_channel),
             value: 0.6,
         }),
         curr_mode,
     );
 
     mode.handle_upstream_messages(
-        XTouchUpstreamMsg::MutePress(MutePress { idx: hw_channel }),
+        XTouchUpstreamMsg::MutePress(MutePress {
+            idx: channel_index(hw_channel),
+        }),
         curr_mode,
     );
 
@@ -1142,7 +1280,7 @@ fn test_17_volume_changes_below_epsilon_threshold_ignored() {
     let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-epsilon-vol".to_string();
+    let track_guid = TrackGuid::from("track-guid-epsilon-vol");
     let hw_channel = 2;
     let initial_volume = 0.5;
 
@@ -1158,6 +1296,7 @@ fn test_17_volume_changes_below_epsilon_threshold_ignored() {
     assert_downstream_solo_led_msg!(&to_xtouch_rx, hw_channel, LEDState::Off);
     assert_downstream_arm_led_msg!(&to_xtouch_rx, hw_channel, LEDState::Off);
     assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, hw_channel, 0.5);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, hw_channel, "", hw_channel + 1);
 
     mode.handle_downstream_messages(
         TrackMsg::TrackDataMsg(TrackDataMsg {
@@ -1190,7 +1329,7 @@ fn test_18_pan_changes_below_epsilon_threshold_ignored() {
     let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-guid-epsilon-pan".to_string();
+    let track_guid = TrackGuid::from("track-guid-epsilon-pan");
     let hw_channel = 1;
     let initial_pan = 0.5;
 
@@ -1206,6 +1345,7 @@ fn test_18_pan_changes_below_epsilon_threshold_ignored() {
     assert_downstream_solo_led_msg!(&to_xtouch_rx, hw_channel, LEDState::Off);
     assert_downstream_arm_led_msg!(&to_xtouch_rx, hw_channel, LEDState::Off);
     assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, hw_channel, 0.5);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, hw_channel, "", hw_channel + 1);
 
     // Send pan change to different value (0.7) - should send because it's > EPSILON from 0.5
     mode.handle_downstream_messages(
@@ -1251,10 +1391,10 @@ fn test_complex_multi_track_integration() {
         mode: Mode::ReaperVolPan,
     };
 
-    let track1_guid = "track-1".to_string();
-    let track2_guid = "track-2".to_string();
-    let track3_guid = "track-3".to_string();
-    let track4_guid = "track-4".to_string();
+    let track1_guid = TrackGuid::from("track-1");
+    let track2_guid = TrackGuid::from("track-2");
+    let track3_guid = TrackGuid::from("track-3");
+    let track4_guid = TrackGuid::from("track-4");
 
     // === PHASE 1: Send state updates to unmapped tracks ===
     // Track 1: Volume only
@@ -1296,8 +1436,6 @@ fn test_complex_multi_track_integration() {
     check_no_message!(&to_xtouch_rx, 100); // No hardware assigned yet
 
     // Track 3: Solo and arm
-    // NOTE: Current implementation may not properly accumulate solo/arm state before mapping
-    // This test documents current behavior
     mode.handle_downstream_messages(
         TrackMsg::TrackDataMsg(TrackDataMsg {
             guid: track3_guid.clone(),
@@ -1328,6 +1466,7 @@ fn test_complex_multi_track_integration() {
     assert_downstream_solo_led_msg!(&to_xtouch_rx, 1, LEDState::Off);
     assert_downstream_arm_led_msg!(&to_xtouch_rx, 1, LEDState::Off);
     assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, 1, 0.5); // Default pan
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, 1, "", 2);
 
     // Verify track 2 all accumulated state sent to channel 2
     assert_downstream_fader_abs_msg!(&to_xtouch_rx, 2, 0.9);
@@ -1335,6 +1474,7 @@ fn test_complex_multi_track_integration() {
     assert_downstream_solo_led_msg!(&to_xtouch_rx, 2, LEDState::Off);
     assert_downstream_arm_led_msg!(&to_xtouch_rx, 2, LEDState::Off);
     assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, 2, 0.3); // Pan set
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, 2, "", 3);
 
     // Verify track 3 accumulated state sent to channel 3
     assert_downstream_fader_abs_msg!(&to_xtouch_rx, 3, FADER_0DB as f64); // Default volume
@@ -1342,6 +1482,7 @@ fn test_complex_multi_track_integration() {
     assert_downstream_solo_led_msg!(&to_xtouch_rx, 3, LEDState::On); // Solo accumulated!
     assert_downstream_arm_led_msg!(&to_xtouch_rx, 3, LEDState::On); // Armed accumulated!
     assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, 3, 0.5); // Default pan
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, 3, "", 4);
 
     // === PHASE 3: Send updates to mapped tracks ===
     // Update track 1 volume (should send to hardware)
@@ -1357,7 +1498,9 @@ fn test_complex_multi_track_integration() {
 
     // Toggle mute on track 2 via hardware
     mode.handle_upstream_messages(
-        XTouchUpstreamMsg::MutePress(MutePress { idx: 2 }),
+        XTouchUpstreamMsg::MutePress(MutePress {
+            idx: ChannelIndex::new(2),
+        }),
         curr_mode,
     );
     // Should send upstream to Reaper (unmute)
@@ -1374,6 +1517,9 @@ fn test_complex_multi_track_integration() {
     assert_downstream_solo_led_msg!(&to_xtouch_rx, 4, LEDState::Off);
     assert_downstream_arm_led_msg!(&to_xtouch_rx, 4, LEDState::Off);
     assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, 4, 0.5);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, 4, "", 5);
+    // Old channel is cleared
+    assert_downstream_blank_channel(&to_xtouch_rx, 1);
 
     // Verify old channel (1) no longer responds to track 1 updates
     mode.handle_downstream_messages(
@@ -1390,7 +1536,9 @@ fn test_complex_multi_track_integration() {
 
     // Verify upstream messages from old channel (1) have no effect
     mode.handle_upstream_messages(
-        XTouchUpstreamMsg::MutePress(MutePress { idx: 1 }),
+        XTouchUpstreamMsg::MutePress(MutePress {
+            idx: ChannelIndex::new(1),
+        }),
         curr_mode,
     );
     // Should have no effect since track 1 is no longer mapped to channel 1
@@ -1441,6 +1589,7 @@ fn test_complex_multi_track_integration() {
     assert_downstream_solo_led_msg!(&to_xtouch_rx, 5, LEDState::Off);
     assert_downstream_arm_led_msg!(&to_xtouch_rx, 5, LEDState::Off);
     assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, 5, 0.8); // Latest pan (not 0.2)
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, 5, "", 6);
 
     // === PHASE 6: Test EPSILON filtering on mapped tracks ===
     // NOTE: EPSILON filtering behavior can be complex due to floating point precision
@@ -1461,7 +1610,9 @@ fn test_complex_multi_track_integration() {
     // === PHASE 7: Hardware interaction on multiple channels ===
     // Press arm button on channel 3 (track 3)
     mode.handle_upstream_messages(
-        XTouchUpstreamMsg::ArmPress(ArmPress { idx: 3 }),
+        XTouchUpstreamMsg::ArmPress(ArmPress {
+            idx: ChannelIndex::new(3),
+        }),
         curr_mode,
     );
     // Should toggle arm state (was on, now off)
@@ -1470,7 +1621,9 @@ fn test_complex_multi_track_integration() {
 
     // Press solo button on channel 4 (track 1)
     mode.handle_upstream_messages(
-        XTouchUpstreamMsg::SoloPress(SoloPress { idx: 4 }),
+        XTouchUpstreamMsg::SoloPress(SoloPress {
+            idx: ChannelIndex::new(4),
+        }),
         curr_mode,
     );
     // Should toggle solo state (was off, now on)
@@ -1480,7 +1633,7 @@ fn test_complex_multi_track_integration() {
     // Move fader on channel 5 (track 4)
     mode.handle_upstream_messages(
         XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
-            idx: 5,
+            idx: ChannelIndex::new(5),
             value: 0.55,
         }),
         curr_mode,
@@ -1497,6 +1650,9 @@ fn test_complex_multi_track_integration() {
     assert_downstream_solo_led_msg!(&to_xtouch_rx, 3, LEDState::Off); // Track 2's solo
     assert_downstream_arm_led_msg!(&to_xtouch_rx, 3, LEDState::Off); // Track 2's arm
     assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, 3, 0.3); // Track 2's pan
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, 3, "", 4);
+    // Track 2's old channel is cleared
+    assert_downstream_blank_channel(&to_xtouch_rx, 2);
 
     // Verify track 3 no longer responds on channel 3
     mode.handle_downstream_messages(
@@ -1531,19 +1687,25 @@ fn test_complex_multi_track_integration() {
 
     // Final state verification via hardware interaction
     mode.handle_upstream_messages(
-        XTouchUpstreamMsg::MutePress(MutePress { idx: 4 }),
+        XTouchUpstreamMsg::MutePress(MutePress {
+            idx: ChannelIndex::new(4),
+        }),
         curr_mode,
     );
     assert_upstream_muted_track_msg!(&to_reaper_rx, &track1_guid, true); // Track 1 on channel 4
 
     mode.handle_upstream_messages(
-        XTouchUpstreamMsg::SoloPress(SoloPress { idx: 3 }),
+        XTouchUpstreamMsg::SoloPress(SoloPress {
+            idx: ChannelIndex::new(3),
+        }),
         curr_mode,
     );
     assert_upstream_soloed_track_msg!(&to_reaper_rx, &track2_guid, true); // Track 2 on channel 3
 
     mode.handle_upstream_messages(
-        XTouchUpstreamMsg::ArmPress(ArmPress { idx: 5 }),
+        XTouchUpstreamMsg::ArmPress(ArmPress {
+            idx: ChannelIndex::new(5),
+        }),
         curr_mode,
     );
     assert_upstream_armed_track_msg!(&to_reaper_rx, &track4_guid, true); // Track 4 on channel 5
@@ -1556,7 +1718,7 @@ fn test_epsilon_tracking_reset_on_remapping() {
     let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
         setup_vol_pan_mode();
 
-    let track_guid = "track-epsilon-remap".to_string();
+    let track_guid = TrackGuid::from("track-epsilon-remap");
     let channel_1 = 0i32;
     let channel_2 = 1i32;
 
@@ -1599,6 +1761,8 @@ fn test_epsilon_tracking_reset_on_remapping() {
     assert_downstream_solo_led_msg!(&to_xtouch_rx, channel_2, LEDState::Off);
     assert_downstream_arm_led_msg!(&to_xtouch_rx, channel_2, LEDState::Off);
     assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, channel_2, 0.5);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, channel_2, "", channel_2 + 1);
+    assert_downstream_blank_channel(&to_xtouch_rx, channel_1);
 
     // Send another small volume update (0.81) - should be filtered again
     mode.handle_downstream_messages(
@@ -1622,3 +1786,538 @@ fn test_epsilon_tracking_reset_on_remapping() {
     );
     assert_downstream_fader_abs_msg!(&to_xtouch_rx, channel_2, 0.82);
 }
+
+#[test]
+fn test_selected_only_policy_follows_selection() {
+    let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+    mode.set_mapping_policy(MappingPolicy::SelectedOnly);
+
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+
+    let select = |mode: &mut VolumePanMode, guid: &str, selected: bool| {
+        mode.handle_downstream_messages(
+            TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid: guid.into(),
+                direction: Direction::Downstream,
+                data: DataPayload::Selected(selected),
+            }),
+            curr_mode,
+        );
+    };
+
+    // Tracks that aren't selected stay off the surface
+    assign_track_to_channel(&mut mode, "track-a", 2, curr_mode);
+    assign_track_to_channel(&mut mode, "track-b", 5, curr_mode);
+    check_no_message!(&to_xtouch_rx, 100);
+
+    // Selected tracks are packed from the first channel, shown with their Reaper track number
+    select(&mut mode, "track-b", true);
+    assert_downstream_fader_abs_msg!(&to_xtouch_rx, 0, FADER_0DB as f64);
+    assert_downstream_mute_led_msg!(&to_xtouch_rx, 0, LEDState::Off);
+    assert_downstream_solo_led_msg!(&to_xtouch_rx, 0, LEDState::Off);
+    assert_downstream_arm_led_msg!(&to_xtouch_rx, 0, LEDState::Off);
+    assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, 0, 0.5);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, 0, "", 6);
+
+    select(&mut mode, "track-a", true);
+    assert_downstream_fader_abs_msg!(&to_xtouch_rx, 0, FADER_0DB as f64);
+    assert_downstream_mute_led_msg!(&to_xtouch_rx, 0, LEDState::Off);
+    assert_downstream_solo_led_msg!(&to_xtouch_rx, 0, LEDState::Off);
+    assert_downstream_arm_led_msg!(&to_xtouch_rx, 0, LEDState::Off);
+    assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, 0, 0.5);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, 0, "", 3);
+    assert_downstream_fader_abs_msg!(&to_xtouch_rx, 1, FADER_0DB as f64);
+    assert_downstream_mute_led_msg!(&to_xtouch_rx, 1, LEDState::Off);
+    assert_downstream_solo_led_msg!(&to_xtouch_rx, 1, LEDState::Off);
+    assert_downstream_arm_led_msg!(&to_xtouch_rx, 1, LEDState::Off);
+    assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, 1, 0.5);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, 1, "", 6);
+    assert_eq!(mode.find_hw_channel(&TrackGuid::from("track-a")), Some(0));
+    assert_eq!(mode.find_hw_channel(&TrackGuid::from("track-b")), Some(1));
+
+    // Deselecting moves the remaining track up and clears the channel it left
+    select(&mut mode, "track-a", false);
+    assert_downstream_fader_abs_msg!(&to_xtouch_rx, 0, FADER_0DB as f64);
+    assert_downstream_mute_led_msg!(&to_xtouch_rx, 0, LEDState::Off);
+    assert_downstream_solo_led_msg!(&to_xtouch_rx, 0, LEDState::Off);
+    assert_downstream_arm_led_msg!(&to_xtouch_rx, 0, LEDState::Off);
+    assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, 0, 0.5);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, 0, "", 6);
+    assert_downstream_blank_channel(&to_xtouch_rx, 1);
+    check_no_message!(&to_xtouch_rx, 100);
+}
+
+/// Helper to assert the messages that reset a channel with no track assigned
+fn assert_downstream_blank_channel(to_xtouch_rx: &Receiver<XTouchDownstreamMsg>, hw_channel: i32) {
+    assert_downstream_fader_abs_msg!(to_xtouch_rx, hw_channel, 0.0);
+    assert_downstream_mute_led_msg!(to_xtouch_rx, hw_channel, LEDState::Off);
+    assert_downstream_solo_led_msg!(to_xtouch_rx, hw_channel, LEDState::Off);
+    assert_downstream_arm_led_msg!(to_xtouch_rx, hw_channel, LEDState::Off);
+    let msg = to_xtouch_rx
+        .recv_timeout(Duration::from_millis(100))
+        .expect("Expected to receive an EncoderRingLED message.");
+    match msg {
+        XTouchDownstreamMsg::EncoderRingLED(EncoderRingLEDMsg::Blank(blank)) => {
+            check!(blank.idx == hw_channel);
+        }
+        _ => panic!("Expected EncoderRingLED Blank message but got {:?}", msg),
+    }
+    assert_downstream_scribble_strip_msg!(to_xtouch_rx, hw_channel, "", "");
+}
+
+/// Helper to assert the assignment display shows the given text
+fn assert_downstream_assignment_display(to_xtouch_rx: &Receiver<XTouchDownstreamMsg>, text: &str) {
+    let msg = to_xtouch_rx
+        .recv_timeout(Duration::from_millis(100))
+        .expect("Expected to receive an AssignmentDisplay message.");
+    match msg {
+        XTouchDownstreamMsg::AssignmentDisplay(AssignmentDisplayMsg { text: shown }) => {
+            check!(shown.iter().collect::<String>() == text);
+        }
+        _ => panic!("Expected AssignmentDisplay message but got {:?}", msg),
+    }
+}
+
+#[test]
+fn test_bank_switching_pages_tracks_onto_hardware() {
+    let (mut mode, _from_reaper_tx, to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+
+    // Track 0 is in the first bank, track 9 is in the second bank (8 channels per bank)
+    assign_track_to_channel(&mut mode, "track-0", 0, curr_mode);
+    assert_downstream_default_track_mapping(&to_xtouch_rx, 0);
+    assign_track_to_channel(&mut mode, "track-9", 9, curr_mode);
+    check_no_message!(&to_xtouch_rx, 100);
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            direction: Direction::Downstream,
+            guid: "track-9".into(),
+            data: DataPayload::Muted(true),
+        }),
+        curr_mode,
+    );
+    check_no_message!(&to_xtouch_rx, 100);
+
+    // Bank left from the first bank does nothing
+    mode.handle_upstream_messages(XTouchUpstreamMsg::BankLeftPress, curr_mode);
+    check_no_message!(&to_xtouch_rx, 100);
+    check!(mode.bank() == 0);
+
+    // Bank right shows track 9 on channel 1, with the state it accumulated while off-screen
+    let result_mode = mode.handle_upstream_messages(XTouchUpstreamMsg::BankRightPress, curr_mode);
+    assert_eq!(result_mode, curr_mode);
+    check!(mode.bank() == 1);
+    assert_downstream_blank_channel(&to_xtouch_rx, 0);
+    assert_downstream_fader_abs_msg!(&to_xtouch_rx, 1, FADER_0DB as f64);
+    assert_downstream_mute_led_msg!(&to_xtouch_rx, 1, LEDState::On);
+    assert_downstream_solo_led_msg!(&to_xtouch_rx, 1, LEDState::Off);
+    assert_downstream_arm_led_msg!(&to_xtouch_rx, 1, LEDState::Off);
+    assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, 1, 0.5);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, 1, "", 10);
+    for hw_channel in 2..8 {
+        assert_downstream_blank_channel(&to_xtouch_rx, hw_channel);
+    }
+    assert_downstream_assignment_display(&to_xtouch_rx, "V2");
+    check!(mode.find_hw_channel(&TrackGuid::from("track-9")) == Some(1));
+    check!(mode.find_hw_channel(&TrackGuid::from("track-0")).is_none());
+
+    // Hardware on channel 1 now controls track 9
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
+            idx: channel_index(1),
+            value: 0.3,
+        }),
+        curr_mode,
+    );
+    assert_volume_track_msg!(&to_reaper_rx, "track-9", 0.3);
+
+    // There is no third bank
+    mode.handle_upstream_messages(XTouchUpstreamMsg::BankRightPress, curr_mode);
+    check_no_message!(&to_xtouch_rx, 100);
+    check!(mode.bank() == 1);
+
+    // Back to the first bank
+    mode.handle_upstream_messages(XTouchUpstreamMsg::BankLeftPress, curr_mode);
+    check!(mode.bank() == 0);
+    assert_downstream_default_track_mapping(&to_xtouch_rx, 0);
+    for hw_channel in 1..8 {
+        assert_downstream_blank_channel(&to_xtouch_rx, hw_channel);
+    }
+    assert_downstream_assignment_display(&to_xtouch_rx, "V1");
+    check!(mode.find_hw_channel(&TrackGuid::from("track-0")) == Some(0));
+}
+
+#[test]
+fn test_scribble_strip_shows_track_name() {
+    let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+
+    // A name received before the track is mapped is shown once it is
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            direction: Direction::Downstream,
+            guid: "track-2".into(),
+            data: DataPayload::Name("Drums".to_string()),
+        }),
+        curr_mode,
+    );
+    check_no_message!(&to_xtouch_rx, 100);
+    assign_track_to_channel(&mut mode, "track-2", 2, curr_mode);
+    assert_downstream_fader_abs_msg!(&to_xtouch_rx, 2, FADER_0DB as f64);
+    assert_downstream_mute_led_msg!(&to_xtouch_rx, 2, LEDState::Off);
+    assert_downstream_solo_led_msg!(&to_xtouch_rx, 2, LEDState::Off);
+    assert_downstream_arm_led_msg!(&to_xtouch_rx, 2, LEDState::Off);
+    assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, 2, 0.5);
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, 2, "Drums", "3");
+
+    // Renaming a mapped track updates its strip
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            direction: Direction::Downstream,
+            guid: "track-2".into(),
+            data: DataPayload::Name("Kit".to_string()),
+        }),
+        curr_mode,
+    );
+    assert_downstream_scribble_strip_msg!(&to_xtouch_rx, 2, "Kit", "3");
+    check_no_message!(&to_xtouch_rx, 100);
+}
+
+#[test]
+fn test_scribble_strip_is_lit_in_track_color() {
+    let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+    let expect_color =
+        |color: ScribbleColor| match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+            Ok(XTouchDownstreamMsg::ScribbleStrip(msg)) => {
+                assert_eq!(msg.idx, channel_index(1));
+                assert_eq!(msg.color, color);
+            }
+            other => panic!("Expected ScribbleStrip message but got {:?}", other),
+        };
+
+    // A color received before the track is mapped is shown once it is
+    let set_color = |mode: &mut VolumePanMode, color: i32| {
+        mode.handle_downstream_messages(
+            TrackMsg::TrackDataMsg(TrackDataMsg {
+                direction: Direction::Downstream,
+                guid: "track-1".into(),
+                data: DataPayload::Color(color),
+            }),
+            curr_mode,
+        )
+    };
+    set_color(&mut mode, 0x2050E0);
+    check_no_message!(&to_xtouch_rx, 100);
+    assign_track_to_channel(&mut mode, "track-1", 1, curr_mode);
+    assert_downstream_fader_abs_msg!(&to_xtouch_rx, 1, FADER_0DB as f64);
+    assert_downstream_mute_led_msg!(&to_xtouch_rx, 1, LEDState::Off);
+    assert_downstream_solo_led_msg!(&to_xtouch_rx, 1, LEDState::Off);
+    assert_downstream_arm_led_msg!(&to_xtouch_rx, 1, LEDState::Off);
+    assert_downstream_encoder_ring_led_msg!(&to_xtouch_rx, 1, 0.5);
+    expect_color(ScribbleColor::Blue);
+
+    // Recoloring a mapped track relights its strip, and taking its color away goes back to white
+    set_color(&mut mode, 0xE0C020);
+    expect_color(ScribbleColor::Yellow);
+    set_color(&mut mode, 0);
+    expect_color(ScribbleColor::White);
+    check_no_message!(&to_xtouch_rx, 100);
+}
+
+#[test]
+fn test_initial_sync_ends_loading_with_full_render() {
+    let (mut mode, _from_reaper_tx, to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+
+    mode.wait_for_initial_sync();
+    assert_downstream_assignment_display(&to_xtouch_rx, "LD");
+
+    // Nothing is rendered while loading, and the surface doesn't control any tracks yet
+    assign_track_to_channel(&mut mode, "track-a", 0, curr_mode);
+    assign_track_to_channel(&mut mode, "track-b", 2, curr_mode);
+    check_no_message!(&to_xtouch_rx, 100);
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
+            idx: channel_index(0),
+            value: 0.3,
+        }),
+        curr_mode,
+    );
+    check_no_message!(&to_reaper_rx, 100);
+
+    // The sync renders every channel at once
+    mode.handle_downstream_messages(TrackMsg::InitialSync(InitialSync::Complete), curr_mode);
+    assert_downstream_default_track_mapping(&to_xtouch_rx, 0);
+    assert_downstream_blank_channel(&to_xtouch_rx, 1);
+    assert_downstream_default_track_mapping(&to_xtouch_rx, 2);
+    for hw_channel in 3..8 {
+        assert_downstream_blank_channel(&to_xtouch_rx, hw_channel);
+    }
+    assert_downstream_assignment_display(&to_xtouch_rx, "V1");
+    check_no_message!(&to_xtouch_rx, 100);
+    assert_eq!(mode.find_hw_channel(&TrackGuid::from("track-b")), Some(2));
+
+    // Only the first sync matters
+    mode.handle_downstream_messages(TrackMsg::InitialSync(InitialSync::TimedOut), curr_mode);
+    check_no_message!(&to_xtouch_rx, 100);
+}
+
+#[test]
+fn test_touched_fader_is_not_moved_until_released() {
+    let (mut mode, _from_reaper_tx, to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+    let volume = |guid: &str, value: f32| {
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: guid.into(),
+            direction: Direction::Downstream,
+            data: DataPayload::Volume(value),
+        })
+    };
+
+    assign_track_to_channel(&mut mode, "track-a", 0, curr_mode);
+    assert_downstream_default_track_mapping(&to_xtouch_rx, 0);
+    assign_track_to_channel(&mut mode, "track-b", 1, curr_mode);
+    assert_downstream_default_track_mapping(&to_xtouch_rx, 1);
+
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::FaderTouch(FaderTouchMsg {
+            idx: channel_index(0),
+        }),
+        curr_mode,
+    );
+
+    // Moving the held fader still reaches Reaper, but Reaper's echo doesn't reach the motor
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::FaderAbs(FaderAbsMsg {
+            idx: channel_index(0),
+            value: 0.4,
+        }),
+        curr_mode,
+    );
+    assert_volume_track_msg!(&to_reaper_rx, "track-a", 0.4);
+    mode.handle_downstream_messages(volume("track-a", 0.4), curr_mode);
+    mode.handle_downstream_messages(volume("track-a", 0.42), curr_mode);
+    check_no_message!(&to_xtouch_rx, 100);
+
+    // Other faders aren't affected
+    mode.handle_downstream_messages(volume("track-b", 0.3), curr_mode);
+    assert_downstream_fader_abs_msg!(&to_xtouch_rx, 1, 0.3);
+
+    // Letting go brings the fader to Reaper's latest value
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::FaderRelease(FaderReleaseMsg {
+            idx: channel_index(0),
+        }),
+        curr_mode,
+    );
+    assert_downstream_fader_abs_msg!(&to_xtouch_rx, 0, 0.42_f32 as f64);
+    mode.handle_downstream_messages(volume("track-a", 0.6), curr_mode);
+    assert_downstream_fader_abs_msg!(&to_xtouch_rx, 0, 0.6_f32 as f64);
+}
+
+#[test]
+fn test_touched_fader_is_released_on_mode_entry() {
+    let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+    let (upstream_tx, _upstream_rx) = unbounded();
+
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+
+    assign_track_to_channel(&mut mode, "track-a", 0, curr_mode);
+    assert_downstream_default_track_mapping(&to_xtouch_rx, 0);
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::FaderTouch(FaderTouchMsg {
+            idx: channel_index(0),
+        }),
+        curr_mode,
+    );
+
+    // The release went to whichever mode had the surface, so coming back forgets the touch
+    mode.initiate_mode_transition(upstream_tx);
+    assert_downstream_assignment_display(&to_xtouch_rx, "V1");
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: "track-a".into(),
+            direction: Direction::Downstream,
+            data: DataPayload::Volume(0.2),
+        }),
+        curr_mode,
+    );
+    assert_downstream_fader_abs_msg!(&to_xtouch_rx, 0, 0.2_f32 as f64);
+}
+
+/// Helper to assert a MasterFaderAbs message is received with the expected value
+fn assert_downstream_master_fader(to_xtouch_rx: &Receiver<XTouchDownstreamMsg>, value: f64) {
+    match to_xtouch_rx.recv_timeout(Duration::from_millis(100)) {
+        Ok(XTouchDownstreamMsg::MasterFaderAbs(msg)) => {
+            check!(
+                approx_eq!(f64, msg.value, value, epsilon = FLOAT_EPSILON),
+                "Master fader value should match approximately\nExpected: {}, Got: {}",
+                value,
+                msg.value,
+            );
+        }
+        other => panic!("Expected MasterFaderAbs message but got {:?}", other),
+    }
+}
+
+#[test]
+fn test_master_volume_moves_master_fader_whatever_the_bank() {
+    let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+
+    // Nine tracks make a second bank to switch to
+    for i in 0..9 {
+        assign_track_to_channel(&mut mode, &format!("track-{}", i), i, curr_mode);
+    }
+    mode.handle_upstream_messages(XTouchUpstreamMsg::BankRightPress, curr_mode);
+    assert!(mode.bank() == 1);
+    while to_xtouch_rx.try_recv().is_ok() {}
+
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: MASTER_GUID.into(),
+            direction: Direction::Downstream,
+            data: DataPayload::Volume(0.6),
+        }),
+        curr_mode,
+    );
+    assert_downstream_master_fader(&to_xtouch_rx, 0.6_f32 as f64);
+    // The master track never takes up a strip
+    check_no_message!(&to_xtouch_rx, 100);
+}
+
+#[test]
+fn test_master_fader_sends_master_volume_upstream() {
+    let (mut mode, _from_reaper_tx, to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+
+    mode.handle_upstream_messages(
+        XTouchUpstreamMsg::MasterFaderAbs(MasterFaderAbsMsg { value: 0.3 }),
+        curr_mode,
+    );
+    assert_volume_track_msg!(&to_reaper_rx, MASTER_GUID, 0.3);
+    check_no_message!(&to_xtouch_rx, 100);
+}
+
+#[test]
+fn test_master_fader_is_restored_on_mode_entry() {
+    let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+    let (upstream_tx, _upstream_rx) = unbounded();
+
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+
+    // Nothing is known about the master track yet, so the master fader is left alone
+    mode.initiate_mode_transition(upstream_tx.clone());
+    assert_downstream_assignment_display(&to_xtouch_rx, "V1");
+
+    mode.handle_downstream_messages(
+        TrackMsg::TrackDataMsg(TrackDataMsg {
+            guid: MASTER_GUID.into(),
+            direction: Direction::Downstream,
+            data: DataPayload::Volume(0.45),
+        }),
+        curr_mode,
+    );
+    assert_downstream_master_fader(&to_xtouch_rx, 0.45_f32 as f64);
+
+    mode.initiate_mode_transition(upstream_tx);
+    assert_downstream_master_fader(&to_xtouch_rx, 0.45_f32 as f64);
+    assert_downstream_assignment_display(&to_xtouch_rx, "V1");
+}
+
+#[test]
+fn test_removed_track_is_cleared_and_later_tracks_move_up() {
+    let (mut mode, _from_reaper_tx, _to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+    assign_track_to_channel(&mut mode, "track-a", 0, curr_mode);
+    assign_track_to_channel(&mut mode, "track-b", 1, curr_mode);
+    while to_xtouch_rx.try_recv().is_ok() {}
+
+    mode.handle_downstream_messages(TrackMsg::TrackRemoved("track-a".into()), curr_mode);
+
+    // track-b takes the first channel, and the second is blanked
+    assert_downstream_default_track_mapping(&to_xtouch_rx, 0);
+    assert_downstream_blank_channel(&to_xtouch_rx, 1);
+    assert_eq!(mode.find_hw_channel(&TrackGuid::from("track-a")), None);
+    assert_eq!(mode.find_hw_channel(&TrackGuid::from("track-b")), Some(0));
+}
+
+#[test]
+fn test_track_removed_while_inactive_leaves_surface_alone() {
+    let (mut mode, _from_reaper_tx, to_reaper_rx, _from_xtouch_tx, to_xtouch_rx) =
+        setup_vol_pan_mode();
+    let curr_mode = ModeState {
+        mode: Mode::ReaperVolPan,
+        state: State::Active,
+    };
+    assign_track_to_channel(&mut mode, "track-a", 0, curr_mode);
+    assign_track_to_channel(&mut mode, "track-b", 1, curr_mode);
+    while to_xtouch_rx.try_recv().is_ok() {}
+
+    mode.forget_track(&TrackGuid::from("track-a"));
+    assert!(to_xtouch_rx.try_recv().is_err());
+
+    // Coming back, only the tracks still around are asked for
+    let (upstream_tx, _upstream_rx) = unbounded();
+    mode.initiate_mode_transition(upstream_tx);
+    let queried: Vec<TrackGuid> = to_reaper_rx
+        .try_iter()
+        .filter_map(|msg| match msg {
+            TrackMsg::TrackQuery(query) => Some(query.guid),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(queried, vec![TrackGuid::from("track-b")]);
+}
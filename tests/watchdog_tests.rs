@@ -0,0 +1,167 @@
+// Tests for the watchdog telling when a consumer has stopped getting through its messages
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::{bounded, unbounded};
+
+use arpad_rust::midi::xtouch::XTouchUpstreamMsg;
+use arpad_rust::modes::mode_manager::{ModeManager, ModeOptions};
+use arpad_rust::watchdog::{ChannelDepth, Consumer, Heartbeat, Watchdog, WatchdogEvent};
+
+const STALL_TIMEOUT: Duration = Duration::from_millis(100);
+
+#[test]
+fn test_consumer_with_messages_waiting_is_reported_stuck_once() {
+    let heartbeat = Heartbeat::new();
+    let (tx, rx) = bounded(4);
+    let watchdog = Watchdog::new(STALL_TIMEOUT);
+    watchdog.watch(Consumer::new("consumer", &heartbeat).with_channel("input", &tx));
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+
+    let later = Instant::now() + STALL_TIMEOUT * 2;
+    let events = watchdog.check(later);
+    let [
+        WatchdogEvent::Stalled {
+            consumer,
+            channels,
+            restarted,
+            ..
+        },
+    ] = events.as_slice()
+    else {
+        panic!("Expected the consumer to stall but got {:?}", events);
+    };
+    assert_eq!(consumer, "consumer");
+    assert_eq!(
+        *channels,
+        vec![ChannelDepth {
+            name: "input".to_string(),
+            len: 2,
+            capacity: Some(4),
+        }]
+    );
+    assert!(!restarted);
+    assert_eq!(watchdog.check(later), vec![]);
+
+    // Once it gets going again
+    rx.recv().unwrap();
+    heartbeat.beat();
+    assert_eq!(
+        watchdog.check(Instant::now()),
+        vec![WatchdogEvent::Recovered {
+            consumer: "consumer".to_string()
+        }]
+    );
+}
+
+#[test]
+fn test_idle_consumer_is_never_stuck() {
+    let heartbeat = Heartbeat::new();
+    let (tx, _rx) = unbounded::<i32>();
+    let watchdog = Watchdog::new(STALL_TIMEOUT);
+    watchdog.watch(Consumer::new("consumer", &heartbeat).with_channel("input", &tx));
+    assert_eq!(watchdog.check(Instant::now() + STALL_TIMEOUT * 10), vec![]);
+}
+
+#[test]
+fn test_stuck_consumer_is_restarted() {
+    let heartbeat = Heartbeat::new();
+    let (tx, _rx) = unbounded();
+    let restarts = Arc::new(AtomicUsize::new(0));
+    let watchdog = Watchdog::new(STALL_TIMEOUT);
+    watchdog.watch(
+        Consumer::new("consumer", &heartbeat)
+            .with_channel("input", &tx)
+            .with_restart({
+                let restarts = restarts.clone();
+                move || {
+                    restarts.fetch_add(1, Ordering::Relaxed);
+                }
+            }),
+    );
+    tx.send(1).unwrap();
+    let events = watchdog.check(Instant::now() + STALL_TIMEOUT * 2);
+    assert!(matches!(
+        events.as_slice(),
+        [WatchdogEvent::Stalled {
+            restarted: true,
+            ..
+        }]
+    ));
+    assert_eq!(restarts.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn test_restart_supersedes_the_stuck_consumer() {
+    let heartbeat = Heartbeat::new();
+    let started_at = heartbeat.generation();
+    let (tx, _rx) = unbounded();
+    let watchdog = Watchdog::new(STALL_TIMEOUT);
+    watchdog.watch(
+        Consumer::new("consumer", &heartbeat)
+            .with_channel("input", &tx)
+            .with_restart(|| {}),
+    );
+    assert!(!heartbeat.superseded(started_at));
+    tx.send(1).unwrap();
+    watchdog.check(Instant::now() + STALL_TIMEOUT * 2);
+    assert!(heartbeat.superseded(started_at));
+    // The thread started in its place isn't
+    assert!(!heartbeat.superseded(heartbeat.generation()));
+}
+
+#[test]
+fn test_superseded_modes_stop_reading_their_channels() {
+    let heartbeat = Heartbeat::new();
+    let (_reaper_tx, reaper_rx) = unbounded();
+    let (xtouch_tx, xtouch_rx) = unbounded();
+    let (to_reaper_tx, _to_reaper_rx) = unbounded();
+    let (to_xtouch_tx, _to_xtouch_rx) = unbounded();
+    ModeManager::start_with_options(
+        reaper_rx,
+        to_reaper_tx,
+        xtouch_rx,
+        to_xtouch_tx,
+        ModeOptions {
+            heartbeat: Some(heartbeat.clone()),
+            ..Default::default()
+        },
+    );
+    thread::sleep(Duration::from_millis(50));
+    heartbeat.supersede();
+
+    // The one the modes were waiting on when they were started over is the last they take, and
+    // then they let go of their channels
+    xtouch_tx.send(XTouchUpstreamMsg::ResyncRelease).unwrap();
+    thread::sleep(Duration::from_millis(50));
+    assert!(xtouch_tx.send(XTouchUpstreamMsg::ResyncRelease).is_err());
+}
+
+#[test]
+fn test_modes_beat_their_heartbeat_as_they_go() {
+    let heartbeat = Heartbeat::new();
+    let (_reaper_tx, reaper_rx) = unbounded();
+    let (xtouch_tx, xtouch_rx) = unbounded();
+    let (to_reaper_tx, _to_reaper_rx) = unbounded();
+    let (to_xtouch_tx, _to_xtouch_rx) = unbounded();
+    ModeManager::start_with_options(
+        reaper_rx,
+        to_reaper_tx,
+        xtouch_rx,
+        to_xtouch_tx,
+        ModeOptions {
+            heartbeat: Some(heartbeat.clone()),
+            ..Default::default()
+        },
+    );
+    thread::sleep(Duration::from_millis(50));
+    let before = heartbeat.last();
+
+    xtouch_tx.send(XTouchUpstreamMsg::ResyncRelease).unwrap();
+    thread::sleep(Duration::from_millis(50));
+    assert!(heartbeat.last() > before);
+}
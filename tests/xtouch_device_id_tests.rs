@@ -0,0 +1,44 @@
+// Tests for mapping daisy-chained XTouch units onto the channels of a shared MIDI port
+
+use arpad_rust::midi::xtouch::DeviceId;
+
+#[test]
+fn test_device_channel_blocks() {
+    assert_eq!(DeviceId(0).first_channel(8), Some(0));
+    assert_eq!(DeviceId(1).first_channel(8), Some(8));
+    // A third 8-strip unit would need channels 16..24, which don't exist
+    assert_eq!(DeviceId(2).first_channel(8), None);
+    assert_eq!(DeviceId(3).first_channel(4), Some(12));
+}
+
+#[test]
+fn test_two_units_split_the_port_channels() {
+    let last = DeviceId(1);
+    for channel in 0..4 {
+        assert_eq!(
+            DeviceId::from_channel(channel, 4, last),
+            Some((DeviceId(0), channel as usize))
+        );
+    }
+    for channel in 4..8 {
+        assert_eq!(
+            DeviceId::from_channel(channel, 4, last),
+            Some((DeviceId(1), channel as usize - 4))
+        );
+    }
+    // The master fader comes after both units' strips, and belongs to neither
+    assert_eq!(last.master_fader_channel(4), Some(8));
+    assert_eq!(DeviceId::from_channel(8, 4, last), None);
+    assert_eq!(DeviceId::from_channel(12, 4, last), None);
+}
+
+#[test]
+fn test_two_full_units_leave_no_channel_for_the_master_fader() {
+    let last = DeviceId(1);
+    assert_eq!(DeviceId::from_channel(7, 8, last), Some((DeviceId(0), 7)));
+    assert_eq!(DeviceId::from_channel(8, 8, last), Some((DeviceId(1), 0)));
+    assert_eq!(DeviceId::from_channel(15, 8, last), Some((DeviceId(1), 7)));
+    assert_eq!(last.master_fader_channel(8), None);
+    // A unit alone on its port keeps the master fader on the channel after its strips
+    assert_eq!(DeviceId(0).master_fader_channel(8), Some(8));
+}
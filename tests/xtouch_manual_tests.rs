@@ -7,8 +7,8 @@
 // Run with: cargo test --test xtouch_manual_tests -- --nocapture --test-threads=1
 
 use arpad_rust::midi::xtouch::{
-    ArmLEDMsg, ArmPress, ArmRelease, FaderAbsMsg, LEDState, MuteLEDMsg, MutePress, MuteRelease,
-    SoloLEDMsg, SoloPress, SoloRelease, XTouchDownstreamMsg, XTouchUpstreamMsg,
+    ArmLEDMsg, ArmPress, ArmRelease, ChannelIndex, FaderAbsMsg, LEDState, MuteLEDMsg, MutePress,
+    MuteRelease, SoloLEDMsg, SoloPress, SoloRelease, XTouchDownstreamMsg, XTouchUpstreamMsg,
 };
 use crossbeam_channel::{Receiver, Sender, bounded};
 use std::io::{self, Write};
@@ -122,7 +122,7 @@ fn run_output_tests(tx: &Sender<XTouchDownstreamMsg>) -> Vec<TestSummary> {
         println!("\nTest: {}", test_name);
 
         tx.send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg {
-            idx: channel,
+            idx: ChannelIndex::new(channel),
             value: 0.0,
         }))
         .unwrap();
@@ -139,7 +139,7 @@ fn run_output_tests(tx: &Sender<XTouchDownstreamMsg>) -> Vec<TestSummary> {
         println!("\nTest: {}", test_name);
 
         tx.send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg {
-            idx: channel,
+            idx: ChannelIndex::new(channel),
             value: 1.0,
         }))
         .unwrap();
@@ -156,7 +156,7 @@ fn run_output_tests(tx: &Sender<XTouchDownstreamMsg>) -> Vec<TestSummary> {
         println!("\nTest: {}", test_name);
 
         tx.send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg {
-            idx: channel,
+            idx: ChannelIndex::new(channel),
             value: 0.75, // Approximate unity gain position
         }))
         .unwrap();
@@ -174,7 +174,7 @@ fn run_output_tests(tx: &Sender<XTouchDownstreamMsg>) -> Vec<TestSummary> {
         println!("\nTest: {}", test_name);
 
         tx.send(XTouchDownstreamMsg::MuteLED(MuteLEDMsg {
-            idx: channel,
+            idx: ChannelIndex::new(channel),
             state: LEDState::On,
         }))
         .unwrap();
@@ -191,7 +191,7 @@ fn run_output_tests(tx: &Sender<XTouchDownstreamMsg>) -> Vec<TestSummary> {
         println!("\nTest: {}", test_name);
 
         tx.send(XTouchDownstreamMsg::MuteLED(MuteLEDMsg {
-            idx: channel,
+            idx: ChannelIndex::new(channel),
             state: LEDState::Off,
         }))
         .unwrap();
@@ -209,7 +209,7 @@ fn run_output_tests(tx: &Sender<XTouchDownstreamMsg>) -> Vec<TestSummary> {
         println!("\nTest: {}", test_name);
 
         tx.send(XTouchDownstreamMsg::SoloLED(SoloLEDMsg {
-            idx: channel,
+            idx: ChannelIndex::new(channel),
             state: LEDState::On,
         }))
         .unwrap();
@@ -226,7 +226,7 @@ fn run_output_tests(tx: &Sender<XTouchDownstreamMsg>) -> Vec<TestSummary> {
         println!("\nTest: {}", test_name);
 
         tx.send(XTouchDownstreamMsg::SoloLED(SoloLEDMsg {
-            idx: channel,
+            idx: ChannelIndex::new(channel),
             state: LEDState::Off,
         }))
         .unwrap();
@@ -244,7 +244,7 @@ fn run_output_tests(tx: &Sender<XTouchDownstreamMsg>) -> Vec<TestSummary> {
         println!("\nTest: {}", test_name);
 
         tx.send(XTouchDownstreamMsg::ArmLED(ArmLEDMsg {
-            idx: channel,
+            idx: ChannelIndex::new(channel),
             state: LEDState::On,
         }))
         .unwrap();
@@ -261,7 +261,7 @@ fn run_output_tests(tx: &Sender<XTouchDownstreamMsg>) -> Vec<TestSummary> {
         println!("\nTest: {}", test_name);
 
         tx.send(XTouchDownstreamMsg::ArmLED(ArmLEDMsg {
-            idx: channel,
+            idx: ChannelIndex::new(channel),
             state: LEDState::Off,
         }))
         .unwrap();
@@ -0,0 +1,222 @@
+// Tests for the in-memory XTouch, on its own and standing in for the real surface under the modes
+
+use std::time::Duration;
+
+use crossbeam_channel::{Receiver, Sender, bounded};
+
+use arpad_rust::midi::xtouch::simulator::{
+    ChannelButton, CommandError, EncoderRing, SimulatedXTouch, SurfaceButton,
+};
+use arpad_rust::midi::xtouch::{
+    AssignmentDisplayMsg, ChannelIndex, EncoderRingLEDMsg, EncoderRingLEDRangeFillMsg, FaderAbsMsg,
+    LEDState, MuteLEDMsg, ScribbleColor, ScribbleStripMsg, XTouchDownstreamMsg, XTouchUpstreamMsg,
+};
+use arpad_rust::modes::mode_manager::{Barrier, ModeManager};
+use arpad_rust::track::track::{DataPayload, Direction, TrackDataMsg, TrackMsg};
+
+const TIMEOUT: Duration = Duration::from_secs(1);
+
+fn simulator() -> (
+    SimulatedXTouch,
+    Sender<XTouchDownstreamMsg>,
+    Receiver<XTouchUpstreamMsg>,
+) {
+    let (to_surface, from_modes) = bounded(128);
+    let (to_modes, from_surface) = bounded(128);
+    let xtouch = SimulatedXTouch::start(8, from_modes, to_modes);
+    (xtouch, to_surface, from_surface)
+}
+
+#[test]
+fn test_downstream_messages_update_the_model_before_the_barrier_returns() {
+    let (xtouch, to_surface, from_surface) = simulator();
+    let idx = ChannelIndex::new(2);
+    for msg in [
+        XTouchDownstreamMsg::FaderAbs(FaderAbsMsg { idx, value: 0.25 }),
+        XTouchDownstreamMsg::MuteLED(MuteLEDMsg {
+            idx,
+            state: LEDState::On,
+        }),
+        XTouchDownstreamMsg::EncoderRingLED(EncoderRingLEDMsg::RangeFill(
+            EncoderRingLEDRangeFillMsg { idx, pos: 0.5 },
+        )),
+        XTouchDownstreamMsg::ScribbleStrip(ScribbleStripMsg {
+            idx,
+            top_line: "Drums".to_string(),
+            bottom_line: "-3.0dB".to_string(),
+            color: ScribbleColor::Red,
+        }),
+        XTouchDownstreamMsg::AssignmentDisplay(AssignmentDisplayMsg::mode_bank('V', 0)),
+        XTouchDownstreamMsg::Send(LEDState::Flash),
+    ] {
+        to_surface.send(msg).unwrap();
+    }
+    let barrier = Barrier::new();
+    to_surface
+        .send(XTouchDownstreamMsg::Barrier(barrier))
+        .unwrap();
+    match from_surface.recv_timeout(TIMEOUT) {
+        Ok(XTouchUpstreamMsg::Barrier(reflected)) => assert_eq!(reflected, barrier),
+        _ => panic!("Expected the barrier to be reflected"),
+    }
+
+    let state = xtouch.state();
+    let channel = state.channel(idx);
+    assert_eq!(channel.fader, 0.25);
+    assert_eq!(channel.mute, LEDState::On);
+    assert_eq!(channel.solo, LEDState::Off);
+    assert_eq!(channel.encoder_ring, EncoderRing::Fill(0.5));
+    assert_eq!(channel.top_line, "Drums");
+    assert_eq!(channel.bottom_line, "-3.0dB");
+    assert_eq!(channel.color, ScribbleColor::Red);
+    assert_eq!(state.assignment_display, ['V', '1']);
+    assert_eq!(state.button(SurfaceButton::Send), LEDState::Flash);
+    assert_eq!(state.button(SurfaceButton::Track), LEDState::Off);
+    assert!(state.to_string().contains("2 Drums  |-3.0dB   25.0% M..."));
+}
+
+#[test]
+fn test_messages_past_the_last_channel_are_ignored() {
+    let (xtouch, to_surface, _from_surface) = simulator();
+    let before = xtouch.state();
+    to_surface
+        .send(XTouchDownstreamMsg::FaderAbs(FaderAbsMsg {
+            idx: ChannelIndex::new(8),
+            value: 1.0,
+        }))
+        .unwrap();
+    to_surface
+        .send(XTouchDownstreamMsg::AssignmentDisplay(
+            AssignmentDisplayMsg { text: ['O', 'K'] },
+        ))
+        .unwrap();
+    let after = xtouch
+        .wait_until(TIMEOUT, |state| state.assignment_display == ['O', 'K'])
+        .unwrap();
+    assert_eq!(after.channels, before.channels);
+}
+
+#[test]
+fn test_user_actions_send_upstream_messages() {
+    let (xtouch, _to_surface, from_surface) = simulator();
+    let idx = ChannelIndex::new(1);
+
+    xtouch.move_fader(idx, 0.6);
+    assert_eq!(xtouch.state().channel(idx).fader, 0.6);
+    match from_surface.recv_timeout(TIMEOUT) {
+        Ok(XTouchUpstreamMsg::FaderAbs(msg)) => {
+            assert_eq!(msg.idx, idx);
+            assert_eq!(msg.value, 0.6);
+        }
+        _ => panic!("Expected a fader move"),
+    }
+
+    xtouch.turn_encoder(idx, -2);
+    for _ in 0..2 {
+        assert!(matches!(
+            from_surface.recv_timeout(TIMEOUT),
+            Ok(XTouchUpstreamMsg::EncoderTurnDec(msg)) if msg.idx == idx
+        ));
+    }
+
+    xtouch.tap_channel(ChannelButton::Mute, idx);
+    assert!(matches!(
+        from_surface.recv_timeout(TIMEOUT),
+        Ok(XTouchUpstreamMsg::MutePress(msg)) if msg.idx == idx
+    ));
+    assert!(matches!(
+        from_surface.recv_timeout(TIMEOUT),
+        Ok(XTouchUpstreamMsg::MuteRelease(msg)) if msg.idx == idx
+    ));
+
+    xtouch.tap(SurfaceButton::BankRight);
+    assert!(matches!(
+        from_surface.recv_timeout(TIMEOUT),
+        Ok(XTouchUpstreamMsg::BankRightPress)
+    ));
+    assert!(matches!(
+        from_surface.recv_timeout(TIMEOUT),
+        Ok(XTouchUpstreamMsg::BankRightRelease)
+    ));
+}
+
+#[test]
+fn test_commands_act_out_user_actions() {
+    let (xtouch, _to_surface, from_surface) = simulator();
+    let idx = ChannelIndex::new(3);
+
+    // A scripted fader move is touched, moved and let go, like a hand would
+    xtouch.run_command("fader 3 0.75").unwrap();
+    assert!(matches!(
+        from_surface.recv_timeout(TIMEOUT),
+        Ok(XTouchUpstreamMsg::FaderTouch(msg)) if msg.idx == idx
+    ));
+    assert!(matches!(
+        from_surface.recv_timeout(TIMEOUT),
+        Ok(XTouchUpstreamMsg::FaderAbs(msg)) if msg.idx == idx && msg.value == 0.75
+    ));
+    assert!(matches!(
+        from_surface.recv_timeout(TIMEOUT),
+        Ok(XTouchUpstreamMsg::FaderRelease(msg)) if msg.idx == idx
+    ));
+    assert_eq!(xtouch.state().channel(idx).fader, 0.75);
+
+    xtouch.run_command("  solo 3 ").unwrap();
+    assert!(matches!(
+        from_surface.recv_timeout(TIMEOUT),
+        Ok(XTouchUpstreamMsg::SoloPress(msg)) if msg.idx == idx
+    ));
+
+    assert_eq!(xtouch.run_command(""), Ok(()));
+    assert_eq!(
+        xtouch.run_command("wiggle 3"),
+        Err(CommandError::Unknown("wiggle".to_string()))
+    );
+    assert_eq!(
+        xtouch.run_command("fader 3 loud"),
+        Err(CommandError::InvalidArgument(2))
+    );
+    assert_eq!(
+        xtouch.run_command("encoder"),
+        Err(CommandError::InvalidArgument(1))
+    );
+}
+
+#[test]
+fn test_modes_drive_the_simulated_surface() {
+    let (to_modes, from_reaper) = bounded(128);
+    let (to_reaper, from_modes) = bounded(128);
+    let (to_surface, from_modes_to_surface) = bounded(128);
+    let (surface_to_modes, from_surface) = bounded(128);
+    let xtouch = SimulatedXTouch::start(8, from_modes_to_surface, surface_to_modes);
+    ModeManager::start(from_reaper, to_reaper, from_surface, to_surface);
+
+    for data in [
+        DataPayload::ReaperTrackIndex(Some(0)),
+        DataPayload::Name("Drums".to_string()),
+    ] {
+        to_modes
+            .send(TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid: "drums".into(),
+                direction: Direction::Downstream,
+                data,
+            }))
+            .unwrap();
+    }
+    let state = xtouch.wait_until(TIMEOUT, |state| state.channels[0].top_line == "Drums");
+    assert!(state.is_some(), "{}", xtouch.state());
+
+    xtouch.move_fader(ChannelIndex::new(0), 0.5);
+    // Anything else the modes send Reaper on the way is skipped; a missing volume times out
+    let volume = std::iter::from_fn(|| from_modes.recv_timeout(TIMEOUT).ok())
+        .find_map(|msg| match msg {
+            TrackMsg::TrackDataMsg(TrackDataMsg {
+                guid,
+                direction: Direction::Upstream,
+                data: DataPayload::Volume(volume),
+            }) if guid == "drums" => Some(volume),
+            _ => None,
+        })
+        .expect("Expected the fader move to reach Reaper");
+    assert!(volume > 0.0);
+}
@@ -0,0 +1,5421 @@
+//! Generates the Rust client and the .ReaperOSC config from the OSC spec, and loads the spec for
+//! anything else that wants to know what's in it.
+
+use clap::{Parser, ValueEnum};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt::{Display, Write};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// What to generate from the spec
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Emit {
+    /// The Rust client
+    Rust,
+    /// A .ReaperOSC pattern config telling Reaper which addresses to use
+    Reaperosc,
+}
+
+/// What Set does with an argument outside the range the spec gives it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+enum OutOfRange {
+    /// Bring it into range. Values outside an enum can't be, so they're still an error.
+    #[default]
+    Clamp,
+    /// Fail with OscError::OutOfRange
+    Error,
+}
+
+/// What to generate, and from which spec
+#[derive(Parser)]
+pub struct GenArgs {
+    /// Path to the OSC YAML spec file
+    pub spec: PathBuf,
+    /// Output file
+    #[clap(short, long, default_value = "generated_osc.rs")]
+    out: PathBuf,
+    /// Kind of output to generate
+    #[clap(long, value_enum, default_value = "rust")]
+    emit: Emit,
+    /// Emit async Set/Query impls backed by tokio::net::UdpSocket
+    #[clap(long = "async")]
+    async_client: bool,
+    /// Write a module directory at OUT (mod.rs, context.rs, dispatcher.rs and a file per
+    /// top-level node) instead of a single file
+    #[clap(long)]
+    split: bool,
+    /// Keep bound handlers in the Reaper, requiring them to be Send, so one Reaper can be shared
+    /// between threads
+    #[clap(long, conflicts_with = "async_client")]
+    thread_safe: bool,
+    /// Derive serde::Serialize on the Args structs. MIDI arguments are left out, since rosc
+    /// can't serialize them.
+    #[clap(long)]
+    serialize: bool,
+    /// Emit a test module round-tripping a value through each route that can be both set and
+    /// bound. Bound handlers are only reached through the dispatcher when they're thread-safe.
+    #[clap(long, requires = "thread_safe")]
+    tests: bool,
+    /// What Set does with an argument outside its min, max or enum. Out-of-range values Reaper
+    /// sends are always dropped.
+    #[clap(long, value_enum, default_value = "clamp")]
+    out_of_range: OutOfRange,
+}
+
+/// Knobs that change the shape of the generated code
+#[derive(Debug, Clone, Copy, Default)]
+struct CodegenOptions {
+    async_client: bool,
+    split: bool,
+    thread_safe: bool,
+    serialize: bool,
+    tests: bool,
+    out_of_range: OutOfRange,
+}
+
+impl CodegenOptions {
+    /// Type the generated endpoints use to talk to Reaper
+    fn socket_type(&self) -> &'static str {
+        if self.async_client {
+            "UdpSocket"
+        } else {
+            "dyn OscTransport"
+        }
+    }
+
+    /// Visibility of the endpoint internals the dispatcher reaches into, which live in sibling
+    /// modules when the output is split
+    fn internal_visibility(&self) -> &'static str {
+        if self.split {
+            "pub(super) "
+        } else {
+            ""
+        }
+    }
+
+    /// Type of the Reaper the dispatcher hands messages to. A thread-safe Reaper keeps its state
+    /// behind locks, so it can dispatch while other threads hold it too.
+    fn dispatch_receiver(&self) -> &'static str {
+        if self.thread_safe {
+            "&Reaper"
+        } else {
+            "&mut Reaper"
+        }
+    }
+}
+
+/// An argument type from the YAML spec: a scalar name, or `array<T>` for a homogeneous array
+#[derive(Debug, Clone, PartialEq)]
+enum ArgType {
+    Int,
+    Long,
+    Float,
+    Double,
+    Bool,
+    String,
+    Blob,
+    Midi,
+    Array(Box<ArgType>),
+}
+
+impl ArgType {
+    fn parse(yaml_type: &str) -> Option<ArgType> {
+        let arg_type = match yaml_type {
+            "int" => ArgType::Int,
+            "long" => ArgType::Long,
+            "float" => ArgType::Float,
+            "double" => ArgType::Double,
+            "bool" => ArgType::Bool,
+            "string" => ArgType::String,
+            "blob" => ArgType::Blob,
+            "midi" => ArgType::Midi,
+            _ => {
+                let item = yaml_type.strip_prefix("array<")?.strip_suffix('>')?;
+                ArgType::Array(Box::new(ArgType::parse(item)?))
+            }
+        };
+        Some(arg_type)
+    }
+
+    /// Like parse, but for types the spec has already committed to
+    fn of(yaml_type: &str) -> ArgType {
+        ArgType::parse(yaml_type)
+            .unwrap_or_else(|| panic!("Unsupported OSC type '{}' in spec", yaml_type))
+    }
+
+    fn rust_type(&self) -> String {
+        match self {
+            ArgType::Int => "i32".to_string(),
+            ArgType::Long => "i64".to_string(),
+            ArgType::Float => "f32".to_string(),
+            ArgType::Double => "f64".to_string(),
+            ArgType::Bool => "bool".to_string(),
+            ArgType::String => "String".to_string(),
+            ArgType::Blob => "Vec<u8>".to_string(),
+            ArgType::Midi => "rosc::OscMidiMessage".to_string(),
+            ArgType::Array(item) => format!("Vec<{}>", item.rust_type()),
+        }
+    }
+
+    /// Whether serde can serialize the Rust type; rosc's MIDI messages don't implement it
+    fn is_serializable(&self) -> bool {
+        match self {
+            ArgType::Midi => false,
+            ArgType::Array(item) => item.is_serializable(),
+            _ => true,
+        }
+    }
+
+    /// The rosc::OscType variant holding this type
+    fn variant(&self) -> &str {
+        match self {
+            ArgType::Int => "Int",
+            ArgType::Long => "Long",
+            ArgType::Float => "Float",
+            ArgType::Double => "Double",
+            ArgType::Bool => "Bool",
+            ArgType::String => "String",
+            ArgType::Blob => "Blob",
+            ArgType::Midi => "Midi",
+            ArgType::Array(_) => "Array",
+        }
+    }
+
+    /// The ParamValue variants a value of this type can be set from, each with the cast turning
+    /// what it holds into this type; None for types ParamValue can't hold
+    fn param_value(&self) -> Option<&'static [(&'static str, &'static str)]> {
+        match self {
+            ArgType::Int => Some(&[("Int", "")]),
+            ArgType::Long => Some(&[("Int", " as i64")]),
+            // Either width sets either, so callers needn't know which the spec picked; a double
+            // is set without going through f32 when the value is one too
+            ArgType::Float => Some(&[("Float", ""), ("Double", " as f32")]),
+            ArgType::Double => Some(&[("Double", ""), ("Float", " as f64")]),
+            ArgType::Bool => Some(&[("Bool", "")]),
+            ArgType::String => Some(&[("String", "")]),
+            _ => None,
+        }
+    }
+
+    /// Expression wrapping an owned value of this type in a rosc::OscType
+    fn encode(&self, value: &str) -> String {
+        match self {
+            ArgType::Array(item) => format!(
+                "rosc::OscType::Array(rosc::OscArray {{ content: {}.into_iter().map({}).collect() }})",
+                value,
+                item.encoder()
+            ),
+            _ => format!("rosc::OscType::{}({})", self.variant(), value),
+        }
+    }
+
+    /// An expression for some value of this type, for the generated tests
+    fn sample(&self) -> String {
+        match self {
+            ArgType::Int | ArgType::Long => "7".to_string(),
+            ArgType::Float | ArgType::Double => "0.5".to_string(),
+            ArgType::Bool => "true".to_string(),
+            ArgType::String => "\"test\".to_string()".to_string(),
+            ArgType::Blob => "vec![1, 2, 3]".to_string(),
+            ArgType::Midi => {
+                "rosc::OscMidiMessage { port: 0, status: 0x90, data1: 60, data2: 100 }".to_string()
+            }
+            ArgType::Array(item) => format!("vec![{0}, {0}]", item.sample()),
+        }
+    }
+
+    /// Function turning an owned value of this type into a rosc::OscType
+    fn encoder(&self) -> String {
+        match self {
+            ArgType::Array(_) => format!("|items: {}| {}", self.rust_type(), self.encode("items")),
+            _ => format!("rosc::OscType::{}", self.variant()),
+        }
+    }
+}
+
+/// Convert a YAML type like "int" or "array<string>" to its Rust type
+fn rust_type(yaml_type: &str) -> String {
+    ArgType::of(yaml_type).rust_type()
+}
+
+/// Sanitize a path segment to be a valid Rust identifier
+fn sanitize_path_level(s: &str) -> String {
+    s.replace("-", "_")
+        .replace(" ", "_")
+        .replace(".", "_")
+        .replace("/", "_")
+        .replace("?", "_")
+        .replace("$", "_")
+}
+
+/// PascalCase a sanitized identifier (for struct names)
+fn pascal_case(s: String) -> String {
+    s.split('_')
+        .filter(|p| !p.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(f) => f.to_uppercase().collect::<String>() + chars.as_str(),
+                None => "".to_string(),
+            }
+        })
+        .collect::<String>()
+}
+
+/// Module the newtypes named by a param's rust_type live in
+const PARAM_TYPES_MODULE: &str = "crate::guid";
+
+// OSC param as represented in the YAML
+#[derive(Debug, Deserialize, Clone)]
+struct OscParam {
+    name: String,
+    #[serde(rename = "type")]
+    typ: String,
+    description: Option<String>,
+    /// Newtype from PARAM_TYPES_MODULE the param is held in, in place of a plain String; only
+    /// string params can have one
+    #[serde(default)]
+    rust_type: Option<String>,
+}
+
+impl OscParam {
+    /// The Rust type the param is held in
+    fn rust_type(&self) -> String {
+        match &self.rust_type {
+            Some(name) => format!("{}::{}", PARAM_TYPES_MODULE, name),
+            None => rust_type(&self.typ),
+        }
+    }
+}
+
+impl Display for OscParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "OscParam {{ name: {}, type: {} }}", self.name, self.typ)
+    }
+}
+
+// OSC argument as represented in the YAML
+#[derive(Debug, Deserialize, Clone)]
+struct OscArgument {
+    name: String,
+    #[serde(rename = "type")]
+    typ: String,
+    description: Option<String>,
+    /// May be left off the end of a message
+    #[serde(default)]
+    optional: bool,
+    /// Value to use when the argument is left off; implies optional
+    default: Option<serde_yaml::Value>,
+    /// Lowest value the argument can take; only numeric arguments can have one
+    #[serde(default)]
+    min: Option<serde_yaml::Value>,
+    /// Highest value the argument can take; only numeric arguments can have one
+    #[serde(default)]
+    max: Option<serde_yaml::Value>,
+    /// The only values the argument can take; only int, long and string arguments can have them
+    #[serde(default, rename = "enum")]
+    one_of: Option<Vec<serde_yaml::Value>>,
+    /// Where incoming messages carry the argument, for peers that don't send them in the order
+    /// they're listed; messages sent keep that order
+    #[serde(default)]
+    arg_index: Option<usize>,
+    /// For string arguments that needn't be UTF-8, like names typed into Reaper: they're handed
+    /// over as the bytes received, and sent as strings of the same bytes
+    #[serde(default)]
+    binary: bool,
+}
+
+impl OscArgument {
+    fn field_name(&self) -> String {
+        sanitize_path_level(&self.name)
+    }
+
+    /// Optional arguments without a default are only known when they're present
+    fn is_omittable(&self) -> bool {
+        self.optional && self.default.is_none()
+    }
+
+    fn arg_type(&self) -> ArgType {
+        ArgType::of(&self.typ)
+    }
+
+    /// The Rust type of the argument's value
+    fn rust_type(&self) -> String {
+        match self.binary {
+            true => "Vec<u8>".to_string(),
+            false => rust_type(&self.typ),
+        }
+    }
+
+    fn field_type(&self) -> String {
+        match self.is_omittable() {
+            true => format!("Option<{}>", self.rust_type()),
+            false => self.rust_type(),
+        }
+    }
+
+    /// Expression wrapping an owned value of the argument in a rosc::OscType
+    fn encode(&self, value: &str) -> String {
+        match self.binary {
+            true => format!("decode::binary_arg({})", value),
+            false => self.arg_type().encode(value),
+        }
+    }
+
+    /// Function turning an owned value of the argument into a rosc::OscType
+    fn encoder(&self) -> String {
+        match self.binary {
+            true => "decode::binary_arg".to_string(),
+            false => self.arg_type().encoder(),
+        }
+    }
+
+    /// The default as a Rust expression of the argument's type
+    fn default_expr(&self) -> Option<String> {
+        self.checked_default_expr().unwrap_or_else(|| {
+            panic!(
+                "Default {:?} doesn't match the type of argument {}",
+                self.default, self
+            )
+        })
+    }
+
+    /// Like default_expr, but None if the default doesn't match the argument's type
+    fn checked_default_expr(&self) -> Option<Option<String>> {
+        let Some(default) = self.default.as_ref() else {
+            return Some(None);
+        };
+        let expr = match self.arg_type() {
+            ArgType::String => format!("{}.to_string()", self.literal(default)?),
+            _ => self.literal(default)?,
+        };
+        Some(Some(expr))
+    }
+
+    /// A value from the spec as a Rust literal of the argument's type, with strings left as
+    /// &str; None if it doesn't match the type
+    fn literal(&self, value: &serde_yaml::Value) -> Option<String> {
+        let literal = match (self.arg_type(), value) {
+            (ArgType::Int | ArgType::Long, serde_yaml::Value::Number(n)) if n.is_i64() => {
+                n.to_string()
+            }
+            (ArgType::Float, serde_yaml::Value::Number(n)) => {
+                format!("{:?}", n.as_f64().unwrap() as f32)
+            }
+            (ArgType::Double, serde_yaml::Value::Number(n)) => {
+                format!("{:?}", n.as_f64().unwrap())
+            }
+            (ArgType::Bool, serde_yaml::Value::Bool(b)) => b.to_string(),
+            (ArgType::String, serde_yaml::Value::String(s)) => format!("{:?}", s),
+            _ => return None,
+        };
+        Some(literal)
+    }
+
+    fn has_range(&self) -> bool {
+        self.min.is_some() || self.max.is_some()
+    }
+
+    /// Whether the spec limits the values the argument can take
+    fn is_constrained(&self) -> bool {
+        self.has_range() || self.one_of.is_some()
+    }
+
+    /// The min and max as Rust expressions for the range helpers
+    fn range_exprs(&self) -> (String, String) {
+        let bound = |value: &Option<serde_yaml::Value>| match value {
+            Some(value) => format!("Some({})", self.literal(value).unwrap()),
+            None => "None".to_string(),
+        };
+        (bound(&self.min), bound(&self.max))
+    }
+
+    /// The allowed values as a Rust slice for the range helpers
+    fn one_of_expr(&self) -> Option<String> {
+        let values: Vec<String> = self
+            .one_of
+            .as_ref()?
+            .iter()
+            .map(|value| self.literal(value).unwrap())
+            .collect();
+        Some(format!("&[{}]", values.join(", ")))
+    }
+
+    /// Some value the argument can take, for the generated tests
+    fn sample(&self) -> String {
+        let allowed = self
+            .min
+            .as_ref()
+            .or(self.max.as_ref())
+            .or(self.one_of.as_ref().and_then(|values| values.first()));
+        match (allowed, self.arg_type()) {
+            (Some(value), ArgType::String) => {
+                format!("{}.to_string()", self.literal(value).unwrap())
+            }
+            (Some(value), _) => self.literal(value).unwrap(),
+            (None, _) if self.binary => "b\"test\".to_vec()".to_string(),
+            (None, arg_type) => arg_type.sample(),
+        }
+    }
+}
+
+impl Display for OscArgument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OscArgument {{ name: {}, type: {} }}",
+            self.name, self.typ
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[serde(rename_all = "lowercase")]
+enum AccessTag {
+    Readable,
+    Writeable,
+    Queryable,
+}
+
+impl Display for AccessTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccessTag::Readable => write!(f, "readable"),
+            AccessTag::Writeable => write!(f, "writeable"),
+            AccessTag::Queryable => write!(f, "queryable"),
+        }
+    }
+}
+
+/// OSC route as represented in the YAML
+#[derive(Debug, Deserialize, Clone)]
+pub struct OscRoute {
+    osc_address: String,
+    params: Vec<OscParam>,
+    arguments: Vec<OscArgument>,
+    access_tags: BTreeSet<AccessTag>,
+    /// Name of the route's accessor in snake_case, which its types are named after too, in place
+    /// of the one made up from its address
+    #[serde(default)]
+    rust_name: Option<String>,
+}
+
+impl Display for OscRoute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OscRoute {{ osc_address: {}, arguments: {:?}, access_tags: {:?} }}",
+            self.osc_address, self.arguments, self.access_tags
+        )
+    }
+}
+
+impl OscRoute {
+    /// The route's address, with a `{param}` segment standing in for each param
+    pub fn osc_address(&self) -> &str {
+        &self.osc_address
+    }
+
+    /// OSC arguments are positional, so an argument can only be left off if every argument after
+    /// it can be too
+    fn check_arguments(&self) {
+        if let Some(arg) = self.misplaced_required_argument() {
+            panic!(
+                "Argument {} of {} must be optional since it follows an optional argument",
+                arg.name, self.osc_address
+            );
+        }
+    }
+
+    // The first required argument that comes after an optional one
+    /// Whether any argument is given a place in incoming messages, rather than lined up with
+    /// them by type
+    fn has_arg_indices(&self) -> bool {
+        self.arguments.iter().any(|arg| arg.arg_index.is_some())
+    }
+
+    /// Where each argument is in incoming messages
+    fn arg_positions(&self) -> Vec<usize> {
+        self.arguments
+            .iter()
+            .enumerate()
+            .map(|(j, arg)| arg.arg_index.unwrap_or(j))
+            .collect()
+    }
+
+    fn misplaced_required_argument(&self) -> Option<&OscArgument> {
+        let first_omittable = self.arguments.iter().position(|arg| arg.is_omittable())?;
+        self.arguments[first_omittable..]
+            .iter()
+            .find(|arg| !arg.is_omittable())
+    }
+
+    /// Whether queries to this route can be answered with its Args. Only the sync client tracks
+    /// pending responses.
+    fn has_response(&self, options: &CodegenOptions) -> bool {
+        !options.async_client
+            && self.access_tags.contains(&AccessTag::Queryable)
+            && self.access_tags.contains(&AccessTag::Readable)
+    }
+
+    /// Where the route's binary arguments are among those of its messages
+    fn binary_args(&self) -> Vec<usize> {
+        self.arguments
+            .iter()
+            .enumerate()
+            .filter(|(_, arg)| arg.binary)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    fn struct_name(&self) -> String {
+        if let Some(rust_name) = &self.rust_name {
+            return pascal_case(rust_name.clone());
+        }
+        let mut name = String::new();
+        let parts: Vec<_> = self
+            .osc_address
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(sanitize_path_level)
+            .collect();
+        let mut i = 0;
+        while i < parts.len() {
+            let part = parts[i].clone();
+            // If this segment is not a wildcard, and the next segment exists and is a wildcard, include it
+            if !part.starts_with('{') && !part.ends_with('}') {
+                let mut chars = part.chars();
+                if let Some(first) = chars.next() {
+                    name.extend(first.to_uppercase());
+                    name.push_str(chars.as_str());
+                }
+            }
+            i += 1;
+        }
+        pascal_case(name)
+    }
+
+    /// Name of the action this route is bound to in a .ReaperOSC file, e.g. TRACK_VOLUME
+    fn reaperosc_action(&self) -> String {
+        self.accessor_name().to_uppercase()
+    }
+
+    /// The .ReaperOSC pattern for this route, with each param replaced by Reaper's `@` wildcard
+    /// and prefixed by the type of the value it carries. None if Reaper has no type for its
+    /// arguments.
+    fn reaperosc_pattern(&self) -> Option<String> {
+        let prefix = match self.arguments.as_slice() {
+            [] => "t",
+            [arg, ..] => match arg.arg_type() {
+                ArgType::Int | ArgType::Long => "i",
+                ArgType::Float | ArgType::Double => "f",
+                ArgType::Bool => "b",
+                ArgType::String => "s",
+                ArgType::Blob | ArgType::Midi | ArgType::Array(_) => return None,
+            },
+        };
+        let address = self
+            .osc_address
+            .split('/')
+            .map(
+                |segment| match segment.starts_with('{') && segment.ends_with('}') {
+                    true => "@",
+                    false => segment,
+                },
+            )
+            .collect::<Vec<_>>()
+            .join("/");
+        Some(format!("{}{}", prefix, address))
+    }
+
+    fn accessor_name(&self) -> String {
+        if let Some(rust_name) = &self.rust_name {
+            return rust_name.clone();
+        }
+        let mut name = String::new();
+        let parts: Vec<_> = self
+            .osc_address
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(sanitize_path_level)
+            .collect();
+        let mut i = 0;
+        while i < parts.len() {
+            let part = parts[i].clone();
+            // If this segment is not a wildcard, and the next segment exists and is a wildcard, include it
+            if !part.starts_with('{') && !part.ends_with('}') {
+                if i > 0 {
+                    name.push('_');
+                }
+                name.push_str(&part);
+            }
+            i += 1;
+        }
+        name.to_lowercase()
+    }
+}
+
+/// A name for the route made up from every segment of its address, path parameters included,
+/// e.g. "/track/{track_guid}/fx" -> "track_track_guid_fx"
+fn full_rust_name(osc_address: &str) -> String {
+    osc_address
+        .split('/')
+        .map(|segment| segment.trim_start_matches('{').trim_end_matches('}'))
+        .map(sanitize_path_level)
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+        .to_lowercase()
+}
+
+/// Tells apart routes whose addresses make up the same type name, e.g. "/track/fx" and
+/// "/track/{track_guid}/fx", by naming each of them after their whole address instead. Routes
+/// with a rust_name in the spec keep it.
+///
+/// Every route sharing a name is renamed, not just the later ones, so a route's name doesn't
+/// depend on where it is in the spec.
+fn resolve_names(routes: &mut [OscRoute]) {
+    let mut by_name: BTreeMap<String, usize> = BTreeMap::new();
+    for route in routes.iter() {
+        *by_name.entry(route.struct_name()).or_default() += 1;
+    }
+    for route in routes.iter_mut() {
+        if route.rust_name.is_none() && by_name[&route.struct_name()] > 1 {
+            route.rust_name = Some(full_rust_name(&route.osc_address));
+        }
+    }
+}
+
+/// A problem with a route in the spec, found before any code is generated
+#[derive(Debug, PartialEq)]
+pub struct SpecError {
+    osc_address: String,
+    message: String,
+}
+
+impl Display for SpecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.osc_address, self.message)
+    }
+}
+
+/// Types a path parameter can have, since they're parsed out of the address
+const PATH_PARAM_TYPES: [&str; 5] = ["int", "float", "double", "bool", "string"];
+
+/// Checks the whole spec, returning every problem found rather than stopping at the first.
+///
+/// Anything reported here would otherwise only show up as a panic halfway through codegen, or as
+/// generated code that doesn't compile.
+fn validate(routes: &[OscRoute]) -> Vec<SpecError> {
+    let mut errors = Vec::new();
+    for route in routes {
+        let mut error = |message: String| {
+            errors.push(SpecError {
+                osc_address: route.osc_address.clone(),
+                message,
+            })
+        };
+        if !route.osc_address.starts_with('/') {
+            error("the address must start with '/'".to_string());
+        }
+        if let Some(rust_name) = &route.rust_name {
+            if !is_snake_case(rust_name) {
+                error(format!(
+                    "rust_name {:?} must be snake_case, starting with a letter",
+                    rust_name
+                ));
+            }
+        }
+        if route.access_tags.is_empty() {
+            error(
+                "no access tags; it needs at least one of readable, writeable or queryable"
+                    .to_string(),
+            );
+        }
+
+        let placeholders = extract_context_params(route);
+        for placeholder in &placeholders {
+            if !route
+                .params
+                .iter()
+                .any(|param| param.name == placeholder.name)
+            {
+                error(format!(
+                    "path parameter {{{}}} isn't declared in params",
+                    placeholder.name
+                ));
+            }
+        }
+        let mut param_names = HashSet::new();
+        for param in &route.params {
+            if !param_names.insert(&param.name) {
+                error(format!("param {} is declared more than once", param.name));
+            }
+            if !placeholders
+                .iter()
+                .any(|placeholder| placeholder.name == param.name)
+            {
+                error(format!(
+                    "param {} doesn't appear in the address",
+                    param.name
+                ));
+            }
+            if !PATH_PARAM_TYPES.contains(&param.typ.as_str()) {
+                error(format!(
+                    "param {} has type '{}', but path parameters must be one of {}",
+                    param.name,
+                    param.typ,
+                    PATH_PARAM_TYPES.join(", ")
+                ));
+            }
+            if let Some(newtype) = &param.rust_type {
+                if param.typ != "string" {
+                    error(format!(
+                        "param {} has rust_type {:?}, but only string params can have one",
+                        param.name, newtype
+                    ));
+                }
+                if !is_pascal_case(newtype) {
+                    error(format!(
+                        "rust_type {:?} of param {} must be a PascalCase type name",
+                        newtype, param.name
+                    ));
+                }
+            }
+        }
+
+        let mut argument_names = HashSet::new();
+        for arg in &route.arguments {
+            if !argument_names.insert(arg.field_name()) {
+                error(format!("argument {} is declared more than once", arg.name));
+            }
+            if ArgType::parse(&arg.typ).is_none() {
+                error(format!(
+                    "argument {} has unknown type '{}'",
+                    arg.name, arg.typ
+                ));
+                continue;
+            }
+            if arg.checked_default_expr().is_none() {
+                error(format!(
+                    "the default of argument {} doesn't match its type '{}'",
+                    arg.name, arg.typ
+                ));
+            }
+            for message in validate_constraints(arg) {
+                error(message);
+            }
+        }
+        let mut positions = HashSet::new();
+        for (arg, position) in route.arguments.iter().zip(route.arg_positions()) {
+            if !positions.insert(position) {
+                error(format!(
+                    "argument {} is at index {} like another argument is",
+                    arg.name, position
+                ));
+            }
+        }
+        if let Some(arg) = route.misplaced_required_argument() {
+            error(format!(
+                "argument {} must be optional since it follows an optional argument",
+                arg.name
+            ));
+        }
+    }
+    errors.extend(validate_duplicates(routes));
+    errors.extend(validate_context_names(routes));
+    errors
+}
+
+// Checks the min, max and enum of an argument against its type and each other
+fn validate_constraints(arg: &OscArgument) -> Vec<String> {
+    let mut errors = Vec::new();
+    let arg_type = arg.arg_type();
+    let numeric = matches!(
+        arg_type,
+        ArgType::Int | ArgType::Long | ArgType::Float | ArgType::Double
+    );
+    for (key, bound) in [("min", &arg.min), ("max", &arg.max)] {
+        let Some(bound) = bound else {
+            continue;
+        };
+        if !numeric {
+            errors.push(format!(
+                "argument {} has a {}, but only numeric arguments can have one",
+                arg.name, key
+            ));
+        } else if arg.literal(bound).is_none() {
+            errors.push(format!(
+                "the {} of argument {} doesn't match its type '{}'",
+                key, arg.name, arg.typ
+            ));
+        }
+    }
+    if let (Some(min), Some(max)) = (
+        arg.min.as_ref().and_then(serde_yaml::Value::as_f64),
+        arg.max.as_ref().and_then(serde_yaml::Value::as_f64),
+    ) {
+        if min > max {
+            errors.push(format!(
+                "argument {} has a min of {} above its max of {}",
+                arg.name, min, max
+            ));
+        }
+    }
+    if let Some(values) = &arg.one_of {
+        if !matches!(arg_type, ArgType::Int | ArgType::Long | ArgType::String) {
+            errors.push(format!(
+                "argument {} has an enum, but only int, long and string arguments can have one",
+                arg.name
+            ));
+        } else if values.is_empty() {
+            errors.push(format!("the enum of argument {} is empty", arg.name));
+        } else if values.iter().any(|value| arg.literal(value).is_none()) {
+            errors.push(format!(
+                "the enum of argument {} has values that don't match its type '{}'",
+                arg.name, arg.typ
+            ));
+        }
+        if arg.has_range() {
+            errors.push(format!(
+                "argument {} has both an enum and a min or max",
+                arg.name
+            ));
+        }
+    }
+    if arg.binary {
+        if arg_type != ArgType::String {
+            errors.push(format!(
+                "argument {} is binary, but only string arguments can be",
+                arg.name
+            ));
+        } else if arg.default.is_some() || arg.one_of.is_some() {
+            errors.push(format!(
+                "argument {} is binary, so it can't have a default or an enum",
+                arg.name
+            ));
+        }
+    }
+    errors
+}
+
+fn is_snake_case(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_uppercase()) && chars.all(|c| c.is_ascii_alphanumeric())
+}
+
+// Contexts under different addresses that would be generated into the same types
+fn validate_context_names(routes: &[OscRoute]) -> Vec<SpecError> {
+    let mut errors = Vec::new();
+    let names = resolve_context_names(routes);
+    let mut by_name: BTreeMap<&String, &OscRoute> = BTreeMap::new();
+    let mut seen = HashSet::new();
+    for route in routes {
+        let Some(prefix) = context_prefix(&route.osc_address) else {
+            continue;
+        };
+        if !seen.insert(prefix.clone()) {
+            continue;
+        }
+        let name = &names[&prefix];
+        match by_name.get(name) {
+            Some(first) => errors.push(SpecError {
+                osc_address: route.osc_address.clone(),
+                message: format!(
+                    "generates the context {} like {} does",
+                    name, first.osc_address
+                ),
+            }),
+            None => {
+                by_name.insert(name, route);
+            }
+        }
+    }
+    errors
+}
+
+// Routes that would be generated twice, or into the same types
+fn validate_duplicates(routes: &[OscRoute]) -> Vec<SpecError> {
+    let mut errors = Vec::new();
+    let mut by_shape: BTreeMap<String, &OscRoute> = BTreeMap::new();
+    let mut by_struct_name: BTreeMap<String, &OscRoute> = BTreeMap::new();
+    let placeholder = Regex::new(r"\{[^}]+\}").unwrap();
+    for route in routes {
+        // Placeholder names don't matter to the dispatcher, so they don't make routes distinct
+        let shape = placeholder
+            .replace_all(&route.osc_address, "{}")
+            .to_string();
+        if let Some(first) = by_shape.get(&shape) {
+            let message = match first.access_tags == route.access_tags {
+                true => format!("duplicates {}", first.osc_address),
+                false => format!(
+                    "duplicates {} with conflicting access tags [{}] and [{}]",
+                    first.osc_address,
+                    sorted_tags(&first.access_tags),
+                    sorted_tags(&route.access_tags)
+                ),
+            };
+            errors.push(SpecError {
+                osc_address: route.osc_address.clone(),
+                message,
+            });
+            continue;
+        }
+        by_shape.insert(shape, route);
+
+        if let Some(first) = by_struct_name.get(&route.struct_name()) {
+            errors.push(SpecError {
+                osc_address: route.osc_address.clone(),
+                message: format!(
+                    "generates the type {} like {} does; give one of them a rust_name",
+                    route.struct_name(),
+                    first.osc_address
+                ),
+            });
+            continue;
+        }
+        by_struct_name.insert(route.struct_name(), route);
+    }
+    errors
+}
+
+fn sorted_tags(tags: &BTreeSet<AccessTag>) -> String {
+    let mut tags: Vec<String> = tags.iter().map(|tag| tag.to_string()).collect();
+    tags.sort();
+    tags.join(", ")
+}
+
+#[derive(Debug)]
+struct ContextParam {
+    name: String,
+    typ: String,
+}
+
+fn write_imports(code: &mut String, options: &CodegenOptions) {
+    code.push_str("// AUTO-GENERATED CODE. DO NOT EDIT!\n\n");
+    if options.async_client {
+        code.push_str("use std::net::SocketAddr;\n");
+        code.push_str("use std::sync::Arc;\n\n");
+        code.push_str("use tokio::net::UdpSocket;\n\n");
+        code.push_str(
+            "use crate::traits::{AsyncQuery, AsyncSet, Bind, Subscribe, Subscription};\n\n",
+        );
+    } else {
+        code.push_str("use std::collections::HashMap;\n");
+        code.push_str("use std::net::SocketAddr;\n");
+        code.push_str("use std::sync::{Arc, Mutex};\n\n");
+        code.push_str("use crossbeam_channel::{Receiver, bounded};\n\n");
+        code.push_str("use crate::osc::transport::OscTransport;\n");
+        code.push_str(
+            "use crate::traits::{Bind, Set, SetMessage, Query, QueryWithResponse, Subscribe, Subscription};\n\n",
+        );
+    }
+
+    code.push_str("use crate::osc::decode::{self, Unhandled};\n");
+    code.push_str("use crate::osc::error::{OscError, check_address_segment};\n");
+    code.push_str("use crate::osc::pattern::{is_pattern, match_address};\n");
+    code.push_str("use crate::osc::route_context::{ContextTrait};\n\n");
+
+    if !options.async_client {
+        code.push_str(
+            "/// Called with the first message to arrive on the address a query is waiting on\n",
+        );
+        code.push_str("type ResponseWaiter = Box<dyn FnOnce(&rosc::OscMessage) + Send>;\n\n");
+        code.push_str("/// Queries waiting for a response, by the concrete OSC address the response arrives on\n");
+        code.push_str(
+            "pub type PendingResponses = Arc<Mutex<HashMap<String, Vec<ResponseWaiter>>>>;\n\n",
+        );
+    }
+    if options.thread_safe {
+        code.push_str("/// A handler bound to one concrete address, parsing the message for the endpoint's own handler\n");
+        code.push_str(
+            "type BoundHandler = Arc<Mutex<Box<dyn FnMut(&rosc::OscMessage) + Send>>>;\n\n",
+        );
+        code.push_str("/// Every bound handler and its subscription, by the concrete OSC address it was bound on\n");
+        code.push_str(
+            "pub type Handlers = Arc<Mutex<HashMap<String, Vec<(Subscription, BoundHandler)>>>>;\n\n",
+        );
+    }
+}
+
+// Helper to extract wildcard path segments as context keys
+fn extract_context_params(route: &OscRoute) -> Vec<ContextParam> {
+    let mut keys = Vec::new();
+    let re = Regex::new(r"\{([^}]+)\}").unwrap();
+    for cap in re.captures_iter(&route.osc_address) {
+        let name = cap[1].to_string();
+        let ty = route
+            .params
+            .iter()
+            .find(|a| a.name == *name)
+            .map(OscParam::rust_type)
+            .unwrap_or_else(|| "String".to_string());
+        keys.push(ContextParam { name, typ: ty });
+    }
+    keys
+}
+
+/// Helper to build a context name from the OSC path, e.g.
+/// "/track/{track_guid}/send/{send_index}/guid" -> "TrackSend"
+/// "/track/{track_guid}/index" -> "Track"
+/// "/track/{track_guid}/send/{send_index}/volume" -> "TrackSend"
+fn build_context_name(osc_address: &str) -> String {
+    let mut name = String::new();
+    let parts: Vec<_> = osc_address.split('/').filter(|s| !s.is_empty()).collect();
+    let mut i = 0;
+    while i < parts.len() {
+        let part = parts[i];
+        // If this segment is not a wildcard, and the next segment exists and is a wildcard, include it
+        let next_is_wildcard = parts
+            .get(i + 1)
+            .map(|p| p.starts_with('{') && p.ends_with('}'))
+            .unwrap_or(false);
+        if !part.starts_with('{') && !part.ends_with('}') && next_is_wildcard {
+            name.push_str(&pascal_case(sanitize_path_level(part)));
+        }
+        i += 1;
+    }
+    name
+}
+
+/// The part of an address that identifies its context: everything up to the last path parameter,
+/// with the parameters left unnamed, e.g. "/track/{}/send/{}" for
+/// "/track/{track_guid}/send/{send_index}/volume". None if the address has no parameters.
+///
+/// Routes with the same prefix share a context.
+fn context_prefix(osc_address: &str) -> Option<String> {
+    let placeholder = Regex::new(r"\{[^}]+\}").unwrap();
+    let last = placeholder.find_iter(osc_address).last()?;
+    Some(
+        placeholder
+            .replace_all(&osc_address[..last.end()], "{}")
+            .to_string(),
+    )
+}
+
+/// Names each context, by its prefix. Contexts whose addresses make up the same name, e.g.
+/// "/track/{track_guid}/fx/{fx_idx}" and "/track_fx/{fx_ident}", are named after every segment
+/// of their prefix instead, path parameters included.
+fn resolve_context_names(routes: &[OscRoute]) -> BTreeMap<String, String> {
+    // The first address of each context, which the full name is made up from
+    let mut addresses: BTreeMap<String, &str> = BTreeMap::new();
+    for route in routes {
+        if let Some(prefix) = context_prefix(&route.osc_address) {
+            addresses.entry(prefix).or_insert(&route.osc_address);
+        }
+    }
+    let mut by_name: BTreeMap<String, usize> = BTreeMap::new();
+    for address in addresses.values() {
+        *by_name.entry(build_context_name(address)).or_default() += 1;
+    }
+    addresses
+        .into_iter()
+        .map(|(prefix, address)| {
+            let name = build_context_name(address);
+            let name = match by_name[&name] > 1 {
+                true => {
+                    let end = address.rfind('}').unwrap() + 1;
+                    pascal_case(full_rust_name(&address[..end]))
+                }
+                false => name,
+            };
+            (prefix, name)
+        })
+        .collect()
+}
+
+// A context the routes are grouped into, e.g. everything under one track
+#[derive(Debug)]
+struct ContextInfo {
+    name: String,
+    parameters: Vec<ContextParam>,
+    regex: Regex,
+    // The address every route of the context starts with, e.g. "/track/{}"
+    prefix: String,
+}
+
+// Gathers all unique contexts with their keys and arguments
+fn collect_contexts(routes: &[OscRoute]) -> BTreeMap<String, ContextInfo> {
+    let mut contexts: BTreeMap<String, ContextInfo> = BTreeMap::new();
+    let names = resolve_context_names(routes);
+
+    for route in routes {
+        let keys = extract_context_params(route); // TODO: make this
+                                                  // return an option
+        if keys.is_empty() {
+            continue; // No context, skip
+        }
+        let Some(prefix) = context_prefix(&route.osc_address) else {
+            continue;
+        };
+        let name = names[&prefix].clone();
+        let regex = osc_address_template_to_regex(&route.osc_address);
+        contexts.entry(name.clone()).or_insert(ContextInfo {
+            name,
+            parameters: keys,
+            regex: Regex::new(&regex).unwrap(),
+            prefix,
+        });
+    }
+    contexts
+}
+
+fn write_context_struct_types(code: &mut String, routes: &[OscRoute]) {
+    let contexts = collect_contexts(routes);
+    writeln!(code, "pub mod context {{").unwrap();
+    write_context_structs(code, &contexts);
+    writeln!(code, "}}\n\n").unwrap();
+
+    writeln!(code, "pub mod context_kind {{").unwrap();
+    write_context_kinds(code, &contexts, "super");
+    writeln!(code, "}}\n\n").unwrap();
+}
+
+// The body of the `context` module: one struct per context
+fn write_context_structs(code: &mut String, contexts: &BTreeMap<String, ContextInfo>) {
+    writeln!(code, "    use crate::osc::route_context::ContextTrait;\n").unwrap();
+
+    for ctx in contexts.values() {
+        writeln!(code, "    #[derive(Clone, Debug, PartialEq, Eq, Hash)]").unwrap();
+        writeln!(code, "    pub struct {} {{", ctx.name).unwrap();
+        for param in &ctx.parameters {
+            writeln!(code, "        pub {}: {},", param.name, param.typ).unwrap();
+        }
+        writeln!(code, "    }}\n\n").unwrap();
+        writeln!(code, "    impl ContextTrait for {} {{}}\n", ctx.name).unwrap();
+    }
+}
+
+// The body of the `context_kind` module: a kind for each context that parses it from an address.
+// `parent` is the relative path to the module holding `context`, so the output can be mounted anywhere
+fn write_context_kinds(code: &mut String, contexts: &BTreeMap<String, ContextInfo>, parent: &str) {
+    writeln!(code, "    use regex::Regex;").unwrap();
+    writeln!(code, "    use {}::context;", parent).unwrap();
+    writeln!(
+        code,
+        "    use crate::osc::route_context::{{ContextKindTrait}};\n"
+    )
+    .unwrap();
+    for ctx in contexts.values() {
+        writeln!(code, "    #[derive(Clone, Debug, PartialEq, Eq, Hash)]").unwrap();
+        writeln!(code, "    pub struct {} {{}}\n\n", ctx.name).unwrap();
+        writeln!(code, "    impl ContextKindTrait for {} {{\n", ctx.name).unwrap();
+        writeln!(code, "        type Context = context::{};\n\n", ctx.name).unwrap();
+        writeln!(code, "        fn context_name() -> &'static str {{").unwrap();
+        writeln!(code, "            \"{}\"\n", ctx.name).unwrap();
+        writeln!(code, "        }}\n\n").unwrap();
+
+        writeln!(
+            code,
+            "    fn parse(osc_address: &str) -> Option<context::{}> {{\n",
+            ctx.name
+        )
+        .unwrap();
+        // Compose capture logic
+        let capture_fields = context_captures(&ctx.parameters);
+        // Every layer parses every message, so the regex is only compiled the once
+        writeln!(
+            code,
+            "            static RE: once_cell::sync::Lazy<Regex> = once_cell::sync::Lazy::new(|| Regex::new(r\"{}\").unwrap());",
+            ctx.regex
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "            RE.captures(osc_address).{}",
+            match capture_fields.contains('?') {
+                true => format!(
+                    "and_then(|caps| Some(context::{}{{ {} }}))",
+                    ctx.name, capture_fields
+                ),
+                false => format!("map(|caps| context::{}{{ {} }})", ctx.name, capture_fields),
+            }
+        )
+        .unwrap();
+        writeln!(code, "        }}\n").unwrap();
+        writeln!(code, "    }}\n").unwrap();
+    }
+}
+
+// The fields of a context struct, made from the captures of its address. A capture that doesn't
+// parse as its param's type means the address isn't one of the context's after all.
+fn context_captures(parameters: &[ContextParam]) -> String {
+    let mut capture_fields = String::new();
+    for (i, param) in parameters.iter().enumerate() {
+        match param.typ.as_str() {
+            "i32" | "f32" | "f64" => {
+                capture_fields.push_str(&format!("{}: caps[{}].parse().ok()?, ", param.name, i + 1))
+            }
+            "bool" => {
+                capture_fields.push_str(&format!("{}: caps[{}] == \"true\", ", param.name, i + 1))
+            }
+            "String" => {
+                capture_fields.push_str(&format!("{}: caps[{}].to_string(), ", param.name, i + 1))
+            }
+            // One of the param newtypes, which are made from a &str
+            _ => capture_fields.push_str(&format!("{}: caps[{}].into(), ", param.name, i + 1)),
+        }
+    }
+    capture_fields
+}
+
+// "TrackFxParam" -> "track_fx_param"
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+/// Generates read-only lookups of the contexts anything is bound under, e.g. `try_track` and
+/// `tracks`, so finding out what's bound doesn't need an accessor or `&mut` at all.
+///
+/// Only the thread-safe client keeps its handlers where the Reaper can see them.
+fn write_context_lookups(code: &mut String, routes: &[OscRoute]) {
+    code.push_str("impl Reaper {\n");
+    for ctx in collect_contexts(routes).values() {
+        let name = snake_case(&ctx.name);
+        let params: Vec<String> = ctx
+            .parameters
+            .iter()
+            .map(|param| format!("{}: {}", param.name, param.typ))
+            .collect();
+        let names: Vec<&str> = ctx.parameters.iter().map(|p| p.name.as_str()).collect();
+        code.push_str(&format!(
+            "    /// The {} context, if anything is bound to one of its routes\n",
+            ctx.name
+        ));
+        code.push_str(&format!(
+            "    pub fn try_{}(&self, {}) -> Option<context::{}> {{\n",
+            name,
+            params.join(", "),
+            ctx.name
+        ));
+        code.push_str(&format!(
+            "        let prefix = format!(\"{}/\", {});\n",
+            ctx.prefix,
+            names.join(", ")
+        ));
+        code.push_str("        let bound = self.handlers.lock().unwrap().keys().any(|addr| addr.starts_with(&prefix));\n");
+        code.push_str(&format!(
+            "        bound.then_some(context::{} {{ {} }})\n",
+            ctx.name,
+            names.join(", ")
+        ));
+        code.push_str("    }\n\n");
+
+        // Everything under the prefix, whichever route it's on
+        let regex = osc_address_template_to_regex(&ctx.prefix.replace("{}", "{_}"));
+        code.push_str(&format!(
+            "    /// Every {} context something is bound to one of the routes of, in no particular order\n",
+            ctx.name
+        ));
+        code.push_str(&format!(
+            "    pub fn {}s(&self) -> impl Iterator<Item = context::{}> {{\n",
+            name, ctx.name
+        ));
+        code.push_str(&format!(
+            "        static RE: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| regex::Regex::new(r\"{}/\").unwrap());\n",
+            regex.trim_end_matches('$')
+        ));
+        code.push_str(&format!(
+            "        let found: std::collections::HashSet<context::{}> = self.handlers.lock().unwrap().keys().filter_map(|addr| {{\n",
+            ctx.name
+        ));
+        code.push_str("            let caps = RE.captures(addr)?;\n");
+        code.push_str(&format!(
+            "            Some(context::{} {{ {} }})\n",
+            ctx.name,
+            context_captures(&ctx.parameters)
+        ));
+        code.push_str("        }).collect();\n");
+        code.push_str("        found.into_iter()\n");
+        code.push_str("    }\n\n");
+    }
+    code.push_str("    /// Drops every handler bound on a route with params, i.e. within some context such as a\n");
+    code.push_str("    /// track, keeping those on routes of the project as a whole. For when the contexts stop\n");
+    code.push_str("    /// meaning what they did, e.g. when Reaper switches to another project.\n");
+    code.push_str("    pub fn unbind_contexts(&self) {\n");
+    code.push_str("        self.handlers.lock().unwrap().retain(|addr, _| {\n");
+    code.push_str("            !ROUTES.iter().any(|route| !route.params.is_empty() && match_address(route.address, addr).is_some())\n");
+    code.push_str("        });\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+}
+
+/// Generates a regex string for an OSC address template.
+/// E.g. "/track/{track_guid}/index" -> r"^/track/([^/]+)/index$"
+pub fn osc_address_template_to_regex(osc_address: &str) -> String {
+    let mut regex = String::from("^");
+    let mut chars = osc_address.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                // Skip until closing brace
+                for c2 in chars.by_ref() {
+                    if c2 == '}' {
+                        break;
+                    }
+                }
+                regex.push_str("([^/]+)");
+            }
+            _ => {
+                // Addresses can have regex syntax in them, like the '.' in "/fx/{fx}/v1.2"
+                regex.push_str(&regex::escape(c.encode_utf8(&mut [0; 4])));
+            }
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+fn write_node_struct_definition(code: &mut String, node: &OscRoute, options: &CodegenOptions) {
+    let bounds = match options.thread_safe {
+        true => "Send + 'static",
+        false => "'static",
+    };
+    code.push_str(&format!(
+        "pub type {0}Handler = Box<dyn FnMut({0}Args) + {1}>;\n\n",
+        node.struct_name(),
+        bounds
+    ));
+
+    code.push_str(&format!("pub struct {} {{\n", node.struct_name()));
+    let vis = options.internal_visibility();
+    code.push_str(&format!(
+        "    {}socket: Arc<{}>,\n",
+        vis,
+        options.socket_type()
+    ));
+    code.push_str(&format!("    {}destination: Option<SocketAddr>,\n", vis));
+    // Only a readable endpoint has anything to bind
+    match options.thread_safe {
+        true if node.access_tags.contains(&AccessTag::Readable) => {
+            code.push_str(&format!("    {}handlers: Handlers,\n", vis))
+        }
+        true => {}
+        false => code.push_str(&format!(
+            "    {}handlers: Vec<(Subscription, {}Handler)>,\n",
+            vis,
+            node.struct_name()
+        )),
+    }
+    if node.has_response(options) {
+        code.push_str(&format!("    {}pending: PendingResponses,\n", vis));
+    }
+
+    for param in &node.params {
+        if let Some(description) = &param.description {
+            code.push_str(&format!("    /// {}\n", description));
+        }
+        code.push_str(&format!("    pub {}: {},\n", param.name, param.rust_type()));
+    }
+    code.push_str("}\n\n");
+}
+
+fn write_node_accessors(code: &mut String, routes: Vec<OscRoute>, options: &CodegenOptions) {
+    code.push_str("impl Reaper {\n");
+    for route in routes {
+        code.push_str(&format!("    pub fn {}(&self", route.accessor_name()));
+        for param in &route.params {
+            code.push_str(&format!(", {}: {}", param.name, param.rust_type()));
+        }
+        code.push_str(&format!(") -> {} {{\n", route.struct_name()));
+        code.push_str(&format!("        {} {{\n", route.struct_name()));
+        code.push_str("        socket: self.socket.clone(),\n");
+        code.push_str("        destination: self.destination,\n");
+        match options.thread_safe {
+            true if route.access_tags.contains(&AccessTag::Readable) => {
+                code.push_str("        handlers: self.handlers.clone(),\n")
+            }
+            true => {}
+            false => code.push_str("        handlers: Vec::new(),\n"),
+        }
+        if route.has_response(options) {
+            code.push_str("        pending: self.pending.clone(),\n");
+        }
+        for param in &route.params {
+            code.push_str(&format!("        {}: {},\n", param.name, param.name));
+        }
+        code.push_str("        }\n");
+        code.push_str("    }\n");
+    }
+    code.push_str("}\n\n");
+}
+
+fn write_node_bind_trait(code: &mut String, node: &OscRoute, options: &CodegenOptions) {
+    code.push_str(&format!("/// {}\n", node.osc_address));
+    let bounds = match options.thread_safe {
+        true => "Send + 'static",
+        false => "'static",
+    };
+    code.push_str(&format!(
+            "impl Bind<{0}Args> for {1} {{\n    fn bind<F>(&mut self, callback: F)\n    where F: FnMut({0}Args) + {2} {{\n",
+            node.struct_name(), node.struct_name(), bounds
+        ));
+    code.push_str("        self.add_handler(Subscription::new(), Box::new(callback));\n");
+    code.push_str("    }\n}\n\n");
+
+    code.push_str(&format!("/// {}\n", node.osc_address));
+    code.push_str(&format!(
+            "impl Subscribe<{0}Args> for {1} {{\n    fn subscribe<F>(&mut self, callback: F) -> Subscription\n    where F: FnMut({0}Args) + {2} {{\n",
+            node.struct_name(), node.struct_name(), bounds
+        ));
+    code.push_str("        let subscription = Subscription::new();\n");
+    code.push_str("        self.add_handler(subscription, Box::new(callback));\n");
+    code.push_str("        subscription\n");
+    code.push_str("    }\n\n");
+    code.push_str("    fn unbind(&mut self, subscription: Subscription) -> bool {\n");
+    match options.thread_safe {
+        true => write_bound_handler_removal(code, node),
+        false => {
+            code.push_str("        let bound = self.handlers.len();\n");
+            code.push_str("        self.handlers.retain(|(other, _)| *other != subscription);\n");
+            code.push_str("        self.handlers.len() != bound\n");
+        }
+    }
+    code.push_str("    }\n}\n\n");
+
+    code.push_str(&format!("impl {} {{\n", node.struct_name()));
+    code.push_str(&format!(
+        "    fn add_handler(&mut self, subscription: Subscription, handler: {}Handler) {{\n",
+        node.struct_name()
+    ));
+    match options.thread_safe {
+        true => write_bound_handler(code, node),
+        false => code.push_str("        self.handlers.push((subscription, handler));\n"),
+    }
+    code.push_str("    }\n}\n\n");
+}
+
+// Skips the rest of a bind or unbind when a parameter can't be part of an address, since nothing
+// can arrive on it
+fn write_bound_address_check(code: &mut String, node: &OscRoute, fallback: &str) {
+    for param in &node.params {
+        if ArgType::of(&param.typ) == ArgType::String {
+            code.push_str(&format!(
+                "        // Nothing can arrive on an address this segment can't be part of\n        if check_address_segment(&self.{}).is_err() {{\n            return{};\n        }}\n",
+                param.name, fallback
+            ));
+        }
+    }
+}
+
+// Registers the handler with the Reaper under the endpoint's concrete address, so it's still
+// there once the endpoint is dropped
+fn write_bound_handler(code: &mut String, node: &OscRoute) {
+    write_bound_address_check(code, node, "");
+    write_osc_address_format(code, node);
+    code.push_str("        let mut handler = handler;\n");
+    code.push_str(&format!(
+        "        let handler: BoundHandler = Arc::new(Mutex::new(Box::new(move |msg| {{\n            if let Ok(args) = parse_{}_args(msg) {{\n                handler(args);\n            }}\n        }})));\n",
+        node.accessor_name()
+    ));
+    code.push_str(
+        "        self.handlers.lock().unwrap().entry(osc_address).or_default().push((subscription, handler));\n",
+    );
+}
+
+// Takes a subscription's handler back out of the Reaper, dropping the address once nothing is
+// bound on it
+fn write_bound_handler_removal(code: &mut String, node: &OscRoute) {
+    write_bound_address_check(code, node, " false");
+    write_osc_address_format(code, node);
+    code.push_str("        let mut handlers = self.handlers.lock().unwrap();\n");
+    code.push_str("        let Some(bound) = handlers.get_mut(&osc_address) else {\n");
+    code.push_str("            return false;\n");
+    code.push_str("        };\n");
+    code.push_str("        let count = bound.len();\n");
+    code.push_str("        bound.retain(|(other, _)| *other != subscription);\n");
+    code.push_str("        let removed = bound.len() != count;\n");
+    code.push_str("        if bound.is_empty() {\n");
+    code.push_str("            handlers.remove(&osc_address);\n");
+    code.push_str("        }\n");
+    code.push_str("        removed\n");
+}
+
+// Sends to the Reaper's destination where it has one, and otherwise leaves it to the socket
+fn write_socket_send(code: &mut String, options: &CodegenOptions) {
+    let wait = match options.async_client {
+        true => ".await",
+        false => "",
+    };
+    code.push_str("        match self.destination {\n");
+    code.push_str(&format!(
+        "            Some(destination) => self.socket.send_to(&buf, destination){}?,\n",
+        wait
+    ));
+    code.push_str(&format!(
+        "            None => self.socket.send(&buf){}?,\n",
+        wait
+    ));
+    code.push_str("        };\n");
+}
+
+// Builds the concrete OSC address of a route, rejecting parameters that can't be part of it
+fn write_osc_address(code: &mut String, node: &OscRoute) {
+    for param in &node.params {
+        if ArgType::of(&param.typ) == ArgType::String {
+            code.push_str(&format!(
+                "        check_address_segment(&self.{})?;\n",
+                param.name
+            ));
+        }
+    }
+    write_osc_address_format(code, node);
+}
+
+fn write_osc_address_format(code: &mut String, node: &OscRoute) {
+    let re = Regex::new(r"\{[^\}]+\}").unwrap();
+    let osc_address_template = re.replace_all(&node.osc_address, "{}");
+    code.push_str(&format!(
+        "        let osc_address = format!(\"{}\"{});\n",
+        osc_address_template,
+        node.params
+            .iter()
+            .map(|param| { format!(", self.{}", param.name) })
+            .collect::<String>()
+    ));
+}
+
+// The endpoint's address, as its template and filled in with its parameters
+fn write_node_address(code: &mut String, node: &OscRoute) {
+    code.push_str(&format!("impl {} {{\n", node.struct_name()));
+    code.push_str("    /// The OSC address of this endpoint, with its parameters in braces\n");
+    code.push_str(&format!(
+        "    pub const ADDRESS_TEMPLATE: &str = \"{}\";\n\n",
+        node.osc_address
+    ));
+    code.push_str("    /// The OSC address of this endpoint, with its parameters filled in\n");
+    code.push_str("    pub fn osc_address(&self) -> String {\n");
+    match node.params.is_empty() {
+        true => code.push_str("        Self::ADDRESS_TEMPLATE.to_string()\n"),
+        false => {
+            let re = Regex::new(r"\{[^\}]+\}").unwrap();
+            code.push_str(&format!(
+                "        format!(\"{}\"{})\n",
+                re.replace_all(&node.osc_address, "{}"),
+                node.params
+                    .iter()
+                    .map(|param| format!(", self.{}", param.name))
+                    .collect::<String>()
+            ));
+        }
+    }
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+
+    code.push_str(&format!(
+        "impl std::fmt::Display for {} {{\n",
+        node.struct_name()
+    ));
+    code.push_str("    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {\n");
+    code.push_str("        f.write_str(&self.osc_address())\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+}
+
+fn write_node_set_trait(code: &mut String, node: &OscRoute, options: &CodegenOptions) {
+    code.push_str(&format!("/// {}\n", node.osc_address));
+    if options.async_client {
+        code.push_str(&format!(
+            "impl AsyncSet<{0}Args> for {1} {{\n    type Error = OscError;\n    async fn set(&mut self, args: {0}Args) -> Result<(), Self::Error> {{\n",
+            node.struct_name(), node.struct_name()
+        ));
+        write_set_message(code, node, options);
+    } else {
+        // The message is built apart from sending it, so it can go in a bundle instead
+        code.push_str(&format!(
+            "impl SetMessage<{0}Args> for {1} {{\n    type Error = OscError;\n",
+            node.struct_name(),
+            node.struct_name()
+        ));
+        if !node.binary_args().is_empty() {
+            code.push_str(&format!(
+                "    const BINARY_ARGS: &'static [usize] = &{:?};\n",
+                node.binary_args()
+            ));
+        }
+        code.push_str(&format!(
+            "    fn set_message(&self, args: {}Args) -> Result<rosc::OscMessage, Self::Error> {{\n",
+            node.struct_name()
+        ));
+        write_set_message(code, node, options);
+        code.push_str("        Ok(osc_msg)\n");
+        code.push_str("    }\n}\n\n");
+        code.push_str(&format!("/// {}\n", node.osc_address));
+        code.push_str(&format!(
+            "impl Set<{0}Args> for {1} {{\n    type Error = OscError;\n    fn set(&mut self, args: {0}Args) -> Result<(), Self::Error> {{\n",
+            node.struct_name(), node.struct_name()
+        ));
+        code.push_str("        let osc_msg = self.set_message(args)?;\n");
+    }
+    code.push_str("        let packet = rosc::OscPacket::Message(osc_msg);\n");
+    match node.binary_args().as_slice() {
+        [] => code.push_str("        let buf = rosc::encoder::encode(&packet)?;\n"),
+        binary => code.push_str(&format!(
+            "        let buf = decode::encode_packet(&packet, &[&{:?}])?;\n",
+            binary
+        )),
+    }
+    write_socket_send(code, options);
+    code.push_str("        Ok(())\n");
+    code.push_str("    }\n}\n\n");
+}
+
+/// Emits `set_value`, which sets the endpoint to anything its args convert from, e.g.
+/// `reaper.track_volume(guid).set_value(0.5)`
+fn write_node_set_value(code: &mut String, node: &OscRoute, options: &CodegenOptions) {
+    if node.arguments.is_empty() {
+        return;
+    }
+    let (asyncness, awaited) = match options.async_client {
+        true => ("async ", ".await"),
+        false => ("", ""),
+    };
+    code.push_str(&format!(
+        "impl {0} {{\n    pub {1}fn set_value(&mut self, value: impl Into<{0}Args>) -> Result<(), OscError> {{\n        self.set(value.into()){2}\n    }}\n}}\n\n",
+        node.struct_name(),
+        asyncness,
+        awaited
+    ));
+}
+
+// Emits the statements building `osc_msg`, the message setting the endpoint to `args`
+fn write_set_message(code: &mut String, node: &OscRoute, options: &CodegenOptions) {
+    write_set_range_checks(code, node, options);
+    write_osc_address(code, node);
+    let (required, omittable): (Vec<_>, Vec<_>) =
+        node.arguments.iter().partition(|arg| !arg.is_omittable());
+    // With no optional arguments the message is built in one go, otherwise the required
+    // arguments are collected first
+    if omittable.is_empty() {
+        code.push_str("        let osc_msg = rosc::OscMessage {\n");
+        code.push_str("            addr: osc_address,\n");
+        code.push_str("            args: vec![\n");
+    } else {
+        code.push_str("        let mut osc_args = vec![\n");
+    }
+    for arg in required {
+        code.push_str(&format!(
+            "                {},\n",
+            arg.encode(&format!("args.{}", arg.field_name()))
+        ));
+    }
+    if omittable.is_empty() {
+        code.push_str("            ],\n");
+        code.push_str("        };\n");
+    } else {
+        code.push_str("        ];\n");
+        code.push_str(
+            "        // Arguments are positional, so an optional one is only sent if those before it are\n",
+        );
+        code.push_str("        osc_args.extend(\n            [\n");
+        for arg in &omittable {
+            code.push_str(&format!(
+                "                args.{}.map({}),\n",
+                arg.field_name(),
+                arg.encoder()
+            ));
+        }
+        code.push_str("            ]\n            .into_iter()\n            .map_while(|arg| arg),\n        );\n");
+        code.push_str("        let osc_msg = rosc::OscMessage {\n");
+        code.push_str("            addr: osc_address,\n");
+        code.push_str("            args: osc_args,\n");
+        code.push_str("        };\n");
+    }
+}
+
+// Emits the statements bringing `args` into the ranges the spec gives them, or failing if they
+// aren't in them
+fn write_set_range_checks(code: &mut String, node: &OscRoute, options: &CodegenOptions) {
+    let constrained: Vec<&OscArgument> = node
+        .arguments
+        .iter()
+        .filter(|arg| arg.is_constrained())
+        .collect();
+    if options.out_of_range == OutOfRange::Clamp && constrained.iter().any(|arg| arg.has_range()) {
+        code.push_str("        let mut args = args;\n");
+    }
+    for arg in constrained {
+        let field = arg.field_name();
+        if options.out_of_range == OutOfRange::Clamp && arg.has_range() {
+            let (min, max) = arg.range_exprs();
+            match arg.is_omittable() {
+                true => code.push_str(&format!(
+                    "        args.{0} = args.{0}.map(|value| crate::osc::range::clamp(value, {1}, {2}));\n",
+                    field, min, max
+                )),
+                false => code.push_str(&format!(
+                    "        args.{0} = crate::osc::range::clamp(args.{0}, {1}, {2});\n",
+                    field, min, max
+                )),
+            }
+            continue;
+        }
+        write_range_check(code, arg, ".map_err(OscError::OutOfRange)?");
+    }
+}
+
+// Emits the check that one argument of `args` is within its range or enum, with `on_error`
+// applied to the Result
+fn write_range_check(code: &mut String, arg: &OscArgument, on_error: &str) {
+    // Enums are checked by reference, so strings needn't be cloned for it
+    let by_ref = match arg.one_of.is_some() {
+        true => "&",
+        false => "",
+    };
+    let check = |value: &str| match arg.one_of_expr() {
+        Some(values) => format!(
+            "crate::osc::range::check_one_of({:?}, {}, {}){}",
+            arg.name, value, values, on_error
+        ),
+        None => {
+            let (min, max) = arg.range_exprs();
+            format!(
+                "crate::osc::range::check_range({:?}, {}, {}, {}){}",
+                arg.name, value, min, max, on_error
+            )
+        }
+    };
+    match arg.is_omittable() {
+        true => code.push_str(&format!(
+            "        if let Some(value) = {}args.{} {{\n            {};\n        }}\n",
+            by_ref,
+            arg.field_name(),
+            check("value")
+        )),
+        false => code.push_str(&format!(
+            "        {};\n",
+            check(&format!("{}args.{}", by_ref, arg.field_name()))
+        )),
+    }
+}
+
+fn write_node_query_trait(code: &mut String, node: &OscRoute, options: &CodegenOptions) {
+    code.push_str(&format!("/// {}\n", node.osc_address));
+    if options.async_client {
+        code.push_str(&format!(
+            "impl AsyncQuery for {0} {{\n    type Error = OscError;\n    async fn query(&self) -> Result<(), Self::Error> {{\n",
+            node.struct_name()
+        ));
+    } else {
+        code.push_str(&format!(
+            "impl Query for {0} {{\n    type Error = OscError;\n    fn query(&self) -> Result<(), Self::Error> {{\n",
+            node.struct_name()
+        ));
+    }
+    write_osc_address(code, node);
+    code.push_str("        let osc_msg = rosc::OscMessage {\n");
+    code.push_str("            addr: osc_address,\n");
+    code.push_str("            args: vec![],\n");
+    code.push_str("        };\n");
+    code.push_str("        let packet = rosc::OscPacket::Message(osc_msg);\n");
+    code.push_str("        let buf = rosc::encoder::encode(&packet)?;\n");
+    write_socket_send(code, options);
+    code.push_str("        Ok(())\n");
+    code.push_str("    }\n}\n\n");
+}
+
+// Parses the Args of a route out of a message that arrived on it, or says why it can't
+fn write_node_args_parser(code: &mut String, node: &OscRoute, options: &CodegenOptions) {
+    let msg = if node.arguments.is_empty() {
+        "_msg"
+    } else {
+        "msg"
+    };
+    code.push_str(&format!(
+        "{}fn parse_{}_args({}: &rosc::OscMessage) -> Result<{}Args, String> {{\n",
+        options.internal_visibility(),
+        node.accessor_name(),
+        msg,
+        node.struct_name()
+    ));
+    // Values outside the ranges the spec gives them are turned away like undecodable ones
+    let constrained: Vec<&OscArgument> = node
+        .arguments
+        .iter()
+        .filter(|arg| arg.is_constrained())
+        .collect();
+    // Without indices from the spec, the arguments are lined up with the message's by type
+    let osc_args = match node.arguments.is_empty() || node.has_arg_indices() {
+        true => "msg.args",
+        false => {
+            let expected: Vec<String> = node
+                .arguments
+                .iter()
+                .map(|arg| format!("decode::expect::<{}>()", arg.rust_type()))
+                .collect();
+            code.push_str(&format!(
+                "    let osc_args = decode::line_up(&msg.args, &[{}]);\n",
+                expected.join(", ")
+            ));
+            "osc_args"
+        }
+    };
+    match constrained.is_empty() {
+        true => code.push_str(&format!("    Ok({}Args {{\n", node.struct_name())),
+        false => code.push_str(&format!("    let args = {}Args {{\n", node.struct_name())),
+    }
+    for (osc_arg, j) in node.arguments.iter().zip(node.arg_positions()) {
+        let field = osc_arg.field_name();
+        // Arguments left off the message are None or their default; ones that can't be decoded
+        // fail the whole parse either way
+        match (osc_arg.default_expr(), osc_arg.is_omittable()) {
+            (Some(default), _) => code.push_str(&format!(
+                "        {}: decode::optional(&{}, {})?.unwrap_or({}),\n",
+                field, osc_args, j, default
+            )),
+            (None, true) => code.push_str(&format!(
+                "        {}: decode::optional(&{}, {})?,\n",
+                field, osc_args, j
+            )),
+            (None, false) => code.push_str(&format!(
+                "        {}: decode::required(&{}, {})?,\n",
+                field, osc_args, j
+            )),
+        }
+    }
+    if constrained.is_empty() {
+        code.push_str("    })\n}\n\n");
+        return;
+    }
+    code.push_str("    };\n");
+    for arg in constrained {
+        write_range_check(code, arg, "?");
+    }
+    code.push_str("    Ok(args)\n}\n\n");
+}
+
+fn write_node_query_with_response_trait(code: &mut String, node: &OscRoute) {
+    code.push_str(&format!("/// {}\n", node.osc_address));
+    code.push_str(&format!(
+        "impl QueryWithResponse<{0}Args> for {0} {{\n    fn query_response(&self) -> Result<Receiver<{0}Args>, Self::Error> {{\n",
+        node.struct_name()
+    ));
+    write_osc_address(code, node);
+    code.push_str("        let (tx, rx) = bounded(1);\n");
+    code.push_str("        // Register before sending so a fast response can't slip past us\n");
+    code.push_str("        self.pending.lock().unwrap().entry(osc_address).or_default().push(Box::new(move |msg| {\n");
+    code.push_str(&format!(
+        "            if let Ok(args) = parse_{}_args(msg) {{\n",
+        node.accessor_name()
+    ));
+    code.push_str("                let _ = tx.send(args);\n");
+    code.push_str("            }\n");
+    code.push_str("        }));\n");
+    code.push_str("        self.query()?;\n");
+    code.push_str("        Ok(rx)\n");
+    code.push_str("    }\n}\n\n");
+}
+
+// Type a builder method takes for an argument; strings take anything that converts so callers
+// can pass literals
+fn builder_param_type(arg: &OscArgument) -> String {
+    match arg.arg_type() {
+        ArgType::String if arg.binary => "impl Into<Vec<u8>>".to_string(),
+        ArgType::String => "impl Into<String>".to_string(),
+        arg_type => arg_type.rust_type(),
+    }
+}
+
+fn builder_param_value(arg: &OscArgument) -> String {
+    match arg.arg_type() {
+        ArgType::String => format!("{}.into()", arg.field_name()),
+        _ => arg.field_name(),
+    }
+}
+
+/// Emits `new`, taking the required arguments, and a `with_` method for each optional one, e.g.
+/// `TrackVolumeArgs::new(0.5).with_unit("dB")`
+fn write_args_constructor(code: &mut String, node: &OscRoute) {
+    if node.arguments.is_empty() {
+        return;
+    }
+    let is_required = |arg: &&OscArgument| !arg.optional && arg.default.is_none();
+    code.push_str(&format!("impl {}Args {{\n", node.struct_name()));
+    code.push_str("    pub fn new(");
+    for arg in node.arguments.iter().filter(is_required) {
+        code.push_str(&format!(
+            "{}: {}, ",
+            arg.field_name(),
+            builder_param_type(arg)
+        ));
+    }
+    code.push_str(") -> Self {\n        Self {\n");
+    for arg in &node.arguments {
+        let value = match (is_required(&arg), arg.default_expr()) {
+            (true, _) => builder_param_value(arg),
+            (false, Some(default)) => default,
+            (false, None) => "None".to_string(),
+        };
+        match value == arg.field_name() {
+            true => code.push_str(&format!("            {},\n", value)),
+            false => code.push_str(&format!("            {}: {},\n", arg.field_name(), value)),
+        }
+    }
+    code.push_str("        }\n    }\n");
+    for arg in node.arguments.iter().filter(|arg| !is_required(arg)) {
+        let value = match arg.is_omittable() {
+            true => format!("Some({})", builder_param_value(arg)),
+            false => builder_param_value(arg),
+        };
+        code.push_str(&format!(
+            "\n    pub fn with_{0}(mut self, {0}: {1}) -> Self {{\n        self.{0} = {2};\n        self\n    }}\n",
+            arg.field_name(),
+            builder_param_type(arg),
+            value
+        ));
+    }
+    code.push_str("}\n\n");
+    write_args_from(code, node);
+}
+
+/// Emits `From` the required arguments, a lone value or a tuple of them, so that e.g.
+/// `0.5.into()` is a `TrackVolumeArgs`
+fn write_args_from(code: &mut String, node: &OscRoute) {
+    let required: Vec<&OscArgument> = node
+        .arguments
+        .iter()
+        .filter(|arg| !arg.optional && arg.default.is_none())
+        .collect();
+    let args = required
+        .iter()
+        .map(|arg| arg.field_name())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let types = required
+        .iter()
+        .map(|arg| arg.rust_type())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let (from, pattern) = match required.len() {
+        0 => return,
+        1 => (types.clone(), format!("{}: {}", args, types)),
+        _ => (format!("({})", types), format!("({}): ({})", args, types)),
+    };
+    code.push_str(&format!(
+        "impl From<{0}> for {1}Args {{\n    fn from({2}) -> Self {{\n        Self::new({3})\n    }}\n}}\n\n",
+        from,
+        node.struct_name(),
+        pattern,
+        args
+    ));
+    // A lone string can be given as a literal
+    if let [arg] = required.as_slice() {
+        if arg.arg_type() != ArgType::String {
+            return;
+        }
+        code.push_str(&format!(
+            "impl From<&str> for {0}Args {{\n    fn from({1}: &str) -> Self {{\n        Self::new({1})\n    }}\n}}\n\n",
+            node.struct_name(),
+            arg.field_name()
+        ));
+    }
+}
+
+fn write_node(
+    code: &mut String,
+    node: &OscRoute,
+    generated_structs: &mut BTreeSet<String>,
+    options: &CodegenOptions,
+) {
+    if generated_structs.contains(&node.struct_name()) {
+        return;
+    }
+    generated_structs.insert(node.struct_name().clone());
+    node.check_arguments();
+    // Generate Args struct and Handler type if needed
+    let endpoint_args_struct = format!("{}Args", node.struct_name());
+    if !generated_structs.contains(&endpoint_args_struct) {
+        match options.serialize {
+            true => code.push_str("#[derive(Debug, serde::Serialize)]\n"),
+            false => code.push_str("#[derive(Debug)]\n"),
+        }
+        code.push_str(&format!("pub struct {} {{\n", endpoint_args_struct));
+        for arg in &node.arguments {
+            if options.serialize && !arg.arg_type().is_serializable() {
+                code.push_str("    #[serde(skip)]\n");
+            }
+            code.push_str(&format!(
+                "    pub {}: {}, // {}\n",
+                arg.field_name(),
+                arg.field_type(),
+                arg.description.as_deref().unwrap_or("")
+            ));
+        }
+        code.push_str("}\n\n");
+        write_args_constructor(code, node);
+        generated_structs.insert(endpoint_args_struct.clone());
+    }
+
+    write_node_struct_definition(code, node, options);
+    write_node_address(code, node);
+
+    if node.access_tags.contains(&AccessTag::Writeable) {
+        write_node_set_trait(code, node, options);
+        write_node_set_value(code, node, options);
+    }
+    if node.access_tags.contains(&AccessTag::Readable) {
+        write_node_bind_trait(code, node, options);
+    }
+    if node.access_tags.contains(&AccessTag::Queryable) {
+        write_node_query_trait(code, node, options);
+    }
+    write_node_args_parser(code, node, options);
+    if node.has_response(options) {
+        write_node_query_with_response_trait(code, node);
+    }
+    if !options.async_client {
+        write_node_dyn_endpoint(code, node);
+    }
+}
+
+// Lets the endpoint be set and queried as a `dyn DynEndpoint`. It can only be set dynamically if
+// it takes at most one argument, of a type ParamValue can hold.
+fn write_node_dyn_endpoint(code: &mut String, node: &OscRoute) {
+    let required: Vec<_> = node
+        .arguments
+        .iter()
+        .filter(|arg| !arg.optional && arg.default.is_none())
+        .collect();
+    let settable = node.access_tags.contains(&AccessTag::Writeable)
+        && match required.as_slice() {
+            [] => true,
+            [arg] => arg.arg_type().param_value().is_some(),
+            _ => false,
+        };
+    let queryable = node.access_tags.contains(&AccessTag::Queryable);
+
+    code.push_str(&format!("impl DynEndpoint for {} {{\n", node.struct_name()));
+    code.push_str("    fn address_template(&self) -> &'static str {\n");
+    code.push_str("        Self::ADDRESS_TEMPLATE\n");
+    code.push_str("    }\n\n");
+    code.push_str("    fn address(&self) -> String {\n");
+    code.push_str("        self.osc_address()\n");
+    code.push_str("    }\n");
+    if settable {
+        code.push_str("\n    fn is_settable(&self) -> bool {\n        true\n    }\n\n");
+        match required.first() {
+            // There's nothing to set, so the value is ignored
+            None => {
+                code.push_str(
+                    "    fn set_dyn(&mut self, _value: ParamValue) -> Result<(), OscError> {\n",
+                );
+                code.push_str(&format!(
+                    "        self.set({}Args {{}})\n",
+                    node.struct_name()
+                ));
+            }
+            Some(arg) => {
+                code.push_str(
+                    "    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {\n",
+                );
+                code.push_str(&format!(
+                    "        let {} = match value {{\n",
+                    arg.field_name()
+                ));
+                for (variant, cast) in arg.arg_type().param_value().unwrap() {
+                    code.push_str(&format!(
+                        "            ParamValue::{}(value) => value{},\n",
+                        variant, cast
+                    ));
+                }
+                code.push_str("            value => {\n");
+                code.push_str("                return Err(OscError::Unsupported(format!(\n");
+                code.push_str(&format!(
+                    "                    \"{{}} takes {}, not {{:?}}\",\n",
+                    arg.typ
+                ));
+                code.push_str("                    Self::ADDRESS_TEMPLATE,\n");
+                code.push_str("                    value\n");
+                code.push_str("                )));\n");
+                code.push_str("            }\n");
+                code.push_str("        };\n");
+                code.push_str(&format!(
+                    "        self.set({}Args::new({}))\n",
+                    node.struct_name(),
+                    arg.field_name()
+                ));
+            }
+        }
+        code.push_str("    }\n");
+    }
+    if queryable {
+        code.push_str("\n    fn is_queryable(&self) -> bool {\n        true\n    }\n\n");
+        code.push_str("    fn query_dyn(&self) -> Result<(), OscError> {\n");
+        code.push_str("        self.query()\n");
+        code.push_str("    }\n");
+    }
+    code.push_str("}\n\n");
+}
+
+/// Emits ParamValue and the DynEndpoint trait every endpoint implements, so code working the same
+/// way with any endpoint, like logging every change, doesn't need to know its Args struct.
+///
+/// Only the blocking client has them: async trait methods can't be called through a `dyn`.
+fn write_dyn_endpoint_trait(code: &mut String, options: &CodegenOptions) {
+    code.push_str("/// The value of any endpoint's argument\n");
+    match options.serialize {
+        true => code.push_str("#[derive(Clone, Debug, PartialEq, serde::Serialize)]\n"),
+        false => code.push_str("#[derive(Clone, Debug, PartialEq)]\n"),
+    }
+    code.push_str("pub enum ParamValue {\n");
+    code.push_str("    Int(i32),\n");
+    code.push_str("    Float(f32),\n");
+    code.push_str("    Double(f64),\n");
+    code.push_str("    Bool(bool),\n");
+    code.push_str("    String(String),\n");
+    code.push_str("}\n\n");
+    code.push_str("impl From<i32> for ParamValue {\n");
+    code.push_str("    fn from(value: i32) -> Self {\n");
+    code.push_str("        ParamValue::Int(value)\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("impl From<f32> for ParamValue {\n");
+    code.push_str("    fn from(value: f32) -> Self {\n");
+    code.push_str("        ParamValue::Float(value)\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("impl From<f64> for ParamValue {\n");
+    code.push_str("    fn from(value: f64) -> Self {\n");
+    code.push_str("        ParamValue::Double(value)\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("impl From<bool> for ParamValue {\n");
+    code.push_str("    fn from(value: bool) -> Self {\n");
+    code.push_str("        ParamValue::Bool(value)\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("impl From<String> for ParamValue {\n");
+    code.push_str("    fn from(value: String) -> Self {\n");
+    code.push_str("        ParamValue::String(value)\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("impl From<&str> for ParamValue {\n");
+    code.push_str("    fn from(value: &str) -> Self {\n");
+    code.push_str("        ParamValue::String(value.to_string())\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("impl std::fmt::Display for ParamValue {\n");
+    code.push_str("    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {\n");
+    code.push_str("        match self {\n");
+    code.push_str("            ParamValue::Int(value) => write!(f, \"{}\", value),\n");
+    code.push_str("            ParamValue::Float(value) => write!(f, \"{}\", value),\n");
+    code.push_str("            ParamValue::Double(value) => write!(f, \"{}\", value),\n");
+    code.push_str("            ParamValue::Bool(value) => write!(f, \"{}\", value),\n");
+    code.push_str("            ParamValue::String(value) => write!(f, \"{:?}\", value),\n");
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("/// Any endpoint, set and queried without knowing its Args struct.\n");
+    code.push_str("///\n");
+    code.push_str("/// An endpoint is set from the value of its one required argument, leaving any optional ones out.\n");
+    code.push_str("/// Endpoints without arguments ignore the value. Setting an endpoint that can't be set, or\n");
+    code.push_str("/// querying one that can't be queried, fails with OscError::Unsupported.\n");
+    code.push_str("pub trait DynEndpoint {\n");
+    code.push_str("    /// The OSC address of this endpoint, with its parameters in braces\n");
+    code.push_str("    fn address_template(&self) -> &'static str;\n\n");
+    code.push_str("    /// The OSC address of this endpoint, with its parameters filled in\n");
+    code.push_str("    fn address(&self) -> String;\n\n");
+    code.push_str("    fn is_settable(&self) -> bool {\n");
+    code.push_str("        false\n");
+    code.push_str("    }\n\n");
+    code.push_str("    fn is_queryable(&self) -> bool {\n");
+    code.push_str("        false\n");
+    code.push_str("    }\n\n");
+    code.push_str("    fn set_dyn(&mut self, value: ParamValue) -> Result<(), OscError> {\n");
+    code.push_str("        Err(OscError::Unsupported(format!(\n");
+    code.push_str("            \"{} can't be set to {:?}\",\n");
+    code.push_str("            self.address_template(),\n");
+    code.push_str("            value\n");
+    code.push_str("        )))\n");
+    code.push_str("    }\n\n");
+    code.push_str("    fn query_dyn(&self) -> Result<(), OscError> {\n");
+    code.push_str("        Err(OscError::Unsupported(format!(\n");
+    code.push_str("            \"{} can't be queried\",\n");
+    code.push_str("            self.address_template()\n");
+    code.push_str("        )))\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+}
+
+fn write_reaper(code: &mut String, options: &CodegenOptions) {
+    code.push_str("pub struct Reaper {\n");
+    code.push_str(&format!("    socket: Arc<{}>,\n", options.socket_type()));
+    code.push_str("    destination: Option<SocketAddr>,\n");
+    if !options.async_client {
+        code.push_str("    pending: PendingResponses,\n");
+    }
+    if options.thread_safe {
+        code.push_str("    handlers: Handlers,\n");
+    }
+    code.push_str("}\n\n");
+    code.push_str("impl Reaper {\n");
+    code.push_str(
+        "    /// Sends everything over `socket` as it is, e.g. to whatever it's connected to.\n",
+    );
+    code.push_str(&format!(
+        "    pub fn new(socket: Arc<{}>) -> Self {{\n",
+        options.socket_type()
+    ));
+    code.push_str("        Self {\n");
+    code.push_str("            socket,\n");
+    code.push_str("            destination: None,\n");
+    if !options.async_client {
+        code.push_str("            pending: Arc::new(Mutex::new(HashMap::new())),\n");
+    }
+    if options.thread_safe {
+        code.push_str("            handlers: Arc::new(Mutex::new(HashMap::new())),\n");
+    }
+    code.push_str("        }\n");
+    code.push_str("    }\n\n");
+    code.push_str("    /// Sends everything over `socket` to `destination`, so the socket can be left unconnected\n");
+    code.push_str("    /// and still hear from anyone.\n");
+    code.push_str(&format!(
+        "    pub fn with_destination(socket: Arc<{}>, destination: SocketAddr) -> Self {{\n",
+        options.socket_type()
+    ));
+    code.push_str("        Self {\n");
+    code.push_str("            destination: Some(destination),\n");
+    code.push_str("            ..Self::new(socket)\n");
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    // for route in routes.iter() {
+    //     code.push_str(&format!(
+    //         "    pub fn {}(&self",
+    //         route.struct_name().to_lowercase(),
+    //     ));
+    //     for param in &route.params {
+    //         code.push_str(&format!(", {}: {}", param.name, rust_type(&param.typ)));
+    //     }
+    //     code.push_str(&format!(") -> {} {{\n", route.struct_name()));
+    //     code.push_str("        ");
+    //     code.push_str(&format!("{} {{\n", route.struct_name()));
+    //     code.push_str("            socket: self.socket.clone(),\n");
+    //     for param in &route.params {
+    //         code.push_str(&format!("            {}: {},\n", param.name, param.name));
+    //     }
+    //     code.push_str("        }\n");
+    //     code.push_str("    }\n");
+    // }
+    code.push_str("}\n\n");
+}
+
+// Emits BundleBuilder, which gathers sets up to send to Reaper in one datagram
+fn write_bundle_builder(code: &mut String, options: &CodegenOptions) {
+    code.push_str("impl Reaper {\n");
+    code.push_str("    /// Starts a bundle of sets to send together.\n");
+    code.push_str("    pub fn bundle(&self) -> BundleBuilder {\n");
+    code.push_str("        BundleBuilder {\n");
+    code.push_str("            socket: self.socket.clone(),\n");
+    code.push_str("            destination: self.destination,\n");
+    code.push_str("            messages: Vec::new(),\n");
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("/// Sets gathered up to go to Reaper in a single OSC bundle, so they all take effect at once,\n");
+    code.push_str("/// e.g. a track's volume, pan and mute when recalling a scene.\n");
+    code.push_str("pub struct BundleBuilder {\n");
+    code.push_str(&format!("    socket: Arc<{}>,\n", options.socket_type()));
+    code.push_str("    destination: Option<SocketAddr>,\n");
+    code.push_str("    // The message for each set along with where its binary arguments are, or why it couldn't\n");
+    code.push_str("    // be built\n");
+    code.push_str("    messages: Vec<Result<(rosc::OscMessage, &'static [usize]), OscError>>,\n");
+    code.push_str("}\n\n");
+    code.push_str("impl BundleBuilder {\n");
+    code.push_str("    /// Adds setting `endpoint` to `args`. Nothing is sent until the bundle is committed.\n");
+    code.push_str("    pub fn set<A, E>(&mut self, endpoint: &E, args: A) -> &mut Self\n");
+    code.push_str("    where\n");
+    code.push_str("        E: SetMessage<A, Error = OscError>,\n");
+    code.push_str("    {\n");
+    code.push_str("        self.messages\n");
+    code.push_str(
+        "            .push(endpoint.set_message(args).map(|msg| (msg, E::BINARY_ARGS)));\n",
+    );
+    code.push_str("        self\n");
+    code.push_str("    }\n\n");
+    code.push_str("    /// How many sets have been added.\n");
+    code.push_str("    pub fn len(&self) -> usize {\n");
+    code.push_str("        self.messages.len()\n");
+    code.push_str("    }\n\n");
+    code.push_str("    pub fn is_empty(&self) -> bool {\n");
+    code.push_str("        self.messages.is_empty()\n");
+    code.push_str("    }\n\n");
+    code.push_str("    /// Sends every set whose message could be built, returning how each set went in the order\n");
+    code.push_str("    /// they were added. Errs only if the bundle couldn't be sent at all.\n");
+    code.push_str("    ///\n");
+    code.push_str("    /// A lone message is sent as it is, since there's nothing to keep it together with.\n");
+    code.push_str("    pub fn commit(self) -> Result<Vec<Result<(), OscError>>, OscError> {\n");
+    code.push_str("        let mut content = Vec::new();\n");
+    code.push_str("        let mut binary = Vec::new();\n");
+    code.push_str("        let results: Vec<Result<(), OscError>> = self\n");
+    code.push_str("            .messages\n");
+    code.push_str("            .into_iter()\n");
+    code.push_str("            .map(|msg| {\n");
+    code.push_str("                let (msg, binary_args) = msg?;\n");
+    code.push_str("                content.push(rosc::OscPacket::Message(msg));\n");
+    code.push_str("                binary.push(binary_args);\n");
+    code.push_str("                Ok(())\n");
+    code.push_str("            })\n");
+    code.push_str("            .collect();\n");
+    code.push_str("        let packet = match content.len() {\n");
+    code.push_str("            0 => return Ok(results),\n");
+    code.push_str("            1 => content.remove(0),\n");
+    code.push_str("            // Timetag 1 means \"immediately\"\n");
+    code.push_str("            _ => rosc::OscPacket::Bundle(rosc::OscBundle {\n");
+    code.push_str("                timetag: rosc::OscTime {\n");
+    code.push_str("                    seconds: 0,\n");
+    code.push_str("                    fractional: 1,\n");
+    code.push_str("                },\n");
+    code.push_str("                content,\n");
+    code.push_str("            }),\n");
+    code.push_str("        };\n");
+    code.push_str("        let buf = decode::encode_packet(&packet, &binary)?;\n");
+    write_socket_send(code, options);
+    code.push_str("        Ok(results)\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+}
+
+/// Emits the spec's routes as data, for tools that look at OSC traffic rather than handle it.
+fn write_route_table(code: &mut String, routes: &[OscRoute]) {
+    code.push_str("/// Which way messages to a route go between Reaper and us.\n");
+    code.push_str("#[derive(Clone, Copy, Debug, PartialEq, Eq)]\n");
+    code.push_str("pub enum RouteDirection {\n");
+    code.push_str("    /// Reaper reports it, and it can't be set\n");
+    code.push_str("    FromReaper,\n");
+    code.push_str("    /// It's sent to Reaper, which never reports it\n");
+    code.push_str("    ToReaper,\n");
+    code.push_str("    /// Reaper reports it, and it can be set too\n");
+    code.push_str("    Both,\n");
+    code.push_str("}\n\n");
+    code.push_str("/// A route of the spec, as data.\n");
+    code.push_str("#[derive(Clone, Copy, Debug, PartialEq, Eq)]\n");
+    code.push_str("pub struct RouteInfo {\n");
+    code.push_str("    /// Name of the route's accessor on Reaper\n");
+    code.push_str("    pub name: &'static str,\n");
+    code.push_str(
+        "    /// Name of the struct the accessor returns, which the route's Args are named after\n",
+    );
+    code.push_str("    pub struct_name: &'static str,\n");
+    code.push_str(
+        "    /// The route's address, with a `{param}` segment standing in for each param\n",
+    );
+    code.push_str("    pub address: &'static str,\n");
+    code.push_str("    pub direction: RouteDirection,\n");
+    code.push_str("    /// Whether Reaper answers a query to it\n");
+    code.push_str("    pub queryable: bool,\n");
+    code.push_str("    /// Each param's name and type, in the order the address has them\n");
+    code.push_str("    pub params: &'static [(&'static str, &'static str)],\n");
+    code.push_str("    /// Each argument's name and type, in the order messages carry them\n");
+    code.push_str("    pub arguments: &'static [(&'static str, &'static str)],\n");
+    code.push_str("}\n\n");
+    code.push_str("/// Every route in the spec, in the order it lists them.\n");
+    code.push_str("pub const ROUTES: &[RouteInfo] = &[\n");
+    for route in routes {
+        let params: Vec<String> = route
+            .params
+            .iter()
+            .map(|param| format!("({:?}, {:?})", param.name, param.typ))
+            .collect();
+        let arguments: Vec<String> = route
+            .arguments
+            .iter()
+            .map(|arg| format!("({:?}, {:?})", arg.name, arg.typ))
+            .collect();
+        code.push_str(&format!(
+            "    RouteInfo {{ name: {:?}, struct_name: {:?}, address: {:?}, direction: RouteDirection::{}, queryable: {}, params: &[{}], arguments: &[{}] }},\n",
+            route.accessor_name(),
+            route.struct_name(),
+            route.osc_address,
+            route_direction(route),
+            route.access_tags.contains(&AccessTag::Queryable),
+            params.join(", "),
+            arguments.join(", ")
+        ));
+    }
+    code.push_str("];\n\n");
+    code.push_str("/// Every route in the spec, for tools that work with any route rather than particular ones.\n");
+    code.push_str("pub fn all_routes() -> &'static [RouteInfo] {\n");
+    code.push_str("    ROUTES\n");
+    code.push_str("}\n\n");
+}
+
+// The RouteDirection variant for `route`. Routes that are only queried are sent to Reaper, which
+// answers on other routes.
+fn route_direction(route: &OscRoute) -> &'static str {
+    let readable = route.access_tags.contains(&AccessTag::Readable);
+    let writeable = route.access_tags.contains(&AccessTag::Writeable);
+    match (readable, writeable) {
+        (true, true) => "Both",
+        (true, false) => "FromReaper",
+        (false, _) => "ToReaper",
+    }
+}
+
+fn write_dispatcher(code: &mut String, routes: Vec<OscRoute>, options: &CodegenOptions) {
+    let receiver = options.dispatch_receiver();
+    code.push_str(&format!(
+        "pub fn dispatch_osc<F>(reaper: {}, msg: rosc::OscMessage, log_unknown: F)\nwhere F: Fn(&str, &Unhandled) {{\n",
+        receiver
+    ));
+    if !options.async_client {
+        code.push_str(
+            "    // Resolve any queries waiting on this address before the bound handlers run\n",
+        );
+        code.push_str("    let waiters = reaper.pending.lock().unwrap().remove(&msg.addr);\n");
+        code.push_str("    for waiter in waiters.into_iter().flatten() {\n");
+        code.push_str("        waiter(&msg);\n");
+        code.push_str("    }\n");
+    }
+    if options.thread_safe {
+        code.push_str("    call_bound_handlers(reaper, &msg);\n");
+    }
+    code.push_str("    let addr = msg.addr.as_str();\n");
+    // Why the message's arguments couldn't be decoded for each route it matched that couldn't
+    code.push_str("    let mut undecodable = Vec::new();\n");
+    // A pattern can match routes anywhere in the trie, so it's tried against all of them
+    code.push_str("    let matched = match is_pattern(addr) {\n");
+    code.push_str("        true => dispatch_every_route(reaper, &msg, addr, &mut undecodable),\n");
+    code.push_str("        false => dispatch_by_segment(reaper, &msg, addr, &mut undecodable),\n");
+    code.push_str("    };\n");
+    code.push_str(
+        "    if !matched {\n        log_unknown(addr, &Unhandled::UnknownAddress);\n    }\n",
+    );
+    code.push_str("    for reason in undecodable {\n");
+    code.push_str("        log_unknown(addr, &Unhandled::BadArgs(reason));\n");
+    code.push_str("    }\n}\n\n");
+
+    // A pattern address may match several routes, and OSC delivers it to all of them
+    code.push_str(&format!(
+        "fn dispatch_every_route(reaper: {}, msg: &rosc::OscMessage, addr: &str, undecodable: &mut Vec<String>) -> bool {{\n",
+        receiver
+    ));
+    code.push_str("    let mut matched = false;\n");
+    for node in &routes {
+        code.push_str(&format!(
+            "    matched |= {}(reaper, msg, addr, undecodable);\n",
+            dispatch_fn_name(node)
+        ));
+    }
+    code.push_str("    matched\n}\n\n");
+
+    let mut trie = SegmentTrie::default();
+    for (i, node) in routes.iter().enumerate() {
+        trie.insert(&node.osc_address, i);
+    }
+    code.push_str(
+        "// Only tries the routes whose literal segments match the address's, so the cost of a\n",
+    );
+    code.push_str("// dispatch doesn't grow with the number of routes\n");
+    code.push_str(&format!(
+        "fn dispatch_by_segment(reaper: {}, msg: &rosc::OscMessage, addr: &str, undecodable: &mut Vec<String>) -> bool {{\n",
+        receiver
+    ));
+    code.push_str(
+        "    let segments: Vec<&str> = addr.split('/').filter(|s| !s.is_empty()).collect();\n",
+    );
+    code.push_str("    let mut matched = false;\n");
+    write_trie_node(code, &trie, 0, &routes);
+    code.push_str("    matched\n}\n\n");
+
+    if options.thread_safe {
+        write_call_bound_handlers(code);
+    }
+    for node in &routes {
+        match options.thread_safe {
+            true => write_route_match(code, node),
+            false => write_route_dispatch(code, node),
+        }
+    }
+}
+
+// Emits the function running the handlers bound on a message's address, or on any address a
+// pattern matches. The handlers are gathered up first and run without the Reaper's lock held, so
+// they can bind and unbind without deadlocking; changes take effect from the next message.
+fn write_call_bound_handlers(code: &mut String) {
+    code.push_str("fn call_bound_handlers(reaper: &Reaper, msg: &rosc::OscMessage) {\n");
+    code.push_str("    let bound: Vec<BoundHandler> = {\n");
+    code.push_str("        let handlers = reaper.handlers.lock().unwrap();\n");
+    code.push_str("        let matching: Vec<&Vec<(Subscription, BoundHandler)>> = match is_pattern(&msg.addr) {\n");
+    code.push_str("            true => handlers\n");
+    code.push_str("                .iter()\n");
+    code.push_str(
+        "                .filter(|(bound, _)| match_address(bound, &msg.addr).is_some())\n",
+    );
+    code.push_str("                .map(|(_, bound)| bound)\n");
+    code.push_str("                .collect(),\n");
+    code.push_str("            false => handlers.get(&msg.addr).into_iter().collect(),\n");
+    code.push_str("        };\n");
+    code.push_str("        matching\n");
+    code.push_str("            .into_iter()\n");
+    code.push_str("            .flatten()\n");
+    code.push_str("            .map(|(_, handler)| handler.clone())\n");
+    code.push_str("            .collect()\n");
+    code.push_str("    };\n");
+    code.push_str("    for handler in bound {\n");
+    code.push_str("        let mut handler = handler.lock().unwrap();\n");
+    code.push_str("        (*handler)(msg);\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+}
+
+// Emits the function telling whether a message's address belongs to one route, noting why its
+// arguments don't suit the route if they don't. The handlers of a thread-safe Reaper have already
+// run by then.
+fn write_route_match(code: &mut String, node: &OscRoute) {
+    code.push_str(&format!("/// {}\n", node.osc_address));
+    code.push_str(&format!(
+        "fn {}(_reaper: &Reaper, msg: &rosc::OscMessage, addr: &str, undecodable: &mut Vec<String>) -> bool {{\n",
+        dispatch_fn_name(node)
+    ));
+    code.push_str(&format!(
+        "    if match_address(\"{}\", addr).is_none() {{\n        return false;\n    }}\n",
+        node.osc_address
+    ));
+    write_args_check(code, node);
+    code.push_str("    true\n}\n\n");
+}
+
+// Emits the check that a matched message's arguments can be decoded for the route, returning
+// early with the reason noted if they can't
+fn write_args_check(code: &mut String, node: &OscRoute) {
+    code.push_str(&format!(
+        "    if let Err(reason) = parse_{}_args(msg) {{\n        undecodable.push(reason);\n        return true;\n    }}\n",
+        node.accessor_name()
+    ));
+}
+
+/// Route indices arranged by their address segments. Placeholders, and any segment using
+/// pattern syntax, can stand for any segment so they all share one child.
+#[derive(Debug, Default)]
+struct SegmentTrie {
+    literals: BTreeMap<String, SegmentTrie>,
+    wildcard: Option<Box<SegmentTrie>>,
+    routes: Vec<usize>,
+}
+
+impl SegmentTrie {
+    fn insert(&mut self, osc_address: &str, route: usize) {
+        let mut node = self;
+        for segment in osc_address.split('/').filter(|s| !s.is_empty()) {
+            let is_literal = !segment.contains(['*', '?', '[', ']', '{', '}']);
+            node = match is_literal {
+                true => node.literals.entry(segment.to_string()).or_default(),
+                false => node.wildcard.get_or_insert_with(Default::default),
+            };
+        }
+        node.routes.push(route);
+    }
+}
+
+// Emits the checks for one level of the trie. An address can match both a literal child and the
+// wildcard child (e.g. /track/all_guids and /track/{track_guid}), so both are tried. The trie only
+// rules routes out; each route's own dispatch checks the whole address, length included.
+fn write_trie_node(code: &mut String, node: &SegmentTrie, depth: usize, routes: &[OscRoute]) {
+    for &i in &node.routes {
+        code.push_str(&format!(
+            "matched |= {}(reaper, msg, addr, undecodable);\n",
+            dispatch_fn_name(&routes[i])
+        ));
+    }
+    let segment = match depth {
+        0 => "segments.first()".to_string(),
+        _ => format!("segments.get({})", depth),
+    };
+    match node.literals.len() {
+        0 => {}
+        1 => {
+            let (literal, child) = node.literals.iter().next().unwrap();
+            code.push_str(&format!(
+                "if let Some({:?}) = {}.copied() {{\n",
+                literal, segment
+            ));
+            write_trie_node(code, child, depth + 1, routes);
+            code.push_str("}\n");
+        }
+        _ => {
+            code.push_str(&format!("match {}.copied() {{\n", segment));
+            for (literal, child) in &node.literals {
+                code.push_str(&format!("Some({:?}) => {{\n", literal));
+                write_trie_node(code, child, depth + 1, routes);
+                code.push_str("}\n");
+            }
+            code.push_str("_ => {}\n}\n");
+        }
+    }
+    if let Some(child) = &node.wildcard {
+        write_trie_node(code, child, depth + 1, routes);
+    }
+}
+
+fn dispatch_fn_name(node: &OscRoute) -> String {
+    format!("dispatch_{}_route", node.accessor_name())
+}
+
+// Emits the function handing a message to one route, if its address matches. A message that
+// matches but can't be resolved to a single endpoint, or whose arguments don't suit the route,
+// still counts as matched.
+fn write_route_dispatch(code: &mut String, node: &OscRoute) {
+    code.push_str(&format!("/// {}\n", node.osc_address));
+    code.push_str(&format!(
+        "fn {}(reaper: &mut Reaper, msg: &rosc::OscMessage, addr: &str, undecodable: &mut Vec<String>) -> bool {{\n",
+        dispatch_fn_name(node)
+    ));
+    let args = match node.params.is_empty() {
+        true => "_args",
+        false => "args",
+    };
+    code.push_str(&format!(
+        "    let Some({}) = match_address(\"{}\", addr) else {{\n        return false;\n    }};\n",
+        args, node.osc_address,
+    ));
+    write_args_check(code, node);
+
+    // Extract path args, captured in the order they appear in the address
+    let placeholders = extract_context_params(node);
+    for param in &node.params {
+        let i = placeholders
+            .iter()
+            .position(|placeholder| placeholder.name == param.name)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Param '{}' does not appear in address {}",
+                    param.name, node.osc_address
+                )
+            });
+        match param.typ.as_str() {
+            // A wildcard sent in place of a number can't be resolved to a single route
+            "int" => {
+                code.push_str(&format!(
+                    "    let Ok({}) = args[{}].parse::<i32>() else {{ return true; }};\n",
+                    param.name, i
+                ));
+            }
+            "float" => {
+                code.push_str(&format!(
+                    "    let Ok({}) = args[{}].parse::<f32>() else {{ return true; }};\n",
+                    param.name, i
+                ));
+            }
+            "double" => {
+                code.push_str(&format!(
+                    "    let Ok({}) = args[{}].parse::<f64>() else {{ return true; }};\n",
+                    param.name, i
+                ));
+            }
+            "bool" => {
+                code.push_str(&format!(
+                    "    let {}: bool = args[{}] == \"true\";\n",
+                    param.name, i
+                ));
+            }
+            "string" if param.rust_type.is_some() => {
+                code.push_str(&format!(
+                    "    let {} = {}::from(args[{}].as_str());\n",
+                    param.name,
+                    param.rust_type(),
+                    i
+                ));
+            }
+            "string" => {
+                code.push_str(&format!("    let {} = args[{}].clone();\n", param.name, i));
+            }
+            _ => {
+                panic!(
+                    "Unsupported path argument type '{}' in node {:?}",
+                    param.typ, node
+                );
+            }
+        }
+    }
+
+    code.push_str(&format!(
+        "    let mut endpoint = reaper.{}(",
+        node.accessor_name(),
+    ));
+    for param in &node.params {
+        code.push_str(&format!("{}, ", param.name));
+    }
+    code.push_str(");\n");
+
+    // Every bound handler gets its own copy of the arguments
+    code.push_str("    for (_, handler) in endpoint.handlers.iter_mut() {\n");
+    code.push_str(&format!(
+        "        if let Ok(args) = parse_{}_args(msg) {{\n",
+        node.accessor_name()
+    ));
+    code.push_str("            handler(args);\n");
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    code.push_str("    true\n}\n\n");
+}
+
+/// Emits a test per route that can be both set and bound, checking that what it's set to comes
+/// back out of the dispatcher as it went in.
+///
+/// The Args structs can't be compared, so what the handler receives is set again and the two
+/// messages compared instead.
+fn write_round_trip_tests(code: &mut String, routes: &[OscRoute]) {
+    code.push_str("#[cfg(test)]\n");
+    code.push_str("mod generated_tests {\n");
+    code.push_str("    use super::*;\n\n");
+    code.push_str("    use std::sync::mpsc;\n\n");
+    code.push_str("    // Sends nowhere; the tests dispatch the messages they build themselves\n");
+    code.push_str("    struct NoTransport;\n\n");
+    code.push_str("    impl OscTransport for NoTransport {\n");
+    code.push_str("        fn send(&self, _packet: &[u8]) -> std::io::Result<()> {\n");
+    code.push_str("            Ok(())\n");
+    code.push_str("        }\n\n");
+    code.push_str("        fn recv(&self) -> std::io::Result<(Vec<u8>, SocketAddr)> {\n");
+    code.push_str("            Err(std::io::ErrorKind::Unsupported.into())\n");
+    code.push_str("        }\n");
+    code.push_str("    }\n\n");
+    for route in routes {
+        // Messages sent keep the spec's order, so they don't come back where arg_index says
+        if !route.access_tags.contains(&AccessTag::Writeable)
+            || !route.access_tags.contains(&AccessTag::Readable)
+            || route.has_arg_indices()
+        {
+            continue;
+        }
+        let params: Vec<String> = route
+            .params
+            .iter()
+            .map(|param| match ArgType::of(&param.typ) {
+                ArgType::String => "\"test\".into()".to_string(),
+                arg_type => arg_type.sample(),
+            })
+            .collect();
+        let fields: Vec<String> = route
+            .arguments
+            .iter()
+            .map(|arg| {
+                let value = arg.sample();
+                match arg.is_omittable() {
+                    true => format!("{}: Some({})", arg.field_name(), value),
+                    false => format!("{}: {}", arg.field_name(), value),
+                }
+            })
+            .collect();
+        code.push_str("    #[test]\n");
+        code.push_str(&format!(
+            "    fn test_{}_round_trips() {{\n",
+            route.accessor_name()
+        ));
+        code.push_str("        let reaper = Reaper::new(Arc::new(NoTransport));\n");
+        code.push_str(&format!(
+            "        let mut endpoint = reaper.{}({});\n",
+            route.accessor_name(),
+            params.join(", ")
+        ));
+        code.push_str("        let (tx, rx) = mpsc::channel();\n");
+        code.push_str("        endpoint.bind(move |args| tx.send(args).unwrap());\n");
+        code.push_str(&format!(
+            "        let msg = endpoint.set_message({}Args {{ {} }}).unwrap();\n",
+            route.struct_name(),
+            fields.join(", ")
+        ));
+        code.push_str("        dispatch_osc(&reaper, msg.clone(), |addr, reason| {\n");
+        code.push_str("            panic!(\"{} wasn't handled: {}\", addr, reason)\n");
+        code.push_str("        });\n");
+        code.push_str(
+            "        let received = rx.try_recv().expect(\"the handler wasn't called\");\n",
+        );
+        code.push_str("        assert_eq!(endpoint.set_message(received).unwrap(), msg);\n");
+        code.push_str("    }\n\n");
+    }
+    code.push_str("}\n");
+}
+
+fn format_code(code: &str) -> String {
+    let mut rustfmt = Command::new("rustfmt")
+        .arg("stdout")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to start rustfmt");
+
+    let stdin = rustfmt.stdin.as_mut().expect("Failed to open stdin");
+    use std::io::Write;
+    stdin
+        .write_all(code.as_bytes())
+        .expect("Failed to write to rustfmt stdin");
+
+    let output = rustfmt
+        .wait_with_output()
+        .expect("Failed to read rustfmt output");
+    String::from_utf8(output.stdout).expect("rustfmt output not valid UTF-8")
+}
+
+/// Emits an async receive loop that decodes incoming packets and feeds them to dispatch_osc.
+fn write_async_receive_loop(code: &mut String) {
+    code.push_str("fn dispatch_packet<F>(reaper: &mut Reaper, packet: rosc::OscPacket, log_unknown: &F)\nwhere F: Fn(&str, &Unhandled) {\n");
+    code.push_str("    match packet {\n");
+    code.push_str(
+        "        rosc::OscPacket::Message(msg) => dispatch_osc(reaper, msg, log_unknown),\n",
+    );
+    code.push_str("        rosc::OscPacket::Bundle(bundle) => {\n");
+    code.push_str("            for packet in bundle.content {\n");
+    code.push_str("                dispatch_packet(reaper, packet, log_unknown);\n");
+    code.push_str("            }\n");
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("/// Receives packets on the Reaper socket and dispatches them until the socket errors out.\n");
+    code.push_str("pub async fn receive_loop<F>(reaper: &mut Reaper, log_unknown: F) -> std::io::Result<()>\nwhere F: Fn(&str, &Unhandled) {\n");
+    code.push_str("    let socket = reaper.socket.clone();\n");
+    code.push_str("    let mut buf = [0u8; rosc::decoder::MTU];\n");
+    code.push_str("    loop {\n");
+    code.push_str("        let (size, _) = socket.recv_from(&mut buf).await?;\n");
+    code.push_str("        match decode::decode_packet(&buf[..size]) {\n");
+    code.push_str("            Ok(packet) => dispatch_packet(reaper, packet, &log_unknown),\n");
+    code.push_str(
+        "            Err(_) => log_unknown(\"<undecodable packet>\", &Unhandled::BadPacket),\n",
+    );
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n");
+}
+
+fn generate_code(routes: &[OscRoute], options: &CodegenOptions) -> String {
+    let mut code = String::new();
+    write_imports(&mut code, options);
+    for route in routes {
+        let mut generated_structs = BTreeSet::new();
+        write_node(&mut code, route, &mut generated_structs, options);
+    }
+    write_context_struct_types(&mut code, routes);
+    write_reaper(&mut code, options);
+    if !options.async_client {
+        write_bundle_builder(&mut code, options);
+        write_dyn_endpoint_trait(&mut code, options);
+    }
+    write_node_accessors(&mut code, routes.to_vec(), options);
+    if options.thread_safe {
+        write_context_lookups(&mut code, routes);
+    }
+    write_route_table(&mut code, routes);
+    write_dispatcher(&mut code, routes.to_vec(), options);
+    if options.async_client {
+        code.push('\n');
+        write_async_receive_loop(&mut code);
+    }
+    if options.tests {
+        write_round_trip_tests(&mut code, routes);
+    }
+    code
+}
+
+/// Name of the module a route goes in when the output is split: its first path segment, so all
+/// the `/track/...` routes end up in `track.rs`
+fn module_name(route: &OscRoute) -> String {
+    let first = route
+        .osc_address
+        .split('/')
+        .find(|s| !s.is_empty())
+        .unwrap_or("root");
+    let name = match first.starts_with('{') {
+        true => "root".to_string(),
+        false => sanitize_path_level(first).to_lowercase(),
+    };
+    // Don't clash with the modules every split output has
+    match name.as_str() {
+        "context" | "dispatcher" => format!("{}_routes", name),
+        _ => name,
+    }
+}
+
+/// Generates the files of a split module directory, as (file name, code) pairs with mod.rs first.
+///
+/// The node modules and dispatcher get everything from mod.rs through `use super::*`, and mod.rs
+/// re-exports their public items, so the module is used exactly like a single generated file.
+fn generate_modules(routes: &[OscRoute], options: &CodegenOptions) -> Vec<(String, String)> {
+    let mut groups: BTreeMap<String, Vec<OscRoute>> = BTreeMap::new();
+    for route in routes {
+        groups
+            .entry(module_name(route))
+            .or_default()
+            .push(route.clone());
+    }
+
+    let mut root = String::new();
+    write_imports(&mut root, options);
+    root.push_str("pub mod context;\n");
+    root.push_str("mod dispatcher;\n");
+    for name in groups.keys() {
+        root.push_str(&format!("mod {};\n", name));
+    }
+    root.push('\n');
+    root.push_str("pub use context::context_kind;\n");
+    root.push_str("pub use dispatcher::*;\n");
+    for name in groups.keys() {
+        root.push_str(&format!("pub use {}::*;\n", name));
+    }
+    root.push('\n');
+    write_reaper(&mut root, options);
+    if !options.async_client {
+        write_bundle_builder(&mut root, options);
+        write_dyn_endpoint_trait(&mut root, options);
+    }
+    if options.thread_safe {
+        write_context_lookups(&mut root, routes);
+    }
+    write_route_table(&mut root, routes);
+    if options.tests {
+        write_round_trip_tests(&mut root, routes);
+    }
+    let mut files = vec![("mod.rs".to_string(), root)];
+
+    let contexts = collect_contexts(routes);
+    let mut context = String::from("// AUTO-GENERATED CODE. DO NOT EDIT!\n\n");
+    write_context_structs(&mut context, &contexts);
+    context.push_str("\npub mod context_kind {\n");
+    write_context_kinds(&mut context, &contexts, "super::super");
+    context.push_str("}\n");
+    files.push(("context.rs".to_string(), context));
+
+    let mut dispatcher = String::from("// AUTO-GENERATED CODE. DO NOT EDIT!\n\nuse super::*;\n\n");
+    write_dispatcher(&mut dispatcher, routes.to_vec(), options);
+    if options.async_client {
+        dispatcher.push('\n');
+        write_async_receive_loop(&mut dispatcher);
+    }
+    files.push(("dispatcher.rs".to_string(), dispatcher));
+
+    for (name, group) in groups {
+        let mut code = String::from("// AUTO-GENERATED CODE. DO NOT EDIT!\n\nuse super::*;\n\n");
+        for route in &group {
+            let mut generated_structs = BTreeSet::new();
+            write_node(&mut code, route, &mut generated_structs, options);
+        }
+        write_node_accessors(&mut code, group, options);
+        files.push((format!("{}.rs", name), code));
+    }
+    files
+}
+
+/// Generates a .ReaperOSC pattern config with a line per route, so Reaper sends and listens on
+/// exactly the addresses in the spec.
+///
+/// Routes whose arguments Reaper can't express are left in as comments rather than dropped
+/// silently.
+fn generate_reaperosc(routes: &[OscRoute]) -> String {
+    let mut config = String::from("# AUTO-GENERATED FROM THE OSC SPEC. DO NOT EDIT!\n\n");
+    for route in routes {
+        match route.reaperosc_pattern() {
+            Some(pattern) => {
+                config.push_str(&format!("{} {}\n", route.reaperosc_action(), pattern))
+            }
+            None => config.push_str(&format!(
+                "# {} {}: no .ReaperOSC type for its arguments\n",
+                route.reaperosc_action(),
+                route.osc_address
+            )),
+        }
+    }
+    config
+}
+
+// Formats generated code, falling back to it unformatted if rustfmt isn't usable
+fn formatted(code: &str) -> String {
+    match std::panic::catch_unwind(|| format_code(code)) {
+        Ok(formatted) if !formatted.trim().is_empty() => formatted,
+        // rustfmt output was empty, fallback to unformatted
+        _ => code.to_string(),
+    }
+}
+
+/// Why a spec couldn't be loaded
+#[derive(Debug)]
+pub enum SpecLoadError {
+    Read(std::io::Error),
+    Yaml(serde_yaml::Error),
+    /// The spec parsed, but these routes are wrong
+    Invalid(Vec<SpecError>),
+}
+
+impl Display for SpecLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpecLoadError::Read(e) => write!(f, "error: couldn't read the spec: {}", e),
+            SpecLoadError::Yaml(e) => write!(f, "error: the spec isn't valid YAML: {}", e),
+            SpecLoadError::Invalid(errors) => {
+                for error in errors {
+                    writeln!(f, "error: {}", error)?;
+                }
+                write!(f, "{} error(s) in the spec", errors.len())
+            }
+        }
+    }
+}
+
+/// Reads the spec from YAML, with each route's names resolved, and checks it; every problem with
+/// it is reported at once.
+pub fn parse_spec(yaml: &str) -> Result<Vec<OscRoute>, SpecLoadError> {
+    let mut routes: Vec<OscRoute> = serde_yaml::from_str(yaml).map_err(SpecLoadError::Yaml)?;
+    resolve_names(&mut routes);
+    let errors = validate(&routes);
+    match errors.is_empty() {
+        true => Ok(routes),
+        false => Err(SpecLoadError::Invalid(errors)),
+    }
+}
+
+/// Like parse_spec, but for the spec in the file at `path`
+pub fn load_spec(path: &Path) -> Result<Vec<OscRoute>, SpecLoadError> {
+    parse_spec(&fs::read_to_string(path).map_err(SpecLoadError::Read)?)
+}
+
+/// A line for each route of the spec, giving its accessor, what it carries and what it can do,
+/// and a line for each context the routes are grouped into
+pub fn describe_spec(routes: &[OscRoute]) -> String {
+    let mut description = String::new();
+    for route in routes {
+        let arguments: Vec<String> = route
+            .arguments
+            .iter()
+            .map(|arg| match arg.is_omittable() {
+                true => format!("{}?: {}", arg.name, arg.typ),
+                false => format!("{}: {}", arg.name, arg.typ),
+            })
+            .collect();
+        writeln!(
+            description,
+            "{} -> {}({}) [{}]",
+            route.osc_address,
+            route.accessor_name(),
+            arguments.join(", "),
+            sorted_tags(&route.access_tags)
+        )
+        .unwrap();
+    }
+    let contexts = collect_contexts(routes);
+    for context in contexts.values() {
+        let parameters: Vec<&str> = context
+            .parameters
+            .iter()
+            .map(|param| param.name.as_str())
+            .collect();
+        writeln!(
+            description,
+            "context {}({}) under {}",
+            context.name,
+            parameters.join(", "),
+            context.prefix
+        )
+        .unwrap();
+    }
+    writeln!(
+        description,
+        "{} routes in {} contexts",
+        routes.len(),
+        contexts.len()
+    )
+    .unwrap();
+    description
+}
+
+/// Generates what `args` asks for from its spec, writing it out. Nothing is written if the spec
+/// can't be loaded.
+pub fn run(args: &GenArgs) -> Result<(), SpecLoadError> {
+    let routes = load_spec(&args.spec)?;
+    if args.emit == Emit::Reaperosc {
+        fs::write(&args.out, generate_reaperosc(&routes))
+            .expect("Failed to write output .ReaperOSC file");
+        return Ok(());
+    }
+    let options = CodegenOptions {
+        async_client: args.async_client,
+        split: args.split,
+        thread_safe: args.thread_safe,
+        serialize: args.serialize,
+        tests: args.tests,
+        out_of_range: args.out_of_range,
+    };
+
+    if !options.split {
+        let code = generate_code(&routes, &options);
+        fs::write(&args.out, formatted(&code)).expect("Failed to write output Rust file");
+        return Ok(());
+    }
+    fs::create_dir_all(&args.out).expect("Failed to create output directory");
+    for (file_name, code) in generate_modules(&routes, &options) {
+        fs::write(args.out.join(file_name), formatted(&code))
+            .expect("Failed to write output Rust file");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_osc_address_template_to_regex {
+    use super::*;
+
+    #[test]
+    fn test_track_index() {
+        let regex_str = osc_address_template_to_regex("/track/{track_guid}/index");
+        let re = regex::Regex::new(&regex_str).unwrap();
+        let caps = re.captures("/track/1234/index").unwrap();
+        assert_eq!(&caps[1], "1234");
+    }
+
+    #[test]
+    fn test_track_selected() {
+        let regex_str = osc_address_template_to_regex("/track/{track_guid}/selected");
+        let re = regex::Regex::new(&regex_str).unwrap();
+        let caps = re.captures("/track/abcd/selected").unwrap();
+        assert_eq!(&caps[1], "abcd");
+    }
+
+    #[test]
+    fn test_track_send_guid() {
+        let regex_str = osc_address_template_to_regex("/track/{track_guid}/send/{send_index}/guid");
+        let re = regex::Regex::new(&regex_str).unwrap();
+        let caps = re.captures("/track/abcd/send/5/guid").unwrap();
+        assert_eq!(&caps[1], "abcd");
+        assert_eq!(&caps[2], "5");
+    }
+
+    #[test]
+    fn test_track_send_volume() {
+        let regex_str =
+            osc_address_template_to_regex("/track/{track_guid}/send/{send_index}/volume");
+        let re = regex::Regex::new(&regex_str).unwrap();
+        let caps = re.captures("/track/abcd/send/3/volume").unwrap();
+        assert_eq!(&caps[1], "abcd");
+        assert_eq!(&caps[2], "3");
+    }
+}
+
+#[cfg(test)]
+mod test_build_context_name {
+    use super::*;
+
+    #[test]
+    fn test_track_index() {
+        assert_eq!(build_context_name("/track/{track_guid}/index"), "Track");
+    }
+
+    #[test]
+    fn test_track_selected() {
+        assert_eq!(build_context_name("/track/{track_guid}/selected"), "Track");
+    }
+
+    #[test]
+    fn test_track_send_guid() {
+        assert_eq!(
+            build_context_name("/track/{track_guid}/send/{send_index}/guid"),
+            "TrackSend"
+        );
+    }
+
+    #[test]
+    fn test_track_send_volume() {
+        assert_eq!(
+            build_context_name("/track/{track_guid}/send/{send_index}/volume"),
+            "TrackSend"
+        );
+    }
+
+    #[test]
+    fn test_nested_example() {
+        assert_eq!(
+            build_context_name("/track/{track_guid}/fx/{fx_guid}/param/{param_guid}/value"),
+            "TrackFxParam"
+        );
+    }
+
+    #[test]
+    fn test_single_path() {
+        assert_eq!(
+            build_context_name("/project/{project_guid}/name"),
+            "Project"
+        );
+    }
+
+    #[test]
+    fn test_segments_are_sanitized() {
+        assert_eq!(build_context_name("/fx-info/{ident}/name"), "FxInfo");
+    }
+
+    fn routes(yaml: &str) -> Vec<OscRoute> {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_colliding_contexts_are_named_after_their_whole_prefix() {
+        let mut routes = routes(
+            r#"
+- osc_address: "/track/{track_guid}/fx/{fx_idx}/name"
+  params:
+    - name: track_guid
+      type: string
+    - name: fx_idx
+      type: int
+  arguments: []
+  access_tags: [readable]
+- osc_address: "/track_fx/{fx_ident}/name"
+  params:
+    - name: fx_ident
+      type: string
+  arguments: []
+  access_tags: [readable]
+- osc_address: "/track/{track_guid}/name"
+  params:
+    - name: track_guid
+      type: string
+  arguments: []
+  access_tags: [readable]
+"#,
+        );
+        let names: Vec<String> = resolve_context_names(&routes).into_values().collect();
+        assert_eq!(names, ["Track", "TrackTrackGuidFxFxIdx", "TrackFxFxIdent"]);
+        // The routes' own names collide as well
+        resolve_names(&mut routes);
+        assert_eq!(validate(&routes), vec![]);
+        let code = generate_code(&routes, &CodegenOptions::default());
+        assert!(code.contains("pub struct TrackTrackGuidFxFxIdx {"));
+        assert!(code.contains("pub struct TrackFxFxIdent {"));
+    }
+}
+
+#[cfg(test)]
+mod test_async_codegen {
+    use super::*;
+
+    fn volume_route() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable, queryable]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sync_is_default() {
+        let code = generate_code(&volume_route(), &CodegenOptions::default());
+        assert!(code.contains("socket: Arc<dyn OscTransport>"));
+        assert!(code.contains("impl Set<TrackVolumeArgs> for TrackVolume"));
+        assert!(!code.contains(".await"));
+        assert!(!code.contains("receive_loop"));
+        assert!(code.contains("impl QueryWithResponse<TrackVolumeArgs> for TrackVolume"));
+        assert!(code.contains("fn parse_track_volume_args(msg: &rosc::OscMessage)"));
+        assert!(code.contains("reaper.pending.lock().unwrap().remove(&msg.addr)"));
+    }
+
+    #[test]
+    fn test_errors_keep_their_cause() {
+        let code = generate_code(&volume_route(), &CodegenOptions::default());
+        assert!(code.contains("use crate::osc::error::{OscError, check_address_segment};"));
+        assert!(!code.contains("pub struct OscError"));
+        assert!(!code.contains("map_err(|_| OscError)"));
+        assert!(code.contains("rosc::encoder::encode(&packet)?;"));
+        assert!(code.contains("None => self.socket.send(&buf)?,"));
+        // String parameters are checked before they go into an address
+        assert!(code.contains("check_address_segment(&self.track_guid)?;"));
+    }
+
+    #[test]
+    fn test_async_uses_tokio_socket() {
+        let options = CodegenOptions {
+            async_client: true,
+            ..Default::default()
+        };
+        let code = generate_code(&volume_route(), &options);
+        assert!(code.contains("use tokio::net::UdpSocket;"));
+        assert!(code.contains("impl AsyncSet<TrackVolumeArgs> for TrackVolume"));
+        assert!(code.contains("async fn set(&mut self, args: TrackVolumeArgs)"));
+        assert!(code.contains("impl AsyncQuery for TrackVolume"));
+        assert!(code.contains("self.socket.send(&buf).await"));
+        assert!(code.contains("pub async fn receive_loop"));
+        // Responses are only correlated by the sync client
+        assert!(!code.contains("QueryWithResponse"));
+        assert!(!code.contains("PendingResponses"));
+    }
+}
+
+#[cfg(test)]
+mod test_optional_arguments {
+    use super::*;
+
+    fn volume_route_with_unit() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+    - name: ramp
+      type: float
+      default: 0.25
+    - name: unit
+      type: string
+      optional: true
+    - name: index
+      type: int
+      optional: true
+  access_tags: [readable, writeable, queryable]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_args_fields_and_builder() {
+        let code = generate_code(&volume_route_with_unit(), &CodegenOptions::default());
+        assert!(code.contains("pub volume: f32,"));
+        assert!(code.contains("pub ramp: f32,"));
+        assert!(code.contains("pub unit: Option<String>,"));
+        assert!(code.contains("pub index: Option<i32>,"));
+        assert!(code.contains("pub fn new(volume: f32, ) -> Self"));
+        assert!(code.contains("ramp: 0.25,"));
+        assert!(code.contains("unit: None,"));
+        assert!(code.contains("pub fn with_ramp(mut self, ramp: f32) -> Self"));
+        assert!(code.contains("pub fn with_unit(mut self, unit: impl Into<String>) -> Self"));
+        assert!(code.contains("self.unit = Some(unit.into());"));
+    }
+
+    #[test]
+    fn test_omitted_arguments_are_left_off_the_message() {
+        let code = generate_code(&volume_route_with_unit(), &CodegenOptions::default());
+        assert!(code.contains("let mut osc_args = vec!["));
+        assert!(code.contains("rosc::OscType::Float(args.ramp)"));
+        assert!(code.contains("args.unit.map(rosc::OscType::String),"));
+        assert!(code.contains("args.index.map(rosc::OscType::Int),"));
+        assert!(code.contains(".map_while(|arg| arg),"));
+    }
+
+    #[test]
+    fn test_missing_arguments_decode_to_none_or_default() {
+        let code = generate_code(&volume_route_with_unit(), &CodegenOptions::default());
+        assert!(code.contains("volume: decode::required(&osc_args, 0)?,"));
+        assert!(code.contains("ramp: decode::optional(&osc_args, 1)?.unwrap_or(0.25),"));
+        assert!(code.contains("unit: decode::optional(&osc_args, 2)?,"));
+        // The dispatcher decodes through the same parser
+        assert!(code.contains("parse_track_volume_args(msg)"));
+    }
+
+    #[test]
+    #[should_panic(expected = "must be optional")]
+    fn test_required_argument_after_optional_is_rejected() {
+        let mut routes = volume_route_with_unit();
+        routes[0].arguments[3].optional = false;
+        generate_code(&routes, &CodegenOptions::default());
+    }
+}
+
+#[cfg(test)]
+mod test_dispatcher {
+    use super::*;
+
+    fn send_route() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/send/{send_index}/volume"
+  params:
+    - name: track_guid
+      type: string
+    - name: send_index
+      type: int
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_path_params_follow_address_order() {
+        let code = generate_code(&send_route(), &CodegenOptions::default());
+        assert!(
+            code.contains("match_address(\"/track/{track_guid}/send/{send_index}/volume\", addr)")
+        );
+        assert!(code.contains("let track_guid = args[0].clone();"));
+        assert!(code.contains("let Ok(send_index) = args[1].parse::<i32>() else { return true; };"));
+    }
+
+    #[test]
+    fn test_param_descriptions_document_their_fields() {
+        let mut routes = send_route();
+        routes[0].params[1].description = Some("index of the send on the track".to_string());
+        let code = generate_code(&routes, &CodegenOptions::default());
+        assert!(code.contains("    /// index of the send on the track\n    pub send_index: i32,\n"));
+        assert!(code.contains("    pub track_guid: String,\n"));
+        assert!(!code.contains("///\n    pub track_guid"));
+    }
+
+    #[test]
+    fn test_every_matching_route_is_dispatched() {
+        let code = generate_code(&send_route(), &CodegenOptions::default());
+        assert!(!code.contains("return;"));
+        assert!(code.contains(
+            "matched |= dispatch_track_send_volume_route(reaper, msg, addr, undecodable);"
+        ));
+        assert!(
+            code.contains("if !matched {\n        log_unknown(addr, &Unhandled::UnknownAddress);")
+        );
+    }
+
+    fn routes(addresses: &[&str]) -> Vec<OscRoute> {
+        addresses
+            .iter()
+            .map(|address| OscRoute {
+                osc_address: address.to_string(),
+                params: extract_context_params(&OscRoute {
+                    osc_address: address.to_string(),
+                    params: vec![],
+                    arguments: vec![],
+                    access_tags: BTreeSet::new(),
+                    rust_name: None,
+                })
+                .into_iter()
+                .map(|param| OscParam {
+                    name: param.name,
+                    typ: "string".to_string(),
+                    description: None,
+                    rust_type: None,
+                })
+                .collect(),
+                arguments: vec![],
+                access_tags: BTreeSet::from([AccessTag::Readable]),
+                rust_name: None,
+            })
+            .collect()
+    }
+
+    // The segment dispatch, with whitespace squashed so it can be compared with a literal
+    fn segment_dispatch(routes: &[OscRoute]) -> String {
+        let mut code = String::new();
+        write_dispatcher(&mut code, routes.to_vec(), &CodegenOptions::default());
+        let start = code.find("fn dispatch_by_segment").unwrap();
+        let end = start + code[start..].find("\n}\n\n").unwrap();
+        code[start..end]
+            .lines()
+            .skip(3)
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_routes_are_narrowed_by_literal_segments() {
+        let routes = routes(&[
+            "/track/{track_guid}/volume",
+            "/track/{track_guid}/pan",
+            "/track/all_guids",
+            "/num_tracks",
+        ]);
+        assert_eq!(
+            segment_dispatch(&routes),
+            [
+                "match segments.first().copied() {",
+                "Some(\"num_tracks\") => {",
+                "matched |= dispatch_num_tracks_route(reaper, msg, addr, undecodable);",
+                "}",
+                "Some(\"track\") => {",
+                // Both the literal and the placeholder are tried for the second segment
+                "if let Some(\"all_guids\") = segments.get(1).copied() {",
+                "matched |= dispatch_track_all_guids_route(reaper, msg, addr, undecodable);",
+                "}",
+                "match segments.get(2).copied() {",
+                "Some(\"pan\") => {",
+                "matched |= dispatch_track_pan_route(reaper, msg, addr, undecodable);",
+                "}",
+                "Some(\"volume\") => {",
+                "matched |= dispatch_track_volume_route(reaper, msg, addr, undecodable);",
+                "}",
+                "_ => {}",
+                "}",
+                "}",
+                "_ => {}",
+                "}",
+                "matched",
+            ]
+            .join(" ")
+        );
+    }
+
+    #[test]
+    fn test_pattern_addresses_try_every_route() {
+        let routes = routes(&["/track/{track_guid}/volume", "/num_tracks"]);
+        let code = generate_code(&routes, &CodegenOptions::default());
+        assert!(
+            code.contains("true => dispatch_every_route(reaper, &msg, addr, &mut undecodable),")
+        );
+        assert!(code.contains(
+            "fn dispatch_every_route(reaper: &mut Reaper, msg: &rosc::OscMessage, addr: &str, undecodable: &mut Vec<String>) -> bool {\n    let mut matched = false;\n    matched |= dispatch_track_volume_route(reaper, msg, addr, undecodable);\n    matched |= dispatch_num_tracks_route(reaper, msg, addr, undecodable);\n    matched\n}"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_argument_types {
+    use super::*;
+
+    fn sysex_route() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/sysex"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: data
+      type: blob
+    - name: position
+      type: double
+    - name: sample
+      type: long
+    - name: names
+      type: array<string>
+    - name: event
+      type: midi
+    - name: levels
+      type: array<float>
+      optional: true
+  access_tags: [readable, writeable]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_args_fields() {
+        let code = generate_code(&sysex_route(), &CodegenOptions::default());
+        assert!(code.contains("pub data: Vec<u8>,"));
+        assert!(code.contains("pub position: f64,"));
+        assert!(code.contains("pub sample: i64,"));
+        assert!(code.contains("pub names: Vec<String>,"));
+        assert!(code.contains("pub event: rosc::OscMidiMessage,"));
+        assert!(code.contains("pub levels: Option<Vec<f32>>,"));
+    }
+
+    #[test]
+    fn test_arguments_encode_to_their_osc_type() {
+        let code = generate_code(&sysex_route(), &CodegenOptions::default());
+        assert!(code.contains("rosc::OscType::Blob(args.data)"));
+        assert!(code.contains("rosc::OscType::Double(args.position)"));
+        assert!(code.contains("rosc::OscType::Long(args.sample)"));
+        assert!(code.contains("rosc::OscType::Midi(args.event)"));
+        assert!(code.contains(
+            "rosc::OscType::Array(rosc::OscArray { content: args.names.into_iter().map(rosc::OscType::String).collect() })"
+        ));
+        assert!(code.contains(
+            "args.levels.map(|items: Vec<f32>| rosc::OscType::Array(rosc::OscArray { content: items.into_iter().map(rosc::OscType::Float).collect() }))"
+        ));
+    }
+
+    #[test]
+    fn test_doubles_keep_their_precision() {
+        let routes: Vec<OscRoute> = serde_yaml::from_str(
+            r#"
+- osc_address: "/marker/{beat}/position"
+  params:
+    - name: beat
+      type: double
+  arguments:
+    - name: position
+      type: double
+  access_tags: [readable, writeable]
+"#,
+        )
+        .unwrap();
+        assert!(validate(&routes).is_empty());
+        let code = generate_code(&routes, &CodegenOptions::default());
+        assert!(code.contains("let Ok(beat) = args[0].parse::<f64>() else { return true; };"));
+        assert!(code.contains("rosc::OscType::Double(args.position)"));
+        // Set from a double as it is, and from a float as before
+        assert!(code.contains(
+            "        let position = match value {\n            ParamValue::Double(value) => value,\n            ParamValue::Float(value) => value as f64,\n"
+        ));
+    }
+
+    #[test]
+    fn test_arguments_decode_from_their_osc_type() {
+        // Which type each argument decodes to follows from the type of its field
+        let code = generate_code(&sysex_route(), &CodegenOptions::default());
+        assert!(code.contains("data: decode::required(&osc_args, 0)?,"));
+        assert!(code.contains("position: decode::required(&osc_args, 1)?,"));
+        assert!(code.contains("sample: decode::required(&osc_args, 2)?,"));
+        assert!(code.contains("names: decode::required(&osc_args, 3)?,"));
+        assert!(code.contains("event: decode::required(&osc_args, 4)?,"));
+    }
+
+    #[test]
+    fn test_undecodable_arguments_are_reported() {
+        let code = generate_code(&sysex_route(), &CodegenOptions::default());
+        assert!(code.contains("-> Result<TrackSysexArgs, String> {"));
+        assert!(code.contains(
+            "    if let Err(reason) = parse_track_sysex_args(msg) {\n        undecodable.push(reason);\n        return true;\n    }"
+        ));
+        assert!(code.contains(
+            "    for reason in undecodable {\n        log_unknown(addr, &Unhandled::BadArgs(reason));\n    }"
+        ));
+    }
+
+    #[test]
+    fn test_nested_array_type() {
+        let arg_type = ArgType::of("array<array<int>>");
+        assert_eq!(arg_type.rust_type(), "Vec<Vec<i32>>");
+        assert_eq!(ArgType::parse("array<int"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported OSC type 'color'")]
+    fn test_unknown_type_is_rejected() {
+        let mut routes = sysex_route();
+        routes[0].arguments[0].typ = "color".to_string();
+        generate_code(&routes, &CodegenOptions::default());
+    }
+}
+
+#[cfg(test)]
+mod test_split_output {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable]
+- osc_address: "/track/{track_guid}/mute"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: mute
+      type: bool
+  access_tags: [readable]
+- osc_address: "/num_tracks"
+  params: []
+  arguments:
+    - name: num_tracks
+      type: int
+  access_tags: [readable]
+"#,
+        )
+        .unwrap()
+    }
+
+    fn split_options() -> CodegenOptions {
+        CodegenOptions {
+            split: true,
+            ..Default::default()
+        }
+    }
+
+    fn file<'a>(files: &'a [(String, String)], name: &str) -> &'a str {
+        files
+            .iter()
+            .find(|(file_name, _)| file_name == name)
+            .map(|(_, code)| code.as_str())
+            .unwrap_or_else(|| panic!("No {} generated", name))
+    }
+
+    #[test]
+    fn test_routes_are_grouped_by_top_level_node() {
+        let files = generate_modules(&routes(), &split_options());
+        let names: Vec<_> = files.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "mod.rs",
+                "context.rs",
+                "dispatcher.rs",
+                "num_tracks.rs",
+                "track.rs"
+            ]
+        );
+        let track = file(&files, "track.rs");
+        assert!(track.contains("pub struct TrackVolume {"));
+        assert!(track.contains("pub struct TrackMute {"));
+        assert!(track.contains("pub fn track_volume(&self"));
+        assert!(!track.contains("NumTracks"));
+        assert!(file(&files, "num_tracks.rs").contains("pub struct NumTracks {"));
+    }
+
+    #[test]
+    fn test_mod_rs_reexports_every_module() {
+        let files = generate_modules(&routes(), &split_options());
+        let root = file(&files, "mod.rs");
+        for line in [
+            "pub mod context;",
+            "mod dispatcher;",
+            "mod track;",
+            "pub use context::context_kind;",
+            "pub use dispatcher::*;",
+            "pub use num_tracks::*;",
+            "pub use track::*;",
+            "pub struct Reaper {",
+        ] {
+            assert!(root.contains(line), "mod.rs is missing {}", line);
+        }
+        assert!(!root.contains("pub fn dispatch_osc"));
+        assert!(file(&files, "dispatcher.rs").contains("pub fn dispatch_osc"));
+        assert!(file(&files, "context.rs").contains("pub mod context_kind {"));
+    }
+
+    #[test]
+    fn test_dispatcher_can_reach_endpoint_internals() {
+        let files = generate_modules(&routes(), &split_options());
+        let track = file(&files, "track.rs");
+        assert!(track.contains("    pub(super) handlers: Vec<(Subscription, TrackVolumeHandler)>,"));
+        assert!(track.contains("pub(super) fn parse_track_volume_args("));
+
+        // A single file keeps them private
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains("    handlers: Vec<(Subscription, TrackVolumeHandler)>,"));
+        assert!(!code.contains("pub(super)"));
+    }
+
+    #[test]
+    fn test_module_names_avoid_the_fixed_modules() {
+        let mut route = routes().remove(2);
+        route.osc_address = "/dispatcher/state".to_string();
+        assert_eq!(module_name(&route), "dispatcher_routes");
+        route.osc_address = "/fx-info".to_string();
+        assert_eq!(module_name(&route), "fx_info");
+    }
+}
+
+#[cfg(test)]
+mod test_validation {
+    use super::*;
+
+    fn routes(yaml: &str) -> Vec<OscRoute> {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn messages(routes: &[OscRoute]) -> Vec<String> {
+        validate(routes).iter().map(|e| e.to_string()).collect()
+    }
+
+    #[test]
+    fn test_valid_spec_has_no_errors() {
+        let routes = routes(
+            r#"
+- osc_address: "/track/{track_guid}/send/{send_index}/volume"
+  params:
+    - name: track_guid
+      type: string
+    - name: send_index
+      type: int
+  arguments:
+    - name: volume
+      type: float
+      optional: true
+      default: 0.5
+  access_tags: [readable, writeable]
+"#,
+        );
+        assert_eq!(messages(&routes), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_every_problem_is_reported() {
+        let routes = routes(
+            r#"
+- osc_address: "/track/{track_guid}/fx/{fx_idx}/name"
+  params:
+    - name: track_guid
+      type: string
+    - name: fx_index
+      type: array<int>
+  arguments:
+    - name: name
+      type: text
+    - name: wet
+      type: float
+      default: "full"
+  access_tags: []
+"#,
+        );
+        assert_eq!(
+            messages(&routes),
+            [
+                "/track/{track_guid}/fx/{fx_idx}/name: no access tags; it needs at least one of readable, writeable or queryable",
+                "/track/{track_guid}/fx/{fx_idx}/name: path parameter {fx_idx} isn't declared in params",
+                "/track/{track_guid}/fx/{fx_idx}/name: param fx_index doesn't appear in the address",
+                "/track/{track_guid}/fx/{fx_idx}/name: param fx_index has type 'array<int>', but path parameters must be one of int, float, double, bool, string",
+                "/track/{track_guid}/fx/{fx_idx}/name: argument name has unknown type 'text'",
+                "/track/{track_guid}/fx/{fx_idx}/name: the default of argument wet doesn't match its type 'float'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_required_argument_after_optional_is_reported() {
+        let routes = routes(
+            r#"
+- osc_address: "/track/{track_guid}/send"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: level
+      type: float
+      optional: true
+    - name: pan
+      type: float
+  access_tags: [writeable]
+"#,
+        );
+        assert_eq!(
+            messages(&routes),
+            ["/track/{track_guid}/send: argument pan must be optional since it follows an optional argument"]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_addresses_are_reported() {
+        let routes = routes(
+            r#"
+- osc_address: "/track/{track_guid}/mute"
+  params:
+    - name: track_guid
+      type: string
+  arguments: []
+  access_tags: [readable]
+- osc_address: "/track/{guid}/mute"
+  params:
+    - name: guid
+      type: string
+  arguments: []
+  access_tags: [writeable, readable]
+- osc_address: "/track/{track_guid}/mute"
+  params:
+    - name: track_guid
+      type: string
+  arguments: []
+  access_tags: [readable]
+- osc_address: "/track/{track_guid}/fx-info"
+  params:
+    - name: track_guid
+      type: string
+  arguments: []
+  access_tags: [readable]
+- osc_address: "/track/{track_guid}/fx_info"
+  params:
+    - name: track_guid
+      type: string
+  arguments: []
+  access_tags: [readable]
+"#,
+        );
+        assert_eq!(
+            messages(&routes),
+            [
+                "/track/{guid}/mute: duplicates /track/{track_guid}/mute with conflicting access tags [readable] and [readable, writeable]",
+                "/track/{track_guid}/mute: duplicates /track/{track_guid}/mute",
+                "/track/{track_guid}/fx_info: generates the type TrackFxInfo like /track/{track_guid}/fx-info does; give one of them a rust_name",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_colliding_routes_are_named_after_their_whole_address() {
+        let mut routes = routes(
+            r#"
+- osc_address: "/track/{track_guid}/fx"
+  params:
+    - name: track_guid
+      type: string
+  arguments: []
+  access_tags: [readable]
+- osc_address: "/track/fx"
+  params: []
+  arguments: []
+  access_tags: [readable]
+- osc_address: "/track/{track_guid}/mute"
+  params:
+    - name: track_guid
+      type: string
+  arguments: []
+  access_tags: [readable]
+"#,
+        );
+        assert_eq!(
+            messages(&routes),
+            ["/track/fx: generates the type TrackFx like /track/{track_guid}/fx does; give one of them a rust_name"]
+        );
+        resolve_names(&mut routes);
+        assert_eq!(messages(&routes), Vec::<String>::new());
+        let names: Vec<(String, String)> = routes
+            .iter()
+            .map(|route| (route.accessor_name(), route.struct_name()))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                (
+                    "track_track_guid_fx".to_string(),
+                    "TrackTrackGuidFx".to_string()
+                ),
+                ("track_fx".to_string(), "TrackFx".to_string()),
+                ("track_mute".to_string(), "TrackMute".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rust_name_overrides_generated_names() {
+        let mut routes = routes(
+            r#"
+- osc_address: "/track/{track_guid}/fx-info"
+  params:
+    - name: track_guid
+      type: string
+  arguments: []
+  access_tags: [readable]
+  rust_name: track_plugins
+- osc_address: "/track/{track_guid}/fx_info"
+  params:
+    - name: track_guid
+      type: string
+  arguments: []
+  access_tags: [readable]
+- osc_address: "/track/{track_guid}/mute"
+  params:
+    - name: track_guid
+      type: string
+  arguments: []
+  access_tags: [readable]
+  rust_name: Track-Mute
+"#,
+        );
+        resolve_names(&mut routes);
+        assert_eq!(
+            messages(&routes),
+            ["/track/{track_guid}/mute: rust_name \"Track-Mute\" must be snake_case, starting with a letter"]
+        );
+        assert_eq!(routes[0].accessor_name(), "track_plugins");
+        assert_eq!(routes[0].struct_name(), "TrackPlugins");
+        // Nothing else is called that any more, so the other route keeps its name
+        assert_eq!(routes[1].struct_name(), "TrackFxInfo");
+        let code = generate_code(&routes[..2], &CodegenOptions::default());
+        assert!(code.contains("pub fn track_plugins(&self, track_guid: String) -> TrackPlugins"));
+        assert!(code.contains("pub struct TrackPluginsArgs"));
+    }
+}
+
+#[cfg(test)]
+mod test_thread_safe {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable]
+- osc_address: "/track/{track_guid}/delete"
+  params:
+    - name: track_guid
+      type: string
+  arguments: []
+  access_tags: [writeable]
+"#,
+        )
+        .unwrap()
+    }
+
+    fn thread_safe_options() -> CodegenOptions {
+        CodegenOptions {
+            thread_safe: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_handlers_must_be_send() {
+        let code = generate_code(&routes(), &thread_safe_options());
+        assert!(code.contains(
+            "pub type TrackVolumeHandler = Box<dyn FnMut(TrackVolumeArgs) + Send + 'static>;"
+        ));
+        assert!(code.contains("where F: FnMut(TrackVolumeArgs) + Send + 'static {"));
+    }
+
+    #[test]
+    fn test_bound_handlers_live_in_the_reaper() {
+        let code = generate_code(&routes(), &thread_safe_options());
+        assert!(code.contains("pub struct Reaper {\n    socket: Arc<dyn OscTransport>,\n    destination: Option<SocketAddr>,\n    pending: PendingResponses,\n    handlers: Handlers,\n}"));
+        assert!(code.contains(".entry(osc_address).or_default().push((subscription, handler));"));
+        assert!(code.contains("handlers: self.handlers.clone(),"));
+        assert!(!code.contains("handler: None,"));
+        // Nothing can be bound on a write-only endpoint, so it doesn't hold the handlers
+        let delete = code.split("pub struct TrackDelete {").nth(1).unwrap();
+        assert!(!delete.split('}').next().unwrap().contains("handlers"));
+    }
+
+    #[test]
+    fn test_dispatch_only_needs_a_shared_reaper() {
+        let code = generate_code(&routes(), &thread_safe_options());
+        assert!(code.contains("pub fn dispatch_osc<F>(reaper: &Reaper, msg: rosc::OscMessage"));
+        assert!(code.contains("    call_bound_handlers(reaper, &msg);\n"));
+        assert!(code.contains("fn dispatch_by_segment(reaper: &Reaper,"));
+        assert!(!code.contains("let mut endpoint"));
+
+        // Without the flag a Reaper is only used from one thread, as before
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains("pub fn dispatch_osc<F>(reaper: &mut Reaper, msg: rosc::OscMessage"));
+        assert!(code.contains("where F: FnMut(TrackVolumeArgs) + 'static {"));
+        assert!(!code.contains("Handlers"));
+    }
+
+    #[test]
+    fn test_binding_again_adds_a_handler() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains("        self.handlers.push((subscription, handler));\n"));
+        assert!(code.contains("    for (_, handler) in endpoint.handlers.iter_mut() {\n"));
+        assert!(!code.contains("self.handler = Some("));
+    }
+
+    #[test]
+    fn test_subscriptions_can_be_unbound() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains("impl Subscribe<TrackVolumeArgs> for TrackVolume {"));
+        assert!(
+            code.contains("        self.handlers.retain(|(other, _)| *other != subscription);\n")
+        );
+
+        // A thread-safe Reaper forgets the address once nothing is bound on it
+        let code = generate_code(&routes(), &thread_safe_options());
+        let unbind = code
+            .split("impl Subscribe<TrackVolumeArgs> for TrackVolume {")
+            .nth(1)
+            .unwrap();
+        assert!(unbind.contains("            return false;\n"));
+        assert!(unbind.contains("            handlers.remove(&osc_address);\n"));
+        // Handlers run without the lock held, so they can unbind themselves
+        assert!(code.contains("            .map(|(_, handler)| handler.clone())\n"));
+    }
+
+    #[test]
+    fn test_async_client_cannot_be_thread_safe() {
+        let cli =
+            GenArgs::try_parse_from(["reaper_oscgen", "spec.yaml", "--async", "--thread-safe"]);
+        assert!(cli.is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_reaperosc {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/send/{send_index}/volume"
+  params:
+    - name: track_guid
+      type: string
+    - name: send_index
+      type: int
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable]
+- osc_address: "/track/{track_guid}/delete"
+  params:
+    - name: track_guid
+      type: string
+  arguments: []
+  access_tags: [writeable]
+- osc_address: "/midi"
+  params: []
+  arguments:
+    - name: message
+      type: midi
+  access_tags: [readable]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_patterns_use_wildcards_and_type_prefixes() {
+        let config = generate_reaperosc(&routes());
+        assert!(config.contains("\nTRACK_SEND_VOLUME f/track/@/send/@/volume\n"));
+        assert!(config.contains("\nTRACK_DELETE t/track/@/delete\n"));
+    }
+
+    #[test]
+    fn test_untyped_routes_are_commented_out() {
+        let config = generate_reaperosc(&routes());
+        assert!(config.contains("# MIDI /midi: no .ReaperOSC type for its arguments\n"));
+        assert!(!config.contains("\nMIDI "));
+    }
+
+    #[test]
+    fn test_checked_in_config_matches_spec() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let yaml = fs::read_to_string(root.join("spec/reaper_osc.yaml")).unwrap();
+        let routes: Vec<OscRoute> = serde_yaml::from_str(&yaml).unwrap();
+        let checked_in = fs::read_to_string(root.join("spec/reaper_osc.ReaperOSC")).unwrap();
+        assert_eq!(
+            checked_in,
+            generate_reaperosc(&routes),
+            "spec/reaper_osc.ReaperOSC is stale; regenerate it with --emit reaperosc"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_address_helpers {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/send/{send_index}/volume"
+  params:
+    - name: track_guid
+      type: string
+    - name: send_index
+      type: int
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable]
+- osc_address: "/midi"
+  params: []
+  arguments:
+    - name: message
+      type: midi
+    - name: velocities
+      type: array<midi>
+  access_tags: [readable]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_endpoints_know_their_address() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains(
+            "    pub const ADDRESS_TEMPLATE: &str = \"/track/{track_guid}/send/{send_index}/volume\";\n"
+        ));
+        assert!(code.contains(
+            "        format!(\"/track/{}/send/{}/volume\", self.track_guid, self.send_index)\n"
+        ));
+        assert!(code.contains("impl std::fmt::Display for TrackSendVolume {"));
+        // Nothing to fill in
+        assert!(code.contains("    pub const ADDRESS_TEMPLATE: &str = \"/midi\";\n"));
+        assert!(code.contains("        Self::ADDRESS_TEMPLATE.to_string()\n"));
+    }
+
+    #[test]
+    fn test_args_serialize_behind_a_flag() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(!code.contains("serde"));
+
+        let options = CodegenOptions {
+            serialize: true,
+            ..Default::default()
+        };
+        let code = generate_code(&routes(), &options);
+        assert!(
+            code.contains("#[derive(Debug, serde::Serialize)]\npub struct TrackSendVolumeArgs {")
+        );
+        // rosc can't serialize MIDI messages, even in an array
+        let midi_args = code.split("pub struct MidiArgs {").nth(1).unwrap();
+        let midi_args = midi_args.split('}').next().unwrap();
+        assert_eq!(midi_args.matches("    #[serde(skip)]\n").count(), 2);
+    }
+}
+
+#[cfg(test)]
+mod test_destination {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable, queryable]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_endpoints_send_to_reapers_destination() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains(
+            "    pub fn with_destination(socket: Arc<dyn OscTransport>, destination: SocketAddr) -> Self {\n"
+        ));
+        assert!(code.contains("    destination: Option<SocketAddr>,\n"));
+        assert!(code.contains("        destination: self.destination,\n"));
+        // Setting, querying and bundles all go through it
+        assert_eq!(
+            code.matches("Some(destination) => self.socket.send_to(&buf, destination)?,")
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_async_endpoints_send_to_reapers_destination() {
+        let options = CodegenOptions {
+            async_client: true,
+            ..Default::default()
+        };
+        let code = generate_code(&routes(), &options);
+        assert!(code.contains(
+            "    pub fn with_destination(socket: Arc<UdpSocket>, destination: SocketAddr) -> Self {\n"
+        ));
+        assert!(code.contains("self.socket.send_to(&buf, destination).await?,"));
+    }
+}
+
+#[cfg(test)]
+mod test_dyn_endpoint {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable, queryable]
+- osc_address: "/stop"
+  params: []
+  arguments: []
+  access_tags: [writeable]
+- osc_address: "/num_tracks"
+  params: []
+  arguments:
+    - name: num_tracks
+      type: int
+  access_tags: [readable, queryable]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_every_endpoint_is_a_dyn_endpoint() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains("pub enum ParamValue {\n"));
+        assert!(code.contains("pub trait DynEndpoint {\n"));
+        for name in ["TrackVolume", "Stop", "NumTracks"] {
+            assert!(code.contains(&format!("impl DynEndpoint for {} {{\n", name)));
+        }
+    }
+
+    #[test]
+    fn test_set_dyn_checks_value_type() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains("            ParamValue::Float(value) => value,\n"));
+        assert!(code.contains("        self.set(TrackVolumeArgs::new(volume))\n"));
+        // Nothing to check for a trigger
+        assert!(code.contains("        self.set(StopArgs {})\n"));
+        // A read-only endpoint falls back on the trait's refusal
+        assert_eq!(code.matches("fn set_dyn(&mut self").count(), 3);
+        assert_eq!(code.matches("fn query_dyn(&self)").count(), 3);
+    }
+
+    #[test]
+    fn test_async_client_has_no_dyn_endpoints() {
+        let options = CodegenOptions {
+            async_client: true,
+            ..Default::default()
+        };
+        let code = generate_code(&routes(), &options);
+        assert!(!code.contains("DynEndpoint"));
+    }
+}
+
+#[cfg(test)]
+mod test_bundle_builder {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_set_builds_its_message_through_set_message() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains("impl SetMessage<TrackVolumeArgs> for TrackVolume {\n"));
+        assert!(code.contains(
+            "    fn set_message(&self, args: TrackVolumeArgs) -> Result<rosc::OscMessage, Self::Error> {\n        check_address_segment(&self.track_guid)?;\n"
+        ));
+        assert!(code.contains("        let osc_msg = self.set_message(args)?;\n"));
+    }
+
+    #[test]
+    fn test_bundle_builder_collects_sets() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains("    pub fn bundle(&self) -> BundleBuilder {\n"));
+        assert!(code.contains("        E: SetMessage<A, Error = OscError>,\n"));
+        assert!(code.contains(
+            "    pub fn commit(self) -> Result<Vec<Result<(), OscError>>, OscError> {\n"
+        ));
+        assert!(code.contains("            _ => rosc::OscPacket::Bundle(rosc::OscBundle {\n"));
+    }
+
+    #[test]
+    fn test_async_client_has_no_bundle_builder() {
+        let options = CodegenOptions {
+            async_client: true,
+            ..Default::default()
+        };
+        let code = generate_code(&routes(), &options);
+        assert!(!code.contains("BundleBuilder"));
+        assert!(!code.contains("SetMessage"));
+        assert!(code.contains("impl AsyncSet<TrackVolumeArgs> for TrackVolume {\n"));
+    }
+}
+
+#[cfg(test)]
+mod test_route_table {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/send/{send_index}/volume"
+  params:
+    - name: track_guid
+      type: string
+    - name: send_index
+      type: int
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable]
+- osc_address: "/play"
+  params: []
+  arguments: []
+  access_tags: [writeable]
+  rust_name: start_playback
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_every_route_is_listed_with_its_arguments() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains("pub const ROUTES: &[RouteInfo] = &[\n"));
+        assert!(code.contains(
+            "    RouteInfo { name: \"track_send_volume\", struct_name: \"TrackSendVolume\", address: \"/track/{track_guid}/send/{send_index}/volume\", direction: RouteDirection::Both, queryable: false, params: &[(\"track_guid\", \"string\"), (\"send_index\", \"int\")], arguments: &[(\"volume\", \"float\")] },\n"
+        ));
+        assert!(code.contains(
+            "    RouteInfo { name: \"start_playback\", struct_name: \"StartPlayback\", address: \"/play\", direction: RouteDirection::ToReaper, queryable: false, params: &[], arguments: &[] },\n"
+        ));
+        assert!(code.contains("pub fn all_routes() -> &'static [RouteInfo] {\n    ROUTES\n}\n"));
+    }
+
+    #[test]
+    fn test_direction_follows_access_tags() {
+        let mut routes = routes();
+        let route = &mut routes[0];
+        route.access_tags = BTreeSet::from([AccessTag::Readable, AccessTag::Queryable]);
+        assert_eq!(route_direction(route), "FromReaper");
+        route.access_tags = BTreeSet::from([AccessTag::Queryable]);
+        assert_eq!(route_direction(route), "ToReaper");
+        route.access_tags = BTreeSet::from([AccessTag::Readable, AccessTag::Writeable]);
+        assert_eq!(route_direction(route), "Both");
+    }
+
+    #[test]
+    fn test_split_output_lists_routes_in_mod_rs() {
+        let files = generate_modules(&routes(), &CodegenOptions::default());
+        assert!(files[0].1.contains("pub const ROUTES: &[RouteInfo]"));
+    }
+}
+
+#[cfg(test)]
+mod test_param_newtypes {
+    use super::*;
+
+    fn routes(guid_type: &str, rust_type: &str) -> Vec<OscRoute> {
+        serde_yaml::from_str(&format!(
+            r#"
+- osc_address: "/track/{{track_guid}}/volume"
+  params:
+    - name: track_guid
+      type: {}
+      rust_type: {}
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable]
+"#,
+            guid_type, rust_type
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_params_with_a_rust_type_use_the_newtype() {
+        let routes = routes("string", "TrackGuid");
+        assert_eq!(validate(&routes), vec![]);
+        let code = generate_code(&routes, &CodegenOptions::default());
+        assert!(code.contains("    pub track_guid: crate::guid::TrackGuid,\n"));
+        assert!(code.contains(
+            "    pub fn track_volume(&self, track_guid: crate::guid::TrackGuid) -> TrackVolume {\n"
+        ));
+        assert!(code.contains("context::Track{ track_guid: caps[1].into(),  }"));
+    }
+
+    #[test]
+    fn test_only_string_params_can_have_a_rust_type() {
+        let errors = validate(&routes("int", "TrackGuid"));
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0]
+            .message
+            .contains("only string params can have one"));
+    }
+
+    #[test]
+    fn test_rust_type_must_be_a_type_name() {
+        let errors = validate(&routes("string", "track_guid"));
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("must be a PascalCase type name"));
+    }
+}
+
+#[cfg(test)]
+mod test_round_trip_tests {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/send/{send_index}/volume"
+  params:
+    - name: track_guid
+      type: string
+    - name: send_index
+      type: int
+  arguments:
+    - name: volume
+      type: float
+    - name: unit
+      type: string
+      optional: true
+  access_tags: [readable, writeable]
+- osc_address: "/play"
+  params: []
+  arguments: []
+  access_tags: [writeable]
+"#,
+        )
+        .unwrap()
+    }
+
+    fn test_options() -> CodegenOptions {
+        CodegenOptions {
+            thread_safe: true,
+            tests: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_routes_that_can_be_set_and_bound_get_a_test() {
+        let code = generate_code(&routes(), &test_options());
+        assert!(code.contains("#[cfg(test)]\nmod generated_tests {\n"));
+        assert!(code.contains("    fn test_track_send_volume_round_trips() {\n"));
+        assert!(code.contains(
+            "        let mut endpoint = reaper.track_send_volume(\"test\".into(), 7);\n"
+        ));
+        assert!(code.contains(
+            "        let msg = endpoint.set_message(TrackSendVolumeArgs { volume: 0.5, unit: Some(\"test\".to_string()) }).unwrap();\n"
+        ));
+        // Nothing reports playing back through /play
+        assert!(!code.contains("fn test_play_round_trips()"));
+    }
+
+    #[test]
+    fn test_no_tests_unless_asked_for() {
+        let options = CodegenOptions {
+            thread_safe: true,
+            ..Default::default()
+        };
+        assert!(!generate_code(&routes(), &options).contains("mod generated_tests"));
+    }
+
+    #[test]
+    fn test_split_output_has_tests_in_mod_rs() {
+        let options = CodegenOptions {
+            split: true,
+            ..test_options()
+        };
+        let files = generate_modules(&routes(), &options);
+        assert!(files[0].1.contains("mod generated_tests {\n"));
+    }
+
+    #[test]
+    fn test_tests_need_a_thread_safe_client() {
+        let cli = GenArgs::try_parse_from(["reaper_oscgen", "spec.yaml", "--tests"]);
+        assert!(cli.is_err());
+        let cli =
+            GenArgs::try_parse_from(["reaper_oscgen", "spec.yaml", "--tests", "--thread-safe"]);
+        assert!(cli.is_ok());
+    }
+}
+
+#[cfg(test)]
+mod test_context_lookups {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable]
+- osc_address: "/track/{track_guid}/fx/{fx_idx}/name"
+  params:
+    - name: track_guid
+      type: string
+    - name: fx_idx
+      type: int
+  arguments:
+    - name: name
+      type: string
+  access_tags: [readable]
+"#,
+        )
+        .unwrap()
+    }
+
+    fn thread_safe() -> CodegenOptions {
+        CodegenOptions {
+            thread_safe: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("Track"), "track");
+        assert_eq!(snake_case("TrackFxParam"), "track_fx_param");
+    }
+
+    #[test]
+    fn test_lookups_for_each_context() {
+        let code = generate_code(&routes(), &thread_safe());
+        assert!(code.contains(
+            "    pub fn try_track(&self, track_guid: String) -> Option<context::Track> {\n"
+        ));
+        assert!(code.contains("        let prefix = format!(\"/track/{}/\", track_guid);\n"));
+        assert!(
+            code.contains("    pub fn tracks(&self) -> impl Iterator<Item = context::Track> {\n")
+        );
+        assert!(code.contains(
+            "    pub fn try_track_fx(&self, track_guid: String, fx_idx: i32) -> Option<context::TrackFx> {\n"
+        ));
+        assert!(code
+            .contains("        let prefix = format!(\"/track/{}/fx/{}/\", track_guid, fx_idx);\n"));
+        assert!(code.contains(
+            "        static RE: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| regex::Regex::new(r\"^/track/([^/]+)/fx/([^/]+)/\").unwrap());\n"
+        ));
+        assert!(code.contains(
+            "            Some(context::TrackFx { track_guid: caps[1].to_string(), fx_idx: caps[2].parse().ok()?,  })\n"
+        ));
+    }
+
+    #[test]
+    fn test_no_lookups_without_shared_handlers() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(!code.contains("pub fn try_track("));
+        assert!(!code.contains("pub fn tracks("));
+        assert!(!code.contains("pub fn unbind_contexts("));
+    }
+
+    #[test]
+    fn test_context_kinds_skip_params_of_the_wrong_type() {
+        let code = generate_code(&routes(), &thread_safe());
+        assert!(code.contains(
+            "            RE.captures(osc_address).and_then(|caps| Some(context::TrackFx{ track_guid: caps[1].to_string(), fx_idx: caps[2].parse().ok()?,  }))\n"
+        ));
+        assert!(!code.contains(".parse().unwrap()"));
+        // Contexts with nothing to parse can't fail on their captures
+        assert!(code.contains("            RE.captures(osc_address).map(|caps| context::Track{ "));
+    }
+
+    #[test]
+    fn test_context_handlers_can_be_dropped_together() {
+        let code = generate_code(&routes(), &thread_safe());
+        assert!(code.contains(
+            "    pub fn unbind_contexts(&self) {\n        self.handlers.lock().unwrap().retain(|addr, _| {\n            !ROUTES.iter().any(|route| !route.params.is_empty() && match_address(route.address, addr).is_some())\n        });\n    }\n"
+        ));
+    }
+
+    #[test]
+    fn test_split_output_has_lookups_in_mod_rs() {
+        let options = CodegenOptions {
+            split: true,
+            ..thread_safe()
+        };
+        let files = generate_modules(&routes(), &options);
+        assert!(files[0].1.contains("pub fn try_track("));
+    }
+}
+
+#[cfg(test)]
+mod test_value_ranges {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+      min: 0
+      max: 1.0
+    - name: curve
+      type: string
+      optional: true
+      enum: [linear, db]
+  access_tags: [readable, writeable]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_set_clamps_by_default() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains(
+            "        let mut args = args;\n        args.volume = crate::osc::range::clamp(args.volume, Some(0.0), Some(1.0));\n"
+        ));
+        // There's no bringing a value into an enum
+        assert!(code.contains(
+            "        if let Some(value) = &args.curve {\n            crate::osc::range::check_one_of(\"curve\", value, &[\"linear\", \"db\"]).map_err(OscError::OutOfRange)?;\n        }\n"
+        ));
+    }
+
+    #[test]
+    fn test_set_can_fail_instead() {
+        let options = CodegenOptions {
+            out_of_range: OutOfRange::Error,
+            ..Default::default()
+        };
+        let code = generate_code(&routes(), &options);
+        assert!(!code.contains("crate::osc::range::clamp"));
+        assert!(!code.contains("let mut args = args;"));
+        assert!(code.contains(
+            "        crate::osc::range::check_range(\"volume\", args.volume, Some(0.0), Some(1.0)).map_err(OscError::OutOfRange)?;\n"
+        ));
+    }
+
+    #[test]
+    fn test_parser_turns_away_values_out_of_range() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains(
+            "    let args = TrackVolumeArgs {\n        volume: decode::required(&osc_args, 0)?,\n        curve: decode::optional(&osc_args, 1)?,\n    };\n"
+        ));
+        assert!(code.contains(
+            "        crate::osc::range::check_range(\"volume\", args.volume, Some(0.0), Some(1.0))?;\n"
+        ));
+        assert!(code.contains(
+            "            crate::osc::range::check_one_of(\"curve\", value, &[\"linear\", \"db\"])?;\n        }\n    Ok(args)\n}\n"
+        ));
+    }
+
+    #[test]
+    fn test_round_trip_samples_are_in_range() {
+        let options = CodegenOptions {
+            thread_safe: true,
+            tests: true,
+            ..Default::default()
+        };
+        let code = generate_code(&routes(), &options);
+        assert!(
+            code.contains("TrackVolumeArgs { volume: 0.0, curve: Some(\"linear\".to_string()) }")
+        );
+    }
+
+    #[test]
+    fn test_bad_constraints_are_reported() {
+        let routes: Vec<OscRoute> = serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/name"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: name
+      type: string
+      min: 0
+    - name: index
+      type: int
+      min: 4
+      max: 2.5
+    - name: gain
+      type: float
+      min: 2
+      max: 1
+      enum: [1, 2]
+    - name: mode
+      type: int
+      enum: []
+  access_tags: [writeable]
+"#,
+        )
+        .unwrap();
+        let messages: Vec<String> = validate(&routes).iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            messages,
+            [
+                "/track/{track_guid}/name: argument name has a min, but only numeric arguments can have one",
+                "/track/{track_guid}/name: the max of argument index doesn't match its type 'int'",
+                "/track/{track_guid}/name: argument index has a min of 4 above its max of 2.5",
+                "/track/{track_guid}/name: argument gain has a min of 2 above its max of 1",
+                "/track/{track_guid}/name: argument gain has an enum, but only int, long and string arguments can have one",
+                "/track/{track_guid}/name: argument gain has both an enum and a min or max",
+                "/track/{track_guid}/name: the enum of argument mode is empty",
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_spec_loading {
+    use super::*;
+
+    const SPEC: &str = r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable, queryable]
+- osc_address: "/stop"
+  params: []
+  arguments:
+    - name: at
+      type: double
+      optional: true
+  access_tags: [writeable]
+"#;
+
+    #[test]
+    fn test_spec_is_described_route_by_route() {
+        let routes = parse_spec(SPEC).unwrap();
+        assert_eq!(
+            describe_spec(&routes),
+            "/track/{track_guid}/volume -> track_volume(volume: float) [queryable, readable, writeable]\n\
+             /stop -> stop(at?: double) [writeable]\n\
+             context Track(track_guid) under /track/{}\n\
+             2 routes in 1 contexts\n"
+        );
+    }
+
+    #[test]
+    fn test_every_problem_stops_the_spec_loading() {
+        let err = parse_spec(&SPEC.replace("type: float", "type: text")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "error: /track/{track_guid}/volume: argument volume has unknown type 'text'\n1 error(s) in the spec"
+        );
+        assert!(matches!(
+            parse_spec("- osc_address: [").unwrap_err(),
+            SpecLoadError::Yaml(_)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_deterministic_output {
+    use super::*;
+
+    const SPEC: &str = r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+  access_tags: [readable, writeable, queryable]
+- osc_address: "/track/{track_guid}/send/{send_index}/pan"
+  params:
+    - name: track_guid
+      type: string
+    - name: send_index
+      type: int
+  arguments:
+    - name: pan
+      type: float
+  access_tags: [writeable, readable]
+- osc_address: "/marker/{marker_idx}/name"
+  params:
+    - name: marker_idx
+      type: int
+  arguments:
+    - name: name
+      type: string
+  access_tags: [readable]
+- osc_address: "/stop"
+  params: []
+  arguments: []
+  access_tags: [writeable]
+"#;
+
+    fn every_option() -> CodegenOptions {
+        CodegenOptions {
+            thread_safe: true,
+            serialize: true,
+            tests: true,
+            ..Default::default()
+        }
+    }
+
+    // Each generation starts from freshly parsed routes, so any hashed collection along the way
+    // gets a new seed
+    #[test]
+    fn test_generating_twice_gives_the_same_code() {
+        let first = generate_code(&parse_spec(SPEC).unwrap(), &every_option());
+        let second = generate_code(&parse_spec(SPEC).unwrap(), &every_option());
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_generating_twice_gives_the_same_modules() {
+        let options = CodegenOptions {
+            split: true,
+            ..every_option()
+        };
+        let first = generate_modules(&parse_spec(SPEC).unwrap(), &options);
+        let second = generate_modules(&parse_spec(SPEC).unwrap(), &options);
+        assert_eq!(first, second);
+    }
+}
+
+#[cfg(test)]
+mod test_argument_positions {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/send/{send_index}/volume"
+  params:
+    - name: track_guid
+      type: string
+    - name: send_index
+      type: int
+  arguments:
+    - name: volume
+      type: float
+    - name: label
+      type: string
+      optional: true
+  access_tags: [readable, writeable]
+- osc_address: "/track/{track_guid}/meter"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: peak
+      type: float
+      arg_index: 1
+    - name: channel
+      type: int
+      arg_index: 0
+  access_tags: [readable, writeable]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_arguments_are_lined_up_by_type() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains(
+            "    let osc_args = decode::line_up(&msg.args, &[decode::expect::<f32>(), decode::expect::<String>()]);\n"
+        ));
+        assert!(code.contains("volume: decode::required(&osc_args, 0)?,"));
+        assert!(code.contains("label: decode::optional(&osc_args, 1)?,"));
+    }
+
+    #[test]
+    fn test_arg_index_says_where_an_argument_is() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains("peak: decode::required(&msg.args, 1)?,"));
+        assert!(code.contains("channel: decode::required(&msg.args, 0)?,"));
+        assert!(!code.contains(
+            "decode::line_up(&msg.args, &[decode::expect::<f32>(), decode::expect::<i32>()])"
+        ));
+        // What's sent keeps the spec's order, so it wouldn't come back as it was sent
+        let with_tests = CodegenOptions {
+            thread_safe: true,
+            tests: true,
+            ..Default::default()
+        };
+        let code = generate_code(&routes(), &with_tests);
+        assert!(code.contains("fn test_track_send_volume_round_trips()"));
+        assert!(!code.contains("fn test_track_meter_round_trips()"));
+    }
+
+    #[test]
+    fn test_two_arguments_cannot_share_an_index() {
+        let mut routes = routes();
+        routes[1].arguments[1].arg_index = None;
+        let errors = validate(&routes);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].message,
+            "argument channel is at index 1 like another argument is"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_args_from {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+    - name: unit
+      type: string
+      optional: true
+  access_tags: [readable, writeable]
+- osc_address: "/track/{track_guid}/name"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: name
+      type: string
+  access_tags: [readable, writeable]
+- osc_address: "/track/{track_guid}/meter"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: channel
+      type: int
+    - name: peak
+      type: float
+  access_tags: [readable]
+- osc_address: "/stop"
+  params: []
+  arguments: []
+  access_tags: [writeable]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_args_convert_from_their_required_arguments() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains(
+            "impl From<f32> for TrackVolumeArgs {\n    fn from(volume: f32) -> Self {\n        Self::new(volume)\n    }\n}\n"
+        ));
+        assert!(code.contains(
+            "impl From<(i32, f32)> for TrackMeterArgs {\n    fn from((channel, peak): (i32, f32)) -> Self {\n        Self::new(channel, peak)\n    }\n}\n"
+        ));
+        // A lone string converts from a literal too
+        assert!(code.contains("impl From<String> for TrackNameArgs {\n"));
+        assert!(code.contains("impl From<&str> for TrackNameArgs {\n"));
+        assert!(!code.contains("for StopArgs"));
+    }
+
+    #[test]
+    fn test_writeable_endpoints_set_values() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains(
+            "impl TrackVolume {\n    pub fn set_value(&mut self, value: impl Into<TrackVolumeArgs>) -> Result<(), OscError> {\n        self.set(value.into())\n    }\n}\n"
+        ));
+        assert_eq!(code.matches("pub fn set_value(").count(), 2);
+
+        let options = CodegenOptions {
+            async_client: true,
+            ..Default::default()
+        };
+        let code = generate_code(&routes(), &options);
+        assert!(code.contains(
+            "    pub async fn set_value(&mut self, value: impl Into<TrackVolumeArgs>) -> Result<(), OscError> {\n        self.set(value.into()).await\n    }\n"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test_address_properties {
+    use super::*;
+    use proptest::prelude::*;
+
+    const SANITIZED: [char; 6] = ['-', ' ', '.', '/', '?', '$'];
+
+    #[derive(Clone, Debug)]
+    enum Segment {
+        Literal(String),
+        Param(String),
+    }
+
+    impl Segment {
+        fn template(&self) -> String {
+            match self {
+                Segment::Literal(literal) => literal.clone(),
+                Segment::Param(name) => format!("{{{}}}", name),
+            }
+        }
+    }
+
+    fn template(segments: &[Segment]) -> String {
+        segments
+            .iter()
+            .map(|segment| format!("/{}", segment.template()))
+            .collect()
+    }
+
+    fn param_name() -> impl Strategy<Value = String> {
+        "[a-z][a-z0-9_]{0,6}"
+    }
+
+    // Anything that can go between two slashes without being taken for a param
+    fn weird_literal() -> impl Strategy<Value = String> {
+        "[^/{}]{1,8}"
+    }
+
+    // The kind of segment a spec is made of
+    fn plain_literal() -> impl Strategy<Value = String> {
+        "[a-z][a-z0-9_.-]{0,6}"
+    }
+
+    fn segments(literal: BoxedStrategy<String>) -> impl Strategy<Value = Vec<Segment>> {
+        let segment = prop_oneof![
+            literal.clone().prop_map(Segment::Literal),
+            param_name().prop_map(Segment::Param),
+        ];
+        (literal, prop::collection::vec(segment, 0..5)).prop_map(|(first, rest)| {
+            std::iter::once(Segment::Literal(first))
+                .chain(rest)
+                .collect()
+        })
+    }
+
+    fn route(osc_address: String) -> OscRoute {
+        let mut route = OscRoute {
+            osc_address,
+            params: vec![],
+            arguments: vec![],
+            access_tags: BTreeSet::from([AccessTag::Readable]),
+            rust_name: None,
+        };
+        route.params = extract_context_params(&route)
+            .into_iter()
+            .map(|param| OscParam {
+                name: param.name,
+                typ: "string".to_string(),
+                description: None,
+                rust_type: None,
+            })
+            .collect();
+        route
+    }
+
+    // The address a template is sent on, with each of its params filled in from `values`
+    fn fill(segments: &[Segment], values: &[String]) -> String {
+        let mut values = values.iter();
+        segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(literal) => format!("/{}", literal),
+                Segment::Param(_) => format!("/{}", values.next().unwrap()),
+            })
+            .collect()
+    }
+
+    fn param_count(segments: &[Segment]) -> usize {
+        segments
+            .iter()
+            .filter(|segment| matches!(segment, Segment::Param(_)))
+            .count()
+    }
+
+    proptest! {
+        #[test]
+        fn sanitizing_only_replaces_what_cant_be_in_an_identifier(level in ".{0,16}") {
+            let sanitized = sanitize_path_level(&level);
+            prop_assert!(!sanitized.contains(SANITIZED));
+            prop_assert_eq!(sanitize_path_level(&sanitized), sanitized.clone());
+            prop_assert_eq!(sanitized.chars().count(), level.chars().count());
+            for (before, after) in level.chars().zip(sanitized.chars()) {
+                match SANITIZED.contains(&before) {
+                    true => prop_assert_eq!(after, '_'),
+                    false => prop_assert_eq!(after, before),
+                }
+            }
+        }
+
+        #[test]
+        fn params_are_extracted_in_order(
+            segments in segments(weird_literal().boxed())
+        ) {
+            let expected: Vec<String> = segments
+                .iter()
+                .filter_map(|segment| match segment {
+                    Segment::Param(name) => Some(name.clone()),
+                    Segment::Literal(_) => None,
+                })
+                .collect();
+            let extracted: Vec<String> = extract_context_params(&route(template(&segments)))
+                .into_iter()
+                .map(|param| param.name)
+                .collect();
+            prop_assert_eq!(extracted, expected);
+        }
+
+        #[test]
+        fn regex_matches_every_filled_in_address(
+            (segments, values) in segments(weird_literal().boxed()).prop_flat_map(|segments| {
+                let count = param_count(&segments);
+                (Just(segments), prop::collection::vec("[^/]{1,8}", count))
+            })
+        ) {
+            let re = Regex::new(&osc_address_template_to_regex(&template(&segments))).unwrap();
+            let address = fill(&segments, &values);
+            let captures = re.captures(&address);
+            prop_assert!(captures.is_some(), "{} doesn't match {}", address, re);
+            let captured: Vec<&str> = captures
+                .unwrap()
+                .iter()
+                .skip(1)
+                .map(|capture| capture.unwrap().as_str())
+                .collect();
+            prop_assert_eq!(captured, values.iter().map(String::as_str).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn regex_matches_nothing_else(
+            (segments, values, at, other) in segments(weird_literal().boxed()).prop_flat_map(|segments| {
+                let count = param_count(&segments);
+                let len = segments.len();
+                (
+                    Just(segments),
+                    prop::collection::vec("[^/]{1,8}", count),
+                    0..len,
+                    weird_literal(),
+                )
+            })
+        ) {
+            let re = Regex::new(&osc_address_template_to_regex(&template(&segments))).unwrap();
+            let address = fill(&segments, &values);
+            let longer = format!("{}/{}", address, other);
+            prop_assert!(!re.is_match(&longer), "{} matches {}", longer, re);
+            if let Some((shorter, _)) = address.rsplit_once('/') {
+                prop_assert!(!re.is_match(shorter), "{} matches {}", shorter, re);
+            }
+            if let Segment::Literal(literal) = &segments[at] {
+                prop_assume!(*literal != other);
+                let mut changed = segments.clone();
+                changed[at] = Segment::Literal(other);
+                let changed = fill(&changed, &values);
+                prop_assert!(!re.is_match(&changed), "{} matches {}", changed, re);
+            }
+        }
+
+        #[test]
+        fn names_are_identifiers(segments in segments(plain_literal().boxed())) {
+            let route = route(template(&segments));
+            prop_assert!(is_pascal_case(&route.struct_name()), "{}", route.struct_name());
+            prop_assert!(is_snake_case(&route.accessor_name()), "{}", route.accessor_name());
+            prop_assert!(is_snake_case(&full_rust_name(&route.osc_address)));
+        }
+
+        #[test]
+        fn naming_never_panics(segments in segments(weird_literal().boxed())) {
+            let route = route(template(&segments));
+            route.struct_name();
+            route.accessor_name();
+            full_rust_name(&route.osc_address);
+        }
+
+        #[test]
+        fn names_never_collide_unreported(
+            addresses in prop::collection::vec(segments(plain_literal().boxed()), 1..6)
+        ) {
+            let mut routes: Vec<OscRoute> = addresses
+                .iter()
+                .map(|segments| route(template(segments)))
+                .collect();
+            resolve_names(&mut routes);
+            if validate_duplicates(&routes).is_empty() {
+                let struct_names: HashSet<String> =
+                    routes.iter().map(OscRoute::struct_name).collect();
+                prop_assert_eq!(struct_names.len(), routes.len());
+                let accessor_names: HashSet<String> =
+                    routes.iter().map(OscRoute::accessor_name).collect();
+                prop_assert_eq!(accessor_names.len(), routes.len());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_binary_args {
+    use super::*;
+
+    fn routes() -> Vec<OscRoute> {
+        serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/name"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: name
+      type: string
+      binary: true
+  access_tags: [readable, writeable]
+- osc_address: "/track/{track_guid}/label"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: label
+      type: string
+      binary: true
+      optional: true
+  access_tags: [readable, writeable]
+"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_binary_arguments_are_bytes_sent_as_strings() {
+        let code = generate_code(&routes(), &CodegenOptions::default());
+        assert!(code.contains("    pub name: Vec<u8>, //"));
+        assert!(code.contains("    pub label: Option<Vec<u8>>, //"));
+        assert!(code.contains("decode::expect::<Vec<u8>>()"));
+        assert!(code.contains("decode::binary_arg(args.name),\n"));
+        assert!(code.contains("args.label.map(decode::binary_arg),\n"));
+        assert!(code.contains("    const BINARY_ARGS: &'static [usize] = &[0];\n"));
+        assert!(code.contains("let buf = decode::encode_packet(&packet, &[&[0]])?;\n"));
+        assert!(code.contains("pub fn new(name: impl Into<Vec<u8>>, ) -> Self {\n"));
+        assert!(code.contains("impl From<Vec<u8>> for TrackNameArgs {\n"));
+        assert!(code.contains("impl From<&str> for TrackNameArgs {\n"));
+        assert!(!code.contains("impl From<String> for TrackNameArgs {\n"));
+    }
+
+    #[test]
+    fn test_binary_arguments_are_still_strings_to_reaper() {
+        assert_eq!(
+            routes()[0].reaperosc_pattern(),
+            Some("s/track/@/name".to_string())
+        );
+    }
+
+    #[test]
+    fn test_only_plain_string_arguments_can_be_binary() {
+        let routes: Vec<OscRoute> = serde_yaml::from_str(
+            r#"
+- osc_address: "/track/{track_guid}/volume"
+  params:
+    - name: track_guid
+      type: string
+  arguments:
+    - name: volume
+      type: float
+      binary: true
+    - name: unit
+      type: string
+      binary: true
+      default: "dB"
+  access_tags: [readable, writeable]
+"#,
+        )
+        .unwrap();
+        let messages: Vec<String> = validate(&routes).iter().map(|e| e.to_string()).collect();
+        assert_eq!(
+            messages,
+            [
+                "/track/{track_guid}/volume: argument volume is binary, but only string arguments can be",
+                "/track/{track_guid}/volume: argument unit is binary, so it can't have a default or an enum",
+            ]
+        );
+    }
+}